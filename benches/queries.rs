@@ -0,0 +1,75 @@
+//! Benchmarks of the queries run through the library. The database is written by sqlite
+//! with rusqlite, so that its b trees have the shape of the ones of real databases.
+
+use std::path::PathBuf;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use sqlite_starter_rust::{Database, OpenOptions};
+
+const NB_ROWS: i64 = 100_000;
+
+/// Writes the database of the benchmarks once, in the temporary directory: a table of
+/// tracks with an index on their artist
+fn fixture() -> PathBuf {
+    let path = std::env::temp_dir().join(format!("sqlite-starter-rust-bench-{}.db", NB_ROWS));
+    if path.exists() {
+        return path;
+    }
+    // written next to the path, then renamed: an interrupted setup leaves no fixture
+    let tmp_path = path.with_extension("tmp");
+    let _ = std::fs::remove_file(&tmp_path);
+    let mut conn = rusqlite::Connection::open(&tmp_path).unwrap();
+    conn.execute_batch(
+        "CREATE TABLE tracks (id INTEGER PRIMARY KEY, name TEXT, artist TEXT, milliseconds INTEGER, price REAL);
+         CREATE INDEX idx_tracks_artist ON tracks (artist);",
+    )
+    .unwrap();
+    let tx = conn.transaction().unwrap();
+    {
+        let mut insert = tx
+            .prepare("INSERT INTO tracks VALUES (?, ?, ?, ?, ?)")
+            .unwrap();
+        for id in 1..=NB_ROWS {
+            insert
+                .execute(rusqlite::params![
+                    id,
+                    format!("track {}", id),
+                    format!("artist {:04}", id * 7919 % 1000),
+                    180_000 + id * 31 % 120_000,
+                    0.99,
+                ])
+                .unwrap();
+        }
+    }
+    tx.commit().unwrap();
+    drop(conn);
+    std::fs::rename(&tmp_path, &path).unwrap();
+    path
+}
+
+fn queries(c: &mut Criterion) {
+    let path = fixture();
+    for (name, sql) in [
+        ("full scan", "SELECT * FROM tracks"),
+        ("count", "SELECT count(*) FROM tracks"),
+        (
+            "indexed lookup",
+            "SELECT name FROM tracks WHERE artist = 'artist 0421'",
+        ),
+        ("rowid lookup", "SELECT name FROM tracks WHERE id = 54321"),
+    ] {
+        for (backend, mmap) in [("file", false), ("mmap", true)] {
+            let options = OpenOptions {
+                mmap,
+                ..OpenOptions::default()
+            };
+            let mut db = Database::open_with(&path, options).unwrap();
+            c.bench_function(&format!("{} ({})", name, backend), |b| {
+                b.iter(|| db.execute(sql).unwrap())
+            });
+        }
+    }
+}
+
+criterion_group!(benches, queries);
+criterion_main!(benches);
@@ -0,0 +1,13 @@
+//! Reads a page header from arbitrary bytes: a corrupted header must be an error, not a
+//! panic. Run with `cargo +nightly fuzz run page_header`.
+#![no_main]
+
+use std::io::Cursor;
+
+use binrw::BinRead;
+use libfuzzer_sys::fuzz_target;
+use sqlite_starter_rust::page::PageHeader;
+
+fuzz_target!(|bytes: &[u8]| {
+    let _ = PageHeader::read(&mut Cursor::new(bytes));
+});
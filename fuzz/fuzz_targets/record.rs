@@ -0,0 +1,26 @@
+//! Reads a record from arbitrary bytes: a corrupted record must be an error, not a
+//! panic, a loop or an allocation of the size of a corrupted varint.
+//! Run with `cargo +nightly fuzz run record`.
+#![no_main]
+
+use std::io::Cursor;
+
+use binrw::BinRead;
+use libfuzzer_sys::fuzz_target;
+use sqlite_starter_rust::page::{Record, TextDecoding, TextEncoding};
+
+fuzz_target!(|bytes: &[u8]| {
+    for text_encoding in [
+        TextEncoding::Utf8,
+        TextEncoding::Utf16le,
+        TextEncoding::Utf16be,
+    ] {
+        for text_decoding in [TextDecoding::Lossy, TextDecoding::Strict, TextDecoding::Raw] {
+            let args = (text_encoding, text_decoding, None);
+            let _ = Record::read_args(&mut Cursor::new(bytes), args);
+        }
+    }
+    // only some columns are decoded, the others are skipped
+    let args = (TextEncoding::Utf8, TextDecoding::Lossy, Some(&[0, 2][..]));
+    let _ = Record::read_args(&mut Cursor::new(bytes), args);
+});
@@ -0,0 +1,97 @@
+//! Queries run from async code without blocking the threads of the tokio executor. The
+//! parsers read the pages through Read and Seek, so a query runs on the blocking pool of
+//! tokio, like tokio::fs does, with its own handle on the file: concurrent queries do not
+//! wait for each other.
+
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use serde::de::DeserializeOwned;
+
+use crate::{
+    error::{Error, Result},
+    Database, OpenOptions, ResultSet,
+};
+
+/// A database shared by the tasks of an async program. Cloning it is cheap: the clones
+/// share the header and the schema read when it was opened.
+#[derive(Clone)]
+pub struct AsyncDatabase {
+    /// Never used for a query, only cloned for each one. The mutex makes it Sync, as the
+    /// file handle of a Database is only Send.
+    db: Arc<Mutex<Database>>,
+}
+
+impl AsyncDatabase {
+    /// Opens the database mapped in memory: the pages are read from the mapping by all
+    /// the queries
+    pub async fn open(path: impl Into<PathBuf>) -> Result<Self> {
+        let options = OpenOptions {
+            mmap: true,
+            ..OpenOptions::default()
+        };
+        AsyncDatabase::open_with(path, options).await
+    }
+
+    /// Without mmap, each query opens the file again
+    pub async fn open_with(path: impl Into<PathBuf>, options: OpenOptions) -> Result<Self> {
+        let path = path.into();
+        let db = run_blocking(move || Database::open_with(path, options)).await?;
+        Ok(AsyncDatabase {
+            db: Arc::new(Mutex::new(db)),
+        })
+    }
+
+    /// Runs a SELECT or an EXPLAIN QUERY PLAN, see Database::execute
+    pub async fn execute(&self, sql: &str) -> Result<ResultSet> {
+        self.execute_with_params(sql, &[]).await
+    }
+
+    pub async fn execute_with_params(&self, sql: &str, params: &[String]) -> Result<ResultSet> {
+        let (sql, params) = (sql.to_string(), params.to_vec());
+        self.run(move |db| db.execute_with_params(&sql, &params))
+            .await
+    }
+
+    /// See Database::query_as
+    pub async fn query_as<T: DeserializeOwned + Send + 'static>(
+        &self,
+        sql: &str,
+    ) -> Result<Vec<T>> {
+        let sql = sql.to_string();
+        self.run(move |db| db.query_as(&sql)).await
+    }
+
+    /// Runs a query on the blocking pool, with a handle of its own. Opening the file
+    /// again for the handle is also done there.
+    async fn run<T: Send + 'static>(
+        &self,
+        query: impl FnOnce(&mut Database) -> Result<T> + Send + 'static,
+    ) -> Result<T> {
+        let db = Arc::clone(&self.db);
+        run_blocking(move || {
+            // the database is never changed, a query which panicked did not leave it
+            // half written
+            let handle = db
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .try_clone()?;
+            let mut handle = handle.ok_or_else(|| {
+                Error::UnsupportedFeature("the database can not be read concurrently".to_string())
+            })?;
+            query(&mut handle)
+        })
+        .await
+    }
+}
+
+/// Runs the reads on the blocking pool. The panic of a query is an error of the query.
+async fn run_blocking<T: Send + 'static>(
+    f: impl FnOnce() -> Result<T> + Send + 'static,
+) -> Result<T> {
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|err| Error::Runtime(err.to_string()))?
+}
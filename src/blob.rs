@@ -0,0 +1,255 @@
+use std::io::{self, Cursor, Read, Seek, SeekFrom};
+
+use binrw::BinResult;
+
+use crate::page::{page_offset, read_varint, ColumnType, FileFormat, LocalPayload};
+
+/// Reads the payload of a cell as a stream. The overflow pages are only read when the
+/// reading gets to them, so a big payload is never entirely in memory. Seeking forward
+/// skips bytes without reading them, and the overflow pages which only hold skipped
+/// bytes at the end of the payload are not read at all.
+/// The bytes of an overflow page are read at once, from the position of the reading to
+/// the end of the page: the varints and the columns of the record are then parsed from
+/// memory, not with a read of the file each.
+pub struct PayloadReader<'a, R> {
+    reader: &'a mut R,
+    file_format: FileFormat,
+    local_bytes: Cursor<Vec<u8>>,
+    first_overflow_page: u32,
+    next_overflow_page: u32,
+    /// Position in the file of the next byte of the current overflow page
+    position_in_file: u64,
+    /// Bytes of the current overflow page read from the file, up to the end of the
+    /// payload. The buffer is reused from one page to the next.
+    page_bytes: Vec<u8>,
+    /// Position in `page_bytes` of the next byte of the current overflow page
+    position_in_page_bytes: usize,
+    nb_bytes_left_in_page: usize,
+    nb_bytes_payload: u64,
+    nb_bytes_left: u64,
+    /// Bytes skipped by a seek, they are passed over at the next read
+    nb_bytes_to_skip: u64,
+}
+
+impl<'a, R: Read + Seek> PayloadReader<'a, R> {
+    pub fn new(reader: &'a mut R, payload: LocalPayload, file_format: FileFormat) -> Self {
+        PayloadReader {
+            reader,
+            file_format,
+            local_bytes: Cursor::new(payload.bytes),
+            first_overflow_page: payload.first_overflow_page.unwrap_or_default(),
+            next_overflow_page: payload.first_overflow_page.unwrap_or_default(),
+            position_in_file: 0,
+            page_bytes: Vec::new(),
+            position_in_page_bytes: 0,
+            nb_bytes_left_in_page: 0,
+            nb_bytes_payload: payload.nb_bytes_payload,
+            nb_bytes_left: payload.nb_bytes_payload,
+            nb_bytes_to_skip: 0,
+        }
+    }
+
+    /// Number of bytes of the payload in the next overflow page
+    fn nb_bytes_in_next_page(&self) -> usize {
+        (self.file_format.usable_size as usize - 4)
+            .min((self.nb_bytes_left + self.nb_bytes_to_skip) as usize)
+    }
+
+    /// Each overflow page starts with the number of the next one, 0 for the last one.
+    /// The bytes of the payload after it are read with the link when `read_bytes` is set.
+    fn read_next_overflow_page(&mut self, read_bytes: bool) -> io::Result<()> {
+        let page_no = self.next_overflow_page;
+        if page_no == 0 || page_no > self.file_format.page_count {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "The overflow pages end before the end of the payload",
+            ));
+        }
+        let nb_bytes_in_page = self.nb_bytes_in_next_page();
+        self.page_bytes
+            .resize(4 + if read_bytes { nb_bytes_in_page } else { 0 }, 0);
+        self.position_in_file = page_offset(page_no, self.file_format);
+        self.reader.seek(SeekFrom::Start(self.position_in_file))?;
+        self.reader.read_exact(&mut self.page_bytes)?;
+        self.next_overflow_page = u32::from_be_bytes(self.page_bytes[..4].try_into().unwrap());
+        self.position_in_file += 4;
+        self.position_in_page_bytes = 4;
+        self.nb_bytes_left_in_page = nb_bytes_in_page;
+        Ok(())
+    }
+
+    /// Reads the rest of the current overflow page, when the reading gets to the bytes
+    /// after the ones which were skipped
+    fn read_rest_of_page(&mut self) -> io::Result<()> {
+        self.page_bytes.resize(self.nb_bytes_left_in_page, 0);
+        self.reader.seek(SeekFrom::Start(self.position_in_file))?;
+        self.reader.read_exact(&mut self.page_bytes)?;
+        self.position_in_page_bytes = 0;
+        Ok(())
+    }
+
+    /// Passes over the skipped bytes, only the first 4 bytes of the overflow pages they
+    /// end in are read
+    fn skip(&mut self) -> io::Result<()> {
+        let local_bytes_left =
+            self.local_bytes.get_ref().len() as u64 - self.local_bytes.position();
+        let nb_bytes = self.nb_bytes_to_skip.min(local_bytes_left);
+        self.local_bytes
+            .set_position(self.local_bytes.position() + nb_bytes);
+        self.nb_bytes_to_skip -= nb_bytes;
+
+        while self.nb_bytes_to_skip > 0 {
+            if self.nb_bytes_left_in_page == 0 {
+                self.read_next_overflow_page(false)?;
+            }
+            let nb_bytes = self.nb_bytes_to_skip.min(self.nb_bytes_left_in_page as u64);
+            self.position_in_file += nb_bytes;
+            self.position_in_page_bytes += nb_bytes as usize;
+            self.nb_bytes_left_in_page -= nb_bytes as usize;
+            self.nb_bytes_to_skip -= nb_bytes;
+        }
+        Ok(())
+    }
+}
+
+impl<R: Read + Seek> Read for PayloadReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() || self.nb_bytes_left == 0 {
+            return Ok(0);
+        }
+        self.skip()?;
+        if self.local_bytes.position() < self.local_bytes.get_ref().len() as u64 {
+            let nb_bytes = self.local_bytes.read(buf)?;
+            self.nb_bytes_left -= nb_bytes as u64;
+            return Ok(nb_bytes);
+        }
+
+        if self.nb_bytes_left_in_page == 0 {
+            self.read_next_overflow_page(true)?;
+        } else if self.position_in_page_bytes >= self.page_bytes.len() {
+            self.read_rest_of_page()?;
+        }
+        let nb_bytes = buf.len().min(self.nb_bytes_left_in_page);
+        buf[..nb_bytes].copy_from_slice(
+            &self.page_bytes[self.position_in_page_bytes..self.position_in_page_bytes + nb_bytes],
+        );
+        self.position_in_page_bytes += nb_bytes;
+        self.position_in_file += nb_bytes as u64;
+        self.nb_bytes_left_in_page -= nb_bytes;
+        self.nb_bytes_left -= nb_bytes as u64;
+        Ok(nb_bytes)
+    }
+}
+
+/// The overflow pages are only chained forward: seeking backwards goes back to the start
+/// of the payload, and skips the bytes up to the position
+impl<R: Read + Seek> Seek for PayloadReader<'_, R> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(position) => Some(position),
+            SeekFrom::Current(delta) => self.position().checked_add_signed(delta),
+            SeekFrom::End(delta) => self.nb_bytes_payload.checked_add_signed(delta),
+        }
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative position",
+            )
+        })?;
+        if position < self.position() {
+            self.local_bytes.set_position(0);
+            self.next_overflow_page = self.first_overflow_page;
+            self.nb_bytes_left_in_page = 0;
+            self.nb_bytes_left = self.nb_bytes_payload;
+            self.nb_bytes_to_skip = 0;
+        }
+        let nb_bytes = position - self.position();
+        if nb_bytes > self.nb_bytes_left {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "Corrupted record: its columns end after its payload",
+            ));
+        }
+        self.nb_bytes_left -= nb_bytes;
+        self.nb_bytes_to_skip += nb_bytes;
+        Ok(self.position())
+    }
+}
+
+impl<R> PayloadReader<'_, R> {
+    /// Number of bytes of the payload read or skipped
+    pub fn position(&self) -> u64 {
+        self.nb_bytes_payload - self.nb_bytes_left
+    }
+}
+
+/// A blob or a text of a record, read as a stream like with sqlite3_blob_open: the bytes
+/// before the column are skipped, and only the bytes of the column are read
+pub struct BlobReader<'a, R> {
+    payload_reader: io::Take<PayloadReader<'a, R>>,
+}
+
+impl<'a, R: Read + Seek> BlobReader<'a, R> {
+    /// Opens the column `column` of the record of the payload
+    pub fn new(
+        reader: &'a mut R,
+        payload: LocalPayload,
+        file_format: FileFormat,
+        column: usize,
+    ) -> BinResult<Self> {
+        let nb_bytes_payload = payload.nb_bytes_payload;
+        let mut payload_reader = PayloadReader::new(reader, payload, file_format);
+        let (header_size, header_size_nb_bytes) = read_varint(&mut payload_reader)?;
+        if header_size > nb_bytes_payload {
+            return Err(binrw::Error::Io(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "Corrupted record: its header has {} bytes but its payload has {} bytes",
+                    header_size, nb_bytes_payload
+                ),
+            )));
+        }
+        let mut header = vec![0u8; header_size as usize - header_size_nb_bytes];
+        payload_reader.read_exact(&mut header)?;
+
+        // the columns added by ALTER TABLE after the record was written are not in it
+        let mut header = Cursor::new(header);
+        let mut offset = 0;
+        let mut column_type = ColumnType::Null;
+        for i in 0..=column {
+            if header.position() == header.get_ref().len() as u64 {
+                column_type = ColumnType::Null;
+                break;
+            }
+            column_type = ColumnType::try_from(read_varint(&mut header)?.0)?;
+            if i < column {
+                offset += column_type.nb_bytes();
+            }
+        }
+        let nb_bytes = match column_type {
+            ColumnType::Blob(nb_bytes) | ColumnType::String(nb_bytes) => nb_bytes,
+            column_type => {
+                return Err(binrw::Error::Io(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("The column is not a blob or a text: {:?}", column_type),
+                )))
+            }
+        };
+
+        if io::copy(&mut (&mut payload_reader).take(offset), &mut io::sink())? < offset {
+            return Err(binrw::Error::Io(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Corrupted record: its columns end after its payload",
+            )));
+        }
+        Ok(BlobReader {
+            payload_reader: payload_reader.take(nb_bytes),
+        })
+    }
+}
+
+impl<R: Read + Seek> Read for BlobReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.payload_reader.read(buf)
+    }
+}
@@ -0,0 +1,313 @@
+use std::{
+    collections::HashSet,
+    fmt,
+    fs::File,
+    io::{self, Read, Seek, SeekFrom},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use memmap2::Mmap;
+
+use crate::{
+    error::{Error, Result},
+    journal::RollbackJournal,
+    wal::Wal,
+};
+
+/// Offset in the database header of the read version, 2 for a database in WAL mode
+const FILE_FORMAT_READ_VERSION_OFFSET: u64 = 18;
+/// Offset in the database header of the page size, 1 for 65536
+const PAGE_SIZE_OFFSET: u64 = 16;
+
+/// A source of the bytes of a database other than a file, like a Cursor on a Vec<u8>
+trait ReadSeek: Read + Seek + Send {}
+
+impl<R: Read + Seek + Send> ReadSeek for R {}
+
+/// Where the pages of the main file are read from
+enum Backend {
+    /// Each read is a system call. The path opens other handles on the file.
+    File { file: File, path: PathBuf },
+    /// The file is mapped in memory once, and the reads copy from the mapping
+    Mmap(Arc<Mmap>),
+    /// Any reader, whose length is found once by seeking to its end
+    Reader { reader: Box<dyn ReadSeek>, len: u64 },
+}
+
+impl fmt::Debug for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Backend::File { file, path } => f
+                .debug_struct("File")
+                .field("file", file)
+                .field("path", path)
+                .finish(),
+            Backend::Mmap(mmap) => f.debug_tuple("Mmap").field(mmap).finish(),
+            Backend::Reader { len, .. } => f.debug_struct("Reader").field("len", len).finish(),
+        }
+    }
+}
+
+impl Backend {
+    fn len(&self) -> io::Result<u64> {
+        match self {
+            Backend::File { file, .. } => Ok(file.metadata()?.len()),
+            Backend::Mmap(mmap) => Ok(mmap.len() as u64),
+            Backend::Reader { len, .. } => Ok(*len),
+        }
+    }
+
+    /// Bytes of the header at `offset`, None when the database is too short to have them
+    fn header_bytes<const N: usize>(&mut self, offset: u64) -> Option<[u8; N]> {
+        let mut bytes = [0u8; N];
+        let mut nb_bytes = 0;
+        while nb_bytes < N {
+            match self.read_at(offset + nb_bytes as u64, &mut bytes[nb_bytes..]) {
+                Ok(0) | Err(_) => return None,
+                Ok(n) => nb_bytes += n,
+            }
+        }
+        Some(bytes)
+    }
+
+    fn read_at(&mut self, position: u64, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            Backend::File { file, .. } => {
+                file.seek(SeekFrom::Start(position))?;
+                file.read(buf)
+            }
+            Backend::Reader { reader, .. } => {
+                reader.seek(SeekFrom::Start(position))?;
+                reader.read(buf)
+            }
+            Backend::Mmap(mmap) => {
+                let start = (position as usize).min(mmap.len());
+                let nb_bytes = buf.len().min(mmap.len() - start);
+                buf[..nb_bytes].copy_from_slice(&mmap[start..start + nb_bytes]);
+                Ok(nb_bytes)
+            }
+        }
+    }
+}
+
+/// The database as the readers see it: the pages committed to the write-ahead log, if
+/// any, replace the ones of the main file, and so do the original pages of a hot journal
+#[derive(Debug)]
+pub struct DatabaseFile {
+    backend: Backend,
+    wal: Option<Arc<Wal>>,
+    journal: Option<Arc<RollbackJournal>>,
+    position: u64,
+    page_size: u64,
+    /// Pages which have been read, at least in part
+    pages_read: HashSet<u64>,
+    /// Number of reads of the main file, each one is a system call for a file
+    nb_file_reads: usize,
+}
+
+impl DatabaseFile {
+    /// Also reads the -wal file next to the database if the database is in WAL mode, and the
+    /// -journal file if it is hot. A hot journal which can not be read is an error unless
+    /// `force` is set: the database may then be half written, and is read as it is.
+    /// With `mmap`, the main file is mapped in memory instead of being read with a system
+    /// call for each cell. The file must not be truncated while it is mapped.
+    pub fn open<P: AsRef<Path>>(path: P, force: bool, mmap: bool) -> Result<Self> {
+        let file = File::open(&path)?;
+        let mut backend = if mmap {
+            // SAFETY: the database is only read, and sqlite does not shrink a database while
+            // another connection reads it
+            Backend::Mmap(Arc::new(unsafe { Mmap::map(&file)? }))
+        } else {
+            Backend::File {
+                file,
+                path: path.as_ref().to_path_buf(),
+            }
+        };
+
+        let is_wal_mode = backend.header_bytes(FILE_FORMAT_READ_VERSION_OFFSET) == Some([2]);
+
+        let mut wal_path = path.as_ref().as_os_str().to_owned();
+        wal_path.push("-wal");
+        let wal = match std::fs::read(&wal_path) {
+            Ok(bytes) if is_wal_mode => Wal::from_bytes(bytes)?.map(Arc::new),
+            _ => None,
+        };
+
+        let mut journal_path = path.as_ref().as_os_str().to_owned();
+        journal_path.push("-journal");
+        let journal = match std::fs::read(&journal_path) {
+            Ok(bytes) => match RollbackJournal::from_bytes(&bytes) {
+                Ok(journal) => journal.map(Arc::new),
+                Err(_) if force => None,
+                Err(err) => {
+                    return Err(Error::CorruptDatabase(format!(
+                        "the database has a hot journal {} which could not be read ({}); \
+                        use --force to read the database as it is",
+                        Path::new(&journal_path).display(),
+                        err
+                    )))
+                }
+            },
+            Err(_) => None,
+        };
+
+        Ok(DatabaseFile::new(backend, wal, journal))
+    }
+
+    /// Reads the database from any source, like a copy of a database file in memory.
+    /// There is no -wal or -journal file next to it: the database is read as it is.
+    pub fn from_reader<R: Read + Seek + Send + 'static>(mut reader: R) -> Result<Self> {
+        let len = reader.seek(SeekFrom::End(0))?;
+        let backend = Backend::Reader {
+            reader: Box::new(reader),
+            len,
+        };
+        Ok(DatabaseFile::new(backend, None, None))
+    }
+
+    fn new(
+        mut backend: Backend,
+        wal: Option<Arc<Wal>>,
+        journal: Option<Arc<RollbackJournal>>,
+    ) -> Self {
+        let page_size = match backend
+            .header_bytes(PAGE_SIZE_OFFSET)
+            .map(u16::from_be_bytes)
+        {
+            Some(1) => 65536,
+            Some(page_size) => page_size as u64,
+            None => 0,
+        };
+        DatabaseFile {
+            backend,
+            wal,
+            journal,
+            position: 0,
+            page_size,
+            pages_read: HashSet::new(),
+            nb_file_reads: 0,
+        }
+    }
+
+    /// Another handle on the database, with its own position and counters, to read it
+    /// from another thread. The file is opened again, while a mapping, the log and the
+    /// journal are shared. None for a reader, which can not be shared.
+    pub fn try_clone(&self) -> Result<Option<Self>> {
+        let backend = match &self.backend {
+            Backend::File { path, .. } => Backend::File {
+                file: File::open(path)?,
+                path: path.clone(),
+            },
+            Backend::Mmap(mmap) => Backend::Mmap(Arc::clone(mmap)),
+            Backend::Reader { .. } => return Ok(None),
+        };
+        Ok(Some(DatabaseFile {
+            backend,
+            wal: self.wal.clone(),
+            journal: self.journal.clone(),
+            position: 0,
+            page_size: self.page_size,
+            pages_read: HashSet::new(),
+            nb_file_reads: 0,
+        }))
+    }
+
+    /// Counts the reads of a clone as reads of this handle, see try_clone
+    pub fn add_reads(&mut self, clone: &DatabaseFile) {
+        self.pages_read.extend(&clone.pages_read);
+        self.nb_file_reads += clone.nb_file_reads;
+    }
+
+    /// Length of the database once the committed transactions of the log are applied, or
+    /// once the interrupted transaction of the journal is rolled back
+    pub fn len(&self) -> io::Result<u64> {
+        match (&self.wal, &self.journal) {
+            (Some(wal), _) => Ok(wal.db_size as u64 * wal.page_size as u64),
+            (None, Some(journal)) => Ok(journal.db_size as u64 * journal.page_size as u64),
+            (None, None) => self.backend.len(),
+        }
+    }
+
+    /// Number of pages which have been read, to check that a query only reads the pages
+    /// it needs
+    pub fn nb_pages_read(&self) -> usize {
+        self.pages_read.len()
+    }
+
+    /// Number of reads of the main file or of the reader, none when the file is mapped in
+    /// memory
+    pub fn nb_file_reads(&self) -> usize {
+        self.nb_file_reads
+    }
+
+    /// Page size and content of the page of the log or of the journal at the position
+    fn overlay_page(&self) -> Option<(u64, Option<&[u8]>)> {
+        match (&self.wal, &self.journal) {
+            (Some(wal), _) => {
+                let page_size = wal.page_size as u64;
+                Some((page_size, wal.page((self.position / page_size + 1) as u32)))
+            }
+            (None, Some(journal)) => {
+                let page_size = journal.page_size as u64;
+                Some((
+                    page_size,
+                    journal.page((self.position / page_size + 1) as u32),
+                ))
+            }
+            (None, None) => None,
+        }
+    }
+
+    /// Reads from the log or the journal if they have the page at the position, from
+    /// the main file otherwise
+    fn read_at_position(&mut self, mut buf: &mut [u8]) -> io::Result<usize> {
+        if let Some((page_size, page)) = self.overlay_page() {
+            // a read stops at the end of a page, the next one may come from another file
+            let offset_in_page = (self.position % page_size) as usize;
+            let nb_bytes = buf.len().min(page_size as usize - offset_in_page);
+            if let Some(page) = page {
+                buf[..nb_bytes].copy_from_slice(&page[offset_in_page..offset_in_page + nb_bytes]);
+                self.position += nb_bytes as u64;
+                return Ok(nb_bytes);
+            }
+            buf = &mut buf[..nb_bytes];
+        }
+        if let Backend::File { .. } | Backend::Reader { .. } = self.backend {
+            self.nb_file_reads += 1;
+        }
+        let nb_bytes = self.backend.read_at(self.position, buf)?;
+        self.position += nb_bytes as u64;
+        Ok(nb_bytes)
+    }
+}
+
+impl Read for DatabaseFile {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let start = self.position;
+        let nb_bytes = self.read_at_position(buf)?;
+        if self.page_size > 0 && nb_bytes > 0 {
+            let last = start + nb_bytes as u64 - 1;
+            self.pages_read
+                .extend(start / self.page_size..=last / self.page_size);
+        }
+        Ok(nb_bytes)
+    }
+}
+
+impl Seek for DatabaseFile {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(position) => Some(position),
+            SeekFrom::End(delta) => self.len()?.checked_add_signed(delta),
+            SeekFrom::Current(delta) => self.position.checked_add_signed(delta),
+        };
+        self.position = position.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative position",
+            )
+        })?;
+        Ok(self.position)
+    }
+}
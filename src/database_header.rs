@@ -1,13 +1,25 @@
 use binrw::binrw;
 
-#[derive(Debug)]
+use crate::{
+    error::{Error, Result},
+    page::{FileFormat, TextDecoding, TextEncoding},
+};
+
+/// The database header occupies the first 100 bytes of the first page
+pub const DATABASE_HEADER_SIZE: u64 = 100;
+
+/// The page holding this byte is used by the locks of the OS and has no content
+const LOCK_BYTE_OFFSET: u64 = 0x4000_0000;
+
+#[derive(Debug, Clone)]
 #[binrw]
 #[brw(big)]
 pub struct DatabaseHeader {
     #[br(assert(String::from_utf8_lossy(&magic_string) == "SQLite format 3\0"))]
     #[br(count = 16)]
     pub magic_string: Vec<u8>,
-    pub page_size: u16,
+    /// Use page_size() instead, 65536 is stored as 1
+    page_size: u16,
     pub file_format_write_version: u8, // 1 for legacy, 2 for WAL
     pub file_format_read_version: u8,  // 1 for legacy, 2 for WAL
     pub bytes_unused_reserved_space: u8,
@@ -17,8 +29,11 @@ pub struct DatabaseHeader {
     pub min_embedded_payload_fraction: u8,
     #[br(assert(leaf_payload_fraction == 32))]
     pub leaf_payload_fraction: u8,
-    pub file_change_counter: u32,
-    pub in_header_db_size: u32,
+    /// The fields changed by each transaction are only written through setters, which keep
+    /// them consistent
+    file_change_counter: u32,
+    /// Use page_count() instead, this size is only valid for some writers
+    in_header_db_size: u32,
     pub page_no_first_freelink_trunk_page: u32,
     pub total_no_freelist_pages: u32,
     pub schema_cookie: u32,
@@ -30,11 +45,145 @@ pub struct DatabaseHeader {
     pub user_version: u32,
     pub incremental_vacuum_mode: u32, //  True (non-zero) for incremental-vacuum mode. False (zero) otherwise.
     pub application_id: u32,
+    /// Should be all 0, see warnings()
     #[br(count = 20)]
-    #[br(assert(vector_all_zeros(&reserved)))]
-    pub reserved: Vec<u8>, // should be all 0
-    pub version_valid_for_number: u32,
+    pub reserved: Vec<u8>,
+    version_valid_for_number: u32,
     pub sqlite_version_number: u32,
+    /// Not part of the header: set from the command line, see FileFormat::lenient_records
+    #[brw(ignore)]
+    pub lenient_records: bool,
+    /// Not part of the header: set from the command line, see TextDecoding
+    #[brw(ignore)]
+    pub text_decoding: TextDecoding,
+    /// Not part of the header: the length of the database file, set when opening it
+    #[brw(ignore)]
+    pub file_len: u64,
+    /// Not part of the header: set from the command line, see OpenOptions::scan_threads
+    #[brw(ignore)]
+    pub scan_threads: usize,
+}
+
+impl DatabaseHeader {
+    /// Size of a page in bytes, a power of two between 512 and 65536
+    pub fn page_size(&self) -> u32 {
+        match self.page_size {
+            1 => 65536,
+            page_size => page_size as u32,
+        }
+    }
+
+    /// The reserved bytes at the end of each page must leave at least 480 usable bytes
+    pub fn set_page_size(&mut self, page_size: u32) -> Result<()> {
+        if !page_size.is_power_of_two() || !(512..=65536).contains(&page_size) {
+            return Err(Error::InvalidValue(format!(
+                "The page size must be a power of two between 512 and 65536, not {}",
+                page_size
+            )));
+        }
+        if page_size - (self.bytes_unused_reserved_space as u32) < 480 {
+            return Err(Error::InvalidValue(format!(
+                "A page size of {} leaves less than 480 usable bytes with {} reserved bytes",
+                page_size, self.bytes_unused_reserved_space
+            )));
+        }
+        self.page_size = match page_size {
+            65536 => 1,
+            page_size => page_size as u16,
+        };
+        Ok(())
+    }
+
+    /// Also makes the size valid, as sqlite writes it with the change counter
+    pub fn set_in_header_db_size(&mut self, nb_pages: u32) -> Result<()> {
+        if nb_pages == 0 {
+            return Err(Error::InvalidValue(
+                "A database has at least one page".to_string(),
+            ));
+        }
+        self.in_header_db_size = nb_pages;
+        self.version_valid_for_number = self.file_change_counter;
+        Ok(())
+    }
+
+    /// Sets the change counter and the version-valid-for number, which sqlite keeps equal
+    pub fn set_change_counter(&mut self, change_counter: u32) {
+        self.file_change_counter = change_counter;
+        self.version_valid_for_number = change_counter;
+    }
+
+    /// What a transaction does to the header, the counter wraps around
+    pub fn increment_change_counter(&mut self) {
+        self.set_change_counter(self.file_change_counter.wrapping_add(1));
+    }
+
+    /// Anomalies of the header which do not prevent reading the database
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if !vector_all_zeros(&self.reserved) {
+            warnings.push(
+                "the bytes 72 to 91 of the database header, reserved for expansion, are not zero"
+                    .to_string(),
+            );
+        }
+        warnings
+    }
+
+    /// Number of pages of the database. The size in the header is only trusted when the
+    /// version-valid-for number matches the change counter, as sqlite versions before 3.7.0
+    /// did not update it. Otherwise, it is derived from the length of the file.
+    pub fn page_count(&self, file_len: u64) -> u32 {
+        if self.in_header_db_size != 0 && self.version_valid_for_number == self.file_change_counter
+        {
+            self.in_header_db_size
+        } else {
+            (file_len / self.page_size() as u64) as u32
+        }
+    }
+
+    /// Databases bigger than 1 GiB have a page which is neither a b tree nor a freelist
+    /// page, pages which iterate over the file must skip it
+    pub fn lock_byte_page(&self) -> u32 {
+        (LOCK_BYTE_OFFSET / self.page_size() as u64) as u32 + 1
+    }
+
+    pub fn file_format(&self) -> FileFormat {
+        let page_size = self.page_size();
+        FileFormat {
+            page_size,
+            usable_size: page_size - self.bytes_unused_reserved_space as u32,
+            text_encoding: match self.db_text_encoding {
+                2 => TextEncoding::Utf16le,
+                3 => TextEncoding::Utf16be,
+                _ => TextEncoding::Utf8,
+            },
+            lenient_records: self.lenient_records,
+            text_decoding: self.text_decoding,
+            page_count: self.page_count(self.file_len),
+        }
+    }
+
+    /// In auto-vacuum mode, the header holds the largest root page, which is 0 otherwise
+    pub fn is_auto_vacuum(&self) -> bool {
+        self.largest_root_b_tree_page_number_auto_incremental_vacuum != 0
+    }
+
+    /// Pointer map pages give the parent of each page in auto-vacuum databases. They are
+    /// neither b tree nor freelist pages. The first one is page 2, and each one is followed
+    /// by the usable_size / 5 pages it maps. A pointer map page which would be the lock-byte
+    /// page is the next page instead.
+    pub fn is_ptrmap_page(&self, page_no: u32) -> bool {
+        if !self.is_auto_vacuum() || page_no < 2 {
+            return false;
+        }
+        let group_size = self.file_format().usable_size / 5 + 1;
+        let ptrmap_page = (page_no - 2) / group_size * group_size + 2;
+        if ptrmap_page == self.lock_byte_page() {
+            page_no == ptrmap_page + 1
+        } else {
+            page_no == ptrmap_page
+        }
+    }
 }
 
 fn vector_all_zeros(vector: &[u8]) -> bool {
@@ -45,3 +194,137 @@ fn vector_all_zeros(vector: &[u8]) -> bool {
     }
     true
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs::File,
+        io::{Cursor, Read},
+    };
+
+    use binrw::{BinRead, BinWrite};
+
+    use super::*;
+
+    fn read_header(db: &str) -> DatabaseHeader {
+        DatabaseHeader::read(&mut File::open(db).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn headers_round_trip() {
+        for db in [
+            // sqlite 3.34.0
+            "sample.db",
+            // sqlite 3.40.1
+            "tests/fixtures/sqlite_3_40.db",
+            // sqlite 3.51.2, with various settings
+            "tests/fixtures/users.db",
+            "tests/fixtures/auto_vacuum.db",
+            "tests/fixtures/utf16be.db",
+            "tests/fixtures/reserved_bytes.db",
+            "tests/fixtures/page_size_64k.db",
+        ] {
+            let mut bytes = vec![0; DATABASE_HEADER_SIZE as usize];
+            File::open(db).unwrap().read_exact(&mut bytes).unwrap();
+            let db_header = DatabaseHeader::read(&mut Cursor::new(&bytes)).unwrap();
+            let mut written = Cursor::new(Vec::new());
+            db_header.write(&mut written).unwrap();
+            assert_eq!(written.into_inner(), bytes, "{}", db);
+        }
+    }
+
+    #[test]
+    fn setters_keep_the_header_consistent() {
+        let mut db_header = read_header("tests/fixtures/page_size_64k.db");
+        assert_eq!(db_header.page_size, 1);
+        db_header.set_page_size(4096).unwrap();
+        assert_eq!(db_header.page_size(), 4096);
+        db_header.set_page_size(65536).unwrap();
+        assert_eq!(db_header.page_size, 1);
+        assert!(db_header.set_page_size(1000).is_err());
+        assert!(db_header.set_page_size(256).is_err());
+        assert!(db_header.set_page_size(131072).is_err());
+
+        db_header.bytes_unused_reserved_space = 64;
+        assert!(db_header.set_page_size(512).is_err());
+
+        // the size of a stale header is valid again once written
+        db_header.set_change_counter(7);
+        db_header.version_valid_for_number = 3;
+        assert_eq!(db_header.page_count(65536 * 5), 5);
+        db_header.set_in_header_db_size(2).unwrap();
+        assert_eq!(db_header.page_count(65536 * 5), 2);
+        assert!(db_header.set_in_header_db_size(0).is_err());
+
+        db_header.increment_change_counter();
+        assert_eq!(db_header.file_change_counter, 8);
+        assert_eq!(db_header.version_valid_for_number, 8);
+        db_header.set_change_counter(u32::MAX);
+        db_header.increment_change_counter();
+        assert_eq!(db_header.file_change_counter, 0);
+    }
+
+    #[test]
+    fn page_count_from_the_file_length_when_the_header_is_stale() {
+        // 15 pages of 512 bytes
+        let mut db_header = read_header("tests/fixtures/corrupted.db");
+        assert_eq!(db_header.page_count(7680), 15);
+
+        // the header is trusted even if the file has more pages
+        assert_eq!(db_header.page_count(10240), 15);
+
+        db_header.version_valid_for_number = db_header.file_change_counter + 1;
+        assert_eq!(db_header.page_count(10240), 20);
+
+        db_header.version_valid_for_number = db_header.file_change_counter;
+        db_header.in_header_db_size = 0;
+        assert_eq!(db_header.page_count(10240), 20);
+    }
+
+    #[test]
+    fn ptrmap_pages_of_auto_vacuum_database() {
+        // 512 bytes pages: each pointer map page maps 102 pages
+        let db_header = read_header("tests/fixtures/auto_vacuum.db");
+        assert!(db_header.is_auto_vacuum());
+        let ptrmap_pages = (1..=db_header.in_header_db_size)
+            .filter(|&page_no| db_header.is_ptrmap_page(page_no))
+            .collect::<Vec<_>>();
+        assert_eq!(ptrmap_pages, vec![2, 105, 208]);
+    }
+
+    #[test]
+    fn lock_byte_page() {
+        let mut db_header = read_header("sample.db");
+        assert_eq!(db_header.lock_byte_page(), 262145);
+        db_header.page_size = 512;
+        assert_eq!(db_header.lock_byte_page(), 2097153);
+        // 65536 is stored as 1
+        db_header.page_size = 1;
+        assert_eq!(db_header.lock_byte_page(), 16385);
+        // the page starts at the lock byte
+        assert_eq!(
+            (db_header.lock_byte_page() as u64 - 1) * db_header.page_size() as u64,
+            LOCK_BYTE_OFFSET
+        );
+    }
+
+    #[test]
+    fn ptrmap_page_moved_after_the_lock_byte_page() {
+        // with 1024 bytes pages, pointer map pages are every 205 pages, and the lock-byte
+        // page 1048577 would be one of them
+        let mut db_header = read_header("tests/fixtures/auto_vacuum.db");
+        db_header.page_size = 1024;
+        assert_eq!(db_header.lock_byte_page(), 1048577);
+        assert!(db_header.is_ptrmap_page(1048577 - 205));
+        assert!(!db_header.is_ptrmap_page(1048577));
+        assert!(db_header.is_ptrmap_page(1048578));
+        assert!(db_header.is_ptrmap_page(1048577 + 205));
+    }
+
+    #[test]
+    fn no_ptrmap_pages_without_auto_vacuum() {
+        let db_header = read_header("sample.db");
+        assert!(!db_header.is_auto_vacuum());
+        assert!(!(1..=db_header.in_header_db_size).any(|page_no| db_header.is_ptrmap_page(page_no)));
+    }
+}
@@ -0,0 +1,445 @@
+//! Rows read into the types deriving serde::Deserialize: the fields of a struct are
+//! matched with the columns by name, the elements of a tuple with the columns in order.
+
+use std::fmt::Display;
+
+use serde::de::{
+    self, value::SeqDeserializer, DeserializeOwned, DeserializeSeed, IntoDeserializer, MapAccess,
+    SeqAccess, Visitor,
+};
+
+use crate::{
+    error::{ConversionError, Error, Result},
+    page::ColumnContent,
+    row::{type_name, Row},
+};
+
+impl de::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Error::Deserialize {
+            column: None,
+            reason: msg.to_string(),
+        }
+    }
+}
+
+/// Reads a row into `T`, like `from_row::<User>(&row)`. The columns which are not fields
+/// of the struct are ignored.
+pub fn from_row<T: DeserializeOwned>(row: &Row) -> Result<T> {
+    T::deserialize(RowDeserializer { row })
+}
+
+struct RowDeserializer<'a> {
+    row: &'a Row,
+}
+
+impl<'de, 'a> de::Deserializer<'de> for RowDeserializer<'a> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_map(Columns {
+            row: self.row,
+            position: 0,
+        })
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_seq(Columns {
+            row: self.row,
+            position: 0,
+        })
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes
+        byte_buf option unit unit_struct enum identifier ignored_any
+    }
+}
+
+/// The columns of a row, as the entries of a map or the elements of a sequence
+struct Columns<'a> {
+    row: &'a Row,
+    position: usize,
+}
+
+impl<'a> Columns<'a> {
+    /// The value of the current column, then moves to the next one
+    fn next_value<'de, T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<T::Value> {
+        let column = &self.row.columns()[self.position];
+        let value = &self.row[self.position];
+        self.position += 1;
+        // the errors of the visitors, like an invalid char, are given the column
+        seed.deserialize(ValueDeserializer { column, value })
+            .map_err(|err| match err {
+                Error::Deserialize {
+                    column: None,
+                    reason,
+                } => Error::Deserialize {
+                    column: Some(column.clone()),
+                    reason,
+                },
+                err => err,
+            })
+    }
+}
+
+impl<'de, 'a> MapAccess<'de> for Columns<'a> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        match self.row.columns().get(self.position) {
+            Some(column) => seed
+                .deserialize(column.as_str().into_deserializer())
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        self.next_value(seed)
+    }
+}
+
+impl<'de, 'a> SeqAccess<'de> for Columns<'a> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        match self.position < self.row.columns().len() {
+            true => self.next_value(seed).map(Some),
+            false => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.row.columns().len() - self.position)
+    }
+}
+
+/// A value with the name of its column, for the error messages
+struct ValueDeserializer<'a> {
+    column: &'a str,
+    value: &'a ColumnContent,
+}
+
+impl<'a> ValueDeserializer<'a> {
+    fn mismatch(&self, expected: &'static str) -> Error {
+        Error::TypeMismatch {
+            column: self.column.to_string(),
+            expected,
+            found: type_name(self.value),
+        }
+    }
+
+    /// The value converted with its TryFrom conversion, see row.rs
+    fn convert<T: TryFrom<&'a ColumnContent, Error = ConversionError>>(&self) -> Result<T> {
+        T::try_from(self.value).map_err(|ConversionError { expected, found }| Error::TypeMismatch {
+            column: self.column.to_string(),
+            expected,
+            found,
+        })
+    }
+
+    /// An integer which fits in `T`, the type of the field
+    fn integer<T: TryFrom<i64>>(&self) -> Result<T> {
+        let x: i64 = self.convert()?;
+        T::try_from(x).map_err(|_| Error::Deserialize {
+            column: Some(self.column.to_string()),
+            reason: format!(
+                "{} is out of the range of {}",
+                x,
+                std::any::type_name::<T>()
+            ),
+        })
+    }
+}
+
+macro_rules! deserialize_integers {
+    ($($deserialize:ident => $visit:ident,)*) => {
+        $(
+            fn $deserialize<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+                visitor.$visit(self.integer()?)
+            }
+        )*
+    };
+}
+
+impl<'de, 'a> de::Deserializer<'de> for ValueDeserializer<'a> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.value {
+            ColumnContent::Null => visitor.visit_unit(),
+            ColumnContent::Int(x) => visitor.visit_i64(*x),
+            ColumnContent::Float(x) => visitor.visit_f64(*x),
+            ColumnContent::String(x) => visitor.visit_str(x),
+            ColumnContent::Blob(x) => visitor.visit_bytes(x),
+        }
+    }
+
+    deserialize_integers! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+    }
+
+    /// Like in sqlite, a value other than 0 is true
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_bool(self.convert()?)
+    }
+
+    /// An integer is read as a real, like with Row::get
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_f32(self.convert::<f64>()? as f32)
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_f64(self.convert()?)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.value {
+            ColumnContent::String(x) => visitor.visit_str(x),
+            _ => Err(self.mismatch("text")),
+        }
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.value {
+            ColumnContent::Blob(x) => visitor.visit_bytes(x),
+            _ => Err(self.mismatch("blob")),
+        }
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_bytes(visitor)
+    }
+
+    /// A Vec<u8> is read as a sequence, from the bytes of a blob
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.value {
+            ColumnContent::Blob(x) => visitor.visit_seq(SeqDeserializer::new(x.iter().copied())),
+            _ => Err(self.mismatch("blob")),
+        }
+    }
+
+    /// A NULL is only read as an Option, like with Row::get
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.value {
+            ColumnContent::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.value {
+            ColumnContent::Null => visitor.visit_unit(),
+            _ => Err(self.mismatch("null")),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    /// The unit variants of an enum are read from their name
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        match self.value {
+            ColumnContent::String(x) => visitor.visit_enum(x.as_str().into_deserializer()),
+            _ => Err(self.mismatch("text")),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        unit_struct tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use serde::Deserialize;
+
+    use super::*;
+
+    fn row(columns: &[&str], values: Vec<ColumnContent>) -> Row {
+        let columns: Arc<[String]> = columns.iter().map(|c| c.to_string()).collect();
+        Row::new(columns, None, values)
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Track {
+        id: u32,
+        name: String,
+        composer: Option<String>,
+        milliseconds: i64,
+        unit_price: f32,
+    }
+
+    #[test]
+    fn columns_are_fields() {
+        let values = vec![
+            ColumnContent::Int(3),
+            ColumnContent::String("Fast As a Shark".to_string()),
+            ColumnContent::Null,
+            ColumnContent::Int(230619),
+            ColumnContent::Float(0.99),
+            ColumnContent::Int(1),
+        ];
+        let columns = [
+            "id",
+            "name",
+            "composer",
+            "milliseconds",
+            "unit_price",
+            "genre",
+        ];
+        let track: Track = from_row(&row(&columns, values)).unwrap();
+        assert_eq!(
+            track,
+            Track {
+                id: 3,
+                name: "Fast As a Shark".to_string(),
+                composer: None,
+                milliseconds: 230619,
+                unit_price: 0.99,
+            }
+        );
+
+        let tuple: (i64, String) = from_row(&row(
+            &["id", "name"],
+            vec![
+                ColumnContent::Int(1),
+                ColumnContent::String("a".to_string()),
+            ],
+        ))
+        .unwrap();
+        assert_eq!(tuple, (1, "a".to_string()));
+    }
+
+    #[test]
+    fn mismatches_name_the_column() {
+        #[derive(Debug, Deserialize)]
+        struct Small {
+            #[allow(dead_code)]
+            value: u8,
+        }
+        let error = |values: Vec<ColumnContent>| {
+            from_row::<Small>(&row(&["value"], values))
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            error(vec![ColumnContent::Int(256)]),
+            "Could not read the column \"value\": 256 is out of the range of u8"
+        );
+        assert_eq!(
+            error(vec![ColumnContent::Int(-1)]),
+            "Could not read the column \"value\": -1 is out of the range of u8"
+        );
+        assert_eq!(
+            error(vec![ColumnContent::String("1".to_string())]),
+            "Could not read the column \"value\": expected integer, found text"
+        );
+        assert_eq!(
+            error(vec![ColumnContent::Null]),
+            "Could not read the column \"value\": expected integer, found null"
+        );
+        assert_eq!(
+            from_row::<Small>(&row(&["other"], vec![ColumnContent::Int(1)]))
+                .unwrap_err()
+                .to_string(),
+            "Could not read the row: missing field `value`"
+        );
+    }
+
+    #[test]
+    fn blobs_booleans_and_enums() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        enum Color {
+            Red,
+            Green,
+        }
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Item {
+            data: Vec<u8>,
+            active: bool,
+            color: Color,
+        }
+        let item: Item = from_row(&row(
+            &["data", "active", "color"],
+            vec![
+                ColumnContent::Blob(vec![0xca, 0xfe]),
+                ColumnContent::Int(1),
+                ColumnContent::String("Green".to_string()),
+            ],
+        ))
+        .unwrap();
+        assert_eq!(
+            item,
+            Item {
+                data: vec![0xca, 0xfe],
+                active: true,
+                color: Color::Green,
+            }
+        );
+    }
+}
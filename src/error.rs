@@ -0,0 +1,82 @@
+use thiserror::Error;
+
+/// Errors of the library. Their messages are the ones of sqlite3 when it has one.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// The SQL does not parse: the parsing stopped at the byte `position` of the
+    /// statement, on the text `near`
+    #[error("Parse error: near \"{near}\": syntax error")]
+    ParseError { near: String, position: usize },
+    #[error("Parse error: no such table: {0}")]
+    NoSuchTable(String),
+    #[error("Parse error: no such column: {0}")]
+    NoSuchColumn(String),
+    /// A statement which parses but can not be run, like a call to a function which does
+    /// not exist
+    #[error("Parse error: {0}")]
+    InvalidStatement(String),
+    /// The values given for the parameters do not match the placeholders of the statement
+    #[error("{0}")]
+    InvalidParameters(String),
+    /// An error of the evaluation of an expression, like an integer overflow
+    #[error("Runtime error: {0}")]
+    Runtime(String),
+    /// A value read with Row::get is not of the type asked for
+    #[error("Could not read the column {column:?}: expected {expected}, found {found}")]
+    TypeMismatch {
+        column: String,
+        expected: &'static str,
+        found: &'static str,
+    },
+    /// A row read with Database::query_as does not match the type asked for: a field has
+    /// no column, or a value does not fit in its field. The column is None for the errors
+    /// of the whole row.
+    #[error(
+        "Could not read the {}: {reason}",
+        column.as_ref().map_or("row".to_string(), |column| format!("column {:?}", column))
+    )]
+    Deserialize {
+        column: Option<String>,
+        reason: String,
+    },
+    /// A page which can not be parsed, or whose content does not match the rest of the
+    /// database
+    #[error("{reason}")]
+    CorruptPage { page_no: u32, reason: String },
+    /// The database is corrupted outside of its b tree pages: the file is truncated, or
+    /// its schema, its freelist or its journal can not be read
+    #[error("{0}")]
+    CorruptDatabase(String),
+    #[error("{0}")]
+    UnsupportedFeature(String),
+    /// A value given to the library is out of its range
+    #[error("{0}")]
+    InvalidValue(String),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// A value which is not of the Rust type asked for, see the TryFrom<&ColumnContent>
+/// conversions. The types are named like by the typeof() function of sqlite.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("expected {expected}, found {found}")]
+pub struct ConversionError {
+    pub expected: &'static str,
+    pub found: &'static str,
+}
+
+/// The errors of the binrw parsers which are not attached to a page
+impl From<binrw::Error> for Error {
+    fn from(mut err: binrw::Error) -> Self {
+        // the errors of the parsers are wrapped with the fields being parsed
+        while let binrw::Error::Backtrace(backtrace) = err {
+            err = *backtrace.error;
+        }
+        match err {
+            binrw::Error::Io(err) => Error::Io(err),
+            err => Error::CorruptDatabase(err.to_string()),
+        }
+    }
+}
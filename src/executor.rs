@@ -0,0 +1,974 @@
+//! Execution of the SELECT statements: the rows of a table are read through the plan
+//! chosen from the where clause, then filtered, grouped, projected and sorted.
+
+use std::{cmp::Ordering, collections::BinaryHeap, ops::ControlFlow, sync::Arc};
+
+use crate::{
+    database_file::DatabaseFile,
+    database_header::DatabaseHeader,
+    error::{Error, Result},
+    expression::{
+        apply_affinities, column_position, contains_aggregate, evaluate, evaluate_aggregate,
+        is_true,
+    },
+    for_each_table_row, get_create_table_query, get_table_columns,
+    page::ColumnContent,
+    plan_table_read,
+    row::Row,
+    schema_table::{SchemaTable, SchemaTableRecord},
+    sql_parser::{
+        parse_create_view_command, Expr, Literal, ResultColumn, SelectQuery, TableSource,
+    },
+    value::{compare_keys, sort_order},
+    Database, Plan, TableRows,
+};
+
+/// Columns and rows produced by a SELECT statement
+#[derive(Debug)]
+pub struct ResultSet {
+    pub columns: Vec<String>,
+    pub rows: Vec<Row>,
+    /// Like sqlite3_stmt_isexplain, tells that the rows are the steps of EXPLAIN QUERY
+    /// PLAN, in a detail column
+    pub is_explain: bool,
+    /// Position of the column which holds the rowid of the table, if it is selected
+    rowid_column: Option<usize>,
+}
+
+impl ResultSet {
+    pub(crate) fn new(
+        columns: Vec<String>,
+        rowid_column: Option<usize>,
+        rows: Vec<Vec<ColumnContent>>,
+    ) -> Self {
+        let row_columns: Arc<[String]> = columns.clone().into();
+        ResultSet {
+            rows: rows
+                .into_iter()
+                .map(|values| Row::new(row_columns.clone(), rowid_column, values))
+                .collect(),
+            columns,
+            is_explain: false,
+            rowid_column,
+        }
+    }
+}
+
+/// Runs a SELECT on the database, or on the attached database of its table, see
+/// Database::database_of
+pub fn execute_select(db: &mut Database, select_query: &SelectQuery) -> Result<ResultSet> {
+    let db = db.database_of(&select_query.from)?;
+    run_select(&mut db.file, &db.header, &db.schema_table, select_query)
+}
+
+/// Runs an EXPLAIN QUERY PLAN of a SELECT: the rows are the steps of the plan, in a
+/// detail column
+pub fn explain_select(db: &mut Database, select_query: &SelectQuery) -> Result<ResultSet> {
+    let db = db.database_of(&select_query.from)?;
+    let steps = explain_steps(&mut db.file, &db.header, &db.schema_table, select_query)?;
+    let mut result_set = ResultSet::new(
+        vec!["detail".to_string()],
+        None,
+        steps
+            .into_iter()
+            .map(|step| vec![ColumnContent::String(step)])
+            .collect(),
+    );
+    result_set.is_explain = true;
+    Ok(result_set)
+}
+
+/// Collects the rows of a table, see for_each_table_row
+fn get_table_rows(
+    file: &mut DatabaseFile,
+    db_header: &DatabaseHeader,
+    schema_table: &SchemaTable,
+    tablename: &str,
+    where_clause: Option<&Expr>,
+    used_columns: Option<&[usize]>,
+) -> Result<ResultSet> {
+    let mut rows = Vec::new();
+    for_each_table_row(
+        file,
+        db_header,
+        schema_table,
+        tablename,
+        where_clause,
+        used_columns,
+        &mut |row| {
+            rows.push(row);
+            Ok(ControlFlow::Continue(()))
+        },
+    )?;
+    let create_table_query = get_create_table_query(schema_table, tablename)?;
+    Ok(ResultSet::new(
+        create_table_query.column_names(),
+        create_table_query.rowid_alias(),
+        rows,
+    ))
+}
+
+/// Calls `f` on every column reference of a select, including the ones of its sub selects
+fn visit_columns(query: &mut SelectQuery, f: &mut dyn FnMut(&mut Expr)) {
+    fn visit_expr(expr: &mut Expr, f: &mut dyn FnMut(&mut Expr)) {
+        match expr {
+            Expr::Column(_) => f(expr),
+            Expr::Exists(query) | Expr::Subquery(query) => visit_columns(query, f),
+            expr => {
+                for child in expr.children_mut() {
+                    visit_expr(child, f);
+                }
+            }
+        }
+    }
+
+    for column in &mut query.columns {
+        if let ResultColumn::Expr { expr, .. } = column {
+            visit_expr(expr, f);
+        }
+    }
+    if let Some(where_clause) = &mut query.where_clause {
+        visit_expr(where_clause, f);
+    }
+}
+
+/// Positions of the columns of the table that a query reads, None if it reads all of
+/// them or if a column can not be found
+fn used_columns(select_query: &SelectQuery, source_columns: &[String]) -> Option<Vec<usize>> {
+    let mut exprs = Vec::new();
+    for column in &select_query.columns {
+        match column {
+            ResultColumn::Star => return None,
+            ResultColumn::Expr { expr, .. } => exprs.push(expr),
+        }
+    }
+    exprs.extend(&select_query.where_clause);
+    exprs.extend(&select_query.group_by);
+    exprs.extend(select_query.order_by.iter().map(|term| &term.expr));
+
+    let mut used_columns = Vec::new();
+    while let Some(expr) = exprs.pop() {
+        match expr {
+            // the aliases of the result columns in ORDER BY are not columns of the table
+            Expr::Column(name) => used_columns.push(column_position(source_columns, name)?),
+            Expr::Exists(_) | Expr::Subquery(_) => return None,
+            expr => exprs.extend(expr.children()),
+        }
+    }
+    Some(used_columns)
+}
+
+pub(crate) fn contains_subquery(expr: &Expr) -> bool {
+    match expr {
+        Expr::Exists(_) | Expr::Subquery(_) => true,
+        expr => expr.children().into_iter().any(contains_subquery),
+    }
+}
+
+/// Replaces the sub selects of an expression by their results. A sub select is
+/// correlated when it references the outer table through a qualified column name,
+/// like `artists.ArtistId`. Correlated sub selects are only executed when the outer
+/// row is given, after its values have been substituted in the sub select.
+fn resolve_subqueries(
+    file: &mut DatabaseFile,
+    db_header: &DatabaseHeader,
+    schema_table: &SchemaTable,
+    expr: &Expr,
+    outer_tablename: &str,
+    outer_row: Option<(&[String], &[ColumnContent])>,
+) -> Result<Expr> {
+    let query = match expr {
+        Expr::Exists(query) | Expr::Subquery(query) => query,
+        expr => {
+            let mut expr = expr.clone();
+            for child in expr.children_mut() {
+                *child = resolve_subqueries(
+                    file,
+                    db_header,
+                    schema_table,
+                    child,
+                    outer_tablename,
+                    outer_row,
+                )?;
+            }
+            return Ok(expr);
+        }
+    };
+
+    let mut query = query.as_ref().clone();
+    let inner_tablename = query.from.name().to_string();
+    let mut correlated = false;
+    visit_columns(&mut query, &mut |column| {
+        let Expr::Column(name) = column else {
+            return;
+        };
+        let Some((tablename, column_name)) = name.split_once('.') else {
+            return;
+        };
+        // the inner table hides the outer one if they have the same name
+        if !tablename.eq_ignore_ascii_case(outer_tablename)
+            || tablename.eq_ignore_ascii_case(&inner_tablename)
+        {
+            return;
+        }
+        correlated = true;
+        if let Some((columns, row)) = outer_row {
+            if let Some(i) = column_position(columns, column_name) {
+                *column = Expr::Literal((&row[i]).into());
+            }
+        }
+    });
+    if correlated && outer_row.is_none() {
+        return Ok(expr.clone());
+    }
+
+    let result = run_select(file, db_header, schema_table, &query)?;
+    let literal = match expr {
+        Expr::Exists(_) => Literal::Integer(!result.rows.is_empty() as i64),
+        _ => {
+            if result.columns.len() != 1 {
+                return Err(Error::InvalidStatement(format!(
+                    "sub-select returns {} columns - expected 1",
+                    result.columns.len()
+                )));
+            }
+            match result.rows.first() {
+                Some(row) => (&row[0]).into(),
+                None => Literal::Null,
+            }
+        }
+    };
+    Ok(Expr::Literal(literal))
+}
+
+/// Splits rows into groups having the same values for the GROUP BY expressions.
+/// Like in sqlite, the groups are sorted by these values.
+fn group_rows(
+    rows: Vec<Vec<ColumnContent>>,
+    group_by: &[Expr],
+    columns: &[String],
+) -> Result<Vec<Vec<Vec<ColumnContent>>>> {
+    let mut keyed_rows = Vec::new();
+    for row in rows {
+        let key = group_by
+            .iter()
+            .map(|expr| evaluate(expr, columns, &row))
+            .collect::<Result<Vec<_>>>()?;
+        keyed_rows.push((key, row));
+    }
+    keyed_rows.sort_by(|(a, _), (b, _)| compare_keys(a, b));
+
+    let mut groups: Vec<(Vec<ColumnContent>, Vec<Vec<ColumnContent>>)> = Vec::new();
+    for (key, row) in keyed_rows {
+        match groups.last_mut() {
+            Some((group_key, group)) if compare_keys(group_key, &key) == Ordering::Equal => {
+                group.push(row)
+            }
+            _ => groups.push((key, vec![row])),
+        }
+    }
+    Ok(groups.into_iter().map(|(_, group)| group).collect())
+}
+
+/// Row waiting to be output, with its ORDER BY values. The greatest row is the one that
+/// comes last in the output.
+struct SortedRow<'a> {
+    key: Vec<ColumnContent>,
+    descending: &'a [bool],
+    /// Position of the row in the input, so that ties keep their input order
+    position: usize,
+    row: Vec<ColumnContent>,
+}
+
+impl Ord for SortedRow<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key
+            .iter()
+            .zip(&other.key)
+            .zip(self.descending)
+            .map(|((a, b), descending)| {
+                let ordering = sort_order(a, b);
+                if *descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            })
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+            .then(self.position.cmp(&other.position))
+    }
+}
+
+impl PartialOrd for SortedRow<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for SortedRow<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for SortedRow<'_> {}
+
+/// Sorts the output rows. With a LIMIT, only the first `limit + offset` rows are kept
+/// in a binary heap whose top is the last kept row, instead of sorting all the rows.
+struct TopK<'a> {
+    capacity: Option<usize>,
+    descending: &'a [bool],
+    heap: BinaryHeap<SortedRow<'a>>,
+    nb_pushed: usize,
+}
+
+impl<'a> TopK<'a> {
+    fn new(capacity: Option<usize>, descending: &'a [bool]) -> Self {
+        Self {
+            capacity,
+            descending,
+            heap: BinaryHeap::new(),
+            nb_pushed: 0,
+        }
+    }
+
+    fn push(&mut self, key: Vec<ColumnContent>, row: Vec<ColumnContent>) {
+        let sorted_row = SortedRow {
+            key,
+            descending: self.descending,
+            position: self.nb_pushed,
+            row,
+        };
+        self.nb_pushed += 1;
+        match self.capacity {
+            Some(capacity) if self.heap.len() >= capacity => {
+                if self.heap.peek().is_some_and(|last| sorted_row < *last) {
+                    self.heap.pop();
+                    self.heap.push(sorted_row);
+                }
+            }
+            _ => self.heap.push(sorted_row),
+        }
+    }
+
+    /// Checks if the rows pushed next can not be kept: without ordering, the first rows
+    /// are the ones kept
+    fn is_full(&self) -> bool {
+        self.descending.is_empty()
+            && self
+                .capacity
+                .is_some_and(|capacity| self.heap.len() >= capacity)
+    }
+
+    /// Sorted rows, without the first `offset` ones
+    fn into_rows(self, offset: usize) -> Vec<Vec<ColumnContent>> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .skip(offset)
+            .map(|sorted_row| sorted_row.row)
+            .collect()
+    }
+}
+
+/// Evaluates a LIMIT or OFFSET expression. Like in sqlite, a negative value means no limit
+fn evaluate_limit(expr: Option<&Expr>) -> Result<Option<usize>> {
+    match expr {
+        None => Ok(None),
+        Some(expr) => match evaluate(expr, &[], &[])? {
+            ColumnContent::Int(x) => Ok(usize::try_from(x).ok()),
+            _ => Err(Error::InvalidStatement("datatype mismatch".to_string())),
+        },
+    }
+}
+
+/// Names of the output columns of a select
+fn output_columns(select_query: &SelectQuery, source_columns: &[String]) -> Vec<String> {
+    let mut columns = Vec::new();
+    for column in &select_query.columns {
+        match column {
+            ResultColumn::Star => columns.extend(source_columns.iter().cloned()),
+            ResultColumn::Expr { name, .. } => columns.push(name.clone()),
+        }
+    }
+    columns
+}
+
+/// Position in the output columns of the column of the source which holds the rowid
+fn output_rowid_column(
+    select_query: &SelectQuery,
+    source_columns: &[String],
+    rowid_column: Option<usize>,
+) -> Option<usize> {
+    let rowid_column = rowid_column?;
+    let mut position = 0;
+    for column in &select_query.columns {
+        match column {
+            ResultColumn::Star => return Some(position + rowid_column),
+            ResultColumn::Expr {
+                expr: Expr::Column(name),
+                ..
+            } if column_position(source_columns, name) == Some(rowid_column) => {
+                return Some(position)
+            }
+            ResultColumn::Expr { .. } => position += 1,
+        }
+    }
+    None
+}
+
+/// English ordinal of a number, like 1st or 12th
+fn ordinal(n: usize) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+/// Computes the ORDER BY values of an output row. Like in sqlite, a term can be the
+/// number of an output column, an output column alias, or an expression of the select
+/// list. Other expressions are evaluated with `evaluate_source`, against the source row.
+fn order_key(
+    select_query: &SelectQuery,
+    source_columns: &[String],
+    output_row: &[ColumnContent],
+    evaluate_source: impl Fn(&Expr) -> Result<ColumnContent>,
+) -> Result<Vec<ColumnContent>> {
+    // position of each expression of the select list in the output row
+    let mut output_exprs = Vec::new();
+    let mut position = 0;
+    for column in &select_query.columns {
+        match column {
+            ResultColumn::Star => position += source_columns.len(),
+            ResultColumn::Expr { expr, name } => {
+                output_exprs.push((expr, name, position));
+                position += 1;
+            }
+        }
+    }
+
+    let mut key = Vec::new();
+    for (i, term) in select_query.order_by.iter().enumerate() {
+        let value = match &term.expr {
+            Expr::Literal(Literal::Integer(number)) => {
+                match usize::try_from(*number)
+                    .ok()
+                    .and_then(|number| output_row.get(number.wrapping_sub(1)))
+                {
+                    Some(value) => value.clone(),
+                    None => {
+                        return Err(Error::InvalidStatement(format!(
+                            "{} ORDER BY term out of range - should be between 1 and {}",
+                            ordinal(i + 1),
+                            output_row.len()
+                        )))
+                    }
+                }
+            }
+            expr => {
+                let output_position = output_exprs.iter().find_map(|(e, name, position)| {
+                    let is_alias =
+                        matches!(expr, Expr::Column(column) if column.eq_ignore_ascii_case(name));
+                    (*e == expr || is_alias).then_some(*position)
+                });
+                match output_position {
+                    Some(position) => output_row[position].clone(),
+                    None => evaluate_source(expr)?,
+                }
+            }
+        };
+        key.push(value);
+    }
+    Ok(key)
+}
+
+/// Evaluates the select list on a row that matches the where clause
+fn project_row(
+    select_query: &SelectQuery,
+    source_columns: &[String],
+    row: &[ColumnContent],
+) -> Result<Vec<ColumnContent>> {
+    let mut projected_row = Vec::new();
+    for column in &select_query.columns {
+        match column {
+            ResultColumn::Star => projected_row.extend(row.iter().cloned()),
+            ResultColumn::Expr { expr, .. } => {
+                projected_row.push(evaluate(expr, source_columns, row)?)
+            }
+        }
+    }
+    Ok(projected_row)
+}
+
+/// Prepares the where clause of a query for its execution: sub selects that do not
+/// depend on the current row are executed only once, and their results can be used to
+/// choose how the table is read
+fn prepare_select(
+    file: &mut DatabaseFile,
+    db_header: &DatabaseHeader,
+    schema_table: &SchemaTable,
+    select_query: &SelectQuery,
+) -> Result<SelectQuery> {
+    let where_clause = match &select_query.where_clause {
+        Some(where_clause) => Some(resolve_subqueries(
+            file,
+            db_header,
+            schema_table,
+            where_clause,
+            select_query.from.name(),
+            None,
+        )?),
+        None => None,
+    };
+    // literals compared to the columns of a table are converted to the column affinity
+    let where_clause = match (&select_query.from, where_clause) {
+        (
+            TableSource::Table {
+                name: tablename, ..
+            },
+            Some(where_clause),
+        ) if schema_table
+            .get_schema_record_for_table(tablename)
+            .is_some() =>
+        {
+            let create_table_query = get_create_table_query(schema_table, tablename)?;
+            Some(apply_affinities(
+                &where_clause,
+                &create_table_query.column_infos(),
+            ))
+        }
+        (_, where_clause) => where_clause,
+    };
+    Ok(SelectQuery {
+        where_clause,
+        ..select_query.clone()
+    })
+}
+
+/// Query of a view. Views selecting from another view are not supported.
+fn get_view_query(
+    schema_table: &SchemaTable,
+    view_record: &SchemaTableRecord,
+) -> Result<SelectQuery> {
+    let view_query = match parse_create_view_command(view_record.sql.as_deref().unwrap_or_default())
+    {
+        Ok((_, create_view_query)) => create_view_query.select_query,
+        Err(_) => {
+            return Err(Error::CorruptDatabase(format!(
+                "malformed database schema ({})",
+                view_record.name
+            )))
+        }
+    };
+    if let TableSource::Table {
+        name: view_tablename,
+        ..
+    } = &view_query.from
+    {
+        if schema_table
+            .get_schema_record_for_view(view_tablename)
+            .is_some()
+        {
+            return Err(Error::UnsupportedFeature(format!(
+                "Nested views are not supported: view {} selects from view {}",
+                view_record.name, view_tablename
+            )));
+        }
+    }
+    Ok(view_query)
+}
+
+/// Steps of EXPLAIN QUERY PLAN: how the rows of the table of a query are read, through
+/// the views and the sub selects of FROM. It is the plan run_select follows, so the
+/// sub selects of the where clause which do not depend on the current row are run.
+fn explain_steps(
+    file: &mut DatabaseFile,
+    db_header: &DatabaseHeader,
+    schema_table: &SchemaTable,
+    select_query: &SelectQuery,
+) -> Result<Vec<String>> {
+    let select_query = prepare_select(file, db_header, schema_table, select_query)?;
+    let mut steps = match &select_query.from {
+        TableSource::Subquery { query, .. } => explain_steps(file, db_header, schema_table, query)?,
+        TableSource::Table {
+            name: tablename, ..
+        } => match schema_table.get_schema_record_for_view(tablename) {
+            Some(view_record) => explain_steps(
+                file,
+                db_header,
+                schema_table,
+                &get_view_query(schema_table, &view_record)?,
+            )?,
+            None => {
+                let create_table_query = get_create_table_query(schema_table, tablename)?;
+                let plan = plan_table_read(
+                    schema_table,
+                    tablename,
+                    &create_table_query,
+                    select_query.where_clause.as_ref(),
+                    used_columns(&select_query, &create_table_query.column_names()).as_deref(),
+                );
+                vec![plan.explain(tablename)]
+            }
+        },
+    };
+    // the rows are grouped and sorted once they are read
+    if !select_query.group_by.is_empty() {
+        steps.push("USE TEMP B-TREE FOR GROUP BY".to_string());
+    }
+    if !select_query.order_by.is_empty() {
+        steps.push("USE TEMP B-TREE FOR ORDER BY".to_string());
+    }
+    Ok(steps)
+}
+
+/// Executes a SELECT against a table or a view, then applies the where clause, the
+/// column projection, the ordering and the limit.
+fn run_select(
+    file: &mut DatabaseFile,
+    db_header: &DatabaseHeader,
+    schema_table: &SchemaTable,
+    select_query: &SelectQuery,
+) -> Result<ResultSet> {
+    let select_query = &prepare_select(file, db_header, schema_table, select_query)?;
+
+    let limit = evaluate_limit(select_query.limit.as_ref())?;
+    let offset = evaluate_limit(select_query.offset.as_ref())?.unwrap_or(0);
+    let descending = select_query
+        .order_by
+        .iter()
+        .map(|term| term.descending)
+        .collect::<Vec<_>>();
+    let mut top_k = TopK::new(limit.map(|limit| limit + offset), &descending);
+
+    let is_grouped = is_grouped(select_query);
+
+    // the rows of a table are streamed when each row can be processed on its own
+    if let Some(tablename) = streamed_table(schema_table, select_query) {
+        let source_columns = get_table_columns(schema_table, tablename)?;
+        for_each_table_row(
+            file,
+            db_header,
+            schema_table,
+            tablename,
+            select_query.where_clause.as_ref(),
+            used_columns(select_query, &source_columns).as_deref(),
+            &mut |row| {
+                if let Some(where_clause) = &select_query.where_clause {
+                    if !is_true(&evaluate(where_clause, &source_columns, &row)?) {
+                        return Ok(ControlFlow::Continue(()));
+                    }
+                }
+                let projected_row = project_row(select_query, &source_columns, &row)?;
+                let key = order_key(select_query, &source_columns, &projected_row, |expr| {
+                    evaluate(expr, &source_columns, &row)
+                })?;
+                top_k.push(key, projected_row);
+                // the b trees are not read further once the rows of the limit are found
+                Ok(match top_k.is_full() {
+                    true => ControlFlow::Break(()),
+                    false => ControlFlow::Continue(()),
+                })
+            },
+        )?;
+        let rowid_column = get_create_table_query(schema_table, tablename)?.rowid_alias();
+        return Ok(ResultSet::new(
+            output_columns(select_query, &source_columns),
+            output_rowid_column(select_query, &source_columns, rowid_column),
+            top_k.into_rows(offset),
+        ));
+    }
+
+    let mut source = match &select_query.from {
+        TableSource::Subquery { query, .. } => run_select(file, db_header, schema_table, query)?,
+        TableSource::Table {
+            name: tablename, ..
+        } => match schema_table.get_schema_record_for_view(tablename) {
+            Some(view_record) => run_select(
+                file,
+                db_header,
+                schema_table,
+                &get_view_query(schema_table, &view_record)?,
+            )?,
+            None => get_table_rows(
+                file,
+                db_header,
+                schema_table,
+                tablename,
+                select_query.where_clause.as_ref(),
+                used_columns(select_query, &get_table_columns(schema_table, tablename)?).as_deref(),
+            )?,
+        },
+    };
+
+    let rows = std::mem::take(&mut source.rows)
+        .into_iter()
+        .map(Row::into_values)
+        .collect::<Vec<_>>();
+    let rows = match &select_query.where_clause {
+        None => rows,
+        Some(where_clause) => {
+            // The filter must be applied before aggregating, otherwise count(*) returns
+            // the total number of rows
+            let mut filtered_rows = Vec::new();
+            for row in rows {
+                let is_match = if contains_subquery(where_clause) {
+                    let where_clause = resolve_subqueries(
+                        file,
+                        db_header,
+                        schema_table,
+                        where_clause,
+                        select_query.from.name(),
+                        Some((&source.columns, &row)),
+                    )?;
+                    is_true(&evaluate(&where_clause, &source.columns, &row)?)
+                } else {
+                    is_true(&evaluate(where_clause, &source.columns, &row)?)
+                };
+                if is_match {
+                    filtered_rows.push(row);
+                }
+            }
+            filtered_rows
+        }
+    };
+
+    if is_grouped {
+        let groups = if select_query.group_by.is_empty() {
+            vec![rows]
+        } else {
+            group_rows(rows, &select_query.group_by, &source.columns)?
+        };
+
+        for group in groups {
+            let mut aggregated_row = Vec::new();
+            for column in &select_query.columns {
+                match column {
+                    ResultColumn::Star => match group.last() {
+                        Some(row) => aggregated_row.extend(row.iter().cloned()),
+                        None => aggregated_row
+                            .extend(source.columns.iter().map(|_| ColumnContent::Null)),
+                    },
+                    ResultColumn::Expr { expr, .. } => {
+                        aggregated_row.push(evaluate_aggregate(expr, &source.columns, &group)?)
+                    }
+                }
+            }
+            let key = order_key(select_query, &source.columns, &aggregated_row, |expr| {
+                evaluate_aggregate(expr, &source.columns, &group)
+            })?;
+            top_k.push(key, aggregated_row);
+        }
+    } else {
+        for row in rows {
+            let projected_row = project_row(select_query, &source.columns, &row)?;
+            let key = order_key(select_query, &source.columns, &projected_row, |expr| {
+                evaluate(expr, &source.columns, &row)
+            })?;
+            top_k.push(key, projected_row);
+        }
+    }
+
+    // the rowid is lost in the aggregated rows
+    let rowid_column = match is_grouped {
+        true => None,
+        false => output_rowid_column(select_query, &source.columns, source.rowid_column),
+    };
+    Ok(ResultSet::new(
+        output_columns(select_query, &source.columns),
+        rowid_column,
+        top_k.into_rows(offset),
+    ))
+}
+
+/// With an aggregate or a GROUP BY, each group of rows is merged into one row. Without
+/// GROUP BY, all the rows are in the same group.
+fn is_grouped(select_query: &SelectQuery) -> bool {
+    let is_aggregate = select_query.columns.iter().any(
+        |column| matches!(column, ResultColumn::Expr { expr, .. } if contains_aggregate(expr)),
+    );
+    is_aggregate || !select_query.group_by.is_empty()
+}
+
+/// Table whose rows can each be processed on their own as they are read, None when the
+/// query reads a view or a sub select, groups its rows, or has a where clause which
+/// depends on a sub select of the current row
+fn streamed_table<'a>(
+    schema_table: &SchemaTable,
+    select_query: &'a SelectQuery,
+) -> Option<&'a str> {
+    let TableSource::Table {
+        name: tablename, ..
+    } = &select_query.from
+    else {
+        return None;
+    };
+    let is_view = schema_table.get_schema_record_for_view(tablename).is_some();
+    let has_subquery = select_query
+        .where_clause
+        .as_ref()
+        .is_some_and(contains_subquery);
+    (!is_view && !is_grouped(select_query) && !has_subquery).then_some(tablename.as_str())
+}
+
+/// Prepares a SELECT whose rows are read one at a time by a SelectCursor: its sub
+/// selects are run and the plan of its table is chosen. None when its rows are not
+/// streamed, see streamed_table, or when they are sorted: all of them must be read first.
+pub(crate) fn plan_select(
+    db: &mut Database,
+    select_query: &SelectQuery,
+) -> Result<Option<(SelectQuery, Plan)>> {
+    let db = db.database_of(&select_query.from)?;
+    let select_query = prepare_select(&mut db.file, &db.header, &db.schema_table, select_query)?;
+    if !select_query.order_by.is_empty() {
+        return Ok(None);
+    }
+    let Some(tablename) = streamed_table(&db.schema_table, &select_query) else {
+        return Ok(None);
+    };
+    let create_table_query = get_create_table_query(&db.schema_table, tablename)?;
+    let plan = plan_table_read(
+        &db.schema_table,
+        tablename,
+        &create_table_query,
+        select_query.where_clause.as_ref(),
+        used_columns(&select_query, &create_table_query.column_names()).as_deref(),
+    );
+    Ok(Some((select_query, plan)))
+}
+
+/// The rows of a SELECT prepared by plan_select, read from its table one at a time: each
+/// one is filtered and projected when it is read, and the table is not read further than
+/// the row asked for. Only the records of an index are read when the cursor is opened.
+pub(crate) struct SelectCursor {
+    select_query: SelectQuery,
+    source_columns: Vec<String>,
+    columns: Arc<[String]>,
+    rowid_column: Option<usize>,
+    rows: TableRows,
+    /// Matching rows still to skip
+    offset: usize,
+    /// Rows still to return, None without LIMIT
+    limit: Option<usize>,
+}
+
+impl SelectCursor {
+    pub(crate) fn open(
+        db: &mut Database,
+        select_query: &SelectQuery,
+        plan: &Plan,
+    ) -> Result<SelectCursor> {
+        let db = db.database_of(&select_query.from)?;
+        let create_table_query =
+            get_create_table_query(&db.schema_table, select_query.from.name())?;
+        let source_columns = create_table_query.column_names();
+        let rows = TableRows::open(
+            &mut db.file,
+            db.header.file_format(),
+            &db.schema_table,
+            &create_table_query,
+            plan,
+            used_columns(select_query, &source_columns).as_deref(),
+        )?;
+        Ok(SelectCursor {
+            columns: output_columns(select_query, &source_columns).into(),
+            rowid_column: output_rowid_column(
+                select_query,
+                &source_columns,
+                create_table_query.rowid_alias(),
+            ),
+            offset: evaluate_limit(select_query.offset.as_ref())?.unwrap_or(0),
+            limit: evaluate_limit(select_query.limit.as_ref())?,
+            select_query: select_query.clone(),
+            source_columns,
+            rows,
+        })
+    }
+
+    /// Reads the next row of the SELECT, None after the last one
+    pub(crate) fn next(&mut self, db: &mut Database) -> Result<Option<Row>> {
+        let file = &mut db.database_of(&self.select_query.from)?.file;
+        while self.limit != Some(0) {
+            let Some(row) = self.rows.next(file)? else {
+                break;
+            };
+            if let Some(where_clause) = &self.select_query.where_clause {
+                if !is_true(&evaluate(where_clause, &self.source_columns, &row)?) {
+                    continue;
+                }
+            }
+            let projected_row = project_row(&self.select_query, &self.source_columns, &row)?;
+            if self.offset > 0 {
+                self.offset -= 1;
+                continue;
+            }
+            self.limit = self.limit.map(|limit| limit - 1);
+            return Ok(Some(Row::new(
+                self.columns.clone(),
+                self.rowid_column,
+                projected_row,
+            )));
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sql_parser::{parse_sql, Statement};
+
+    fn select(sql: &str) -> SelectQuery {
+        match parse_sql(sql).unwrap() {
+            Statement::Select(select_query) | Statement::ExplainQueryPlan(select_query) => {
+                select_query
+            }
+        }
+    }
+
+    #[test]
+    fn selects_are_run_without_the_sql() {
+        let mut db = Database::open("sample.db").unwrap();
+        let result_set = execute_select(
+            &mut db,
+            &select("SELECT name FROM apples WHERE id > 2 ORDER BY name DESC LIMIT 1"),
+        )
+        .unwrap();
+        assert_eq!(result_set.columns, vec!["name"]);
+        let rows: Vec<_> = result_set.rows.iter().map(|row| row.values()).collect();
+        assert_eq!(
+            rows,
+            vec![[ColumnContent::String("Honeycrisp".to_string())]]
+        );
+
+        let steps = explain_select(&mut db, &select("SELECT * FROM apples WHERE id = 1")).unwrap();
+        assert!(steps.is_explain);
+        assert_eq!(steps.columns, vec!["detail"]);
+        assert_eq!(
+            steps.rows[0].values(),
+            [ColumnContent::String(
+                "SEARCH apples USING INTEGER PRIMARY KEY (rowid=?)".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn ordinals() {
+        let ordinals: Vec<_> = [1, 2, 3, 4, 11, 12, 13, 21, 22, 101, 111]
+            .into_iter()
+            .map(ordinal)
+            .collect();
+        assert_eq!(
+            ordinals,
+            vec![
+                "1st", "2nd", "3rd", "4th", "11th", "12th", "13th", "21st", "22nd", "101st",
+                "111th"
+            ]
+        );
+    }
+}
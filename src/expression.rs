@@ -0,0 +1,888 @@
+use std::{cell::RefCell, cmp::Ordering, collections::HashMap};
+
+use crate::error::{Error, Result};
+use regex::Regex;
+
+use crate::{
+    page::ColumnContent,
+    sql_parser::{
+        Affinity, ArithmeticOperator, ColumnInfo, ComparisonOperator, Expr, Literal,
+        LogicalOperator,
+    },
+    value::sort_order,
+};
+
+impl From<&Literal> for ColumnContent {
+    fn from(literal: &Literal) -> Self {
+        match literal {
+            Literal::Null => ColumnContent::Null,
+            Literal::Integer(x) => ColumnContent::Int(*x),
+            Literal::Real(x) => ColumnContent::Float(*x),
+            Literal::Text(x) => ColumnContent::String(x.to_string()),
+            Literal::Blob(x) => ColumnContent::Blob(x.clone()),
+        }
+    }
+}
+
+impl From<&ColumnContent> for Literal {
+    fn from(content: &ColumnContent) -> Self {
+        match content {
+            ColumnContent::Null => Literal::Null,
+            ColumnContent::Int(x) => Literal::Integer(*x),
+            ColumnContent::Float(x) => Literal::Real(*x),
+            ColumnContent::String(x) => Literal::Text(x.to_string()),
+            ColumnContent::Blob(x) => Literal::Blob(x.clone()),
+        }
+    }
+}
+
+/// Finds the position of a column in a row. Only one table is in scope, so a table
+/// qualifier in front of the column name is ignored
+pub fn column_position(columns: &[String], name: &str) -> Option<usize> {
+    let position = |name: &str| {
+        columns
+            .iter()
+            .position(|c| c.to_lowercase() == name.to_lowercase())
+    };
+    position(name).or_else(|| {
+        name.split_once('.')
+            .and_then(|(_, column_name)| position(column_name))
+    })
+}
+
+/// Evaluates an expression against a row. The names of the columns of the row are
+/// used to resolve the column references.
+pub fn evaluate(expr: &Expr, columns: &[String], row: &[ColumnContent]) -> Result<ColumnContent> {
+    match expr {
+        Expr::Literal(literal) => Ok(literal.into()),
+        Expr::Parameter(parameter) => Err(Error::InvalidParameters(format!(
+            "Unbound parameter {:?}",
+            parameter
+        ))),
+        Expr::Column(name) => match column_position(columns, name) {
+            Some(i) => Ok(row[i].clone()),
+            None => Err(Error::NoSuchColumn(name.to_string())),
+        },
+        // the executor replaces the sub selects by their results before evaluation
+        Expr::Exists(_) | Expr::Subquery(_) => Err(Error::UnsupportedFeature(
+            "Sub selects are only supported in the where clause".to_string(),
+        )),
+        // iif only evaluates the branch that is returned
+        Expr::Function { name, args } if name == "iif" => {
+            if args.len() != 3 {
+                return Err(Error::InvalidStatement(
+                    "wrong number of arguments to function iif()".to_string(),
+                ));
+            }
+            if is_true(&evaluate(&args[0], columns, row)?) {
+                evaluate(&args[1], columns, row)
+            } else {
+                evaluate(&args[2], columns, row)
+            }
+        }
+        Expr::Function { name, args } => {
+            let args = args
+                .iter()
+                .map(|arg| evaluate(arg, columns, row))
+                .collect::<Result<Vec<_>>>()?;
+            call_scalar_function(name, args)
+        }
+        Expr::Negate(operand) => Ok(negate(&evaluate(operand, columns, row)?)),
+        Expr::Arithmetic {
+            left,
+            operator,
+            right,
+        } => {
+            let left = evaluate(left, columns, row)?;
+            let right = evaluate(right, columns, row)?;
+            Ok(arithmetic(*operator, &left, &right))
+        }
+        Expr::Comparison {
+            left,
+            operator,
+            right,
+        } => {
+            let left_value = evaluate(left, columns, row)?;
+            let right_value = evaluate(right, columns, row)?;
+            Ok(match compare(&left_value, &right_value) {
+                Some(ordering) => ColumnContent::Int(operator.matches(ordering) as i64),
+                None => ColumnContent::Null,
+            })
+        }
+        Expr::Logical {
+            left,
+            operator,
+            right,
+        } => {
+            // the right side is not evaluated when the left one decides the result
+            let truth = |content: &ColumnContent| match content {
+                ColumnContent::Null => None,
+                content => Some(is_true(content)),
+            };
+            let decisive = *operator == LogicalOperator::Or;
+            let left = truth(&evaluate(left, columns, row)?);
+            if left == Some(decisive) {
+                return Ok(ColumnContent::Int(decisive as i64));
+            }
+            let right = truth(&evaluate(right, columns, row)?);
+            Ok(match (left, right) {
+                (_, Some(right)) if right == decisive => ColumnContent::Int(decisive as i64),
+                (Some(_), Some(_)) => ColumnContent::Int(!decisive as i64),
+                _ => ColumnContent::Null,
+            })
+        }
+        Expr::Regexp { left, pattern } => {
+            let left = evaluate(left, columns, row)?;
+            let pattern = evaluate(pattern, columns, row)?;
+            match (left, pattern) {
+                (ColumnContent::String(text), ColumnContent::String(pattern)) => {
+                    Ok(ColumnContent::Int(regex_matches(&pattern, &text)? as i64))
+                }
+                _ => Ok(ColumnContent::Int(0)),
+            }
+        }
+        Expr::Case {
+            operand,
+            when_then,
+            else_expr,
+        } => {
+            let operand = match operand {
+                Some(operand) => Some(evaluate(operand, columns, row)?),
+                None => None,
+            };
+            // the branches are evaluated in order and the first match wins
+            for (when, then) in when_then {
+                let when = evaluate(when, columns, row)?;
+                let matched = match &operand {
+                    Some(operand) => compare(operand, &when)
+                        .is_some_and(|ordering| ComparisonOperator::Eq.matches(ordering)),
+                    None => is_true(&when),
+                };
+                if matched {
+                    return evaluate(then, columns, row);
+                }
+            }
+            match else_expr {
+                Some(else_expr) => evaluate(else_expr, columns, row),
+                None => Ok(ColumnContent::Null),
+            }
+        }
+    }
+}
+
+/// Parses a text that is entirely a number, with optional surrounding spaces
+fn parse_numeric_text(text: &str) -> Option<ColumnContent> {
+    let text = text.trim();
+    let is_number_char = |c: char| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '+' | '-');
+    if !text.chars().any(|c| c.is_ascii_digit()) || !text.chars().all(is_number_char) {
+        return None;
+    }
+    match text.parse::<i64>() {
+        Ok(x) => Some(ColumnContent::Int(x)),
+        Err(_) => text.parse::<f64>().ok().map(ColumnContent::Float),
+    }
+}
+
+/// Converts a value the way sqlite does before comparing it to a column with the given
+/// affinity: numeric affinities convert the texts that look like numbers, and the text
+/// affinity converts the numbers to texts
+pub fn apply_affinity(content: &ColumnContent, affinity: Affinity) -> ColumnContent {
+    match (affinity, content) {
+        (Affinity::Integer | Affinity::Real | Affinity::Numeric, ColumnContent::String(text)) => {
+            parse_numeric_text(text).unwrap_or_else(|| content.clone())
+        }
+        (Affinity::Text, ColumnContent::Int(_) | ColumnContent::Float(_)) => {
+            ColumnContent::String(content.repr())
+        }
+        _ => content.clone(),
+    }
+}
+
+/// Converts the literals compared to a column according to the affinity of the column.
+/// The conversion is done once, before the rows are evaluated.
+pub fn apply_affinities(expr: &Expr, columns: &[ColumnInfo]) -> Expr {
+    let names = columns.iter().map(|c| c.name.clone()).collect::<Vec<_>>();
+    let affinity_of = |expr: &Expr| match expr {
+        Expr::Column(name) => column_position(&names, name).map(|i| columns[i].affinity),
+        _ => None,
+    };
+    let convert = |literal: &Literal, affinity: Affinity| {
+        Box::new(Expr::Literal(
+            (&apply_affinity(&literal.into(), affinity)).into(),
+        ))
+    };
+
+    if let Expr::Comparison {
+        left,
+        operator,
+        right,
+    } = expr
+    {
+        let converted = match (left.as_ref(), right.as_ref()) {
+            (column, Expr::Literal(literal)) => {
+                affinity_of(column).map(|affinity| (left.clone(), convert(literal, affinity)))
+            }
+            (Expr::Literal(literal), column) => {
+                affinity_of(column).map(|affinity| (convert(literal, affinity), right.clone()))
+            }
+            _ => None,
+        };
+        if let Some((left, right)) = converted {
+            return Expr::Comparison {
+                left,
+                operator: *operator,
+                right,
+            };
+        }
+    }
+
+    let mut expr = expr.clone();
+    for child in expr.children_mut() {
+        *child = apply_affinities(child, columns);
+    }
+    expr
+}
+
+/// Checks if a function call is an aggregate. min and max are scalar functions when
+/// they have several arguments
+fn is_aggregate_call(name: &str, args: &[Expr]) -> bool {
+    match name {
+        "count" => args.len() <= 1,
+        "min" | "max" | "sum" | "avg" | "total" => args.len() == 1,
+        _ => false,
+    }
+}
+
+/// Checks if an expression contains an aggregate function, outside of sub selects
+pub fn contains_aggregate(expr: &Expr) -> bool {
+    match expr {
+        Expr::Function { name, args } if is_aggregate_call(name, args) => true,
+        expr => expr.children().into_iter().any(contains_aggregate),
+    }
+}
+
+/// Evaluates an expression over a group of rows. Aggregate functions are computed
+/// over all the rows, the rest of the expression is evaluated against the last row,
+/// or against a row of NULLs if there are no rows.
+pub fn evaluate_aggregate(
+    expr: &Expr,
+    columns: &[String],
+    rows: &[Vec<ColumnContent>],
+) -> Result<ColumnContent> {
+    let expr = replace_aggregates(expr, columns, rows)?;
+    match rows.last() {
+        Some(row) => evaluate(&expr, columns, row),
+        None => evaluate(&expr, columns, &vec![ColumnContent::Null; columns.len()]),
+    }
+}
+
+/// Replaces the aggregate function calls of an expression by their values
+fn replace_aggregates(
+    expr: &Expr,
+    columns: &[String],
+    rows: &[Vec<ColumnContent>],
+) -> Result<Expr> {
+    if let Expr::Function { name, args } = expr {
+        if is_aggregate_call(name, args) {
+            let values = match args.first() {
+                // count(*) counts all the rows
+                None => vec![ColumnContent::Int(1); rows.len()],
+                Some(arg) => rows
+                    .iter()
+                    .map(|row| evaluate(arg, columns, row))
+                    .collect::<Result<Vec<_>>>()?,
+            };
+            let value = call_aggregate_function(name, values)?;
+            return Ok(Expr::Literal((&value).into()));
+        }
+    }
+    let mut expr = expr.clone();
+    for child in expr.children_mut() {
+        *child = replace_aggregates(child, columns, rows)?;
+    }
+    Ok(expr)
+}
+
+/// Computes an aggregate over the values of its argument. Like in sqlite, NULL values
+/// are ignored
+fn call_aggregate_function(name: &str, values: Vec<ColumnContent>) -> Result<ColumnContent> {
+    let values = values
+        .into_iter()
+        .filter(|value| *value != ColumnContent::Null)
+        .collect::<Vec<_>>();
+    let best = |wanted: Ordering| {
+        values.iter().fold(ColumnContent::Null, |best, value| {
+            if best == ColumnContent::Null || compare(value, &best) == Some(wanted) {
+                value.clone()
+            } else {
+                best
+            }
+        })
+    };
+    Ok(match name {
+        "count" => ColumnContent::Int(values.len() as i64),
+        "min" => best(Ordering::Less),
+        "max" => best(Ordering::Greater),
+        "sum" | "avg" | "total" => {
+            let numbers = values.iter().map(as_number).collect::<Vec<_>>();
+            let is_integer = numbers.iter().all(|n| matches!(n, ColumnContent::Int(_)));
+            let real_sum = numbers.iter().map(as_real).sum::<f64>();
+            match name {
+                "total" => ColumnContent::Float(real_sum),
+                _ if numbers.is_empty() => ColumnContent::Null,
+                "avg" => ColumnContent::Float(real_sum / numbers.len() as f64),
+                _ if is_integer => {
+                    let mut sum = 0i64;
+                    for number in &numbers {
+                        sum = match sum.checked_add(as_integer(number)) {
+                            Some(sum) => sum,
+                            None => return Err(Error::Runtime("integer overflow".to_string())),
+                        };
+                    }
+                    ColumnContent::Int(sum)
+                }
+                _ => ColumnContent::Float(real_sum),
+            }
+        }
+        _ => {
+            return Err(Error::InvalidStatement(format!(
+                "no such function: {}",
+                name
+            )))
+        }
+    })
+}
+
+/// Patterns kept compiled by each thread. A query only uses a few of them, unless they
+/// come from its rows: the cache is then emptied once full, so it does not grow forever.
+const REGEX_CACHE_SIZE: usize = 64;
+
+thread_local! {
+    /// Compiled REGEXP patterns, so that they are not compiled again for each row
+    static REGEX_CACHE: RefCell<HashMap<String, Regex>> = RefCell::new(HashMap::new());
+}
+
+fn regex_matches(pattern: &str, text: &str) -> Result<bool> {
+    REGEX_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if !cache.contains_key(pattern) {
+            let regex = match Regex::new(pattern) {
+                Ok(regex) => regex,
+                Err(err) => {
+                    return Err(Error::Runtime(format!(
+                        "Invalid regular expression {}: {}",
+                        pattern, err
+                    )))
+                }
+            };
+            if cache.len() >= REGEX_CACHE_SIZE {
+                cache.clear();
+            }
+            cache.insert(pattern.to_string(), regex);
+        }
+        Ok(cache[pattern].is_match(text))
+    })
+}
+
+/// Compares two values with the comparison operators of SQL: NULL is never comparable,
+/// the other values are in the order of Value
+pub fn compare(left: &ColumnContent, right: &ColumnContent) -> Option<Ordering> {
+    match (left, right) {
+        (ColumnContent::Null, _) | (_, ColumnContent::Null) => None,
+        (left, right) => Some(sort_order(left, right)),
+    }
+}
+
+/// Numeric value of an operand of an arithmetic operator. Texts and blobs are
+/// converted through their numeric prefix, NULL stays NULL
+fn as_number(content: &ColumnContent) -> ColumnContent {
+    match content {
+        ColumnContent::Null | ColumnContent::Int(_) | ColumnContent::Float(_) => content.clone(),
+        content => {
+            let text = as_text(content).unwrap_or_default();
+            let number = numeric_prefix(&text);
+            match number.parse::<i64>() {
+                Ok(x) => ColumnContent::Int(x),
+                Err(_) if number.is_empty() => ColumnContent::Int(0),
+                Err(_) => ColumnContent::Float(number.parse::<f64>().unwrap_or_default()),
+            }
+        }
+    }
+}
+
+/// The unary minus, on the numeric value of its operand like the binary operators.
+/// -i64::MIN does not fit in an integer, it is a real.
+fn negate(content: &ColumnContent) -> ColumnContent {
+    match as_number(content) {
+        ColumnContent::Int(x) => match x.checked_neg() {
+            Some(x) => ColumnContent::Int(x),
+            None => ColumnContent::Float(-(x as f64)),
+        },
+        ColumnContent::Float(x) => ColumnContent::Float(-x),
+        content => content,
+    }
+}
+
+/// Applies an arithmetic operator. Integer operations that overflow are done on reals,
+/// and a division or modulo by zero gives NULL
+fn arithmetic(
+    operator: ArithmeticOperator,
+    left: &ColumnContent,
+    right: &ColumnContent,
+) -> ColumnContent {
+    match (as_number(left), as_number(right)) {
+        (ColumnContent::Null, _) | (_, ColumnContent::Null) => ColumnContent::Null,
+        (ColumnContent::Int(x), ColumnContent::Int(y)) => {
+            let result = match operator {
+                ArithmeticOperator::Add => x.checked_add(y),
+                ArithmeticOperator::Subtract => x.checked_sub(y),
+                ArithmeticOperator::Multiply => x.checked_mul(y),
+                ArithmeticOperator::Divide | ArithmeticOperator::Modulo if y == 0 => {
+                    return ColumnContent::Null
+                }
+                ArithmeticOperator::Divide => x.checked_div(y),
+                // only i64::MIN % -1 overflows, and the remainder is 0
+                ArithmeticOperator::Modulo => Some(x.checked_rem(y).unwrap_or(0)),
+            };
+            match result {
+                Some(result) => ColumnContent::Int(result),
+                None => arithmetic(
+                    operator,
+                    &ColumnContent::Float(x as f64),
+                    &ColumnContent::Float(y as f64),
+                ),
+            }
+        }
+        (left, right) => {
+            let (x, y) = (as_real(&left), as_real(&right));
+            match operator {
+                ArithmeticOperator::Add => ColumnContent::Float(x + y),
+                ArithmeticOperator::Subtract => ColumnContent::Float(x - y),
+                ArithmeticOperator::Multiply => ColumnContent::Float(x * y),
+                ArithmeticOperator::Divide if y == 0.0 => ColumnContent::Null,
+                ArithmeticOperator::Divide => ColumnContent::Float(x / y),
+                // the modulo of reals is computed on their integer parts
+                ArithmeticOperator::Modulo => {
+                    let (x, y) = (x as i64, y as i64);
+                    if y == 0 {
+                        ColumnContent::Null
+                    } else {
+                        ColumnContent::Float(x.checked_rem(y).unwrap_or(0) as f64)
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Truth value of a condition: NULL and zero are false, texts are converted to numbers
+pub fn is_true(content: &ColumnContent) -> bool {
+    match content {
+        ColumnContent::Null => false,
+        ColumnContent::Int(x) => *x != 0,
+        ColumnContent::Float(x) => *x != 0.0,
+        content => as_real(content) != 0.0,
+    }
+}
+
+/// Text value of a column, as used by the string functions. NULL has no text value
+fn as_text(content: &ColumnContent) -> Option<String> {
+    match content {
+        ColumnContent::Null => None,
+        ColumnContent::Blob(x) => Some(String::from_utf8_lossy(x).to_string()),
+        content => Some(content.repr()),
+    }
+}
+
+/// Numeric prefix of a text, like "12.5" in "12.5kg". Empty if there is no number
+fn numeric_prefix(text: &str) -> &str {
+    let text = text.trim_start();
+    let mut end = 0;
+    let mut seen_digit = false;
+    let mut seen_dot = false;
+    for (i, c) in text.char_indices() {
+        match c {
+            '+' | '-' if i == 0 => {}
+            '0'..='9' => seen_digit = true,
+            '.' if !seen_dot => seen_dot = true,
+            _ => break,
+        }
+        end = i + c.len_utf8();
+    }
+    if seen_digit {
+        &text[..end]
+    } else {
+        ""
+    }
+}
+
+/// Integer value of a column, as used by the numeric functions. NULL and
+/// non numeric texts are 0
+fn as_integer(content: &ColumnContent) -> i64 {
+    match content {
+        ColumnContent::Int(x) => *x,
+        ColumnContent::Float(x) => *x as i64,
+        content => {
+            let text = as_text(content).unwrap_or_default();
+            let number = numeric_prefix(&text);
+            number
+                .parse::<i64>()
+                .unwrap_or_else(|_| number.parse::<f64>().unwrap_or_default() as i64)
+        }
+    }
+}
+
+/// Real value of a column, as used by the numeric functions. NULL and
+/// non numeric texts are 0
+fn as_real(content: &ColumnContent) -> f64 {
+    match content {
+        ColumnContent::Int(x) => *x as f64,
+        ColumnContent::Float(x) => *x,
+        content => {
+            let text = as_text(content).unwrap_or_default();
+            numeric_prefix(&text).parse::<f64>().unwrap_or_default()
+        }
+    }
+}
+
+/// Subset of the sqlite printf: %s, %d, %i, %f, %x and %%, with the -, +, space and 0
+/// flags, a width and a precision. Missing arguments are treated as NULL, which gives
+/// 0 for numeric conversions and an empty string for %s.
+fn printf(format: &str, args: &[ColumnContent]) -> String {
+    let mut result = String::new();
+    let mut args = args.iter();
+    let mut chars = format.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            result.push(c);
+            continue;
+        }
+
+        let mut left_align = false;
+        let mut zero_pad = false;
+        let mut sign = "";
+        while let Some(&flag) = chars.peek() {
+            match flag {
+                '-' => left_align = true,
+                '0' => zero_pad = true,
+                '+' => sign = "+",
+                ' ' if sign.is_empty() => sign = " ",
+                ' ' => {}
+                _ => break,
+            }
+            chars.next();
+        }
+
+        let mut width = 0;
+        while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+            width = width * 10 + digit as usize;
+            chars.next();
+        }
+
+        let mut precision = None;
+        if chars.peek() == Some(&'.') {
+            chars.next();
+            let mut p = 0;
+            while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+                p = p * 10 + digit as usize;
+                chars.next();
+            }
+            precision = Some(p);
+        }
+
+        // %% takes no argument
+        let mut next_arg = || args.next().unwrap_or(&ColumnContent::Null);
+        // the numeric conversions return their sign separately so that zero padding
+        // can be inserted after it
+        let (prefix, body, numeric) = match chars.next() {
+            Some('%') => {
+                result.push('%');
+                continue;
+            }
+            Some('d') | Some('i') => {
+                let x = as_integer(next_arg());
+                let prefix = if x < 0 { "-" } else { sign };
+                let mut digits = x.unsigned_abs().to_string();
+                if let Some(precision) = precision {
+                    digits = format!("{:0>precision$}", digits);
+                }
+                (prefix, digits, true)
+            }
+            Some('f') => {
+                let x = as_real(next_arg());
+                let precision = precision.unwrap_or(6);
+                let prefix = if x.is_sign_negative() && x != 0.0 {
+                    "-"
+                } else {
+                    sign
+                };
+                (
+                    prefix,
+                    format!("{:.*}", precision, round_half_away(x.abs(), precision)),
+                    true,
+                )
+            }
+            Some('x') => {
+                let mut digits = format!("{:x}", as_integer(next_arg()));
+                if let Some(precision) = precision {
+                    digits = format!("{:0>precision$}", digits);
+                }
+                ("", digits, true)
+            }
+            Some('s') => {
+                let text = as_text(next_arg()).unwrap_or_default();
+                let text = match precision {
+                    Some(precision) => text.chars().take(precision).collect(),
+                    None => text,
+                };
+                ("", text, false)
+            }
+            // unknown conversions are kept as is
+            Some(other) => ("", format!("%{}", other), false),
+            None => ("", "%".to_string(), false),
+        };
+
+        let len = prefix.chars().count() + body.chars().count();
+        let padding = width.saturating_sub(len);
+        if left_align {
+            result.push_str(prefix);
+            result.push_str(&body);
+            result.push_str(&" ".repeat(padding));
+        } else if zero_pad && numeric {
+            result.push_str(prefix);
+            result.push_str(&"0".repeat(padding));
+            result.push_str(&body);
+        } else {
+            result.push_str(&" ".repeat(padding));
+            result.push_str(prefix);
+            result.push_str(&body);
+        }
+    }
+    result
+}
+
+/// Rounds to `precision` decimals, the halves away from zero like sqlite does: the
+/// formatting of Rust rounds them to the even digit. The values too large to be scaled
+/// are left to the formatting.
+fn round_half_away(x: f64, precision: usize) -> f64 {
+    let scale = 10f64.powi(precision.min(308) as i32);
+    let scaled = x * scale;
+    if scaled.is_finite() && scaled.abs() < (1u64 << 53) as f64 {
+        scaled.round() / scale
+    } else {
+        x
+    }
+}
+
+fn json_path_error(near: &str) -> Error {
+    Error::Runtime(format!("JSON path error near '{}'", near))
+}
+
+/// Follows a json path like `$.user.tags[0]`. Returns None if the path does not exist
+fn json_path_lookup<'a>(
+    json: &'a serde_json::Value,
+    path: &str,
+) -> Result<Option<&'a serde_json::Value>> {
+    let Some(mut rest) = path.strip_prefix('$') else {
+        return Err(json_path_error(path));
+    };
+    let mut current = json;
+    while !rest.is_empty() {
+        let next = if let Some(after_dot) = rest.strip_prefix('.') {
+            // keys end at the next step, unless they are quoted
+            let (key, after_key) = match after_dot.strip_prefix('"') {
+                Some(quoted) => match quoted.split_once('"') {
+                    Some(split) => split,
+                    None => return Err(json_path_error(rest)),
+                },
+                None => {
+                    let end = after_dot.find(['.', '[']).unwrap_or(after_dot.len());
+                    after_dot.split_at(end)
+                }
+            };
+            if key.is_empty() {
+                return Err(json_path_error(rest));
+            }
+            rest = after_key;
+            current.get(key)
+        } else if let Some(after_bracket) = rest.strip_prefix('[') {
+            let index = match after_bracket.split_once(']') {
+                Some((index, after_index)) if !index.is_empty() => {
+                    rest = after_index;
+                    match index.parse::<usize>() {
+                        Ok(index) => index,
+                        Err(_) => return Err(json_path_error(index)),
+                    }
+                }
+                _ => return Err(json_path_error(rest)),
+            };
+            current.get(index)
+        } else {
+            return Err(json_path_error(rest));
+        };
+
+        match next {
+            Some(next) => current = next,
+            None => return Ok(None),
+        }
+    }
+    Ok(Some(current))
+}
+
+/// Converts an extracted json value to the value sqlite returns: scalars are converted
+/// to sql values, objects and arrays are returned as minified json text
+fn json_to_content(json: &serde_json::Value) -> ColumnContent {
+    match json {
+        serde_json::Value::Null => ColumnContent::Null,
+        serde_json::Value::Bool(x) => ColumnContent::Int(*x as i64),
+        serde_json::Value::Number(x) => match x.as_i64() {
+            Some(x) => ColumnContent::Int(x),
+            None => ColumnContent::Float(x.as_f64().unwrap_or_default()),
+        },
+        serde_json::Value::String(x) => ColumnContent::String(x.clone()),
+        json => ColumnContent::String(json.to_string()),
+    }
+}
+
+fn check_nb_args(name: &str, args: &[ColumnContent], allowed: &[usize]) -> Result<()> {
+    if !allowed.contains(&args.len()) {
+        return Err(Error::InvalidStatement(format!(
+            "wrong number of arguments to function {}()",
+            name
+        )));
+    }
+    Ok(())
+}
+
+/// Removes the characters of `chars` at the start and/or the end of `text`
+fn trim_chars(text: &str, chars: &str, start: bool, end: bool) -> String {
+    let is_trimmed = |c: char| chars.contains(c);
+    let mut text = text;
+    if start {
+        text = text.trim_start_matches(is_trimmed);
+    }
+    if end {
+        text = text.trim_end_matches(is_trimmed);
+    }
+    text.to_string()
+}
+
+/// Dispatches a call to a scalar function. Function names are already lowercase.
+/// Like in sqlite, most functions return NULL as soon as one of their arguments is NULL.
+fn call_scalar_function(name: &str, args: Vec<ColumnContent>) -> Result<ColumnContent> {
+    match name {
+        "instr" => {
+            check_nb_args(name, &args, &[2])?;
+            match (&args[0], &args[1]) {
+                (ColumnContent::Null, _) | (_, ColumnContent::Null) => Ok(ColumnContent::Null),
+                // blobs are searched byte by byte
+                (ColumnContent::Blob(haystack), ColumnContent::Blob(needle)) => {
+                    let position = if needle.is_empty() {
+                        Some(0)
+                    } else {
+                        haystack.windows(needle.len()).position(|w| w == needle)
+                    };
+                    Ok(ColumnContent::Int(position.map_or(0, |p| p as i64 + 1)))
+                }
+                (haystack, needle) => {
+                    let haystack = as_text(haystack).unwrap_or_default();
+                    let needle = as_text(needle).unwrap_or_default();
+                    // positions are in characters and start at 1
+                    let position = haystack
+                        .find(&needle)
+                        .map_or(0, |p| haystack[..p].chars().count() as i64 + 1);
+                    Ok(ColumnContent::Int(position))
+                }
+            }
+        }
+        "replace" => {
+            check_nb_args(name, &args, &[3])?;
+            match (as_text(&args[0]), as_text(&args[1]), as_text(&args[2])) {
+                (Some(text), Some(pattern), Some(replacement)) => {
+                    if pattern.is_empty() {
+                        Ok(ColumnContent::String(text))
+                    } else {
+                        Ok(ColumnContent::String(text.replace(&pattern, &replacement)))
+                    }
+                }
+                _ => Ok(ColumnContent::Null),
+            }
+        }
+        "trim" | "ltrim" | "rtrim" => {
+            check_nb_args(name, &args, &[1, 2])?;
+            let chars = match args.get(1) {
+                // only spaces are removed by default
+                None => Some(" ".to_string()),
+                Some(chars) => as_text(chars),
+            };
+            match (as_text(&args[0]), chars) {
+                (Some(text), Some(chars)) => Ok(ColumnContent::String(trim_chars(
+                    &text,
+                    &chars,
+                    name != "rtrim",
+                    name != "ltrim",
+                ))),
+                _ => Ok(ColumnContent::Null),
+            }
+        }
+        "json_valid" => {
+            check_nb_args(name, &args, &[1])?;
+            match as_text(&args[0]) {
+                Some(text) => Ok(ColumnContent::Int(
+                    serde_json::from_str::<serde_json::Value>(&text).is_ok() as i64,
+                )),
+                None => Ok(ColumnContent::Null),
+            }
+        }
+        "json_extract" => {
+            check_nb_args(name, &args, &[2])?;
+            let (Some(text), Some(path)) = (as_text(&args[0]), as_text(&args[1])) else {
+                return Ok(ColumnContent::Null);
+            };
+            // invalid json gives NULL, like a missing path
+            let Ok(json) = serde_json::from_str::<serde_json::Value>(&text) else {
+                return Ok(ColumnContent::Null);
+            };
+            Ok(json_path_lookup(&json, &path)?.map_or(ColumnContent::Null, json_to_content))
+        }
+        "printf" | "format" => {
+            if args.is_empty() {
+                return Err(Error::InvalidStatement(format!(
+                    "wrong number of arguments to function {}()",
+                    name
+                )));
+            }
+            match as_text(&args[0]) {
+                Some(format) => Ok(ColumnContent::String(printf(&format, &args[1..]))),
+                None => Ok(ColumnContent::Null),
+            }
+        }
+        "min" | "max" if args.len() > 1 => {
+            let wanted = if name == "min" {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+            let mut best = args[0].clone();
+            for arg in &args {
+                if *arg == ColumnContent::Null {
+                    return Ok(ColumnContent::Null);
+                }
+                if compare(arg, &best) == Some(wanted) {
+                    best = arg.clone();
+                }
+            }
+            Ok(best)
+        }
+        // aggregates are computed by evaluate_aggregate, so this is a call in a where clause
+        "count" | "min" | "max" | "sum" | "avg" | "total" => Err(Error::InvalidStatement(format!(
+            "misuse of aggregate function {}()",
+            name
+        ))),
+        _ => Err(Error::InvalidStatement(format!(
+            "no such function: {}",
+            name
+        ))),
+    }
+}
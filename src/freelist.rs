@@ -0,0 +1,89 @@
+use std::io::{Seek, SeekFrom};
+
+use binrw::{binrw, BinRead};
+
+use crate::{
+    database_file::DatabaseFile,
+    database_header::DatabaseHeader,
+    error::{Error, Result},
+    page::page_offset,
+};
+
+/// The unused pages of the database form the freelist: a linked list of trunk pages,
+/// each one holding the numbers of some leaf pages.
+/// https://www.sqlite.org/fileformat.html#the_freelist
+#[derive(Debug)]
+#[binrw]
+#[brw(big)]
+pub struct FreelistTrunkPage {
+    /// 0 for the last trunk page
+    pub next_trunk_page: u32,
+    pub nb_leaf_pages: u32,
+    #[br(count = nb_leaf_pages)]
+    pub leaf_pages: Vec<u32>,
+}
+
+#[derive(Debug, Default)]
+pub struct Freelist {
+    pub trunk_pages: Vec<u32>,
+    pub leaf_pages: Vec<u32>,
+}
+
+impl Freelist {
+    /// Walks the chain of trunk pages starting from the one referenced in the database
+    /// header, and checks that the number of pages matches the one of the header
+    pub fn read(file: &mut DatabaseFile, db_header: &DatabaseHeader) -> Result<Self> {
+        let file_format = db_header.file_format();
+        let nb_pages = db_header.total_no_freelist_pages as usize;
+
+        let mut freelist = Freelist::default();
+        let mut trunk_page = db_header.page_no_first_freelink_trunk_page;
+        while trunk_page != 0 {
+            // a corrupted list could loop forever
+            if freelist.nb_pages() >= nb_pages {
+                return Err(Error::CorruptDatabase(format!(
+                    "The freelist has more pages than the {} of the database header",
+                    nb_pages
+                )));
+            }
+            if trunk_page > file_format.page_count {
+                return Err(Error::CorruptDatabase(format!(
+                    "The freelist trunk page {} is beyond the {} pages of the database",
+                    trunk_page, file_format.page_count
+                )));
+            }
+            file.seek(SeekFrom::Start(page_offset(trunk_page, file_format)))?;
+            let freelist_trunk_page = FreelistTrunkPage::read(file)?;
+
+            freelist.trunk_pages.push(trunk_page);
+            freelist.leaf_pages.extend(freelist_trunk_page.leaf_pages);
+            trunk_page = freelist_trunk_page.next_trunk_page;
+        }
+
+        if let Some(page) = freelist
+            .trunk_pages
+            .iter()
+            .chain(&freelist.leaf_pages)
+            .find(|&&page| db_header.is_ptrmap_page(page) || page == db_header.lock_byte_page())
+        {
+            return Err(Error::CorruptDatabase(format!(
+                "The freelist contains the page {}, which is a pointer map or the lock-byte page",
+                page
+            )));
+        }
+
+        if freelist.nb_pages() != nb_pages {
+            return Err(Error::CorruptDatabase(format!(
+                "The freelist has {} pages but the database header announces {}",
+                freelist.nb_pages(),
+                nb_pages
+            )));
+        }
+        Ok(freelist)
+    }
+
+    /// Both trunk and leaf pages are free
+    pub fn nb_pages(&self) -> usize {
+        self.trunk_pages.len() + self.leaf_pages.len()
+    }
+}
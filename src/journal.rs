@@ -0,0 +1,147 @@
+use std::{collections::HashMap, io::Cursor};
+
+use binrw::{binrw, BinRead};
+
+/// Before a transaction changes a page of a database in rollback mode, sqlite copies the
+/// original page to the -journal file next to the database. The journal is deleted once
+/// the transaction commits: if it is still there, the transaction was interrupted and the
+/// main file may be half written. Such a journal is hot, and its pages must be put back.
+/// https://www.sqlite.org/fileformat.html#the_rollback_journal
+#[derive(Debug)]
+#[binrw]
+#[brw(big)]
+pub struct JournalHeader {
+    #[br(assert(magic == JOURNAL_MAGIC))]
+    pub magic: [u8; 8],
+    /// Number of pages in this segment of the journal. -1 when the journal was not synced,
+    /// the pages then go to the end of the file.
+    pub nb_pages: u32,
+    /// Start of the checksums of the pages
+    pub nonce: u32,
+    /// Number of pages of the database before the transaction
+    pub initial_db_size: u32,
+    /// The header is padded to a sector, and so is each segment of the journal
+    pub sector_size: u32,
+    pub page_size: u32,
+}
+
+pub const JOURNAL_MAGIC: [u8; 8] = [0xd9, 0xd5, 0x05, 0xf9, 0x20, 0xa1, 0x63, 0xd7];
+
+/// The original pages of an interrupted transaction
+#[derive(Debug)]
+pub struct RollbackJournal {
+    pub page_size: u32,
+    pages: HashMap<u32, Vec<u8>>,
+    /// Number of pages of the database before the transaction
+    pub db_size: u32,
+}
+
+impl RollbackJournal {
+    /// Returns None if the journal is not hot: a journal starting with zeros was kept
+    /// after a commit instead of being deleted. The records are read up to the first one
+    /// whose checksum is wrong, which was not completely written.
+    pub fn from_bytes(bytes: &[u8]) -> binrw::BinResult<Option<RollbackJournal>> {
+        if bytes.first().is_none_or(|&byte| byte == 0) {
+            return Ok(None);
+        }
+
+        let mut pages = HashMap::new();
+        let mut db_size = None;
+        let mut page_size = 0;
+        let mut position = 0;
+        'segments: while position < bytes.len() && bytes[position] != 0 {
+            let journal_header = JournalHeader::read(&mut Cursor::new(&bytes[position..]))?;
+            db_size.get_or_insert(journal_header.initial_db_size);
+            page_size = journal_header.page_size as usize;
+            let record_size = 4 + page_size + 4;
+            position += journal_header.sector_size as usize;
+
+            let nb_records = match journal_header.nb_pages {
+                0xffffffff => (bytes.len().saturating_sub(position)) / record_size,
+                nb_pages => nb_pages as usize,
+            };
+            for _ in 0..nb_records {
+                if position + record_size > bytes.len() {
+                    break 'segments;
+                }
+                let page_no = u32::from_be_bytes(bytes[position..position + 4].try_into().unwrap());
+                let content = &bytes[position + 4..position + 4 + page_size];
+                let checksum = u32::from_be_bytes(
+                    bytes[position + 4 + page_size..position + record_size]
+                        .try_into()
+                        .unwrap(),
+                );
+                if checksum != page_checksum(content, journal_header.nonce) {
+                    break 'segments;
+                }
+                // the first copy of a page is the original one, and the pages added by the
+                // transaction are dropped with the rest of the file
+                if page_no <= db_size.unwrap_or(0) {
+                    pages.entry(page_no).or_insert_with(|| content.to_vec());
+                }
+                position += record_size;
+            }
+            // the next segment starts on a sector boundary
+            let sector_size = journal_header.sector_size as usize;
+            position = position.div_ceil(sector_size) * sector_size;
+        }
+
+        Ok(db_size.map(|db_size| RollbackJournal {
+            page_size: page_size as u32,
+            pages,
+            db_size,
+        }))
+    }
+
+    /// Original content of a page changed by the transaction
+    pub fn page(&self, page_no: u32) -> Option<&[u8]> {
+        self.pages.get(&page_no).map(|page| page.as_slice())
+    }
+}
+
+/// sqlite only sums one byte out of 200 of the page, going back from 200 bytes before its
+/// end, but not the first byte
+fn page_checksum(content: &[u8], nonce: u32) -> u32 {
+    (200..content.len())
+        .step_by(200)
+        .fold(nonce, |checksum, i| {
+            checksum.wrapping_add(content[content.len() - i] as u32)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture() -> Vec<u8> {
+        std::fs::read("tests/fixtures/hot_journal.db-journal").unwrap()
+    }
+
+    #[test]
+    fn original_pages_of_the_interrupted_transaction() {
+        let journal = RollbackJournal::from_bytes(&fixture()).unwrap().unwrap();
+        assert_eq!(journal.page_size, 512);
+        assert_eq!(journal.db_size, 2);
+        assert_eq!(journal.pages.len(), 2);
+        assert!(journal.page(1).unwrap().starts_with(b"SQLite format 3\0"));
+        assert!(journal.page(3).is_none());
+    }
+
+    #[test]
+    fn records_after_a_wrong_checksum_are_ignored() {
+        // the checksum covers the byte 312 of the second page
+        let mut bytes = fixture();
+        bytes[512 + 520 + 4 + 312] ^= 1;
+        let journal = RollbackJournal::from_bytes(&bytes).unwrap().unwrap();
+        assert_eq!(journal.pages.len(), 1);
+
+        // a journal starting with zeros is not hot
+        let mut bytes = fixture();
+        bytes[..28].fill(0);
+        assert!(RollbackJournal::from_bytes(&bytes).unwrap().is_none());
+
+        let mut bytes = fixture();
+        bytes[4] ^= 1;
+        assert!(RollbackJournal::from_bytes(&bytes).is_err());
+    }
+}
@@ -0,0 +1,1981 @@
+//! Reads sqlite databases: the b trees of the tables and of the indexes are parsed from
+//! the file, and SELECT statements are run against them.
+//! https://www.sqlite.org/fileformat.html
+
+pub mod async_database;
+mod blob;
+mod database_file;
+pub mod database_header;
+pub mod de;
+pub mod error;
+pub mod executor;
+mod expression;
+mod freelist;
+mod journal;
+pub mod page;
+pub mod row;
+pub mod schema_table;
+pub mod sql_parser;
+pub mod statement;
+pub mod value;
+mod wal;
+
+use binrw::BinRead;
+use itertools::Itertools;
+use rayon::prelude::*;
+use sql_parser::parse_sql;
+use std::{
+    cmp::Ordering,
+    collections::HashSet,
+    io::{Cursor, Read, Seek},
+    iter,
+    ops::{Bound, ControlFlow},
+    path::Path,
+};
+use tracing::{debug, debug_span};
+
+use blob::BlobReader;
+use database_file::DatabaseFile;
+use database_header::{DatabaseHeader, DATABASE_HEADER_SIZE};
+use error::{Error, Result};
+use executor::contains_subquery;
+use freelist::Freelist;
+use page::{FileFormat, IndexEntry, Page, PageSpace, PageType, Record, TextDecoding};
+
+pub use async_database::AsyncDatabase;
+pub use executor::ResultSet;
+pub use statement::Statement;
+
+use crate::{
+    expression::{column_position, evaluate, is_true},
+    page::ColumnContent,
+    schema_table::{IndexInfo, SchemaTable, SchemaTableRecord, TableInfo},
+    sql_parser::{
+        parse_create_table_command, Affinity, ColumnInfo, ComparisonOperator, CreateIndexQuery,
+        CreateTableQuery, Expr, Literal, LogicalOperator, TableSource,
+    },
+    value::sort_order,
+};
+
+/// Helper function to parse all the information of a table. Each record is passed to `f`
+/// as soon as it is read.
+/// For the sample.db, we can just read the number of cells in the page header.
+/// However it does not work for more complex databases such as Chinook
+/// (https://github.com/lerocha/chinook-database/releases):
+/// the first page is not a LeafTable but an InteriorTable
+/// In this case, the idea is to traverse the tree until we reach a LeafTable and
+/// then parse the leaf cells
+fn for_each_table_record(
+    file: &mut DatabaseFile,
+    page_no: u32,
+    file_format: FileFormat,
+    f: &mut dyn FnMut(Record) -> Result<ControlFlow<()>>,
+) -> Result<()> {
+    for_each_table_record_in_range(file, page_no, file_format, RowidRange::ALL, None, f)
+}
+
+/// Collects all the records of a table, see for_each_table_record
+fn get_table_records(
+    file: &mut DatabaseFile,
+    page_no: u32,
+    file_format: FileFormat,
+) -> Result<Vec<Record>> {
+    let mut records = Vec::new();
+    for_each_table_record(file, page_no, file_format, &mut |record| {
+        records.push(record);
+        Ok(ControlFlow::Continue(()))
+    })?;
+    Ok(records)
+}
+
+/// Passes each page of a b tree to `f`, a page before its children
+fn for_each_b_tree_page(
+    file: &mut DatabaseFile,
+    page_no: u32,
+    file_format: FileFormat,
+    f: &mut dyn FnMut(&Page) -> Result<()>,
+) -> Result<()> {
+    let mut b_tree_reader = BTreeReader::new(page_no, file_format);
+    let mut stack = Vec::new();
+    push_page(&mut stack, file, &mut b_tree_reader, page_no)?;
+    f(&stack[0].page)?;
+
+    while let Some(frame) = stack.last_mut() {
+        let page = &frame.page;
+        let i = frame.next_child;
+        frame.next_child += 1;
+        let child = match (&page.header.page_type, page.header.right_most_pointer) {
+            (_, Some(right_most_pointer)) => match i.cmp(&page.nb_cells()) {
+                Ordering::Less if page.header.page_type == PageType::InteriorTable => {
+                    Some(page.table_interior_cell(i)?.left_child_pointer)
+                }
+                Ordering::Less => Some(page.index_interior_cell(i)?.left_child_pointer),
+                Ordering::Equal => Some(right_most_pointer),
+                Ordering::Greater => None,
+            },
+            (_, None) => None,
+        };
+
+        match child {
+            Some(page_no) => {
+                push_page(&mut stack, file, &mut b_tree_reader, page_no)?;
+                f(&stack[stack.len() - 1].page)?;
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Free bytes of a group of pages, shown by .stats
+#[derive(Debug, Default)]
+pub struct SpaceStats {
+    nb_pages: usize,
+    usable_bytes: usize,
+    free_bytes: usize,
+    unallocated_bytes: usize,
+    nb_freeblocks: usize,
+    freeblock_bytes: usize,
+    fragmented_bytes: usize,
+}
+
+impl SpaceStats {
+    fn add(&mut self, space: PageSpace, file_format: FileFormat) {
+        self.nb_pages += 1;
+        self.usable_bytes += file_format.usable_size as usize;
+        self.free_bytes += space.free_bytes();
+        self.unallocated_bytes += space.unallocated_bytes;
+        self.nb_freeblocks += space.freeblocks.len();
+        self.freeblock_bytes += space.freeblock_bytes();
+        self.fragmented_bytes += space.fragmented_bytes;
+    }
+
+    fn merge(self, other: &SpaceStats) -> SpaceStats {
+        SpaceStats {
+            nb_pages: self.nb_pages + other.nb_pages,
+            usable_bytes: self.usable_bytes + other.usable_bytes,
+            free_bytes: self.free_bytes + other.free_bytes,
+            unallocated_bytes: self.unallocated_bytes + other.unallocated_bytes,
+            nb_freeblocks: self.nb_freeblocks + other.nb_freeblocks,
+            freeblock_bytes: self.freeblock_bytes + other.freeblock_bytes,
+            fragmented_bytes: self.fragmented_bytes + other.fragmented_bytes,
+        }
+    }
+}
+
+impl std::fmt::Display for SpaceStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{} pages, {} free bytes ({:.1}%): {} unallocated, {} in {} freeblocks, {} fragmented",
+            self.nb_pages,
+            self.free_bytes,
+            100.0 * self.free_bytes as f64 / self.usable_bytes.max(1) as f64,
+            self.unallocated_bytes,
+            self.freeblock_bytes,
+            self.nb_freeblocks,
+            self.fragmented_bytes
+        )
+    }
+}
+
+/// sqlite considers deeper b trees as corrupted. This also stops the traversal of a
+/// b tree whose pages form a cycle.
+const MAX_BTREE_DEPTH: usize = 20;
+
+/// A page of the b tree being traversed, with the next of its children to visit
+struct TraversalFrame {
+    page_no: u32,
+    page: Page,
+    next_child: usize,
+}
+
+/// A page of the wrong kind of b tree, like a table page in an index
+fn unexpected_page_type(page: &Page, b_tree_type: &str) -> Error {
+    Error::CorruptPage {
+        page_no: page.page_no,
+        reason: format!(
+            "When traversing the b tree, only interior and leaf {} pages should be encountered",
+            b_tree_type
+        ),
+    }
+}
+
+/// B trees are traversed depth first with an explicit stack of pages: the page to visit
+/// is pushed on top of its ancestors
+fn push_page<'a>(
+    stack: &'a mut Vec<TraversalFrame>,
+    file: &mut DatabaseFile,
+    b_tree_reader: &mut BTreeReader,
+    page_no: u32,
+) -> Result<&'a mut TraversalFrame> {
+    if stack.len() >= MAX_BTREE_DEPTH {
+        return Err(Error::CorruptPage {
+            page_no,
+            reason: format!(
+                "Corrupted b tree: it has more than {} levels",
+                MAX_BTREE_DEPTH
+            ),
+        });
+    }
+    let parent = stack.last().map(|frame| frame.page_no);
+    let depth = stack.len();
+    stack.push(TraversalFrame {
+        page_no,
+        page: b_tree_reader.read_page(file, page_no, parent)?,
+        next_child: 0,
+    });
+    Ok(&mut stack[depth])
+}
+
+/// Reads the pages of a b tree during one scan. In a corrupted database, a child pointer
+/// can be out of the file or point back to a page already read, which would make the
+/// scan loop forever.
+struct BTreeReader {
+    root_page: u32,
+    file_format: FileFormat,
+    visited: HashSet<u32>,
+}
+
+impl BTreeReader {
+    fn new(root_page: u32, file_format: FileFormat) -> Self {
+        BTreeReader {
+            root_page,
+            file_format,
+            visited: HashSet::new(),
+        }
+    }
+
+    /// Reads the page `page_no`, which `parent` points to (None for the root page)
+    fn read_page(
+        &mut self,
+        file: &mut DatabaseFile,
+        page_no: u32,
+        parent: Option<u32>,
+    ) -> Result<Page> {
+        let page_count = self.file_format.page_count;
+        if page_no == 0 || page_no > page_count {
+            let reason = match parent {
+                Some(parent) => format!(
+                    "Corrupted database: the page {} points to the page {}, but the database has {} pages",
+                    parent,
+                    page_no,
+                    page_count
+                ),
+                None => format!(
+                    "Corrupted database: the b tree starts at the page {}, but the database has {} pages",
+                    page_no,
+                    page_count
+                ),
+            };
+            // the error is in the page holding the pointer
+            return Err(Error::CorruptPage {
+                page_no: parent.unwrap_or(page_no),
+                reason,
+            });
+        }
+        if !self.visited.insert(page_no) {
+            return Err(Error::CorruptPage {
+                page_no: parent.unwrap_or(page_no),
+                reason: format!(
+                    "Corrupted database: the page {} points to the page {}, already read in the b tree starting at the page {}",
+                    parent.unwrap_or(page_no),
+                    page_no,
+                    self.root_page
+                ),
+            });
+        }
+        Page::read(file, page_no, self.file_format)
+    }
+}
+
+/// Inclusive bounds on the rowid, used to skip the subtrees which can not contain
+/// matching records
+#[derive(Debug, Clone, Copy)]
+struct RowidRange {
+    min: Option<i64>,
+    max: Option<i64>,
+}
+
+impl RowidRange {
+    const ALL: RowidRange = RowidRange {
+        min: None,
+        max: None,
+    };
+
+    /// Converts an inequality between the rowid and an integer into a range.
+    /// Equalities are handled by a point lookup instead.
+    fn from_condition(operator: ComparisonOperator, val: i64) -> Option<Self> {
+        // an impossible range matches nothing
+        let empty = RowidRange {
+            min: Some(1),
+            max: Some(0),
+        };
+        let range = match operator {
+            ComparisonOperator::Gt if val == i64::MAX => empty,
+            ComparisonOperator::Gt => RowidRange {
+                min: Some(val + 1),
+                max: None,
+            },
+            ComparisonOperator::GtEq => RowidRange {
+                min: Some(val),
+                max: None,
+            },
+            ComparisonOperator::Lt if val == i64::MIN => empty,
+            ComparisonOperator::Lt => RowidRange {
+                min: None,
+                max: Some(val - 1),
+            },
+            ComparisonOperator::LtEq => RowidRange {
+                min: None,
+                max: Some(val),
+            },
+            ComparisonOperator::Eq | ComparisonOperator::NotEq => return None,
+        };
+        Some(range)
+    }
+
+    /// Range of the rowids in both ranges
+    fn intersect(self, other: RowidRange) -> RowidRange {
+        let either = |a: Option<i64>, b: Option<i64>, tighter: fn(i64, i64) -> i64| match (a, b) {
+            (Some(a), Some(b)) => Some(tighter(a, b)),
+            (a, b) => a.or(b),
+        };
+        RowidRange {
+            min: either(self.min, other.min, i64::max),
+            max: either(self.max, other.max, i64::min),
+        }
+    }
+
+    fn contains(&self, integer_key: i64) -> bool {
+        self.min.is_none_or(|min| integer_key >= min)
+            && self.max.is_none_or(|max| integer_key <= max)
+    }
+
+    /// Binary searches the first cell of a table page whose key is not below the range.
+    /// On an interior page, the key of a cell is the largest key of its left child: the
+    /// children before this cell only hold keys below the range.
+    fn first_cell_in_range(&self, page: &Page) -> Result<usize> {
+        let Some(min) = self.min else {
+            return Ok(0);
+        };
+        partition_point(page.nb_cells(), |i| {
+            let integer_key = match page.header.page_type {
+                PageType::InteriorTable => page.table_interior_cell(i)?.integer_key,
+                _ => page.table_leaf_cell(i)?.integer_key,
+            };
+            Ok(integer_key < min)
+        })
+    }
+
+    /// Checks if keys strictly greater than previous_key can be in the range
+    fn may_contain_keys_above(&self, previous_key: Option<i64>) -> bool {
+        match (self.max, previous_key) {
+            (Some(max), Some(previous_key)) => previous_key < max,
+            _ => true,
+        }
+    }
+}
+
+/// Same as for_each_table_record, but only passes the records whose rowid is in the
+/// range. Children of interior pages are skipped when their keys are out of the range.
+/// Only the `columns` of the records are decoded, None for all of them.
+fn for_each_table_record_in_range(
+    file: &mut DatabaseFile,
+    page_no: u32,
+    file_format: FileFormat,
+    range: RowidRange,
+    columns: Option<&[usize]>,
+    f: &mut dyn FnMut(Record) -> Result<ControlFlow<()>>,
+) -> Result<()> {
+    let mut cursor = TableCursor::seek(file, page_no, file_format, range)?;
+    while let Some(record) = cursor.next(file, columns)? {
+        if f(record)?.is_break() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Reads the records of a table b tree in the order of their rowids, the ones in a range
+/// only. Like IndexCursor, it keeps the pages from the root to the current one on a
+/// stack, with the position of the next cell to read in each of them.
+struct TableCursor {
+    b_tree_reader: BTreeReader,
+    stack: Vec<TraversalFrame>,
+    range: RowidRange,
+}
+
+impl TableCursor {
+    /// Starts at the root page of the b tree
+    fn seek(
+        file: &mut DatabaseFile,
+        page_no: u32,
+        file_format: FileFormat,
+        range: RowidRange,
+    ) -> Result<Self> {
+        let mut b_tree_reader = BTreeReader::new(page_no, file_format);
+        let mut stack = Vec::new();
+        push_page(&mut stack, file, &mut b_tree_reader, page_no)?;
+        Ok(TableCursor {
+            b_tree_reader,
+            stack,
+            range,
+        })
+    }
+
+    /// Reads the next record in the range, None after the last one. Only the `columns`
+    /// of the record are decoded, None for all of them.
+    fn next(
+        &mut self,
+        file: &mut DatabaseFile,
+        columns: Option<&[usize]>,
+    ) -> Result<Option<Record>> {
+        let range = self.range;
+        while let Some(frame) = self.stack.last_mut() {
+            let page = &frame.page;
+            let child = match (&page.header.page_type, page.header.right_most_pointer) {
+                (PageType::InteriorTable, Some(right_most_pointer)) => {
+                    // The left child of a cell contains the keys lower or equal to the key of
+                    // the cell and strictly greater than the key of the previous cell. The
+                    // right most pointer contains the keys greater than all the cell keys.
+                    if frame.next_child == 0 {
+                        frame.next_child = range.first_cell_in_range(page)?;
+                    }
+                    let i = frame.next_child;
+                    frame.next_child += 1;
+                    let previous_key = match i {
+                        0 => None,
+                        i if i > page.nb_cells() => None,
+                        i => Some(page.table_interior_cell(i - 1)?.integer_key),
+                    };
+                    if i > page.nb_cells() || !range.may_contain_keys_above(previous_key) {
+                        // keys are sorted: the next children are also out of range
+                        None
+                    } else if i == page.nb_cells() {
+                        Some(right_most_pointer)
+                    } else {
+                        Some(page.table_interior_cell(i)?.left_child_pointer)
+                    }
+                }
+                (PageType::LeafTable, None) => {
+                    // For leaf table, I was tempted to simply read the number_of_cells but
+                    // it overestimated the result for the Chinook db
+                    // Instead, we can parse the pointer array and look at each individual
+                    // cell then check the payload for the CREATE TABLE string.
+                    // This seems to work...
+                    if frame.next_child == 0 {
+                        frame.next_child = range.first_cell_in_range(page)?;
+                    }
+                    let i = frame.next_child;
+                    frame.next_child += 1;
+                    // the keys after the first one above the range are also above it
+                    if i < page.nb_cells() && range.contains(page.table_leaf_cell(i)?.integer_key) {
+                        return Ok(Some(page.table_leaf_record(file, i, columns)?));
+                    }
+                    None
+                }
+                _ => return Err(unexpected_page_type(page, "TABLE")),
+            };
+
+            match child {
+                Some(page_no) => {
+                    debug!(
+                        page_no = frame.page_no,
+                        child = page_no,
+                        cell = frame.next_child - 1,
+                        "descending into the next child which can hold rowids of the range"
+                    );
+                    push_page(&mut self.stack, file, &mut self.b_tree_reader, page_no)?;
+                }
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Same as a scan of the whole table by for_each_table_record_in_range, with the subtrees
+/// of the root page read by a pool of `threads` threads. Each subtree is read with its
+/// own handle on the file: its records are converted by `record_to_row`, and only the
+/// rows matching `filter` are kept. The rows are then passed to `f` in the order of the
+/// rowids, and the errors are returned where the scan would have met them. All the
+/// subtrees are read, even when `f` stops early.
+#[allow(clippy::too_many_arguments)]
+fn for_each_table_row_in_parallel(
+    file: &mut DatabaseFile,
+    threads: usize,
+    root_page: u32,
+    file_format: FileFormat,
+    columns: Option<&[usize]>,
+    record_to_row: &(dyn Fn(Record) -> Vec<ColumnContent> + Sync),
+    filter: Option<(&Expr, &[String])>,
+    f: &mut dyn FnMut(Vec<ColumnContent>) -> Result<ControlFlow<()>>,
+) -> Result<()> {
+    let mut serial_scan = |file: &mut DatabaseFile| {
+        for_each_table_record_in_range(
+            file,
+            root_page,
+            file_format,
+            RowidRange::ALL,
+            columns,
+            &mut |record| f(record_to_row(record)),
+        )
+    };
+    let root = BTreeReader::new(root_page, file_format).read_page(file, root_page, None)?;
+    let children = match (&root.header.page_type, root.header.right_most_pointer) {
+        (PageType::InteriorTable, Some(right_most_pointer)) => (0..root.nb_cells())
+            .map(|i| Ok(root.table_interior_cell(i)?.left_child_pointer))
+            .chain(iter::once(Ok(right_most_pointer)))
+            .collect::<Result<Vec<_>>>()?,
+        // a table in a single page has no subtrees to share
+        _ => return serial_scan(file),
+    };
+    let mut handles = Vec::new();
+    for _ in &children {
+        match file.try_clone()? {
+            Some(handle) => handles.push(handle),
+            None => return serial_scan(file),
+        }
+    }
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .map_err(|err| Error::Runtime(err.to_string()))?;
+    let subtrees = pool.install(|| {
+        children
+            .into_par_iter()
+            .zip(handles)
+            .map(|(child, mut handle)| {
+                let mut rows = Vec::new();
+                let mut read_subtree = || -> Result<()> {
+                    let mut b_tree_reader = BTreeReader::new(root_page, file_format);
+                    let stack = vec![TraversalFrame {
+                        page_no: child,
+                        page: b_tree_reader.read_page(&mut handle, child, Some(root_page))?,
+                        next_child: 0,
+                    }];
+                    let mut cursor = TableCursor {
+                        b_tree_reader,
+                        stack,
+                        range: RowidRange::ALL,
+                    };
+                    while let Some(record) = cursor.next(&mut handle, columns)? {
+                        let row = record_to_row(record);
+                        let is_match = match filter {
+                            Some((expr, col_names)) => is_true(&evaluate(expr, col_names, &row)?),
+                            None => true,
+                        };
+                        if is_match {
+                            rows.push(row);
+                        }
+                    }
+                    Ok(())
+                };
+                let result = read_subtree();
+                (rows, result, handle)
+            })
+            .collect::<Vec<_>>()
+    });
+
+    for (_, _, handle) in &subtrees {
+        file.add_reads(handle);
+    }
+    for (rows, result, _) in subtrees {
+        for row in rows {
+            if f(row)?.is_break() {
+                return Ok(());
+            }
+        }
+        result?;
+    }
+    Ok(())
+}
+
+/// Opens a blob or a text of a row for streaming, see BlobReader. Unlike a SELECT, the
+/// value is never read entirely in memory.
+fn open_blob<'a>(
+    file: &'a mut DatabaseFile,
+    file_format: FileFormat,
+    schema_table: &SchemaTable,
+    tablename: &str,
+    rowid: i64,
+    colname: &str,
+) -> Result<BlobReader<'a, DatabaseFile>> {
+    let create_table_query = get_create_table_query(schema_table, tablename)?;
+    if create_table_query.without_rowid {
+        return Err(Error::UnsupportedFeature(format!(
+            "cannot open a blob of the WITHOUT ROWID table {}",
+            tablename
+        )));
+    }
+    let column = column_position(&create_table_query.column_names(), colname)
+        .ok_or_else(|| Error::NoSuchColumn(colname.to_string()))?;
+    let root_page = schema_table
+        .get_schema_record_for_table(tablename)
+        .map(|table_record| table_record.rootpage)
+        .unwrap_or_default();
+
+    let (page, i) = get_leaf_cell_by_rowid(file, root_page, file_format, rowid)?
+        .ok_or_else(|| Error::InvalidValue(format!("no such rowid: {}", rowid)))?;
+    let cell = page.table_leaf_cell(i)?;
+    Ok(BlobReader::new(file, cell.payload, file_format, column)?)
+}
+
+/// Finds the record with a given rowid, see get_leaf_cell_by_rowid. Only its `columns`
+/// are decoded, None for all of them.
+fn get_record_by_rowid(
+    file: &mut DatabaseFile,
+    root_page: u32,
+    file_format: FileFormat,
+    rowid: i64,
+    columns: Option<&[usize]>,
+) -> Result<Option<Record>> {
+    match get_leaf_cell_by_rowid(file, root_page, file_format, rowid)? {
+        Some((page, i)) => Ok(Some(page.table_leaf_record(file, i, columns)?)),
+        None => Ok(None),
+    }
+}
+
+/// Finds the leaf page holding a given rowid, with the position of its cell, by
+/// descending the table b tree. At each page, the first cell whose key is greater or
+/// equal to the rowid is binary searched, like for the start of a range of rowids: on
+/// an interior page, its left child (or the right most pointer if the rowid is greater
+/// than all the keys) is followed.
+fn get_leaf_cell_by_rowid(
+    file: &mut DatabaseFile,
+    root_page: u32,
+    file_format: FileFormat,
+    rowid: i64,
+) -> Result<Option<(Page, usize)>> {
+    let range = RowidRange {
+        min: Some(rowid),
+        max: Some(rowid),
+    };
+    let mut b_tree_reader = BTreeReader::new(root_page, file_format);
+    let mut parent = None;
+    let mut page_no = root_page;
+    loop {
+        let page = b_tree_reader.read_page(file, page_no, parent)?;
+        parent = Some(page_no);
+
+        let i = range.first_cell_in_range(&page)?;
+        match (&page.header.page_type, page.header.right_most_pointer) {
+            (PageType::InteriorTable, Some(right_most_pointer)) => {
+                page_no = match i {
+                    i if i == page.nb_cells() => right_most_pointer,
+                    i => page.table_interior_cell(i)?.left_child_pointer,
+                };
+                debug!(
+                    page_no = page.page_no,
+                    child = page_no,
+                    cell = i,
+                    rowid,
+                    "descending into the child of the first key not below the rowid"
+                );
+            }
+            (PageType::LeafTable, None) => {
+                if i < page.nb_cells() && page.table_leaf_cell(i)?.integer_key == rowid {
+                    return Ok(Some((page, i)));
+                }
+                return Ok(None);
+            }
+            _ => return Err(unexpected_page_type(&page, "TABLE")),
+        }
+    }
+}
+
+/// Bounds on the first column of the records of an index. An equality is a range whose
+/// bounds are both the value.
+#[derive(Debug, Clone)]
+struct IndexRange {
+    lower: Bound<ColumnContent>,
+    upper: Bound<ColumnContent>,
+}
+
+impl IndexRange {
+    /// Converts a comparison between the first column of the index and a value into a
+    /// range. NULL is lower than all the values in an index but never satisfies an
+    /// inequality, so it is left out of the ranges without lower bound.
+    fn from_condition(operator: ComparisonOperator, value: ColumnContent) -> Option<Self> {
+        let (lower, upper) = match operator {
+            ComparisonOperator::Eq => (Bound::Included(value.clone()), Bound::Included(value)),
+            ComparisonOperator::Gt => (Bound::Excluded(value), Bound::Unbounded),
+            ComparisonOperator::GtEq => (Bound::Included(value), Bound::Unbounded),
+            ComparisonOperator::Lt => {
+                (Bound::Excluded(ColumnContent::Null), Bound::Excluded(value))
+            }
+            ComparisonOperator::LtEq => {
+                (Bound::Excluded(ColumnContent::Null), Bound::Included(value))
+            }
+            ComparisonOperator::NotEq => return None,
+        };
+        Some(IndexRange { lower, upper })
+    }
+
+    /// Range of the keys in both ranges
+    fn intersect(self, other: IndexRange) -> IndexRange {
+        // of two bounds on the same value, the excluded one is the tighter
+        let tighter =
+            |a: Bound<ColumnContent>, b: Bound<ColumnContent>, wanted: Ordering| match (&a, &b) {
+                (Bound::Unbounded, _) => b,
+                (_, Bound::Unbounded) => a,
+                (
+                    Bound::Included(x) | Bound::Excluded(x),
+                    Bound::Included(y) | Bound::Excluded(y),
+                ) => match sort_order(x, y) {
+                    Ordering::Equal if matches!(a, Bound::Included(_)) => b,
+                    Ordering::Equal => a,
+                    ordering if ordering == wanted => a,
+                    _ => b,
+                },
+            };
+        IndexRange {
+            lower: tighter(self.lower, other.lower, Ordering::Greater),
+            upper: tighter(self.upper, other.upper, Ordering::Less),
+        }
+    }
+
+    fn is_point(&self) -> bool {
+        match (&self.lower, &self.upper) {
+            (Bound::Included(lower), Bound::Included(upper)) => {
+                sort_order(lower, upper) == Ordering::Equal
+            }
+            _ => false,
+        }
+    }
+
+    /// Checks if a key is after the upper bound. The keys are read in order, so the next
+    /// ones are too.
+    fn is_after(&self, key: &ColumnContent) -> bool {
+        match &self.upper {
+            Bound::Unbounded => false,
+            Bound::Included(upper) => sort_order(key, upper) == Ordering::Greater,
+            Bound::Excluded(upper) => sort_order(key, upper) != Ordering::Less,
+        }
+    }
+}
+
+/// First column of a record of an index, which the index is sorted by first
+fn first_column(record: &Record) -> &ColumnContent {
+    record
+        .column_contents
+        .first()
+        .unwrap_or(&ColumnContent::Null)
+}
+
+/// Reads the records of an index b tree in order, from a lower bound on their first
+/// column. Like the scans, it keeps the pages from the root to the current one on a
+/// stack, and each interior page only holds the position of its next cell: the records
+/// are read one at a time instead of being collected.
+struct IndexCursor {
+    b_tree_reader: BTreeReader,
+    stack: Vec<TraversalFrame>,
+}
+
+impl IndexCursor {
+    /// Descends the b tree to the first record which is not below `lower`
+    fn seek(
+        file: &mut DatabaseFile,
+        page_no: u32,
+        file_format: FileFormat,
+        lower: Bound<&ColumnContent>,
+    ) -> Result<Self> {
+        let mut cursor = IndexCursor {
+            b_tree_reader: BTreeReader::new(page_no, file_format),
+            stack: Vec::new(),
+        };
+        let is_below = |record: &Record| {
+            let is_below = match lower {
+                Bound::Unbounded => false,
+                Bound::Included(key) => sort_order(first_column(record), key) == Ordering::Less,
+                Bound::Excluded(key) => sort_order(first_column(record), key) != Ordering::Greater,
+            };
+            debug!(key = ?first_column(record), ?lower, is_below, "index probe");
+            is_below
+        };
+
+        let mut child = Some(page_no);
+        while let Some(page_no) = child {
+            let frame = push_page(&mut cursor.stack, file, &mut cursor.b_tree_reader, page_no)?;
+            let page = &frame.page;
+            match (&page.header.page_type, page.header.right_most_pointer) {
+                (PageType::InteriorIndex, Some(right_most_pointer)) => {
+                    // The left child of a cell holds the records lower or equal to the
+                    // record of the cell, and the right most pointer the records greater
+                    // than all of them. The first record not below the bound is in the
+                    // child of the first cell not below it, and this cell comes next.
+                    let l = partition_point(page.nb_cells(), |i| {
+                        Ok(is_below(&page.index_interior_record(file, i)?))
+                    })?;
+                    let child_page_no = match l.cmp(&page.nb_cells()) {
+                        Ordering::Less => page.index_interior_cell(l)?.left_child_pointer,
+                        _ => right_most_pointer,
+                    };
+                    debug!(
+                        page_no = page.page_no,
+                        child = child_page_no,
+                        cell = l,
+                        "descending into the child of the first key not below the bound"
+                    );
+                    child = Some(child_page_no);
+                    frame.next_child = l + 1;
+                }
+                (PageType::LeafIndex, None) => {
+                    frame.next_child = partition_point(page.nb_cells(), |i| {
+                        Ok(is_below(&page.index_leaf_record(file, i)?))
+                    })?;
+                    child = None;
+                }
+                _ => return Err(unexpected_page_type(page, "INDEX")),
+            }
+        }
+        Ok(cursor)
+    }
+
+    /// Reads the next record in the order of the index, None after the last one
+    fn next(&mut self, file: &mut DatabaseFile) -> Result<Option<Record>> {
+        while let Some(frame) = self.stack.last_mut() {
+            let page = &frame.page;
+            let i = frame.next_child;
+            frame.next_child += 1;
+            match (&page.header.page_type, page.header.right_most_pointer) {
+                (PageType::InteriorIndex, Some(right_most_pointer)) => {
+                    // the left child of the cell i - 1 has been read, then comes the cell
+                    // and the records of the next child
+                    let record = match i {
+                        0 => None,
+                        i if i > page.nb_cells() => None,
+                        i => Some(page.index_interior_record(file, i - 1)?),
+                    };
+                    let child = match i.cmp(&page.nb_cells()) {
+                        Ordering::Less => Some(page.index_interior_cell(i)?.left_child_pointer),
+                        Ordering::Equal => Some(right_most_pointer),
+                        Ordering::Greater => None,
+                    };
+                    match child {
+                        Some(page_no) => {
+                            push_page(&mut self.stack, file, &mut self.b_tree_reader, page_no)?;
+                        }
+                        None => {
+                            self.stack.pop();
+                        }
+                    }
+                    if record.is_some() {
+                        return Ok(record);
+                    }
+                }
+                (PageType::LeafIndex, None) => {
+                    if i < page.nb_cells() {
+                        return Ok(Some(page.index_leaf_record(file, i)?));
+                    }
+                    self.stack.pop();
+                }
+                _ => return Err(unexpected_page_type(page, "INDEX")),
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Finds the records of an index whose first column is in the range, in the order of
+/// the index. In a unique index, an equality matches at most one record.
+fn get_index_records(
+    file: &mut DatabaseFile,
+    page_no: u32,
+    file_format: FileFormat,
+    range: &IndexRange,
+    unique: bool,
+) -> Result<Vec<Record>> {
+    let mut cursor = IndexCursor::seek(file, page_no, file_format, range.lower.as_ref())?;
+    let mut records = Vec::new();
+    while let Some(record) = cursor.next(file)? {
+        if range.is_after(first_column(&record)) {
+            break;
+        }
+        records.push(record);
+        if unique && range.is_point() {
+            break;
+        }
+    }
+    Ok(records)
+}
+
+/// Binary searches the first of `nb_cells` sorted cells which is not `before` the
+/// searched position, or `nb_cells` if there is none
+fn partition_point(
+    nb_cells: usize,
+    mut before: impl FnMut(usize) -> Result<bool>,
+) -> Result<usize> {
+    let mut l = 0;
+    let mut r = nb_cells;
+    while l < r {
+        let mid = l + (r - l) / 2;
+        if before(mid)? {
+            l = mid + 1;
+        } else {
+            r = mid;
+        }
+    }
+    Ok(l)
+}
+
+/// Splits a condition into the conditions joined by AND, which must all be true
+fn conjuncts(expr: &Expr) -> Vec<&Expr> {
+    match expr {
+        Expr::Logical {
+            left,
+            operator: LogicalOperator::And,
+            right,
+        } => {
+            let mut conditions = conjuncts(left);
+            conditions.extend(conjuncts(right));
+            conditions
+        }
+        expr => vec![expr],
+    }
+}
+
+/// Parses the CREATE TABLE statement of a table
+fn get_create_table_query(schema_table: &SchemaTable, tablename: &str) -> Result<CreateTableQuery> {
+    // views and indexes are not tables: get_schema_record_for_table
+    // only looks at records with the "table" type
+    let table_record = match schema_table.get_schema_record_for_table(tablename) {
+        Some(table_record) => table_record,
+        None if schema_table
+            .get_view_names()
+            .iter()
+            .any(|name| name.eq_ignore_ascii_case(tablename)) =>
+        {
+            return Err(Error::InvalidStatement(format!(
+                "{} is a view, not a table",
+                tablename
+            )))
+        }
+        None => return Err(Error::NoSuchTable(tablename.to_string())),
+    };
+    // the rows of a virtual table come from its module, they are not in a b tree
+    if table_record.rootpage == 0 {
+        return Err(Error::UnsupportedFeature(format!(
+            "Parse error: virtual tables are not supported: {}",
+            tablename
+        )));
+    }
+
+    match parse_create_table_command(table_record.sql.as_deref().unwrap_or_default()) {
+        Ok((_, create_table_query))
+            if create_table_query.tablename.eq_ignore_ascii_case(tablename) =>
+        {
+            Ok(create_table_query)
+        }
+        _ => Err(Error::CorruptDatabase(format!(
+            "malformed database schema ({})",
+            table_record.name
+        ))),
+    }
+}
+
+/// Names of the columns of a table
+fn get_table_columns(schema_table: &SchemaTable, tablename: &str) -> Result<Vec<String>> {
+    Ok(get_create_table_query(schema_table, tablename)?.column_names())
+}
+
+/// Reads all the columns of a table and passes each row to `f`. If the where clause
+/// targets an indexed column, only the matching rows are read through the index,
+/// otherwise the whole b tree is traversed and the rows are not kept in memory.
+/// `used_columns` are the positions of the columns the caller reads, None for all of
+/// them: the other columns are NULL, they are not decoded from the records, and when
+/// the index holds the used columns, the rows are not read from the table at all.
+fn for_each_table_row(
+    file: &mut DatabaseFile,
+    db_header: &DatabaseHeader,
+    schema_table: &SchemaTable,
+    tablename: &str,
+    where_clause: Option<&Expr>,
+    used_columns: Option<&[usize]>,
+    f: &mut dyn FnMut(Vec<ColumnContent>) -> Result<ControlFlow<()>>,
+) -> Result<()> {
+    let _span = debug_span!("read_table", table = tablename).entered();
+    read_table_rows(
+        file,
+        db_header,
+        schema_table,
+        tablename,
+        where_clause,
+        used_columns,
+        &mut |row| {
+            debug!(?row, "row read");
+            f(row)
+        },
+    )
+    .map_err(|err| table_error(tablename, err))
+}
+
+/// The errors of the parsing of the pages only know the page and the cell
+fn table_error(tablename: &str, err: Error) -> Error {
+    match err {
+        Error::CorruptPage { page_no, reason } => Error::CorruptPage {
+            page_no,
+            reason: format!("Could not read the table {}: {}", tablename, reason),
+        },
+        err => err,
+    }
+}
+
+/// See for_each_table_row
+fn read_table_rows(
+    file: &mut DatabaseFile,
+    db_header: &DatabaseHeader,
+    schema_table: &SchemaTable,
+    tablename: &str,
+    where_clause: Option<&Expr>,
+    used_columns: Option<&[usize]>,
+    f: &mut dyn FnMut(Vec<ColumnContent>) -> Result<ControlFlow<()>>,
+) -> Result<()> {
+    let create_table_query = get_create_table_query(schema_table, tablename)?;
+    let plan = plan_table_read(
+        schema_table,
+        tablename,
+        &create_table_query,
+        where_clause,
+        used_columns,
+    );
+    debug!(plan = %plan.explain(tablename), "plan chosen");
+
+    // the rows are filtered by the threads, the sub selects are run by the caller
+    if matches!(plan, Plan::Scan) && !create_table_query.without_rowid && db_header.scan_threads > 1
+    {
+        let root_page = table_root_page(schema_table, tablename)?;
+        let decoder = RowDecoder::new(&create_table_query, used_columns);
+        return for_each_table_row_in_parallel(
+            file,
+            db_header.scan_threads,
+            root_page,
+            db_header.file_format(),
+            decoder.record_columns.as_deref(),
+            &|record| decoder.record_to_row(record),
+            where_clause
+                .filter(|where_clause| !contains_subquery(where_clause))
+                .map(|where_clause| (where_clause, decoder.col_names.as_slice())),
+            f,
+        );
+    }
+
+    let mut rows = TableRows::open(
+        file,
+        db_header.file_format(),
+        schema_table,
+        &create_table_query,
+        &plan,
+        used_columns,
+    )?;
+    while let Some(row) = rows.next(file)? {
+        if f(row)?.is_break() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Root page of the b tree of a table
+fn table_root_page(schema_table: &SchemaTable, tablename: &str) -> Result<u32> {
+    let table_record = match schema_table.get_schema_record_for_table(tablename) {
+        Some(table_record) => table_record,
+        None => return Err(Error::NoSuchTable(tablename.to_string())),
+    };
+    // views are stored without b tree, their root page is 0
+    if table_record.rootpage == 0 {
+        return Err(Error::InvalidStatement(format!(
+            "cannot scan {}: it has no b tree",
+            tablename
+        )));
+    }
+    Ok(table_record.rootpage)
+}
+
+/// Turns the records of a table into rows, with a value for each column of the table
+struct RowDecoder {
+    col_names: Vec<String>,
+    /// Positions in the table of the columns stored in the records
+    stored_order: Vec<usize>,
+    is_stored_in_order: bool,
+    /// Positions in the records of the used columns, None for all of them
+    record_columns: Option<Vec<usize>>,
+    real_columns: Vec<usize>,
+    id_col: Option<usize>,
+}
+
+impl RowDecoder {
+    fn new(create_table_query: &CreateTableQuery, used_columns: Option<&[usize]>) -> Self {
+        let column_infos = create_table_query.column_infos();
+        let col_names = column_infos
+            .iter()
+            .map(|c| c.name.clone())
+            .collect::<Vec<_>>();
+
+        // virtual generated columns are computed when read, they are not stored. In a
+        // WITHOUT ROWID table, the primary key columns are stored first.
+        let is_stored = |i: &usize| !create_table_query.columns[*i].is_virtual();
+        let stored_order = if create_table_query.without_rowid {
+            let primary_key = create_table_query
+                .primary_key()
+                .iter()
+                .filter_map(|colname| column_position(&col_names, colname))
+                .collect::<Vec<_>>();
+            primary_key
+                .iter()
+                .copied()
+                .chain((0..col_names.len()).filter(|i| !primary_key.contains(i)))
+                .filter(is_stored)
+                .collect::<Vec<_>>()
+        } else {
+            (0..col_names.len()).filter(is_stored).collect::<Vec<_>>()
+        };
+        let record_columns = used_columns.map(|used_columns| {
+            stored_order
+                .iter()
+                .positions(|i| used_columns.contains(i))
+                .collect::<Vec<_>>()
+        });
+        // sqlite stores the reals without fractional part as integers, to save space
+        let real_columns = column_infos
+            .iter()
+            .positions(|c| c.affinity == Affinity::Real)
+            .collect::<Vec<_>>();
+        RowDecoder {
+            is_stored_in_order: stored_order.iter().copied().eq(0..col_names.len()),
+            stored_order,
+            record_columns,
+            real_columns,
+            id_col: column_infos.iter().position(|c| c.is_rowid_alias),
+            col_names,
+        }
+    }
+
+    fn fix_reals(&self, row: &mut [ColumnContent]) {
+        for &i in &self.real_columns {
+            if let ColumnContent::Int(x) = row[i] {
+                row[i] = ColumnContent::Float(x as f64);
+            }
+        }
+    }
+
+    fn contents_to_row(&self, contents: Vec<ColumnContent>) -> Vec<ColumnContent> {
+        let mut row = if self.is_stored_in_order {
+            contents
+        } else {
+            // virtual columns are NULL for now
+            let mut row = vec![ColumnContent::Null; self.col_names.len()];
+            for (content, &i) in contents.into_iter().zip(&self.stored_order) {
+                row[i] = content;
+            }
+            row
+        };
+        self.fix_reals(&mut row);
+        row
+    }
+
+    fn record_to_row(&self, record: Record) -> Vec<ColumnContent> {
+        let mut row = self.contents_to_row(record.column_contents);
+        // the rowid alias is stored as NULL in the record
+        if let Some(id_col) = self.id_col {
+            row[id_col] = ColumnContent::Int(record.integer_key);
+        }
+        row
+    }
+
+    /// Builds a row from the record of a covering index, with the rowid in its last
+    /// column. `index_positions` are the positions in the table of the indexed columns.
+    fn index_entry_to_row(
+        &self,
+        entry: IndexEntry,
+        index_positions: &[Option<usize>],
+    ) -> Vec<ColumnContent> {
+        let mut row = vec![ColumnContent::Null; self.col_names.len()];
+        for (&position, content) in index_positions.iter().zip(entry.key) {
+            if let Some(i) = position {
+                row[i] = content;
+            }
+        }
+        if let Some(id_col) = self.id_col {
+            row[id_col] = ColumnContent::Int(entry.rowid);
+        }
+        self.fix_reals(&mut row);
+        row
+    }
+}
+
+/// The rows of a table read through a plan, see for_each_table_row. The caller pulls
+/// them one at a time, so a prepared statement can stop between two rows.
+pub(crate) struct TableRows {
+    tablename: String,
+    root_page: u32,
+    file_format: FileFormat,
+    decoder: RowDecoder,
+    source: RowSource,
+}
+
+/// Where TableRows read their rows from
+enum RowSource {
+    /// The records of a table b tree whose rowid is in a range
+    Table(TableCursor),
+    /// The records of a WITHOUT ROWID table, which is stored in an index b tree
+    WithoutRowid(IndexCursor),
+    /// The rowids to look up in the table, in their order. When they come from an index,
+    /// the index is corrupted if one of them is missing.
+    Rowids {
+        rowids: std::vec::IntoIter<i64>,
+        index_name: Option<String>,
+    },
+    /// The rows built from the records of a covering index
+    Rows(std::vec::IntoIter<Vec<ColumnContent>>),
+}
+
+impl TableRows {
+    /// Prepares the reading of the rows of a table through `plan`. Only the
+    /// `used_columns` are read, None for all of them.
+    pub(crate) fn open(
+        file: &mut DatabaseFile,
+        file_format: FileFormat,
+        schema_table: &SchemaTable,
+        create_table_query: &CreateTableQuery,
+        plan: &Plan,
+        used_columns: Option<&[usize]>,
+    ) -> Result<TableRows> {
+        let tablename = create_table_query.tablename.as_str();
+        Self::open_source(
+            file,
+            file_format,
+            schema_table,
+            create_table_query,
+            plan,
+            used_columns,
+        )
+        .map_err(|err| table_error(tablename, err))
+    }
+
+    fn open_source(
+        file: &mut DatabaseFile,
+        file_format: FileFormat,
+        schema_table: &SchemaTable,
+        create_table_query: &CreateTableQuery,
+        plan: &Plan,
+        used_columns: Option<&[usize]>,
+    ) -> Result<TableRows> {
+        let tablename = create_table_query.tablename.as_str();
+        let root_page = table_root_page(schema_table, tablename)?;
+        let decoder = RowDecoder::new(create_table_query, used_columns);
+        let source = match plan {
+            Plan::Scan if create_table_query.without_rowid => RowSource::WithoutRowid(
+                IndexCursor::seek(file, root_page, file_format, Bound::Unbounded)?,
+            ),
+            Plan::Scan => RowSource::Table(TableCursor::seek(
+                file,
+                root_page,
+                file_format,
+                RowidRange::ALL,
+            )?),
+            Plan::RowidLookup(rowid) => RowSource::Rowids {
+                rowids: vec![*rowid].into_iter(),
+                index_name: None,
+            },
+            Plan::RowidRange(rowid_range) => RowSource::Table(TableCursor::seek(
+                file,
+                root_page,
+                file_format,
+                *rowid_range,
+            )?),
+            Plan::IndexLookup {
+                index_record,
+                create_index_query,
+                range,
+                covering,
+            } => {
+                let records = get_index_records(
+                    file,
+                    index_record.rootpage,
+                    file_format,
+                    range,
+                    // with more columns, several rows can share the value of the first one
+                    create_index_query.unique && create_index_query.columns.len() == 1,
+                )?;
+                // the rows are read in the order of their rowid, like with a scan of the
+                // table
+                let entries = records
+                    .into_iter()
+                    .map(IndexEntry::try_from)
+                    .collect::<Result<Vec<_>, _>>()?
+                    .into_iter()
+                    .sorted_by_key(|entry| entry.rowid);
+                match covering {
+                    true => {
+                        let index_positions =
+                            index_positions(create_index_query, &decoder.col_names);
+                        RowSource::Rows(
+                            entries
+                                .map(|entry| decoder.index_entry_to_row(entry, &index_positions))
+                                .collect::<Vec<_>>()
+                                .into_iter(),
+                        )
+                    }
+                    false => RowSource::Rowids {
+                        rowids: entries
+                            .map(|entry| entry.rowid)
+                            .collect::<Vec<_>>()
+                            .into_iter(),
+                        index_name: Some(index_record.name.clone()),
+                    },
+                }
+            }
+        };
+        Ok(TableRows {
+            tablename: tablename.to_string(),
+            root_page,
+            file_format,
+            decoder,
+            source,
+        })
+    }
+
+    /// Reads the next row, None after the last one
+    pub(crate) fn next(&mut self, file: &mut DatabaseFile) -> Result<Option<Vec<ColumnContent>>> {
+        self.read_row(file)
+            .map_err(|err| table_error(&self.tablename, err))
+    }
+
+    fn read_row(&mut self, file: &mut DatabaseFile) -> Result<Option<Vec<ColumnContent>>> {
+        let decoder = &self.decoder;
+        let record_columns = decoder.record_columns.as_deref();
+        match &mut self.source {
+            RowSource::Table(cursor) => Ok(cursor
+                .next(file, record_columns)?
+                .map(|record| decoder.record_to_row(record))),
+            RowSource::WithoutRowid(cursor) => Ok(cursor
+                .next(file)?
+                .map(|record| decoder.contents_to_row(record.column_contents))),
+            RowSource::Rowids { rowids, index_name } => {
+                for rowid in rowids {
+                    match get_record_by_rowid(
+                        file,
+                        self.root_page,
+                        self.file_format,
+                        rowid,
+                        record_columns,
+                    )? {
+                        Some(record) => return Ok(Some(decoder.record_to_row(record))),
+                        None => {
+                            if let Some(index_name) = index_name {
+                                return Err(Error::CorruptDatabase(format!(
+                                    "Corrupted index {}: it points to the rowid {}, which is not in the table {}",
+                                    index_name,
+                                    rowid,
+                                    self.tablename
+                                )));
+                            }
+                        }
+                    }
+                }
+                Ok(None)
+            }
+            RowSource::Rows(rows) => Ok(rows.next()),
+        }
+    }
+}
+
+/// How the rows of a table are read, as decided from the where clause of the query
+enum Plan {
+    /// All the rows, in the order of the b tree
+    Scan,
+    /// The row of a rowid
+    RowidLookup(i64),
+    /// The rows whose rowid is in the range, the other subtrees are skipped
+    RowidRange(RowidRange),
+    /// The rows whose rowids are in the records of an index, in a range of its first
+    /// column. A covering index holds all the columns the query reads: the rows are
+    /// then built from its records, without reading the table.
+    IndexLookup {
+        index_record: SchemaTableRecord,
+        create_index_query: Box<CreateIndexQuery>,
+        range: IndexRange,
+        covering: bool,
+    },
+}
+
+impl Plan {
+    /// Describes the plan like the EXPLAIN QUERY PLAN of sqlite3, with ? for the values
+    fn explain(&self, tablename: &str) -> String {
+        match self {
+            Plan::Scan => format!("SCAN {}", tablename),
+            Plan::RowidLookup(_) => {
+                format!("SEARCH {} USING INTEGER PRIMARY KEY (rowid=?)", tablename)
+            }
+            Plan::RowidRange(rowid_range) => format!(
+                "SEARCH {} USING INTEGER PRIMARY KEY ({})",
+                tablename,
+                [
+                    rowid_range.min.map(|_| "rowid>?"),
+                    rowid_range.max.map(|_| "rowid<?"),
+                ]
+                .into_iter()
+                .flatten()
+                .join(" AND ")
+            ),
+            Plan::IndexLookup {
+                index_record,
+                create_index_query,
+                range,
+                covering,
+            } => {
+                let column = &create_index_query.columns[0].name;
+                let constraint = if range.is_point() {
+                    format!("{}=?", column)
+                } else {
+                    // the NULL lower bound of the ranges without one only skips the NULLs
+                    let has_lower = !matches!(
+                        range.lower,
+                        Bound::Unbounded | Bound::Excluded(ColumnContent::Null)
+                    );
+                    let has_upper = !matches!(range.upper, Bound::Unbounded);
+                    [
+                        has_lower.then(|| format!("{}>?", column)),
+                        has_upper.then(|| format!("{}<?", column)),
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .join(" AND ")
+                };
+                format!(
+                    "SEARCH {} USING {}INDEX {} ({})",
+                    tablename,
+                    if *covering { "COVERING " } else { "" },
+                    index_record.name,
+                    constraint
+                )
+            }
+        }
+    }
+}
+
+/// Positions in the table of the columns of an index, None for its expressions
+fn index_positions(
+    create_index_query: &CreateIndexQuery,
+    col_names: &[String],
+) -> Vec<Option<usize>> {
+    create_index_query
+        .columns
+        .iter()
+        .map(|column| match column.is_expression {
+            true => None,
+            false => column_position(col_names, &column.name),
+        })
+        .collect()
+}
+
+/// Chooses how to read the rows of a table which can satisfy the where clause, see
+/// for_each_table_row. The b tree can only be pruned with the comparisons between a
+/// column and a value which must all be true: the rows read must still be filtered
+/// with the where clause.
+fn plan_table_read(
+    schema_table: &SchemaTable,
+    tablename: &str,
+    create_table_query: &CreateTableQuery,
+    where_clause: Option<&Expr>,
+    used_columns: Option<&[usize]>,
+) -> Plan {
+    // the rows of a WITHOUT ROWID table are in an index b tree, sorted by primary key
+    if create_table_query.without_rowid {
+        return Plan::Scan;
+    }
+    let column_infos = create_table_query.column_infos();
+    let col_names = create_table_query.column_names();
+    let id_col = column_infos.iter().position(|c| c.is_rowid_alias);
+
+    let comparisons = where_clause
+        .map(conjuncts)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|expr| match expr {
+            Expr::Comparison {
+                left,
+                operator,
+                right,
+            } => match (left.as_ref(), right.as_ref()) {
+                (Expr::Column(colname), Expr::Literal(literal)) => {
+                    Some((colname, *operator, literal))
+                }
+                (Expr::Literal(literal), Expr::Column(colname)) => {
+                    Some((colname, operator.flip(), literal))
+                }
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    // comparisons between the rowid alias and integers only need part of the b tree
+    let rowid_conditions = comparisons
+        .iter()
+        .filter_map(|&(colname, operator, literal)| match literal {
+            Literal::Integer(val)
+                if id_col
+                    .is_some_and(|i| col_names[i].to_lowercase() == colname.to_lowercase()) =>
+            {
+                Some((operator, *val))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    if let Some(&(_, rowid)) = rowid_conditions
+        .iter()
+        .find(|(operator, _)| *operator == ComparisonOperator::Eq)
+    {
+        return Plan::RowidLookup(rowid);
+    }
+    if let Some(rowid_range) = rowid_conditions
+        .iter()
+        .filter_map(|&(operator, val)| RowidRange::from_condition(operator, val))
+        .reduce(RowidRange::intersect)
+    {
+        return Plan::RowidRange(rowid_range);
+    }
+
+    // an index on a compared column gives the rowids of the matching rows, with all the
+    // comparisons on its first column as bounds
+    let Some(where_clause) = where_clause else {
+        return Plan::Scan;
+    };
+    let compared_columns = comparisons
+        .iter()
+        .map(|&(colname, operator, _)| (colname.as_str(), operator))
+        .collect::<Vec<_>>();
+    match schema_table.choose_index(tablename, where_clause, &compared_columns) {
+        Some((index_record, create_index_query)) => {
+            let indexed_column = &create_index_query.columns[0].name;
+            // the index was chosen from a comparison on its column
+            let Some(range) = comparisons
+                .iter()
+                .filter(|(colname, _, _)| colname.eq_ignore_ascii_case(indexed_column))
+                .filter_map(|&(_, operator, literal)| {
+                    IndexRange::from_condition(operator, literal.into())
+                })
+                .reduce(IndexRange::intersect)
+            else {
+                return Plan::Scan;
+            };
+            // an index holding all the columns that are read covers the query
+            let index_positions = index_positions(&create_index_query, &col_names);
+            let covering = used_columns.is_some_and(|used_columns| {
+                used_columns
+                    .iter()
+                    .all(|&i| id_col == Some(i) || index_positions.contains(&Some(i)))
+            });
+            // each row found in the index is then searched in the table: like sqlite3, the
+            // table is scanned instead when ANALYZE tells the index selects more than half
+            // of its rows
+            let estimated_rows =
+                schema_table.estimated_rows(&create_index_query, &compared_columns);
+            if !covering
+                && estimated_rows
+                    .zip(schema_table.analyzed_table_rows(tablename))
+                    .is_some_and(|(estimated_rows, nb_rows)| estimated_rows * 2 > nb_rows)
+            {
+                return Plan::Scan;
+            }
+            Plan::IndexLookup {
+                index_record,
+                create_index_query: Box::new(create_index_query),
+                range,
+                covering,
+            }
+        }
+        None => Plan::Scan,
+    }
+}
+
+/// How to read a database, see the options of the command line
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OpenOptions {
+    /// Reads the records whose size does not match the payload of their cell instead
+    /// of failing
+    pub lenient: bool,
+    /// Reads the database as it is when its hot journal can not be read to roll back
+    /// the interrupted transaction
+    pub force: bool,
+    /// What to do with the texts which are not valid in the encoding of the database
+    pub text_decoding: TextDecoding,
+    /// Maps the file in memory instead of reading it with a system call for each cell
+    pub mmap: bool,
+    /// Number of threads reading the subtrees of the root page of a table during a scan
+    /// of the whole table. With 0 or 1, the table is read by the current thread.
+    pub scan_threads: usize,
+}
+
+/// An open database, with its header and its schema
+pub struct Database {
+    file: DatabaseFile,
+    header: DatabaseHeader,
+    schema_table: SchemaTable,
+    /// The options it was opened with, also used for the attached databases
+    options: OpenOptions,
+    /// Databases read through the qualifier of their name, like `other.users`, in the
+    /// order they were attached
+    attached: Vec<(String, Database)>,
+}
+
+impl Database {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Database> {
+        Database::open_with(path, OpenOptions::default())
+    }
+
+    /// Opens the database mapped in memory, for large databases which are only read
+    pub fn open_mmap<P: AsRef<Path>>(path: P) -> Result<Database> {
+        Database::open_with(
+            path,
+            OpenOptions {
+                mmap: true,
+                ..OpenOptions::default()
+            },
+        )
+    }
+
+    /// Opens the database and reads its header and its schema. A file cut short, by an
+    /// interrupted copy for instance, would otherwise fail in the middle of the parsing
+    /// of a record.
+    pub fn open_with<P: AsRef<Path>>(path: P, options: OpenOptions) -> Result<Database> {
+        let file = DatabaseFile::open(path, options.force, options.mmap)?;
+        Database::read(file, options)
+    }
+
+    /// Opens a database from any source of its bytes, like a Cursor on a database
+    /// downloaded or decompressed in memory. Without the -wal and -journal files of a
+    /// database file, the pages are read as they are.
+    pub fn open_from_reader<R: Read + Seek + Send + 'static>(reader: R) -> Result<Database> {
+        Database::open_from_reader_with(reader, OpenOptions::default())
+    }
+
+    /// Same as open_from_reader, the force and mmap options only apply to files
+    pub fn open_from_reader_with<R: Read + Seek + Send + 'static>(
+        reader: R,
+        options: OpenOptions,
+    ) -> Result<Database> {
+        Database::read(DatabaseFile::from_reader(reader)?, options)
+    }
+
+    /// Reads the header and the schema, see open_with
+    fn read(mut file: DatabaseFile, options: OpenOptions) -> Result<Database> {
+        let file_size = file.len()?;
+        if file_size < DATABASE_HEADER_SIZE {
+            return Err(Error::CorruptDatabase(format!(
+                "database file is truncated: expected at least {} bytes, found {}",
+                DATABASE_HEADER_SIZE, file_size
+            )));
+        }
+        // the header is parsed from memory, not with a read of the file for each field
+        let mut header_bytes = [0u8; DATABASE_HEADER_SIZE as usize];
+        file.read_exact(&mut header_bytes)?;
+        let mut header = DatabaseHeader::read(&mut Cursor::new(header_bytes))?;
+        header.lenient_records = options.lenient;
+        header.text_decoding = options.text_decoding;
+        header.file_len = file_size;
+        header.scan_threads = options.scan_threads;
+        let expected_size = header.page_count(file_size) as u64 * header.page_size() as u64;
+        if file_size < expected_size {
+            return Err(Error::CorruptDatabase(format!(
+                "database file is truncated: expected {} bytes, found {}",
+                expected_size, file_size
+            )));
+        }
+
+        let records = get_table_records(&mut file, 1, header.file_format())?;
+        let mut schema_table = SchemaTable::try_from(records)?;
+        // the statistics written by ANALYZE help choosing the indexes, a database without
+        // them is read all the same
+        if let Some(stat1_record) = schema_table.get_schema_record_for_table("sqlite_stat1") {
+            if let Ok(records) =
+                get_table_records(&mut file, stat1_record.rootpage, header.file_format())
+            {
+                schema_table.set_stats(records);
+            }
+        }
+
+        Ok(Database {
+            file,
+            header,
+            schema_table,
+            options,
+            attached: Vec::new(),
+        })
+    }
+
+    /// Another handle on the database, whose header and schema are not read again, to run
+    /// queries from another thread, see DatabaseFile::try_clone. None for a database
+    /// opened from a reader.
+    pub fn try_clone(&self) -> Result<Option<Database>> {
+        let mut attached = Vec::new();
+        for (name, db) in &self.attached {
+            match db.try_clone()? {
+                Some(db) => attached.push((name.clone(), db)),
+                None => return Ok(None),
+            }
+        }
+        Ok(self.file.try_clone()?.map(|file| Database {
+            file,
+            header: self.header.clone(),
+            schema_table: self.schema_table.clone(),
+            options: self.options,
+            attached,
+        }))
+    }
+
+    /// Like ATTACH DATABASE: the tables of the database file are read as `name.table`, or
+    /// by their name alone when the main database and the databases attached before do
+    /// not have a table of this name. The file is opened with the options of this
+    /// database.
+    pub fn attach<P: AsRef<Path>>(&mut self, name: &str, path: P) -> Result<()> {
+        let is_used = ["main", "temp"]
+            .iter()
+            .any(|reserved| reserved.eq_ignore_ascii_case(name))
+            || self
+                .attached
+                .iter()
+                .any(|(attached_name, _)| attached_name.eq_ignore_ascii_case(name));
+        if is_used {
+            return Err(Error::InvalidValue(format!(
+                "database {} is already in use",
+                name
+            )));
+        }
+        let db = Database::open_with(path, self.options)?;
+        self.attached.push((name.to_string(), db));
+        Ok(())
+    }
+
+    /// Whether the schema has a table or a view of this name
+    fn has_table_or_view(&self, name: &str) -> bool {
+        self.schema_table
+            .get_schema_record_for_table(name)
+            .is_some()
+            || self.schema_table.get_schema_record_for_view(name).is_some()
+    }
+
+    /// The database a query reads its table from, through the sub selects of FROM: the
+    /// attached database of its qualifier or, like in sqlite, the first database with a
+    /// table of this name, the main one first. The sub selects of the where clause are
+    /// read from the same database.
+    pub(crate) fn database_of(&mut self, from: &TableSource) -> Result<&mut Database> {
+        let (schema, name) = match from {
+            TableSource::Subquery { query, .. } => return self.database_of(&query.from),
+            TableSource::Table { schema, name } => (schema, name),
+        };
+        match schema {
+            Some(schema) if schema.eq_ignore_ascii_case("main") => Ok(self),
+            Some(schema) => {
+                // like in sqlite, an unknown database is a table which does not exist
+                self.attached
+                    .iter_mut()
+                    .find(|(attached_name, db)| {
+                        attached_name.eq_ignore_ascii_case(schema) && db.has_table_or_view(name)
+                    })
+                    .map(|(_, db)| db)
+                    .ok_or_else(|| Error::NoSuchTable(format!("{}.{}", schema, name)))
+            }
+            None if self.has_table_or_view(name) => Ok(self),
+            None => match self
+                .attached
+                .iter()
+                .position(|(_, db)| db.has_table_or_view(name))
+            {
+                Some(i) => Ok(&mut self.attached[i].1),
+                None => Ok(self),
+            },
+        }
+    }
+
+    pub fn header(&self) -> &DatabaseHeader {
+        &self.header
+    }
+
+    pub fn schema(&self) -> &SchemaTable {
+        &self.schema_table
+    }
+
+    /// Anomalies of the header and indexes which are not supported, found when the
+    /// database was opened. Nothing is printed: the planner skips these indexes. The
+    /// warnings of an attached database start with its name.
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = self.header.warnings();
+        warnings.extend(self.schema_table.warnings());
+        for (name, db) in &self.attached {
+            warnings.extend(
+                db.warnings()
+                    .into_iter()
+                    .map(|warning| format!("{}: {}", name, warning)),
+            );
+        }
+        warnings
+    }
+
+    /// Names of the tables, without the internal tables of sqlite. Like the .tables
+    /// command of sqlite3, the tables of the attached databases follow, qualified with
+    /// the name of their database.
+    pub fn table_names(&self) -> Vec<String> {
+        let mut names = self.schema_table.get_table_names();
+        for (name, db) in &self.attached {
+            names.extend(
+                db.schema_table
+                    .get_table_names()
+                    .into_iter()
+                    .map(|tablename| format!("{}.{}", name, tablename)),
+            );
+        }
+        names
+    }
+
+    /// Tables of the main database, without the internal tables of sqlite like table_names
+    pub fn tables(&self) -> Result<Vec<TableInfo>> {
+        let mut tables = Vec::new();
+        for name in self.schema_table.get_table_names() {
+            let Some(table_record) = self.schema_table.get_schema_record_for_table(&name) else {
+                continue;
+            };
+            let (without_rowid, primary_key) = match table_record.rootpage {
+                0 => (false, Vec::new()),
+                _ => {
+                    let create_table_query = get_create_table_query(&self.schema_table, &name)?;
+                    (
+                        create_table_query.without_rowid,
+                        create_table_query.primary_key(),
+                    )
+                }
+            };
+            tables.push(TableInfo {
+                name,
+                root_page: table_record.rootpage,
+                is_virtual: table_record.rootpage == 0,
+                without_rowid,
+                primary_key,
+            });
+        }
+        Ok(tables)
+    }
+
+    /// Columns of a table, like PRAGMA table_info
+    pub fn columns(&self, tablename: &str) -> Result<Vec<ColumnInfo>> {
+        Ok(get_create_table_query(&self.schema_table, tablename)?.column_infos())
+    }
+
+    /// Indexes of a table, like PRAGMA index_list
+    pub fn indexes(&self, tablename: &str) -> Result<Vec<IndexInfo>> {
+        if self
+            .schema_table
+            .get_schema_record_for_table(tablename)
+            .is_none()
+        {
+            return Err(Error::NoSuchTable(tablename.to_string()));
+        }
+        Ok(self.schema_table.get_index_infos(tablename))
+    }
+
+    /// Runs a SELECT or an EXPLAIN QUERY PLAN
+    pub fn execute(&mut self, sql: &str) -> Result<ResultSet> {
+        self.execute_with_params(sql, &[])
+    }
+
+    /// Same as execute, with the values of the ? and :name placeholders, see
+    /// SelectQuery::bind_parameters
+    pub fn execute_with_params(&mut self, sql: &str, params: &[String]) -> Result<ResultSet> {
+        let _span = debug_span!("execute", sql).entered();
+        let (mut select_query, is_explain) = match parse_sql(sql)? {
+            sql_parser::Statement::Select(select_query) => (select_query, false),
+            sql_parser::Statement::ExplainQueryPlan(select_query) => (select_query, true),
+        };
+        select_query.bind_parameters(params)?;
+
+        match is_explain {
+            true => executor::explain_select(self, &select_query),
+            false => executor::execute_select(self, &select_query),
+        }
+    }
+
+    /// Rows of a SELECT read into a type deriving serde::Deserialize, like
+    /// `db.query_as::<User>("SELECT id, name FROM users")`, see de::from_row
+    pub fn query_as<T: serde::de::DeserializeOwned>(&mut self, sql: &str) -> Result<Vec<T>> {
+        self.execute(sql)?.rows.iter().map(de::from_row).collect()
+    }
+
+    /// Parses a statement to run it with step, and again with other values bound to its
+    /// placeholders
+    pub fn prepare(&mut self, sql: &str) -> Result<Statement<'_>> {
+        Statement::prepare(self, sql)
+    }
+
+    /// Number of pages read since the database was opened, with the ones of the attached
+    /// databases
+    pub fn nb_pages_read(&self) -> usize {
+        self.file.nb_pages_read()
+            + self
+                .attached
+                .iter()
+                .map(|(_, db)| db.nb_pages_read())
+                .sum::<usize>()
+    }
+
+    /// Number of reads of the file since the database was opened, none when it is mapped
+    /// in memory
+    pub fn nb_file_reads(&self) -> usize {
+        self.file.nb_file_reads()
+    }
+
+    /// Number of pages of the freelist, which the database does not use
+    pub fn nb_freelist_pages(&mut self) -> Result<usize> {
+        Ok(Freelist::read(&mut self.file, &self.header)?.nb_pages())
+    }
+
+    /// Free bytes of the pages of each b tree, starting with the schema, then of all of
+    /// them in a last "total" entry
+    pub fn space_stats(&mut self) -> Result<Vec<(String, SpaceStats)>> {
+        let file_format = self.header.file_format();
+        let mut b_trees = vec![("sqlite_schema".to_string(), 1)];
+        b_trees.extend(self.schema_table.get_b_trees());
+
+        let mut stats = Vec::new();
+        for (name, root_page) in b_trees {
+            let mut b_tree_stats = SpaceStats::default();
+            for_each_b_tree_page(&mut self.file, root_page, file_format, &mut |page| {
+                b_tree_stats.add(page.space()?, file_format);
+                Ok(())
+            })?;
+            stats.push((name, b_tree_stats));
+        }
+        let total = stats
+            .iter()
+            .fold(SpaceStats::default(), |total, (_, b_tree_stats)| {
+                total.merge(b_tree_stats)
+            });
+        stats.push(("total".to_string(), total));
+        Ok(stats)
+    }
+
+    /// Opens a blob or a text of a row for streaming, see open_blob
+    pub fn open_blob(
+        &mut self,
+        tablename: &str,
+        rowid: i64,
+        colname: &str,
+    ) -> Result<impl Read + '_> {
+        open_blob(
+            &mut self.file,
+            self.header.file_format(),
+            &self.schema_table,
+            tablename,
+            rowid,
+            colname,
+        )
+    }
+}
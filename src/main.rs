@@ -1,29 +1,9 @@
-mod database_header;
-mod page;
-mod schema_table;
-mod sql_parser;
+use std::io::Cursor;
 
 use anyhow::Result;
-use binrw::BinRead;
 use clap::{Parser, Subcommand};
 use itertools::Itertools;
-use sql_parser::parse_select_command;
-use std::{
-    fs::File,
-    io::{Seek, SeekFrom},
-};
-
-use database_header::DatabaseHeader;
-use page::{
-    BTreeIndexInteriorCell, BTreeIndexLeafCell, BTreeTableLeafCell, PageCellPointerArray,
-    PageHeader, PageType, Record,
-};
-
-use page::BTreeTableInteriorCell;
-
-use crate::{
-    page::ColumnContent, schema_table::SchemaTable, sql_parser::parse_create_table_command,
-};
+use sqlite_starter_rust::{error::Error, page::TextDecoding, Database, OpenOptions};
 
 #[derive(Parser, Clone)]
 #[command(version, about="Custom sqlite", long_about=None )]
@@ -34,6 +14,68 @@ struct Cli {
     #[arg(help = "SQL command to execute")]
     sql_command: Option<String>,
 
+    #[arg(
+        long = "param",
+        help = "Value bound to a ? placeholder, or to :name with the form name=value. Can be repeated"
+    )]
+    params: Vec<String>,
+
+    #[arg(
+        long = "attach",
+        help = "Attaches another database file, whose tables are read as name.table, with the form name=path. Can be repeated"
+    )]
+    attach: Vec<String>,
+
+    #[arg(
+        long = "lenient",
+        help = "Reads the records whose size does not match the payload of their cell instead of failing"
+    )]
+    lenient: bool,
+
+    #[arg(
+        long = "force",
+        help = "Reads the database as it is when its hot journal can not be read to roll back the interrupted transaction"
+    )]
+    force: bool,
+
+    #[arg(
+        long = "text-decoding",
+        default_value = "lossy",
+        help = "What to do with the texts which are not valid in the encoding of the database: lossy replaces the invalid bytes, strict fails, raw reads them as blobs"
+    )]
+    text_decoding: TextDecoding,
+
+    #[arg(
+        long = "count-pages",
+        help = "Prints on stderr the number of pages of the database read by the SQL command"
+    )]
+    count_pages: bool,
+
+    #[arg(
+        long = "mmap",
+        help = "Maps the database in memory instead of reading it with a system call for each cell"
+    )]
+    mmap: bool,
+
+    #[arg(
+        long = "threads",
+        default_value_t = 0,
+        help = "Number of threads reading a table in parallel when it is scanned entirely, 0 or 1 for none"
+    )]
+    threads: usize,
+
+    #[arg(
+        long = "in-memory",
+        help = "Copies the database file in memory and reads it from there, without its -wal and -journal files"
+    )]
+    in_memory: bool,
+
+    #[arg(
+        long = "verbose",
+        help = "Logs on stderr the pages read, the b tree descents, the index probes and the rows read"
+    )]
+    verbose: bool,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
@@ -44,425 +86,120 @@ enum Commands {
     DbInfo,
     #[command(name = ".tables", about = "Prints the table names")]
     Tables,
+    #[command(
+        name = ".stats",
+        about = "Shows the free bytes of the b tree pages of each table and index"
+    )]
+    Stats,
+    #[command(
+        name = ".blob",
+        about = "Writes a blob or a text of a row to a file, without reading it in memory"
+    )]
+    Blob {
+        table: String,
+        rowid: i64,
+        column: String,
+        #[arg(help = "File to write, - for the standard output")]
+        output: String,
+    },
 }
 
-/// Helper function to parse all the information of a table
-/// For the sample.db, we can just read the number of cells in the page header.
-/// However it does not work for more complex databases such as Chinook
-/// (https://github.com/lerocha/chinook-database/releases):
-/// the first page is not a LeafTable but an InteriorTable
-/// In this case, the idea is to traverse the tree until we reach a LeafTable and
-/// then parse the leaf cells
-fn get_table_records(file: &mut File, initial_pos: u64, page_size: u16) -> Result<Vec<Record>> {
-    // initial_pos can be different from current stream position. For ex, on the first page,
-    // this should be called after parsing the db header:
-    // initial_pos is still 0 but file.stream_position() is 100.
-    // For other pages, the page actually start with the page header, so the initial_pos
-    // corresponds to file.stream_position()
-
-    let page_header = PageHeader::read(file)?;
-    let page_cell_pointer_array = PageCellPointerArray::read_args(
-        file,
-        binrw::args! {nb_cells: page_header.number_of_cells.into()},
-    )?;
-
-    let records = match page_header.page_type {
-        PageType::InteriorTable => {
-            let mut records = Vec::new();
-
-            // Here we read the pages corresponding to the pointer array.
-            // sqlite pages start at 1, which is why we have the -1
-            for offset in page_cell_pointer_array.offsets {
-                // offset is relative to start of the page
-                file.seek(SeekFrom::Start(initial_pos + offset as u64))?;
-                let b_tree_table_interior_cell = BTreeTableInteriorCell::read(file)?;
-
-                let page_position =
-                    page_size as u64 * (b_tree_table_interior_cell.left_child_pointer - 1) as u64;
-
-                file.seek(SeekFrom::Start(page_position))?;
-                // traverse the b tree.
-                let child_records = get_table_records(file, page_position, page_size)?;
-                records.extend(child_records);
-            }
-
-            // Important: We need to also add the page referenced by the right_most_pointer
-            let page_position = page_size as u64 * (page_header.right_most_pointer - 1) as u64;
-            file.seek(SeekFrom::Start(page_position))?;
-            let child_records = get_table_records(file, page_position, page_size)?;
-            records.extend(child_records);
-            records
-        }
-        PageType::LeafTable => {
-            // For leaf table, I was tempted to simply read the number_of_cells but
-            // it overestimated the result for the Chinook db
-            // Instead, we can parse the pointer array and look at each individual
-            // cell then check the payload for the CREATE TABLE string.
-            // This seems to work...
-
-            let mut records = Vec::new();
-            for offset in page_cell_pointer_array.offsets {
-                let cell_position = initial_pos + offset as u64;
-                file.seek(SeekFrom::Start(cell_position))?;
-                let b_tree_table_leaf_cell = BTreeTableLeafCell::read(file)?;
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    if cli.verbose {
+        // without time, so that the logs of two runs can be compared
+        tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_writer(std::io::stderr)
+            .without_time()
+            .init();
+    }
 
-                records.push(b_tree_table_leaf_cell.record);
-            }
-            records
-        }
-        _ => anyhow::bail!(
-            "When traversing the b tree, only interior and leaf TABLE pages should be encountered"
-        ),
+    let options = OpenOptions {
+        lenient: cli.lenient,
+        force: cli.force,
+        text_decoding: cli.text_decoding,
+        mmap: cli.mmap,
+        scan_threads: cli.threads,
     };
-
-    Ok(records)
-}
-
-fn get_table_integer_key_record(
-    file: &mut File,
-    initial_pos: u64,
-    page_size: u16,
-    integer_key: u64,
-) -> Result<Record> {
-    let page_header = PageHeader::read(file)?;
-    let page_cell_pointer_array = PageCellPointerArray::read_args(
-        file,
-        binrw::args! {nb_cells: page_header.number_of_cells.into()},
-    )?;
-    match page_header.page_type {
-        PageType::InteriorTable => {
-            let mut page_position = page_size as u64 * (page_header.right_most_pointer - 1) as u64;
-
-            for offset in page_cell_pointer_array.offsets.iter().rev() {
-                // offset is relative to start of the page
-                file.seek(SeekFrom::Start(initial_pos + *offset as u64))?;
-                let b_tree_table_interior_cell = BTreeTableInteriorCell::read(file)?;
-                if integer_key > b_tree_table_interior_cell.integer_key {
-                    break;
-                }
-
-                page_position =
-                    page_size as u64 * (b_tree_table_interior_cell.left_child_pointer - 1) as u64;
-            }
-
-            file.seek(SeekFrom::Start(page_position))?;
-            get_table_integer_key_record(file, page_position, page_size, integer_key)
-        }
-        PageType::LeafTable => {
-            for offset in page_cell_pointer_array.offsets {
-                let cell_position = initial_pos + offset as u64;
-                file.seek(SeekFrom::Start(cell_position))?;
-                let b_tree_table_leaf_cell = BTreeTableLeafCell::read(file)?;
-                let record = b_tree_table_leaf_cell.record;
-
-                if record.integer_key == integer_key {
-                    return Ok(record);
-                }
-            }
-            anyhow::bail!("Could not find record")
-        }
-        _ => anyhow::bail!(
-            "When traversing the b tree, only interior and leaf TABLE pages should be encountered"
-        ),
+    let db = if cli.in_memory {
+        std::fs::read(&cli.filename)
+            .map_err(Error::from)
+            .and_then(|bytes| Database::open_from_reader_with(Cursor::new(bytes), options))
+    } else {
+        Database::open_with(&cli.filename, options)
+    };
+    let mut db = db?;
+    for attach in &cli.attach {
+        let Some((name, path)) = attach.split_once('=') else {
+            anyhow::bail!("Expected name=path for --attach, not {}", attach);
+        };
+        db.attach(name, path)?;
+    }
+    for warning in db.warnings() {
+        eprintln!("warning: {}", warning);
     }
-}
-
-fn get_index_records(
-    file: &mut File,
-    initial_pos: u64,
-    page_size: u16,
-    val: &str,
-) -> Result<Vec<Record>> {
-    let page_header = PageHeader::read(file)?;
-
-    let page_cell_pointer_array = PageCellPointerArray::read_args(
-        file,
-        binrw::args! {nb_cells: page_header.number_of_cells.into()},
-    )?;
-
-    let records = match page_header.page_type {
-        PageType::InteriorIndex => {
-            // TODO: handle case when we have to use right most pointer
-            let mut l = 0;
-            let mut r = page_cell_pointer_array.offsets.len() - 1;
-
-            let mut records = Vec::new();
-
-            let val = val.to_string();
-            while l < r {
-                let mid = l + (r - l) / 2;
-
-                let mid_val = {
-                    file.seek(SeekFrom::Start(
-                        initial_pos + page_cell_pointer_array.offsets[mid] as u64,
-                    ))?;
-                    let b_tree_index_interior_cell = BTreeIndexInteriorCell::read(file)?;
-                    b_tree_index_interior_cell.record.column_contents[0].repr()
-                };
 
-                if mid_val > val {
-                    r = mid;
-                } else if mid_val < val {
-                    l = mid + 1;
-                } else {
-                    break;
+    if let Some(sql_command) = &cli.sql_command {
+        match db.execute_with_params(sql_command, &cli.params) {
+            // the steps are printed as a tree, like in sqlite3
+            Ok(result_set) if result_set.is_explain => {
+                println!("QUERY PLAN");
+                for (i, row) in result_set.rows.iter().enumerate() {
+                    let branch = if i + 1 == result_set.rows.len() {
+                        "`--"
+                    } else {
+                        "|--"
+                    };
+                    println!("{}{}", branch, row[0].repr());
                 }
             }
-            for pos in l..=r {
-                file.seek(SeekFrom::Start(
-                    initial_pos + page_cell_pointer_array.offsets[pos] as u64,
-                ))?;
-                let b_tree_index_interior_cell = BTreeIndexInteriorCell::read(file)?;
-                let pos_val = b_tree_index_interior_cell.record.column_contents[0].repr();
-                if pos_val == val {
-                    records.push(b_tree_index_interior_cell.record);
+            Ok(result_set) => {
+                for row in result_set.rows {
+                    println!("{}", row.values().iter().map(|c| c.repr()).join("|"));
                 }
-
-                let page_position =
-                    page_size as u64 * (b_tree_index_interior_cell.left_child_pointer - 1) as u64;
-
-                file.seek(SeekFrom::Start(page_position))?;
-                // traverse the b tree.
-                let child_records = get_index_records(file, page_position, page_size, &val)?;
-                for child_record in child_records {
-                    if child_record.column_contents[0] == ColumnContent::String(val.clone()) {
-                        records.push(child_record);
-                    }
-                }
-            }
-
-            // handle right most pointer
-            // NOTE: There is probably a more elegant way
-            let page_position = page_size as u64 * (page_header.right_most_pointer - 1) as u64;
-            file.seek(SeekFrom::Start(page_position))?;
-
-            let child_records = get_index_records(file, page_position, page_size, &val)?;
-            for child_record in child_records {
-                if child_record.column_contents[0] == ColumnContent::String(val.clone()) {
-                    records.push(child_record);
+                if cli.count_pages {
+                    eprintln!("pages read: {}", db.nb_pages_read());
                 }
             }
-
-            records
-        }
-        PageType::LeafIndex => {
-            let mut records = Vec::new();
-            for offset in page_cell_pointer_array.offsets {
-                let cell_position = initial_pos + offset as u64;
-                file.seek(SeekFrom::Start(cell_position))?;
-                let b_tree_index_leaf_cell = BTreeIndexLeafCell::read(file)?;
-
-                records.push(b_tree_index_leaf_cell.record);
+            Err(err) => {
+                // mimics the sqlite3 error message
+                eprintln!("{}", err);
+                std::process::exit(1);
             }
-            records
         }
-        _ => anyhow::bail!(
-            "When traversing the b tree, only interior and leaf TABLE pages should be encountered"
-        ),
-    };
-
-    Ok(records)
-}
-
-fn main() -> Result<()> {
-    let cli = Cli::parse();
-
-    // needs the finish keyword to avoid lifetime erros
-    let mut is_sql_command = false;
-    if let Some(sql_command) = &cli.sql_command {
-        is_sql_command = true;
-        match parse_select_command(sql_command) {
-            Ok((_, select_query)) => {
-                let mut file = File::open(&cli.filename)?;
-
-                let db_header = DatabaseHeader::read(&mut file)?;
-
-                let records = get_table_records(&mut file, 0, db_header.page_size)?;
-                let schema_table = SchemaTable::try_from(records)?;
-
-                let table_record = schema_table
-                    .get_schema_record_for_table(&select_query.tablename)
-                    .expect("Could not find table");
-
-                let col_names = match parse_create_table_command(&table_record.sql) {
-                    Ok((_, create_table_query)) => {
-                        assert_eq!(
-                            &create_table_query.tablename.to_lowercase(),
-                            &select_query.tablename.to_lowercase()
-                        );
-                        create_table_query
-                            .columns_and_types
-                            .into_iter()
-                            .map(|c| c[0].clone())
-                            .collect::<Vec<_>>()
-                    }
-                    Err(_) => {
-                        anyhow::bail!("Error parsing SQL command")
-                    }
-                };
-
-                // only look at index if there is a where clause
-                let index_record_and_create_index_query = match select_query.where_clause.clone() {
-                    None => None,
-                    Some(where_clause) => schema_table
-                        .get_schema_index_for_table(&select_query.tablename, &where_clause.0),
-                };
-
-                match index_record_and_create_index_query {
-                    None => {}
-                    Some(x) => {
-                        let (index_record, create_index_query) = x;
-                        let page_position =
-                            db_header.page_size as u64 * (index_record.rootpage - 1) as u64;
-                        file.seek(SeekFrom::Start(page_position))?;
-                        let records = get_index_records(
-                            &mut file,
-                            page_position,
-                            db_header.page_size,
-                            &select_query.where_clause.unwrap().1,
-                        )?;
-
-                        let integer_keys = records
-                            .iter()
-                            .filter_map(|r| match r.column_contents[1] {
-                                ColumnContent::Int(x) => Some(x),
-                                _ => None,
-                            })
-                            .sorted()
-                            .collect::<Vec<_>>();
-
-                        let mut records = Vec::new();
-                        for integer_key in integer_keys {
-                            let page_position =
-                                db_header.page_size as u64 * (table_record.rootpage - 1) as u64;
-                            file.seek(SeekFrom::Start(page_position))?;
-                            records.push(get_table_integer_key_record(
-                                &mut file,
-                                page_position,
-                                db_header.page_size,
-                                integer_key,
-                            )?);
-                        }
-                        let mut kept_cols = Vec::new();
-
-                        let mut id_col = None;
-                        for column in &select_query.columns {
-                            for (i, col) in col_names.iter().enumerate() {
-                                if column.to_lowercase() == col.to_lowercase() {
-                                    kept_cols.push(i);
-                                }
-                                // TODO: make a better paser, this is wrong
-                                if col == "id" {
-                                    id_col = Some(i);
-                                }
-                            }
-                        }
-
-                        for record in records {
-                            let mut cur_recs = Vec::new();
-
-                            for kept_col in &kept_cols {
-                                let mut column_repr = record.column_contents[*kept_col].repr();
-                                if id_col == Some(*kept_col) {
-                                    column_repr = format!("{}", record.integer_key);
-                                }
-                                cur_recs.push(column_repr);
-                            }
-                            println!("{}", cur_recs.join("|"));
-                        }
-
-                        return Ok(());
-                    }
-                }
-
-                let page_position = db_header.page_size as u64 * (table_record.rootpage - 1) as u64;
-                file.seek(SeekFrom::Start(page_position))?;
-                let records = get_table_records(&mut file, page_position, db_header.page_size)?;
-                if select_query.columns.len() == 1
-                    && select_query.columns[0].to_lowercase() == "count(*)"
-                {
-                    println!("{}", records.len());
-                } else {
-                    let mut kept_cols = Vec::new();
-
-                    let mut where_col = None;
-                    let mut where_val = String::from("");
-                    let mut id_col = None;
-                    for column in &select_query.columns {
-                        for (i, col) in col_names.iter().enumerate() {
-                            if column.to_lowercase() == col.to_lowercase() {
-                                kept_cols.push(i);
-                            }
-                            // TODO: make a better paser, this is wrong
-                            if col == "id" {
-                                id_col = Some(i);
-                            }
-                            if let Some(where_clause) = &select_query.where_clause {
-                                if col.to_lowercase() == where_clause.0.to_lowercase() {
-                                    where_val = where_clause.1.clone();
-                                    where_col = Some(i);
-                                }
-                            }
-                        }
-                    }
-
-                    for record in records {
-                        let mut cur_recs = Vec::new();
-                        if let Some(where_col) = where_col {
-                            let mut column_repr = record.column_contents[where_col].repr();
-                            if id_col == Some(where_col) {
-                                column_repr = format!("{}", record.integer_key);
-                            }
-
-                            if where_val != column_repr {
-                                continue;
-                            }
-                        }
-
-                        for kept_col in &kept_cols {
-                            let mut column_repr = record.column_contents[*kept_col].repr();
-                            if id_col == Some(*kept_col) {
-                                column_repr = format!("{}", record.integer_key);
-                            }
-                            cur_recs.push(column_repr);
-                        }
-                        println!("{}", cur_recs.join("|"));
-                    }
-                }
-            }
-            Err(x) => {
-                anyhow::bail!("Error parsing SQL command")
-            }
-        };
-    }
-
-    if is_sql_command {
         return Ok(());
     }
 
-    match &cli.command.expect("Should have a command at this point") {
+    let Some(command) = &cli.command else {
+        anyhow::bail!("Expected an SQL command or one of .dbinfo, .tables, .stats and .blob");
+    };
+    match command {
         Commands::DbInfo => {
-            let mut file = File::open(&cli.filename)?;
-
-            let db_header = DatabaseHeader::read(&mut file)?;
-
-            println!("database page size: {}", db_header.page_size);
-
-            let records = get_table_records(&mut file, 0, db_header.page_size)?;
-            let schema_table = SchemaTable::try_from(records)?;
-            let nb_tables = schema_table.get_nb_tables();
-            println!("number of tables: {}", nb_tables);
+            println!("database page size: {}", db.header().page_size());
+            println!("number of tables: {}", db.schema().get_nb_tables());
+            println!("number of freelist pages: {}", db.nb_freelist_pages()?);
         }
         Commands::Tables => {
-            let mut file = File::open(&cli.filename)?;
-
-            let db_header = DatabaseHeader::read(&mut file)?;
-
-            let records = get_table_records(&mut file, 0, db_header.page_size)?;
-            let schema_table = SchemaTable::try_from(records)?;
-            let table_names = schema_table.get_table_names();
-
-            println!("{}", table_names.join(" "));
+            println!("{}", db.table_names().join(" "));
+        }
+        Commands::Stats => {
+            for (name, b_tree_stats) in db.space_stats()? {
+                println!("{}: {}", name, b_tree_stats);
+            }
+        }
+        Commands::Blob {
+            table,
+            rowid,
+            column,
+            output,
+        } => {
+            let mut blob_reader = db.open_blob(table, *rowid, column)?;
+            if output == "-" {
+                std::io::copy(&mut blob_reader, &mut std::io::stdout().lock())?;
+            } else {
+                std::io::copy(&mut blob_reader, &mut std::fs::File::create(output)?)?;
+            }
         }
     }
     Ok(())
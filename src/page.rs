@@ -1,4 +1,13 @@
-use binrw::{binread, binrw, BinRead, BinResult};
+use std::io::{Cursor, Read, Seek, SeekFrom};
+
+use binrw::{binrw, BinRead, BinResult, BinWrite};
+use tracing::debug;
+
+use crate::{
+    blob::PayloadReader,
+    database_header::DATABASE_HEADER_SIZE,
+    error::{Error, Result},
+};
 
 // https://www.sqlite.org/fileformat.html
 
@@ -13,10 +22,11 @@ pub struct PageHeader {
     /// A zero value for this integer is interpreted as 65536
     pub start_cell_content_area: u16,
     pub number_of_fragmented_free_bytes_in_cell_content_area: u8,
-    /// binrw does not parse this field if the condition is not met, which means we
-    /// don't advance the cursor
+    /// Only interior pages have this field, the header of leaf pages is 4 bytes shorter.
+    /// binrw does not parse it if the condition is not met, which means we don't advance
+    /// the cursor
     #[br(if(page_type == PageType::InteriorTable || page_type == PageType::InteriorIndex))]
-    pub right_most_pointer: u32,
+    pub right_most_pointer: Option<u32>,
 }
 
 /// The page type is coded on a unique byte
@@ -45,7 +55,134 @@ pub struct PageCellPointerArray {
     pub offsets: Vec<u16>,
 }
 
-#[derive(Debug)]
+/// What the database header tells about how to read the pages: the sizes needed to
+/// find a page and to know how much of a payload spills to overflow pages, and the
+/// encoding of the strings
+#[derive(Debug, Clone, Copy)]
+pub struct FileFormat {
+    pub page_size: u32,
+    /// The page size minus the space reserved at the end of each page
+    pub usable_size: u32,
+    pub text_encoding: TextEncoding,
+    /// By default, a record whose header and columns do not take exactly the payload
+    /// size of its cell is an error. Lenient reading accepts it, to get what can be read
+    /// from a database written by a buggy program.
+    pub lenient_records: bool,
+    pub text_decoding: TextDecoding,
+    /// Number of pages of the database, the b trees can not point to other pages
+    pub page_count: u32,
+}
+
+/// What to do with a text which is not valid in the encoding of the database
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum TextDecoding {
+    /// The invalid bytes are replaced by U+FFFD, the way sqlite3 shows them
+    #[default]
+    Lossy,
+    /// The text is an error, to tell a corrupted database from a valid one
+    Strict,
+    /// The text is read as a blob of its bytes
+    Raw,
+}
+
+impl std::str::FromStr for TextDecoding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "lossy" => Ok(TextDecoding::Lossy),
+            "strict" => Ok(TextDecoding::Strict),
+            "raw" => Ok(TextDecoding::Raw),
+            _ => Err(format!(
+                "unknown text decoding {}, expected lossy, strict or raw",
+                s
+            )),
+        }
+    }
+}
+
+/// All the strings of a database share the same encoding
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextEncoding {
+    Utf8,
+    Utf16le,
+    Utf16be,
+}
+
+impl TextEncoding {
+    /// Decodes a string of the record. An odd byte at the end of a UTF-16 string is ignored.
+    fn decode(&self, bytes: &[u8]) -> String {
+        let to_u16 = match self {
+            TextEncoding::Utf8 => return String::from_utf8_lossy(bytes).to_string(),
+            TextEncoding::Utf16le => u16::from_le_bytes,
+            TextEncoding::Utf16be => u16::from_be_bytes,
+        };
+        let units = bytes
+            .chunks_exact(2)
+            .map(|pair| to_u16([pair[0], pair[1]]))
+            .collect::<Vec<_>>();
+        String::from_utf16_lossy(&units)
+    }
+
+    /// Decodes a string of the record, or returns None if it is not valid in the encoding
+    fn try_decode(&self, bytes: &[u8]) -> Option<String> {
+        let to_u16 = match self {
+            TextEncoding::Utf8 => return String::from_utf8(bytes.to_vec()).ok(),
+            TextEncoding::Utf16le => u16::from_le_bytes,
+            TextEncoding::Utf16be => u16::from_be_bytes,
+        };
+        if !bytes.len().is_multiple_of(2) {
+            return None;
+        }
+        let units = bytes
+            .chunks_exact(2)
+            .map(|pair| to_u16([pair[0], pair[1]]))
+            .collect::<Vec<_>>();
+        String::from_utf16(&units).ok()
+    }
+
+    fn encode(&self, string: &str) -> Vec<u8> {
+        let to_bytes = match self {
+            TextEncoding::Utf8 => return string.as_bytes().to_vec(),
+            TextEncoding::Utf16le => u16::to_le_bytes,
+            TextEncoding::Utf16be => u16::to_be_bytes,
+        };
+        string.encode_utf16().flat_map(to_bytes).collect()
+    }
+}
+
+impl FileFormat {
+    /// Number of bytes of the payload of a table leaf cell stored in the cell itself
+    pub fn local_table_payload_size(&self, nb_bytes_payload: u64) -> u64 {
+        let max_local = self.usable_size as u64 - 35;
+        self.local_payload_size(nb_bytes_payload, max_local)
+    }
+
+    /// Same as local_table_payload_size for the cells of index pages, which keep less
+    /// bytes so that an interior page holds at least 4 cells
+    pub fn local_index_payload_size(&self, nb_bytes_payload: u64) -> u64 {
+        let max_local = ((self.usable_size as u64 - 12) * 64) / 255 - 23;
+        self.local_payload_size(nb_bytes_payload, max_local)
+    }
+
+    /// If the payload is bigger than max_local, as much as possible of it spills, while
+    /// keeping at least min_local bytes in the cell and filling the last overflow page
+    fn local_payload_size(&self, nb_bytes_payload: u64, max_local: u64) -> u64 {
+        if nb_bytes_payload <= max_local {
+            return nb_bytes_payload;
+        }
+        let usable_size = self.usable_size as u64;
+        let min_local = ((usable_size - 12) * 32) / 255 - 23;
+        let local = min_local + (nb_bytes_payload - min_local) % (usable_size - 4);
+        if local <= max_local {
+            local
+        } else {
+            min_local
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
 #[binrw]
 #[brw(big)]
 pub struct BTreeTableInteriorCell {
@@ -53,87 +190,502 @@ pub struct BTreeTableInteriorCell {
     /// A varint is between 1 and 9 bytes in length. The varint consists of either zero or more
     /// bytes which have the high-order bit set followed by a single byte with the high-order bit
     /// clear, or nine bytes, whichever is shorter.
-    #[br(parse_with = parse_varint)]
-    pub integer_key: u64,
+    #[br(parse_with = parse_varint, map = |key: u64| key as i64)]
+    #[bw(write_with = write_integer_key)]
+    pub integer_key: i64,
 }
 
-/// NOTE: not fully parsed, still have to figure out how to differentiate
-/// the payload and the 4-byte big-endian integer page number for the
-/// first page of the overflow page list
-/// For now, we will only handle cases without overflow
-#[binread]
-#[derive(Debug)]
+/// The payload of a cell is a record. When it is too big, only its first bytes are in the
+/// cell, followed by the 4-byte big-endian page number of the first overflow page.
+#[binrw]
+#[derive(Debug, PartialEq)]
 #[brw(big)]
+#[br(import_raw(file_format: FileFormat))]
 pub struct BTreeTableLeafCell {
     #[br(parse_with = parse_varint)]
+    #[bw(write_with = write_varint)]
     pub nb_bytes_key_payload_including_overflow: u64,
 
-    #[br(args {
-        nb_bytes_key_payload_including_overflow: nb_bytes_key_payload_including_overflow as usize,
-        with_integer_key: true
-    })]
-    pub record: Record,
-    // initial portion of the payload that does not spill to overflow pages
-    // we suppose there is no overflow
-    // REST not parsed - we suppose there is no overflow
+    #[br(parse_with = parse_varint, map = |key: u64| key as i64)]
+    #[bw(write_with = write_integer_key)]
+    pub integer_key: i64,
+
+    #[br(args(
+        nb_bytes_key_payload_including_overflow,
+        file_format.local_table_payload_size(nb_bytes_key_payload_including_overflow),
+    ))]
+    pub payload: LocalPayload,
 }
 
-#[derive(Debug)]
-#[binread]
+/// Same as BTreeTableLeafCell, but without rowid and with a smaller threshold
+#[binrw]
+#[derive(Debug, PartialEq)]
 #[brw(big)]
+#[br(import_raw(file_format: FileFormat))]
 pub struct BTreeIndexInteriorCell {
     pub left_child_pointer: u32,
     #[br(parse_with = parse_varint)]
+    #[bw(write_with = write_varint)]
     pub nb_bytes_key_payload_including_overflow: u64,
 
-    #[br(args {
-        nb_bytes_key_payload_including_overflow: nb_bytes_key_payload_including_overflow as usize,
-        with_integer_key: false
-    })]
-    pub record: Record,
-    // initial portion of the payload that does not spill to overflow pages
-    // we suppose there is no overflow
-    // REST not parsed - we suppose there is no overflow
+    #[br(args(
+        nb_bytes_key_payload_including_overflow,
+        file_format.local_index_payload_size(nb_bytes_key_payload_including_overflow),
+    ))]
+    pub payload: LocalPayload,
 }
 
-#[binread]
-#[derive(Debug)]
+#[binrw]
+#[derive(Debug, PartialEq)]
 #[brw(big)]
+#[br(import_raw(file_format: FileFormat))]
 pub struct BTreeIndexLeafCell {
     #[br(parse_with = parse_varint)]
+    #[bw(write_with = write_varint)]
     pub nb_bytes_key_payload_including_overflow: u64,
 
-    #[br(args {
-        nb_bytes_key_payload_including_overflow: nb_bytes_key_payload_including_overflow as usize,
-        with_integer_key: false
-    })]
-    pub record: Record,
-    // initial portion of the payload that does not spill to overflow pages
-    // we suppose there is no overflow
-    // REST not parsed - we suppose there is no overflow
+    #[br(args(
+        nb_bytes_key_payload_including_overflow,
+        file_format.local_index_payload_size(nb_bytes_key_payload_including_overflow),
+    ))]
+    pub payload: LocalPayload,
+}
+
+/// The part of a payload stored in its cell
+#[binrw]
+#[derive(Debug, PartialEq)]
+#[brw(big)]
+#[br(import(nb_bytes_payload: u64, local_size: u64))]
+pub struct LocalPayload {
+    /// Written in the cell before the payload, as its size in the cell depends on it
+    #[br(calc = nb_bytes_payload)]
+    #[bw(ignore)]
+    pub nb_bytes_payload: u64,
+    #[br(parse_with = parse_local_payload, args(local_size, local_size < nb_bytes_payload))]
+    pub bytes: Vec<u8>,
+    #[br(if(local_size < nb_bytes_payload))]
+    pub first_overflow_page: Option<u32>,
+}
+
+/// A page read in memory. The cells are parsed from its bytes, only the overflow pages of
+/// their payloads are read from the file.
+#[derive(Debug)]
+pub struct Page {
+    pub page_no: u32,
+    pub header: PageHeader,
+    pub cell_offsets: Vec<u16>,
+    bytes: Vec<u8>,
+    file_format: FileFormat,
+}
+
+impl Page {
+    /// Reads the page, sqlite pages start at 1
+    pub fn read<R: Read + Seek>(
+        reader: &mut R,
+        page_no: u32,
+        file_format: FileFormat,
+    ) -> Result<Self> {
+        let mut bytes = vec![0u8; file_format.page_size as usize];
+        reader
+            .seek(SeekFrom::Start(page_offset(page_no, file_format)))
+            .and_then(|_| reader.read_exact(&mut bytes))
+            .map_err(|err| Error::CorruptPage {
+                page_no,
+                reason: format!("Could not read the page {}: {}", page_no, err),
+            })?;
+        Self::from_bytes(bytes, page_no, file_format)
+    }
+
+    /// The first page begins with the database header, the page header comes after it.
+    /// The cell offsets are still relative to the start of the page.
+    pub fn from_bytes(bytes: Vec<u8>, page_no: u32, file_format: FileFormat) -> Result<Self> {
+        let mut cursor = Cursor::new(&bytes);
+        if page_no == 1 {
+            cursor.set_position(DATABASE_HEADER_SIZE);
+        }
+        let (header, page_cell_pointer_array) = PageHeader::read(&mut cursor)
+            .and_then(|header| {
+                let page_cell_pointer_array = PageCellPointerArray::read_args(
+                    &mut cursor,
+                    binrw::args! {nb_cells: header.number_of_cells.into()},
+                )?;
+                Ok((header, page_cell_pointer_array))
+            })
+            .map_err(|err| located_error(page_no, format!("the page {}", page_no), err))?;
+        debug!(
+            page_no,
+            page_type = ?header.page_type,
+            nb_cells = header.number_of_cells,
+            "page read"
+        );
+        Ok(Page {
+            page_no,
+            header,
+            cell_offsets: page_cell_pointer_array.offsets,
+            bytes,
+            file_format,
+        })
+    }
+
+    pub fn nb_cells(&self) -> usize {
+        self.cell_offsets.len()
+    }
+
+    /// Bytes of a cell, from its start to the reserved space at the end of the page
+    fn cell_bytes(&self, i: usize) -> BinResult<&[u8]> {
+        let offset = self.cell_offsets[i] as usize;
+        let end = self.file_format.usable_size as usize;
+        if offset >= end.min(self.bytes.len()) {
+            return Err(binrw::Error::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "Corrupted page: the cell starts outside of the page",
+            )));
+        }
+        Ok(&self.bytes[offset..end])
+    }
+
+    /// Names the cell in the errors of its parsing, which do not say where they happened
+    fn cell_error(&self, i: usize, err: binrw::Error) -> Error {
+        located_error(
+            self.page_no,
+            format!("the cell {} of the page {}", i, self.page_no),
+            err,
+        )
+    }
+
+    /// Same as cell_error, with the rowid of the row stored in the cell
+    fn row_error(&self, i: usize, rowid: i64, err: binrw::Error) -> Error {
+        located_error(
+            self.page_no,
+            format!(
+                "the cell {} of the page {}, with the rowid {}",
+                i, self.page_no, rowid
+            ),
+            err,
+        )
+    }
+
+    pub fn table_interior_cell(&self, i: usize) -> Result<BTreeTableInteriorCell> {
+        self.cell_bytes(i)
+            .and_then(|bytes| BTreeTableInteriorCell::read(&mut Cursor::new(bytes)))
+            .map_err(|err| self.cell_error(i, err))
+    }
+
+    pub fn table_leaf_cell(&self, i: usize) -> Result<BTreeTableLeafCell> {
+        self.cell_bytes(i)
+            .and_then(|bytes| {
+                BTreeTableLeafCell::read_args(&mut Cursor::new(bytes), self.file_format)
+            })
+            .map_err(|err| self.cell_error(i, err))
+    }
+
+    pub fn index_interior_cell(&self, i: usize) -> Result<BTreeIndexInteriorCell> {
+        self.cell_bytes(i)
+            .and_then(|bytes| {
+                BTreeIndexInteriorCell::read_args(&mut Cursor::new(bytes), self.file_format)
+            })
+            .map_err(|err| self.cell_error(i, err))
+    }
+
+    pub fn index_leaf_cell(&self, i: usize) -> Result<BTreeIndexLeafCell> {
+        self.cell_bytes(i)
+            .and_then(|bytes| {
+                BTreeIndexLeafCell::read_args(&mut Cursor::new(bytes), self.file_format)
+            })
+            .map_err(|err| self.cell_error(i, err))
+    }
+
+    /// Parses the record of a cell, the end of the payload is read from the overflow pages.
+    /// Only the `columns` are decoded, None for all of them: the payload is then read as a
+    /// stream, and the overflow pages after the last of these columns are not read.
+    fn read_record<R: Read + Seek>(
+        &self,
+        reader: &mut R,
+        payload: LocalPayload,
+        columns: Option<&[usize]>,
+    ) -> BinResult<Record> {
+        let nb_bytes_payload = payload.nb_bytes_payload;
+        let args = (
+            self.file_format.text_encoding,
+            self.file_format.text_decoding,
+            columns,
+        );
+        let (record, nb_bytes_read) = match columns {
+            None => {
+                let payload = read_overflow(reader, &payload, self.file_format)?;
+                let mut cursor = Cursor::new(&payload);
+                (Record::read_args(&mut cursor, args)?, cursor.position())
+            }
+            Some(_) => {
+                let mut payload_reader = PayloadReader::new(reader, payload, self.file_format);
+                let record = Record::read_args(&mut payload_reader, args)?;
+                (record, payload_reader.position())
+            }
+        };
+        if !self.file_format.lenient_records && nb_bytes_read != nb_bytes_payload {
+            return Err(binrw::Error::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!(
+                    "Corrupted record: its header and columns take {} bytes but the payload of the cell has {} bytes",
+                    nb_bytes_read, nb_bytes_payload
+                ),
+            )));
+        }
+        Ok(record)
+    }
+
+    /// Record of a table leaf cell, with its rowid. See read_record for `columns`.
+    pub fn table_leaf_record<R: Read + Seek>(
+        &self,
+        reader: &mut R,
+        i: usize,
+        columns: Option<&[usize]>,
+    ) -> Result<Record> {
+        let cell = self.table_leaf_cell(i)?;
+        let record = self
+            .read_record(reader, cell.payload, columns)
+            .map_err(|err| self.row_error(i, cell.integer_key, err))?;
+        Ok(Record {
+            integer_key: cell.integer_key,
+            ..record
+        })
+    }
+
+    /// Record of an index interior cell, which comes after the records of its left child
+    pub fn index_interior_record<R: Read + Seek>(
+        &self,
+        reader: &mut R,
+        i: usize,
+    ) -> Result<Record> {
+        let cell = self.index_interior_cell(i)?;
+        self.read_record(reader, cell.payload, None)
+            .map_err(|err| self.cell_error(i, err))
+    }
+
+    pub fn index_leaf_record<R: Read + Seek>(&self, reader: &mut R, i: usize) -> Result<Record> {
+        let cell = self.index_leaf_cell(i)?;
+        self.read_record(reader, cell.payload, None)
+            .map_err(|err| self.cell_error(i, err))
+    }
+
+    /// Number of bytes of a cell in the page, up to the pointer to its first overflow page
+    fn cell_size(&self, i: usize) -> BinResult<usize> {
+        let mut cursor = Cursor::new(self.cell_bytes(i)?);
+        match self.header.page_type {
+            PageType::InteriorTable => BTreeTableInteriorCell::read(&mut cursor).map(|_| ())?,
+            PageType::LeafTable => {
+                BTreeTableLeafCell::read_args(&mut cursor, self.file_format).map(|_| ())?
+            }
+            PageType::InteriorIndex => {
+                BTreeIndexInteriorCell::read_args(&mut cursor, self.file_format).map(|_| ())?
+            }
+            PageType::LeafIndex => {
+                BTreeIndexLeafCell::read_args(&mut cursor, self.file_format).map(|_| ())?
+            }
+        }
+        Ok(cursor.position() as usize)
+    }
+
+    /// Follows the freeblocks of the page and counts its free bytes. The freeblocks must be
+    /// in the cell content area, sorted, and must not overlap each other or a cell.
+    pub fn space(&self) -> Result<PageSpace> {
+        let usable_size = self.file_format.usable_size as usize;
+        let header_start = if self.page_no == 1 {
+            DATABASE_HEADER_SIZE as usize
+        } else {
+            0
+        };
+        let header_size = match self.header.right_most_pointer {
+            Some(_) => 12,
+            None => 8,
+        };
+        let cell_pointers_end = header_start + header_size + 2 * self.nb_cells();
+        let cell_content_start = match self.header.start_cell_content_area {
+            0 => 65536,
+            start => start as usize,
+        };
+        if cell_content_start < cell_pointers_end || cell_content_start > usable_size {
+            return Err(self.corrupted(format!(
+                "the cell content area starts at {}, outside of the bytes {} to {}",
+                cell_content_start, cell_pointers_end, usable_size
+            )));
+        }
+
+        let cells = (0..self.nb_cells())
+            .map(|i| {
+                let start = self.cell_offsets[i] as usize;
+                self.cell_size(i)
+                    .map(|size| (i, start, start + size))
+                    .map_err(|err| self.cell_error(i, err))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut freeblocks: Vec<Freeblock> = Vec::new();
+        let mut offset = self.header.start_first_freeblock_on_page as usize;
+        while offset != 0 {
+            let previous_end = freeblocks
+                .last()
+                .map_or(cell_content_start, |freeblock| freeblock.end());
+            if offset < previous_end || offset + 4 > usable_size {
+                return Err(self.corrupted(format!(
+                    "the freeblock at {} is not after the bytes {} to {}",
+                    offset, previous_end, usable_size
+                )));
+            }
+            let next = u16::from_be_bytes([self.bytes[offset], self.bytes[offset + 1]]);
+            let size = u16::from_be_bytes([self.bytes[offset + 2], self.bytes[offset + 3]]);
+            let freeblock = Freeblock {
+                offset: offset as u16,
+                size,
+            };
+            if size < 4 || freeblock.end() > usable_size {
+                return Err(self.corrupted(format!(
+                    "the freeblock at {} has a size of {} bytes",
+                    offset, size
+                )));
+            }
+            if let Some((i, _, _)) = cells
+                .iter()
+                .find(|(_, start, end)| *start < freeblock.end() && offset < *end)
+            {
+                return Err(self.corrupted(format!(
+                    "the freeblock at {} overlaps the cell {}",
+                    offset, i
+                )));
+            }
+            freeblocks.push(freeblock);
+            offset = next as usize;
+        }
+
+        Ok(PageSpace {
+            unallocated_bytes: cell_content_start - cell_pointers_end,
+            freeblocks,
+            fragmented_bytes: self
+                .header
+                .number_of_fragmented_free_bytes_in_cell_content_area
+                as usize,
+        })
+    }
+
+    fn corrupted(&self, message: String) -> Error {
+        Error::CorruptPage {
+            page_no: self.page_no,
+            reason: format!("Corrupted page {}: {}", self.page_no, message),
+        }
+    }
 }
 
-#[derive(Debug, BinRead)]
+/// Names the location of the page in the errors of its parsing
+fn located_error(page_no: u32, location: String, mut err: binrw::Error) -> Error {
+    // the errors of the parsers are wrapped with the fields being parsed
+    while let binrw::Error::Backtrace(backtrace) = err {
+        err = *backtrace.error;
+    }
+    Error::CorruptPage {
+        page_no,
+        reason: format!("Could not read {}: {}", location, err),
+    }
+}
+
+/// A block of free bytes in the cell content area of a page. It starts with the 2-byte
+/// offset of the next freeblock, 0 for the last one, followed by its 2-byte size.
+#[derive(Debug, PartialEq)]
+pub struct Freeblock {
+    pub offset: u16,
+    pub size: u16,
+}
+
+impl Freeblock {
+    fn end(&self) -> usize {
+        self.offset as usize + self.size as usize
+    }
+}
+
+/// The free bytes of a page, which the deletions leave behind until the database is
+/// vacuumed
+#[derive(Debug, PartialEq)]
+pub struct PageSpace {
+    /// Bytes between the cell pointer array and the cell content area
+    pub unallocated_bytes: usize,
+    pub freeblocks: Vec<Freeblock>,
+    /// Groups of less than 4 free bytes, too small to be freeblocks
+    pub fragmented_bytes: usize,
+}
+
+impl PageSpace {
+    pub fn freeblock_bytes(&self) -> usize {
+        self.freeblocks
+            .iter()
+            .map(|freeblock| freeblock.size as usize)
+            .sum()
+    }
+
+    pub fn free_bytes(&self) -> usize {
+        self.unallocated_bytes + self.freeblock_bytes() + self.fragmented_bytes
+    }
+}
+
+/// Position of the start of a page in the file
+pub fn page_offset(page_no: u32, file_format: FileFormat) -> u64 {
+    file_format.page_size as u64 * (page_no as u64 - 1)
+}
+
+#[derive(Debug, PartialEq, BinRead, BinWrite)]
 #[brw(big)]
-#[br(import { nb_bytes_key_payload_including_overflow: usize, with_integer_key: bool })]
+#[br(import(text_encoding: TextEncoding, text_decoding: TextDecoding, columns: Option<&[usize]>))]
+#[bw(import_raw(text_encoding: TextEncoding))]
 pub struct Record {
-    #[br(if(with_integer_key))]
-    #[br(parse_with = parse_varint)]
-    pub integer_key: u64,
+    /// The rowid for the records of a table, it is stored in the cell before the record
+    #[brw(ignore)]
+    pub integer_key: i64,
     /// Header consists in a list of ColumnTypes after a varint indicating the size
     #[br(parse_with = parse_varint_with_bytes)]
+    #[bw(write_with = write_varint_with_bytes)]
     pub size_header_varint: (u64, usize),
 
     #[br(parse_with = parse_record_header, args(size_header_varint))]
+    #[bw(write_with = write_record_header)]
     pub column_types: Vec<ColumnType>,
-    /// Payload depends on the column types. Note that we don't handle overflow here
-    #[br(parse_with = parse_record_payload, args(&column_types, nb_bytes_key_payload_including_overflow, size_header_varint.0))]
+    /// Payload depends on the column types. Only the `columns` are decoded, None for all
+    /// of them: the others are skipped and are NULL.
+    #[br(parse_with = parse_record_payload, args(&column_types, text_encoding, text_decoding, columns))]
+    #[bw(write_with = write_record_payload, args(column_types, text_encoding))]
     pub column_contents: Vec<ColumnContent>,
 }
 
-#[binrw]
-#[brw(big)]
-#[derive(Debug, Clone)]
+/// A record of an index b tree, split into the values of the indexed columns and the
+/// rowid of the row in the table, which sqlite appends as the last column
+#[derive(Debug, PartialEq)]
+pub struct IndexEntry {
+    pub key: Vec<ColumnContent>,
+    pub rowid: i64,
+}
+
+impl TryFrom<Record> for IndexEntry {
+    type Error = binrw::Error;
+
+    fn try_from(mut record: Record) -> Result<Self, Self::Error> {
+        // whatever the integer serial type of the rowid, from 0 and 1 stored in the header
+        // to 8 bytes, it is read as an Int
+        match record.column_contents.pop() {
+            Some(ColumnContent::Int(rowid)) => Ok(IndexEntry {
+                key: record.column_contents,
+                rowid,
+            }),
+            last_column => Err(binrw::Error::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!(
+                    "Corrupted index record: it should end with a rowid, not {:?}",
+                    last_column
+                ),
+            ))),
+        }
+    }
+}
+
+/// Serial type of a column, read from the header of a record. It gives the type of the
+/// column content and its number of bytes in the record.
+#[derive(Debug, Clone, PartialEq)]
 pub enum ColumnType {
     Null,
     Int8,
@@ -178,12 +730,50 @@ impl TryFrom<u64> for ColumnType {
     }
 }
 
+impl ColumnType {
+    /// The varint written in the header of a record
+    pub fn serial_type(&self) -> u64 {
+        match self {
+            ColumnType::Null => 0,
+            ColumnType::Int8 => 1,
+            ColumnType::Int16 => 2,
+            ColumnType::Int24 => 3,
+            ColumnType::Int32 => 4,
+            ColumnType::Int48 => 5,
+            ColumnType::Int64 => 6,
+            ColumnType::Float64 => 7,
+            ColumnType::Integer0 => 8,
+            ColumnType::Integer1 => 9,
+            ColumnType::Reserved => 10,
+            ColumnType::Blob(nb_bytes) => nb_bytes * 2 + 12,
+            ColumnType::String(nb_bytes) => nb_bytes * 2 + 13,
+        }
+    }
+
+    /// Number of bytes of the content of the column in the record
+    pub fn nb_bytes(&self) -> u64 {
+        match self {
+            ColumnType::Null
+            | ColumnType::Integer0
+            | ColumnType::Integer1
+            | ColumnType::Reserved => 0,
+            ColumnType::Int8 => 1,
+            ColumnType::Int16 => 2,
+            ColumnType::Int24 => 3,
+            ColumnType::Int32 => 4,
+            ColumnType::Int48 => 6,
+            ColumnType::Int64 | ColumnType::Float64 => 8,
+            ColumnType::Blob(nb_bytes) | ColumnType::String(nb_bytes) => *nb_bytes,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, BinRead)]
 #[br(big)]
 #[br(import { nb_bytes: usize })]
 pub enum ColumnContent {
     Null,
-    Int(u64),
+    Int(i64),
     Float(f64),
     Blob(#[br(count = nb_bytes)] Vec<u8>),
     String(
@@ -198,43 +788,208 @@ impl ColumnContent {
         match self {
             ColumnContent::Null => "".to_string(),
             ColumnContent::Int(x) => format!("{}", x),
-            ColumnContent::Float(x) => format!("{}", x),
-            ColumnContent::Blob(x) => "Blob".to_string(),
+            ColumnContent::Float(x) => format_real(*x),
+            ColumnContent::Blob(x) => format_blob(x),
             ColumnContent::String(x) => x.to_string(),
         }
     }
 }
+
+/// Values bound to the parameters of a statement, see Statement::bind
+impl From<i64> for ColumnContent {
+    fn from(x: i64) -> Self {
+        ColumnContent::Int(x)
+    }
+}
+
+impl From<f64> for ColumnContent {
+    fn from(x: f64) -> Self {
+        ColumnContent::Float(x)
+    }
+}
+
+impl From<&str> for ColumnContent {
+    fn from(x: &str) -> Self {
+        ColumnContent::String(x.to_string())
+    }
+}
+
+impl From<String> for ColumnContent {
+    fn from(x: String) -> Self {
+        ColumnContent::String(x)
+    }
+}
+
+impl From<Vec<u8>> for ColumnContent {
+    fn from(x: Vec<u8>) -> Self {
+        ColumnContent::Blob(x)
+    }
+}
+
+/// None is NULL
+impl<T: Into<ColumnContent>> From<Option<T>> for ColumnContent {
+    fn from(x: Option<T>) -> Self {
+        x.map_or(ColumnContent::Null, Into::into)
+    }
+}
+
+/// Formats a blob as a literal, the way the quote() function of sqlite does: X'0A1B'
+pub fn format_blob(bytes: &[u8]) -> String {
+    let digits = bytes
+        .iter()
+        .map(|byte| format!("{:02X}", byte))
+        .collect::<String>();
+    format!("X'{}'", digits)
+}
+
+/// Formats a real like sqlite: 15 significant digits, always with a decimal point,
+/// and an exponent for very large or very small numbers
+pub fn format_real(x: f64) -> String {
+    if x.is_infinite() {
+        return if x > 0.0 { "Inf" } else { "-Inf" }.to_string();
+    }
+    if x == 0.0 {
+        return "0.0".to_string();
+    }
+    // rounding to 15 significant digits may change the exponent, so it is read
+    // from the rounded number
+    let scientific = format!("{:.14e}", x);
+    let (mantissa, exponent) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+    let exponent = exponent.parse::<i32>().unwrap_or_default();
+
+    if !(-4..15).contains(&exponent) {
+        let mantissa = mantissa.trim_end_matches('0');
+        let mantissa = mantissa
+            .strip_suffix('.')
+            .map_or(mantissa.to_string(), |m| format!("{}.0", m));
+        let sign = if exponent < 0 { '-' } else { '+' };
+        return format!("{}e{}{:02}", mantissa, sign, exponent.abs());
+    }
+
+    let decimal = format!("{:.*}", (14 - exponent) as usize, x);
+    if !decimal.contains('.') {
+        return format!("{}.0", decimal);
+    }
+    let decimal = decimal.trim_end_matches('0');
+    match decimal.strip_suffix('.') {
+        Some(integer) => format!("{}.0", integer),
+        None => decimal.to_string(),
+    }
+}
+
 /// Helper function to parse varint fields
 #[binrw::parser(reader, endian)]
 fn parse_varint() -> BinResult<u64> {
-    let mut result = 0u64;
-    for shift in 0..9u64 {
-        let byte = u8::read_options(reader, endian, ())?;
-        result <<= 7;
+    let (result, _) = parse_varint_with_bytes(reader, endian, ())?;
+    Ok(result)
+}
 
-        result |= (byte & 0x7F) as u64;
-        if (byte & 0x80) == 0 {
-            break;
+/// Reads a varint and returns it with its number of bytes. The first 8 bytes give 7 bits
+/// each, and the ninth byte, if any, gives all of its 8 bits.
+pub fn read_varint<R: Read>(reader: &mut R) -> std::io::Result<(u64, usize)> {
+    let mut byte = [0u8];
+    let mut result = 0u64;
+    for bytes_read in 1..=8 {
+        reader.read_exact(&mut byte)?;
+        result = (result << 7) | (byte[0] & 0x7F) as u64;
+        if (byte[0] & 0x80) == 0 {
+            return Ok((result, bytes_read));
         }
     }
-    Ok(result)
+    reader.read_exact(&mut byte)?;
+    Ok(((result << 8) | byte[0] as u64, 9))
 }
 
-#[binrw::parser(reader, endian)]
+/// Parses a varint and returns it with its number of bytes, see read_varint
+#[binrw::parser(reader)]
 fn parse_varint_with_bytes() -> BinResult<(u64, usize)> {
-    let mut result = 0u64;
-    let mut bytes_read = 0;
-    for shift in 0..9u64 {
-        let byte = u8::read_options(reader, endian, ())?;
-        bytes_read += 1;
-        result <<= 7;
-
-        result |= (byte & 0x7F) as u64;
-        if (byte & 0x80) == 0 {
-            break;
+    Ok(read_varint(reader)?)
+}
+
+/// Encodes a varint the way sqlite does, see parse_varint_with_bytes
+fn encode_varint(value: u64) -> Vec<u8> {
+    if value > 0x00FF_FFFF_FFFF_FFFF {
+        let mut bytes = (0..8)
+            .map(|i| ((value >> (8 + 7 * (7 - i))) & 0x7F) as u8 | 0x80)
+            .collect::<Vec<_>>();
+        bytes.push(value as u8);
+        return bytes;
+    }
+    let mut bytes = vec![(value & 0x7F) as u8];
+    let mut rest = value >> 7;
+    while rest > 0 {
+        bytes.insert(0, (rest & 0x7F) as u8 | 0x80);
+        rest >>= 7;
+    }
+    bytes
+}
+
+#[binrw::writer(writer)]
+fn write_varint(value: &u64) -> BinResult<()> {
+    writer.write_all(&encode_varint(*value))?;
+    Ok(())
+}
+
+/// Writes the varint of a value read with parse_varint_with_bytes, its number of bytes
+/// follows from the value
+#[binrw::writer(writer, endian)]
+fn write_varint_with_bytes(varint: &(u64, usize)) -> BinResult<()> {
+    write_varint(&varint.0, writer, endian, ())
+}
+
+/// Negative rowids are stored as the varint of their two's complement
+#[binrw::writer(writer, endian)]
+fn write_integer_key(integer_key: &i64) -> BinResult<()> {
+    write_varint(&(*integer_key as u64), writer, endian, ())
+}
+
+/// Reads the bytes of a payload which are in the cell. If some bytes spill, they are
+/// followed by the number of the first overflow page.
+#[binrw::parser(reader)]
+fn parse_local_payload(local_size: u64, has_overflow: bool) -> BinResult<Vec<u8>> {
+    // the cell must end before the space reserved at the end of the page, which is also
+    // the end of the bytes of the cell
+    let position = reader.stream_position()?;
+    let cell_end = reader.seek(SeekFrom::End(0))?;
+    reader.seek(SeekFrom::Start(position))?;
+    if position + local_size + if has_overflow { 4 } else { 0 } > cell_end {
+        return Err(binrw::Error::Io(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "Corrupted cell: it extends into the reserved space at the end of the page",
+        )));
+    }
+
+    let mut bytes = vec![0u8; local_size as usize];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Reads the whole payload of a cell. Each overflow page starts with the number of the
+/// next one, 0 for the last one, followed by the content.
+fn read_overflow<R: Read + Seek>(
+    reader: &mut R,
+    payload: &LocalPayload,
+    file_format: FileFormat,
+) -> BinResult<Vec<u8>> {
+    let nb_bytes_payload = payload.nb_bytes_payload as usize;
+    let mut bytes = payload.bytes.clone();
+    let mut next_page = payload.first_overflow_page.unwrap_or_default();
+    while bytes.len() < nb_bytes_payload {
+        if next_page == 0 {
+            return Err(binrw::Error::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "The overflow pages end before the end of the payload",
+            )));
         }
+        reader.seek(SeekFrom::Start(page_offset(next_page, file_format)))?;
+        next_page = u32::read_be(reader)?;
+
+        let nb_bytes = (nb_bytes_payload - bytes.len()).min(file_format.usable_size as usize - 4);
+        let start = bytes.len();
+        bytes.resize(start + nb_bytes, 0);
+        reader.read_exact(&mut bytes[start..])?;
     }
-    Ok((result, bytes_read))
+    Ok(bytes)
 }
 
 #[binrw::parser(reader, endian)]
@@ -253,101 +1008,181 @@ fn parse_record_header(size_header_varint: (u64, usize)) -> BinResult<Vec<Column
     Ok(records_type)
 }
 
-/// TODO: handle page overflow
-#[binrw::parser(reader, endian)]
+/// Writes the serial types of the columns, after the size of the header
+#[binrw::writer(writer)]
+fn write_record_header(column_types: &Vec<ColumnType>) -> BinResult<()> {
+    for column_type in column_types {
+        writer.write_all(&encode_varint(column_type.serial_type()))?;
+    }
+    Ok(())
+}
+
+/// Writes the column contents with the number of bytes of their serial type. The
+/// integers are truncated to it, like in parse_record_payload they are sign-extended.
+#[binrw::writer(writer)]
+fn write_record_payload(
+    column_contents: &Vec<ColumnContent>,
+    column_types: &Vec<ColumnType>,
+    text_encoding: TextEncoding,
+) -> BinResult<()> {
+    for (column_type, column_content) in column_types.iter().zip(column_contents) {
+        match (column_type, column_content) {
+            (ColumnType::Null, ColumnContent::Null)
+            | (ColumnType::Integer0, ColumnContent::Int(0))
+            | (ColumnType::Integer1, ColumnContent::Int(1)) => {}
+            (ColumnType::Int8, ColumnContent::Int(x)) => writer.write_all(&x.to_be_bytes()[7..])?,
+            (ColumnType::Int16, ColumnContent::Int(x)) => {
+                writer.write_all(&x.to_be_bytes()[6..])?
+            }
+            (ColumnType::Int24, ColumnContent::Int(x)) => {
+                writer.write_all(&x.to_be_bytes()[5..])?
+            }
+            (ColumnType::Int32, ColumnContent::Int(x)) => {
+                writer.write_all(&x.to_be_bytes()[4..])?
+            }
+            (ColumnType::Int48, ColumnContent::Int(x)) => {
+                writer.write_all(&x.to_be_bytes()[2..])?
+            }
+            (ColumnType::Int64, ColumnContent::Int(x)) => writer.write_all(&x.to_be_bytes())?,
+            (ColumnType::Float64, ColumnContent::Float(x)) => writer.write_all(&x.to_be_bytes())?,
+            (ColumnType::Blob(_), ColumnContent::Blob(bytes)) => writer.write_all(bytes)?,
+            (ColumnType::String(_), ColumnContent::String(string)) => {
+                writer.write_all(&text_encoding.encode(string))?
+            }
+            _ => {
+                return Err(binrw::Error::Io(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!(
+                        "Could not write {:?} as a column of type {:?}",
+                        column_content, column_type
+                    ),
+                )))
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Converts a big-endian two's complement integer of at most 8 bytes, such as the
+/// 3 bytes of an Int24, by padding it with its sign
+fn be_bytes_to_i64(bytes: &[u8]) -> i64 {
+    let is_negative = bytes.first().is_some_and(|byte| byte & 0x80 != 0);
+    let mut buf = if is_negative { [0xFF; 8] } else { [0; 8] };
+    buf[8 - bytes.len()..].copy_from_slice(bytes);
+    i64::from_be_bytes(buf)
+}
+
+/// Reads the bytes of a blob or a text. Their number comes from the record header, which
+/// can be corrupted: the buffer grows with the bytes read instead of being allocated
+/// with this number first.
+fn read_column_bytes<R: Read>(reader: &mut R, nb_bytes: u64) -> BinResult<Vec<u8>> {
+    let mut buf = Vec::with_capacity(nb_bytes.min(1 << 16) as usize);
+    reader.take(nb_bytes).read_to_end(&mut buf)?;
+    if buf.len() as u64 != nb_bytes {
+        return Err(binrw::Error::Io(std::io::Error::new(
+            std::io::ErrorKind::UnexpectedEof,
+            format!(
+                "Corrupted record: a column of {} bytes goes past the end of the payload",
+                nb_bytes
+            ),
+        )));
+    }
+    Ok(buf)
+}
+
+/// The columns of a record. The bytes they take are checked against the payload of the
+/// cell by Page::read_record.
+#[binrw::parser(reader)]
 fn parse_record_payload(
     column_types: &[ColumnType],
-    nb_bytes_key_payload_including_overflow: usize,
-    header_size: u64,
+    text_encoding: TextEncoding,
+    text_decoding: TextDecoding,
+    columns: Option<&[usize]>,
 ) -> BinResult<Vec<ColumnContent>> {
-    // TODO: Could be used for overflow.
-    // let P = nb_bytes_key_payload_including_overflow;
-    // let U = page_size - reserved_space;
-    // let X = U - 35;
-    //
-    // let M = ((U - 12) * 32) / 255 - 23;
-    // let K = if P < M { P } else { M + ((P - M) % (U - 4)) };
-
-    let mut nb_bytes_parsed = header_size;
-
     let mut column_contents = Vec::new();
-    for column_type in column_types {
+    for (i, column_type) in column_types.iter().enumerate() {
+        // the record header gives the size of the columns, which are skipped without
+        // being read. The PayloadReader of a cell does not seek past its payload.
+        if columns.is_some_and(|columns| !columns.contains(&i)) {
+            reader.seek(SeekFrom::Current(column_type.nb_bytes() as i64))?;
+            column_contents.push(ColumnContent::Null);
+            continue;
+        }
         let column_content = match column_type {
             ColumnType::Null => ColumnContent::Null,
             ColumnType::Int8 => {
                 let mut buf = [0u8; 1];
                 reader.read_exact(&mut buf)?;
-                let val = u8::from_be_bytes(buf);
-                nb_bytes_parsed += buf.len() as u64;
-                ColumnContent::Int(val as u64)
+                let val = i8::from_be_bytes(buf);
+                ColumnContent::Int(val as i64)
             }
             ColumnType::Int16 => {
                 let mut buf = [0u8; 2];
                 reader.read_exact(&mut buf)?;
-                let val = u16::from_be_bytes(buf);
-                nb_bytes_parsed += buf.len() as u64;
-                ColumnContent::Int(val as u64)
+                let val = i16::from_be_bytes(buf);
+                ColumnContent::Int(val as i64)
             }
             ColumnType::Int24 => {
                 let mut buf = [0u8; 3];
                 reader.read_exact(&mut buf)?;
-                let val: u32 = ((buf[0] as u32) << 16) + ((buf[1] as u32) << 8) + (buf[2] as u32);
-                nb_bytes_parsed += buf.len() as u64;
-                ColumnContent::Int(val as u64)
+                ColumnContent::Int(be_bytes_to_i64(&buf))
             }
             ColumnType::Int32 => {
                 let mut buf = [0u8; 4];
                 reader.read_exact(&mut buf)?;
-                let val = u32::from_be_bytes(buf);
-                nb_bytes_parsed += buf.len() as u64;
-                ColumnContent::Int(val as u64)
+                let val = i32::from_be_bytes(buf);
+                ColumnContent::Int(val as i64)
             }
             ColumnType::Int48 => {
                 let mut buf = [0u8; 6];
                 reader.read_exact(&mut buf)?;
-                let val: u64 = ((buf[0] as u64) << 40)
-                    + ((buf[1] as u64) << 32)
-                    + ((buf[2] as u64) << 24)
-                    + ((buf[3] as u64) << 16)
-                    + ((buf[4] as u64) << 8)
-                    + (buf[5] as u64);
-                nb_bytes_parsed += buf.len() as u64;
-                ColumnContent::Int(val)
+                ColumnContent::Int(be_bytes_to_i64(&buf))
             }
             ColumnType::Int64 => {
                 let mut buf = [0u8; 8];
                 reader.read_exact(&mut buf)?;
-                let val = u64::from_be_bytes(buf);
-                nb_bytes_parsed += buf.len() as u64;
+                let val = i64::from_be_bytes(buf);
                 ColumnContent::Int(val)
             }
             ColumnType::Float64 => {
                 let mut buf = [0u8; 8];
                 reader.read_exact(&mut buf)?;
                 let val = f64::from_be_bytes(buf);
-                nb_bytes_parsed += buf.len() as u64;
                 ColumnContent::Float(val)
             }
             ColumnType::Integer0 => ColumnContent::Int(0),
             ColumnType::Integer1 => ColumnContent::Int(1),
-            ColumnType::Reserved => todo!(),
+            ColumnType::Reserved => {
+                return Err(binrw::Error::Io(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!(
+                        "Corrupted record: the column {} has a reserved serial type",
+                        i
+                    ),
+                )))
+            }
             ColumnType::Blob(x) => {
-                let mut buf = vec![0u8; *x as usize];
-                reader.read_exact(&mut buf)?;
-                nb_bytes_parsed += buf.len() as u64;
+                let buf = read_column_bytes(reader, *x)?;
                 ColumnContent::Blob(buf)
             }
             ColumnType::String(x) => {
-                // For some reason, sometimes the string size is completely overestimated
-                // There must be a problem with my varint
-                let bufsize = *x as usize;
-                let mut buf = vec![0u8; bufsize];
-
-                reader.read_exact(&mut buf)?;
-                let val = String::from_utf8_lossy(&buf);
-                nb_bytes_parsed += buf.len() as u64;
-
-                ColumnContent::String(val.to_string())
+                let buf = read_column_bytes(reader, *x)?;
+                match (text_decoding, text_encoding.try_decode(&buf)) {
+                    (_, Some(string)) => ColumnContent::String(string),
+                    (TextDecoding::Lossy, None) => {
+                        ColumnContent::String(text_encoding.decode(&buf))
+                    }
+                    (TextDecoding::Strict, None) => {
+                        return Err(binrw::Error::Io(std::io::Error::new(
+                            std::io::ErrorKind::InvalidData,
+                            format!(
+                                "Invalid {:?} text in the column {} of the record",
+                                text_encoding, i
+                            ),
+                        )))
+                    }
+                    (TextDecoding::Raw, None) => ColumnContent::Blob(buf),
+                }
             }
         };
         column_contents.push(column_content);
@@ -355,3 +1190,536 @@ fn parse_record_payload(
 
     Ok(column_contents)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use binrw::Endian;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn decode_varint(bytes: &[u8]) -> (u64, usize) {
+        parse_varint_with_bytes(&mut Cursor::new(bytes), Endian::Big, ()).unwrap()
+    }
+
+    #[test]
+    fn varint_round_trip() {
+        for value in [
+            0,
+            1,
+            127,
+            128,
+            16383,
+            16384,
+            0x00FF_FFFF_FFFF_FFFF,
+            0x0100_0000_0000_0000,
+            i64::MAX as u64,
+            u64::MAX - 1,
+            u64::MAX,
+        ] {
+            let bytes = encode_varint(value);
+            assert!(bytes.len() <= 9);
+            assert_eq!(decode_varint(&bytes), (value, bytes.len()), "{}", value);
+        }
+    }
+
+    /// Writes a cell and reads it back
+    fn round_trip<T, A>(value: &T, args: A) -> T
+    where
+        T: for<'a> BinRead<Args<'a> = A> + for<'a> BinWrite<Args<'a> = ()>,
+    {
+        let mut cursor = Cursor::new(Vec::new());
+        value.write_be(&mut cursor).unwrap();
+        cursor.set_position(0);
+        T::read_be_args(&mut cursor, args).unwrap()
+    }
+
+    fn record_round_trip(record: &Record, text_encoding: TextEncoding) -> Record {
+        let mut cursor = Cursor::new(Vec::new());
+        record.write_args(&mut cursor, text_encoding).unwrap();
+        cursor.set_position(0);
+        Record::read_args(&mut cursor, (text_encoding, TextDecoding::Lossy, None)).unwrap()
+    }
+
+    /// Builds a record from its columns, the size of the header includes its own varint
+    fn record(columns: Vec<(ColumnType, ColumnContent)>) -> Record {
+        let (column_types, column_contents): (Vec<_>, Vec<_>) = columns.into_iter().unzip();
+        let nb_bytes_types = column_types
+            .iter()
+            .map(|column_type| encode_varint(column_type.serial_type()).len())
+            .sum::<usize>();
+        let mut size_header = nb_bytes_types + 1;
+        while nb_bytes_types + encode_varint(size_header as u64).len() != size_header {
+            size_header = nb_bytes_types + encode_varint(size_header as u64).len();
+        }
+        Record {
+            integer_key: 0,
+            size_header_varint: (size_header as u64, size_header - nb_bytes_types),
+            column_types,
+            column_contents,
+        }
+    }
+
+    #[test]
+    fn records_round_trip() {
+        let string = |s: &str| ColumnContent::String(s.to_string());
+        // the reserved serial types 10 and 11 are not used by sqlite
+        let corpus = vec![
+            vec![],
+            vec![(ColumnType::Null, ColumnContent::Null)],
+            vec![
+                (ColumnType::Int8, ColumnContent::Int(-128)),
+                (ColumnType::Int16, ColumnContent::Int(32767)),
+                (ColumnType::Int24, ColumnContent::Int(-8388608)),
+                (ColumnType::Int32, ColumnContent::Int(-1)),
+                (ColumnType::Int48, ColumnContent::Int(1704067200000)),
+                (ColumnType::Int64, ColumnContent::Int(i64::MIN)),
+                (ColumnType::Integer0, ColumnContent::Int(0)),
+                (ColumnType::Integer1, ColumnContent::Int(1)),
+            ],
+            vec![
+                (ColumnType::Float64, ColumnContent::Float(-0.5)),
+                (ColumnType::Float64, ColumnContent::Float(f64::MAX)),
+            ],
+            vec![
+                (ColumnType::Blob(0), ColumnContent::Blob(vec![])),
+                (
+                    ColumnType::Blob(3),
+                    ColumnContent::Blob(vec![0, 0xAB, 0xFF]),
+                ),
+                (ColumnType::String(0), string("")),
+                (ColumnType::String(5), string("apple")),
+            ],
+            // the serial type of a long string takes several bytes
+            vec![(ColumnType::String(1000), string(&"a".repeat(1000)))],
+            // and so does the header of a record with many columns
+            vec![(ColumnType::Integer1, ColumnContent::Int(1)); 200],
+        ];
+        for columns in corpus {
+            let record = record(columns);
+            assert_eq!(
+                record_round_trip(&record, TextEncoding::Utf8),
+                record,
+                "{:?}",
+                record
+            );
+        }
+
+        for text_encoding in [TextEncoding::Utf16le, TextEncoding::Utf16be] {
+            let record = record(vec![(ColumnType::String(6), string("épi"))]);
+            assert_eq!(record_round_trip(&record, text_encoding), record);
+        }
+    }
+
+    #[test]
+    fn only_the_projected_columns_are_decoded() {
+        let record = record(vec![
+            (ColumnType::Int16, ColumnContent::Int(300)),
+            (ColumnType::Blob(3), ColumnContent::Blob(vec![1, 2, 3])),
+            (
+                ColumnType::String(2),
+                ColumnContent::String("hi".to_string()),
+            ),
+        ]);
+        let mut cursor = Cursor::new(Vec::new());
+        record.write_args(&mut cursor, TextEncoding::Utf8).unwrap();
+        let nb_bytes = cursor.position();
+        cursor.set_position(0);
+        let args = (TextEncoding::Utf8, TextDecoding::Lossy, Some(&[0, 2][..]));
+        let projected = Record::read_args(&mut cursor, args).unwrap();
+        assert_eq!(
+            projected.column_contents,
+            vec![
+                ColumnContent::Int(300),
+                ColumnContent::Null,
+                ColumnContent::String("hi".to_string())
+            ]
+        );
+        assert_eq!(projected.column_types, record.column_types);
+        assert_eq!(cursor.position(), nb_bytes);
+    }
+
+    #[test]
+    fn skipped_columns_past_the_payload_are_errors() {
+        // the blob is said to have 50 bytes, the payload ends after 2 of them
+        let bytes = vec![3, 1, 112, 42, 1, 2];
+        let payload = LocalPayload {
+            nb_bytes_payload: bytes.len() as u64,
+            bytes,
+            first_overflow_page: None,
+        };
+        let mut file = Cursor::new(Vec::new());
+        let mut payload_reader = PayloadReader::new(&mut file, payload, FILE_FORMAT);
+        let args = (TextEncoding::Utf8, TextDecoding::Lossy, Some(&[0][..]));
+        let err = Record::read_args(&mut payload_reader, args).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("its columns end after its payload"));
+    }
+
+    #[test]
+    fn records_with_contents_not_matching_their_types_are_not_written() {
+        let record = record(vec![(ColumnType::Int8, ColumnContent::Float(1.5))]);
+        let mut cursor = Cursor::new(Vec::new());
+        assert!(record.write_args(&mut cursor, TextEncoding::Utf8).is_err());
+    }
+
+    #[test]
+    fn cells_round_trip() {
+        let table_interior_cell = BTreeTableInteriorCell {
+            left_child_pointer: 3,
+            integer_key: -1,
+        };
+        assert_eq!(round_trip(&table_interior_cell, ()), table_interior_cell);
+
+        let payload = |nb_bytes_payload: u64, local_size: u64| LocalPayload {
+            nb_bytes_payload,
+            bytes: (0..local_size).map(|i| i as u8).collect(),
+            first_overflow_page: (local_size < nb_bytes_payload).then_some(7),
+        };
+        // with 512 bytes pages, a payload of 1000 bytes spills to an overflow page
+        for nb_bytes_payload in [10, 1000] {
+            let local_table = FILE_FORMAT.local_table_payload_size(nb_bytes_payload);
+            let local_index = FILE_FORMAT.local_index_payload_size(nb_bytes_payload);
+
+            let table_leaf_cell = BTreeTableLeafCell {
+                nb_bytes_key_payload_including_overflow: nb_bytes_payload,
+                integer_key: 1 << 60,
+                payload: payload(nb_bytes_payload, local_table),
+            };
+            assert_eq!(round_trip(&table_leaf_cell, FILE_FORMAT), table_leaf_cell);
+
+            let index_interior_cell = BTreeIndexInteriorCell {
+                left_child_pointer: 12,
+                nb_bytes_key_payload_including_overflow: nb_bytes_payload,
+                payload: payload(nb_bytes_payload, local_index),
+            };
+            assert_eq!(
+                round_trip(&index_interior_cell, FILE_FORMAT),
+                index_interior_cell
+            );
+
+            let index_leaf_cell = BTreeIndexLeafCell {
+                nb_bytes_key_payload_including_overflow: nb_bytes_payload,
+                payload: payload(nb_bytes_payload, local_index),
+            };
+            assert_eq!(round_trip(&index_leaf_cell, FILE_FORMAT), index_leaf_cell);
+        }
+    }
+
+    #[test]
+    fn nine_byte_varints_from_sqlite() {
+        // rowids of a table leaf page written by sqlite3
+        let rowid_minus_one = [0xff; 9];
+        assert_eq!(decode_varint(&rowid_minus_one), (u64::MAX, 9));
+        let rowid_i64_max = [0xbf, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff];
+        assert_eq!(decode_varint(&rowid_i64_max), (i64::MAX as u64, 9));
+        let rowid_two_pow_56 = [0x80, 0xc0, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x00];
+        assert_eq!(decode_varint(&rowid_two_pow_56), (1 << 56, 9));
+    }
+
+    #[test]
+    fn int24_values() {
+        assert_eq!(be_bytes_to_i64(&[0x00, 0x80, 0x00]), 32768);
+        assert_eq!(be_bytes_to_i64(&[0x12, 0x34, 0x56]), 0x123456);
+        assert_eq!(be_bytes_to_i64(&[0x7F, 0xFF, 0xFF]), 8388607);
+        assert_eq!(be_bytes_to_i64(&[0xFF, 0xFF, 0xFF]), -1);
+        assert_eq!(be_bytes_to_i64(&[0x80, 0x00, 0x00]), -8388608);
+    }
+
+    #[test]
+    fn int48_values() {
+        // 2024-01-01 00:00:00 UTC in milliseconds
+        assert_eq!(
+            be_bytes_to_i64(&[0x01, 0x8C, 0xC2, 0x51, 0xF4, 0x00]),
+            1704067200000
+        );
+        assert_eq!(
+            be_bytes_to_i64(&[0x00, 0x00, 0x80, 0x00, 0x00, 0x00]),
+            2147483648
+        );
+        assert_eq!(
+            be_bytes_to_i64(&[0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]),
+            140737488355327
+        );
+        assert_eq!(
+            be_bytes_to_i64(&[0xFF, 0xFF, 0x7F, 0xFF, 0xFF, 0xFF]),
+            -2147483649
+        );
+        assert_eq!(
+            be_bytes_to_i64(&[0x80, 0x00, 0x00, 0x00, 0x00, 0x00]),
+            -140737488355328
+        );
+    }
+
+    #[test]
+    fn utf16_strings() {
+        // "é🚀": U+00E9 then the surrogate pair D83D DE80
+        let le = [0xE9, 0x00, 0x3D, 0xD8, 0x80, 0xDE];
+        let be = [0x00, 0xE9, 0xD8, 0x3D, 0xDE, 0x80];
+        assert_eq!(TextEncoding::Utf16le.decode(&le), "é🚀");
+        assert_eq!(TextEncoding::Utf16be.decode(&be), "é🚀");
+        // the last byte of an odd-length string is ignored
+        assert_eq!(TextEncoding::Utf16le.decode(&[0x61, 0x00, 0x62]), "a");
+        assert_eq!(TextEncoding::Utf16be.decode(&[0x00]), "");
+    }
+
+    const FILE_FORMAT: FileFormat = FileFormat {
+        page_size: 512,
+        usable_size: 512,
+        text_encoding: TextEncoding::Utf8,
+        lenient_records: false,
+        text_decoding: TextDecoding::Lossy,
+        page_count: 1,
+    };
+
+    /// A table leaf page with a single cell: rowid 7 and the record (42, 'hello')
+    fn leaf_table_page(header_offset: usize, cell_offset: u16) -> Vec<u8> {
+        let cell = [
+            9, // payload size
+            7, // rowid
+            3, 1, 0x17, // record header: its size, an Int8 and a 5 bytes string
+            42, b'h', b'e', b'l', b'l', b'o',
+        ];
+        let mut bytes = vec![0u8; 512];
+        let [hi, lo] = cell_offset.to_be_bytes();
+        bytes[header_offset..header_offset + 10]
+            .copy_from_slice(&[13, 0, 0, 0, 1, hi, lo, 0, hi, lo]);
+        let start = 512 - cell.len();
+        bytes[start..].copy_from_slice(&cell);
+        bytes
+    }
+
+    #[test]
+    fn cells_of_an_in_memory_page() {
+        let page = Page::from_bytes(leaf_table_page(0, 501), 2, FILE_FORMAT).unwrap();
+        assert_eq!(page.header.page_type, PageType::LeafTable);
+        assert_eq!(page.nb_cells(), 1);
+        assert_eq!(page.table_leaf_cell(0).unwrap().integer_key, 7);
+        // the payload has no overflow, the file is not read
+        let record = page
+            .table_leaf_record(&mut Cursor::new(vec![]), 0, None)
+            .unwrap();
+        assert_eq!(record.integer_key, 7);
+        assert_eq!(
+            record.column_contents,
+            vec![
+                ColumnContent::Int(42),
+                ColumnContent::String("hello".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn first_page_starts_with_the_database_header() {
+        let bytes = leaf_table_page(DATABASE_HEADER_SIZE as usize, 501);
+        let page = Page::from_bytes(bytes, 1, FILE_FORMAT).unwrap();
+        assert_eq!(page.cell_offsets, vec![501]);
+        assert_eq!(page.table_leaf_cell(0).unwrap().integer_key, 7);
+    }
+
+    #[test]
+    fn cells_out_of_the_page_are_errors() {
+        let page = Page::from_bytes(leaf_table_page(0, 600), 2, FILE_FORMAT).unwrap();
+        assert_eq!(
+            page.table_leaf_cell(0).unwrap_err().to_string(),
+            "Could not read the cell 0 of the page 2: Corrupted page: the cell starts outside of the page"
+        );
+
+        // the last 8 bytes of the page are reserved, the cell overlaps them
+        let file_format = FileFormat {
+            usable_size: 504,
+            ..FILE_FORMAT
+        };
+        let page = Page::from_bytes(leaf_table_page(0, 501), 2, file_format).unwrap();
+        assert!(page.table_leaf_cell(0).is_err());
+    }
+
+    #[test]
+    fn only_interior_page_headers_have_a_right_most_pointer() {
+        for (page_type, expected_len, right_most_pointer) in [
+            (2, 12, Some(0x0102_0304)),
+            (5, 12, Some(0x0102_0304)),
+            (10, 8, None),
+            (13, 8, None),
+        ] {
+            let bytes = [page_type, 0, 0, 0, 1, 0x01, 0xF0, 0, 1, 2, 3, 4];
+            let mut cursor = Cursor::new(&bytes);
+            let header = PageHeader::read(&mut cursor).unwrap();
+            assert_eq!(cursor.position(), expected_len);
+            assert_eq!(header.right_most_pointer, right_most_pointer);
+            assert_eq!(header.number_of_cells, 1);
+        }
+    }
+
+    #[test]
+    fn index_entries_end_with_the_rowid() {
+        let read_entry = |bytes: &[u8]| {
+            let record = Record::read_args(
+                &mut Cursor::new(bytes),
+                (TextEncoding::Utf8, TextDecoding::Lossy, None),
+            )
+            .unwrap();
+            IndexEntry::try_from(record)
+        };
+
+        // ('b', 1): the rowid 1 has no content, its serial type is 9
+        assert_eq!(
+            read_entry(&[3, 15, 9, b'b']).unwrap(),
+            IndexEntry {
+                key: vec![ColumnContent::String("b".to_string())],
+                rowid: 1
+            }
+        );
+        // (NULL, 'b', 65536): the rowid is a 3 bytes integer
+        assert_eq!(
+            read_entry(&[4, 0, 15, 3, b'b', 1, 0, 0]).unwrap(),
+            IndexEntry {
+                key: vec![ColumnContent::Null, ColumnContent::String("b".to_string())],
+                rowid: 65536
+            }
+        );
+        assert!(read_entry(&[2, 15, b'b']).is_err());
+    }
+
+    #[test]
+    fn freeblocks_of_a_page() {
+        // the cell starts at 501, the cell content area at 480 with 2 freeblocks before it
+        let page_with_freeblocks = |freeblocks: &[(u16, u16, u16)]| {
+            let mut bytes = leaf_table_page(0, 501);
+            bytes[1..3].copy_from_slice(&freeblocks[0].0.to_be_bytes());
+            bytes[5..7].copy_from_slice(&480u16.to_be_bytes());
+            bytes[7] = 3;
+            for &(offset, next, size) in freeblocks {
+                let offset = offset as usize;
+                bytes[offset..offset + 2].copy_from_slice(&next.to_be_bytes());
+                bytes[offset + 2..offset + 4].copy_from_slice(&size.to_be_bytes());
+            }
+            Page::from_bytes(bytes, 2, FILE_FORMAT).unwrap()
+        };
+
+        let space = page_with_freeblocks(&[(480, 490, 8), (490, 0, 6)])
+            .space()
+            .unwrap();
+        assert_eq!(space.unallocated_bytes, 470);
+        assert_eq!(
+            space.freeblocks,
+            vec![
+                Freeblock {
+                    offset: 480,
+                    size: 8
+                },
+                Freeblock {
+                    offset: 490,
+                    size: 6
+                }
+            ]
+        );
+        assert_eq!(space.fragmented_bytes, 3);
+        assert_eq!(space.free_bytes(), 487);
+
+        let error = |freeblocks| {
+            page_with_freeblocks(freeblocks)
+                .space()
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            error(&[(480, 496, 8), (496, 0, 6)]),
+            "Corrupted page 2: the freeblock at 496 overlaps the cell 0"
+        );
+        assert_eq!(
+            error(&[(490, 480, 6), (480, 0, 8)]),
+            "Corrupted page 2: the freeblock at 480 is not after the bytes 496 to 512"
+        );
+        assert_eq!(
+            error(&[(480, 0, 2)]),
+            "Corrupted page 2: the freeblock at 480 has a size of 2 bytes"
+        );
+    }
+
+    #[test]
+    fn varint_stops_after_nine_bytes() {
+        // the high bit of the ninth byte does not continue the varint
+        let bytes = [0xff; 12];
+        assert_eq!(decode_varint(&bytes), (u64::MAX, 9));
+    }
+
+    #[test]
+    fn corrupted_record_headers_are_errors() {
+        let read = |bytes: &[u8]| {
+            let args = (TextEncoding::Utf8, TextDecoding::Lossy, None);
+            Record::read_args(&mut Cursor::new(bytes), args)
+        };
+        // the serial type 10 is reserved
+        assert!(read(&[2, 10]).is_err());
+        // a blob of 2^62 bytes in a record of 11 bytes is not allocated
+        let mut bytes = vec![10];
+        bytes.extend(encode_varint((1 << 63) + 12));
+        assert!(read(&bytes).is_err());
+    }
+
+    /// A column of a serial type, with a value of this type
+    fn any_column(
+        text_encoding: TextEncoding,
+    ) -> impl Strategy<Value = (ColumnType, ColumnContent)> {
+        use ColumnContent::Int;
+        prop_oneof![
+            Just((ColumnType::Null, ColumnContent::Null)),
+            any::<i8>().prop_map(|x| (ColumnType::Int8, Int(x.into()))),
+            any::<i16>().prop_map(|x| (ColumnType::Int16, Int(x.into()))),
+            (-(1i64 << 23)..1 << 23).prop_map(|x| (ColumnType::Int24, Int(x))),
+            any::<i32>().prop_map(|x| (ColumnType::Int32, Int(x.into()))),
+            (-(1i64 << 47)..1 << 47).prop_map(|x| (ColumnType::Int48, Int(x))),
+            any::<i64>().prop_map(|x| (ColumnType::Int64, Int(x))),
+            // a NaN is not equal to itself
+            any::<f64>()
+                .prop_filter("NaN", |x| !x.is_nan())
+                .prop_map(|x| (ColumnType::Float64, ColumnContent::Float(x))),
+            Just((ColumnType::Integer0, Int(0))),
+            Just((ColumnType::Integer1, Int(1))),
+            proptest::collection::vec(any::<u8>(), 0..300).prop_map(|bytes| (
+                ColumnType::Blob(bytes.len() as u64),
+                ColumnContent::Blob(bytes)
+            )),
+            ".{0,100}".prop_map(move |s| (
+                ColumnType::String(text_encoding.encode(&s).len() as u64),
+                ColumnContent::String(s)
+            )),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn varints_round_trip(value in any::<u64>()) {
+            let bytes = encode_varint(value);
+            prop_assert!(bytes.len() <= 9);
+            prop_assert_eq!(decode_varint(&bytes), (value, bytes.len()));
+        }
+
+        #[test]
+        fn random_records_round_trip(
+            (text_encoding, columns) in prop_oneof![
+                Just(TextEncoding::Utf8),
+                Just(TextEncoding::Utf16le),
+                Just(TextEncoding::Utf16be),
+            ]
+            .prop_flat_map(|text_encoding| {
+                (Just(text_encoding), proptest::collection::vec(any_column(text_encoding), 0..20))
+            })
+        ) {
+            let record = record(columns);
+            prop_assert_eq!(record_round_trip(&record, text_encoding), record);
+        }
+
+        /// Like the fuzz targets, with fewer and shorter inputs
+        #[test]
+        fn arbitrary_bytes_are_read_or_rejected(bytes in proptest::collection::vec(any::<u8>(), 0..100)) {
+            let args = (TextEncoding::Utf16le, TextDecoding::Strict, None);
+            let _ = Record::read_args(&mut Cursor::new(&bytes), args);
+            let _ = PageHeader::read(&mut Cursor::new(&bytes));
+        }
+    }
+}
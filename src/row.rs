@@ -0,0 +1,189 @@
+use std::{ops::Index, sync::Arc};
+
+use crate::{
+    error::{ConversionError, Error, Result},
+    expression::{column_position, is_true},
+    page::ColumnContent,
+};
+
+/// Names of the rowid, which can be selected through the INTEGER PRIMARY KEY column
+const ROWID_NAMES: [&str; 3] = ["rowid", "oid", "_rowid_"];
+
+/// A row of a result set, with the names of its columns. The values are read by name
+/// with their Rust type, like `row.get::<String>("name")`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    columns: Arc<[String]>,
+    /// Position of the column which holds the rowid, the INTEGER PRIMARY KEY of the table
+    rowid_column: Option<usize>,
+    values: Vec<ColumnContent>,
+}
+
+impl Row {
+    pub fn new(
+        columns: Arc<[String]>,
+        rowid_column: Option<usize>,
+        values: Vec<ColumnContent>,
+    ) -> Self {
+        Row {
+            columns,
+            rowid_column,
+            values,
+        }
+    }
+
+    /// Value of the column `name`, converted to `T`. Like in sqlite, the names are not
+    /// case sensitive, and rowid, oid and _rowid_ are the INTEGER PRIMARY KEY column
+    /// when the table has one.
+    pub fn get<T: FromColumnContent>(&self, name: &str) -> Result<T> {
+        let position = column_position(&self.columns, name).or_else(|| {
+            ROWID_NAMES
+                .iter()
+                .any(|rowid_name| rowid_name.eq_ignore_ascii_case(name))
+                .then_some(self.rowid_column)
+                .flatten()
+        });
+        match position {
+            Some(i) => T::from_column_content(&self.values[i]).map_err(
+                |ConversionError { expected, found }| Error::TypeMismatch {
+                    column: name.to_string(),
+                    expected,
+                    found,
+                },
+            ),
+            None => Err(Error::NoSuchColumn(name.to_string())),
+        }
+    }
+
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    pub fn values(&self) -> &[ColumnContent] {
+        &self.values
+    }
+
+    pub fn into_values(self) -> Vec<ColumnContent> {
+        self.values
+    }
+}
+
+impl Index<usize> for Row {
+    type Output = ColumnContent;
+
+    fn index(&self, i: usize) -> &ColumnContent {
+        &self.values[i]
+    }
+}
+
+/// Conversion of a value to the type asked to Row::get: the TryFrom conversions, and a
+/// NULL read as None for an Option.
+pub trait FromColumnContent: Sized {
+    fn from_column_content(value: &ColumnContent) -> Result<Self, ConversionError>;
+}
+
+/// Type of a value for the error messages, like the typeof() function of sqlite
+pub(crate) fn type_name(value: &ColumnContent) -> &'static str {
+    match value {
+        ColumnContent::Null => "null",
+        ColumnContent::Int(_) => "integer",
+        ColumnContent::Float(_) => "real",
+        ColumnContent::String(_) => "text",
+        ColumnContent::Blob(_) => "blob",
+    }
+}
+
+fn mismatch<T>(value: &ColumnContent, expected: &'static str) -> Result<T, ConversionError> {
+    Err(ConversionError {
+        expected,
+        found: type_name(value),
+    })
+}
+
+impl TryFrom<&ColumnContent> for i64 {
+    type Error = ConversionError;
+
+    fn try_from(value: &ColumnContent) -> Result<Self, ConversionError> {
+        match value {
+            ColumnContent::Int(x) => Ok(*x),
+            value => mismatch(value, "integer"),
+        }
+    }
+}
+
+/// An integer is read as a real, like with sqlite3_column_double
+impl TryFrom<&ColumnContent> for f64 {
+    type Error = ConversionError;
+
+    fn try_from(value: &ColumnContent) -> Result<Self, ConversionError> {
+        match value {
+            ColumnContent::Float(x) => Ok(*x),
+            ColumnContent::Int(x) => Ok(*x as f64),
+            value => mismatch(value, "real"),
+        }
+    }
+}
+
+impl TryFrom<&ColumnContent> for String {
+    type Error = ConversionError;
+
+    fn try_from(value: &ColumnContent) -> Result<Self, ConversionError> {
+        match value {
+            ColumnContent::String(x) => Ok(x.clone()),
+            value => mismatch(value, "text"),
+        }
+    }
+}
+
+impl TryFrom<&ColumnContent> for Vec<u8> {
+    type Error = ConversionError;
+
+    fn try_from(value: &ColumnContent) -> Result<Self, ConversionError> {
+        match value {
+            ColumnContent::Blob(x) => Ok(x.clone()),
+            value => mismatch(value, "blob"),
+        }
+    }
+}
+
+/// The truth value of sqlite, like in a where clause: zero is false, and a text is
+/// converted to a number first. A NULL is neither true nor false.
+impl TryFrom<&ColumnContent> for bool {
+    type Error = ConversionError;
+
+    fn try_from(value: &ColumnContent) -> Result<Self, ConversionError> {
+        match value {
+            ColumnContent::Null => mismatch(value, "boolean"),
+            value => Ok(is_true(value)),
+        }
+    }
+}
+
+macro_rules! from_column_content {
+    ($($type:ty),*) => {
+        $(
+            impl FromColumnContent for $type {
+                fn from_column_content(value: &ColumnContent) -> Result<Self, ConversionError> {
+                    Self::try_from(value)
+                }
+            }
+        )*
+    };
+}
+
+from_column_content!(i64, f64, String, Vec<u8>, bool);
+
+impl<T: FromColumnContent> FromColumnContent for Option<T> {
+    fn from_column_content(value: &ColumnContent) -> Result<Self, ConversionError> {
+        match value {
+            ColumnContent::Null => Ok(None),
+            value => T::from_column_content(value).map(Some),
+        }
+    }
+}
+
+impl FromColumnContent for ColumnContent {
+    fn from_column_content(value: &ColumnContent) -> Result<Self, ConversionError> {
+        Ok(value.clone())
+    }
+}
@@ -1,16 +1,26 @@
 use crate::{
-    page::{ColumnContent, Record},
-    sql_parser::{parse_create_index_command, CreateIndexQuery},
+    conjuncts,
+    error::{ConversionError, Error, Result},
+    page::Record,
+    row::FromColumnContent,
+    sql_parser::{
+        parse_create_index_command, parse_create_table_command, ComparisonOperator,
+        CreateIndexQuery, Expr, IndexedColumn,
+    },
 };
 
 /// https://sqlite.org/schematab.html
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SchemaTable {
     records: Vec<SchemaTableRecord>,
+    /// Rows of sqlite_stat1, empty if the database was not analyzed
+    stats: Vec<Stat1Record>,
 }
 
 impl SchemaTable {
+    /// Like the .dbinfo command of sqlite3, internal tables such as sqlite_sequence are
+    /// counted. They are only hidden from the .tables command.
     pub fn get_nb_tables(&self) -> usize {
         self.records.iter().filter(|s| s.coltype == "table").count()
     }
@@ -24,7 +34,41 @@ impl SchemaTable {
             .collect()
     }
 
+    /// Views are only listed by .schema, not by .tables
+    pub fn get_view_names(&self) -> Vec<String> {
+        self.records
+            .iter()
+            .filter(|s| s.coltype == "view")
+            .map(|s| s.name.to_string())
+            .collect()
+    }
+
+    /// Names and root pages of the tables and indexes. Views and virtual tables have no
+    /// b tree.
+    pub fn get_b_trees(&self) -> Vec<(String, u32)> {
+        self.records
+            .iter()
+            .filter(|s| s.rootpage != 0)
+            .map(|s| (s.name.to_string(), s.rootpage))
+            .collect()
+    }
+
     pub fn get_schema_record_for_table(&self, name: &str) -> Option<SchemaTableRecord> {
+        // The schema table does not describe itself, so we build its record by hand.
+        // sqlite_schema is an alias of sqlite_master.
+        if ["sqlite_master", "sqlite_schema"].contains(&name.to_lowercase().as_str()) {
+            return Some(SchemaTableRecord {
+                coltype: "table".to_string(),
+                name: name.to_string(),
+                tbl_name: name.to_string(),
+                rootpage: 1,
+                sql: Some(format!(
+                    "CREATE TABLE {} (type text, name text, tbl_name text, rootpage integer, sql text)",
+                    name
+                )),
+            });
+        }
+
         self.records.iter().find_map(|s| {
             if s.coltype == "table" && s.name.to_lowercase() == name.to_lowercase() {
                 Some(s.clone())
@@ -33,37 +77,235 @@ impl SchemaTable {
             }
         })
     }
-    pub fn get_schema_index_for_table(
+    pub fn get_schema_record_for_view(&self, name: &str) -> Option<SchemaTableRecord> {
+        self.records
+            .iter()
+            .find(|s| s.coltype == "view" && s.name.to_lowercase() == name.to_lowercase())
+            .cloned()
+    }
+
+    /// Indexes of a table, in the order of the schema, with their parsed CREATE INDEX.
+    /// The columns of an autoindex, which has no sql, come from the constraints of the
+    /// CREATE TABLE statement. An index that can not be parsed is skipped, see warnings.
+    pub fn get_indexes_for_table(
+        &self,
+        tablename: &str,
+    ) -> Vec<(SchemaTableRecord, CreateIndexQuery)> {
+        self.records
+            .iter()
+            .filter(|s| s.coltype == "index" && s.tbl_name.eq_ignore_ascii_case(tablename))
+            .filter_map(|s| {
+                let create_index_query = match &s.sql {
+                    None => self.get_autoindex_query(s)?,
+                    Some(sql) => parse_index_sql(sql)?,
+                };
+                Some((s.clone(), create_index_query))
+            })
+            .collect()
+    }
+
+    /// The indexes whose CREATE INDEX can not be parsed. They are never used, the
+    /// queries scan their table instead.
+    pub fn warnings(&self) -> Vec<String> {
+        self.records
+            .iter()
+            .filter(|s| s.coltype == "index")
+            .filter_map(|s| {
+                let sql = s.sql.as_ref()?;
+                parse_index_sql(sql)
+                    .is_none()
+                    .then(|| format!("index {} is not supported: {}", s.name, sql))
+            })
+            .collect()
+    }
+
+    /// Indexes of the table which a lookup can use: the records of an index are sorted by
+    /// its first column first, so any index starting with a column of the table can be
+    /// used, whatever its other columns. The lookup compares the values as they are, in
+    /// ascending order: a NOCASE index is sorted differently.
+    /// A partial index does not contain the other rows: it is only used if its condition
+    /// is one of the conditions joined by AND in the where clause of the query.
+    pub fn get_usable_indexes_for_table(
         &self,
         tablename: &str,
-        colname: &str,
+        where_clause: &Expr,
+    ) -> Vec<(SchemaTableRecord, CreateIndexQuery)> {
+        self.get_indexes_for_table(tablename)
+            .into_iter()
+            .filter(|(_, create_index_query)| {
+                create_index_query.columns.first().is_some_and(|first| {
+                    !first.is_expression && !first.descending && first.collation.is_none()
+                }) && create_index_query
+                    .where_clause
+                    .as_ref()
+                    .is_none_or(|condition| conjuncts(where_clause).contains(&condition))
+            })
+            .collect()
+    }
+
+    /// Rebuilds the CREATE INDEX of an index sqlite created for a PRIMARY KEY or UNIQUE
+    /// constraint, from the constraints of its table
+    fn get_autoindex_query(&self, index_record: &SchemaTableRecord) -> Option<CreateIndexQuery> {
+        let n = index_record.autoindex_constraint()?;
+        let table_record = self.get_schema_record_for_table(&index_record.tbl_name)?;
+        let (_, create_table_query) =
+            parse_create_table_command(table_record.sql.as_deref()?).ok()?;
+        let columns = create_table_query
+            .autoindex_columns()
+            .into_iter()
+            .nth(n.checked_sub(1)?)?;
+        Some(CreateIndexQuery {
+            indexname: index_record.name.clone(),
+            unique: true,
+            tablename: index_record.tbl_name.clone(),
+            columns,
+            where_clause: None,
+        })
+    }
+
+    /// Description of the indexes of a table, see get_indexes_for_table
+    pub fn get_index_infos(&self, tablename: &str) -> Vec<IndexInfo> {
+        self.get_indexes_for_table(tablename)
+            .into_iter()
+            .map(|(s, create_index_query)| IndexInfo {
+                name: s.name,
+                columns: create_index_query.columns,
+                unique: create_index_query.unique,
+                partial: create_index_query.where_clause.is_some(),
+                is_autoindex: s.sql.is_none(),
+            })
+            .collect()
+    }
+
+    /// Chooses the index which should select the fewest rows, from the comparisons
+    /// between a column and a value which must all be true. From the best to the worst:
+    /// a unique index whose columns are all equal to values, which selects at most one
+    /// row, an index whose first column is equal to a value, then an index whose first
+    /// column is in a range. When ANALYZE gave statistics for all these indexes, the one
+    /// expected to read the fewest rows is chosen instead. Ties go to the first index of
+    /// the schema.
+    /// None if no index can be used: the table is then scanned.
+    pub fn choose_index(
+        &self,
+        tablename: &str,
+        where_clause: &Expr,
+        comparisons: &[(&str, ComparisonOperator)],
     ) -> Option<(SchemaTableRecord, CreateIndexQuery)> {
-        self.records.iter().find_map(|s| {
-            if s.coltype == "index" {
-                let (_, create_index_query) = parse_create_index_command(&s.sql).ok().unzip();
-                match create_index_query {
-                    None => None,
-                    Some(create_index_query) => {
-                        if create_index_query.tablename == tablename
-                            && create_index_query.colname == colname
-                        {
-                            Some((s.clone(), create_index_query))
-                        } else {
-                            None
-                        }
-                    }
+        let candidates = self
+            .get_usable_indexes_for_table(tablename, where_clause)
+            .into_iter()
+            .filter_map(|(index_record, create_index_query)| {
+                let columns = &create_index_query.columns;
+                let rank = if create_index_query.unique
+                    && columns.iter().all(|column| {
+                        !column.is_expression && is_compared(comparisons, &column.name, true)
+                    }) {
+                    0
+                } else if is_compared(comparisons, &columns[0].name, true) {
+                    1
+                } else if is_compared(comparisons, &columns[0].name, false) {
+                    2
+                } else {
+                    return None;
+                };
+                let estimated_rows = self.estimated_rows(&create_index_query, comparisons);
+                Some((rank, estimated_rows, index_record, create_index_query))
+            })
+            .collect::<Vec<_>>();
+        let use_stats = candidates
+            .iter()
+            .all(|(_, estimated_rows, _, _)| estimated_rows.is_some());
+        candidates
+            .into_iter()
+            .min_by_key(|(rank, estimated_rows, _, _)| {
+                if use_stats {
+                    (*estimated_rows, *rank)
+                } else {
+                    (None, *rank)
                 }
-            } else {
-                None
-            }
-        })
+            })
+            .map(|(_, _, index_record, create_index_query)| (index_record, create_index_query))
+    }
+
+    /// Keeps the valid rows of sqlite_stat1, the others are ignored like the statistics
+    /// of an index which is not there anymore
+    pub fn set_stats(&mut self, records: Vec<Record>) {
+        self.stats = records
+            .into_iter()
+            .filter_map(|record| Stat1Record::try_from(record).ok())
+            .collect();
+    }
+
+    /// Number of rows an index lookup should read according to sqlite_stat1: the rows
+    /// whose first column is equal to a value, or a quarter of the rows for a range, like
+    /// sqlite assumes without the histograms of sqlite_stat4.
+    /// None if the index was not analyzed.
+    pub fn estimated_rows(
+        &self,
+        create_index_query: &CreateIndexQuery,
+        comparisons: &[(&str, ComparisonOperator)],
+    ) -> Option<u64> {
+        let stat = &self
+            .stats
+            .iter()
+            .find(|stat1_record| {
+                stat1_record
+                    .idx
+                    .as_ref()
+                    .is_some_and(|idx| idx.eq_ignore_ascii_case(&create_index_query.indexname))
+            })?
+            .stat;
+        let first_column = create_index_query.columns.first()?;
+        if is_compared(comparisons, &first_column.name, true) {
+            stat.get(1).copied()
+        } else {
+            Some(stat[0] / 4)
+        }
+    }
+
+    /// Number of rows of a table according to sqlite_stat1. A partial index has less
+    /// rows than its table.
+    pub fn analyzed_table_rows(&self, tablename: &str) -> Option<u64> {
+        self.stats
+            .iter()
+            .filter(|stat1_record| stat1_record.tbl.eq_ignore_ascii_case(tablename))
+            .map(|stat1_record| stat1_record.stat[0])
+            .max()
+    }
+}
+
+/// Parses the CREATE INDEX of an index, None if it is not supported. A partially parsed
+/// condition would not be the condition of the index.
+fn parse_index_sql(sql: &str) -> Option<CreateIndexQuery> {
+    match parse_create_index_command(sql) {
+        Ok((rest, create_index_query)) if rest.trim().is_empty() => Some(create_index_query),
+        _ => None,
     }
 }
 
+/// Checks if a column is compared to a value, by an equality or by any comparison
+/// giving a range. != can not use an index.
+fn is_compared(comparisons: &[(&str, ComparisonOperator)], colname: &str, equality: bool) -> bool {
+    comparisons.iter().any(|(compared, operator)| {
+        compared.eq_ignore_ascii_case(colname)
+            && match operator {
+                ComparisonOperator::Eq => true,
+                ComparisonOperator::NotEq => false,
+                _ => !equality,
+            }
+    })
+}
+
+/// Error of a record of the schema or of sqlite_stat1 which does not have the expected
+/// columns
+fn corrupted_schema(message: &str) -> Error {
+    Error::CorruptDatabase(message.to_string())
+}
+
 impl TryFrom<Vec<Record>> for SchemaTable {
-    type Error = anyhow::Error;
+    type Error = Error;
 
-    fn try_from(records: Vec<Record>) -> anyhow::Result<Self> {
+    fn try_from(records: Vec<Record>) -> Result<Self> {
         let schema_records = records
             .into_iter()
             // we only keep the valid records
@@ -72,6 +314,7 @@ impl TryFrom<Vec<Record>> for SchemaTable {
 
         Ok(Self {
             records: schema_records,
+            stats: Vec::new(),
         })
     }
 }
@@ -80,48 +323,308 @@ pub struct SchemaTableRecord {
     pub coltype: String,
     pub name: String,
     pub tbl_name: String,
-    pub rootpage: u64,
-    pub sql: String,
+    pub rootpage: u32,
+    /// None for the indexes sqlite creates itself, see autoindex_constraint
+    pub sql: Option<String>,
+}
+
+impl SchemaTableRecord {
+    /// sqlite creates an index named sqlite_autoindex_<table>_<n> for the n-th UNIQUE or
+    /// PRIMARY KEY constraint of a table, starting at 1. The table is in tbl_name.
+    pub fn autoindex_constraint(&self) -> Option<usize> {
+        if self.coltype != "index" {
+            return None;
+        }
+        self.name
+            .strip_prefix("sqlite_autoindex_")?
+            .strip_prefix(&self.tbl_name)?
+            .strip_prefix('_')?
+            .parse()
+            .ok()
+    }
+}
+
+/// A table of the schema, see Database::tables
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableInfo {
+    pub name: String,
+    /// First page of the b tree of the table, 0 for a virtual table
+    pub root_page: u32,
+    /// The rows of a virtual table come from its module: it has no primary key here
+    pub is_virtual: bool,
+    pub without_rowid: bool,
+    /// Columns of the primary key, in order. Empty when the rows are only keyed by their
+    /// rowid
+    pub primary_key: Vec<String>,
+}
+
+/// An index of a table, see Database::indexes
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexInfo {
+    pub name: String,
+    /// Columns of the key, in order
+    pub columns: Vec<IndexedColumn>,
+    pub unique: bool,
+    /// Only the rows matching the where clause of the index are in it
+    pub partial: bool,
+    /// Created by sqlite for a PRIMARY KEY or UNIQUE constraint, without CREATE INDEX
+    pub is_autoindex: bool,
 }
 
 impl TryFrom<Record> for SchemaTableRecord {
-    type Error = anyhow::Error;
+    type Error = Error;
 
-    fn try_from(record: Record) -> anyhow::Result<Self> {
-        if record.column_contents.len() != 5 {
-            anyhow::bail!("Wrong number of columns to build the schema table");
-        }
+    fn try_from(record: Record) -> Result<Self> {
+        let [coltype, name, tbl_name, rootpage, sql] = record.column_contents.as_slice() else {
+            return Err(corrupted_schema(
+                "Wrong number of columns to build the schema table",
+            ));
+        };
+        let wrong_type = |err: ConversionError| {
+            corrupted_schema(&format!("Wrong column type for schema table: {}", err))
+        };
+        Ok(SchemaTableRecord {
+            coltype: String::try_from(coltype).map_err(wrong_type)?,
+            name: String::try_from(name).map_err(wrong_type)?,
+            tbl_name: String::try_from(tbl_name).map_err(wrong_type)?,
+            rootpage: i64::try_from(rootpage).map_err(wrong_type)? as u32,
+            // the indexes created for UNIQUE and PRIMARY KEY constraints have no sql
+            sql: Option::<String>::from_column_content(sql).map_err(wrong_type)?,
+        })
+    }
+}
 
-        let coltype = match &record.column_contents[0] {
-            ColumnContent::String(x) => x.to_string(),
-            _ => anyhow::bail!("Wrong column type for schema table"),
+/// A row of sqlite_stat1, which ANALYZE fills with the number of rows of each index.
+/// https://www.sqlite.org/fileformat.html#the_sqlite_stat1_table
+#[derive(Debug, Clone)]
+pub struct Stat1Record {
+    pub tbl: String,
+    /// None for the row of a table without index
+    pub idx: Option<String>,
+    /// The number of rows of the index, then the average number of rows sharing the
+    /// same values for its first column, for its first two columns and so on. The
+    /// keywords sqlite may add after the numbers are ignored.
+    pub stat: Vec<u64>,
+}
+
+impl TryFrom<Record> for Stat1Record {
+    type Error = Error;
+
+    fn try_from(record: Record) -> Result<Self> {
+        let [tbl, idx, stat] = record.column_contents.as_slice() else {
+            return Err(corrupted_schema("Wrong columns for sqlite_stat1"));
         };
-        let name = match &record.column_contents[1] {
-            ColumnContent::String(x) => x.to_string(),
-            _ => anyhow::bail!("Wrong column type for schema table"),
+        let wrong_type = |err: ConversionError| {
+            corrupted_schema(&format!("Wrong column type for sqlite_stat1: {}", err))
         };
-        let tbl_name = match &record.column_contents[2] {
-            ColumnContent::String(x) => x.to_string(),
-            _ => anyhow::bail!("Wrong column type for schema table"),
+        let tbl = String::try_from(tbl).map_err(wrong_type)?;
+        let idx = Option::<String>::from_column_content(idx).map_err(wrong_type)?;
+        let stat = String::try_from(stat).map_err(wrong_type)?;
+        let stat = stat
+            .split_whitespace()
+            .map_while(|number| number.parse().ok())
+            .collect::<Vec<_>>();
+        if stat.is_empty() {
+            return Err(corrupted_schema("No number of rows in sqlite_stat1"));
+        }
+        Ok(Stat1Record { tbl, idx, stat })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sql_parser::{parse_expr, Literal};
+
+    fn index(name: &str, sql: &str) -> SchemaTableRecord {
+        SchemaTableRecord {
+            coltype: "index".to_string(),
+            name: name.to_string(),
+            tbl_name: "users".to_string(),
+            rootpage: 3,
+            sql: Some(sql.to_string()),
+        }
+    }
+
+    /// The users table, with three indexes which all start with a different column
+    fn schema_table() -> SchemaTable {
+        SchemaTable {
+            records: vec![
+                SchemaTableRecord {
+                    coltype: "table".to_string(),
+                    name: "users".to_string(),
+                    tbl_name: "users".to_string(),
+                    rootpage: 2,
+                    sql: Some(
+                        "CREATE TABLE users (id integer primary key, country text, email text, age integer)"
+                            .to_string(),
+                    ),
+                },
+                index(
+                    "idx_users_country_age",
+                    "CREATE INDEX idx_users_country_age ON users (country, age)",
+                ),
+                index(
+                    "idx_users_age",
+                    "CREATE INDEX idx_users_age ON users (age)",
+                ),
+                index(
+                    "idx_users_email",
+                    "CREATE UNIQUE INDEX idx_users_email ON users (email)",
+                ),
+            ],
+            stats: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn indexes_of_a_table() {
+        let mut schema_table = schema_table();
+        schema_table.records[0].sql = Some(
+            "CREATE TABLE users (id integer primary key, country text, email text, age integer, \
+             nickname text UNIQUE)"
+                .to_string(),
+        );
+        schema_table.records.extend([
+            SchemaTableRecord {
+                sql: None,
+                ..index("sqlite_autoindex_users_1", "")
+            },
+            index("idx_users_bad", "CREATE INDEX idx_users_bad ON users (age"),
+            SchemaTableRecord {
+                tbl_name: "orders".to_string(),
+                ..index(
+                    "idx_orders_user",
+                    "CREATE INDEX idx_orders_user ON orders (user)",
+                )
+            },
+        ]);
+
+        // the table name is not case sensitive, the index which does not parse is skipped
+        let indexes = schema_table.get_indexes_for_table("USERS");
+        let names = indexes
+            .iter()
+            .map(|(record, query)| {
+                let columns = query.columns.iter().map(|c| c.name.as_str()).collect();
+                (record.name.as_str(), columns, query.unique)
+            })
+            .collect::<Vec<(_, Vec<_>, _)>>();
+        assert_eq!(
+            names,
+            vec![
+                ("idx_users_country_age", vec!["country", "age"], false),
+                ("idx_users_age", vec!["age"], false),
+                ("idx_users_email", vec!["email"], true),
+                ("sqlite_autoindex_users_1", vec!["nickname"], true),
+            ]
+        );
+        let infos = schema_table.get_index_infos("users");
+        assert_eq!(infos.len(), 4);
+        assert!(infos[3].is_autoindex && !infos[2].is_autoindex);
+        assert!(schema_table.get_indexes_for_table("guests").is_empty());
+        assert_eq!(
+            schema_table.warnings(),
+            vec!["index idx_users_bad is not supported: CREATE INDEX idx_users_bad ON users (age"]
+        );
+    }
+
+    fn chosen_index(comparisons: &[(&str, ComparisonOperator)]) -> Option<String> {
+        let where_clause = Expr::Literal(Literal::Integer(1));
+        schema_table()
+            .choose_index("users", &where_clause, comparisons)
+            .map(|(index_record, _)| index_record.name)
+    }
+
+    #[test]
+    fn most_selective_index() {
+        use ComparisonOperator::*;
+        // the unique index, even after the other equalities
+        assert_eq!(
+            chosen_index(&[("country", Eq), ("age", Eq), ("email", Eq)]).as_deref(),
+            Some("idx_users_email")
+        );
+        // an equality rather than a range
+        assert_eq!(
+            chosen_index(&[("country", Gt), ("AGE", Eq)]).as_deref(),
+            Some("idx_users_age")
+        );
+        assert_eq!(
+            chosen_index(&[("email", Lt), ("country", Eq)]).as_deref(),
+            Some("idx_users_country_age")
+        );
+        // between two equalities, the first index of the schema
+        assert_eq!(
+            chosen_index(&[("age", Eq), ("country", Eq)]).as_deref(),
+            Some("idx_users_country_age")
+        );
+        assert_eq!(
+            chosen_index(&[("email", GtEq)]).as_deref(),
+            Some("idx_users_email")
+        );
+        // id has no index, and != can not use one
+        assert_eq!(chosen_index(&[("id", Eq), ("email", NotEq)]), None);
+    }
+
+    #[test]
+    fn partial_index_only_for_its_condition() {
+        let mut schema_table = schema_table();
+        schema_table.records.push(index(
+            "idx_adults_id",
+            "CREATE INDEX idx_adults_id ON users (id) WHERE age >= 18",
+        ));
+        // the comparisons of id = 5, the other condition can not use an index
+        let comparisons = [("id", ComparisonOperator::Eq)];
+        let chosen_index = |where_clause: &str| {
+            let (_, where_clause) = parse_expr(where_clause).unwrap();
+            schema_table
+                .choose_index("users", &where_clause, &comparisons)
+                .map(|(index_record, _)| index_record.name)
         };
-        let rootpage = match &record.column_contents[3] {
-            ColumnContent::Int(x) => *x,
-            _ => anyhow::bail!("Wrong column type for schema table"),
+        assert_eq!(
+            chosen_index("id = 5 AND age >= 18").as_deref(),
+            Some("idx_adults_id")
+        );
+        assert_eq!(
+            chosen_index("age >= 18 AND name = 'Bob' AND id = 5").as_deref(),
+            Some("idx_adults_id")
+        );
+        assert_eq!(chosen_index("id = 5 AND age >= 21"), None);
+        assert_eq!(chosen_index("id = 5 OR age >= 18"), None);
+    }
+
+    #[test]
+    fn statistics_compare_the_indexes() {
+        use ComparisonOperator::*;
+        let stat1_record = |idx: &str, stat: &[u64]| Stat1Record {
+            tbl: "users".to_string(),
+            idx: Some(idx.to_string()),
+            stat: stat.to_vec(),
         };
-        let sql = match &record.column_contents[4] {
-            ColumnContent::String(x) => x.to_string(),
-            // for some reason, we have blobs in chinook db
-            // maybe there is a parsing error somewhere
-            ColumnContent::Blob(_) => "Blob".to_string(),
-            _ => anyhow::bail!("Wrong column type for schema table"),
+        let mut schema_table = schema_table();
+        let where_clause = Expr::Literal(Literal::Integer(1));
+        let comparisons = [("country", Eq), ("age", Eq)];
+        let chosen_index = |schema_table: &SchemaTable| {
+            schema_table
+                .choose_index("users", &where_clause, &comparisons)
+                .map(|(index_record, _)| index_record.name)
         };
 
-        Ok(SchemaTableRecord {
-            coltype,
-            name,
-            tbl_name,
-            rootpage,
-            sql,
-        })
+        // a country has 400 users on average, an age 5
+        schema_table.stats = vec![
+            stat1_record("idx_users_country_age", &[1000, 400, 2]),
+            stat1_record("idx_users_age", &[1000, 5]),
+        ];
+        assert_eq!(
+            chosen_index(&schema_table).as_deref(),
+            Some("idx_users_age")
+        );
+        assert_eq!(schema_table.analyzed_table_rows("USERS"), Some(1000));
+
+        // all the indexes need statistics to be compared
+        schema_table.stats.pop();
+        assert_eq!(
+            chosen_index(&schema_table).as_deref(),
+            Some("idx_users_country_age")
+        );
     }
 }
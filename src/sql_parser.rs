@@ -1,168 +1,1807 @@
 use nom::{
     branch::alt,
-    bytes::complete::{tag, tag_no_case, take_until, take_while1},
-    character::complete::{char, multispace0, multispace1, space0},
-    multi::{separated_list0, separated_list1},
-    sequence::{delimited, preceded, separated_pair},
+    bytes::complete::{tag, tag_no_case, take_until, take_while, take_while1},
+    character::complete::{char, digit0, digit1, multispace1, not_line_ending, one_of, satisfy},
+    combinator::{consumed, map, not, opt, peek, recognize, value, verify},
+    multi::{fold_many0, many0, many1, separated_list0, separated_list1},
+    sequence::{delimited, pair, preceded, terminated, tuple},
     IResult,
 };
 
-#[derive(Debug, Clone)]
+use crate::error::Error;
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct SelectQuery {
-    pub columns: Vec<String>,
-    pub tablename: String,
-    // compares column name to value
-    pub where_clause: Option<(String, String)>,
+    pub columns: Vec<ResultColumn>,
+    pub from: TableSource,
+    pub where_clause: Option<Expr>,
+    pub group_by: Vec<Expr>,
+    pub order_by: Vec<OrderingTerm>,
+    pub limit: Option<Expr>,
+    pub offset: Option<Expr>,
+}
+
+/// An expression of the ORDER BY clause
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderingTerm {
+    pub expr: Expr,
+    pub descending: bool,
+}
+
+/// What a SELECT reads its rows from
+#[derive(Debug, Clone, PartialEq)]
+pub enum TableSource {
+    /// A table or a view, of the attached database `schema` when it is qualified, like
+    /// `other.users`
+    Table {
+        schema: Option<String>,
+        name: String,
+    },
+    /// `(SELECT ...) [AS alias]`
+    Subquery {
+        query: Box<SelectQuery>,
+        alias: Option<String>,
+    },
+}
+
+impl TableSource {
+    /// Name used to qualify the columns of the source. Empty for a sub select without alias
+    pub fn name(&self) -> &str {
+        match self {
+            TableSource::Table {
+                name: tablename, ..
+            } => tablename,
+            TableSource::Subquery { alias, .. } => alias.as_deref().unwrap_or_default(),
+        }
+    }
+}
+
+/// An element of the select list
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResultColumn {
+    /// All the columns of the table
+    Star,
+    /// The name of the output column is its alias, or the text of the expression like
+    /// in sqlite
+    Expr { expr: Expr, name: String },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Literal(Literal),
+    Parameter(Parameter),
+    Column(String),
+    /// Scalar and aggregate functions. count(*) is stored without arguments
+    Function {
+        name: String,
+        args: Vec<Expr>,
+    },
+    /// Unary minus, `-expr`. The unary plus is not stored, it does not change its operand.
+    Negate(Box<Expr>),
+    /// Follows the sqlite numeric semantics: NULL if one of the operands is NULL,
+    /// integer result if both operands are integers
+    Arithmetic {
+        left: Box<Expr>,
+        operator: ArithmeticOperator,
+        right: Box<Expr>,
+    },
+    /// Evaluates to 1 if the comparison is true, 0 if it is false, and NULL if one of
+    /// the sides is NULL
+    Comparison {
+        left: Box<Expr>,
+        operator: ComparisonOperator,
+        right: Box<Expr>,
+    },
+    /// `AND` and `OR`, with the three-valued logic of sql: NULL is an unknown value, so
+    /// `NULL AND 0` is 0 but `NULL AND 1` is NULL
+    Logical {
+        left: Box<Expr>,
+        operator: LogicalOperator,
+        right: Box<Expr>,
+    },
+    /// `expr REGEXP pattern`: 1 if the text matches the pattern, 0 otherwise, including
+    /// when the value is not a text
+    Regexp {
+        left: Box<Expr>,
+        pattern: Box<Expr>,
+    },
+    /// `CASE [operand] WHEN ... THEN ... [ELSE ...] END`. Without operand, the WHEN
+    /// expressions are conditions, otherwise they are compared to the operand
+    Case {
+        operand: Option<Box<Expr>>,
+        when_then: Vec<(Expr, Expr)>,
+        else_expr: Option<Box<Expr>>,
+    },
+    /// `EXISTS (SELECT ...)`: 1 if the sub select returns at least one row, 0 otherwise
+    Exists(Box<SelectQuery>),
+    /// `(SELECT ...)`: first column of the first row of the sub select, or NULL
+    Subquery(Box<SelectQuery>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArithmeticOperator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Modulo,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ComparisonOperator {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+impl ComparisonOperator {
+    /// Checks if the result of a comparison satisfies the operator
+    pub fn matches(&self, ordering: std::cmp::Ordering) -> bool {
+        use std::cmp::Ordering;
+        match self {
+            ComparisonOperator::Eq => ordering == Ordering::Equal,
+            ComparisonOperator::NotEq => ordering != Ordering::Equal,
+            ComparisonOperator::Lt => ordering == Ordering::Less,
+            ComparisonOperator::LtEq => ordering != Ordering::Greater,
+            ComparisonOperator::Gt => ordering == Ordering::Greater,
+            ComparisonOperator::GtEq => ordering != Ordering::Less,
+        }
+    }
+
+    /// Operator to use when the operands are swapped: `a < b` is `b > a`
+    pub fn flip(&self) -> Self {
+        match self {
+            ComparisonOperator::Lt => ComparisonOperator::Gt,
+            ComparisonOperator::LtEq => ComparisonOperator::GtEq,
+            ComparisonOperator::Gt => ComparisonOperator::Lt,
+            ComparisonOperator::GtEq => ComparisonOperator::LtEq,
+            operator => *operator,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LogicalOperator {
+    And,
+    Or,
+}
+
+/// A constant value written in the query
+#[derive(Debug, Clone, PartialEq)]
+pub enum Literal {
+    Null,
+    Integer(i64),
+    Real(f64),
+    Text(String),
+    /// Written X'0A1B' in queries
+    Blob(Vec<u8>),
+}
+
+impl Literal {
+    /// Guesses the type of a value passed from the command line.
+    /// Only canonical numbers are converted so that strings like '007' are kept as text
+    pub fn from_param(param: &str) -> Self {
+        if let Ok(x) = param.parse::<i64>() {
+            if x.to_string() == param {
+                return Literal::Integer(x);
+            }
+        }
+        if let Ok(x) = param.parse::<f64>() {
+            if x.to_string() == param {
+                return Literal::Real(x);
+            }
+        }
+        Literal::Text(param.to_string())
+    }
+}
+
+/// Placeholder in a value position, replaced by a bound value before execution
+#[derive(Debug, Clone, PartialEq)]
+pub enum Parameter {
+    /// `?` or `?NNN`. Anonymous `?` are numbered when binding
+    Positional(Option<usize>),
+    /// `:name`
+    Named(String),
+}
+
+impl Expr {
+    /// Collects the parameters of the expression, in order of appearance
+    fn collect_parameters<'a>(&'a mut self, parameters: &mut Vec<&'a mut Expr>) {
+        if matches!(self, Expr::Parameter(_)) {
+            parameters.push(self);
+            return;
+        }
+        if let Expr::Exists(query) | Expr::Subquery(query) = self {
+            query.collect_parameters(parameters);
+            return;
+        }
+        for child in self.children_mut() {
+            child.collect_parameters(parameters);
+        }
+    }
+
+    /// Direct sub expressions, in the order they are written. The expressions of a
+    /// sub select are not part of them since they are evaluated against other rows
+    pub fn children(&self) -> Vec<&Expr> {
+        match self {
+            Expr::Literal(_)
+            | Expr::Parameter(_)
+            | Expr::Column(_)
+            | Expr::Exists(_)
+            | Expr::Subquery(_) => Vec::new(),
+            Expr::Function { args, .. } => args.iter().collect(),
+            Expr::Negate(operand) => vec![operand.as_ref()],
+            Expr::Arithmetic { left, right, .. }
+            | Expr::Comparison { left, right, .. }
+            | Expr::Logical { left, right, .. } => vec![left.as_ref(), right.as_ref()],
+            Expr::Regexp { left, pattern } => vec![left.as_ref(), pattern.as_ref()],
+            Expr::Case {
+                operand,
+                when_then,
+                else_expr,
+            } => {
+                let mut children = Vec::new();
+                if let Some(operand) = operand {
+                    children.push(operand.as_ref());
+                }
+                for (when, then) in when_then {
+                    children.push(when);
+                    children.push(then);
+                }
+                if let Some(else_expr) = else_expr {
+                    children.push(else_expr.as_ref());
+                }
+                children
+            }
+        }
+    }
+
+    /// Same as children, but mutable
+    pub fn children_mut(&mut self) -> Vec<&mut Expr> {
+        match self {
+            Expr::Literal(_)
+            | Expr::Parameter(_)
+            | Expr::Column(_)
+            | Expr::Exists(_)
+            | Expr::Subquery(_) => Vec::new(),
+            Expr::Function { args, .. } => args.iter_mut().collect(),
+            Expr::Negate(operand) => vec![operand.as_mut()],
+            Expr::Arithmetic { left, right, .. }
+            | Expr::Comparison { left, right, .. }
+            | Expr::Logical { left, right, .. } => vec![left.as_mut(), right.as_mut()],
+            Expr::Regexp { left, pattern } => vec![left.as_mut(), pattern.as_mut()],
+            Expr::Case {
+                operand,
+                when_then,
+                else_expr,
+            } => {
+                let mut children = Vec::new();
+                if let Some(operand) = operand {
+                    children.push(operand.as_mut());
+                }
+                for (when, then) in when_then {
+                    children.push(when);
+                    children.push(then);
+                }
+                if let Some(else_expr) = else_expr {
+                    children.push(else_expr.as_mut());
+                }
+                children
+            }
+        }
+    }
+}
+
+impl SelectQuery {
+    /// Collects the parameters of the query, in the order they are written
+    fn collect_parameters<'a>(&'a mut self, parameters: &mut Vec<&'a mut Expr>) {
+        for column in &mut self.columns {
+            if let ResultColumn::Expr { expr, .. } = column {
+                expr.collect_parameters(parameters);
+            }
+        }
+        if let TableSource::Subquery { query, .. } = &mut self.from {
+            query.collect_parameters(parameters);
+        }
+        if let Some(where_clause) = &mut self.where_clause {
+            where_clause.collect_parameters(parameters);
+        }
+        for expr in &mut self.group_by {
+            expr.collect_parameters(parameters);
+        }
+        for term in &mut self.order_by {
+            term.expr.collect_parameters(parameters);
+        }
+        if let Some(limit) = &mut self.limit {
+            limit.collect_parameters(parameters);
+        }
+        if let Some(offset) = &mut self.offset {
+            offset.collect_parameters(parameters);
+        }
+    }
+
+    /// Replaces each placeholder by the literal `value` returns for it. The anonymous `?`
+    /// are numbered like in sqlite, after the largest number before them: `value` is
+    /// always passed a Positional(Some(number)) or a Named parameter.
+    pub fn replace_parameters(
+        &mut self,
+        value: &mut dyn FnMut(&Parameter) -> Result<Literal, Error>,
+    ) -> Result<(), Error> {
+        let mut parameters = Vec::new();
+        self.collect_parameters(&mut parameters);
+
+        let mut largest_number = 0;
+        for expr in parameters {
+            let Expr::Parameter(parameter) = &*expr else {
+                continue;
+            };
+            let parameter = match parameter {
+                Parameter::Positional(number) => {
+                    let number = number.unwrap_or(largest_number + 1);
+                    largest_number = largest_number.max(number);
+                    Parameter::Positional(Some(number))
+                }
+                Parameter::Named(name) => Parameter::Named(name.clone()),
+            };
+            *expr = Expr::Literal(value(&parameter)?);
+        }
+        Ok(())
+    }
+
+    /// Placeholders of the query in the order they are written, numbered like by
+    /// replace_parameters. A placeholder used twice is listed twice.
+    pub fn parameters(&self) -> Vec<Parameter> {
+        let mut parameters = Vec::new();
+        // the placeholders are only replaced in a copy of the query
+        let _ = self.clone().replace_parameters(&mut |parameter| {
+            parameters.push(parameter.clone());
+            Ok(Literal::Null)
+        });
+        parameters
+    }
+
+    /// Replaces the placeholders by the values given on the command line.
+    /// A param of the form name=value binds :name, the other ones are used in order
+    /// for the positional placeholders. Missing or extra params are errors.
+    pub fn bind_parameters(&mut self, params: &[String]) -> Result<(), Error> {
+        let names = self
+            .parameters()
+            .into_iter()
+            .filter_map(|p| match p {
+                Parameter::Named(name) => Some(name),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let mut named = Vec::new();
+        let mut positional = Vec::new();
+        for param in params {
+            match param.split_once('=') {
+                Some((name, value)) if names.iter().any(|n| n == name) => {
+                    named.push((name.to_string(), value.to_string()))
+                }
+                _ => positional.push(param.to_string()),
+            }
+        }
+
+        let mut nb_positional_used = 0;
+        let mut named_used = Vec::new();
+        self.replace_parameters(&mut |parameter| {
+            let value = match parameter {
+                Parameter::Positional(number) => {
+                    // sqlite numbers parameters from 1
+                    let number = number.unwrap_or_default();
+                    nb_positional_used = nb_positional_used.max(number);
+                    match number.checked_sub(1).and_then(|i| positional.get(i)) {
+                        Some(value) => value,
+                        None => {
+                            return Err(Error::InvalidParameters(format!(
+                                "Missing value for parameter ?{}",
+                                number
+                            )))
+                        }
+                    }
+                }
+                Parameter::Named(name) => match named.iter().find(|(n, _)| n == name) {
+                    Some((_, value)) => {
+                        if !named_used.contains(name) {
+                            named_used.push(name.clone());
+                        }
+                        value
+                    }
+                    None => {
+                        return Err(Error::InvalidParameters(format!(
+                            "Missing value for parameter :{}",
+                            name
+                        )))
+                    }
+                },
+            };
+            Ok(Literal::from_param(value))
+        })?;
+
+        if positional.len() > nb_positional_used || named.len() > named_used.len() {
+            return Err(Error::InvalidParameters(format!(
+                "Too many parameters: the query uses {}, got {}",
+                nb_positional_used + named_used.len(),
+                params.len()
+            )));
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct CreateTableQuery {
-    // names and types
-    pub columns_and_types: Vec<Vec<String>>,
     pub tablename: String,
+    pub columns: Vec<ColumnDef>,
+    pub table_constraints: Vec<TableConstraint>,
+    /// The rows are stored in an index b tree keyed by the primary key
+    pub without_rowid: bool,
+}
+
+impl CreateTableQuery {
+    pub fn column_names(&self) -> Vec<String> {
+        self.columns.iter().map(|c| c.name.clone()).collect()
+    }
+
+    /// Columns of the primary key, declared either on a column or as a table constraint
+    pub fn primary_key(&self) -> Vec<String> {
+        let table_primary_key = self.table_constraints.iter().find_map(|c| match c {
+            TableConstraint::PrimaryKey(columns) => {
+                Some(columns.iter().map(|c| c.name.clone()).collect())
+            }
+            _ => None,
+        });
+        table_primary_key.unwrap_or_else(|| {
+            self.columns
+                .iter()
+                .filter(|c| {
+                    c.constraints
+                        .iter()
+                        .any(|c| matches!(c, ColumnConstraint::PrimaryKey { .. }))
+                })
+                .map(|c| c.name.clone())
+                .collect()
+        })
+    }
+
+    /// Key columns of the indexes sqlite creates for the PRIMARY KEY and UNIQUE
+    /// constraints, in the order of their names sqlite_autoindex_<table>_<n>: the
+    /// constraints of the columns, then the ones of the table. The INTEGER PRIMARY KEY is
+    /// the rowid and has no index, and a constraint on the columns of a previous one
+    /// shares its index. The primary key of a WITHOUT ROWID table takes a number, but its
+    /// index is the b tree of the table.
+    pub fn autoindex_columns(&self) -> Vec<Vec<IndexedColumn>> {
+        let mut constraints = Vec::new();
+        for column in &self.columns {
+            for constraint in &column.constraints {
+                let (is_primary_key, descending) = match constraint {
+                    ColumnConstraint::PrimaryKey { descending, .. } => (true, *descending),
+                    ColumnConstraint::Unique => (false, false),
+                    _ => continue,
+                };
+                let indexed_column = IndexedColumn {
+                    name: column.name.clone(),
+                    is_expression: false,
+                    descending,
+                    collation: None,
+                };
+                constraints.push((is_primary_key, vec![indexed_column]));
+            }
+        }
+        for constraint in &self.table_constraints {
+            match constraint {
+                TableConstraint::PrimaryKey(columns) => constraints.push((true, columns.clone())),
+                TableConstraint::Unique(columns) => constraints.push((false, columns.clone())),
+                _ => {}
+            }
+        }
+
+        // without COLLATE in the constraint, the index uses the collation of the column
+        let column_collation = |colname: &str| {
+            self.columns
+                .iter()
+                .find(|c| c.name.eq_ignore_ascii_case(colname))
+                .and_then(|c| {
+                    c.constraints.iter().find_map(|c| match c {
+                        ColumnConstraint::Collate(collation) => Some(collation.clone()),
+                        _ => None,
+                    })
+                })
+                .filter(|collation| !collation.eq_ignore_ascii_case("BINARY"))
+        };
+        let mut autoindexes: Vec<Vec<IndexedColumn>> = Vec::new();
+        for (is_primary_key, mut columns) in constraints {
+            if is_primary_key && self.rowid_alias().is_some() {
+                continue;
+            }
+            for column in &mut columns {
+                if column.collation.is_none() {
+                    column.collation = column_collation(&column.name);
+                }
+            }
+            if !autoindexes.contains(&columns) {
+                autoindexes.push(columns);
+            }
+        }
+        autoindexes
+    }
+
+    /// Position of the column declared as INTEGER PRIMARY KEY, which is an alias of the
+    /// rowid. A descending primary key declared on the column is not an alias.
+    pub fn rowid_alias(&self) -> Option<usize> {
+        if self.without_rowid {
+            return None;
+        }
+        match self.primary_key().as_slice() {
+            [colname] => self.columns.iter().position(|c| {
+                c.name.eq_ignore_ascii_case(colname)
+                    && c.decl_type.eq_ignore_ascii_case("INTEGER")
+                    && !c.constraints.iter().any(|c| {
+                        matches!(
+                            c,
+                            ColumnConstraint::PrimaryKey {
+                                descending: true,
+                                ..
+                            }
+                        )
+                    })
+            }),
+            _ => None,
+        }
+    }
+
+    /// What is known about each column, in the order of the table
+    pub fn column_infos(&self) -> Vec<ColumnInfo> {
+        let rowid_alias = self.rowid_alias();
+        let primary_key = self.primary_key();
+        self.columns
+            .iter()
+            .enumerate()
+            .map(|(i, c)| ColumnInfo {
+                name: c.name.clone(),
+                declared_type: c.decl_type.clone(),
+                affinity: Affinity::from_declared_type(&c.decl_type),
+                is_rowid_alias: rowid_alias == Some(i),
+                not_null: c
+                    .constraints
+                    .iter()
+                    .any(|c| matches!(c, ColumnConstraint::NotNull)),
+                default: c.constraints.iter().find_map(|c| match c {
+                    ColumnConstraint::Default(value) => Some(value.clone()),
+                    _ => None,
+                }),
+                primary_key_position: primary_key
+                    .iter()
+                    .position(|name| name.eq_ignore_ascii_case(&c.name))
+                    .map(|position| position + 1),
+            })
+            .collect()
+    }
+}
+
+/// Column of a table, as used when reading and comparing its values
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnInfo {
+    pub name: String,
+    /// Type as written, or "" if there is none
+    pub declared_type: String,
+    pub affinity: Affinity,
+    /// The INTEGER PRIMARY KEY column, whose value is the rowid
+    pub is_rowid_alias: bool,
+    pub not_null: bool,
+    /// Default value, as written
+    pub default: Option<String>,
+    /// Position of the column in the primary key, from 1 like the pk column of
+    /// PRAGMA table_info
+    pub primary_key_position: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnDef {
+    pub name: String,
+    /// Type as written, for example "NVARCHAR(160)", or "" if there is none
+    pub decl_type: String,
+    pub constraints: Vec<ColumnConstraint>,
+}
+
+impl ColumnDef {
+    /// Virtual generated columns are not stored in the records
+    pub fn is_virtual(&self) -> bool {
+        self.constraints
+            .iter()
+            .any(|c| matches!(c, ColumnConstraint::Generated { stored: false, .. }))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnConstraint {
+    PrimaryKey {
+        descending: bool,
+        autoincrement: bool,
+    },
+    NotNull,
+    Null,
+    Unique,
+    /// Text of the expression, between the parentheses
+    Check(String),
+    /// Text of the value, parentheses included
+    Default(String),
+    Collate(String),
+    References(ForeignKey),
+    /// Text of the expression of a generated column. Only STORED columns are in the
+    /// records, VIRTUAL ones are computed when they are read.
+    Generated {
+        expr: String,
+        stored: bool,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForeignKey {
+    pub tablename: String,
+    pub columns: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TableConstraint {
+    /// The columns keep their ordering and collation, which are the ones of the index
+    /// sqlite creates for the constraint
+    PrimaryKey(Vec<IndexedColumn>),
+    Unique(Vec<IndexedColumn>),
+    Check(String),
+    ForeignKey {
+        columns: Vec<String>,
+        references: ForeignKey,
+    },
+}
+
+/// Type preference of a column, used to convert the values it is compared to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Affinity {
+    Integer,
+    Text,
+    /// Also used for columns without declared type: values are not converted
+    Blob,
+    Real,
+    Numeric,
+}
+
+impl Affinity {
+    /// Applies the sqlite rules, in order: INT gives INTEGER, CHAR, CLOB or TEXT give
+    /// TEXT, BLOB or no type give BLOB, REAL, FLOA or DOUB give REAL, otherwise NUMERIC
+    pub fn from_declared_type(declared_type: &str) -> Self {
+        let declared_type = declared_type.to_uppercase();
+        let contains_any = |words: &[&str]| words.iter().any(|w| declared_type.contains(w));
+        if contains_any(&["INT"]) {
+            Affinity::Integer
+        } else if contains_any(&["CHAR", "CLOB", "TEXT"]) {
+            Affinity::Text
+        } else if declared_type.trim().is_empty() || contains_any(&["BLOB"]) {
+            Affinity::Blob
+        } else if contains_any(&["REAL", "FLOA", "DOUB"]) {
+            Affinity::Real
+        } else {
+            Affinity::Numeric
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateViewQuery {
+    pub viewname: String,
+    pub select_query: SelectQuery,
+}
+
+/// A column of an index key
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexedColumn {
+    /// Name of the column, or text of the expression for an index on an expression
+    pub name: String,
+    pub is_expression: bool,
+    pub descending: bool,
+    /// `COLLATE name`, None for the default BINARY collation
+    pub collation: Option<String>,
 }
 
 #[derive(Debug, Clone)]
 pub struct CreateIndexQuery {
     pub indexname: String,
-    pub colname: String,
+    /// Columns of the index key, in order. The rowid follows them in the index records
+    pub columns: Vec<IndexedColumn>,
     pub tablename: String,
+    /// Two rows can not have the same key, except when it contains NULL
+    pub unique: bool,
+    /// Partial indexes only contain the rows matching this condition
+    pub where_clause: Option<Expr>,
 }
 
-fn parse_identifier(input: &str) -> IResult<&str, &str> {
+/// Skips whitespaces and comments, which can be `-- until the end of the line` or
+/// `/* between delimiters */`
+fn space0(input: &str) -> IResult<&str, &str> {
+    recognize(many0(alt((
+        multispace1,
+        recognize(pair(tag("--"), not_line_ending)),
+        recognize(tuple((tag("/*"), take_until("*/"), tag("*/")))),
+    ))))(input)
+}
+
+/// Parses a name between quotes, brackets or backticks. sqlite also accepts single
+/// quotes, for example in the tables it creates for fts5. Inside quotes and backticks,
+/// a doubled quote stands for the quote itself.
+fn parse_quoted_identifier(input: &str) -> IResult<&str, String> {
+    let (rest, open) = one_of("\"'[`")(input)?;
+    let close = if open == '[' { ']' } else { open };
+
+    let mut name = String::new();
+    let mut chars = rest.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if c != close {
+            name.push(c);
+        } else if close != ']' && chars.peek().is_some_and(|&(_, next)| next == close) {
+            name.push(c);
+            chars.next();
+        } else {
+            return Ok((&rest[i + 1..], name));
+        }
+    }
+    Err(nom::Err::Error(nom::error::Error::new(
+        input,
+        nom::error::ErrorKind::Char,
+    )))
+}
+
+fn parse_identifier(input: &str) -> IResult<&str, String> {
     delimited(
-        multispace0,
+        space0,
         alt((
-            take_while1(|c: char| c == '_' || c.is_alphanumeric()),
-            delimited(char('"'), take_until("\""), char('"')),
+            map(take_while1(is_identifier_char), |name: &str| {
+                name.to_string()
+            }),
+            parse_quoted_identifier,
         )),
-        multispace0,
+        space0,
     )(input)
 }
 
-fn parse_double_quote_value(input: &str) -> IResult<&str, &str> {
-    delimited(char('\"'), take_until("'"), char('\''))(input)
+fn is_identifier_char(c: char) -> bool {
+    c == '_' || c.is_alphanumeric()
 }
 
-fn parse_identifier_or_star(input: &str) -> IResult<&str, &str> {
-    delimited(
-        multispace0,
-        take_while1(|c: char| {
-            c == '(' || c == ')' || c == '*' || c == '\'' || c == '_' || c.is_alphanumeric()
-        }),
-        multispace0,
-    )(input)
+/// Matches a keyword, making sure it is not the prefix of an identifier
+fn keyword<'a>(kw: &'static str) -> impl FnMut(&'a str) -> IResult<&'a str, &'a str> {
+    terminated(tag_no_case(kw), not(satisfy(is_identifier_char)))
 }
 
-fn parse_columns(input: &str) -> IResult<&str, Vec<&str>> {
-    separated_list0(
-        delimited(multispace0, char(','), multispace0),
-        parse_identifier_or_star,
-    )(input)
+/// Same as keyword, but skips the whitespaces before it
+fn keyword_after_space<'a>(kw: &'static str) -> impl FnMut(&'a str) -> IResult<&'a str, &'a str> {
+    preceded(space0, keyword(kw))
 }
 
 fn parse_value(input: &str) -> IResult<&str, &str> {
     delimited(char('\''), take_until("'"), char('\''))(input)
 }
 
-fn parse_where_clause(input: &str) -> IResult<&str, (&str, &str)> {
-    preceded(
-        tag_no_case("WHERE"),
+fn parse_number(input: &str) -> IResult<&str, Literal> {
+    let (input, number) = recognize(tuple((
+        opt(char('-')),
+        digit1,
+        opt(pair(char('.'), digit0)),
+        opt(tuple((one_of("eE"), opt(one_of("+-")), digit1))),
+    )))(input)?;
+    let literal = match number.parse::<i64>() {
+        Ok(x) => Literal::Integer(x),
+        // the recognized string is always a valid float
+        Err(_) => Literal::Real(number.parse::<f64>().unwrap_or_default()),
+    };
+    Ok((input, literal))
+}
+
+/// A blob is an even number of hexadecimal digits, between quotes preceded by X or x
+fn parse_blob(input: &str) -> IResult<&str, Vec<u8>> {
+    let (input, digits) = preceded(
+        one_of("xX"),
         delimited(
-            multispace1,
-            separated_pair(
-                parse_identifier,
-                delimited(multispace0, char('='), multispace0),
-                parse_value,
+            char('\''),
+            verify(
+                take_while(|c: char| c.is_ascii_hexdigit()),
+                |digits: &str| digits.len().is_multiple_of(2),
             ),
-            multispace0,
+            char('\''),
+        ),
+    )(input)?;
+    let bytes = (0..digits.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&digits[i..i + 2], 16).unwrap_or_default())
+        .collect();
+    Ok((input, bytes))
+}
+
+fn parse_literal(input: &str) -> IResult<&str, Literal> {
+    alt((
+        map(parse_blob, Literal::Blob),
+        map(parse_value, |s| Literal::Text(s.to_string())),
+        parse_number,
+        value(Literal::Null, keyword("NULL")),
+    ))(input)
+}
+
+fn parse_parameter(input: &str) -> IResult<&str, Parameter> {
+    alt((
+        map(preceded(char('?'), opt(digit1)), |number: Option<&str>| {
+            Parameter::Positional(number.and_then(|n| n.parse::<usize>().ok()))
+        }),
+        map(
+            preceded(char(':'), take_while1(is_identifier_char)),
+            |name: &str| Parameter::Named(name.to_string()),
         ),
+    ))(input)
+}
+
+fn parse_function_call(input: &str) -> IResult<&str, Expr> {
+    let (input, name) = take_while1(is_identifier_char)(input)?;
+    let (input, _) = preceded(space0, char('('))(input)?;
+    let (input, args) = alt((
+        // count(*) is the same as count()
+        value(Vec::new(), delimited(space0, char('*'), space0)),
+        separated_list0(char(','), parse_expr),
+    ))(input)?;
+    let (input, _) = preceded(space0, char(')'))(input)?;
+
+    let function = Expr::Function {
+        name: name.to_lowercase(),
+        args,
+    };
+    Ok((input, function))
+}
+
+/// Parses a column name, optionally qualified by its table name
+fn parse_column_name(input: &str) -> IResult<&str, String> {
+    map(
+        pair(parse_identifier, opt(preceded(char('.'), parse_identifier))),
+        |(first, second)| match second {
+            Some(second) => format!("{}.{}", first, second),
+            None => first.to_string(),
+        },
     )(input)
 }
 
-pub fn parse_select_command(input: &str) -> IResult<&str, SelectQuery> {
-    let (input, _) = tag_no_case("SELECT")(input)?;
-    let (input, columns) = parse_columns(input)?;
-    let columns = columns
-        .into_iter()
-        .map(|s| s.to_string())
-        .collect::<Vec<_>>();
+/// Parses a parenthesized select
+fn parse_subquery(input: &str) -> IResult<&str, SelectQuery> {
+    delimited(preceded(space0, char('(')), parse_select_command, char(')'))(input)
+}
+
+fn parse_case(input: &str) -> IResult<&str, Expr> {
+    let (input, _) = keyword("CASE")(input)?;
+    // the operand is optional, in which case WHEN follows directly
+    let (input, operand) = opt(preceded(not(keyword_after_space("WHEN")), parse_expr))(input)?;
+    let (input, when_then) = many1(pair(
+        preceded(keyword_after_space("WHEN"), parse_expr),
+        preceded(keyword("THEN"), parse_expr),
+    ))(input)?;
+    let (input, else_expr) = opt(preceded(keyword("ELSE"), parse_expr))(input)?;
+    let (input, _) = keyword("END")(input)?;
+
+    let case = Expr::Case {
+        operand: operand.map(Box::new),
+        when_then,
+        else_expr: else_expr.map(Box::new),
+    };
+    Ok((input, case))
+}
+
+fn parse_primary(input: &str) -> IResult<&str, Expr> {
+    delimited(
+        space0,
+        alt((
+            map(parse_literal, Expr::Literal),
+            map(parse_parameter, Expr::Parameter),
+            parse_case,
+            map(preceded(keyword("EXISTS"), parse_subquery), |query| {
+                Expr::Exists(Box::new(query))
+            }),
+            map(parse_subquery, |query| Expr::Subquery(Box::new(query))),
+            delimited(char('('), parse_expr, char(')')),
+            parse_function_call,
+            map(parse_column_name, Expr::Column),
+        )),
+        space0,
+    )(input)
+}
+
+/// Parses a left associative chain of binary arithmetic operators, with operands
+/// parsed by `operand`
+fn parse_arithmetic_chain<'a>(
+    operand: fn(&'a str) -> IResult<&'a str, Expr>,
+    operator: fn(&'a str) -> IResult<&'a str, ArithmeticOperator>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Expr> {
+    move |input| {
+        let (input, first) = operand(input)?;
+        fold_many0(
+            pair(operator, operand),
+            move || first.clone(),
+            |left, (operator, right)| Expr::Arithmetic {
+                left: Box::new(left),
+                operator,
+                right: Box::new(right),
+            },
+        )(input)
+    }
+}
+
+fn parse_multiplicative_operator(input: &str) -> IResult<&str, ArithmeticOperator> {
+    alt((
+        value(ArithmeticOperator::Multiply, char('*')),
+        value(ArithmeticOperator::Divide, char('/')),
+        value(ArithmeticOperator::Modulo, char('%')),
+    ))(input)
+}
+
+fn parse_additive_operator(input: &str) -> IResult<&str, ArithmeticOperator> {
+    alt((
+        value(ArithmeticOperator::Add, char('+')),
+        value(ArithmeticOperator::Subtract, char('-')),
+    ))(input)
+}
+
+/// Parses the unary - and + before an operand. A number after a - is a negative literal.
+fn parse_unary(input: &str) -> IResult<&str, Expr> {
+    alt((
+        parse_primary,
+        map(preceded(pair(space0, char('-')), parse_unary), |operand| {
+            Expr::Negate(Box::new(operand))
+        }),
+        preceded(pair(space0, char('+')), parse_unary),
+    ))(input)
+}
+
+fn parse_multiplicative(input: &str) -> IResult<&str, Expr> {
+    parse_arithmetic_chain(parse_unary, parse_multiplicative_operator)(input)
+}
+
+fn parse_additive(input: &str) -> IResult<&str, Expr> {
+    parse_arithmetic_chain(parse_multiplicative, parse_additive_operator)(input)
+}
+
+fn parse_logical_chain<'a>(
+    operand: fn(&'a str) -> IResult<&'a str, Expr>,
+    operator: LogicalOperator,
+    kw: &'static str,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Expr> {
+    move |input| {
+        let (input, first) = operand(input)?;
+        fold_many0(
+            preceded(keyword(kw), operand),
+            move || first.clone(),
+            move |left, right| Expr::Logical {
+                left: Box::new(left),
+                operator,
+                right: Box::new(right),
+            },
+        )(input)
+    }
+}
+
+fn parse_and(input: &str) -> IResult<&str, Expr> {
+    parse_logical_chain(parse_comparison, LogicalOperator::And, "AND")(input)
+}
+
+/// Parses an expression, with the surrounding whitespaces. From the lowest to the
+/// highest precedence: OR, AND, comparisons and REGEXP, + and -, *, / and %, then the
+/// unary - and +
+pub fn parse_expr(input: &str) -> IResult<&str, Expr> {
+    parse_logical_chain(parse_and, LogicalOperator::Or, "OR")(input)
+}
+
+fn parse_comparison(input: &str) -> IResult<&str, Expr> {
+    let (input, left) = parse_additive(input)?;
+    let (input, expr) = opt(alt((
+        map(
+            pair(parse_comparison_operator, parse_additive),
+            |(operator, right)| Expr::Comparison {
+                left: Box::new(left.clone()),
+                operator,
+                right: Box::new(right),
+            },
+        ),
+        map(preceded(keyword("REGEXP"), parse_additive), |pattern| {
+            Expr::Regexp {
+                left: Box::new(left.clone()),
+                pattern: Box::new(pattern),
+            }
+        }),
+    )))(input)?;
+
+    Ok((input, expr.unwrap_or(left)))
+}
+
+/// Keywords that can follow an expression or a table, and can therefore not be used
+/// as an alias without AS
+const RESERVED_KEYWORDS: [&str; 10] = [
+    "FROM", "WHERE", "GROUP", "ORDER", "LIMIT", "OFFSET", "HAVING", "REGEXP", "AND", "OR",
+];
+
+/// Parses `AS alias`, or an alias without AS
+fn parse_alias(input: &str) -> IResult<&str, String> {
+    alt((
+        preceded(keyword_after_space("AS"), parse_identifier),
+        verify(parse_identifier, |alias: &str| {
+            !RESERVED_KEYWORDS
+                .iter()
+                .any(|kw| kw.eq_ignore_ascii_case(alias))
+        }),
+    ))(input)
+}
+
+fn parse_result_column(input: &str) -> IResult<&str, ResultColumn> {
+    alt((
+        value(ResultColumn::Star, delimited(space0, char('*'), space0)),
+        map(
+            pair(consumed(parse_expr), opt(parse_alias)),
+            |((text, expr), alias)| ResultColumn::Expr {
+                expr,
+                name: alias.unwrap_or_else(|| text.trim().to_string()),
+            },
+        ),
+    ))(input)
+}
+
+fn parse_columns(input: &str) -> IResult<&str, Vec<ResultColumn>> {
+    separated_list1(char(','), parse_result_column)(input)
+}
+
+fn parse_comparison_operator(input: &str) -> IResult<&str, ComparisonOperator> {
+    // longest operators first, otherwise "<=" would be parsed as "<"
+    alt((
+        value(ComparisonOperator::Eq, tag("==")),
+        value(ComparisonOperator::Eq, tag("=")),
+        value(ComparisonOperator::NotEq, tag("!=")),
+        value(ComparisonOperator::NotEq, tag("<>")),
+        value(ComparisonOperator::LtEq, tag("<=")),
+        value(ComparisonOperator::GtEq, tag(">=")),
+        value(ComparisonOperator::Lt, tag("<")),
+        value(ComparisonOperator::Gt, tag(">")),
+    ))(input)
+}
+
+fn parse_where_clause(input: &str) -> IResult<&str, Expr> {
+    preceded(keyword_after_space("WHERE"), parse_expr)(input)
+}
+
+fn parse_group_by_clause(input: &str) -> IResult<&str, Vec<Expr>> {
+    preceded(
+        pair(keyword_after_space("GROUP"), keyword_after_space("BY")),
+        separated_list1(char(','), parse_expr),
+    )(input)
+}
+
+fn parse_ordering_term(input: &str) -> IResult<&str, OrderingTerm> {
+    let (input, expr) = parse_expr(input)?;
+    let (input, direction) = opt(alt((
+        value(false, keyword("ASC")),
+        value(true, keyword("DESC")),
+    )))(input)?;
     let (input, _) = space0(input)?;
-    let (input, _) = tag_no_case("FROM")(input)?;
 
-    let (input, tablename) = parse_identifier(input)?;
-    let tablename = tablename.to_string();
+    let term = OrderingTerm {
+        expr,
+        descending: direction.unwrap_or(false),
+    };
+    Ok((input, term))
+}
 
-    let (_, where_clause) = parse_where_clause(input).ok().unzip();
+fn parse_order_by_clause(input: &str) -> IResult<&str, Vec<OrderingTerm>> {
+    preceded(
+        pair(keyword_after_space("ORDER"), keyword_after_space("BY")),
+        separated_list1(char(','), parse_ordering_term),
+    )(input)
+}
 
-    let where_clause = where_clause.map(|(a, b)| (a.to_owned(), b.to_owned()));
-    // let (input, _) = tag(";")(input)?;
+/// Parses `LIMIT count [OFFSET skipped]` or `LIMIT skipped, count`.
+/// Returns the limit and the offset
+fn parse_limit_clause(input: &str) -> IResult<&str, (Expr, Option<Expr>)> {
+    let (input, _) = keyword_after_space("LIMIT")(input)?;
+    let (input, first) = parse_expr(input)?;
+    if let Ok((input, offset)) = preceded(keyword("OFFSET"), parse_expr)(input) {
+        return Ok((input, (first, Some(offset))));
+    }
+    if let Ok((input, limit)) = preceded(char(','), parse_expr)(input) {
+        return Ok((input, (limit, Some(first))));
+    }
+    Ok((input, (first, None)))
+}
+
+fn parse_table_source(input: &str) -> IResult<&str, TableSource> {
+    alt((
+        map(pair(parse_subquery, opt(parse_alias)), |(query, alias)| {
+            TableSource::Subquery {
+                query: Box::new(query),
+                alias,
+            }
+        }),
+        map(
+            pair(parse_identifier, opt(preceded(char('.'), parse_identifier))),
+            |(first, second)| match second {
+                Some(name) => TableSource::Table {
+                    schema: Some(first),
+                    name,
+                },
+                None => TableSource::Table {
+                    schema: None,
+                    name: first,
+                },
+            },
+        ),
+    ))(input)
+}
+
+pub fn parse_select_command(input: &str) -> IResult<&str, SelectQuery> {
+    let (input, _) = preceded(space0, keyword("SELECT"))(input)?;
+    let (input, columns) = parse_columns(input)?;
+    let (input, _) = keyword("FROM")(input)?;
+
+    let (input, from) = parse_table_source(input)?;
+    let (input, where_clause) = opt(parse_where_clause)(input)?;
+    let (input, group_by) = opt(parse_group_by_clause)(input)?;
+    let (input, order_by) = opt(parse_order_by_clause)(input)?;
+    let (input, limit) = opt(parse_limit_clause)(input)?;
+    let (limit, offset) = match limit {
+        Some((limit, offset)) => (Some(limit), offset),
+        None => (None, None),
+    };
+
+    let (input, _) = opt(char(';'))(input)?;
+    let (input, _) = space0(input)?;
 
     let select_query = SelectQuery {
         columns,
-        tablename,
+        from,
         where_clause,
+        group_by: group_by.unwrap_or_default(),
+        order_by: order_by.unwrap_or_default(),
+        limit,
+        offset,
     };
 
     Ok((input, select_query))
 }
 
-fn parse_column_def(input: &str) -> IResult<&str, Vec<&str>> {
-    separated_list1(
-        multispace1,
-        // alphanumeric1
-        take_while1(|c: char| c == '_' || c.is_alphanumeric()),
+/// A statement given on the command line
+#[derive(Debug, Clone, PartialEq)]
+pub enum Statement {
+    Select(SelectQuery),
+    /// `EXPLAIN QUERY PLAN SELECT ...`: shows how the rows are read instead of reading them
+    ExplainQueryPlan(SelectQuery),
+}
+
+/// Parses a whole statement. A statement which does not parse, or which has text after
+/// it, is an error saying where the parsing stopped.
+pub fn parse_sql(sql: &str) -> Result<Statement, Error> {
+    let rest = match parse_statement(sql) {
+        Ok(("", statement)) => return Ok(statement),
+        Ok((rest, _)) => rest,
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => err.input,
+        Err(nom::Err::Incomplete(_)) => "",
+    };
+    Err(Error::ParseError {
+        near: rest.to_string(),
+        position: sql.len() - rest.len(),
+    })
+}
+
+pub fn parse_statement(input: &str) -> IResult<&str, Statement> {
+    alt((
+        map(
+            preceded(
+                tuple((
+                    keyword_after_space("EXPLAIN"),
+                    keyword_after_space("QUERY"),
+                    keyword_after_space("PLAN"),
+                )),
+                parse_select_command,
+            ),
+            Statement::ExplainQueryPlan,
+        ),
+        map(parse_select_command, Statement::Select),
+    ))(input)
+}
+
+/// Parses text between balanced parentheses and returns it without the outer ones
+fn parse_parenthesized_text(input: &str) -> IResult<&str, &str> {
+    let (rest, _) = preceded(space0, char('('))(input)?;
+    let mut depth = 0;
+    // end of the quoted text or comment the scan is in
+    let mut closing: Option<&str> = None;
+    let mut skip_until = 0;
+    for (i, c) in rest.char_indices() {
+        if i < skip_until {
+            continue;
+        }
+        if let Some(end) = closing {
+            if rest[i..].starts_with(end) {
+                closing = None;
+                skip_until = i + end.len();
+            }
+            continue;
+        }
+        match c {
+            '\'' => closing = Some("'"),
+            '"' => closing = Some("\""),
+            '`' => closing = Some("`"),
+            '[' => closing = Some("]"),
+            '-' if rest[i..].starts_with("--") => closing = Some("\n"),
+            '/' if rest[i..].starts_with("/*") => closing = Some("*/"),
+            '(' => depth += 1,
+            ')' if depth == 0 => return Ok((&rest[i + 1..], rest[..i].trim())),
+            ')' => depth -= 1,
+            _ => {}
+        }
+        skip_until = i + 1 + usize::from(matches!(closing, Some("\n" | "*/")));
+    }
+    Err(nom::Err::Error(nom::error::Error::new(
+        input,
+        nom::error::ErrorKind::Char,
+    )))
+}
+
+/// Parses an element of the column list of an index or of a table constraint
+fn parse_indexed_column(input: &str) -> IResult<&str, IndexedColumn> {
+    let end_of_entry = tuple((
+        opt(preceded(keyword("COLLATE"), parse_identifier)),
+        opt(alt((keyword("ASC"), keyword("DESC")))),
+        space0,
+        alt((char(','), char(')'))),
+    ));
+    let (input, (name, is_expression)) = alt((
+        map(terminated(parse_identifier, peek(end_of_entry)), |name| {
+            (name, false)
+        }),
+        map(consumed(parse_expr), |(text, _)| {
+            (text.trim().to_string(), true)
+        }),
+    ))(input)?;
+    let (input, collation) = opt(preceded(keyword("COLLATE"), parse_identifier))(input)?;
+    let (input, order) = opt(alt((keyword("ASC"), keyword("DESC"))))(input)?;
+    let (input, _) = space0(input)?;
+
+    let indexed_column = IndexedColumn {
+        name,
+        is_expression,
+        descending: order.is_some_and(|order| order.eq_ignore_ascii_case("DESC")),
+        collation: collation.filter(|collation| !collation.eq_ignore_ascii_case("BINARY")),
+    };
+    Ok((input, indexed_column))
+}
+
+fn parse_indexed_columns(input: &str) -> IResult<&str, Vec<IndexedColumn>> {
+    delimited(
+        preceded(space0, char('(')),
+        separated_list1(char(','), parse_indexed_column),
+        char(')'),
     )(input)
 }
 
-fn parse_column_defs(input: &str) -> IResult<&str, Vec<Vec<&str>>> {
-    separated_list0(
-        tag(","),
-        delimited(multispace0, parse_column_def, multispace0),
+/// Parses a parenthesized list of column names, ignoring their ordering and collation
+fn parse_column_list(input: &str) -> IResult<&str, Vec<String>> {
+    map(parse_indexed_columns, |columns| {
+        columns.into_iter().map(|column| column.name).collect()
+    })(input)
+}
+
+/// ON CONFLICT clause of a constraint, which does not change how data is read
+fn parse_conflict_clause(input: &str) -> IResult<&str, ()> {
+    value(
+        (),
+        tuple((
+            keyword_after_space("ON"),
+            keyword_after_space("CONFLICT"),
+            parse_identifier,
+        )),
     )(input)
 }
 
+/// Parses the part of a foreign key after REFERENCES, skipping its actions
+fn parse_foreign_key_clause(input: &str) -> IResult<&str, ForeignKey> {
+    let (input, _) = keyword_after_space("REFERENCES")(input)?;
+    let (input, tablename) = parse_identifier(input)?;
+    let (input, columns) = opt(parse_column_list)(input)?;
+    let action = alt((
+        recognize(pair(
+            keyword_after_space("SET"),
+            alt((keyword_after_space("NULL"), keyword_after_space("DEFAULT"))),
+        )),
+        recognize(pair(
+            keyword_after_space("NO"),
+            keyword_after_space("ACTION"),
+        )),
+        keyword_after_space("CASCADE"),
+        keyword_after_space("RESTRICT"),
+    ));
+    let (input, _) = many0(alt((
+        recognize(tuple((
+            keyword_after_space("ON"),
+            alt((keyword_after_space("DELETE"), keyword_after_space("UPDATE"))),
+            action,
+        ))),
+        recognize(pair(keyword_after_space("MATCH"), parse_identifier)),
+        recognize(tuple((
+            opt(keyword_after_space("NOT")),
+            keyword_after_space("DEFERRABLE"),
+            opt(pair(
+                keyword_after_space("INITIALLY"),
+                alt((
+                    keyword_after_space("DEFERRED"),
+                    keyword_after_space("IMMEDIATE"),
+                )),
+            )),
+        ))),
+    )))(input)?;
+
+    let foreign_key = ForeignKey {
+        tablename,
+        columns: columns.unwrap_or_default(),
+    };
+    Ok((input, foreign_key))
+}
+
+/// Value of a DEFAULT constraint, as written: a literal, a signed number, a parenthesized
+/// expression or a keyword such as CURRENT_TIMESTAMP
+fn parse_default_value(input: &str) -> IResult<&str, &str> {
+    preceded(
+        space0,
+        alt((
+            // quotes are escaped by doubling them
+            recognize(many1(parse_value)),
+            recognize(pair(opt(char('+')), parse_number)),
+            recognize(parse_parenthesized_text),
+            take_while1(is_identifier_char),
+        )),
+    )(input)
+}
+
+fn parse_column_constraint(input: &str) -> IResult<&str, ColumnConstraint> {
+    let (input, _) = opt(pair(keyword_after_space("CONSTRAINT"), parse_identifier))(input)?;
+    alt((
+        map(
+            tuple((
+                keyword_after_space("PRIMARY"),
+                keyword_after_space("KEY"),
+                opt(alt((
+                    keyword_after_space("ASC"),
+                    keyword_after_space("DESC"),
+                ))),
+                opt(parse_conflict_clause),
+                opt(keyword_after_space("AUTOINCREMENT")),
+            )),
+            |(_, _, order, _, autoincrement)| ColumnConstraint::PrimaryKey {
+                descending: order.is_some_and(|order| order.eq_ignore_ascii_case("DESC")),
+                autoincrement: autoincrement.is_some(),
+            },
+        ),
+        value(
+            ColumnConstraint::NotNull,
+            tuple((
+                keyword_after_space("NOT"),
+                keyword_after_space("NULL"),
+                opt(parse_conflict_clause),
+            )),
+        ),
+        value(
+            ColumnConstraint::Null,
+            pair(keyword_after_space("NULL"), opt(parse_conflict_clause)),
+        ),
+        value(
+            ColumnConstraint::Unique,
+            pair(keyword_after_space("UNIQUE"), opt(parse_conflict_clause)),
+        ),
+        map(
+            preceded(keyword_after_space("CHECK"), parse_parenthesized_text),
+            |text| ColumnConstraint::Check(text.to_string()),
+        ),
+        map(
+            preceded(keyword_after_space("DEFAULT"), parse_default_value),
+            |text| ColumnConstraint::Default(text.to_string()),
+        ),
+        map(
+            preceded(keyword_after_space("COLLATE"), parse_identifier),
+            ColumnConstraint::Collate,
+        ),
+        map(parse_foreign_key_clause, ColumnConstraint::References),
+        map(
+            tuple((
+                opt(pair(
+                    keyword_after_space("GENERATED"),
+                    keyword_after_space("ALWAYS"),
+                )),
+                preceded(keyword_after_space("AS"), parse_parenthesized_text),
+                opt(alt((
+                    keyword_after_space("STORED"),
+                    keyword_after_space("VIRTUAL"),
+                ))),
+            )),
+            |(_, expr, storage)| ColumnConstraint::Generated {
+                expr: expr.to_string(),
+                // generated columns are virtual by default
+                stored: storage.is_some_and(|storage| storage.eq_ignore_ascii_case("STORED")),
+            },
+        ),
+    ))(input)
+}
+
+/// Words that end the type of a column definition
+const COLUMN_CONSTRAINT_KEYWORDS: [&str; 11] = [
+    "CONSTRAINT",
+    "PRIMARY",
+    "NOT",
+    "NULL",
+    "UNIQUE",
+    "CHECK",
+    "DEFAULT",
+    "COLLATE",
+    "REFERENCES",
+    "GENERATED",
+    "AS",
+];
+
+/// Parses a type name such as "INTEGER", "UNSIGNED BIG INT" or "DECIMAL(10,5)". The
+/// arguments in parentheses are kept as written.
+fn parse_type_name(input: &str) -> IResult<&str, String> {
+    let type_word = verify(parse_identifier, |word: &str| {
+        !COLUMN_CONSTRAINT_KEYWORDS
+            .iter()
+            .any(|kw| kw.eq_ignore_ascii_case(word))
+    });
+    let signed_number = delimited(space0, pair(opt(char('+')), parse_number), space0);
+    let (input, words) = many1(type_word)(input)?;
+    let (input, arguments) = opt(terminated(
+        recognize(delimited(
+            char('('),
+            separated_list1(char(','), signed_number),
+            char(')'),
+        )),
+        space0,
+    ))(input)?;
+
+    let type_name = words.join(" ") + arguments.unwrap_or_default();
+    Ok((input, type_name))
+}
+
+fn parse_column_def(input: &str) -> IResult<&str, ColumnDef> {
+    let (input, name) = parse_identifier(input)?;
+    let (input, decl_type) = opt(parse_type_name)(input)?;
+    let (input, constraints) = many0(parse_column_constraint)(input)?;
+    let (input, _) = space0(input)?;
+
+    let column_def = ColumnDef {
+        name,
+        decl_type: decl_type.unwrap_or_default(),
+        constraints,
+    };
+    Ok((input, column_def))
+}
+
+fn parse_table_constraint(input: &str) -> IResult<&str, TableConstraint> {
+    let (input, _) = opt(pair(keyword_after_space("CONSTRAINT"), parse_identifier))(input)?;
+    let (input, constraint) = alt((
+        map(
+            preceded(
+                pair(keyword_after_space("PRIMARY"), keyword_after_space("KEY")),
+                parse_indexed_columns,
+            ),
+            TableConstraint::PrimaryKey,
+        ),
+        map(
+            preceded(keyword_after_space("UNIQUE"), parse_indexed_columns),
+            TableConstraint::Unique,
+        ),
+        map(
+            preceded(keyword_after_space("CHECK"), parse_parenthesized_text),
+            |text| TableConstraint::Check(text.to_string()),
+        ),
+        map(
+            preceded(
+                pair(keyword_after_space("FOREIGN"), keyword_after_space("KEY")),
+                pair(parse_column_list, parse_foreign_key_clause),
+            ),
+            |(columns, references)| TableConstraint::ForeignKey {
+                columns,
+                references,
+            },
+        ),
+    ))(input)?;
+    let (input, _) = opt(parse_conflict_clause)(input)?;
+    let (input, _) = space0(input)?;
+    Ok((input, constraint))
+}
+
+/// Parses `CREATE [TEMP | TEMPORARY | UNIQUE] <object> [IF NOT EXISTS] [schema.]name`
+/// and returns the modifier and the name without its schema
+fn parse_create_object<'a>(
+    object: &'static str,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (Option<&'a str>, String)> {
+    map(
+        tuple((
+            keyword_after_space("CREATE"),
+            opt(alt((
+                keyword_after_space("TEMPORARY"),
+                keyword_after_space("TEMP"),
+                keyword_after_space("UNIQUE"),
+            ))),
+            keyword_after_space(object),
+            opt(tuple((
+                keyword_after_space("IF"),
+                keyword_after_space("NOT"),
+                keyword_after_space("EXISTS"),
+            ))),
+            opt(terminated(parse_identifier, char('.'))),
+            parse_identifier,
+        )),
+        |(_, modifier, _, _, _, name)| (modifier, name),
+    )
+}
+
 // "CREATE TABLE apples\n(\n\tid integer primary key autoincrement,\n\tname text,\n\tcolor text\n)"
 
 pub fn parse_create_table_command(input: &str) -> IResult<&str, CreateTableQuery> {
-    let (input, _) = tag_no_case("CREATE TABLE")(input)?;
-    let (input, tablename) = parse_identifier(input)?;
-    let tablename = tablename.to_string();
-    let (input, _) = tag_no_case("(")(input)?;
-    let (input, _) = multispace0(input)?;
-    let (input, columns_and_types) = parse_column_defs(input)?;
-
-    let columns_and_types: Vec<Vec<String>> = columns_and_types
-        .into_iter()
-        .map(|inner_vec| inner_vec.into_iter().map(|s| s.to_string()).collect())
-        .collect();
+    let (input, (_, tablename)) = parse_create_object("TABLE")(input)?;
+    let (input, _) = char('(')(input)?;
+
+    // column definitions come first, then the table constraints
+    let mut columns = Vec::new();
+    let mut table_constraints = Vec::new();
+    let (mut input, column) = parse_column_def(input)?;
+    columns.push(column);
+    while let Ok((rest, _)) = char::<&str, nom::error::Error<&str>>(',')(input) {
+        if let Ok((rest, constraint)) = parse_table_constraint(rest) {
+            table_constraints.push(constraint);
+            input = rest;
+        } else if table_constraints.is_empty() {
+            let (rest, column) = parse_column_def(rest)?;
+            columns.push(column);
+            input = rest;
+        } else {
+            // columns can not be declared after a table constraint
+            return Err(nom::Err::Error(nom::error::Error::new(
+                rest,
+                nom::error::ErrorKind::Verify,
+            )));
+        }
+    }
+    let (input, _) = char(')')(input)?;
+    let (input, options) = separated_list0(
+        char(','),
+        alt((
+            recognize(pair(
+                keyword_after_space("WITHOUT"),
+                keyword_after_space("ROWID"),
+            )),
+            keyword_after_space("STRICT"),
+        )),
+    )(input)?;
+    let without_rowid = options
+        .iter()
+        .any(|option| option.to_uppercase().contains("ROWID"));
 
     let create_table_query = CreateTableQuery {
-        columns_and_types,
         tablename,
+        columns,
+        table_constraints,
+        without_rowid,
     };
     Ok((input, create_table_query))
 }
 
 // CREATE INDEX idx_companies_country on companies (country)
 pub fn parse_create_index_command(input: &str) -> IResult<&str, CreateIndexQuery> {
-    let (input, _) = tag_no_case("CREATE INDEX")(input)?;
-    let (input, indexname) = parse_identifier(input)?;
-    let indexname = indexname.to_string();
-    let (input, _) = multispace0(input)?;
-    let (input, _) = tag_no_case("on")(input)?;
-    let (input, _) = multispace0(input)?;
+    let (input, (modifier, indexname)) = parse_create_object("INDEX")(input)?;
+    let unique = modifier.is_some_and(|modifier| modifier.eq_ignore_ascii_case("UNIQUE"));
+    let (input, _) = keyword("ON")(input)?;
     let (input, tablename) = parse_identifier(input)?;
-    let tablename = tablename.to_string();
-    let (input, _) = tag_no_case("(")(input)?;
-    let (input, _) = multispace0(input)?;
-    let (input, colname) = parse_identifier(input)?;
-    let colname = colname.to_string();
+    let (input, columns) = parse_indexed_columns(input)?;
+    let (input, where_clause) = opt(parse_where_clause)(input)?;
 
     let create_index_query = CreateIndexQuery {
         indexname,
+        unique,
         tablename,
-        colname,
+        columns,
+        where_clause,
     };
     Ok((input, create_index_query))
 }
+
+// CREATE VIEW yellow_apples AS SELECT name FROM apples WHERE color = 'Yellow'
+pub fn parse_create_view_command(input: &str) -> IResult<&str, CreateViewQuery> {
+    let (input, (_, viewname)) = parse_create_object("VIEW")(input)?;
+    let (input, _) = tag_no_case("AS")(input)?;
+    let (input, _) = space0(input)?;
+    let (input, select_query) = parse_select_command(input)?;
+
+    let create_view_query = CreateViewQuery {
+        viewname,
+        select_query,
+    };
+    Ok((input, create_view_query))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column_infos(sql: &str) -> Vec<ColumnInfo> {
+        let (_, create_table_query) = parse_create_table_command(sql).unwrap();
+        create_table_query.column_infos()
+    }
+
+    #[test]
+    fn column_infos_of_sample_db() {
+        let infos = column_infos(
+            "CREATE TABLE apples\n(\n\tid integer primary key autoincrement,\n\tname text,\n\tcolor text\n)",
+        );
+        assert_eq!(
+            infos.iter().map(|c| c.name.as_str()).collect::<Vec<_>>(),
+            ["id", "name", "color"]
+        );
+        assert!(infos[0].is_rowid_alias);
+        assert_eq!(infos[0].affinity, Affinity::Integer);
+        assert_eq!(infos[1].affinity, Affinity::Text);
+        assert!(!infos[1].is_rowid_alias);
+    }
+
+    #[test]
+    fn column_infos_of_chinook_track() {
+        let infos = column_infos(
+            "CREATE TABLE [Track]
+(
+    [TrackId] INTEGER  NOT NULL,
+    [Name] NVARCHAR(200)  NOT NULL,
+    [AlbumId] INTEGER,
+    [MediaTypeId] INTEGER  NOT NULL,
+    [GenreId] INTEGER,
+    [Composer] NVARCHAR(220),
+    [Milliseconds] INTEGER  NOT NULL,
+    [Bytes] INTEGER,
+    [UnitPrice] NUMERIC(10,2)  NOT NULL,
+    CONSTRAINT [PK_Track] PRIMARY KEY  ([TrackId]),
+    FOREIGN KEY ([AlbumId]) REFERENCES [Album] ([AlbumId]) 
+\t\tON DELETE NO ACTION ON UPDATE NO ACTION,
+    FOREIGN KEY ([GenreId]) REFERENCES [Genre] ([GenreId]) 
+\t\tON DELETE NO ACTION ON UPDATE NO ACTION,
+    FOREIGN KEY ([MediaTypeId]) REFERENCES [MediaType] ([MediaTypeId]) 
+\t\tON DELETE NO ACTION ON UPDATE NO ACTION
+)",
+        );
+        assert_eq!(infos.len(), 9);
+        assert_eq!(infos[0].name, "TrackId");
+        assert!(infos[0].is_rowid_alias);
+        assert!(infos[0].not_null);
+        assert_eq!(infos[1].declared_type, "NVARCHAR(200)");
+        assert_eq!(infos[1].affinity, Affinity::Text);
+        assert!(!infos[2].not_null);
+        assert_eq!(infos[8].name, "UnitPrice");
+        assert_eq!(infos[8].declared_type, "NUMERIC(10,2)");
+        assert_eq!(infos[8].affinity, Affinity::Numeric);
+        assert_eq!(infos.iter().filter(|c| c.is_rowid_alias).count(), 1);
+    }
+
+    #[test]
+    fn column_infos_with_defaults_and_no_types() {
+        let infos = column_infos(
+            "CREATE TABLE t (a INT PRIMARY KEY, b REAL DEFAULT -1.5, c DEFAULT 'x, y', d, e DOUBLE PRECISION NOT NULL)",
+        );
+        // only INTEGER PRIMARY KEY is an alias of the rowid
+        assert!(!infos[0].is_rowid_alias);
+        assert_eq!(infos[0].affinity, Affinity::Integer);
+        assert_eq!(infos[1].default.as_deref(), Some("-1.5"));
+        assert_eq!(infos[1].affinity, Affinity::Real);
+        assert_eq!(infos[2].default.as_deref(), Some("'x, y'"));
+        assert_eq!(infos[2].affinity, Affinity::Blob);
+        assert_eq!(infos[3].declared_type, "");
+        assert_eq!(infos[4].declared_type, "DOUBLE PRECISION");
+        assert_eq!(infos[4].affinity, Affinity::Real);
+        assert!(infos[4].not_null);
+    }
+
+    #[test]
+    fn without_rowid_table_has_no_rowid_alias() {
+        let infos = column_infos("CREATE TABLE kv (k INTEGER PRIMARY KEY, v TEXT) WITHOUT ROWID");
+        assert!(infos.iter().all(|c| !c.is_rowid_alias));
+    }
+
+    #[test]
+    fn and_has_precedence_over_or() {
+        let (rest, expr) = parse_expr("a = 1 OR b >= 2 AND c < 3").unwrap();
+        assert_eq!(rest, "");
+        let comparison = |name: &str, operator, x| Expr::Comparison {
+            left: Box::new(Expr::Column(name.to_string())),
+            operator,
+            right: Box::new(Expr::Literal(Literal::Integer(x))),
+        };
+        assert_eq!(
+            expr,
+            Expr::Logical {
+                left: Box::new(comparison("a", ComparisonOperator::Eq, 1)),
+                operator: LogicalOperator::Or,
+                right: Box::new(Expr::Logical {
+                    left: Box::new(comparison("b", ComparisonOperator::GtEq, 2)),
+                    operator: LogicalOperator::And,
+                    right: Box::new(comparison("c", ComparisonOperator::Lt, 3)),
+                }),
+            }
+        );
+        // a column named like the keyword is not cut
+        assert_eq!(
+            parse_expr("origin = 1").unwrap().1,
+            comparison("origin", ComparisonOperator::Eq, 1)
+        );
+    }
+
+    #[test]
+    fn columns_of_a_multi_column_index() {
+        let (_, create_index_query) = parse_create_index_command(
+            "CREATE INDEX idx_orders_date_amount ON orders (order_date COLLATE NOCASE, amount DESC)",
+        )
+        .unwrap();
+        let columns = create_index_query.columns;
+        assert_eq!(columns.len(), 2);
+        assert_eq!(columns[0].name, "order_date");
+        assert_eq!(columns[0].collation.as_deref(), Some("NOCASE"));
+        assert!(!columns[0].descending);
+        assert_eq!(columns[1].name, "amount");
+        assert_eq!(columns[1].collation, None);
+        assert!(columns[1].descending);
+    }
+
+    #[test]
+    fn autoindexes_of_the_constraints() {
+        let names = |sql: &str| {
+            let (_, create_table_query) = parse_create_table_command(sql).unwrap();
+            create_table_query
+                .autoindex_columns()
+                .into_iter()
+                .map(|columns| columns.into_iter().map(|c| c.name).collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        };
+        // the constraints of the columns come first, and UNIQUE (a) is the index of a
+        assert_eq!(
+            names("CREATE TABLE t (a UNIQUE, b TEXT PRIMARY KEY, c, d, UNIQUE (c, d), UNIQUE (a))"),
+            [vec!["a"], vec!["b"], vec!["c", "d"]]
+        );
+        // the rowid needs no index, but a descending INTEGER PRIMARY KEY is not the rowid
+        assert_eq!(
+            names("CREATE TABLE u (x INTEGER PRIMARY KEY, y UNIQUE, UNIQUE (x))"),
+            [vec!["y"], vec!["x"]]
+        );
+        assert_eq!(
+            names("CREATE TABLE d (id INTEGER PRIMARY KEY DESC, w UNIQUE)"),
+            [vec!["id"], vec!["w"]]
+        );
+        assert_eq!(
+            names("CREATE TABLE kv (k TEXT PRIMARY KEY, v UNIQUE) WITHOUT ROWID"),
+            [vec!["k"], vec!["v"]]
+        );
+
+        let (_, create_table_query) = parse_create_table_command(
+            "CREATE TABLE n (a TEXT COLLATE NOCASE, b, PRIMARY KEY (a, b DESC))",
+        )
+        .unwrap();
+        let columns = &create_table_query.autoindex_columns()[0];
+        assert_eq!(columns[0].collation.as_deref(), Some("NOCASE"));
+        assert!(columns[1].descending);
+    }
+
+    #[test]
+    fn syntax_errors_point_where_the_parsing_stopped() {
+        assert!(parse_sql("SELECT name FROM apples").is_ok());
+        match parse_sql("SELECT name FROM apples WHERE") {
+            Err(Error::ParseError { near, position }) => {
+                assert_eq!(near, "WHERE");
+                assert_eq!(position, 24);
+            }
+            result => panic!("expected a parse error, got {:?}", result),
+        }
+    }
+}
@@ -0,0 +1,163 @@
+//! Prepared statements: the SQL is parsed once, then run again with other values bound
+//! to its placeholders, like with sqlite3_prepare, sqlite3_bind and sqlite3_step.
+
+use crate::{
+    error::{Error, Result},
+    executor::{execute_select, explain_select, plan_select, SelectCursor},
+    page::ColumnContent,
+    row::Row,
+    sql_parser::{parse_sql, Literal, Parameter, SelectQuery, Statement as ParsedStatement},
+    Database, Plan,
+};
+
+/// A SELECT or an EXPLAIN QUERY PLAN parsed once, whose rows are read with step.
+/// The plan is chosen at the first step after a binding, since it depends on the bound
+/// values: `id = ?` is a lookup of the rowid once ? is an integer.
+pub struct Statement<'db> {
+    db: &'db mut Database,
+    select_query: SelectQuery,
+    is_explain: bool,
+    /// Placeholders of the query, see SelectQuery::parameters
+    parameters: Vec<Parameter>,
+    /// Values of the placeholders. Like in sqlite, the unbound ones are NULL
+    values: Vec<(Parameter, ColumnContent)>,
+    /// The query with the bound values and its plan, kept until other values are bound:
+    /// a reset runs the statement again with the same plan. None until the first step.
+    prepared: Option<Prepared>,
+    /// Rows of the current run, None until the first step
+    rows: Option<Rows>,
+}
+
+/// A query with its values bound
+enum Prepared {
+    /// A query whose rows are read one at a time from its table through its plan
+    Streamed(SelectQuery, Plan),
+    /// A query which must read all the rows of its source before returning one: it
+    /// sorts or groups them, or reads a view or a sub select. EXPLAIN QUERY PLAN too.
+    Collected(SelectQuery),
+}
+
+/// Rows of a run of a statement
+enum Rows {
+    Streamed(Box<SelectCursor>),
+    Collected(std::vec::IntoIter<Row>),
+}
+
+impl<'db> Statement<'db> {
+    pub(crate) fn prepare(db: &'db mut Database, sql: &str) -> Result<Self> {
+        let (select_query, is_explain) = match parse_sql(sql)? {
+            ParsedStatement::Select(select_query) => (select_query, false),
+            ParsedStatement::ExplainQueryPlan(select_query) => (select_query, true),
+        };
+        Ok(Statement {
+            db,
+            parameters: select_query.parameters(),
+            select_query,
+            is_explain,
+            values: Vec::new(),
+            prepared: None,
+            rows: None,
+        })
+    }
+
+    /// Binds a value to the placeholder `?index`, numbered from 1 like in sqlite: the
+    /// anonymous `?` are numbered in the order they are written
+    pub fn bind(&mut self, index: usize, value: impl Into<ColumnContent>) -> Result<()> {
+        self.bind_parameter(Parameter::Positional(Some(index)), value.into())
+    }
+
+    /// Binds a value to the placeholder `:name`, the name is given with or without the
+    /// colon
+    pub fn bind_named(&mut self, name: &str, value: impl Into<ColumnContent>) -> Result<()> {
+        let name = name.strip_prefix(':').unwrap_or(name);
+        self.bind_parameter(Parameter::Named(name.to_string()), value.into())
+    }
+
+    /// Binding a value starts the statement again, at its first row
+    fn bind_parameter(&mut self, parameter: Parameter, value: ColumnContent) -> Result<()> {
+        if !self.parameters.contains(&parameter) {
+            return Err(Error::InvalidParameters(match parameter {
+                Parameter::Positional(number) => {
+                    format!(
+                        "No parameter ?{} in the statement",
+                        number.unwrap_or_default()
+                    )
+                }
+                Parameter::Named(name) => format!("No parameter :{} in the statement", name),
+            }));
+        }
+        self.values.retain(|(p, _)| *p != parameter);
+        self.values.push((parameter, value));
+        self.prepared = None;
+        self.reset();
+        Ok(())
+    }
+
+    /// Sets all the placeholders back to NULL
+    pub fn clear_bindings(&mut self) {
+        self.values.clear();
+        self.prepared = None;
+        self.reset();
+    }
+
+    /// Starts the statement again: the next step runs it with the bound values
+    pub fn reset(&mut self) {
+        self.rows = None;
+    }
+
+    /// Next row of the statement, None once all of them are read. The first step runs
+    /// the query: when each row of its table can be processed on its own, the rows are
+    /// then read one at a time, at each step. Otherwise they are collected by the first
+    /// step, and handed out one at a time.
+    pub fn step(&mut self) -> Result<Option<Row>> {
+        if self.rows.is_none() {
+            let prepared = match self.prepared.take() {
+                Some(prepared) => prepared,
+                None => self.prepare_run()?,
+            };
+            let rows = match &prepared {
+                Prepared::Streamed(select_query, plan) => {
+                    SelectCursor::open(self.db, select_query, plan)
+                        .map(|cursor| Rows::Streamed(Box::new(cursor)))
+                }
+                Prepared::Collected(select_query) => match self.is_explain {
+                    true => explain_select(self.db, select_query),
+                    false => execute_select(self.db, select_query),
+                }
+                .map(|result_set| Rows::Collected(result_set.rows.into_iter())),
+            };
+            self.prepared = Some(prepared);
+            self.rows = Some(rows?);
+        }
+        match &mut self.rows {
+            Some(Rows::Streamed(cursor)) => cursor.next(self.db),
+            Some(Rows::Collected(rows)) => Ok(rows.next()),
+            None => Ok(None),
+        }
+    }
+
+    /// Binds the values to the query and chooses its plan
+    fn prepare_run(&mut self) -> Result<Prepared> {
+        let mut select_query = self.select_query.clone();
+        select_query.replace_parameters(&mut |parameter| {
+            Ok(self
+                .values
+                .iter()
+                .find(|(p, _)| p == parameter)
+                .map_or(Literal::Null, |(_, value)| Literal::from(value)))
+        })?;
+        if self.is_explain {
+            return Ok(Prepared::Collected(select_query));
+        }
+        Ok(match plan_select(self.db, &select_query)? {
+            Some((select_query, plan)) => Prepared::Streamed(select_query, plan),
+            None => Prepared::Collected(select_query),
+        })
+    }
+
+    /// Like sqlite3_stmt_isexplain, tells that the rows are the steps of EXPLAIN QUERY
+    /// PLAN
+    pub fn is_explain(&self) -> bool {
+        self.is_explain
+    }
+}
@@ -0,0 +1,236 @@
+use std::cmp::Ordering;
+
+use crate::page::ColumnContent;
+
+/// A value with the order of sqlite: NULL first, then the numbers, then the texts and
+/// then the blobs. The numbers are compared by their value, whatever their type: 1 and
+/// 1.0 are equal, and a large integer is not rounded to a real to be compared with one.
+/// Unlike with the comparison operators of SQL, two NULLs are equal, so that values can
+/// be sorted and grouped.
+/// The equality of ColumnContent is structural instead: Int(1) is not Float(1.0).
+#[derive(Debug, Clone)]
+pub struct Value(pub ColumnContent);
+
+impl From<ColumnContent> for Value {
+    fn from(content: ColumnContent) -> Self {
+        Value(content)
+    }
+}
+
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> Ordering {
+        sort_order(&self.0, &other.0)
+    }
+}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Value {}
+
+/// The order of Value, without wrapping the values. The texts are compared byte by byte,
+/// like with the BINARY collation.
+pub fn sort_order(left: &ColumnContent, right: &ColumnContent) -> Ordering {
+    match (left, right) {
+        (ColumnContent::Int(x), ColumnContent::Int(y)) => x.cmp(y),
+        (ColumnContent::Int(x), ColumnContent::Float(y)) => compare_int_float(*x, *y),
+        (ColumnContent::Float(x), ColumnContent::Int(y)) => compare_int_float(*y, *x).reverse(),
+        (ColumnContent::Float(x), ColumnContent::Float(y)) => compare_floats(*x, *y),
+        (ColumnContent::String(x), ColumnContent::String(y)) => x.as_bytes().cmp(y.as_bytes()),
+        (ColumnContent::Blob(x), ColumnContent::Blob(y)) => x.cmp(y),
+        (left, right) => type_rank(left).cmp(&type_rank(right)),
+    }
+}
+
+/// Compares lists of values with sort_order, the first values first
+pub fn compare_keys(left: &[ColumnContent], right: &[ColumnContent]) -> Ordering {
+    left.iter()
+        .zip(right)
+        .map(|(l, r)| sort_order(l, r))
+        .find(|ordering| *ordering != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+/// Position of the type of a value in the sqlite sort order
+fn type_rank(content: &ColumnContent) -> u8 {
+    match content {
+        ColumnContent::Null => 0,
+        ColumnContent::Int(_) | ColumnContent::Float(_) => 1,
+        ColumnContent::String(_) => 2,
+        ColumnContent::Blob(_) => 3,
+    }
+}
+
+/// sqlite never stores a NaN, it becomes a NULL: it comes before the other numbers
+fn compare_floats(x: f64, y: f64) -> Ordering {
+    x.partial_cmp(&y)
+        .unwrap_or_else(|| y.is_nan().cmp(&x.is_nan()))
+}
+
+/// Compares an integer and a real without rounding the integer, like
+/// sqlite3IntFloatCompare: above 2^53, an i64 is not always exactly a f64
+fn compare_int_float(x: i64, y: f64) -> Ordering {
+    // -2^63 and 2^63 are exact in a f64
+    const MIN: f64 = i64::MIN as f64;
+    const MAX: f64 = -MIN;
+    if y.is_nan() {
+        return Ordering::Greater;
+    }
+    if y < MIN {
+        return Ordering::Greater;
+    }
+    if y >= MAX {
+        return Ordering::Less;
+    }
+    // the real is in the range of the integers: its integer part is exact
+    let integer_part = y.trunc();
+    match x.cmp(&(integer_part as i64)) {
+        Ordering::Equal => integer_part.partial_cmp(&y).unwrap_or(Ordering::Equal),
+        ordering => ordering,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    use ColumnContent::*;
+
+    fn text(s: &str) -> ColumnContent {
+        String(s.to_string())
+    }
+
+    /// Values in the order of `SELECT quote(x) FROM ... ORDER BY x` in sqlite3
+    fn sorted_by_sqlite3() -> Vec<ColumnContent> {
+        vec![
+            Null,
+            Float(-1e300),
+            Int(-3),
+            Float(-0.5),
+            Int(1),
+            Float(1.5),
+            Float(2.0),
+            Int(i64::MAX),
+            Float(9.3e18),
+            text(""),
+            text("10"),
+            text("9"),
+            text("ABC"),
+            text("abc"),
+            Blob(vec![]),
+            Blob(vec![0]),
+            Blob(vec![0xff]),
+        ]
+    }
+
+    #[test]
+    fn values_are_sorted_like_in_sqlite3() {
+        let expected = sorted_by_sqlite3();
+        let mut values = expected.clone();
+        values.reverse();
+        values.sort_by(sort_order);
+        assert_eq!(values, expected);
+    }
+
+    #[test]
+    fn integers_and_reals_are_compared_exactly() {
+        // checked with sqlite3: SELECT 9007199254740993 > 9007199254740992.0, ...
+        let cases = [
+            (
+                Int(9007199254740993),
+                Float(9007199254740992.0),
+                Ordering::Greater,
+            ),
+            (Int(i64::MAX), Float(9223372036854775808.0), Ordering::Less),
+            (
+                Int(i64::MIN),
+                Float(-9223372036854775808.0),
+                Ordering::Equal,
+            ),
+            (
+                Int(i64::MIN),
+                Float(-9223372036854777856.0),
+                Ordering::Greater,
+            ),
+            (
+                Int(i64::MAX),
+                Float(9223372036854774784.0),
+                Ordering::Greater,
+            ),
+            (Int(1), Float(1.0), Ordering::Equal),
+            (Int(0), Float(-0.0), Ordering::Equal),
+            (Int(0), Float(0.5), Ordering::Less),
+            (Int(0), Float(-0.5), Ordering::Greater),
+            (Int(i64::MAX), Float(1e308), Ordering::Less),
+        ];
+        for (left, right, ordering) in cases {
+            assert_eq!(
+                sort_order(&left, &right),
+                ordering,
+                "{:?} {:?}",
+                left,
+                right
+            );
+            assert_eq!(
+                sort_order(&right, &left),
+                ordering.reverse(),
+                "{:?} {:?}",
+                right,
+                left
+            );
+        }
+        assert_eq!(Value(Int(1)), Value(Float(1.0)));
+        assert!(Value(Null) < Value(Float(f64::NEG_INFINITY)));
+    }
+
+    fn any_value() -> impl Strategy<Value = ColumnContent> {
+        prop_oneof![
+            Just(Null),
+            any::<i64>().prop_map(Int),
+            // the reals around the integers, and the integers stored as reals
+            any::<f64>().prop_map(Float),
+            any::<i64>().prop_map(|x| Float(x as f64)),
+            "[a-c]{0,3}".prop_map(String),
+            proptest::collection::vec(0u8..3, 0..3).prop_map(Blob),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn the_order_is_total(a in any_value(), b in any_value(), c in any_value()) {
+            prop_assert_eq!(sort_order(&a, &b), sort_order(&b, &a).reverse());
+            prop_assert_eq!(sort_order(&a, &a), Ordering::Equal);
+            if sort_order(&a, &b) != Ordering::Greater && sort_order(&b, &c) != Ordering::Greater {
+                prop_assert_ne!(sort_order(&a, &c), Ordering::Greater);
+            }
+        }
+
+        #[test]
+        fn types_come_in_the_order_of_sqlite(a in any_value(), b in any_value()) {
+            if type_rank(&a) != type_rank(&b) {
+                prop_assert_eq!(sort_order(&a, &b), type_rank(&a).cmp(&type_rank(&b)));
+            }
+        }
+
+        #[test]
+        fn integers_and_reals_are_compared_by_value(x in any::<i64>(), y in -1e19f64..1e19) {
+            // the exact comparison, as rationals with the fraction of the real
+            let floor = y.floor();
+            let expected = (x as i128)
+                .cmp(&(floor as i128))
+                .then(if y > floor { Ordering::Less } else { Ordering::Equal });
+            prop_assert_eq!(sort_order(&Int(x), &Float(y)), expected);
+        }
+    }
+}
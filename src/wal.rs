@@ -0,0 +1,176 @@
+use std::{collections::HashMap, io::Cursor};
+
+use binrw::{binrw, BinRead};
+
+/// In WAL mode, sqlite appends the pages changed by a transaction to the -wal file next to
+/// the database, as frames. The pages of the main file are only replaced when the log is
+/// checkpointed, until then the log holds the latest version of these pages.
+/// https://www.sqlite.org/fileformat.html#the_write_ahead_log
+#[derive(Debug)]
+#[binrw]
+#[brw(big)]
+pub struct WalHeader {
+    /// 0x377f0682 or 0x377f0683, the lowest bit tells the endianness of the checksums
+    pub magic_number: u32,
+    pub file_format_version: u32,
+    pub page_size: u32,
+    pub checkpoint_sequence_number: u32,
+    /// Frames written before the last reset of the log have other salts
+    pub salt_1: u32,
+    pub salt_2: u32,
+    pub checksum_1: u32,
+    pub checksum_2: u32,
+}
+
+/// Each frame is this header followed by the content of a page
+#[derive(Debug)]
+#[binrw]
+#[brw(big)]
+pub struct WalFrameHeader {
+    pub page_no: u32,
+    /// For the last frame of a transaction, the number of pages of the database after it.
+    /// 0 for the other frames.
+    pub db_size_after_commit: u32,
+    pub salt_1: u32,
+    pub salt_2: u32,
+    pub checksum_1: u32,
+    pub checksum_2: u32,
+}
+
+const WAL_HEADER_SIZE: usize = 32;
+const WAL_FRAME_HEADER_SIZE: usize = 24;
+
+/// The pages of the committed transactions of a log
+#[derive(Debug)]
+pub struct Wal {
+    bytes: Vec<u8>,
+    pub page_size: u32,
+    /// Position in bytes of the latest committed content of each page
+    frames: HashMap<u32, usize>,
+    /// Number of pages of the database after the last commit
+    pub db_size: u32,
+}
+
+impl Wal {
+    /// Reads the frames up to the last valid commit. A frame is valid if it has the salts of
+    /// the header and if its checksum, which covers the header and all the previous frames,
+    /// is right: the end of the log may be left by an interrupted transaction or by a
+    /// previous use of the file. Returns None if no transaction is committed.
+    pub fn from_bytes(bytes: Vec<u8>) -> binrw::BinResult<Option<Wal>> {
+        if bytes.len() < WAL_HEADER_SIZE {
+            return Ok(None);
+        }
+        let wal_header = WalHeader::read(&mut Cursor::new(&bytes))?;
+        let big_endian = match wal_header.magic_number {
+            0x377f0682 => false,
+            0x377f0683 => true,
+            _ => return Ok(None),
+        };
+        let mut checksum = wal_checksum(&bytes[..WAL_HEADER_SIZE - 8], big_endian, (0, 0));
+        if checksum != (wal_header.checksum_1, wal_header.checksum_2) {
+            return Ok(None);
+        }
+
+        let page_size = wal_header.page_size as usize;
+        let mut frames = HashMap::new();
+        let mut uncommitted_frames = HashMap::new();
+        let mut db_size = 0;
+        let mut position = WAL_HEADER_SIZE;
+        while position + WAL_FRAME_HEADER_SIZE + page_size <= bytes.len() {
+            let frame_header = WalFrameHeader::read(&mut Cursor::new(&bytes[position..]))?;
+            if (frame_header.salt_1, frame_header.salt_2) != (wal_header.salt_1, wal_header.salt_2)
+            {
+                break;
+            }
+            let content = position + WAL_FRAME_HEADER_SIZE;
+            checksum = wal_checksum(&bytes[position..position + 8], big_endian, checksum);
+            checksum = wal_checksum(&bytes[content..content + page_size], big_endian, checksum);
+            if checksum != (frame_header.checksum_1, frame_header.checksum_2) {
+                break;
+            }
+
+            uncommitted_frames.insert(frame_header.page_no, content);
+            if frame_header.db_size_after_commit != 0 {
+                frames.extend(uncommitted_frames.drain());
+                db_size = frame_header.db_size_after_commit;
+            }
+            position = content + page_size;
+        }
+
+        if db_size == 0 {
+            return Ok(None);
+        }
+        Ok(Some(Wal {
+            bytes,
+            page_size: wal_header.page_size,
+            frames,
+            db_size,
+        }))
+    }
+
+    /// Latest committed content of a page, if the log has one
+    pub fn page(&self, page_no: u32) -> Option<&[u8]> {
+        self.frames
+            .get(&page_no)
+            .map(|&position| &self.bytes[position..position + self.page_size as usize])
+    }
+}
+
+/// The checksums of the log sum its content as pairs of 32-bit integers, starting from the
+/// checksum of the previous frame
+fn wal_checksum(bytes: &[u8], big_endian: bool, (mut s0, mut s1): (u32, u32)) -> (u32, u32) {
+    let to_u32 = if big_endian {
+        u32::from_be_bytes
+    } else {
+        u32::from_le_bytes
+    };
+    for words in bytes.chunks_exact(8) {
+        let x0 = to_u32([words[0], words[1], words[2], words[3]]);
+        let x1 = to_u32([words[4], words[5], words[6], words[7]]);
+        s0 = s0.wrapping_add(x0).wrapping_add(s1);
+        s1 = s1.wrapping_add(x1).wrapping_add(s0);
+    }
+    (s0, s1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture() -> Vec<u8> {
+        std::fs::read("tests/fixtures/wal.db-wal").unwrap()
+    }
+
+    #[test]
+    fn frames_up_to_the_last_commit() {
+        // 6 committed frames, then a frame which is not committed
+        let wal = Wal::from_bytes(fixture()).unwrap().unwrap();
+        assert_eq!(wal.page_size, 4096);
+        assert_eq!(wal.db_size, 3);
+        assert_eq!(wal.frames.len(), 3);
+        let page_2 = wal.page(2).unwrap();
+        let contains = |word: &[u8]| page_2.windows(word.len()).any(|w| w == word);
+        assert!(contains(b"cherry"));
+        assert!(!contains(b"cherrz"));
+        assert!(wal.page(4).is_none());
+    }
+
+    #[test]
+    fn frames_after_a_wrong_checksum_are_ignored() {
+        // the last frame deletes 'pear', the previous transactions still have 3 pages
+        let mut bytes = fixture();
+        bytes[WAL_HEADER_SIZE + 5 * (WAL_FRAME_HEADER_SIZE + 4096) + 100] ^= 1;
+        let wal = Wal::from_bytes(bytes).unwrap().unwrap();
+        assert_eq!(wal.db_size, 3);
+
+        // the first frame
+        let mut bytes = fixture();
+        bytes[WAL_HEADER_SIZE + WAL_FRAME_HEADER_SIZE] ^= 1;
+        assert!(Wal::from_bytes(bytes).unwrap().is_none());
+
+        // the header
+        let mut bytes = fixture();
+        bytes[12] ^= 1;
+        assert!(Wal::from_bytes(bytes).unwrap().is_none());
+    }
+}
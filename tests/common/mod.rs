@@ -0,0 +1,321 @@
+//! Builds small databases in code, for the edge cases which are hard to get from sqlite3
+//! with a fixed layout: a table on exactly two leaf pages, an empty table, a record with
+//! every serial type.
+//! The bytes are written here, without the writers of the crate, so that a bug in them
+//! does not hide the same bug in the readers. Only the tables of rowids are supported,
+//! with records small enough to fit in their page.
+
+use sqlite_starter_rust::page::ColumnContent;
+
+const HEADER_SIZE: usize = 100;
+const LEAF_HEADER_SIZE: usize = 8;
+const INTERIOR_HEADER_SIZE: usize = 12;
+const TABLE_LEAF: u8 = 0x0d;
+const TABLE_INTERIOR: u8 = 0x05;
+
+/// A table with its rows, in the order of their rowids
+pub struct Table {
+    name: String,
+    sql: String,
+    rows: Vec<(i64, Vec<ColumnContent>)>,
+    rows_per_leaf: Option<usize>,
+}
+
+impl Table {
+    /// The name is the one of the CREATE TABLE statement, it is not parsed from it
+    pub fn new(name: &str, sql: &str) -> Self {
+        Table {
+            name: name.to_string(),
+            sql: sql.to_string(),
+            rows: Vec::new(),
+            rows_per_leaf: None,
+        }
+    }
+
+    /// The column aliasing the rowid, if any, is stored as NULL, like sqlite does
+    pub fn row(mut self, rowid: i64, values: Vec<ColumnContent>) -> Self {
+        self.rows.push((rowid, values));
+        self
+    }
+
+    /// Puts at most this many rows on each leaf page instead of filling them
+    pub fn rows_per_leaf(mut self, rows_per_leaf: usize) -> Self {
+        self.rows_per_leaf = Some(rows_per_leaf);
+        self
+    }
+}
+
+/// The description of a database, written with write
+pub struct DatabaseBuilder {
+    page_size: usize,
+    tables: Vec<Table>,
+}
+
+impl Default for DatabaseBuilder {
+    fn default() -> Self {
+        DatabaseBuilder {
+            page_size: 4096,
+            tables: Vec::new(),
+        }
+    }
+}
+
+impl DatabaseBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn table(mut self, table: Table) -> Self {
+        self.tables.push(table);
+        self
+    }
+
+    /// Bytes of the database file: the schema on the first page, then the pages of each
+    /// table, their root last
+    pub fn build(&self) -> Vec<u8> {
+        // the pages after the first one, page n is at n - 2
+        let mut pages = Vec::new();
+        let mut schema_rows = Vec::new();
+        for (i, table) in self.tables.iter().enumerate() {
+            let root_page = self.write_b_tree(&mut pages, table);
+            let values = vec![
+                text("table"),
+                text(&table.name),
+                text(&table.name),
+                ColumnContent::Int(root_page as i64),
+                text(&table.sql),
+            ];
+            schema_rows.push((i as i64 + 1, values));
+        }
+
+        let cells = schema_rows
+            .iter()
+            .map(|(rowid, values)| self.leaf_cell(*rowid, values))
+            .collect::<Vec<_>>();
+        let mut first_page = self.page(HEADER_SIZE, TABLE_LEAF, &cells, None);
+        first_page[..HEADER_SIZE].copy_from_slice(&self.header(pages.len() + 1));
+        std::iter::once(first_page).chain(pages).flatten().collect()
+    }
+
+    /// Writes the database in the temporary directory of the tests and returns its path
+    pub fn write(&self, name: &str) -> String {
+        let path = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("{}.db", name));
+        std::fs::write(&path, self.build()).unwrap();
+        path.to_string_lossy().into_owned()
+    }
+
+    /// The 100 bytes of the database header, for a database in UTF-8 without free pages
+    fn header(&self, nb_pages: usize) -> Vec<u8> {
+        let mut header = b"SQLite format 3\0".to_vec();
+        let page_size = match self.page_size {
+            65536 => 1,
+            page_size => page_size as u16,
+        };
+        header.extend(page_size.to_be_bytes());
+        // versions 1 for the rollback journal, no reserved bytes, payload fractions
+        header.extend([1, 1, 0, 64, 32, 32]);
+        let fields: [u32; 12] = [
+            1, // file change counter
+            nb_pages as u32,
+            0, // first freelist trunk page
+            0, // number of freelist pages
+            1, // schema cookie
+            4, // schema format number
+            0, // default page cache size
+            0, // largest root page, without auto vacuum
+            1, // UTF-8
+            0, // user version
+            0, // incremental vacuum
+            0, // application id
+        ];
+        for field in fields {
+            header.extend(field.to_be_bytes());
+        }
+        // reserved for expansion
+        header.extend([0; 20]);
+        header.extend(1u32.to_be_bytes()); // version valid for, the change counter
+        header.extend(3045000u32.to_be_bytes());
+        assert_eq!(header.len(), HEADER_SIZE);
+        header
+    }
+
+    /// Writes the leaf pages of a table, then the interior pages above them, and returns
+    /// the number of its root page
+    fn write_b_tree(&self, pages: &mut Vec<Vec<u8>>, table: &Table) -> usize {
+        // the pages of a level, with the largest rowid under each of them
+        let mut children = Vec::new();
+        let mut cells = Vec::new();
+        let mut max_rowid = 0;
+        for (rowid, values) in &table.rows {
+            let cell = self.leaf_cell(*rowid, values);
+            let is_full = table
+                .rows_per_leaf
+                .map_or(!self.fits(LEAF_HEADER_SIZE, &cells, &cell), |n| {
+                    cells.len() == n
+                });
+            if is_full && !cells.is_empty() {
+                let page = self.page(0, TABLE_LEAF, &std::mem::take(&mut cells), None);
+                children.push((push_page(pages, page), max_rowid));
+            }
+            cells.push(cell);
+            max_rowid = *rowid;
+        }
+        // an empty table is a single empty leaf
+        if !cells.is_empty() || children.is_empty() {
+            let page = self.page(0, TABLE_LEAF, &cells, None);
+            children.push((push_page(pages, page), max_rowid));
+        }
+
+        while children.len() > 1 {
+            let mut parents = Vec::new();
+            let mut cells = Vec::new();
+            let mut level = std::mem::take(&mut children).into_iter().peekable();
+            while let Some((page_no, max_rowid)) = level.next() {
+                let cell = interior_cell(page_no, max_rowid);
+                // the last child of a page is its right pointer, not a cell
+                if level.peek().is_none() || !self.fits(INTERIOR_HEADER_SIZE, &cells, &cell) {
+                    let page = self.page(0, TABLE_INTERIOR, &cells, Some(page_no));
+                    parents.push((push_page(pages, page), max_rowid));
+                    cells.clear();
+                } else {
+                    cells.push(cell);
+                }
+            }
+            children = parents;
+        }
+        children[0].0
+    }
+
+    /// Whether one more cell and its pointer fit in a page
+    fn fits(&self, header_size: usize, cells: &[Vec<u8>], cell: &[u8]) -> bool {
+        let used: usize = cells.iter().map(|cell| cell.len() + 2).sum();
+        header_size + used + cell.len() + 2 <= self.page_size
+    }
+
+    /// A page with its cells at the end, in reverse order like sqlite writes them. The
+    /// first page has the database header before the page header, it is left as zeros.
+    fn page(
+        &self,
+        offset: usize,
+        page_type: u8,
+        cells: &[Vec<u8>],
+        right: Option<usize>,
+    ) -> Vec<u8> {
+        let mut page = vec![0; self.page_size];
+        let header_size = match right {
+            Some(_) => INTERIOR_HEADER_SIZE,
+            None => LEAF_HEADER_SIZE,
+        };
+        let mut content_start = self.page_size;
+        let mut pointer = offset + header_size;
+        for cell in cells {
+            content_start -= cell.len();
+            assert!(
+                pointer + 2 <= content_start,
+                "the cells do not fit in the page"
+            );
+            page[content_start..content_start + cell.len()].copy_from_slice(cell);
+            page[pointer..pointer + 2].copy_from_slice(&(content_start as u16).to_be_bytes());
+            pointer += 2;
+        }
+        page[offset] = page_type;
+        // no freeblock
+        page[offset + 3..offset + 5].copy_from_slice(&(cells.len() as u16).to_be_bytes());
+        // 65536 is stored as 0
+        page[offset + 5..offset + 7].copy_from_slice(&(content_start as u16).to_be_bytes());
+        if let Some(right) = right {
+            page[offset + 8..offset + 12].copy_from_slice(&(right as u32).to_be_bytes());
+        }
+        page
+    }
+
+    /// The size of the record, the rowid and the record. The payloads which would spill
+    /// to overflow pages are not supported.
+    fn leaf_cell(&self, rowid: i64, values: &[ColumnContent]) -> Vec<u8> {
+        let record = record(values);
+        assert!(
+            record.len() <= self.page_size - 35,
+            "the record of the rowid {} needs overflow pages",
+            rowid
+        );
+        let mut cell = varint(record.len() as u64);
+        cell.extend(varint(rowid as u64));
+        cell.extend(record);
+        cell
+    }
+}
+
+/// Number of the page, the first page is not in the pages
+fn push_page(pages: &mut Vec<Vec<u8>>, page: Vec<u8>) -> usize {
+    pages.push(page);
+    pages.len() + 1
+}
+
+fn interior_cell(left_child: usize, key: i64) -> Vec<u8> {
+    let mut cell = (left_child as u32).to_be_bytes().to_vec();
+    cell.extend(varint(key as u64));
+    cell
+}
+
+pub fn text(s: &str) -> ColumnContent {
+    ColumnContent::String(s.to_string())
+}
+
+/// A record in the smallest serial types, as sqlite writes it: 0 and 1 without bytes,
+/// the integers on the fewest bytes which hold them
+pub fn record(values: &[ColumnContent]) -> Vec<u8> {
+    let mut types = Vec::new();
+    let mut body = Vec::new();
+    for value in values {
+        let (serial_type, bytes) = match value {
+            ColumnContent::Null => (0, vec![]),
+            ColumnContent::Int(0) => (8, vec![]),
+            ColumnContent::Int(1) => (9, vec![]),
+            ColumnContent::Int(x) => {
+                let (serial_type, nb_bytes) = match x {
+                    -0x80..=0x7f => (1, 1),
+                    -0x8000..=0x7fff => (2, 2),
+                    -0x80_0000..=0x7f_ffff => (3, 3),
+                    -0x8000_0000..=0x7fff_ffff => (4, 4),
+                    -0x8000_0000_0000..=0x7fff_ffff_ffff => (5, 6),
+                    _ => (6, 8),
+                };
+                (serial_type, x.to_be_bytes()[8 - nb_bytes..].to_vec())
+            }
+            ColumnContent::Float(x) => (7, x.to_be_bytes().to_vec()),
+            ColumnContent::Blob(blob) => (blob.len() as u64 * 2 + 12, blob.clone()),
+            ColumnContent::String(s) => (s.len() as u64 * 2 + 13, s.as_bytes().to_vec()),
+        };
+        types.extend(varint(serial_type));
+        body.extend(bytes);
+    }
+    // the size of the header includes its own varint
+    let mut nb_bytes_header = types.len() + 1;
+    if varint(nb_bytes_header as u64).len() > 1 {
+        nb_bytes_header += 1;
+    }
+    let mut record = varint(nb_bytes_header as u64);
+    record.extend(types);
+    record.extend(body);
+    record
+}
+
+/// The varints of sqlite: 7 bits in each byte, the high bit set when another byte
+/// follows, and the 9th byte with all its 8 bits
+pub fn varint(value: u64) -> Vec<u8> {
+    if value >> 56 != 0 {
+        let mut bytes = (0..8)
+            .map(|i| ((value >> (57 - 7 * i)) & 0x7f) as u8 | 0x80)
+            .collect::<Vec<_>>();
+        bytes.push(value as u8);
+        return bytes;
+    }
+    let mut bytes = vec![(value & 0x7f) as u8];
+    let mut value = value >> 7;
+    while value != 0 {
+        bytes.push((value & 0x7f) as u8 | 0x80);
+        value >>= 7;
+    }
+    bytes.reverse();
+    bytes
+}
@@ -0,0 +1,574 @@
+use std::io::Cursor;
+
+use serde::Deserialize;
+
+use sqlite_starter_rust::{
+    error::{ConversionError, Error},
+    page::ColumnContent,
+    row::Row,
+    schema_table::IndexInfo,
+    sql_parser::Affinity,
+    AsyncDatabase, Database, OpenOptions, Statement,
+};
+
+/// Values of the rows of a result set
+fn values(rows: &[Row]) -> Vec<Vec<ColumnContent>> {
+    rows.iter().map(|row| row.values().to_vec()).collect()
+}
+
+#[test]
+fn tables_and_rows_of_a_database() {
+    let mut db = Database::open("sample.db").unwrap();
+    assert_eq!(db.header().page_size(), 4096);
+    assert_eq!(db.table_names(), vec!["apples", "oranges"]);
+    assert_eq!(db.schema().get_nb_tables(), 3);
+
+    let result_set = db
+        .execute("SELECT id, name FROM apples WHERE color = 'Yellow'")
+        .unwrap();
+    assert_eq!(result_set.columns, vec!["id", "name"]);
+    assert_eq!(
+        values(&result_set.rows),
+        vec![vec![
+            ColumnContent::Int(4),
+            ColumnContent::String("Golden Delicious".to_string())
+        ]]
+    );
+    assert!(!result_set.is_explain);
+
+    // a database is reused across statements
+    let count = db.execute("SELECT count(*) FROM oranges").unwrap();
+    assert_eq!(values(&count.rows), vec![vec![ColumnContent::Int(6)]]);
+}
+
+#[test]
+fn parameters_and_query_plans() {
+    let mut db = Database::open("tests/fixtures/companies.db").unwrap();
+    let result_set = db
+        .execute_with_params(
+            "SELECT count(*) FROM companies WHERE country = ?",
+            &["eritrea".to_string()],
+        )
+        .unwrap();
+    assert_eq!(values(&result_set.rows), vec![vec![ColumnContent::Int(30)]]);
+
+    let plan = db
+        .execute("EXPLAIN QUERY PLAN SELECT name FROM companies WHERE id = 5")
+        .unwrap();
+    assert!(plan.is_explain);
+    assert_eq!(
+        values(&plan.rows),
+        vec![vec![ColumnContent::String(
+            "SEARCH companies USING INTEGER PRIMARY KEY (rowid=?)".to_string()
+        )]]
+    );
+}
+
+#[test]
+fn values_are_converted_to_rust_types() {
+    use ColumnContent::{Blob, Float, Int, Null};
+    assert_eq!(i64::try_from(&Int(-3)), Ok(-3));
+    assert_eq!(f64::try_from(&Int(2)), Ok(2.0));
+    assert_eq!(
+        String::try_from(&ColumnContent::String("a".to_string())),
+        Ok("a".to_string())
+    );
+    assert_eq!(Vec::<u8>::try_from(&Blob(vec![1, 2])), Ok(vec![1, 2]));
+    // the truth values of sqlite
+    assert_eq!(bool::try_from(&Int(2)), Ok(true));
+    assert_eq!(bool::try_from(&Float(0.0)), Ok(false));
+    assert_eq!(
+        bool::try_from(&ColumnContent::String("1.5kg".to_string())),
+        Ok(true)
+    );
+    assert_eq!(
+        bool::try_from(&ColumnContent::String("yes".to_string())),
+        Ok(false)
+    );
+
+    let error = i64::try_from(&Float(1.5)).unwrap_err();
+    assert_eq!(
+        error,
+        ConversionError {
+            expected: "integer",
+            found: "real"
+        }
+    );
+    assert_eq!(error.to_string(), "expected integer, found real");
+    assert_eq!(
+        bool::try_from(&Null).unwrap_err().to_string(),
+        "expected boolean, found null"
+    );
+    assert_eq!(
+        String::try_from(&Blob(vec![])).unwrap_err().to_string(),
+        "expected text, found blob"
+    );
+    // a NULL is only None
+    assert_eq!(ColumnContent::from(None::<i64>), Null);
+}
+
+#[test]
+fn values_are_read_by_name_with_their_type() {
+    let mut db = Database::open("tests/fixtures/reals.db").unwrap();
+    let result_set = db
+        .execute("SELECT id, label AS name, value FROM reals WHERE label = 'tenth'")
+        .unwrap();
+    let row = &result_set.rows[0];
+    assert_eq!(row.columns(), ["id", "name", "value"]);
+    assert_eq!(row.get::<i64>("id").unwrap(), 1);
+    assert_eq!(row.get::<String>("NAME").unwrap(), "tenth");
+    assert_eq!(row.get::<f64>("value").unwrap(), 0.1);
+    assert_eq!(row.get::<Option<f64>>("value").unwrap(), Some(0.1));
+    // the INTEGER PRIMARY KEY is the rowid
+    assert_eq!(row.get::<i64>("rowid").unwrap(), 1);
+    assert_eq!(row.get::<i64>("_ROWID_").unwrap(), 1);
+
+    assert_eq!(
+        row.get::<i64>("name").unwrap_err().to_string(),
+        "Could not read the column \"name\": expected integer, found text"
+    );
+    assert_eq!(
+        row.get::<i64>("label").unwrap_err().to_string(),
+        "Parse error: no such column: label"
+    );
+
+    let result_set = db
+        .execute("SELECT * FROM (SELECT min(value) AS value FROM reals WHERE value < 0)")
+        .unwrap();
+    assert_eq!(result_set.rows[0].get::<f64>("value").unwrap(), -1.5);
+    // the rowid is not selected
+    assert!(result_set.rows[0].get::<i64>("rowid").is_err());
+    let result_set = db
+        .execute("SELECT value FROM reals WHERE value > 1e300")
+        .unwrap();
+    assert!(result_set.rows[0].get::<i64>("rowid").is_err());
+
+    // a column which is not a rowid alias is not the rowid
+    let mut db = Database::open("tests/fixtures/rowid_alias.db").unwrap();
+    let result_set = db.execute("SELECT * FROM codes WHERE code = 3").unwrap();
+    assert!(result_set.rows[0].get::<i64>("rowid").is_err());
+    let result_set = db.execute("SELECT * FROM people LIMIT 1").unwrap();
+    assert_eq!(result_set.rows[0].get::<String>("id").unwrap(), "p-1");
+    assert_eq!(
+        result_set.rows[0]
+            .get::<Option<i64>>("rowid")
+            .unwrap_err()
+            .to_string(),
+        "Parse error: no such column: rowid"
+    );
+}
+
+#[test]
+fn errors_are_returned() {
+    assert!(Database::open("tests/fixtures/missing.db").is_err());
+
+    let mut db = Database::open("sample.db").unwrap();
+    let err = db.execute("SELECT name FROM pears").unwrap_err();
+    assert_eq!(err.to_string(), "Parse error: no such table: pears");
+    let err = db.execute("SELECT name FROM apples LIMIT").unwrap_err();
+    assert!(err.to_string().starts_with("Parse error: near"), "{}", err);
+    // the database can still be used after an error
+    assert_eq!(db.execute("SELECT id FROM apples").unwrap().rows.len(), 4);
+}
+
+#[test]
+fn errors_can_be_matched() {
+    let mut db = Database::open("sample.db").unwrap();
+    assert!(matches!(
+        db.execute("SELECT name FROM pears"),
+        Err(Error::NoSuchTable(name)) if name == "pears"
+    ));
+    assert!(matches!(
+        db.execute("SELECT name FROM apples LIMIT"),
+        Err(Error::ParseError { position: 24, .. })
+    ));
+    assert!(matches!(
+        db.execute("SELECT nme FROM apples"),
+        Err(Error::NoSuchColumn(name)) if name == "nme"
+    ));
+
+    // the page holding the pointer out of the file
+    let mut db = Database::open("tests/fixtures/corrupted_out_of_range.db").unwrap();
+    assert!(matches!(
+        db.execute("SELECT name FROM items"),
+        Err(Error::CorruptPage { page_no: 2, .. })
+    ));
+    assert!(matches!(
+        Database::open("tests/fixtures/truncated.db"),
+        Err(Error::CorruptDatabase(_))
+    ));
+}
+
+#[test]
+fn memory_mapped_databases_read_the_same_rows() {
+    let sql = "SELECT id, name, data FROM files WHERE size > 900";
+    let mut db = Database::open("tests/fixtures/wide_blobs.db").unwrap();
+    let mut mapped_db = Database::open_mmap("tests/fixtures/wide_blobs.db").unwrap();
+    let result_set = db.execute(sql).unwrap();
+    assert!(!result_set.rows.is_empty());
+    assert_eq!(mapped_db.execute(sql).unwrap().rows, result_set.rows);
+    assert_eq!(mapped_db.nb_pages_read(), db.nb_pages_read());
+}
+
+#[test]
+fn pages_are_read_with_one_system_call() {
+    // the first page is read for the header, and then for the schema
+    let mut db = Database::open("tests/fixtures/companies.db").unwrap();
+    db.execute("SELECT count(*) FROM companies").unwrap();
+    assert_eq!(db.nb_file_reads(), db.nb_pages_read() + 1);
+
+    // the overflow pages are read with their link
+    let mut db = Database::open("tests/fixtures/wide_blobs.db").unwrap();
+    db.execute("SELECT data FROM files").unwrap();
+    assert_eq!(db.nb_pages_read(), 302);
+    assert_eq!(db.nb_file_reads(), 303);
+
+    let mut db = Database::open_mmap("tests/fixtures/wide_blobs.db").unwrap();
+    db.execute("SELECT data FROM files").unwrap();
+    assert_eq!(db.nb_file_reads(), 0);
+}
+
+#[test]
+fn in_memory_copies_read_the_same_rows() {
+    for (path, sql) in [
+        ("sample.db", "SELECT id, name FROM apples ORDER BY name"),
+        (
+            "tests/fixtures/wide_blobs.db",
+            "SELECT id, data FROM files WHERE size > 900",
+        ),
+        ("tests/fixtures/reals.db", "SELECT * FROM reals"),
+    ] {
+        let mut db = Database::open(path).unwrap();
+        let bytes = std::fs::read(path).unwrap();
+        let mut copy = Database::open_from_reader(Cursor::new(bytes)).unwrap();
+        assert_eq!(copy.table_names(), db.table_names());
+        let result_set = db.execute(sql).unwrap();
+        assert!(!result_set.rows.is_empty());
+        assert_eq!(copy.execute(sql).unwrap().rows, result_set.rows);
+        assert_eq!(copy.nb_pages_read(), db.nb_pages_read());
+    }
+}
+
+#[test]
+fn parallel_scans_read_the_same_rows() {
+    let path = "tests/fixtures/companies.db";
+    let options = OpenOptions {
+        scan_threads: 4,
+        ..OpenOptions::default()
+    };
+    for sql in [
+        "SELECT * FROM companies",
+        "SELECT id, name FROM companies WHERE name > 'm' ORDER BY name DESC",
+        "SELECT count(*) FROM companies",
+        "SELECT country, count(*) FROM companies WHERE name > 'c' GROUP BY country",
+    ] {
+        let mut db = Database::open(path).unwrap();
+        let mut parallel_db = Database::open_with(path, options).unwrap();
+        let result_set = db.execute(sql).unwrap();
+        assert_eq!(parallel_db.execute(sql).unwrap().rows, result_set.rows);
+        assert_eq!(parallel_db.nb_pages_read(), db.nb_pages_read());
+    }
+}
+
+#[test]
+fn prepared_statements_are_run_again_with_other_values() {
+    let mut db = Database::open("sample.db").unwrap();
+    let mut stmt = db
+        .prepare("SELECT name FROM apples WHERE id = ? OR color = :color")
+        .unwrap();
+    let names = |stmt: &mut Statement| {
+        let mut names = Vec::new();
+        while let Some(row) = stmt.step().unwrap() {
+            names.push(row.get::<String>("name").unwrap());
+        }
+        names
+    };
+
+    // the unbound placeholders are NULL
+    assert_eq!(names(&mut stmt), Vec::<String>::new());
+    stmt.bind(1, 2).unwrap();
+    assert_eq!(names(&mut stmt), vec!["Fuji"]);
+    stmt.bind_named(":color", "Yellow").unwrap();
+    assert_eq!(names(&mut stmt), vec!["Fuji", "Golden Delicious"]);
+    // a statement can be read again once reset
+    assert_eq!(stmt.step().unwrap(), None);
+    stmt.reset();
+    assert_eq!(names(&mut stmt), vec!["Fuji", "Golden Delicious"]);
+    stmt.clear_bindings();
+    stmt.bind(1, 3).unwrap();
+    assert_eq!(names(&mut stmt), vec!["Honeycrisp"]);
+
+    assert!(matches!(
+        stmt.bind(2, 1),
+        Err(Error::InvalidParameters(message)) if message == "No parameter ?2 in the statement"
+    ));
+    assert!(matches!(
+        stmt.bind_named("name", 1),
+        Err(Error::InvalidParameters(_))
+    ));
+}
+
+#[test]
+fn prepared_statements_read_the_rows_at_each_step() {
+    let path = "tests/fixtures/companies.db";
+    let mut db = Database::open(path).unwrap();
+    db.execute("SELECT name FROM companies").unwrap();
+    let pages_of_a_scan = db.nb_pages_read();
+
+    // the first step only reads the pages down to the first leaf of the table
+    let mut db = Database::open(path).unwrap();
+    let mut stmt = db.prepare("SELECT name FROM companies").unwrap();
+    let row = stmt.step().unwrap().unwrap();
+    assert_eq!(row.get::<String>("name").unwrap(), "company 0001");
+    drop(stmt);
+    assert!(db.nb_pages_read() * 10 < pages_of_a_scan);
+
+    for (path, sql) in [
+        (
+            path,
+            "SELECT id, name FROM companies WHERE name > 'company 2990'",
+        ),
+        (
+            path,
+            "SELECT name FROM companies WHERE id >= 1500 LIMIT 3 OFFSET 2",
+        ),
+        (path, "SELECT id FROM companies WHERE country = 'albania'"),
+        (
+            path,
+            "SELECT name FROM companies WHERE country = 'eritrea' AND id > 1000",
+        ),
+        (
+            path,
+            "SELECT name FROM companies ORDER BY name DESC LIMIT 2",
+        ),
+        (
+            path,
+            "SELECT country, count(*) FROM companies GROUP BY country",
+        ),
+        (
+            "tests/fixtures/without_rowid.db",
+            "SELECT k, v FROM kv WHERE v > 'value 3'",
+        ),
+    ] {
+        let mut db = Database::open(path).unwrap();
+        let expected = db.execute(sql).unwrap().rows;
+        let mut stmt = db.prepare(sql).unwrap();
+        let mut rows = Vec::new();
+        while let Some(row) = stmt.step().unwrap() {
+            rows.push(row);
+        }
+        assert!(!rows.is_empty(), "{}", sql);
+        assert_eq!(rows, expected, "{}", sql);
+    }
+}
+
+#[test]
+fn schema_of_the_chinook_database() {
+    let db = Database::open("tests/fixtures/chinook.db").unwrap();
+    let tables = db.tables().unwrap();
+    assert_eq!(tables.len(), 11);
+    let playlist_track = tables
+        .iter()
+        .find(|table| table.name == "PlaylistTrack")
+        .unwrap();
+    assert_eq!(playlist_track.primary_key, vec!["PlaylistId", "TrackId"]);
+    assert!(!playlist_track.without_rowid && !playlist_track.is_virtual);
+
+    // the names are not case sensitive, like in the queries
+    let columns = db.columns("track").unwrap();
+    assert_eq!(columns.len(), 9);
+    assert_eq!(columns[0].name, "TrackId");
+    assert!(columns[0].is_rowid_alias);
+    assert_eq!(columns[0].primary_key_position, Some(1));
+    assert_eq!(columns[8].declared_type, "NUMERIC(10,2)");
+    assert_eq!(columns[8].affinity, Affinity::Numeric);
+    assert_eq!(columns[8].primary_key_position, None);
+    let positions = db
+        .columns("PlaylistTrack")
+        .unwrap()
+        .iter()
+        .map(|column| column.primary_key_position)
+        .collect::<Vec<_>>();
+    assert_eq!(positions, vec![Some(1), Some(2)]);
+
+    let names = |indexes: &[IndexInfo]| {
+        indexes
+            .iter()
+            .map(|index| {
+                let columns = index.columns.iter().map(|column| column.name.as_str());
+                (
+                    index.name.clone(),
+                    columns.collect::<Vec<_>>().join(","),
+                    index.unique,
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        names(&db.indexes("Track").unwrap()),
+        vec![
+            ("IFK_TrackAlbumId".to_string(), "AlbumId".to_string(), false),
+            ("IFK_TrackGenreId".to_string(), "GenreId".to_string(), false),
+            (
+                "IFK_TrackMediaTypeId".to_string(),
+                "MediaTypeId".to_string(),
+                false
+            ),
+        ]
+    );
+    let indexes = db.indexes("PlaylistTrack").unwrap();
+    assert_eq!(
+        names(&indexes),
+        vec![
+            (
+                "sqlite_autoindex_PlaylistTrack_1".to_string(),
+                "PlaylistId,TrackId".to_string(),
+                true
+            ),
+            (
+                "IFK_PlaylistTrackTrackId".to_string(),
+                "TrackId".to_string(),
+                false
+            ),
+        ]
+    );
+    assert!(indexes[0].is_autoindex && !indexes[1].is_autoindex);
+    assert!(db.indexes("Genre").unwrap().is_empty());
+
+    assert!(matches!(db.columns("Tracks"), Err(Error::NoSuchTable(_))));
+    assert!(matches!(db.indexes("Tracks"), Err(Error::NoSuchTable(_))));
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+struct Track {
+    track_id: i64,
+    name: String,
+    composer: Option<String>,
+    milliseconds: u32,
+}
+
+#[test]
+fn rows_are_read_into_structs() {
+    let mut db = Database::open("tests/fixtures/chinook.db").unwrap();
+    let tracks: Vec<Track> = db
+        .query_as("SELECT TrackId, Name, Composer, Milliseconds FROM Track WHERE TrackId < 3")
+        .unwrap();
+    assert_eq!(
+        tracks,
+        vec![
+            Track {
+                track_id: 1,
+                name: "For Those About To Rock (We Salute You)".to_string(),
+                composer: Some("Angus Young, Malcolm Young, Brian Johnson".to_string()),
+                milliseconds: 343719,
+            },
+            Track {
+                track_id: 2,
+                name: "Balls to the Wall".to_string(),
+                composer: None,
+                milliseconds: 342562,
+            },
+        ]
+    );
+
+    let names: Vec<(String, f64)> = db
+        .query_as("SELECT Name, UnitPrice FROM Track WHERE TrackId = 6")
+        .unwrap();
+    assert_eq!(names, vec![("Put The Finger On You".to_string(), 0.99)]);
+
+    let error = db
+        .query_as::<Track>("SELECT TrackId, Composer AS Name, Composer, Milliseconds FROM Track")
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Could not read the column \"Name\": expected text, found null"
+    );
+    assert!(matches!(
+        db.query_as::<Track>("SELECT TrackId, Name FROM Track"),
+        Err(Error::Deserialize { column: None, .. })
+    ));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn concurrent_queries_on_an_async_database() {
+    let path = "tests/fixtures/companies.db";
+    let count_sql = "SELECT count(*) FROM companies";
+    let search_sql = "SELECT id, name FROM companies WHERE country = 'eritrea'";
+    let mut db = Database::open(path).unwrap();
+    let expected_count = db.execute(count_sql).unwrap();
+    let expected_search = db.execute(search_sql).unwrap();
+
+    for async_db in [
+        AsyncDatabase::open(path).await.unwrap(),
+        AsyncDatabase::open_with(path, OpenOptions::default())
+            .await
+            .unwrap(),
+    ] {
+        let other_db = async_db.clone();
+        // both queries are running at the same time, on the threads of the blocking pool
+        let count = tokio::spawn(async move { other_db.execute(count_sql).await });
+        let search = async_db.execute(search_sql).await.unwrap();
+        let count = count.await.unwrap().unwrap();
+        assert_eq!(values(&count.rows), values(&expected_count.rows));
+        assert_eq!(values(&search.rows), values(&expected_search.rows));
+        assert_eq!(search.rows.len(), 30);
+
+        let names: Vec<(i64, String)> = async_db.query_as(search_sql).await.unwrap();
+        assert_eq!(names.len(), 30);
+        assert!(matches!(
+            async_db.execute("SELECT * FROM nowhere").await,
+            Err(Error::NoSuchTable(_))
+        ));
+    }
+    assert!(matches!(
+        AsyncDatabase::open("tests/fixtures/missing.db").await,
+        Err(Error::Io(_))
+    ));
+}
+
+#[test]
+fn attached_databases_are_read_through_their_name() {
+    let mut db = Database::open("sample.db").unwrap();
+    db.attach("users_db", "tests/fixtures/users.db").unwrap();
+    let result_set = db.execute("SELECT count(*) FROM users_db.guests").unwrap();
+    assert_eq!(
+        values(&result_set.rows),
+        vec![vec![ColumnContent::Int(1501)]]
+    );
+    assert!(db.nb_pages_read() > 0);
+
+    assert!(matches!(
+        db.attach("USERS_DB", "tests/fixtures/users.db"),
+        Err(Error::InvalidValue(_))
+    ));
+    assert!(matches!(
+        db.attach("main", "tests/fixtures/users.db"),
+        Err(Error::InvalidValue(_))
+    ));
+    assert!(matches!(
+        db.attach("missing", "tests/fixtures/missing.db"),
+        Err(Error::Io(_))
+    ));
+    // the main database and its tables are unchanged
+    assert_eq!(db.tables().unwrap().len(), 2);
+    let apples = db.execute("SELECT count(*) FROM apples").unwrap();
+    assert_eq!(values(&apples.rows), vec![vec![ColumnContent::Int(4)]]);
+}
+
+#[test]
+fn unsupported_indexes_are_returned_as_warnings() {
+    let mut db = Database::open("sample.db").unwrap();
+    assert!(db.warnings().is_empty());
+    db.attach("quoted", "tests/fixtures/quoted_index.db")
+        .unwrap();
+    let warnings = db.warnings();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].starts_with("quoted: index idx_not_null is not supported: "));
+    // the index is skipped by the planner, which prints nothing
+    let rows = db
+        .execute("SELECT id FROM quoted.\"my table\" WHERE col = 'value42'")
+        .unwrap()
+        .rows;
+    assert_eq!(rows.len(), 4);
+}
@@ -0,0 +1,15 @@
+-- The indexes of the constraints have no sql in the schema: sqlite_autoindex_accounts_1
+-- is the index of username, sqlite_autoindex_accounts_2 the one of email and
+-- sqlite_autoindex_accounts_3 the one of nickname, which is sorted without case
+PRAGMA page_size = 512;
+CREATE TABLE accounts (
+    username TEXT PRIMARY KEY,
+    email TEXT UNIQUE,
+    nickname TEXT UNIQUE COLLATE NOCASE,
+    age integer
+);
+WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 1000)
+INSERT INTO accounts
+SELECT printf('user%04d', (i * 7919) % 1000), printf('user%04d@example.com', i),
+    printf('%s%d', CASE i % 2 WHEN 0 THEN 'Nick' ELSE 'nick' END, i), 20 + i % 50
+FROM n;
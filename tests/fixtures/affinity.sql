@@ -0,0 +1,5 @@
+CREATE TABLE readings (id integer primary key, code text, amount integer, ratio real, raw);
+INSERT INTO readings (code, amount, ratio, raw) VALUES ('1000', 1000, 5.0, '1000');
+INSERT INTO readings (code, amount, ratio, raw) VALUES ('007', 7, 0.5, 7);
+INSERT INTO readings (code, amount, ratio, raw) VALUES ('7', '42', 5, '42');
+INSERT INTO readings (code, amount, ratio, raw) VALUES ('abc', 'abc', 2.5, 'abc');
@@ -0,0 +1,10 @@
+-- ANALYZE wrote sqlite_stat1: a status has 500 rows on average, a customer 3, and all the
+-- rows have the same region
+CREATE TABLE orders (id INTEGER PRIMARY KEY, status TEXT, customer TEXT, region TEXT, amount INTEGER);
+CREATE INDEX idx_orders_status ON orders (status);
+CREATE INDEX idx_orders_customer ON orders (customer);
+CREATE INDEX idx_orders_region ON orders (region);
+WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 1000)
+INSERT INTO orders (status, customer, region, amount)
+SELECT CASE WHEN i % 50 = 0 THEN 'pending' ELSE 'done' END, printf('c%03d', i % 400), 'eu', i FROM n;
+ANALYZE;
@@ -0,0 +1,7 @@
+-- The data of the first attachment spans about a hundred overflow pages: it is the
+-- numbers 0 to 19999 written on 5 digits
+PRAGMA page_size = 1024;
+CREATE TABLE attachments (id INTEGER PRIMARY KEY, name text, data blob);
+WITH RECURSIVE n(i) AS (SELECT 0 UNION ALL SELECT i + 1 FROM n WHERE i < 19999)
+INSERT INTO attachments SELECT 1, 'numbers.txt', CAST(group_concat(printf('%05d', i), '') AS BLOB) FROM n;
+INSERT INTO attachments VALUES (2, 'small.bin', X'00FF10'), (3, 'empty.bin', X''), (4, 'missing.bin', NULL);
@@ -0,0 +1,9 @@
+-- with 512 bytes pages, a pointer map page maps the 102 next pages: pages 2, 105, 208...
+-- are pointer map pages. In incremental mode, the deleted pages stay in the freelist.
+PRAGMA page_size = 512;
+PRAGMA auto_vacuum = INCREMENTAL;
+CREATE TABLE events (id INTEGER PRIMARY KEY, name text);
+CREATE INDEX idx_events_name ON events (name);
+WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 3000)
+INSERT INTO events (name) SELECT 'event ' || i FROM n;
+DELETE FROM events WHERE id > 2000;
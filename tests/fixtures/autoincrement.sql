@@ -0,0 +1,6 @@
+-- AUTOINCREMENT makes sqlite create the internal sqlite_sequence table
+CREATE TABLE tasks (id integer PRIMARY KEY AUTOINCREMENT, title text NOT NULL);
+CREATE TABLE labels (name text);
+INSERT INTO tasks (title) VALUES ('write'), ('review'), ('ship');
+DELETE FROM tasks WHERE id = 3;
+INSERT INTO labels VALUES ('urgent');
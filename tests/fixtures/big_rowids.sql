@@ -0,0 +1,3 @@
+-- these rowids are stored as 9 byte varints
+CREATE TABLE t (id INTEGER PRIMARY KEY, label text);
+INSERT INTO t VALUES (9223372036854775807, 'max'), (-1, 'minus one'), (72057594037927936, 'two pow 56');
@@ -0,0 +1,8 @@
+CREATE TABLE files (id INTEGER PRIMARY KEY, name text, data blob);
+INSERT INTO files (name, data) VALUES
+  ('logo.png', X'89504E470D0A1A0A'),
+  ('empty', X''),
+  ('hello.txt', CAST('hello' AS BLOB)),
+  ('missing', NULL),
+  ('text', 'not a blob');
+CREATE INDEX idx_files_data ON files (data);
@@ -0,0 +1,10 @@
+CREATE TABLE notes ( -- one row per note
+    id integer primary key, -- rowid alias
+    /* the title, which may contain commas, like this */
+    title text NOT NULL /* a comment, before the comma */,
+    -- body text, removed
+    rating integer CHECK (rating < 5 /* ) */ AND rating >= 0 -- )
+    ),
+    author text
+);
+INSERT INTO notes (title, rating, author) VALUES ('first', 3, 'ann'), ('second', 4, 'bob');
@@ -0,0 +1,16 @@
+-- A small version of the companies database of codecrafters, with smaller pages so that
+-- the index on the country has interior pages. 'united states' spans many pages of the
+-- index, 'albania' and 'zimbabwe' are its first and last keys.
+PRAGMA page_size = 512;
+CREATE TABLE companies (id INTEGER PRIMARY KEY, name text, country text);
+CREATE INDEX idx_companies_country ON companies (country);
+WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 3000)
+INSERT INTO companies
+SELECT i, printf('company %04d', i), CASE
+  WHEN i % 97 = 0 THEN 'eritrea'
+  WHEN i % 101 = 0 THEN 'albania'
+  WHEN i % 103 = 0 THEN 'zimbabwe'
+  WHEN i % 5 = 0 THEN 'india'
+  WHEN i % 5 = 1 THEN 'france'
+  ELSE 'united states'
+END FROM n;
@@ -0,0 +1,68 @@
+-- tables from the Chinook sample database
+CREATE TABLE [Artist]
+(
+    [ArtistId] INTEGER  NOT NULL,
+    [Name] NVARCHAR(120),
+    CONSTRAINT [PK_Artist] PRIMARY KEY  ([ArtistId])
+);
+CREATE TABLE [Album]
+(
+    [AlbumId] INTEGER  NOT NULL,
+    [Title] NVARCHAR(160)  NOT NULL,
+    [ArtistId] INTEGER  NOT NULL,
+    CONSTRAINT [PK_Album] PRIMARY KEY  ([AlbumId]),
+    FOREIGN KEY ([ArtistId]) REFERENCES [Artist] ([ArtistId])
+		ON DELETE NO ACTION ON UPDATE NO ACTION
+);
+CREATE TABLE [Invoice]
+(
+    [InvoiceId] INTEGER  NOT NULL,
+    [CustomerId] INTEGER  NOT NULL,
+    [InvoiceDate] DATETIME  NOT NULL,
+    [BillingAddress] NVARCHAR(70),
+    [BillingCity] NVARCHAR(40),
+    [BillingState] NVARCHAR(40),
+    [BillingCountry] NVARCHAR(40),
+    [BillingPostalCode] NVARCHAR(10),
+    [Total] NUMERIC(10,2)  NOT NULL,
+    CONSTRAINT [PK_Invoice] PRIMARY KEY  ([InvoiceId]),
+    FOREIGN KEY ([CustomerId]) REFERENCES [Customer] ([CustomerId]) 
+		ON DELETE NO ACTION ON UPDATE NO ACTION
+);
+-- every kind of column constraint
+CREATE TABLE tags (
+    id integer PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL DEFAULT 'x' UNIQUE,
+    separators text DEFAULT 'a,b' COLLATE NOCASE,
+    weight real CHECK (weight >= -1 AND (weight < 10)) DEFAULT -1,
+    artist_id integer CONSTRAINT fk_artist REFERENCES Artist(ArtistId) ON DELETE CASCADE,
+    created text DEFAULT CURRENT_TIMESTAMP,
+    doubled real GENERATED ALWAYS AS (weight * 2) STORED,
+    note,
+    level integer CHECK (level IN (1, 2, 3)) DEFAULT (1),
+    size text DEFAULT (printf('%d,%d', 3, 4))
+);
+INSERT INTO Artist VALUES (1, 'AC/DC'), (2, 'Accept');
+INSERT INTO Album VALUES (1, 'For Those About To Rock We Salute You', 1), (2, 'Balls to the Wall', 2), (3, 'Restless and Wild', 2);
+INSERT INTO Invoice VALUES (1, 2, '2009-01-01 00:00:00', 'Theodor-Heuss-Straße 34', 'Stuttgart', NULL, 'Germany', '70174', 1.98), (2, 4, '2009-01-02 00:00:00', 'Ullevålsveien 14', 'Oslo', NULL, 'Norway', '0171', 3.96);
+INSERT INTO tags (name, weight, artist_id, note) VALUES ('rock', 2.5, 1, 'loud');
+INSERT INTO tags (separators, artist_id, level) VALUES ('c,d', 2, 3);
+-- composite primary key, which creates an automatic index
+CREATE TABLE memberships (artist_id INTEGER, band_id INTEGER, role TEXT, PRIMARY KEY (artist_id, band_id), FOREIGN KEY (band_id) REFERENCES Artist(ArtistId));
+INSERT INTO memberships VALUES (1, 2, 'guitar'), (2, 2, 'drums'), (1, 1, 'vocals');
+-- CHECK and DEFAULT bodies with parentheses, commas and quotes
+CREATE TABLE prices (
+    label text CHECK (label <> 'a,b)' AND length(label) < (10 + (1 * 2))),
+    price real DEFAULT (1 AND (2 OR 0)) CHECK (price > 0 AND price IN (1.5, 2.5, 3)),
+    currency text DEFAULT 'it''s, (EUR)'
+);
+INSERT INTO prices (label, price) VALUES ('tea', 2.5), ('coffee', 3);
+-- VIRTUAL generated columns are not stored in the records
+CREATE TABLE line_items (
+    price real,
+    qty integer,
+    total real GENERATED ALWAYS AS (price * qty) STORED,
+    label text AS (upper(name) || ' x' || qty),
+    name text
+);
+INSERT INTO line_items (price, qty, name) VALUES (2.5, 4, 'tea'), (1.25, 2, 'milk');
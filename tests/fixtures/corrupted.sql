@@ -0,0 +1,14 @@
+-- healthy database from which the databases below are derived, by overwriting child
+-- pointers of the root pages (page 2 for items, page 3 for the index) or header fields:
+-- corrupted_cycle.db: the right most pointer of page 2 is 2
+-- corrupted_index_cycle.db: the right most pointer of page 3 is 3
+-- corrupted_out_of_range.db: the left child of the first cell of page 2 is 1000
+-- small_page_count.db: the header announces 3 pages
+-- stale_page_count.db: the header announces 3 pages, but its version-valid-for number
+-- differs from the change counter
+-- reserved_header_bytes.db: the byte 80 of the header, reserved for expansion, is 1
+PRAGMA page_size = 512;
+CREATE TABLE items (id INTEGER PRIMARY KEY, name text, filler text);
+CREATE INDEX idx_items_name ON items (name);
+WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 40)
+INSERT INTO items SELECT i, printf('item %02d', i), printf('%.100c', 'x') FROM n;
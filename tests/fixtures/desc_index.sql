@@ -0,0 +1,1505 @@
+CREATE TABLE people (id integer primary key, name text, email text);
+CREATE INDEX idx_people_name_desc ON people (name DESC);
+CREATE INDEX idx_people_email_lower ON people (lower(email));
+BEGIN;
+INSERT INTO people (name, email) VALUES ('name001', 'Person1@Example.com');
+INSERT INTO people (name, email) VALUES ('name002', 'Person2@Example.com');
+INSERT INTO people (name, email) VALUES ('name003', 'Person3@Example.com');
+INSERT INTO people (name, email) VALUES ('name004', 'Person4@Example.com');
+INSERT INTO people (name, email) VALUES ('name005', 'Person5@Example.com');
+INSERT INTO people (name, email) VALUES ('name006', 'Person6@Example.com');
+INSERT INTO people (name, email) VALUES ('name007', 'Person7@Example.com');
+INSERT INTO people (name, email) VALUES ('name008', 'Person8@Example.com');
+INSERT INTO people (name, email) VALUES ('name009', 'Person9@Example.com');
+INSERT INTO people (name, email) VALUES ('name010', 'Person10@Example.com');
+INSERT INTO people (name, email) VALUES ('name011', 'Person11@Example.com');
+INSERT INTO people (name, email) VALUES ('name012', 'Person12@Example.com');
+INSERT INTO people (name, email) VALUES ('name013', 'Person13@Example.com');
+INSERT INTO people (name, email) VALUES ('name014', 'Person14@Example.com');
+INSERT INTO people (name, email) VALUES ('name015', 'Person15@Example.com');
+INSERT INTO people (name, email) VALUES ('name016', 'Person16@Example.com');
+INSERT INTO people (name, email) VALUES ('name017', 'Person17@Example.com');
+INSERT INTO people (name, email) VALUES ('name018', 'Person18@Example.com');
+INSERT INTO people (name, email) VALUES ('name019', 'Person19@Example.com');
+INSERT INTO people (name, email) VALUES ('name020', 'Person20@Example.com');
+INSERT INTO people (name, email) VALUES ('name021', 'Person21@Example.com');
+INSERT INTO people (name, email) VALUES ('name022', 'Person22@Example.com');
+INSERT INTO people (name, email) VALUES ('name023', 'Person23@Example.com');
+INSERT INTO people (name, email) VALUES ('name024', 'Person24@Example.com');
+INSERT INTO people (name, email) VALUES ('name025', 'Person25@Example.com');
+INSERT INTO people (name, email) VALUES ('name026', 'Person26@Example.com');
+INSERT INTO people (name, email) VALUES ('name027', 'Person27@Example.com');
+INSERT INTO people (name, email) VALUES ('name028', 'Person28@Example.com');
+INSERT INTO people (name, email) VALUES ('name029', 'Person29@Example.com');
+INSERT INTO people (name, email) VALUES ('name030', 'Person30@Example.com');
+INSERT INTO people (name, email) VALUES ('name031', 'Person31@Example.com');
+INSERT INTO people (name, email) VALUES ('name032', 'Person32@Example.com');
+INSERT INTO people (name, email) VALUES ('name033', 'Person33@Example.com');
+INSERT INTO people (name, email) VALUES ('name034', 'Person34@Example.com');
+INSERT INTO people (name, email) VALUES ('name035', 'Person35@Example.com');
+INSERT INTO people (name, email) VALUES ('name036', 'Person36@Example.com');
+INSERT INTO people (name, email) VALUES ('name037', 'Person37@Example.com');
+INSERT INTO people (name, email) VALUES ('name038', 'Person38@Example.com');
+INSERT INTO people (name, email) VALUES ('name039', 'Person39@Example.com');
+INSERT INTO people (name, email) VALUES ('name040', 'Person40@Example.com');
+INSERT INTO people (name, email) VALUES ('name041', 'Person41@Example.com');
+INSERT INTO people (name, email) VALUES ('name042', 'Person42@Example.com');
+INSERT INTO people (name, email) VALUES ('name043', 'Person43@Example.com');
+INSERT INTO people (name, email) VALUES ('name044', 'Person44@Example.com');
+INSERT INTO people (name, email) VALUES ('name045', 'Person45@Example.com');
+INSERT INTO people (name, email) VALUES ('name046', 'Person46@Example.com');
+INSERT INTO people (name, email) VALUES ('name047', 'Person47@Example.com');
+INSERT INTO people (name, email) VALUES ('name048', 'Person48@Example.com');
+INSERT INTO people (name, email) VALUES ('name049', 'Person49@Example.com');
+INSERT INTO people (name, email) VALUES ('name050', 'Person50@Example.com');
+INSERT INTO people (name, email) VALUES ('name051', 'Person51@Example.com');
+INSERT INTO people (name, email) VALUES ('name052', 'Person52@Example.com');
+INSERT INTO people (name, email) VALUES ('name053', 'Person53@Example.com');
+INSERT INTO people (name, email) VALUES ('name054', 'Person54@Example.com');
+INSERT INTO people (name, email) VALUES ('name055', 'Person55@Example.com');
+INSERT INTO people (name, email) VALUES ('name056', 'Person56@Example.com');
+INSERT INTO people (name, email) VALUES ('name057', 'Person57@Example.com');
+INSERT INTO people (name, email) VALUES ('name058', 'Person58@Example.com');
+INSERT INTO people (name, email) VALUES ('name059', 'Person59@Example.com');
+INSERT INTO people (name, email) VALUES ('name060', 'Person60@Example.com');
+INSERT INTO people (name, email) VALUES ('name061', 'Person61@Example.com');
+INSERT INTO people (name, email) VALUES ('name062', 'Person62@Example.com');
+INSERT INTO people (name, email) VALUES ('name063', 'Person63@Example.com');
+INSERT INTO people (name, email) VALUES ('name064', 'Person64@Example.com');
+INSERT INTO people (name, email) VALUES ('name065', 'Person65@Example.com');
+INSERT INTO people (name, email) VALUES ('name066', 'Person66@Example.com');
+INSERT INTO people (name, email) VALUES ('name067', 'Person67@Example.com');
+INSERT INTO people (name, email) VALUES ('name068', 'Person68@Example.com');
+INSERT INTO people (name, email) VALUES ('name069', 'Person69@Example.com');
+INSERT INTO people (name, email) VALUES ('name070', 'Person70@Example.com');
+INSERT INTO people (name, email) VALUES ('name071', 'Person71@Example.com');
+INSERT INTO people (name, email) VALUES ('name072', 'Person72@Example.com');
+INSERT INTO people (name, email) VALUES ('name073', 'Person73@Example.com');
+INSERT INTO people (name, email) VALUES ('name074', 'Person74@Example.com');
+INSERT INTO people (name, email) VALUES ('name075', 'Person75@Example.com');
+INSERT INTO people (name, email) VALUES ('name076', 'Person76@Example.com');
+INSERT INTO people (name, email) VALUES ('name077', 'Person77@Example.com');
+INSERT INTO people (name, email) VALUES ('name078', 'Person78@Example.com');
+INSERT INTO people (name, email) VALUES ('name079', 'Person79@Example.com');
+INSERT INTO people (name, email) VALUES ('name080', 'Person80@Example.com');
+INSERT INTO people (name, email) VALUES ('name081', 'Person81@Example.com');
+INSERT INTO people (name, email) VALUES ('name082', 'Person82@Example.com');
+INSERT INTO people (name, email) VALUES ('name083', 'Person83@Example.com');
+INSERT INTO people (name, email) VALUES ('name084', 'Person84@Example.com');
+INSERT INTO people (name, email) VALUES ('name085', 'Person85@Example.com');
+INSERT INTO people (name, email) VALUES ('name086', 'Person86@Example.com');
+INSERT INTO people (name, email) VALUES ('name087', 'Person87@Example.com');
+INSERT INTO people (name, email) VALUES ('name088', 'Person88@Example.com');
+INSERT INTO people (name, email) VALUES ('name089', 'Person89@Example.com');
+INSERT INTO people (name, email) VALUES ('name090', 'Person90@Example.com');
+INSERT INTO people (name, email) VALUES ('name091', 'Person91@Example.com');
+INSERT INTO people (name, email) VALUES ('name092', 'Person92@Example.com');
+INSERT INTO people (name, email) VALUES ('name093', 'Person93@Example.com');
+INSERT INTO people (name, email) VALUES ('name094', 'Person94@Example.com');
+INSERT INTO people (name, email) VALUES ('name095', 'Person95@Example.com');
+INSERT INTO people (name, email) VALUES ('name096', 'Person96@Example.com');
+INSERT INTO people (name, email) VALUES ('name097', 'Person97@Example.com');
+INSERT INTO people (name, email) VALUES ('name098', 'Person98@Example.com');
+INSERT INTO people (name, email) VALUES ('name099', 'Person99@Example.com');
+INSERT INTO people (name, email) VALUES ('name100', 'Person100@Example.com');
+INSERT INTO people (name, email) VALUES ('name101', 'Person101@Example.com');
+INSERT INTO people (name, email) VALUES ('name102', 'Person102@Example.com');
+INSERT INTO people (name, email) VALUES ('name103', 'Person103@Example.com');
+INSERT INTO people (name, email) VALUES ('name104', 'Person104@Example.com');
+INSERT INTO people (name, email) VALUES ('name105', 'Person105@Example.com');
+INSERT INTO people (name, email) VALUES ('name106', 'Person106@Example.com');
+INSERT INTO people (name, email) VALUES ('name107', 'Person107@Example.com');
+INSERT INTO people (name, email) VALUES ('name108', 'Person108@Example.com');
+INSERT INTO people (name, email) VALUES ('name109', 'Person109@Example.com');
+INSERT INTO people (name, email) VALUES ('name110', 'Person110@Example.com');
+INSERT INTO people (name, email) VALUES ('name111', 'Person111@Example.com');
+INSERT INTO people (name, email) VALUES ('name112', 'Person112@Example.com');
+INSERT INTO people (name, email) VALUES ('name113', 'Person113@Example.com');
+INSERT INTO people (name, email) VALUES ('name114', 'Person114@Example.com');
+INSERT INTO people (name, email) VALUES ('name115', 'Person115@Example.com');
+INSERT INTO people (name, email) VALUES ('name116', 'Person116@Example.com');
+INSERT INTO people (name, email) VALUES ('name117', 'Person117@Example.com');
+INSERT INTO people (name, email) VALUES ('name118', 'Person118@Example.com');
+INSERT INTO people (name, email) VALUES ('name119', 'Person119@Example.com');
+INSERT INTO people (name, email) VALUES ('name120', 'Person120@Example.com');
+INSERT INTO people (name, email) VALUES ('name121', 'Person121@Example.com');
+INSERT INTO people (name, email) VALUES ('name122', 'Person122@Example.com');
+INSERT INTO people (name, email) VALUES ('name123', 'Person123@Example.com');
+INSERT INTO people (name, email) VALUES ('name124', 'Person124@Example.com');
+INSERT INTO people (name, email) VALUES ('name125', 'Person125@Example.com');
+INSERT INTO people (name, email) VALUES ('name126', 'Person126@Example.com');
+INSERT INTO people (name, email) VALUES ('name127', 'Person127@Example.com');
+INSERT INTO people (name, email) VALUES ('name128', 'Person128@Example.com');
+INSERT INTO people (name, email) VALUES ('name129', 'Person129@Example.com');
+INSERT INTO people (name, email) VALUES ('name130', 'Person130@Example.com');
+INSERT INTO people (name, email) VALUES ('name131', 'Person131@Example.com');
+INSERT INTO people (name, email) VALUES ('name132', 'Person132@Example.com');
+INSERT INTO people (name, email) VALUES ('name133', 'Person133@Example.com');
+INSERT INTO people (name, email) VALUES ('name134', 'Person134@Example.com');
+INSERT INTO people (name, email) VALUES ('name135', 'Person135@Example.com');
+INSERT INTO people (name, email) VALUES ('name136', 'Person136@Example.com');
+INSERT INTO people (name, email) VALUES ('name137', 'Person137@Example.com');
+INSERT INTO people (name, email) VALUES ('name138', 'Person138@Example.com');
+INSERT INTO people (name, email) VALUES ('name139', 'Person139@Example.com');
+INSERT INTO people (name, email) VALUES ('name140', 'Person140@Example.com');
+INSERT INTO people (name, email) VALUES ('name141', 'Person141@Example.com');
+INSERT INTO people (name, email) VALUES ('name142', 'Person142@Example.com');
+INSERT INTO people (name, email) VALUES ('name143', 'Person143@Example.com');
+INSERT INTO people (name, email) VALUES ('name144', 'Person144@Example.com');
+INSERT INTO people (name, email) VALUES ('name145', 'Person145@Example.com');
+INSERT INTO people (name, email) VALUES ('name146', 'Person146@Example.com');
+INSERT INTO people (name, email) VALUES ('name147', 'Person147@Example.com');
+INSERT INTO people (name, email) VALUES ('name148', 'Person148@Example.com');
+INSERT INTO people (name, email) VALUES ('name149', 'Person149@Example.com');
+INSERT INTO people (name, email) VALUES ('name150', 'Person150@Example.com');
+INSERT INTO people (name, email) VALUES ('name151', 'Person151@Example.com');
+INSERT INTO people (name, email) VALUES ('name152', 'Person152@Example.com');
+INSERT INTO people (name, email) VALUES ('name153', 'Person153@Example.com');
+INSERT INTO people (name, email) VALUES ('name154', 'Person154@Example.com');
+INSERT INTO people (name, email) VALUES ('name155', 'Person155@Example.com');
+INSERT INTO people (name, email) VALUES ('name156', 'Person156@Example.com');
+INSERT INTO people (name, email) VALUES ('name157', 'Person157@Example.com');
+INSERT INTO people (name, email) VALUES ('name158', 'Person158@Example.com');
+INSERT INTO people (name, email) VALUES ('name159', 'Person159@Example.com');
+INSERT INTO people (name, email) VALUES ('name160', 'Person160@Example.com');
+INSERT INTO people (name, email) VALUES ('name161', 'Person161@Example.com');
+INSERT INTO people (name, email) VALUES ('name162', 'Person162@Example.com');
+INSERT INTO people (name, email) VALUES ('name163', 'Person163@Example.com');
+INSERT INTO people (name, email) VALUES ('name164', 'Person164@Example.com');
+INSERT INTO people (name, email) VALUES ('name165', 'Person165@Example.com');
+INSERT INTO people (name, email) VALUES ('name166', 'Person166@Example.com');
+INSERT INTO people (name, email) VALUES ('name167', 'Person167@Example.com');
+INSERT INTO people (name, email) VALUES ('name168', 'Person168@Example.com');
+INSERT INTO people (name, email) VALUES ('name169', 'Person169@Example.com');
+INSERT INTO people (name, email) VALUES ('name170', 'Person170@Example.com');
+INSERT INTO people (name, email) VALUES ('name171', 'Person171@Example.com');
+INSERT INTO people (name, email) VALUES ('name172', 'Person172@Example.com');
+INSERT INTO people (name, email) VALUES ('name173', 'Person173@Example.com');
+INSERT INTO people (name, email) VALUES ('name174', 'Person174@Example.com');
+INSERT INTO people (name, email) VALUES ('name175', 'Person175@Example.com');
+INSERT INTO people (name, email) VALUES ('name176', 'Person176@Example.com');
+INSERT INTO people (name, email) VALUES ('name177', 'Person177@Example.com');
+INSERT INTO people (name, email) VALUES ('name178', 'Person178@Example.com');
+INSERT INTO people (name, email) VALUES ('name179', 'Person179@Example.com');
+INSERT INTO people (name, email) VALUES ('name180', 'Person180@Example.com');
+INSERT INTO people (name, email) VALUES ('name181', 'Person181@Example.com');
+INSERT INTO people (name, email) VALUES ('name182', 'Person182@Example.com');
+INSERT INTO people (name, email) VALUES ('name183', 'Person183@Example.com');
+INSERT INTO people (name, email) VALUES ('name184', 'Person184@Example.com');
+INSERT INTO people (name, email) VALUES ('name185', 'Person185@Example.com');
+INSERT INTO people (name, email) VALUES ('name186', 'Person186@Example.com');
+INSERT INTO people (name, email) VALUES ('name187', 'Person187@Example.com');
+INSERT INTO people (name, email) VALUES ('name188', 'Person188@Example.com');
+INSERT INTO people (name, email) VALUES ('name189', 'Person189@Example.com');
+INSERT INTO people (name, email) VALUES ('name190', 'Person190@Example.com');
+INSERT INTO people (name, email) VALUES ('name191', 'Person191@Example.com');
+INSERT INTO people (name, email) VALUES ('name192', 'Person192@Example.com');
+INSERT INTO people (name, email) VALUES ('name193', 'Person193@Example.com');
+INSERT INTO people (name, email) VALUES ('name194', 'Person194@Example.com');
+INSERT INTO people (name, email) VALUES ('name195', 'Person195@Example.com');
+INSERT INTO people (name, email) VALUES ('name196', 'Person196@Example.com');
+INSERT INTO people (name, email) VALUES ('name197', 'Person197@Example.com');
+INSERT INTO people (name, email) VALUES ('name198', 'Person198@Example.com');
+INSERT INTO people (name, email) VALUES ('name199', 'Person199@Example.com');
+INSERT INTO people (name, email) VALUES ('name200', 'Person200@Example.com');
+INSERT INTO people (name, email) VALUES ('name201', 'Person201@Example.com');
+INSERT INTO people (name, email) VALUES ('name202', 'Person202@Example.com');
+INSERT INTO people (name, email) VALUES ('name203', 'Person203@Example.com');
+INSERT INTO people (name, email) VALUES ('name204', 'Person204@Example.com');
+INSERT INTO people (name, email) VALUES ('name205', 'Person205@Example.com');
+INSERT INTO people (name, email) VALUES ('name206', 'Person206@Example.com');
+INSERT INTO people (name, email) VALUES ('name207', 'Person207@Example.com');
+INSERT INTO people (name, email) VALUES ('name208', 'Person208@Example.com');
+INSERT INTO people (name, email) VALUES ('name209', 'Person209@Example.com');
+INSERT INTO people (name, email) VALUES ('name210', 'Person210@Example.com');
+INSERT INTO people (name, email) VALUES ('name211', 'Person211@Example.com');
+INSERT INTO people (name, email) VALUES ('name212', 'Person212@Example.com');
+INSERT INTO people (name, email) VALUES ('name213', 'Person213@Example.com');
+INSERT INTO people (name, email) VALUES ('name214', 'Person214@Example.com');
+INSERT INTO people (name, email) VALUES ('name215', 'Person215@Example.com');
+INSERT INTO people (name, email) VALUES ('name216', 'Person216@Example.com');
+INSERT INTO people (name, email) VALUES ('name217', 'Person217@Example.com');
+INSERT INTO people (name, email) VALUES ('name218', 'Person218@Example.com');
+INSERT INTO people (name, email) VALUES ('name219', 'Person219@Example.com');
+INSERT INTO people (name, email) VALUES ('name220', 'Person220@Example.com');
+INSERT INTO people (name, email) VALUES ('name221', 'Person221@Example.com');
+INSERT INTO people (name, email) VALUES ('name222', 'Person222@Example.com');
+INSERT INTO people (name, email) VALUES ('name223', 'Person223@Example.com');
+INSERT INTO people (name, email) VALUES ('name224', 'Person224@Example.com');
+INSERT INTO people (name, email) VALUES ('name225', 'Person225@Example.com');
+INSERT INTO people (name, email) VALUES ('name226', 'Person226@Example.com');
+INSERT INTO people (name, email) VALUES ('name227', 'Person227@Example.com');
+INSERT INTO people (name, email) VALUES ('name228', 'Person228@Example.com');
+INSERT INTO people (name, email) VALUES ('name229', 'Person229@Example.com');
+INSERT INTO people (name, email) VALUES ('name230', 'Person230@Example.com');
+INSERT INTO people (name, email) VALUES ('name231', 'Person231@Example.com');
+INSERT INTO people (name, email) VALUES ('name232', 'Person232@Example.com');
+INSERT INTO people (name, email) VALUES ('name233', 'Person233@Example.com');
+INSERT INTO people (name, email) VALUES ('name234', 'Person234@Example.com');
+INSERT INTO people (name, email) VALUES ('name235', 'Person235@Example.com');
+INSERT INTO people (name, email) VALUES ('name236', 'Person236@Example.com');
+INSERT INTO people (name, email) VALUES ('name237', 'Person237@Example.com');
+INSERT INTO people (name, email) VALUES ('name238', 'Person238@Example.com');
+INSERT INTO people (name, email) VALUES ('name239', 'Person239@Example.com');
+INSERT INTO people (name, email) VALUES ('name240', 'Person240@Example.com');
+INSERT INTO people (name, email) VALUES ('name241', 'Person241@Example.com');
+INSERT INTO people (name, email) VALUES ('name242', 'Person242@Example.com');
+INSERT INTO people (name, email) VALUES ('name243', 'Person243@Example.com');
+INSERT INTO people (name, email) VALUES ('name244', 'Person244@Example.com');
+INSERT INTO people (name, email) VALUES ('name245', 'Person245@Example.com');
+INSERT INTO people (name, email) VALUES ('name246', 'Person246@Example.com');
+INSERT INTO people (name, email) VALUES ('name247', 'Person247@Example.com');
+INSERT INTO people (name, email) VALUES ('name248', 'Person248@Example.com');
+INSERT INTO people (name, email) VALUES ('name249', 'Person249@Example.com');
+INSERT INTO people (name, email) VALUES ('name250', 'Person250@Example.com');
+INSERT INTO people (name, email) VALUES ('name251', 'Person251@Example.com');
+INSERT INTO people (name, email) VALUES ('name252', 'Person252@Example.com');
+INSERT INTO people (name, email) VALUES ('name253', 'Person253@Example.com');
+INSERT INTO people (name, email) VALUES ('name254', 'Person254@Example.com');
+INSERT INTO people (name, email) VALUES ('name255', 'Person255@Example.com');
+INSERT INTO people (name, email) VALUES ('name256', 'Person256@Example.com');
+INSERT INTO people (name, email) VALUES ('name257', 'Person257@Example.com');
+INSERT INTO people (name, email) VALUES ('name258', 'Person258@Example.com');
+INSERT INTO people (name, email) VALUES ('name259', 'Person259@Example.com');
+INSERT INTO people (name, email) VALUES ('name260', 'Person260@Example.com');
+INSERT INTO people (name, email) VALUES ('name261', 'Person261@Example.com');
+INSERT INTO people (name, email) VALUES ('name262', 'Person262@Example.com');
+INSERT INTO people (name, email) VALUES ('name263', 'Person263@Example.com');
+INSERT INTO people (name, email) VALUES ('name264', 'Person264@Example.com');
+INSERT INTO people (name, email) VALUES ('name265', 'Person265@Example.com');
+INSERT INTO people (name, email) VALUES ('name266', 'Person266@Example.com');
+INSERT INTO people (name, email) VALUES ('name267', 'Person267@Example.com');
+INSERT INTO people (name, email) VALUES ('name268', 'Person268@Example.com');
+INSERT INTO people (name, email) VALUES ('name269', 'Person269@Example.com');
+INSERT INTO people (name, email) VALUES ('name270', 'Person270@Example.com');
+INSERT INTO people (name, email) VALUES ('name271', 'Person271@Example.com');
+INSERT INTO people (name, email) VALUES ('name272', 'Person272@Example.com');
+INSERT INTO people (name, email) VALUES ('name273', 'Person273@Example.com');
+INSERT INTO people (name, email) VALUES ('name274', 'Person274@Example.com');
+INSERT INTO people (name, email) VALUES ('name275', 'Person275@Example.com');
+INSERT INTO people (name, email) VALUES ('name276', 'Person276@Example.com');
+INSERT INTO people (name, email) VALUES ('name277', 'Person277@Example.com');
+INSERT INTO people (name, email) VALUES ('name278', 'Person278@Example.com');
+INSERT INTO people (name, email) VALUES ('name279', 'Person279@Example.com');
+INSERT INTO people (name, email) VALUES ('name280', 'Person280@Example.com');
+INSERT INTO people (name, email) VALUES ('name281', 'Person281@Example.com');
+INSERT INTO people (name, email) VALUES ('name282', 'Person282@Example.com');
+INSERT INTO people (name, email) VALUES ('name283', 'Person283@Example.com');
+INSERT INTO people (name, email) VALUES ('name284', 'Person284@Example.com');
+INSERT INTO people (name, email) VALUES ('name285', 'Person285@Example.com');
+INSERT INTO people (name, email) VALUES ('name286', 'Person286@Example.com');
+INSERT INTO people (name, email) VALUES ('name287', 'Person287@Example.com');
+INSERT INTO people (name, email) VALUES ('name288', 'Person288@Example.com');
+INSERT INTO people (name, email) VALUES ('name289', 'Person289@Example.com');
+INSERT INTO people (name, email) VALUES ('name290', 'Person290@Example.com');
+INSERT INTO people (name, email) VALUES ('name291', 'Person291@Example.com');
+INSERT INTO people (name, email) VALUES ('name292', 'Person292@Example.com');
+INSERT INTO people (name, email) VALUES ('name293', 'Person293@Example.com');
+INSERT INTO people (name, email) VALUES ('name294', 'Person294@Example.com');
+INSERT INTO people (name, email) VALUES ('name295', 'Person295@Example.com');
+INSERT INTO people (name, email) VALUES ('name296', 'Person296@Example.com');
+INSERT INTO people (name, email) VALUES ('name297', 'Person297@Example.com');
+INSERT INTO people (name, email) VALUES ('name298', 'Person298@Example.com');
+INSERT INTO people (name, email) VALUES ('name299', 'Person299@Example.com');
+INSERT INTO people (name, email) VALUES ('name300', 'Person300@Example.com');
+INSERT INTO people (name, email) VALUES ('name301', 'Person301@Example.com');
+INSERT INTO people (name, email) VALUES ('name302', 'Person302@Example.com');
+INSERT INTO people (name, email) VALUES ('name303', 'Person303@Example.com');
+INSERT INTO people (name, email) VALUES ('name304', 'Person304@Example.com');
+INSERT INTO people (name, email) VALUES ('name305', 'Person305@Example.com');
+INSERT INTO people (name, email) VALUES ('name306', 'Person306@Example.com');
+INSERT INTO people (name, email) VALUES ('name307', 'Person307@Example.com');
+INSERT INTO people (name, email) VALUES ('name308', 'Person308@Example.com');
+INSERT INTO people (name, email) VALUES ('name309', 'Person309@Example.com');
+INSERT INTO people (name, email) VALUES ('name310', 'Person310@Example.com');
+INSERT INTO people (name, email) VALUES ('name311', 'Person311@Example.com');
+INSERT INTO people (name, email) VALUES ('name312', 'Person312@Example.com');
+INSERT INTO people (name, email) VALUES ('name313', 'Person313@Example.com');
+INSERT INTO people (name, email) VALUES ('name314', 'Person314@Example.com');
+INSERT INTO people (name, email) VALUES ('name315', 'Person315@Example.com');
+INSERT INTO people (name, email) VALUES ('name316', 'Person316@Example.com');
+INSERT INTO people (name, email) VALUES ('name317', 'Person317@Example.com');
+INSERT INTO people (name, email) VALUES ('name318', 'Person318@Example.com');
+INSERT INTO people (name, email) VALUES ('name319', 'Person319@Example.com');
+INSERT INTO people (name, email) VALUES ('name320', 'Person320@Example.com');
+INSERT INTO people (name, email) VALUES ('name321', 'Person321@Example.com');
+INSERT INTO people (name, email) VALUES ('name322', 'Person322@Example.com');
+INSERT INTO people (name, email) VALUES ('name323', 'Person323@Example.com');
+INSERT INTO people (name, email) VALUES ('name324', 'Person324@Example.com');
+INSERT INTO people (name, email) VALUES ('name325', 'Person325@Example.com');
+INSERT INTO people (name, email) VALUES ('name326', 'Person326@Example.com');
+INSERT INTO people (name, email) VALUES ('name327', 'Person327@Example.com');
+INSERT INTO people (name, email) VALUES ('name328', 'Person328@Example.com');
+INSERT INTO people (name, email) VALUES ('name329', 'Person329@Example.com');
+INSERT INTO people (name, email) VALUES ('name330', 'Person330@Example.com');
+INSERT INTO people (name, email) VALUES ('name331', 'Person331@Example.com');
+INSERT INTO people (name, email) VALUES ('name332', 'Person332@Example.com');
+INSERT INTO people (name, email) VALUES ('name333', 'Person333@Example.com');
+INSERT INTO people (name, email) VALUES ('name334', 'Person334@Example.com');
+INSERT INTO people (name, email) VALUES ('name335', 'Person335@Example.com');
+INSERT INTO people (name, email) VALUES ('name336', 'Person336@Example.com');
+INSERT INTO people (name, email) VALUES ('name337', 'Person337@Example.com');
+INSERT INTO people (name, email) VALUES ('name338', 'Person338@Example.com');
+INSERT INTO people (name, email) VALUES ('name339', 'Person339@Example.com');
+INSERT INTO people (name, email) VALUES ('name340', 'Person340@Example.com');
+INSERT INTO people (name, email) VALUES ('name341', 'Person341@Example.com');
+INSERT INTO people (name, email) VALUES ('name342', 'Person342@Example.com');
+INSERT INTO people (name, email) VALUES ('name343', 'Person343@Example.com');
+INSERT INTO people (name, email) VALUES ('name344', 'Person344@Example.com');
+INSERT INTO people (name, email) VALUES ('name345', 'Person345@Example.com');
+INSERT INTO people (name, email) VALUES ('name346', 'Person346@Example.com');
+INSERT INTO people (name, email) VALUES ('name347', 'Person347@Example.com');
+INSERT INTO people (name, email) VALUES ('name348', 'Person348@Example.com');
+INSERT INTO people (name, email) VALUES ('name349', 'Person349@Example.com');
+INSERT INTO people (name, email) VALUES ('name350', 'Person350@Example.com');
+INSERT INTO people (name, email) VALUES ('name351', 'Person351@Example.com');
+INSERT INTO people (name, email) VALUES ('name352', 'Person352@Example.com');
+INSERT INTO people (name, email) VALUES ('name353', 'Person353@Example.com');
+INSERT INTO people (name, email) VALUES ('name354', 'Person354@Example.com');
+INSERT INTO people (name, email) VALUES ('name355', 'Person355@Example.com');
+INSERT INTO people (name, email) VALUES ('name356', 'Person356@Example.com');
+INSERT INTO people (name, email) VALUES ('name357', 'Person357@Example.com');
+INSERT INTO people (name, email) VALUES ('name358', 'Person358@Example.com');
+INSERT INTO people (name, email) VALUES ('name359', 'Person359@Example.com');
+INSERT INTO people (name, email) VALUES ('name360', 'Person360@Example.com');
+INSERT INTO people (name, email) VALUES ('name361', 'Person361@Example.com');
+INSERT INTO people (name, email) VALUES ('name362', 'Person362@Example.com');
+INSERT INTO people (name, email) VALUES ('name363', 'Person363@Example.com');
+INSERT INTO people (name, email) VALUES ('name364', 'Person364@Example.com');
+INSERT INTO people (name, email) VALUES ('name365', 'Person365@Example.com');
+INSERT INTO people (name, email) VALUES ('name366', 'Person366@Example.com');
+INSERT INTO people (name, email) VALUES ('name367', 'Person367@Example.com');
+INSERT INTO people (name, email) VALUES ('name368', 'Person368@Example.com');
+INSERT INTO people (name, email) VALUES ('name369', 'Person369@Example.com');
+INSERT INTO people (name, email) VALUES ('name370', 'Person370@Example.com');
+INSERT INTO people (name, email) VALUES ('name371', 'Person371@Example.com');
+INSERT INTO people (name, email) VALUES ('name372', 'Person372@Example.com');
+INSERT INTO people (name, email) VALUES ('name373', 'Person373@Example.com');
+INSERT INTO people (name, email) VALUES ('name374', 'Person374@Example.com');
+INSERT INTO people (name, email) VALUES ('name375', 'Person375@Example.com');
+INSERT INTO people (name, email) VALUES ('name376', 'Person376@Example.com');
+INSERT INTO people (name, email) VALUES ('name377', 'Person377@Example.com');
+INSERT INTO people (name, email) VALUES ('name378', 'Person378@Example.com');
+INSERT INTO people (name, email) VALUES ('name379', 'Person379@Example.com');
+INSERT INTO people (name, email) VALUES ('name380', 'Person380@Example.com');
+INSERT INTO people (name, email) VALUES ('name381', 'Person381@Example.com');
+INSERT INTO people (name, email) VALUES ('name382', 'Person382@Example.com');
+INSERT INTO people (name, email) VALUES ('name383', 'Person383@Example.com');
+INSERT INTO people (name, email) VALUES ('name384', 'Person384@Example.com');
+INSERT INTO people (name, email) VALUES ('name385', 'Person385@Example.com');
+INSERT INTO people (name, email) VALUES ('name386', 'Person386@Example.com');
+INSERT INTO people (name, email) VALUES ('name387', 'Person387@Example.com');
+INSERT INTO people (name, email) VALUES ('name388', 'Person388@Example.com');
+INSERT INTO people (name, email) VALUES ('name389', 'Person389@Example.com');
+INSERT INTO people (name, email) VALUES ('name390', 'Person390@Example.com');
+INSERT INTO people (name, email) VALUES ('name391', 'Person391@Example.com');
+INSERT INTO people (name, email) VALUES ('name392', 'Person392@Example.com');
+INSERT INTO people (name, email) VALUES ('name393', 'Person393@Example.com');
+INSERT INTO people (name, email) VALUES ('name394', 'Person394@Example.com');
+INSERT INTO people (name, email) VALUES ('name395', 'Person395@Example.com');
+INSERT INTO people (name, email) VALUES ('name396', 'Person396@Example.com');
+INSERT INTO people (name, email) VALUES ('name397', 'Person397@Example.com');
+INSERT INTO people (name, email) VALUES ('name398', 'Person398@Example.com');
+INSERT INTO people (name, email) VALUES ('name399', 'Person399@Example.com');
+INSERT INTO people (name, email) VALUES ('name000', 'Person400@Example.com');
+INSERT INTO people (name, email) VALUES ('name001', 'Person401@Example.com');
+INSERT INTO people (name, email) VALUES ('name002', 'Person402@Example.com');
+INSERT INTO people (name, email) VALUES ('name003', 'Person403@Example.com');
+INSERT INTO people (name, email) VALUES ('name004', 'Person404@Example.com');
+INSERT INTO people (name, email) VALUES ('name005', 'Person405@Example.com');
+INSERT INTO people (name, email) VALUES ('name006', 'Person406@Example.com');
+INSERT INTO people (name, email) VALUES ('name007', 'Person407@Example.com');
+INSERT INTO people (name, email) VALUES ('name008', 'Person408@Example.com');
+INSERT INTO people (name, email) VALUES ('name009', 'Person409@Example.com');
+INSERT INTO people (name, email) VALUES ('name010', 'Person410@Example.com');
+INSERT INTO people (name, email) VALUES ('name011', 'Person411@Example.com');
+INSERT INTO people (name, email) VALUES ('name012', 'Person412@Example.com');
+INSERT INTO people (name, email) VALUES ('name013', 'Person413@Example.com');
+INSERT INTO people (name, email) VALUES ('name014', 'Person414@Example.com');
+INSERT INTO people (name, email) VALUES ('name015', 'Person415@Example.com');
+INSERT INTO people (name, email) VALUES ('name016', 'Person416@Example.com');
+INSERT INTO people (name, email) VALUES ('name017', 'Person417@Example.com');
+INSERT INTO people (name, email) VALUES ('name018', 'Person418@Example.com');
+INSERT INTO people (name, email) VALUES ('name019', 'Person419@Example.com');
+INSERT INTO people (name, email) VALUES ('name020', 'Person420@Example.com');
+INSERT INTO people (name, email) VALUES ('name021', 'Person421@Example.com');
+INSERT INTO people (name, email) VALUES ('name022', 'Person422@Example.com');
+INSERT INTO people (name, email) VALUES ('name023', 'Person423@Example.com');
+INSERT INTO people (name, email) VALUES ('name024', 'Person424@Example.com');
+INSERT INTO people (name, email) VALUES ('name025', 'Person425@Example.com');
+INSERT INTO people (name, email) VALUES ('name026', 'Person426@Example.com');
+INSERT INTO people (name, email) VALUES ('name027', 'Person427@Example.com');
+INSERT INTO people (name, email) VALUES ('name028', 'Person428@Example.com');
+INSERT INTO people (name, email) VALUES ('name029', 'Person429@Example.com');
+INSERT INTO people (name, email) VALUES ('name030', 'Person430@Example.com');
+INSERT INTO people (name, email) VALUES ('name031', 'Person431@Example.com');
+INSERT INTO people (name, email) VALUES ('name032', 'Person432@Example.com');
+INSERT INTO people (name, email) VALUES ('name033', 'Person433@Example.com');
+INSERT INTO people (name, email) VALUES ('name034', 'Person434@Example.com');
+INSERT INTO people (name, email) VALUES ('name035', 'Person435@Example.com');
+INSERT INTO people (name, email) VALUES ('name036', 'Person436@Example.com');
+INSERT INTO people (name, email) VALUES ('name037', 'Person437@Example.com');
+INSERT INTO people (name, email) VALUES ('name038', 'Person438@Example.com');
+INSERT INTO people (name, email) VALUES ('name039', 'Person439@Example.com');
+INSERT INTO people (name, email) VALUES ('name040', 'Person440@Example.com');
+INSERT INTO people (name, email) VALUES ('name041', 'Person441@Example.com');
+INSERT INTO people (name, email) VALUES ('name042', 'Person442@Example.com');
+INSERT INTO people (name, email) VALUES ('name043', 'Person443@Example.com');
+INSERT INTO people (name, email) VALUES ('name044', 'Person444@Example.com');
+INSERT INTO people (name, email) VALUES ('name045', 'Person445@Example.com');
+INSERT INTO people (name, email) VALUES ('name046', 'Person446@Example.com');
+INSERT INTO people (name, email) VALUES ('name047', 'Person447@Example.com');
+INSERT INTO people (name, email) VALUES ('name048', 'Person448@Example.com');
+INSERT INTO people (name, email) VALUES ('name049', 'Person449@Example.com');
+INSERT INTO people (name, email) VALUES ('name050', 'Person450@Example.com');
+INSERT INTO people (name, email) VALUES ('name051', 'Person451@Example.com');
+INSERT INTO people (name, email) VALUES ('name052', 'Person452@Example.com');
+INSERT INTO people (name, email) VALUES ('name053', 'Person453@Example.com');
+INSERT INTO people (name, email) VALUES ('name054', 'Person454@Example.com');
+INSERT INTO people (name, email) VALUES ('name055', 'Person455@Example.com');
+INSERT INTO people (name, email) VALUES ('name056', 'Person456@Example.com');
+INSERT INTO people (name, email) VALUES ('name057', 'Person457@Example.com');
+INSERT INTO people (name, email) VALUES ('name058', 'Person458@Example.com');
+INSERT INTO people (name, email) VALUES ('name059', 'Person459@Example.com');
+INSERT INTO people (name, email) VALUES ('name060', 'Person460@Example.com');
+INSERT INTO people (name, email) VALUES ('name061', 'Person461@Example.com');
+INSERT INTO people (name, email) VALUES ('name062', 'Person462@Example.com');
+INSERT INTO people (name, email) VALUES ('name063', 'Person463@Example.com');
+INSERT INTO people (name, email) VALUES ('name064', 'Person464@Example.com');
+INSERT INTO people (name, email) VALUES ('name065', 'Person465@Example.com');
+INSERT INTO people (name, email) VALUES ('name066', 'Person466@Example.com');
+INSERT INTO people (name, email) VALUES ('name067', 'Person467@Example.com');
+INSERT INTO people (name, email) VALUES ('name068', 'Person468@Example.com');
+INSERT INTO people (name, email) VALUES ('name069', 'Person469@Example.com');
+INSERT INTO people (name, email) VALUES ('name070', 'Person470@Example.com');
+INSERT INTO people (name, email) VALUES ('name071', 'Person471@Example.com');
+INSERT INTO people (name, email) VALUES ('name072', 'Person472@Example.com');
+INSERT INTO people (name, email) VALUES ('name073', 'Person473@Example.com');
+INSERT INTO people (name, email) VALUES ('name074', 'Person474@Example.com');
+INSERT INTO people (name, email) VALUES ('name075', 'Person475@Example.com');
+INSERT INTO people (name, email) VALUES ('name076', 'Person476@Example.com');
+INSERT INTO people (name, email) VALUES ('name077', 'Person477@Example.com');
+INSERT INTO people (name, email) VALUES ('name078', 'Person478@Example.com');
+INSERT INTO people (name, email) VALUES ('name079', 'Person479@Example.com');
+INSERT INTO people (name, email) VALUES ('name080', 'Person480@Example.com');
+INSERT INTO people (name, email) VALUES ('name081', 'Person481@Example.com');
+INSERT INTO people (name, email) VALUES ('name082', 'Person482@Example.com');
+INSERT INTO people (name, email) VALUES ('name083', 'Person483@Example.com');
+INSERT INTO people (name, email) VALUES ('name084', 'Person484@Example.com');
+INSERT INTO people (name, email) VALUES ('name085', 'Person485@Example.com');
+INSERT INTO people (name, email) VALUES ('name086', 'Person486@Example.com');
+INSERT INTO people (name, email) VALUES ('name087', 'Person487@Example.com');
+INSERT INTO people (name, email) VALUES ('name088', 'Person488@Example.com');
+INSERT INTO people (name, email) VALUES ('name089', 'Person489@Example.com');
+INSERT INTO people (name, email) VALUES ('name090', 'Person490@Example.com');
+INSERT INTO people (name, email) VALUES ('name091', 'Person491@Example.com');
+INSERT INTO people (name, email) VALUES ('name092', 'Person492@Example.com');
+INSERT INTO people (name, email) VALUES ('name093', 'Person493@Example.com');
+INSERT INTO people (name, email) VALUES ('name094', 'Person494@Example.com');
+INSERT INTO people (name, email) VALUES ('name095', 'Person495@Example.com');
+INSERT INTO people (name, email) VALUES ('name096', 'Person496@Example.com');
+INSERT INTO people (name, email) VALUES ('name097', 'Person497@Example.com');
+INSERT INTO people (name, email) VALUES ('name098', 'Person498@Example.com');
+INSERT INTO people (name, email) VALUES ('name099', 'Person499@Example.com');
+INSERT INTO people (name, email) VALUES ('name100', 'Person500@Example.com');
+INSERT INTO people (name, email) VALUES ('name101', 'Person501@Example.com');
+INSERT INTO people (name, email) VALUES ('name102', 'Person502@Example.com');
+INSERT INTO people (name, email) VALUES ('name103', 'Person503@Example.com');
+INSERT INTO people (name, email) VALUES ('name104', 'Person504@Example.com');
+INSERT INTO people (name, email) VALUES ('name105', 'Person505@Example.com');
+INSERT INTO people (name, email) VALUES ('name106', 'Person506@Example.com');
+INSERT INTO people (name, email) VALUES ('name107', 'Person507@Example.com');
+INSERT INTO people (name, email) VALUES ('name108', 'Person508@Example.com');
+INSERT INTO people (name, email) VALUES ('name109', 'Person509@Example.com');
+INSERT INTO people (name, email) VALUES ('name110', 'Person510@Example.com');
+INSERT INTO people (name, email) VALUES ('name111', 'Person511@Example.com');
+INSERT INTO people (name, email) VALUES ('name112', 'Person512@Example.com');
+INSERT INTO people (name, email) VALUES ('name113', 'Person513@Example.com');
+INSERT INTO people (name, email) VALUES ('name114', 'Person514@Example.com');
+INSERT INTO people (name, email) VALUES ('name115', 'Person515@Example.com');
+INSERT INTO people (name, email) VALUES ('name116', 'Person516@Example.com');
+INSERT INTO people (name, email) VALUES ('name117', 'Person517@Example.com');
+INSERT INTO people (name, email) VALUES ('name118', 'Person518@Example.com');
+INSERT INTO people (name, email) VALUES ('name119', 'Person519@Example.com');
+INSERT INTO people (name, email) VALUES ('name120', 'Person520@Example.com');
+INSERT INTO people (name, email) VALUES ('name121', 'Person521@Example.com');
+INSERT INTO people (name, email) VALUES ('name122', 'Person522@Example.com');
+INSERT INTO people (name, email) VALUES ('name123', 'Person523@Example.com');
+INSERT INTO people (name, email) VALUES ('name124', 'Person524@Example.com');
+INSERT INTO people (name, email) VALUES ('name125', 'Person525@Example.com');
+INSERT INTO people (name, email) VALUES ('name126', 'Person526@Example.com');
+INSERT INTO people (name, email) VALUES ('name127', 'Person527@Example.com');
+INSERT INTO people (name, email) VALUES ('name128', 'Person528@Example.com');
+INSERT INTO people (name, email) VALUES ('name129', 'Person529@Example.com');
+INSERT INTO people (name, email) VALUES ('name130', 'Person530@Example.com');
+INSERT INTO people (name, email) VALUES ('name131', 'Person531@Example.com');
+INSERT INTO people (name, email) VALUES ('name132', 'Person532@Example.com');
+INSERT INTO people (name, email) VALUES ('name133', 'Person533@Example.com');
+INSERT INTO people (name, email) VALUES ('name134', 'Person534@Example.com');
+INSERT INTO people (name, email) VALUES ('name135', 'Person535@Example.com');
+INSERT INTO people (name, email) VALUES ('name136', 'Person536@Example.com');
+INSERT INTO people (name, email) VALUES ('name137', 'Person537@Example.com');
+INSERT INTO people (name, email) VALUES ('name138', 'Person538@Example.com');
+INSERT INTO people (name, email) VALUES ('name139', 'Person539@Example.com');
+INSERT INTO people (name, email) VALUES ('name140', 'Person540@Example.com');
+INSERT INTO people (name, email) VALUES ('name141', 'Person541@Example.com');
+INSERT INTO people (name, email) VALUES ('name142', 'Person542@Example.com');
+INSERT INTO people (name, email) VALUES ('name143', 'Person543@Example.com');
+INSERT INTO people (name, email) VALUES ('name144', 'Person544@Example.com');
+INSERT INTO people (name, email) VALUES ('name145', 'Person545@Example.com');
+INSERT INTO people (name, email) VALUES ('name146', 'Person546@Example.com');
+INSERT INTO people (name, email) VALUES ('name147', 'Person547@Example.com');
+INSERT INTO people (name, email) VALUES ('name148', 'Person548@Example.com');
+INSERT INTO people (name, email) VALUES ('name149', 'Person549@Example.com');
+INSERT INTO people (name, email) VALUES ('name150', 'Person550@Example.com');
+INSERT INTO people (name, email) VALUES ('name151', 'Person551@Example.com');
+INSERT INTO people (name, email) VALUES ('name152', 'Person552@Example.com');
+INSERT INTO people (name, email) VALUES ('name153', 'Person553@Example.com');
+INSERT INTO people (name, email) VALUES ('name154', 'Person554@Example.com');
+INSERT INTO people (name, email) VALUES ('name155', 'Person555@Example.com');
+INSERT INTO people (name, email) VALUES ('name156', 'Person556@Example.com');
+INSERT INTO people (name, email) VALUES ('name157', 'Person557@Example.com');
+INSERT INTO people (name, email) VALUES ('name158', 'Person558@Example.com');
+INSERT INTO people (name, email) VALUES ('name159', 'Person559@Example.com');
+INSERT INTO people (name, email) VALUES ('name160', 'Person560@Example.com');
+INSERT INTO people (name, email) VALUES ('name161', 'Person561@Example.com');
+INSERT INTO people (name, email) VALUES ('name162', 'Person562@Example.com');
+INSERT INTO people (name, email) VALUES ('name163', 'Person563@Example.com');
+INSERT INTO people (name, email) VALUES ('name164', 'Person564@Example.com');
+INSERT INTO people (name, email) VALUES ('name165', 'Person565@Example.com');
+INSERT INTO people (name, email) VALUES ('name166', 'Person566@Example.com');
+INSERT INTO people (name, email) VALUES ('name167', 'Person567@Example.com');
+INSERT INTO people (name, email) VALUES ('name168', 'Person568@Example.com');
+INSERT INTO people (name, email) VALUES ('name169', 'Person569@Example.com');
+INSERT INTO people (name, email) VALUES ('name170', 'Person570@Example.com');
+INSERT INTO people (name, email) VALUES ('name171', 'Person571@Example.com');
+INSERT INTO people (name, email) VALUES ('name172', 'Person572@Example.com');
+INSERT INTO people (name, email) VALUES ('name173', 'Person573@Example.com');
+INSERT INTO people (name, email) VALUES ('name174', 'Person574@Example.com');
+INSERT INTO people (name, email) VALUES ('name175', 'Person575@Example.com');
+INSERT INTO people (name, email) VALUES ('name176', 'Person576@Example.com');
+INSERT INTO people (name, email) VALUES ('name177', 'Person577@Example.com');
+INSERT INTO people (name, email) VALUES ('name178', 'Person578@Example.com');
+INSERT INTO people (name, email) VALUES ('name179', 'Person579@Example.com');
+INSERT INTO people (name, email) VALUES ('name180', 'Person580@Example.com');
+INSERT INTO people (name, email) VALUES ('name181', 'Person581@Example.com');
+INSERT INTO people (name, email) VALUES ('name182', 'Person582@Example.com');
+INSERT INTO people (name, email) VALUES ('name183', 'Person583@Example.com');
+INSERT INTO people (name, email) VALUES ('name184', 'Person584@Example.com');
+INSERT INTO people (name, email) VALUES ('name185', 'Person585@Example.com');
+INSERT INTO people (name, email) VALUES ('name186', 'Person586@Example.com');
+INSERT INTO people (name, email) VALUES ('name187', 'Person587@Example.com');
+INSERT INTO people (name, email) VALUES ('name188', 'Person588@Example.com');
+INSERT INTO people (name, email) VALUES ('name189', 'Person589@Example.com');
+INSERT INTO people (name, email) VALUES ('name190', 'Person590@Example.com');
+INSERT INTO people (name, email) VALUES ('name191', 'Person591@Example.com');
+INSERT INTO people (name, email) VALUES ('name192', 'Person592@Example.com');
+INSERT INTO people (name, email) VALUES ('name193', 'Person593@Example.com');
+INSERT INTO people (name, email) VALUES ('name194', 'Person594@Example.com');
+INSERT INTO people (name, email) VALUES ('name195', 'Person595@Example.com');
+INSERT INTO people (name, email) VALUES ('name196', 'Person596@Example.com');
+INSERT INTO people (name, email) VALUES ('name197', 'Person597@Example.com');
+INSERT INTO people (name, email) VALUES ('name198', 'Person598@Example.com');
+INSERT INTO people (name, email) VALUES ('name199', 'Person599@Example.com');
+INSERT INTO people (name, email) VALUES ('name200', 'Person600@Example.com');
+INSERT INTO people (name, email) VALUES ('name201', 'Person601@Example.com');
+INSERT INTO people (name, email) VALUES ('name202', 'Person602@Example.com');
+INSERT INTO people (name, email) VALUES ('name203', 'Person603@Example.com');
+INSERT INTO people (name, email) VALUES ('name204', 'Person604@Example.com');
+INSERT INTO people (name, email) VALUES ('name205', 'Person605@Example.com');
+INSERT INTO people (name, email) VALUES ('name206', 'Person606@Example.com');
+INSERT INTO people (name, email) VALUES ('name207', 'Person607@Example.com');
+INSERT INTO people (name, email) VALUES ('name208', 'Person608@Example.com');
+INSERT INTO people (name, email) VALUES ('name209', 'Person609@Example.com');
+INSERT INTO people (name, email) VALUES ('name210', 'Person610@Example.com');
+INSERT INTO people (name, email) VALUES ('name211', 'Person611@Example.com');
+INSERT INTO people (name, email) VALUES ('name212', 'Person612@Example.com');
+INSERT INTO people (name, email) VALUES ('name213', 'Person613@Example.com');
+INSERT INTO people (name, email) VALUES ('name214', 'Person614@Example.com');
+INSERT INTO people (name, email) VALUES ('name215', 'Person615@Example.com');
+INSERT INTO people (name, email) VALUES ('name216', 'Person616@Example.com');
+INSERT INTO people (name, email) VALUES ('name217', 'Person617@Example.com');
+INSERT INTO people (name, email) VALUES ('name218', 'Person618@Example.com');
+INSERT INTO people (name, email) VALUES ('name219', 'Person619@Example.com');
+INSERT INTO people (name, email) VALUES ('name220', 'Person620@Example.com');
+INSERT INTO people (name, email) VALUES ('name221', 'Person621@Example.com');
+INSERT INTO people (name, email) VALUES ('name222', 'Person622@Example.com');
+INSERT INTO people (name, email) VALUES ('name223', 'Person623@Example.com');
+INSERT INTO people (name, email) VALUES ('name224', 'Person624@Example.com');
+INSERT INTO people (name, email) VALUES ('name225', 'Person625@Example.com');
+INSERT INTO people (name, email) VALUES ('name226', 'Person626@Example.com');
+INSERT INTO people (name, email) VALUES ('name227', 'Person627@Example.com');
+INSERT INTO people (name, email) VALUES ('name228', 'Person628@Example.com');
+INSERT INTO people (name, email) VALUES ('name229', 'Person629@Example.com');
+INSERT INTO people (name, email) VALUES ('name230', 'Person630@Example.com');
+INSERT INTO people (name, email) VALUES ('name231', 'Person631@Example.com');
+INSERT INTO people (name, email) VALUES ('name232', 'Person632@Example.com');
+INSERT INTO people (name, email) VALUES ('name233', 'Person633@Example.com');
+INSERT INTO people (name, email) VALUES ('name234', 'Person634@Example.com');
+INSERT INTO people (name, email) VALUES ('name235', 'Person635@Example.com');
+INSERT INTO people (name, email) VALUES ('name236', 'Person636@Example.com');
+INSERT INTO people (name, email) VALUES ('name237', 'Person637@Example.com');
+INSERT INTO people (name, email) VALUES ('name238', 'Person638@Example.com');
+INSERT INTO people (name, email) VALUES ('name239', 'Person639@Example.com');
+INSERT INTO people (name, email) VALUES ('name240', 'Person640@Example.com');
+INSERT INTO people (name, email) VALUES ('name241', 'Person641@Example.com');
+INSERT INTO people (name, email) VALUES ('name242', 'Person642@Example.com');
+INSERT INTO people (name, email) VALUES ('name243', 'Person643@Example.com');
+INSERT INTO people (name, email) VALUES ('name244', 'Person644@Example.com');
+INSERT INTO people (name, email) VALUES ('name245', 'Person645@Example.com');
+INSERT INTO people (name, email) VALUES ('name246', 'Person646@Example.com');
+INSERT INTO people (name, email) VALUES ('name247', 'Person647@Example.com');
+INSERT INTO people (name, email) VALUES ('name248', 'Person648@Example.com');
+INSERT INTO people (name, email) VALUES ('name249', 'Person649@Example.com');
+INSERT INTO people (name, email) VALUES ('name250', 'Person650@Example.com');
+INSERT INTO people (name, email) VALUES ('name251', 'Person651@Example.com');
+INSERT INTO people (name, email) VALUES ('name252', 'Person652@Example.com');
+INSERT INTO people (name, email) VALUES ('name253', 'Person653@Example.com');
+INSERT INTO people (name, email) VALUES ('name254', 'Person654@Example.com');
+INSERT INTO people (name, email) VALUES ('name255', 'Person655@Example.com');
+INSERT INTO people (name, email) VALUES ('name256', 'Person656@Example.com');
+INSERT INTO people (name, email) VALUES ('name257', 'Person657@Example.com');
+INSERT INTO people (name, email) VALUES ('name258', 'Person658@Example.com');
+INSERT INTO people (name, email) VALUES ('name259', 'Person659@Example.com');
+INSERT INTO people (name, email) VALUES ('name260', 'Person660@Example.com');
+INSERT INTO people (name, email) VALUES ('name261', 'Person661@Example.com');
+INSERT INTO people (name, email) VALUES ('name262', 'Person662@Example.com');
+INSERT INTO people (name, email) VALUES ('name263', 'Person663@Example.com');
+INSERT INTO people (name, email) VALUES ('name264', 'Person664@Example.com');
+INSERT INTO people (name, email) VALUES ('name265', 'Person665@Example.com');
+INSERT INTO people (name, email) VALUES ('name266', 'Person666@Example.com');
+INSERT INTO people (name, email) VALUES ('name267', 'Person667@Example.com');
+INSERT INTO people (name, email) VALUES ('name268', 'Person668@Example.com');
+INSERT INTO people (name, email) VALUES ('name269', 'Person669@Example.com');
+INSERT INTO people (name, email) VALUES ('name270', 'Person670@Example.com');
+INSERT INTO people (name, email) VALUES ('name271', 'Person671@Example.com');
+INSERT INTO people (name, email) VALUES ('name272', 'Person672@Example.com');
+INSERT INTO people (name, email) VALUES ('name273', 'Person673@Example.com');
+INSERT INTO people (name, email) VALUES ('name274', 'Person674@Example.com');
+INSERT INTO people (name, email) VALUES ('name275', 'Person675@Example.com');
+INSERT INTO people (name, email) VALUES ('name276', 'Person676@Example.com');
+INSERT INTO people (name, email) VALUES ('name277', 'Person677@Example.com');
+INSERT INTO people (name, email) VALUES ('name278', 'Person678@Example.com');
+INSERT INTO people (name, email) VALUES ('name279', 'Person679@Example.com');
+INSERT INTO people (name, email) VALUES ('name280', 'Person680@Example.com');
+INSERT INTO people (name, email) VALUES ('name281', 'Person681@Example.com');
+INSERT INTO people (name, email) VALUES ('name282', 'Person682@Example.com');
+INSERT INTO people (name, email) VALUES ('name283', 'Person683@Example.com');
+INSERT INTO people (name, email) VALUES ('name284', 'Person684@Example.com');
+INSERT INTO people (name, email) VALUES ('name285', 'Person685@Example.com');
+INSERT INTO people (name, email) VALUES ('name286', 'Person686@Example.com');
+INSERT INTO people (name, email) VALUES ('name287', 'Person687@Example.com');
+INSERT INTO people (name, email) VALUES ('name288', 'Person688@Example.com');
+INSERT INTO people (name, email) VALUES ('name289', 'Person689@Example.com');
+INSERT INTO people (name, email) VALUES ('name290', 'Person690@Example.com');
+INSERT INTO people (name, email) VALUES ('name291', 'Person691@Example.com');
+INSERT INTO people (name, email) VALUES ('name292', 'Person692@Example.com');
+INSERT INTO people (name, email) VALUES ('name293', 'Person693@Example.com');
+INSERT INTO people (name, email) VALUES ('name294', 'Person694@Example.com');
+INSERT INTO people (name, email) VALUES ('name295', 'Person695@Example.com');
+INSERT INTO people (name, email) VALUES ('name296', 'Person696@Example.com');
+INSERT INTO people (name, email) VALUES ('name297', 'Person697@Example.com');
+INSERT INTO people (name, email) VALUES ('name298', 'Person698@Example.com');
+INSERT INTO people (name, email) VALUES ('name299', 'Person699@Example.com');
+INSERT INTO people (name, email) VALUES ('name300', 'Person700@Example.com');
+INSERT INTO people (name, email) VALUES ('name301', 'Person701@Example.com');
+INSERT INTO people (name, email) VALUES ('name302', 'Person702@Example.com');
+INSERT INTO people (name, email) VALUES ('name303', 'Person703@Example.com');
+INSERT INTO people (name, email) VALUES ('name304', 'Person704@Example.com');
+INSERT INTO people (name, email) VALUES ('name305', 'Person705@Example.com');
+INSERT INTO people (name, email) VALUES ('name306', 'Person706@Example.com');
+INSERT INTO people (name, email) VALUES ('name307', 'Person707@Example.com');
+INSERT INTO people (name, email) VALUES ('name308', 'Person708@Example.com');
+INSERT INTO people (name, email) VALUES ('name309', 'Person709@Example.com');
+INSERT INTO people (name, email) VALUES ('name310', 'Person710@Example.com');
+INSERT INTO people (name, email) VALUES ('name311', 'Person711@Example.com');
+INSERT INTO people (name, email) VALUES ('name312', 'Person712@Example.com');
+INSERT INTO people (name, email) VALUES ('name313', 'Person713@Example.com');
+INSERT INTO people (name, email) VALUES ('name314', 'Person714@Example.com');
+INSERT INTO people (name, email) VALUES ('name315', 'Person715@Example.com');
+INSERT INTO people (name, email) VALUES ('name316', 'Person716@Example.com');
+INSERT INTO people (name, email) VALUES ('name317', 'Person717@Example.com');
+INSERT INTO people (name, email) VALUES ('name318', 'Person718@Example.com');
+INSERT INTO people (name, email) VALUES ('name319', 'Person719@Example.com');
+INSERT INTO people (name, email) VALUES ('name320', 'Person720@Example.com');
+INSERT INTO people (name, email) VALUES ('name321', 'Person721@Example.com');
+INSERT INTO people (name, email) VALUES ('name322', 'Person722@Example.com');
+INSERT INTO people (name, email) VALUES ('name323', 'Person723@Example.com');
+INSERT INTO people (name, email) VALUES ('name324', 'Person724@Example.com');
+INSERT INTO people (name, email) VALUES ('name325', 'Person725@Example.com');
+INSERT INTO people (name, email) VALUES ('name326', 'Person726@Example.com');
+INSERT INTO people (name, email) VALUES ('name327', 'Person727@Example.com');
+INSERT INTO people (name, email) VALUES ('name328', 'Person728@Example.com');
+INSERT INTO people (name, email) VALUES ('name329', 'Person729@Example.com');
+INSERT INTO people (name, email) VALUES ('name330', 'Person730@Example.com');
+INSERT INTO people (name, email) VALUES ('name331', 'Person731@Example.com');
+INSERT INTO people (name, email) VALUES ('name332', 'Person732@Example.com');
+INSERT INTO people (name, email) VALUES ('name333', 'Person733@Example.com');
+INSERT INTO people (name, email) VALUES ('name334', 'Person734@Example.com');
+INSERT INTO people (name, email) VALUES ('name335', 'Person735@Example.com');
+INSERT INTO people (name, email) VALUES ('name336', 'Person736@Example.com');
+INSERT INTO people (name, email) VALUES ('name337', 'Person737@Example.com');
+INSERT INTO people (name, email) VALUES ('name338', 'Person738@Example.com');
+INSERT INTO people (name, email) VALUES ('name339', 'Person739@Example.com');
+INSERT INTO people (name, email) VALUES ('name340', 'Person740@Example.com');
+INSERT INTO people (name, email) VALUES ('name341', 'Person741@Example.com');
+INSERT INTO people (name, email) VALUES ('name342', 'Person742@Example.com');
+INSERT INTO people (name, email) VALUES ('name343', 'Person743@Example.com');
+INSERT INTO people (name, email) VALUES ('name344', 'Person744@Example.com');
+INSERT INTO people (name, email) VALUES ('name345', 'Person745@Example.com');
+INSERT INTO people (name, email) VALUES ('name346', 'Person746@Example.com');
+INSERT INTO people (name, email) VALUES ('name347', 'Person747@Example.com');
+INSERT INTO people (name, email) VALUES ('name348', 'Person748@Example.com');
+INSERT INTO people (name, email) VALUES ('name349', 'Person749@Example.com');
+INSERT INTO people (name, email) VALUES ('name350', 'Person750@Example.com');
+INSERT INTO people (name, email) VALUES ('name351', 'Person751@Example.com');
+INSERT INTO people (name, email) VALUES ('name352', 'Person752@Example.com');
+INSERT INTO people (name, email) VALUES ('name353', 'Person753@Example.com');
+INSERT INTO people (name, email) VALUES ('name354', 'Person754@Example.com');
+INSERT INTO people (name, email) VALUES ('name355', 'Person755@Example.com');
+INSERT INTO people (name, email) VALUES ('name356', 'Person756@Example.com');
+INSERT INTO people (name, email) VALUES ('name357', 'Person757@Example.com');
+INSERT INTO people (name, email) VALUES ('name358', 'Person758@Example.com');
+INSERT INTO people (name, email) VALUES ('name359', 'Person759@Example.com');
+INSERT INTO people (name, email) VALUES ('name360', 'Person760@Example.com');
+INSERT INTO people (name, email) VALUES ('name361', 'Person761@Example.com');
+INSERT INTO people (name, email) VALUES ('name362', 'Person762@Example.com');
+INSERT INTO people (name, email) VALUES ('name363', 'Person763@Example.com');
+INSERT INTO people (name, email) VALUES ('name364', 'Person764@Example.com');
+INSERT INTO people (name, email) VALUES ('name365', 'Person765@Example.com');
+INSERT INTO people (name, email) VALUES ('name366', 'Person766@Example.com');
+INSERT INTO people (name, email) VALUES ('name367', 'Person767@Example.com');
+INSERT INTO people (name, email) VALUES ('name368', 'Person768@Example.com');
+INSERT INTO people (name, email) VALUES ('name369', 'Person769@Example.com');
+INSERT INTO people (name, email) VALUES ('name370', 'Person770@Example.com');
+INSERT INTO people (name, email) VALUES ('name371', 'Person771@Example.com');
+INSERT INTO people (name, email) VALUES ('name372', 'Person772@Example.com');
+INSERT INTO people (name, email) VALUES ('name373', 'Person773@Example.com');
+INSERT INTO people (name, email) VALUES ('name374', 'Person774@Example.com');
+INSERT INTO people (name, email) VALUES ('name375', 'Person775@Example.com');
+INSERT INTO people (name, email) VALUES ('name376', 'Person776@Example.com');
+INSERT INTO people (name, email) VALUES ('name377', 'Person777@Example.com');
+INSERT INTO people (name, email) VALUES ('name378', 'Person778@Example.com');
+INSERT INTO people (name, email) VALUES ('name379', 'Person779@Example.com');
+INSERT INTO people (name, email) VALUES ('name380', 'Person780@Example.com');
+INSERT INTO people (name, email) VALUES ('name381', 'Person781@Example.com');
+INSERT INTO people (name, email) VALUES ('name382', 'Person782@Example.com');
+INSERT INTO people (name, email) VALUES ('name383', 'Person783@Example.com');
+INSERT INTO people (name, email) VALUES ('name384', 'Person784@Example.com');
+INSERT INTO people (name, email) VALUES ('name385', 'Person785@Example.com');
+INSERT INTO people (name, email) VALUES ('name386', 'Person786@Example.com');
+INSERT INTO people (name, email) VALUES ('name387', 'Person787@Example.com');
+INSERT INTO people (name, email) VALUES ('name388', 'Person788@Example.com');
+INSERT INTO people (name, email) VALUES ('name389', 'Person789@Example.com');
+INSERT INTO people (name, email) VALUES ('name390', 'Person790@Example.com');
+INSERT INTO people (name, email) VALUES ('name391', 'Person791@Example.com');
+INSERT INTO people (name, email) VALUES ('name392', 'Person792@Example.com');
+INSERT INTO people (name, email) VALUES ('name393', 'Person793@Example.com');
+INSERT INTO people (name, email) VALUES ('name394', 'Person794@Example.com');
+INSERT INTO people (name, email) VALUES ('name395', 'Person795@Example.com');
+INSERT INTO people (name, email) VALUES ('name396', 'Person796@Example.com');
+INSERT INTO people (name, email) VALUES ('name397', 'Person797@Example.com');
+INSERT INTO people (name, email) VALUES ('name398', 'Person798@Example.com');
+INSERT INTO people (name, email) VALUES ('name399', 'Person799@Example.com');
+INSERT INTO people (name, email) VALUES ('name000', 'Person800@Example.com');
+INSERT INTO people (name, email) VALUES ('name001', 'Person801@Example.com');
+INSERT INTO people (name, email) VALUES ('name002', 'Person802@Example.com');
+INSERT INTO people (name, email) VALUES ('name003', 'Person803@Example.com');
+INSERT INTO people (name, email) VALUES ('name004', 'Person804@Example.com');
+INSERT INTO people (name, email) VALUES ('name005', 'Person805@Example.com');
+INSERT INTO people (name, email) VALUES ('name006', 'Person806@Example.com');
+INSERT INTO people (name, email) VALUES ('name007', 'Person807@Example.com');
+INSERT INTO people (name, email) VALUES ('name008', 'Person808@Example.com');
+INSERT INTO people (name, email) VALUES ('name009', 'Person809@Example.com');
+INSERT INTO people (name, email) VALUES ('name010', 'Person810@Example.com');
+INSERT INTO people (name, email) VALUES ('name011', 'Person811@Example.com');
+INSERT INTO people (name, email) VALUES ('name012', 'Person812@Example.com');
+INSERT INTO people (name, email) VALUES ('name013', 'Person813@Example.com');
+INSERT INTO people (name, email) VALUES ('name014', 'Person814@Example.com');
+INSERT INTO people (name, email) VALUES ('name015', 'Person815@Example.com');
+INSERT INTO people (name, email) VALUES ('name016', 'Person816@Example.com');
+INSERT INTO people (name, email) VALUES ('name017', 'Person817@Example.com');
+INSERT INTO people (name, email) VALUES ('name018', 'Person818@Example.com');
+INSERT INTO people (name, email) VALUES ('name019', 'Person819@Example.com');
+INSERT INTO people (name, email) VALUES ('name020', 'Person820@Example.com');
+INSERT INTO people (name, email) VALUES ('name021', 'Person821@Example.com');
+INSERT INTO people (name, email) VALUES ('name022', 'Person822@Example.com');
+INSERT INTO people (name, email) VALUES ('name023', 'Person823@Example.com');
+INSERT INTO people (name, email) VALUES ('name024', 'Person824@Example.com');
+INSERT INTO people (name, email) VALUES ('name025', 'Person825@Example.com');
+INSERT INTO people (name, email) VALUES ('name026', 'Person826@Example.com');
+INSERT INTO people (name, email) VALUES ('name027', 'Person827@Example.com');
+INSERT INTO people (name, email) VALUES ('name028', 'Person828@Example.com');
+INSERT INTO people (name, email) VALUES ('name029', 'Person829@Example.com');
+INSERT INTO people (name, email) VALUES ('name030', 'Person830@Example.com');
+INSERT INTO people (name, email) VALUES ('name031', 'Person831@Example.com');
+INSERT INTO people (name, email) VALUES ('name032', 'Person832@Example.com');
+INSERT INTO people (name, email) VALUES ('name033', 'Person833@Example.com');
+INSERT INTO people (name, email) VALUES ('name034', 'Person834@Example.com');
+INSERT INTO people (name, email) VALUES ('name035', 'Person835@Example.com');
+INSERT INTO people (name, email) VALUES ('name036', 'Person836@Example.com');
+INSERT INTO people (name, email) VALUES ('name037', 'Person837@Example.com');
+INSERT INTO people (name, email) VALUES ('name038', 'Person838@Example.com');
+INSERT INTO people (name, email) VALUES ('name039', 'Person839@Example.com');
+INSERT INTO people (name, email) VALUES ('name040', 'Person840@Example.com');
+INSERT INTO people (name, email) VALUES ('name041', 'Person841@Example.com');
+INSERT INTO people (name, email) VALUES ('name042', 'Person842@Example.com');
+INSERT INTO people (name, email) VALUES ('name043', 'Person843@Example.com');
+INSERT INTO people (name, email) VALUES ('name044', 'Person844@Example.com');
+INSERT INTO people (name, email) VALUES ('name045', 'Person845@Example.com');
+INSERT INTO people (name, email) VALUES ('name046', 'Person846@Example.com');
+INSERT INTO people (name, email) VALUES ('name047', 'Person847@Example.com');
+INSERT INTO people (name, email) VALUES ('name048', 'Person848@Example.com');
+INSERT INTO people (name, email) VALUES ('name049', 'Person849@Example.com');
+INSERT INTO people (name, email) VALUES ('name050', 'Person850@Example.com');
+INSERT INTO people (name, email) VALUES ('name051', 'Person851@Example.com');
+INSERT INTO people (name, email) VALUES ('name052', 'Person852@Example.com');
+INSERT INTO people (name, email) VALUES ('name053', 'Person853@Example.com');
+INSERT INTO people (name, email) VALUES ('name054', 'Person854@Example.com');
+INSERT INTO people (name, email) VALUES ('name055', 'Person855@Example.com');
+INSERT INTO people (name, email) VALUES ('name056', 'Person856@Example.com');
+INSERT INTO people (name, email) VALUES ('name057', 'Person857@Example.com');
+INSERT INTO people (name, email) VALUES ('name058', 'Person858@Example.com');
+INSERT INTO people (name, email) VALUES ('name059', 'Person859@Example.com');
+INSERT INTO people (name, email) VALUES ('name060', 'Person860@Example.com');
+INSERT INTO people (name, email) VALUES ('name061', 'Person861@Example.com');
+INSERT INTO people (name, email) VALUES ('name062', 'Person862@Example.com');
+INSERT INTO people (name, email) VALUES ('name063', 'Person863@Example.com');
+INSERT INTO people (name, email) VALUES ('name064', 'Person864@Example.com');
+INSERT INTO people (name, email) VALUES ('name065', 'Person865@Example.com');
+INSERT INTO people (name, email) VALUES ('name066', 'Person866@Example.com');
+INSERT INTO people (name, email) VALUES ('name067', 'Person867@Example.com');
+INSERT INTO people (name, email) VALUES ('name068', 'Person868@Example.com');
+INSERT INTO people (name, email) VALUES ('name069', 'Person869@Example.com');
+INSERT INTO people (name, email) VALUES ('name070', 'Person870@Example.com');
+INSERT INTO people (name, email) VALUES ('name071', 'Person871@Example.com');
+INSERT INTO people (name, email) VALUES ('name072', 'Person872@Example.com');
+INSERT INTO people (name, email) VALUES ('name073', 'Person873@Example.com');
+INSERT INTO people (name, email) VALUES ('name074', 'Person874@Example.com');
+INSERT INTO people (name, email) VALUES ('name075', 'Person875@Example.com');
+INSERT INTO people (name, email) VALUES ('name076', 'Person876@Example.com');
+INSERT INTO people (name, email) VALUES ('name077', 'Person877@Example.com');
+INSERT INTO people (name, email) VALUES ('name078', 'Person878@Example.com');
+INSERT INTO people (name, email) VALUES ('name079', 'Person879@Example.com');
+INSERT INTO people (name, email) VALUES ('name080', 'Person880@Example.com');
+INSERT INTO people (name, email) VALUES ('name081', 'Person881@Example.com');
+INSERT INTO people (name, email) VALUES ('name082', 'Person882@Example.com');
+INSERT INTO people (name, email) VALUES ('name083', 'Person883@Example.com');
+INSERT INTO people (name, email) VALUES ('name084', 'Person884@Example.com');
+INSERT INTO people (name, email) VALUES ('name085', 'Person885@Example.com');
+INSERT INTO people (name, email) VALUES ('name086', 'Person886@Example.com');
+INSERT INTO people (name, email) VALUES ('name087', 'Person887@Example.com');
+INSERT INTO people (name, email) VALUES ('name088', 'Person888@Example.com');
+INSERT INTO people (name, email) VALUES ('name089', 'Person889@Example.com');
+INSERT INTO people (name, email) VALUES ('name090', 'Person890@Example.com');
+INSERT INTO people (name, email) VALUES ('name091', 'Person891@Example.com');
+INSERT INTO people (name, email) VALUES ('name092', 'Person892@Example.com');
+INSERT INTO people (name, email) VALUES ('name093', 'Person893@Example.com');
+INSERT INTO people (name, email) VALUES ('name094', 'Person894@Example.com');
+INSERT INTO people (name, email) VALUES ('name095', 'Person895@Example.com');
+INSERT INTO people (name, email) VALUES ('name096', 'Person896@Example.com');
+INSERT INTO people (name, email) VALUES ('name097', 'Person897@Example.com');
+INSERT INTO people (name, email) VALUES ('name098', 'Person898@Example.com');
+INSERT INTO people (name, email) VALUES ('name099', 'Person899@Example.com');
+INSERT INTO people (name, email) VALUES ('name100', 'Person900@Example.com');
+INSERT INTO people (name, email) VALUES ('name101', 'Person901@Example.com');
+INSERT INTO people (name, email) VALUES ('name102', 'Person902@Example.com');
+INSERT INTO people (name, email) VALUES ('name103', 'Person903@Example.com');
+INSERT INTO people (name, email) VALUES ('name104', 'Person904@Example.com');
+INSERT INTO people (name, email) VALUES ('name105', 'Person905@Example.com');
+INSERT INTO people (name, email) VALUES ('name106', 'Person906@Example.com');
+INSERT INTO people (name, email) VALUES ('name107', 'Person907@Example.com');
+INSERT INTO people (name, email) VALUES ('name108', 'Person908@Example.com');
+INSERT INTO people (name, email) VALUES ('name109', 'Person909@Example.com');
+INSERT INTO people (name, email) VALUES ('name110', 'Person910@Example.com');
+INSERT INTO people (name, email) VALUES ('name111', 'Person911@Example.com');
+INSERT INTO people (name, email) VALUES ('name112', 'Person912@Example.com');
+INSERT INTO people (name, email) VALUES ('name113', 'Person913@Example.com');
+INSERT INTO people (name, email) VALUES ('name114', 'Person914@Example.com');
+INSERT INTO people (name, email) VALUES ('name115', 'Person915@Example.com');
+INSERT INTO people (name, email) VALUES ('name116', 'Person916@Example.com');
+INSERT INTO people (name, email) VALUES ('name117', 'Person917@Example.com');
+INSERT INTO people (name, email) VALUES ('name118', 'Person918@Example.com');
+INSERT INTO people (name, email) VALUES ('name119', 'Person919@Example.com');
+INSERT INTO people (name, email) VALUES ('name120', 'Person920@Example.com');
+INSERT INTO people (name, email) VALUES ('name121', 'Person921@Example.com');
+INSERT INTO people (name, email) VALUES ('name122', 'Person922@Example.com');
+INSERT INTO people (name, email) VALUES ('name123', 'Person923@Example.com');
+INSERT INTO people (name, email) VALUES ('name124', 'Person924@Example.com');
+INSERT INTO people (name, email) VALUES ('name125', 'Person925@Example.com');
+INSERT INTO people (name, email) VALUES ('name126', 'Person926@Example.com');
+INSERT INTO people (name, email) VALUES ('name127', 'Person927@Example.com');
+INSERT INTO people (name, email) VALUES ('name128', 'Person928@Example.com');
+INSERT INTO people (name, email) VALUES ('name129', 'Person929@Example.com');
+INSERT INTO people (name, email) VALUES ('name130', 'Person930@Example.com');
+INSERT INTO people (name, email) VALUES ('name131', 'Person931@Example.com');
+INSERT INTO people (name, email) VALUES ('name132', 'Person932@Example.com');
+INSERT INTO people (name, email) VALUES ('name133', 'Person933@Example.com');
+INSERT INTO people (name, email) VALUES ('name134', 'Person934@Example.com');
+INSERT INTO people (name, email) VALUES ('name135', 'Person935@Example.com');
+INSERT INTO people (name, email) VALUES ('name136', 'Person936@Example.com');
+INSERT INTO people (name, email) VALUES ('name137', 'Person937@Example.com');
+INSERT INTO people (name, email) VALUES ('name138', 'Person938@Example.com');
+INSERT INTO people (name, email) VALUES ('name139', 'Person939@Example.com');
+INSERT INTO people (name, email) VALUES ('name140', 'Person940@Example.com');
+INSERT INTO people (name, email) VALUES ('name141', 'Person941@Example.com');
+INSERT INTO people (name, email) VALUES ('name142', 'Person942@Example.com');
+INSERT INTO people (name, email) VALUES ('name143', 'Person943@Example.com');
+INSERT INTO people (name, email) VALUES ('name144', 'Person944@Example.com');
+INSERT INTO people (name, email) VALUES ('name145', 'Person945@Example.com');
+INSERT INTO people (name, email) VALUES ('name146', 'Person946@Example.com');
+INSERT INTO people (name, email) VALUES ('name147', 'Person947@Example.com');
+INSERT INTO people (name, email) VALUES ('name148', 'Person948@Example.com');
+INSERT INTO people (name, email) VALUES ('name149', 'Person949@Example.com');
+INSERT INTO people (name, email) VALUES ('name150', 'Person950@Example.com');
+INSERT INTO people (name, email) VALUES ('name151', 'Person951@Example.com');
+INSERT INTO people (name, email) VALUES ('name152', 'Person952@Example.com');
+INSERT INTO people (name, email) VALUES ('name153', 'Person953@Example.com');
+INSERT INTO people (name, email) VALUES ('name154', 'Person954@Example.com');
+INSERT INTO people (name, email) VALUES ('name155', 'Person955@Example.com');
+INSERT INTO people (name, email) VALUES ('name156', 'Person956@Example.com');
+INSERT INTO people (name, email) VALUES ('name157', 'Person957@Example.com');
+INSERT INTO people (name, email) VALUES ('name158', 'Person958@Example.com');
+INSERT INTO people (name, email) VALUES ('name159', 'Person959@Example.com');
+INSERT INTO people (name, email) VALUES ('name160', 'Person960@Example.com');
+INSERT INTO people (name, email) VALUES ('name161', 'Person961@Example.com');
+INSERT INTO people (name, email) VALUES ('name162', 'Person962@Example.com');
+INSERT INTO people (name, email) VALUES ('name163', 'Person963@Example.com');
+INSERT INTO people (name, email) VALUES ('name164', 'Person964@Example.com');
+INSERT INTO people (name, email) VALUES ('name165', 'Person965@Example.com');
+INSERT INTO people (name, email) VALUES ('name166', 'Person966@Example.com');
+INSERT INTO people (name, email) VALUES ('name167', 'Person967@Example.com');
+INSERT INTO people (name, email) VALUES ('name168', 'Person968@Example.com');
+INSERT INTO people (name, email) VALUES ('name169', 'Person969@Example.com');
+INSERT INTO people (name, email) VALUES ('name170', 'Person970@Example.com');
+INSERT INTO people (name, email) VALUES ('name171', 'Person971@Example.com');
+INSERT INTO people (name, email) VALUES ('name172', 'Person972@Example.com');
+INSERT INTO people (name, email) VALUES ('name173', 'Person973@Example.com');
+INSERT INTO people (name, email) VALUES ('name174', 'Person974@Example.com');
+INSERT INTO people (name, email) VALUES ('name175', 'Person975@Example.com');
+INSERT INTO people (name, email) VALUES ('name176', 'Person976@Example.com');
+INSERT INTO people (name, email) VALUES ('name177', 'Person977@Example.com');
+INSERT INTO people (name, email) VALUES ('name178', 'Person978@Example.com');
+INSERT INTO people (name, email) VALUES ('name179', 'Person979@Example.com');
+INSERT INTO people (name, email) VALUES ('name180', 'Person980@Example.com');
+INSERT INTO people (name, email) VALUES ('name181', 'Person981@Example.com');
+INSERT INTO people (name, email) VALUES ('name182', 'Person982@Example.com');
+INSERT INTO people (name, email) VALUES ('name183', 'Person983@Example.com');
+INSERT INTO people (name, email) VALUES ('name184', 'Person984@Example.com');
+INSERT INTO people (name, email) VALUES ('name185', 'Person985@Example.com');
+INSERT INTO people (name, email) VALUES ('name186', 'Person986@Example.com');
+INSERT INTO people (name, email) VALUES ('name187', 'Person987@Example.com');
+INSERT INTO people (name, email) VALUES ('name188', 'Person988@Example.com');
+INSERT INTO people (name, email) VALUES ('name189', 'Person989@Example.com');
+INSERT INTO people (name, email) VALUES ('name190', 'Person990@Example.com');
+INSERT INTO people (name, email) VALUES ('name191', 'Person991@Example.com');
+INSERT INTO people (name, email) VALUES ('name192', 'Person992@Example.com');
+INSERT INTO people (name, email) VALUES ('name193', 'Person993@Example.com');
+INSERT INTO people (name, email) VALUES ('name194', 'Person994@Example.com');
+INSERT INTO people (name, email) VALUES ('name195', 'Person995@Example.com');
+INSERT INTO people (name, email) VALUES ('name196', 'Person996@Example.com');
+INSERT INTO people (name, email) VALUES ('name197', 'Person997@Example.com');
+INSERT INTO people (name, email) VALUES ('name198', 'Person998@Example.com');
+INSERT INTO people (name, email) VALUES ('name199', 'Person999@Example.com');
+INSERT INTO people (name, email) VALUES ('name200', 'Person1000@Example.com');
+INSERT INTO people (name, email) VALUES ('name201', 'Person1001@Example.com');
+INSERT INTO people (name, email) VALUES ('name202', 'Person1002@Example.com');
+INSERT INTO people (name, email) VALUES ('name203', 'Person1003@Example.com');
+INSERT INTO people (name, email) VALUES ('name204', 'Person1004@Example.com');
+INSERT INTO people (name, email) VALUES ('name205', 'Person1005@Example.com');
+INSERT INTO people (name, email) VALUES ('name206', 'Person1006@Example.com');
+INSERT INTO people (name, email) VALUES ('name207', 'Person1007@Example.com');
+INSERT INTO people (name, email) VALUES ('name208', 'Person1008@Example.com');
+INSERT INTO people (name, email) VALUES ('name209', 'Person1009@Example.com');
+INSERT INTO people (name, email) VALUES ('name210', 'Person1010@Example.com');
+INSERT INTO people (name, email) VALUES ('name211', 'Person1011@Example.com');
+INSERT INTO people (name, email) VALUES ('name212', 'Person1012@Example.com');
+INSERT INTO people (name, email) VALUES ('name213', 'Person1013@Example.com');
+INSERT INTO people (name, email) VALUES ('name214', 'Person1014@Example.com');
+INSERT INTO people (name, email) VALUES ('name215', 'Person1015@Example.com');
+INSERT INTO people (name, email) VALUES ('name216', 'Person1016@Example.com');
+INSERT INTO people (name, email) VALUES ('name217', 'Person1017@Example.com');
+INSERT INTO people (name, email) VALUES ('name218', 'Person1018@Example.com');
+INSERT INTO people (name, email) VALUES ('name219', 'Person1019@Example.com');
+INSERT INTO people (name, email) VALUES ('name220', 'Person1020@Example.com');
+INSERT INTO people (name, email) VALUES ('name221', 'Person1021@Example.com');
+INSERT INTO people (name, email) VALUES ('name222', 'Person1022@Example.com');
+INSERT INTO people (name, email) VALUES ('name223', 'Person1023@Example.com');
+INSERT INTO people (name, email) VALUES ('name224', 'Person1024@Example.com');
+INSERT INTO people (name, email) VALUES ('name225', 'Person1025@Example.com');
+INSERT INTO people (name, email) VALUES ('name226', 'Person1026@Example.com');
+INSERT INTO people (name, email) VALUES ('name227', 'Person1027@Example.com');
+INSERT INTO people (name, email) VALUES ('name228', 'Person1028@Example.com');
+INSERT INTO people (name, email) VALUES ('name229', 'Person1029@Example.com');
+INSERT INTO people (name, email) VALUES ('name230', 'Person1030@Example.com');
+INSERT INTO people (name, email) VALUES ('name231', 'Person1031@Example.com');
+INSERT INTO people (name, email) VALUES ('name232', 'Person1032@Example.com');
+INSERT INTO people (name, email) VALUES ('name233', 'Person1033@Example.com');
+INSERT INTO people (name, email) VALUES ('name234', 'Person1034@Example.com');
+INSERT INTO people (name, email) VALUES ('name235', 'Person1035@Example.com');
+INSERT INTO people (name, email) VALUES ('name236', 'Person1036@Example.com');
+INSERT INTO people (name, email) VALUES ('name237', 'Person1037@Example.com');
+INSERT INTO people (name, email) VALUES ('name238', 'Person1038@Example.com');
+INSERT INTO people (name, email) VALUES ('name239', 'Person1039@Example.com');
+INSERT INTO people (name, email) VALUES ('name240', 'Person1040@Example.com');
+INSERT INTO people (name, email) VALUES ('name241', 'Person1041@Example.com');
+INSERT INTO people (name, email) VALUES ('name242', 'Person1042@Example.com');
+INSERT INTO people (name, email) VALUES ('name243', 'Person1043@Example.com');
+INSERT INTO people (name, email) VALUES ('name244', 'Person1044@Example.com');
+INSERT INTO people (name, email) VALUES ('name245', 'Person1045@Example.com');
+INSERT INTO people (name, email) VALUES ('name246', 'Person1046@Example.com');
+INSERT INTO people (name, email) VALUES ('name247', 'Person1047@Example.com');
+INSERT INTO people (name, email) VALUES ('name248', 'Person1048@Example.com');
+INSERT INTO people (name, email) VALUES ('name249', 'Person1049@Example.com');
+INSERT INTO people (name, email) VALUES ('name250', 'Person1050@Example.com');
+INSERT INTO people (name, email) VALUES ('name251', 'Person1051@Example.com');
+INSERT INTO people (name, email) VALUES ('name252', 'Person1052@Example.com');
+INSERT INTO people (name, email) VALUES ('name253', 'Person1053@Example.com');
+INSERT INTO people (name, email) VALUES ('name254', 'Person1054@Example.com');
+INSERT INTO people (name, email) VALUES ('name255', 'Person1055@Example.com');
+INSERT INTO people (name, email) VALUES ('name256', 'Person1056@Example.com');
+INSERT INTO people (name, email) VALUES ('name257', 'Person1057@Example.com');
+INSERT INTO people (name, email) VALUES ('name258', 'Person1058@Example.com');
+INSERT INTO people (name, email) VALUES ('name259', 'Person1059@Example.com');
+INSERT INTO people (name, email) VALUES ('name260', 'Person1060@Example.com');
+INSERT INTO people (name, email) VALUES ('name261', 'Person1061@Example.com');
+INSERT INTO people (name, email) VALUES ('name262', 'Person1062@Example.com');
+INSERT INTO people (name, email) VALUES ('name263', 'Person1063@Example.com');
+INSERT INTO people (name, email) VALUES ('name264', 'Person1064@Example.com');
+INSERT INTO people (name, email) VALUES ('name265', 'Person1065@Example.com');
+INSERT INTO people (name, email) VALUES ('name266', 'Person1066@Example.com');
+INSERT INTO people (name, email) VALUES ('name267', 'Person1067@Example.com');
+INSERT INTO people (name, email) VALUES ('name268', 'Person1068@Example.com');
+INSERT INTO people (name, email) VALUES ('name269', 'Person1069@Example.com');
+INSERT INTO people (name, email) VALUES ('name270', 'Person1070@Example.com');
+INSERT INTO people (name, email) VALUES ('name271', 'Person1071@Example.com');
+INSERT INTO people (name, email) VALUES ('name272', 'Person1072@Example.com');
+INSERT INTO people (name, email) VALUES ('name273', 'Person1073@Example.com');
+INSERT INTO people (name, email) VALUES ('name274', 'Person1074@Example.com');
+INSERT INTO people (name, email) VALUES ('name275', 'Person1075@Example.com');
+INSERT INTO people (name, email) VALUES ('name276', 'Person1076@Example.com');
+INSERT INTO people (name, email) VALUES ('name277', 'Person1077@Example.com');
+INSERT INTO people (name, email) VALUES ('name278', 'Person1078@Example.com');
+INSERT INTO people (name, email) VALUES ('name279', 'Person1079@Example.com');
+INSERT INTO people (name, email) VALUES ('name280', 'Person1080@Example.com');
+INSERT INTO people (name, email) VALUES ('name281', 'Person1081@Example.com');
+INSERT INTO people (name, email) VALUES ('name282', 'Person1082@Example.com');
+INSERT INTO people (name, email) VALUES ('name283', 'Person1083@Example.com');
+INSERT INTO people (name, email) VALUES ('name284', 'Person1084@Example.com');
+INSERT INTO people (name, email) VALUES ('name285', 'Person1085@Example.com');
+INSERT INTO people (name, email) VALUES ('name286', 'Person1086@Example.com');
+INSERT INTO people (name, email) VALUES ('name287', 'Person1087@Example.com');
+INSERT INTO people (name, email) VALUES ('name288', 'Person1088@Example.com');
+INSERT INTO people (name, email) VALUES ('name289', 'Person1089@Example.com');
+INSERT INTO people (name, email) VALUES ('name290', 'Person1090@Example.com');
+INSERT INTO people (name, email) VALUES ('name291', 'Person1091@Example.com');
+INSERT INTO people (name, email) VALUES ('name292', 'Person1092@Example.com');
+INSERT INTO people (name, email) VALUES ('name293', 'Person1093@Example.com');
+INSERT INTO people (name, email) VALUES ('name294', 'Person1094@Example.com');
+INSERT INTO people (name, email) VALUES ('name295', 'Person1095@Example.com');
+INSERT INTO people (name, email) VALUES ('name296', 'Person1096@Example.com');
+INSERT INTO people (name, email) VALUES ('name297', 'Person1097@Example.com');
+INSERT INTO people (name, email) VALUES ('name298', 'Person1098@Example.com');
+INSERT INTO people (name, email) VALUES ('name299', 'Person1099@Example.com');
+INSERT INTO people (name, email) VALUES ('name300', 'Person1100@Example.com');
+INSERT INTO people (name, email) VALUES ('name301', 'Person1101@Example.com');
+INSERT INTO people (name, email) VALUES ('name302', 'Person1102@Example.com');
+INSERT INTO people (name, email) VALUES ('name303', 'Person1103@Example.com');
+INSERT INTO people (name, email) VALUES ('name304', 'Person1104@Example.com');
+INSERT INTO people (name, email) VALUES ('name305', 'Person1105@Example.com');
+INSERT INTO people (name, email) VALUES ('name306', 'Person1106@Example.com');
+INSERT INTO people (name, email) VALUES ('name307', 'Person1107@Example.com');
+INSERT INTO people (name, email) VALUES ('name308', 'Person1108@Example.com');
+INSERT INTO people (name, email) VALUES ('name309', 'Person1109@Example.com');
+INSERT INTO people (name, email) VALUES ('name310', 'Person1110@Example.com');
+INSERT INTO people (name, email) VALUES ('name311', 'Person1111@Example.com');
+INSERT INTO people (name, email) VALUES ('name312', 'Person1112@Example.com');
+INSERT INTO people (name, email) VALUES ('name313', 'Person1113@Example.com');
+INSERT INTO people (name, email) VALUES ('name314', 'Person1114@Example.com');
+INSERT INTO people (name, email) VALUES ('name315', 'Person1115@Example.com');
+INSERT INTO people (name, email) VALUES ('name316', 'Person1116@Example.com');
+INSERT INTO people (name, email) VALUES ('name317', 'Person1117@Example.com');
+INSERT INTO people (name, email) VALUES ('name318', 'Person1118@Example.com');
+INSERT INTO people (name, email) VALUES ('name319', 'Person1119@Example.com');
+INSERT INTO people (name, email) VALUES ('name320', 'Person1120@Example.com');
+INSERT INTO people (name, email) VALUES ('name321', 'Person1121@Example.com');
+INSERT INTO people (name, email) VALUES ('name322', 'Person1122@Example.com');
+INSERT INTO people (name, email) VALUES ('name323', 'Person1123@Example.com');
+INSERT INTO people (name, email) VALUES ('name324', 'Person1124@Example.com');
+INSERT INTO people (name, email) VALUES ('name325', 'Person1125@Example.com');
+INSERT INTO people (name, email) VALUES ('name326', 'Person1126@Example.com');
+INSERT INTO people (name, email) VALUES ('name327', 'Person1127@Example.com');
+INSERT INTO people (name, email) VALUES ('name328', 'Person1128@Example.com');
+INSERT INTO people (name, email) VALUES ('name329', 'Person1129@Example.com');
+INSERT INTO people (name, email) VALUES ('name330', 'Person1130@Example.com');
+INSERT INTO people (name, email) VALUES ('name331', 'Person1131@Example.com');
+INSERT INTO people (name, email) VALUES ('name332', 'Person1132@Example.com');
+INSERT INTO people (name, email) VALUES ('name333', 'Person1133@Example.com');
+INSERT INTO people (name, email) VALUES ('name334', 'Person1134@Example.com');
+INSERT INTO people (name, email) VALUES ('name335', 'Person1135@Example.com');
+INSERT INTO people (name, email) VALUES ('name336', 'Person1136@Example.com');
+INSERT INTO people (name, email) VALUES ('name337', 'Person1137@Example.com');
+INSERT INTO people (name, email) VALUES ('name338', 'Person1138@Example.com');
+INSERT INTO people (name, email) VALUES ('name339', 'Person1139@Example.com');
+INSERT INTO people (name, email) VALUES ('name340', 'Person1140@Example.com');
+INSERT INTO people (name, email) VALUES ('name341', 'Person1141@Example.com');
+INSERT INTO people (name, email) VALUES ('name342', 'Person1142@Example.com');
+INSERT INTO people (name, email) VALUES ('name343', 'Person1143@Example.com');
+INSERT INTO people (name, email) VALUES ('name344', 'Person1144@Example.com');
+INSERT INTO people (name, email) VALUES ('name345', 'Person1145@Example.com');
+INSERT INTO people (name, email) VALUES ('name346', 'Person1146@Example.com');
+INSERT INTO people (name, email) VALUES ('name347', 'Person1147@Example.com');
+INSERT INTO people (name, email) VALUES ('name348', 'Person1148@Example.com');
+INSERT INTO people (name, email) VALUES ('name349', 'Person1149@Example.com');
+INSERT INTO people (name, email) VALUES ('name350', 'Person1150@Example.com');
+INSERT INTO people (name, email) VALUES ('name351', 'Person1151@Example.com');
+INSERT INTO people (name, email) VALUES ('name352', 'Person1152@Example.com');
+INSERT INTO people (name, email) VALUES ('name353', 'Person1153@Example.com');
+INSERT INTO people (name, email) VALUES ('name354', 'Person1154@Example.com');
+INSERT INTO people (name, email) VALUES ('name355', 'Person1155@Example.com');
+INSERT INTO people (name, email) VALUES ('name356', 'Person1156@Example.com');
+INSERT INTO people (name, email) VALUES ('name357', 'Person1157@Example.com');
+INSERT INTO people (name, email) VALUES ('name358', 'Person1158@Example.com');
+INSERT INTO people (name, email) VALUES ('name359', 'Person1159@Example.com');
+INSERT INTO people (name, email) VALUES ('name360', 'Person1160@Example.com');
+INSERT INTO people (name, email) VALUES ('name361', 'Person1161@Example.com');
+INSERT INTO people (name, email) VALUES ('name362', 'Person1162@Example.com');
+INSERT INTO people (name, email) VALUES ('name363', 'Person1163@Example.com');
+INSERT INTO people (name, email) VALUES ('name364', 'Person1164@Example.com');
+INSERT INTO people (name, email) VALUES ('name365', 'Person1165@Example.com');
+INSERT INTO people (name, email) VALUES ('name366', 'Person1166@Example.com');
+INSERT INTO people (name, email) VALUES ('name367', 'Person1167@Example.com');
+INSERT INTO people (name, email) VALUES ('name368', 'Person1168@Example.com');
+INSERT INTO people (name, email) VALUES ('name369', 'Person1169@Example.com');
+INSERT INTO people (name, email) VALUES ('name370', 'Person1170@Example.com');
+INSERT INTO people (name, email) VALUES ('name371', 'Person1171@Example.com');
+INSERT INTO people (name, email) VALUES ('name372', 'Person1172@Example.com');
+INSERT INTO people (name, email) VALUES ('name373', 'Person1173@Example.com');
+INSERT INTO people (name, email) VALUES ('name374', 'Person1174@Example.com');
+INSERT INTO people (name, email) VALUES ('name375', 'Person1175@Example.com');
+INSERT INTO people (name, email) VALUES ('name376', 'Person1176@Example.com');
+INSERT INTO people (name, email) VALUES ('name377', 'Person1177@Example.com');
+INSERT INTO people (name, email) VALUES ('name378', 'Person1178@Example.com');
+INSERT INTO people (name, email) VALUES ('name379', 'Person1179@Example.com');
+INSERT INTO people (name, email) VALUES ('name380', 'Person1180@Example.com');
+INSERT INTO people (name, email) VALUES ('name381', 'Person1181@Example.com');
+INSERT INTO people (name, email) VALUES ('name382', 'Person1182@Example.com');
+INSERT INTO people (name, email) VALUES ('name383', 'Person1183@Example.com');
+INSERT INTO people (name, email) VALUES ('name384', 'Person1184@Example.com');
+INSERT INTO people (name, email) VALUES ('name385', 'Person1185@Example.com');
+INSERT INTO people (name, email) VALUES ('name386', 'Person1186@Example.com');
+INSERT INTO people (name, email) VALUES ('name387', 'Person1187@Example.com');
+INSERT INTO people (name, email) VALUES ('name388', 'Person1188@Example.com');
+INSERT INTO people (name, email) VALUES ('name389', 'Person1189@Example.com');
+INSERT INTO people (name, email) VALUES ('name390', 'Person1190@Example.com');
+INSERT INTO people (name, email) VALUES ('name391', 'Person1191@Example.com');
+INSERT INTO people (name, email) VALUES ('name392', 'Person1192@Example.com');
+INSERT INTO people (name, email) VALUES ('name393', 'Person1193@Example.com');
+INSERT INTO people (name, email) VALUES ('name394', 'Person1194@Example.com');
+INSERT INTO people (name, email) VALUES ('name395', 'Person1195@Example.com');
+INSERT INTO people (name, email) VALUES ('name396', 'Person1196@Example.com');
+INSERT INTO people (name, email) VALUES ('name397', 'Person1197@Example.com');
+INSERT INTO people (name, email) VALUES ('name398', 'Person1198@Example.com');
+INSERT INTO people (name, email) VALUES ('name399', 'Person1199@Example.com');
+INSERT INTO people (name, email) VALUES ('name000', 'Person1200@Example.com');
+INSERT INTO people (name, email) VALUES ('name001', 'Person1201@Example.com');
+INSERT INTO people (name, email) VALUES ('name002', 'Person1202@Example.com');
+INSERT INTO people (name, email) VALUES ('name003', 'Person1203@Example.com');
+INSERT INTO people (name, email) VALUES ('name004', 'Person1204@Example.com');
+INSERT INTO people (name, email) VALUES ('name005', 'Person1205@Example.com');
+INSERT INTO people (name, email) VALUES ('name006', 'Person1206@Example.com');
+INSERT INTO people (name, email) VALUES ('name007', 'Person1207@Example.com');
+INSERT INTO people (name, email) VALUES ('name008', 'Person1208@Example.com');
+INSERT INTO people (name, email) VALUES ('name009', 'Person1209@Example.com');
+INSERT INTO people (name, email) VALUES ('name010', 'Person1210@Example.com');
+INSERT INTO people (name, email) VALUES ('name011', 'Person1211@Example.com');
+INSERT INTO people (name, email) VALUES ('name012', 'Person1212@Example.com');
+INSERT INTO people (name, email) VALUES ('name013', 'Person1213@Example.com');
+INSERT INTO people (name, email) VALUES ('name014', 'Person1214@Example.com');
+INSERT INTO people (name, email) VALUES ('name015', 'Person1215@Example.com');
+INSERT INTO people (name, email) VALUES ('name016', 'Person1216@Example.com');
+INSERT INTO people (name, email) VALUES ('name017', 'Person1217@Example.com');
+INSERT INTO people (name, email) VALUES ('name018', 'Person1218@Example.com');
+INSERT INTO people (name, email) VALUES ('name019', 'Person1219@Example.com');
+INSERT INTO people (name, email) VALUES ('name020', 'Person1220@Example.com');
+INSERT INTO people (name, email) VALUES ('name021', 'Person1221@Example.com');
+INSERT INTO people (name, email) VALUES ('name022', 'Person1222@Example.com');
+INSERT INTO people (name, email) VALUES ('name023', 'Person1223@Example.com');
+INSERT INTO people (name, email) VALUES ('name024', 'Person1224@Example.com');
+INSERT INTO people (name, email) VALUES ('name025', 'Person1225@Example.com');
+INSERT INTO people (name, email) VALUES ('name026', 'Person1226@Example.com');
+INSERT INTO people (name, email) VALUES ('name027', 'Person1227@Example.com');
+INSERT INTO people (name, email) VALUES ('name028', 'Person1228@Example.com');
+INSERT INTO people (name, email) VALUES ('name029', 'Person1229@Example.com');
+INSERT INTO people (name, email) VALUES ('name030', 'Person1230@Example.com');
+INSERT INTO people (name, email) VALUES ('name031', 'Person1231@Example.com');
+INSERT INTO people (name, email) VALUES ('name032', 'Person1232@Example.com');
+INSERT INTO people (name, email) VALUES ('name033', 'Person1233@Example.com');
+INSERT INTO people (name, email) VALUES ('name034', 'Person1234@Example.com');
+INSERT INTO people (name, email) VALUES ('name035', 'Person1235@Example.com');
+INSERT INTO people (name, email) VALUES ('name036', 'Person1236@Example.com');
+INSERT INTO people (name, email) VALUES ('name037', 'Person1237@Example.com');
+INSERT INTO people (name, email) VALUES ('name038', 'Person1238@Example.com');
+INSERT INTO people (name, email) VALUES ('name039', 'Person1239@Example.com');
+INSERT INTO people (name, email) VALUES ('name040', 'Person1240@Example.com');
+INSERT INTO people (name, email) VALUES ('name041', 'Person1241@Example.com');
+INSERT INTO people (name, email) VALUES ('name042', 'Person1242@Example.com');
+INSERT INTO people (name, email) VALUES ('name043', 'Person1243@Example.com');
+INSERT INTO people (name, email) VALUES ('name044', 'Person1244@Example.com');
+INSERT INTO people (name, email) VALUES ('name045', 'Person1245@Example.com');
+INSERT INTO people (name, email) VALUES ('name046', 'Person1246@Example.com');
+INSERT INTO people (name, email) VALUES ('name047', 'Person1247@Example.com');
+INSERT INTO people (name, email) VALUES ('name048', 'Person1248@Example.com');
+INSERT INTO people (name, email) VALUES ('name049', 'Person1249@Example.com');
+INSERT INTO people (name, email) VALUES ('name050', 'Person1250@Example.com');
+INSERT INTO people (name, email) VALUES ('name051', 'Person1251@Example.com');
+INSERT INTO people (name, email) VALUES ('name052', 'Person1252@Example.com');
+INSERT INTO people (name, email) VALUES ('name053', 'Person1253@Example.com');
+INSERT INTO people (name, email) VALUES ('name054', 'Person1254@Example.com');
+INSERT INTO people (name, email) VALUES ('name055', 'Person1255@Example.com');
+INSERT INTO people (name, email) VALUES ('name056', 'Person1256@Example.com');
+INSERT INTO people (name, email) VALUES ('name057', 'Person1257@Example.com');
+INSERT INTO people (name, email) VALUES ('name058', 'Person1258@Example.com');
+INSERT INTO people (name, email) VALUES ('name059', 'Person1259@Example.com');
+INSERT INTO people (name, email) VALUES ('name060', 'Person1260@Example.com');
+INSERT INTO people (name, email) VALUES ('name061', 'Person1261@Example.com');
+INSERT INTO people (name, email) VALUES ('name062', 'Person1262@Example.com');
+INSERT INTO people (name, email) VALUES ('name063', 'Person1263@Example.com');
+INSERT INTO people (name, email) VALUES ('name064', 'Person1264@Example.com');
+INSERT INTO people (name, email) VALUES ('name065', 'Person1265@Example.com');
+INSERT INTO people (name, email) VALUES ('name066', 'Person1266@Example.com');
+INSERT INTO people (name, email) VALUES ('name067', 'Person1267@Example.com');
+INSERT INTO people (name, email) VALUES ('name068', 'Person1268@Example.com');
+INSERT INTO people (name, email) VALUES ('name069', 'Person1269@Example.com');
+INSERT INTO people (name, email) VALUES ('name070', 'Person1270@Example.com');
+INSERT INTO people (name, email) VALUES ('name071', 'Person1271@Example.com');
+INSERT INTO people (name, email) VALUES ('name072', 'Person1272@Example.com');
+INSERT INTO people (name, email) VALUES ('name073', 'Person1273@Example.com');
+INSERT INTO people (name, email) VALUES ('name074', 'Person1274@Example.com');
+INSERT INTO people (name, email) VALUES ('name075', 'Person1275@Example.com');
+INSERT INTO people (name, email) VALUES ('name076', 'Person1276@Example.com');
+INSERT INTO people (name, email) VALUES ('name077', 'Person1277@Example.com');
+INSERT INTO people (name, email) VALUES ('name078', 'Person1278@Example.com');
+INSERT INTO people (name, email) VALUES ('name079', 'Person1279@Example.com');
+INSERT INTO people (name, email) VALUES ('name080', 'Person1280@Example.com');
+INSERT INTO people (name, email) VALUES ('name081', 'Person1281@Example.com');
+INSERT INTO people (name, email) VALUES ('name082', 'Person1282@Example.com');
+INSERT INTO people (name, email) VALUES ('name083', 'Person1283@Example.com');
+INSERT INTO people (name, email) VALUES ('name084', 'Person1284@Example.com');
+INSERT INTO people (name, email) VALUES ('name085', 'Person1285@Example.com');
+INSERT INTO people (name, email) VALUES ('name086', 'Person1286@Example.com');
+INSERT INTO people (name, email) VALUES ('name087', 'Person1287@Example.com');
+INSERT INTO people (name, email) VALUES ('name088', 'Person1288@Example.com');
+INSERT INTO people (name, email) VALUES ('name089', 'Person1289@Example.com');
+INSERT INTO people (name, email) VALUES ('name090', 'Person1290@Example.com');
+INSERT INTO people (name, email) VALUES ('name091', 'Person1291@Example.com');
+INSERT INTO people (name, email) VALUES ('name092', 'Person1292@Example.com');
+INSERT INTO people (name, email) VALUES ('name093', 'Person1293@Example.com');
+INSERT INTO people (name, email) VALUES ('name094', 'Person1294@Example.com');
+INSERT INTO people (name, email) VALUES ('name095', 'Person1295@Example.com');
+INSERT INTO people (name, email) VALUES ('name096', 'Person1296@Example.com');
+INSERT INTO people (name, email) VALUES ('name097', 'Person1297@Example.com');
+INSERT INTO people (name, email) VALUES ('name098', 'Person1298@Example.com');
+INSERT INTO people (name, email) VALUES ('name099', 'Person1299@Example.com');
+INSERT INTO people (name, email) VALUES ('name100', 'Person1300@Example.com');
+INSERT INTO people (name, email) VALUES ('name101', 'Person1301@Example.com');
+INSERT INTO people (name, email) VALUES ('name102', 'Person1302@Example.com');
+INSERT INTO people (name, email) VALUES ('name103', 'Person1303@Example.com');
+INSERT INTO people (name, email) VALUES ('name104', 'Person1304@Example.com');
+INSERT INTO people (name, email) VALUES ('name105', 'Person1305@Example.com');
+INSERT INTO people (name, email) VALUES ('name106', 'Person1306@Example.com');
+INSERT INTO people (name, email) VALUES ('name107', 'Person1307@Example.com');
+INSERT INTO people (name, email) VALUES ('name108', 'Person1308@Example.com');
+INSERT INTO people (name, email) VALUES ('name109', 'Person1309@Example.com');
+INSERT INTO people (name, email) VALUES ('name110', 'Person1310@Example.com');
+INSERT INTO people (name, email) VALUES ('name111', 'Person1311@Example.com');
+INSERT INTO people (name, email) VALUES ('name112', 'Person1312@Example.com');
+INSERT INTO people (name, email) VALUES ('name113', 'Person1313@Example.com');
+INSERT INTO people (name, email) VALUES ('name114', 'Person1314@Example.com');
+INSERT INTO people (name, email) VALUES ('name115', 'Person1315@Example.com');
+INSERT INTO people (name, email) VALUES ('name116', 'Person1316@Example.com');
+INSERT INTO people (name, email) VALUES ('name117', 'Person1317@Example.com');
+INSERT INTO people (name, email) VALUES ('name118', 'Person1318@Example.com');
+INSERT INTO people (name, email) VALUES ('name119', 'Person1319@Example.com');
+INSERT INTO people (name, email) VALUES ('name120', 'Person1320@Example.com');
+INSERT INTO people (name, email) VALUES ('name121', 'Person1321@Example.com');
+INSERT INTO people (name, email) VALUES ('name122', 'Person1322@Example.com');
+INSERT INTO people (name, email) VALUES ('name123', 'Person1323@Example.com');
+INSERT INTO people (name, email) VALUES ('name124', 'Person1324@Example.com');
+INSERT INTO people (name, email) VALUES ('name125', 'Person1325@Example.com');
+INSERT INTO people (name, email) VALUES ('name126', 'Person1326@Example.com');
+INSERT INTO people (name, email) VALUES ('name127', 'Person1327@Example.com');
+INSERT INTO people (name, email) VALUES ('name128', 'Person1328@Example.com');
+INSERT INTO people (name, email) VALUES ('name129', 'Person1329@Example.com');
+INSERT INTO people (name, email) VALUES ('name130', 'Person1330@Example.com');
+INSERT INTO people (name, email) VALUES ('name131', 'Person1331@Example.com');
+INSERT INTO people (name, email) VALUES ('name132', 'Person1332@Example.com');
+INSERT INTO people (name, email) VALUES ('name133', 'Person1333@Example.com');
+INSERT INTO people (name, email) VALUES ('name134', 'Person1334@Example.com');
+INSERT INTO people (name, email) VALUES ('name135', 'Person1335@Example.com');
+INSERT INTO people (name, email) VALUES ('name136', 'Person1336@Example.com');
+INSERT INTO people (name, email) VALUES ('name137', 'Person1337@Example.com');
+INSERT INTO people (name, email) VALUES ('name138', 'Person1338@Example.com');
+INSERT INTO people (name, email) VALUES ('name139', 'Person1339@Example.com');
+INSERT INTO people (name, email) VALUES ('name140', 'Person1340@Example.com');
+INSERT INTO people (name, email) VALUES ('name141', 'Person1341@Example.com');
+INSERT INTO people (name, email) VALUES ('name142', 'Person1342@Example.com');
+INSERT INTO people (name, email) VALUES ('name143', 'Person1343@Example.com');
+INSERT INTO people (name, email) VALUES ('name144', 'Person1344@Example.com');
+INSERT INTO people (name, email) VALUES ('name145', 'Person1345@Example.com');
+INSERT INTO people (name, email) VALUES ('name146', 'Person1346@Example.com');
+INSERT INTO people (name, email) VALUES ('name147', 'Person1347@Example.com');
+INSERT INTO people (name, email) VALUES ('name148', 'Person1348@Example.com');
+INSERT INTO people (name, email) VALUES ('name149', 'Person1349@Example.com');
+INSERT INTO people (name, email) VALUES ('name150', 'Person1350@Example.com');
+INSERT INTO people (name, email) VALUES ('name151', 'Person1351@Example.com');
+INSERT INTO people (name, email) VALUES ('name152', 'Person1352@Example.com');
+INSERT INTO people (name, email) VALUES ('name153', 'Person1353@Example.com');
+INSERT INTO people (name, email) VALUES ('name154', 'Person1354@Example.com');
+INSERT INTO people (name, email) VALUES ('name155', 'Person1355@Example.com');
+INSERT INTO people (name, email) VALUES ('name156', 'Person1356@Example.com');
+INSERT INTO people (name, email) VALUES ('name157', 'Person1357@Example.com');
+INSERT INTO people (name, email) VALUES ('name158', 'Person1358@Example.com');
+INSERT INTO people (name, email) VALUES ('name159', 'Person1359@Example.com');
+INSERT INTO people (name, email) VALUES ('name160', 'Person1360@Example.com');
+INSERT INTO people (name, email) VALUES ('name161', 'Person1361@Example.com');
+INSERT INTO people (name, email) VALUES ('name162', 'Person1362@Example.com');
+INSERT INTO people (name, email) VALUES ('name163', 'Person1363@Example.com');
+INSERT INTO people (name, email) VALUES ('name164', 'Person1364@Example.com');
+INSERT INTO people (name, email) VALUES ('name165', 'Person1365@Example.com');
+INSERT INTO people (name, email) VALUES ('name166', 'Person1366@Example.com');
+INSERT INTO people (name, email) VALUES ('name167', 'Person1367@Example.com');
+INSERT INTO people (name, email) VALUES ('name168', 'Person1368@Example.com');
+INSERT INTO people (name, email) VALUES ('name169', 'Person1369@Example.com');
+INSERT INTO people (name, email) VALUES ('name170', 'Person1370@Example.com');
+INSERT INTO people (name, email) VALUES ('name171', 'Person1371@Example.com');
+INSERT INTO people (name, email) VALUES ('name172', 'Person1372@Example.com');
+INSERT INTO people (name, email) VALUES ('name173', 'Person1373@Example.com');
+INSERT INTO people (name, email) VALUES ('name174', 'Person1374@Example.com');
+INSERT INTO people (name, email) VALUES ('name175', 'Person1375@Example.com');
+INSERT INTO people (name, email) VALUES ('name176', 'Person1376@Example.com');
+INSERT INTO people (name, email) VALUES ('name177', 'Person1377@Example.com');
+INSERT INTO people (name, email) VALUES ('name178', 'Person1378@Example.com');
+INSERT INTO people (name, email) VALUES ('name179', 'Person1379@Example.com');
+INSERT INTO people (name, email) VALUES ('name180', 'Person1380@Example.com');
+INSERT INTO people (name, email) VALUES ('name181', 'Person1381@Example.com');
+INSERT INTO people (name, email) VALUES ('name182', 'Person1382@Example.com');
+INSERT INTO people (name, email) VALUES ('name183', 'Person1383@Example.com');
+INSERT INTO people (name, email) VALUES ('name184', 'Person1384@Example.com');
+INSERT INTO people (name, email) VALUES ('name185', 'Person1385@Example.com');
+INSERT INTO people (name, email) VALUES ('name186', 'Person1386@Example.com');
+INSERT INTO people (name, email) VALUES ('name187', 'Person1387@Example.com');
+INSERT INTO people (name, email) VALUES ('name188', 'Person1388@Example.com');
+INSERT INTO people (name, email) VALUES ('name189', 'Person1389@Example.com');
+INSERT INTO people (name, email) VALUES ('name190', 'Person1390@Example.com');
+INSERT INTO people (name, email) VALUES ('name191', 'Person1391@Example.com');
+INSERT INTO people (name, email) VALUES ('name192', 'Person1392@Example.com');
+INSERT INTO people (name, email) VALUES ('name193', 'Person1393@Example.com');
+INSERT INTO people (name, email) VALUES ('name194', 'Person1394@Example.com');
+INSERT INTO people (name, email) VALUES ('name195', 'Person1395@Example.com');
+INSERT INTO people (name, email) VALUES ('name196', 'Person1396@Example.com');
+INSERT INTO people (name, email) VALUES ('name197', 'Person1397@Example.com');
+INSERT INTO people (name, email) VALUES ('name198', 'Person1398@Example.com');
+INSERT INTO people (name, email) VALUES ('name199', 'Person1399@Example.com');
+INSERT INTO people (name, email) VALUES ('name200', 'Person1400@Example.com');
+INSERT INTO people (name, email) VALUES ('name201', 'Person1401@Example.com');
+INSERT INTO people (name, email) VALUES ('name202', 'Person1402@Example.com');
+INSERT INTO people (name, email) VALUES ('name203', 'Person1403@Example.com');
+INSERT INTO people (name, email) VALUES ('name204', 'Person1404@Example.com');
+INSERT INTO people (name, email) VALUES ('name205', 'Person1405@Example.com');
+INSERT INTO people (name, email) VALUES ('name206', 'Person1406@Example.com');
+INSERT INTO people (name, email) VALUES ('name207', 'Person1407@Example.com');
+INSERT INTO people (name, email) VALUES ('name208', 'Person1408@Example.com');
+INSERT INTO people (name, email) VALUES ('name209', 'Person1409@Example.com');
+INSERT INTO people (name, email) VALUES ('name210', 'Person1410@Example.com');
+INSERT INTO people (name, email) VALUES ('name211', 'Person1411@Example.com');
+INSERT INTO people (name, email) VALUES ('name212', 'Person1412@Example.com');
+INSERT INTO people (name, email) VALUES ('name213', 'Person1413@Example.com');
+INSERT INTO people (name, email) VALUES ('name214', 'Person1414@Example.com');
+INSERT INTO people (name, email) VALUES ('name215', 'Person1415@Example.com');
+INSERT INTO people (name, email) VALUES ('name216', 'Person1416@Example.com');
+INSERT INTO people (name, email) VALUES ('name217', 'Person1417@Example.com');
+INSERT INTO people (name, email) VALUES ('name218', 'Person1418@Example.com');
+INSERT INTO people (name, email) VALUES ('name219', 'Person1419@Example.com');
+INSERT INTO people (name, email) VALUES ('name220', 'Person1420@Example.com');
+INSERT INTO people (name, email) VALUES ('name221', 'Person1421@Example.com');
+INSERT INTO people (name, email) VALUES ('name222', 'Person1422@Example.com');
+INSERT INTO people (name, email) VALUES ('name223', 'Person1423@Example.com');
+INSERT INTO people (name, email) VALUES ('name224', 'Person1424@Example.com');
+INSERT INTO people (name, email) VALUES ('name225', 'Person1425@Example.com');
+INSERT INTO people (name, email) VALUES ('name226', 'Person1426@Example.com');
+INSERT INTO people (name, email) VALUES ('name227', 'Person1427@Example.com');
+INSERT INTO people (name, email) VALUES ('name228', 'Person1428@Example.com');
+INSERT INTO people (name, email) VALUES ('name229', 'Person1429@Example.com');
+INSERT INTO people (name, email) VALUES ('name230', 'Person1430@Example.com');
+INSERT INTO people (name, email) VALUES ('name231', 'Person1431@Example.com');
+INSERT INTO people (name, email) VALUES ('name232', 'Person1432@Example.com');
+INSERT INTO people (name, email) VALUES ('name233', 'Person1433@Example.com');
+INSERT INTO people (name, email) VALUES ('name234', 'Person1434@Example.com');
+INSERT INTO people (name, email) VALUES ('name235', 'Person1435@Example.com');
+INSERT INTO people (name, email) VALUES ('name236', 'Person1436@Example.com');
+INSERT INTO people (name, email) VALUES ('name237', 'Person1437@Example.com');
+INSERT INTO people (name, email) VALUES ('name238', 'Person1438@Example.com');
+INSERT INTO people (name, email) VALUES ('name239', 'Person1439@Example.com');
+INSERT INTO people (name, email) VALUES ('name240', 'Person1440@Example.com');
+INSERT INTO people (name, email) VALUES ('name241', 'Person1441@Example.com');
+INSERT INTO people (name, email) VALUES ('name242', 'Person1442@Example.com');
+INSERT INTO people (name, email) VALUES ('name243', 'Person1443@Example.com');
+INSERT INTO people (name, email) VALUES ('name244', 'Person1444@Example.com');
+INSERT INTO people (name, email) VALUES ('name245', 'Person1445@Example.com');
+INSERT INTO people (name, email) VALUES ('name246', 'Person1446@Example.com');
+INSERT INTO people (name, email) VALUES ('name247', 'Person1447@Example.com');
+INSERT INTO people (name, email) VALUES ('name248', 'Person1448@Example.com');
+INSERT INTO people (name, email) VALUES ('name249', 'Person1449@Example.com');
+INSERT INTO people (name, email) VALUES ('name250', 'Person1450@Example.com');
+INSERT INTO people (name, email) VALUES ('name251', 'Person1451@Example.com');
+INSERT INTO people (name, email) VALUES ('name252', 'Person1452@Example.com');
+INSERT INTO people (name, email) VALUES ('name253', 'Person1453@Example.com');
+INSERT INTO people (name, email) VALUES ('name254', 'Person1454@Example.com');
+INSERT INTO people (name, email) VALUES ('name255', 'Person1455@Example.com');
+INSERT INTO people (name, email) VALUES ('name256', 'Person1456@Example.com');
+INSERT INTO people (name, email) VALUES ('name257', 'Person1457@Example.com');
+INSERT INTO people (name, email) VALUES ('name258', 'Person1458@Example.com');
+INSERT INTO people (name, email) VALUES ('name259', 'Person1459@Example.com');
+INSERT INTO people (name, email) VALUES ('name260', 'Person1460@Example.com');
+INSERT INTO people (name, email) VALUES ('name261', 'Person1461@Example.com');
+INSERT INTO people (name, email) VALUES ('name262', 'Person1462@Example.com');
+INSERT INTO people (name, email) VALUES ('name263', 'Person1463@Example.com');
+INSERT INTO people (name, email) VALUES ('name264', 'Person1464@Example.com');
+INSERT INTO people (name, email) VALUES ('name265', 'Person1465@Example.com');
+INSERT INTO people (name, email) VALUES ('name266', 'Person1466@Example.com');
+INSERT INTO people (name, email) VALUES ('name267', 'Person1467@Example.com');
+INSERT INTO people (name, email) VALUES ('name268', 'Person1468@Example.com');
+INSERT INTO people (name, email) VALUES ('name269', 'Person1469@Example.com');
+INSERT INTO people (name, email) VALUES ('name270', 'Person1470@Example.com');
+INSERT INTO people (name, email) VALUES ('name271', 'Person1471@Example.com');
+INSERT INTO people (name, email) VALUES ('name272', 'Person1472@Example.com');
+INSERT INTO people (name, email) VALUES ('name273', 'Person1473@Example.com');
+INSERT INTO people (name, email) VALUES ('name274', 'Person1474@Example.com');
+INSERT INTO people (name, email) VALUES ('name275', 'Person1475@Example.com');
+INSERT INTO people (name, email) VALUES ('name276', 'Person1476@Example.com');
+INSERT INTO people (name, email) VALUES ('name277', 'Person1477@Example.com');
+INSERT INTO people (name, email) VALUES ('name278', 'Person1478@Example.com');
+INSERT INTO people (name, email) VALUES ('name279', 'Person1479@Example.com');
+INSERT INTO people (name, email) VALUES ('name280', 'Person1480@Example.com');
+INSERT INTO people (name, email) VALUES ('name281', 'Person1481@Example.com');
+INSERT INTO people (name, email) VALUES ('name282', 'Person1482@Example.com');
+INSERT INTO people (name, email) VALUES ('name283', 'Person1483@Example.com');
+INSERT INTO people (name, email) VALUES ('name284', 'Person1484@Example.com');
+INSERT INTO people (name, email) VALUES ('name285', 'Person1485@Example.com');
+INSERT INTO people (name, email) VALUES ('name286', 'Person1486@Example.com');
+INSERT INTO people (name, email) VALUES ('name287', 'Person1487@Example.com');
+INSERT INTO people (name, email) VALUES ('name288', 'Person1488@Example.com');
+INSERT INTO people (name, email) VALUES ('name289', 'Person1489@Example.com');
+INSERT INTO people (name, email) VALUES ('name290', 'Person1490@Example.com');
+INSERT INTO people (name, email) VALUES ('name291', 'Person1491@Example.com');
+INSERT INTO people (name, email) VALUES ('name292', 'Person1492@Example.com');
+INSERT INTO people (name, email) VALUES ('name293', 'Person1493@Example.com');
+INSERT INTO people (name, email) VALUES ('name294', 'Person1494@Example.com');
+INSERT INTO people (name, email) VALUES ('name295', 'Person1495@Example.com');
+INSERT INTO people (name, email) VALUES ('name296', 'Person1496@Example.com');
+INSERT INTO people (name, email) VALUES ('name297', 'Person1497@Example.com');
+INSERT INTO people (name, email) VALUES ('name298', 'Person1498@Example.com');
+INSERT INTO people (name, email) VALUES ('name299', 'Person1499@Example.com');
+INSERT INTO people (name, email) VALUES ('name300', 'Person1500@Example.com');
+COMMIT;
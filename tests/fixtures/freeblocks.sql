@@ -0,0 +1,9 @@
+-- Deleting rows leaves freeblocks in the pages of the table and of the index, and
+-- shrinking rows leaves fragmented bytes
+PRAGMA page_size = 1024;
+CREATE TABLE notes (id INTEGER PRIMARY KEY, body text);
+CREATE INDEX notes_body ON notes (body);
+WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 60)
+INSERT INTO notes SELECT i, printf('note %02d %s', i, substr('abcdefghijklmnopqrstuvwxyz', 1, i % 20 + 5)) FROM n;
+DELETE FROM notes WHERE id % 7 = 0;
+UPDATE notes SET body = substr(body, 1, length(body) - 2) WHERE id % 5 = 0;
@@ -0,0 +1,7 @@
+-- with 512 bytes pages, a trunk page holds at most 126 leaf pages, so the deleted rows
+-- leave a freelist of several trunk pages
+PRAGMA page_size = 512;
+CREATE TABLE logs (id INTEGER PRIMARY KEY, message text);
+WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 2000)
+INSERT INTO logs (message) SELECT printf('%.100c', 'm') || i FROM n;
+DELETE FROM logs WHERE id > 10;
@@ -0,0 +1,5 @@
+CREATE TABLE notes (id integer primary key, body text);
+INSERT INTO notes (body) VALUES ('hello'), ('world');
+-- creates the shadow tables docs_data, docs_idx, docs_content, docs_docsize and docs_config
+CREATE VIRTUAL TABLE docs USING fts5(title, body);
+INSERT INTO docs VALUES ('first', 'hello world'), ('second', 'goodbye, world');
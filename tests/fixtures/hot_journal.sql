@@ -0,0 +1,18 @@
+-- tests/fixtures/hot_journal.db was written with a page size of 512 and synchronous = OFF,
+-- so the journal does not record its number of pages. hot_journal.db-journal was copied
+-- during the transaction below, and hot_journal.db after its commit: together they are a
+-- crash in the middle of the commit, and the transaction must be rolled back, leaving
+-- the 20 first accounts with a balance of 100.
+-- corrupted_journal.db is the same database, with a journal whose magic is wrong.
+-- Opening them with the sqlite3 shell rolls the journal back and deletes it, use a copy.
+PRAGMA page_size = 512;
+PRAGMA synchronous = OFF;
+CREATE TABLE accounts (id INTEGER PRIMARY KEY, owner text, balance integer);
+WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 20)
+INSERT INTO accounts SELECT i, printf('owner %02d', i), 100 FROM n;
+BEGIN;
+UPDATE accounts SET balance = balance - 30 WHERE id = 3;
+UPDATE accounts SET balance = balance + 30 WHERE id = 17;
+WITH RECURSIVE n(i) AS (SELECT 21 UNION ALL SELECT i + 1 FROM n WHERE i < 60)
+INSERT INTO accounts SELECT i, printf('owner %02d', i), 0 FROM n;
+COMMIT;
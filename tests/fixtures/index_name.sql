@@ -0,0 +1,3 @@
+CREATE TABLE fruits (id integer primary key, name text);
+CREATE INDEX idx_fruits_name ON fruits (name);
+INSERT INTO fruits (name) VALUES ('apple'), ('pear');
@@ -0,0 +1,3 @@
+-- The body of the second note is a text with the invalid UTF-8 byte 0xFF
+CREATE TABLE notes (id INTEGER PRIMARY KEY, body text);
+INSERT INTO notes VALUES (1, 'hello'), (2, CAST(X'6869FF21' AS TEXT)), (3, 'bye');
@@ -0,0 +1,10 @@
+-- small pages and wide rows: the table, its index and the WITHOUT ROWID table all have
+-- at least two levels of interior pages
+PRAGMA page_size = 512;
+CREATE TABLE items (id INTEGER PRIMARY KEY, name text, filler text);
+CREATE INDEX idx_items_name ON items (name);
+CREATE TABLE tags (name text PRIMARY KEY, filler text) WITHOUT ROWID;
+WITH RECURSIVE n(i) AS (SELECT 0 UNION ALL SELECT i + 1 FROM n WHERE i < 999)
+INSERT INTO items SELECT (i * 37) % 1000 + 1, printf('item %04d', (i * 53) % 1000), printf('%.100c', 'x') FROM n;
+WITH RECURSIVE n(i) AS (SELECT 0 UNION ALL SELECT i + 1 FROM n WHERE i < 999)
+INSERT INTO tags SELECT printf('tag %04d', (i * 53) % 1000), printf('%.100c', 'y') FROM n;
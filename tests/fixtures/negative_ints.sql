@@ -0,0 +1,11 @@
+-- one value per integer width class, each with its negative counterpart
+CREATE TABLE ints (id INTEGER PRIMARY KEY, width text, value integer);
+INSERT INTO ints (width, value) VALUES
+  ('int8', -1), ('int8', -128), ('int8', 127),
+  ('int16', -129), ('int16', -32768), ('int16', 32767),
+  ('int24', -32769), ('int24', -8388608), ('int24', 8388607),
+  ('int32', -8388609), ('int32', -2147483648), ('int32', 2147483647),
+  ('int48', -2147483649), ('int48', -140737488355328), ('int48', 140737488355327),
+  ('int64', -140737488355329), ('int64', -9223372036854775808), ('int64', 9223372036854775807);
+CREATE TABLE negative_rowids (id INTEGER PRIMARY KEY, label text);
+INSERT INTO negative_rowids VALUES (-300, 'a'), (-2, 'b'), (0, 'c'), (5, 'd');
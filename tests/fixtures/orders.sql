@@ -0,0 +1,2005 @@
+CREATE TABLE orders (id integer primary key, customer text, order_date text, amount integer);
+CREATE INDEX idx_orders_customer_date ON orders (customer, order_date DESC);
+CREATE INDEX idx_orders_date_amount ON orders (order_date COLLATE NOCASE, amount);
+BEGIN;
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer07', '2024-02-02', 13);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer14', '2024-03-03', 26);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer21', '2024-04-04', 39);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer28', '2024-05-05', 52);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer35', '2024-06-06', 65);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer42', '2024-07-07', 78);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer49', '2024-08-08', 91);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer56', '2024-09-09', 104);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer63', '2024-10-10', 117);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer70', '2024-11-11', 130);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer77', '2024-12-12', 143);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer84', '2024-01-13', 156);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer91', '2024-02-14', 169);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer01', '2024-03-15', 182);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer08', '2024-04-16', 195);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer15', '2024-05-17', 208);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer22', '2024-06-18', 221);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer29', '2024-07-19', 234);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer36', '2024-08-20', 247);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer43', '2024-09-21', 260);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer50', '2024-10-22', 273);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer57', '2024-11-23', 286);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer64', '2024-12-24', 299);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer71', '2024-01-25', 312);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer78', '2024-02-26', 325);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer85', '2024-03-27', 338);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer92', '2024-04-28', 351);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer02', '2024-05-01', 364);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer09', '2024-06-02', 377);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer16', '2024-07-03', 390);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer23', '2024-08-04', 403);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer30', '2024-09-05', 416);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer37', '2024-10-06', 429);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer44', '2024-11-07', 442);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer51', '2024-12-08', 455);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer58', '2024-01-09', 468);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer65', '2024-02-10', 481);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer72', '2024-03-11', 494);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer79', '2024-04-12', 7);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer86', '2024-05-13', 20);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer93', '2024-06-14', 33);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer03', '2024-07-15', 46);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer10', '2024-08-16', 59);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer17', '2024-09-17', 72);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer24', '2024-10-18', 85);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer31', '2024-11-19', 98);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer38', '2024-12-20', 111);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer45', '2024-01-21', 124);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer52', '2024-02-22', 137);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer59', '2024-03-23', 150);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer66', '2024-04-24', 163);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer73', '2024-05-25', 176);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer80', '2024-06-26', 189);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer87', '2024-07-27', 202);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer94', '2024-08-28', 215);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer04', '2024-09-01', 228);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer11', '2024-10-02', 241);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer18', '2024-11-03', 254);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer25', '2024-12-04', 267);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer32', '2024-01-05', 280);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer39', '2024-02-06', 293);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer46', '2024-03-07', 306);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer53', '2024-04-08', 319);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer60', '2024-05-09', 332);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer67', '2024-06-10', 345);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer74', '2024-07-11', 358);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer81', '2024-08-12', 371);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer88', '2024-09-13', 384);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer95', '2024-10-14', 397);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer05', '2024-11-15', 410);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer12', '2024-12-16', 423);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer19', '2024-01-17', 436);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer26', '2024-02-18', 449);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer33', '2024-03-19', 462);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer40', '2024-04-20', 475);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer47', '2024-05-21', 488);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer54', '2024-06-22', 1);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer61', '2024-07-23', 14);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer68', '2024-08-24', 27);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer75', '2024-09-25', 40);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer82', '2024-10-26', 53);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer89', '2024-11-27', 66);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer96', '2024-12-28', 79);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer06', '2024-01-01', 92);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer13', '2024-02-02', 105);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer20', '2024-03-03', 118);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer27', '2024-04-04', 131);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer34', '2024-05-05', 144);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer41', '2024-06-06', 157);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer48', '2024-07-07', 170);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer55', '2024-08-08', 183);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer62', '2024-09-09', 196);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer69', '2024-10-10', 209);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer76', '2024-11-11', 222);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer83', '2024-12-12', 235);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer90', '2024-01-13', 248);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer00', '2024-02-14', 261);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer07', '2024-03-15', 274);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer14', '2024-04-16', 287);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer21', '2024-05-17', 300);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer28', '2024-06-18', 313);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer35', '2024-07-19', 326);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer42', '2024-08-20', 339);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer49', '2024-09-21', 352);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer56', '2024-10-22', 365);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer63', '2024-11-23', 378);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer70', '2024-12-24', 391);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer77', '2024-01-25', 404);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer84', '2024-02-26', 417);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer91', '2024-03-27', 430);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer01', '2024-04-28', 443);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer08', '2024-05-01', 456);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer15', '2024-06-02', 469);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer22', '2024-07-03', 482);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer29', '2024-08-04', 495);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer36', '2024-09-05', 8);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer43', '2024-10-06', 21);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer50', '2024-11-07', 34);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer57', '2024-12-08', 47);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer64', '2024-01-09', 60);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer71', '2024-02-10', 73);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer78', '2024-03-11', 86);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer85', '2024-04-12', 99);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer92', '2024-05-13', 112);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer02', '2024-06-14', 125);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer09', '2024-07-15', 138);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer16', '2024-08-16', 151);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer23', '2024-09-17', 164);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer30', '2024-10-18', 177);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer37', '2024-11-19', 190);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer44', '2024-12-20', 203);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer51', '2024-01-21', 216);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer58', '2024-02-22', 229);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer65', '2024-03-23', 242);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer72', '2024-04-24', 255);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer79', '2024-05-25', 268);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer86', '2024-06-26', 281);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer93', '2024-07-27', 294);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer03', '2024-08-28', 307);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer10', '2024-09-01', 320);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer17', '2024-10-02', 333);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer24', '2024-11-03', 346);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer31', '2024-12-04', 359);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer38', '2024-01-05', 372);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer45', '2024-02-06', 385);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer52', '2024-03-07', 398);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer59', '2024-04-08', 411);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer66', '2024-05-09', 424);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer73', '2024-06-10', 437);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer80', '2024-07-11', 450);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer87', '2024-08-12', 463);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer94', '2024-09-13', 476);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer04', '2024-10-14', 489);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer11', '2024-11-15', 2);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer18', '2024-12-16', 15);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer25', '2024-01-17', 28);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer32', '2024-02-18', 41);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer39', '2024-03-19', 54);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer46', '2024-04-20', 67);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer53', '2024-05-21', 80);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer60', '2024-06-22', 93);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer67', '2024-07-23', 106);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer74', '2024-08-24', 119);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer81', '2024-09-25', 132);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer88', '2024-10-26', 145);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer95', '2024-11-27', 158);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer05', '2024-12-28', 171);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer12', '2024-01-01', 184);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer19', '2024-02-02', 197);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer26', '2024-03-03', 210);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer33', '2024-04-04', 223);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer40', '2024-05-05', 236);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer47', '2024-06-06', 249);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer54', '2024-07-07', 262);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer61', '2024-08-08', 275);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer68', '2024-09-09', 288);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer75', '2024-10-10', 301);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer82', '2024-11-11', 314);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer89', '2024-12-12', 327);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer96', '2024-01-13', 340);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer06', '2024-02-14', 353);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer13', '2024-03-15', 366);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer20', '2024-04-16', 379);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer27', '2024-05-17', 392);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer34', '2024-06-18', 405);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer41', '2024-07-19', 418);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer48', '2024-08-20', 431);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer55', '2024-09-21', 444);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer62', '2024-10-22', 457);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer69', '2024-11-23', 470);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer76', '2024-12-24', 483);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer83', '2024-01-25', 496);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer90', '2024-02-26', 9);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer00', '2024-03-27', 22);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer07', '2024-04-28', 35);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer14', '2024-05-01', 48);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer21', '2024-06-02', 61);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer28', '2024-07-03', 74);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer35', '2024-08-04', 87);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer42', '2024-09-05', 100);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer49', '2024-10-06', 113);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer56', '2024-11-07', 126);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer63', '2024-12-08', 139);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer70', '2024-01-09', 152);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer77', '2024-02-10', 165);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer84', '2024-03-11', 178);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer91', '2024-04-12', 191);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer01', '2024-05-13', 204);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer08', '2024-06-14', 217);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer15', '2024-07-15', 230);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer22', '2024-08-16', 243);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer29', '2024-09-17', 256);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer36', '2024-10-18', 269);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer43', '2024-11-19', 282);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer50', '2024-12-20', 295);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer57', '2024-01-21', 308);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer64', '2024-02-22', 321);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer71', '2024-03-23', 334);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer78', '2024-04-24', 347);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer85', '2024-05-25', 360);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer92', '2024-06-26', 373);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer02', '2024-07-27', 386);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer09', '2024-08-28', 399);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer16', '2024-09-01', 412);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer23', '2024-10-02', 425);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer30', '2024-11-03', 438);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer37', '2024-12-04', 451);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer44', '2024-01-05', 464);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer51', '2024-02-06', 477);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer58', '2024-03-07', 490);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer65', '2024-04-08', 3);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer72', '2024-05-09', 16);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer79', '2024-06-10', 29);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer86', '2024-07-11', 42);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer93', '2024-08-12', 55);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer03', '2024-09-13', 68);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer10', '2024-10-14', 81);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer17', '2024-11-15', 94);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer24', '2024-12-16', 107);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer31', '2024-01-17', 120);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer38', '2024-02-18', 133);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer45', '2024-03-19', 146);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer52', '2024-04-20', 159);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer59', '2024-05-21', 172);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer66', '2024-06-22', 185);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer73', '2024-07-23', 198);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer80', '2024-08-24', 211);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer87', '2024-09-25', 224);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer94', '2024-10-26', 237);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer04', '2024-11-27', 250);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer11', '2024-12-28', 263);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer18', '2024-01-01', 276);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer25', '2024-02-02', 289);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer32', '2024-03-03', 302);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer39', '2024-04-04', 315);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer46', '2024-05-05', 328);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer53', '2024-06-06', 341);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer60', '2024-07-07', 354);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer67', '2024-08-08', 367);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer74', '2024-09-09', 380);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer81', '2024-10-10', 393);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer88', '2024-11-11', 406);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer95', '2024-12-12', 419);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer05', '2024-01-13', 432);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer12', '2024-02-14', 445);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer19', '2024-03-15', 458);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer26', '2024-04-16', 471);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer33', '2024-05-17', 484);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer40', '2024-06-18', 497);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer47', '2024-07-19', 10);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer54', '2024-08-20', 23);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer61', '2024-09-21', 36);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer68', '2024-10-22', 49);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer75', '2024-11-23', 62);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer82', '2024-12-24', 75);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer89', '2024-01-25', 88);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer96', '2024-02-26', 101);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer06', '2024-03-27', 114);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer13', '2024-04-28', 127);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer20', '2024-05-01', 140);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer27', '2024-06-02', 153);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer34', '2024-07-03', 166);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer41', '2024-08-04', 179);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer48', '2024-09-05', 192);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer55', '2024-10-06', 205);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer62', '2024-11-07', 218);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer69', '2024-12-08', 231);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer76', '2024-01-09', 244);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer83', '2024-02-10', 257);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer90', '2024-03-11', 270);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer00', '2024-04-12', 283);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer07', '2024-05-13', 296);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer14', '2024-06-14', 309);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer21', '2024-07-15', 322);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer28', '2024-08-16', 335);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer35', '2024-09-17', 348);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer42', '2024-10-18', 361);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer49', '2024-11-19', 374);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer56', '2024-12-20', 387);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer63', '2024-01-21', 400);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer70', '2024-02-22', 413);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer77', '2024-03-23', 426);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer84', '2024-04-24', 439);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer91', '2024-05-25', 452);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer01', '2024-06-26', 465);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer08', '2024-07-27', 478);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer15', '2024-08-28', 491);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer22', '2024-09-01', 4);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer29', '2024-10-02', 17);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer36', '2024-11-03', 30);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer43', '2024-12-04', 43);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer50', '2024-01-05', 56);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer57', '2024-02-06', 69);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer64', '2024-03-07', 82);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer71', '2024-04-08', 95);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer78', '2024-05-09', 108);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer85', '2024-06-10', 121);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer92', '2024-07-11', 134);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer02', '2024-08-12', 147);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer09', '2024-09-13', 160);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer16', '2024-10-14', 173);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer23', '2024-11-15', 186);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer30', '2024-12-16', 199);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer37', '2024-01-17', 212);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer44', '2024-02-18', 225);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer51', '2024-03-19', 238);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer58', '2024-04-20', 251);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer65', '2024-05-21', 264);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer72', '2024-06-22', 277);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer79', '2024-07-23', 290);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer86', '2024-08-24', 303);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer93', '2024-09-25', 316);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer03', '2024-10-26', 329);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer10', '2024-11-27', 342);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer17', '2024-12-28', 355);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer24', '2024-01-01', 368);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer31', '2024-02-02', 381);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer38', '2024-03-03', 394);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer45', '2024-04-04', 407);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer52', '2024-05-05', 420);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer59', '2024-06-06', 433);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer66', '2024-07-07', 446);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer73', '2024-08-08', 459);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer80', '2024-09-09', 472);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer87', '2024-10-10', 485);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer94', '2024-11-11', 498);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer04', '2024-12-12', 11);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer11', '2024-01-13', 24);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer18', '2024-02-14', 37);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer25', '2024-03-15', 50);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer32', '2024-04-16', 63);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer39', '2024-05-17', 76);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer46', '2024-06-18', 89);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer53', '2024-07-19', 102);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer60', '2024-08-20', 115);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer67', '2024-09-21', 128);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer74', '2024-10-22', 141);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer81', '2024-11-23', 154);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer88', '2024-12-24', 167);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer95', '2024-01-25', 180);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer05', '2024-02-26', 193);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer12', '2024-03-27', 206);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer19', '2024-04-28', 219);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer26', '2024-05-01', 232);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer33', '2024-06-02', 245);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer40', '2024-07-03', 258);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer47', '2024-08-04', 271);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer54', '2024-09-05', 284);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer61', '2024-10-06', 297);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer68', '2024-11-07', 310);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer75', '2024-12-08', 323);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer82', '2024-01-09', 336);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer89', '2024-02-10', 349);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer96', '2024-03-11', 362);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer06', '2024-04-12', 375);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer13', '2024-05-13', 388);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer20', '2024-06-14', 401);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer27', '2024-07-15', 414);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer34', '2024-08-16', 427);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer41', '2024-09-17', 440);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer48', '2024-10-18', 453);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer55', '2024-11-19', 466);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer62', '2024-12-20', 479);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer69', '2024-01-21', 492);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer76', '2024-02-22', 5);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer83', '2024-03-23', 18);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer90', '2024-04-24', 31);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer00', '2024-05-25', 44);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer07', '2024-06-26', 57);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer14', '2024-07-27', 70);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer21', '2024-08-28', 83);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer28', '2024-09-01', 96);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer35', '2024-10-02', 109);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer42', '2024-11-03', 122);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer49', '2024-12-04', 135);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer56', '2024-01-05', 148);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer63', '2024-02-06', 161);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer70', '2024-03-07', 174);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer77', '2024-04-08', 187);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer84', '2024-05-09', 200);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer91', '2024-06-10', 213);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer01', '2024-07-11', 226);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer08', '2024-08-12', 239);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer15', '2024-09-13', 252);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer22', '2024-10-14', 265);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer29', '2024-11-15', 278);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer36', '2024-12-16', 291);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer43', '2024-01-17', 304);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer50', '2024-02-18', 317);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer57', '2024-03-19', 330);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer64', '2024-04-20', 343);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer71', '2024-05-21', 356);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer78', '2024-06-22', 369);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer85', '2024-07-23', 382);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer92', '2024-08-24', 395);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer02', '2024-09-25', 408);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer09', '2024-10-26', 421);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer16', '2024-11-27', 434);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer23', '2024-12-28', 447);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer30', '2024-01-01', 460);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer37', '2024-02-02', 473);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer44', '2024-03-03', 486);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer51', '2024-04-04', 499);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer58', '2024-05-05', 12);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer65', '2024-06-06', 25);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer72', '2024-07-07', 38);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer79', '2024-08-08', 51);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer86', '2024-09-09', 64);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer93', '2024-10-10', 77);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer03', '2024-11-11', 90);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer10', '2024-12-12', 103);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer17', '2024-01-13', 116);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer24', '2024-02-14', 129);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer31', '2024-03-15', 142);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer38', '2024-04-16', 155);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer45', '2024-05-17', 168);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer52', '2024-06-18', 181);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer59', '2024-07-19', 194);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer66', '2024-08-20', 207);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer73', '2024-09-21', 220);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer80', '2024-10-22', 233);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer87', '2024-11-23', 246);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer94', '2024-12-24', 259);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer04', '2024-01-25', 272);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer11', '2024-02-26', 285);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer18', '2024-03-27', 298);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer25', '2024-04-28', 311);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer32', '2024-05-01', 324);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer39', '2024-06-02', 337);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer46', '2024-07-03', 350);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer53', '2024-08-04', 363);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer60', '2024-09-05', 376);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer67', '2024-10-06', 389);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer74', '2024-11-07', 402);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer81', '2024-12-08', 415);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer88', '2024-01-09', 428);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer95', '2024-02-10', 441);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer05', '2024-03-11', 454);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer12', '2024-04-12', 467);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer19', '2024-05-13', 480);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer26', '2024-06-14', 493);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer33', '2024-07-15', 6);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer40', '2024-08-16', 19);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer47', '2024-09-17', 32);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer54', '2024-10-18', 45);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer61', '2024-11-19', 58);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer68', '2024-12-20', 71);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer75', '2024-01-21', 84);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer82', '2024-02-22', 97);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer89', '2024-03-23', 110);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer96', '2024-04-24', 123);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer06', '2024-05-25', 136);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer13', '2024-06-26', 149);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer20', '2024-07-27', 162);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer27', '2024-08-28', 175);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer34', '2024-09-01', 188);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer41', '2024-10-02', 201);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer48', '2024-11-03', 214);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer55', '2024-12-04', 227);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer62', '2024-01-05', 240);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer69', '2024-02-06', 253);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer76', '2024-03-07', 266);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer83', '2024-04-08', 279);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer90', '2024-05-09', 292);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer00', '2024-06-10', 305);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer07', '2024-07-11', 318);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer14', '2024-08-12', 331);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer21', '2024-09-13', 344);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer28', '2024-10-14', 357);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer35', '2024-11-15', 370);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer42', '2024-12-16', 383);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer49', '2024-01-17', 396);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer56', '2024-02-18', 409);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer63', '2024-03-19', 422);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer70', '2024-04-20', 435);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer77', '2024-05-21', 448);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer84', '2024-06-22', 461);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer91', '2024-07-23', 474);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer01', '2024-08-24', 487);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer08', '2024-09-25', 0);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer15', '2024-10-26', 13);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer22', '2024-11-27', 26);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer29', '2024-12-28', 39);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer36', '2024-01-01', 52);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer43', '2024-02-02', 65);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer50', '2024-03-03', 78);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer57', '2024-04-04', 91);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer64', '2024-05-05', 104);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer71', '2024-06-06', 117);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer78', '2024-07-07', 130);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer85', '2024-08-08', 143);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer92', '2024-09-09', 156);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer02', '2024-10-10', 169);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer09', '2024-11-11', 182);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer16', '2024-12-12', 195);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer23', '2024-01-13', 208);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer30', '2024-02-14', 221);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer37', '2024-03-15', 234);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer44', '2024-04-16', 247);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer51', '2024-05-17', 260);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer58', '2024-06-18', 273);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer65', '2024-07-19', 286);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer72', '2024-08-20', 299);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer79', '2024-09-21', 312);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer86', '2024-10-22', 325);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer93', '2024-11-23', 338);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer03', '2024-12-24', 351);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer10', '2024-01-25', 364);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer17', '2024-02-26', 377);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer24', '2024-03-27', 390);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer31', '2024-04-28', 403);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer38', '2024-05-01', 416);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer45', '2024-06-02', 429);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer52', '2024-07-03', 442);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer59', '2024-08-04', 455);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer66', '2024-09-05', 468);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer73', '2024-10-06', 481);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer80', '2024-11-07', 494);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer87', '2024-12-08', 7);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer94', '2024-01-09', 20);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer04', '2024-02-10', 33);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer11', '2024-03-11', 46);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer18', '2024-04-12', 59);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer25', '2024-05-13', 72);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer32', '2024-06-14', 85);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer39', '2024-07-15', 98);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer46', '2024-08-16', 111);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer53', '2024-09-17', 124);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer60', '2024-10-18', 137);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer67', '2024-11-19', 150);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer74', '2024-12-20', 163);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer81', '2024-01-21', 176);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer88', '2024-02-22', 189);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer95', '2024-03-23', 202);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer05', '2024-04-24', 215);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer12', '2024-05-25', 228);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer19', '2024-06-26', 241);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer26', '2024-07-27', 254);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer33', '2024-08-28', 267);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer40', '2024-09-01', 280);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer47', '2024-10-02', 293);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer54', '2024-11-03', 306);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer61', '2024-12-04', 319);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer68', '2024-01-05', 332);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer75', '2024-02-06', 345);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer82', '2024-03-07', 358);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer89', '2024-04-08', 371);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer96', '2024-05-09', 384);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer06', '2024-06-10', 397);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer13', '2024-07-11', 410);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer20', '2024-08-12', 423);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer27', '2024-09-13', 436);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer34', '2024-10-14', 449);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer41', '2024-11-15', 462);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer48', '2024-12-16', 475);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer55', '2024-01-17', 488);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer62', '2024-02-18', 1);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer69', '2024-03-19', 14);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer76', '2024-04-20', 27);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer83', '2024-05-21', 40);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer90', '2024-06-22', 53);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer00', '2024-07-23', 66);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer07', '2024-08-24', 79);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer14', '2024-09-25', 92);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer21', '2024-10-26', 105);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer28', '2024-11-27', 118);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer35', '2024-12-28', 131);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer42', '2024-01-01', 144);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer49', '2024-02-02', 157);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer56', '2024-03-03', 170);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer63', '2024-04-04', 183);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer70', '2024-05-05', 196);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer77', '2024-06-06', 209);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer84', '2024-07-07', 222);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer91', '2024-08-08', 235);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer01', '2024-09-09', 248);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer08', '2024-10-10', 261);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer15', '2024-11-11', 274);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer22', '2024-12-12', 287);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer29', '2024-01-13', 300);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer36', '2024-02-14', 313);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer43', '2024-03-15', 326);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer50', '2024-04-16', 339);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer57', '2024-05-17', 352);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer64', '2024-06-18', 365);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer71', '2024-07-19', 378);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer78', '2024-08-20', 391);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer85', '2024-09-21', 404);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer92', '2024-10-22', 417);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer02', '2024-11-23', 430);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer09', '2024-12-24', 443);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer16', '2024-01-25', 456);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer23', '2024-02-26', 469);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer30', '2024-03-27', 482);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer37', '2024-04-28', 495);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer44', '2024-05-01', 8);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer51', '2024-06-02', 21);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer58', '2024-07-03', 34);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer65', '2024-08-04', 47);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer72', '2024-09-05', 60);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer79', '2024-10-06', 73);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer86', '2024-11-07', 86);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer93', '2024-12-08', 99);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer03', '2024-01-09', 112);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer10', '2024-02-10', 125);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer17', '2024-03-11', 138);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer24', '2024-04-12', 151);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer31', '2024-05-13', 164);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer38', '2024-06-14', 177);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer45', '2024-07-15', 190);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer52', '2024-08-16', 203);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer59', '2024-09-17', 216);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer66', '2024-10-18', 229);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer73', '2024-11-19', 242);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer80', '2024-12-20', 255);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer87', '2024-01-21', 268);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer94', '2024-02-22', 281);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer04', '2024-03-23', 294);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer11', '2024-04-24', 307);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer18', '2024-05-25', 320);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer25', '2024-06-26', 333);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer32', '2024-07-27', 346);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer39', '2024-08-28', 359);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer46', '2024-09-01', 372);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer53', '2024-10-02', 385);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer60', '2024-11-03', 398);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer67', '2024-12-04', 411);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer74', '2024-01-05', 424);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer81', '2024-02-06', 437);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer88', '2024-03-07', 450);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer95', '2024-04-08', 463);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer05', '2024-05-09', 476);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer12', '2024-06-10', 489);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer19', '2024-07-11', 2);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer26', '2024-08-12', 15);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer33', '2024-09-13', 28);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer40', '2024-10-14', 41);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer47', '2024-11-15', 54);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer54', '2024-12-16', 67);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer61', '2024-01-17', 80);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer68', '2024-02-18', 93);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer75', '2024-03-19', 106);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer82', '2024-04-20', 119);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer89', '2024-05-21', 132);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer96', '2024-06-22', 145);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer06', '2024-07-23', 158);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer13', '2024-08-24', 171);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer20', '2024-09-25', 184);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer27', '2024-10-26', 197);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer34', '2024-11-27', 210);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer41', '2024-12-28', 223);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer48', '2024-01-01', 236);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer55', '2024-02-02', 249);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer62', '2024-03-03', 262);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer69', '2024-04-04', 275);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer76', '2024-05-05', 288);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer83', '2024-06-06', 301);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer90', '2024-07-07', 314);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer00', '2024-08-08', 327);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer07', '2024-09-09', 340);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer14', '2024-10-10', 353);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer21', '2024-11-11', 366);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer28', '2024-12-12', 379);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer35', '2024-01-13', 392);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer42', '2024-02-14', 405);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer49', '2024-03-15', 418);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer56', '2024-04-16', 431);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer63', '2024-05-17', 444);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer70', '2024-06-18', 457);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer77', '2024-07-19', 470);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer84', '2024-08-20', 483);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer91', '2024-09-21', 496);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer01', '2024-10-22', 9);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer08', '2024-11-23', 22);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer15', '2024-12-24', 35);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer22', '2024-01-25', 48);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer29', '2024-02-26', 61);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer36', '2024-03-27', 74);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer43', '2024-04-28', 87);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer50', '2024-05-01', 100);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer57', '2024-06-02', 113);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer64', '2024-07-03', 126);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer71', '2024-08-04', 139);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer78', '2024-09-05', 152);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer85', '2024-10-06', 165);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer92', '2024-11-07', 178);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer02', '2024-12-08', 191);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer09', '2024-01-09', 204);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer16', '2024-02-10', 217);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer23', '2024-03-11', 230);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer30', '2024-04-12', 243);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer37', '2024-05-13', 256);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer44', '2024-06-14', 269);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer51', '2024-07-15', 282);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer58', '2024-08-16', 295);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer65', '2024-09-17', 308);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer72', '2024-10-18', 321);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer79', '2024-11-19', 334);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer86', '2024-12-20', 347);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer93', '2024-01-21', 360);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer03', '2024-02-22', 373);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer10', '2024-03-23', 386);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer17', '2024-04-24', 399);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer24', '2024-05-25', 412);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer31', '2024-06-26', 425);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer38', '2024-07-27', 438);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer45', '2024-08-28', 451);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer52', '2024-09-01', 464);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer59', '2024-10-02', 477);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer66', '2024-11-03', 490);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer73', '2024-12-04', 3);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer80', '2024-01-05', 16);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer87', '2024-02-06', 29);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer94', '2024-03-07', 42);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer04', '2024-04-08', 55);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer11', '2024-05-09', 68);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer18', '2024-06-10', 81);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer25', '2024-07-11', 94);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer32', '2024-08-12', 107);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer39', '2024-09-13', 120);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer46', '2024-10-14', 133);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer53', '2024-11-15', 146);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer60', '2024-12-16', 159);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer67', '2024-01-17', 172);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer74', '2024-02-18', 185);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer81', '2024-03-19', 198);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer88', '2024-04-20', 211);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer95', '2024-05-21', 224);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer05', '2024-06-22', 237);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer12', '2024-07-23', 250);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer19', '2024-08-24', 263);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer26', '2024-09-25', 276);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer33', '2024-10-26', 289);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer40', '2024-11-27', 302);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer47', '2024-12-28', 315);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer54', '2024-01-01', 328);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer61', '2024-02-02', 341);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer68', '2024-03-03', 354);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer75', '2024-04-04', 367);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer82', '2024-05-05', 380);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer89', '2024-06-06', 393);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer96', '2024-07-07', 406);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer06', '2024-08-08', 419);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer13', '2024-09-09', 432);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer20', '2024-10-10', 445);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer27', '2024-11-11', 458);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer34', '2024-12-12', 471);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer41', '2024-01-13', 484);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer48', '2024-02-14', 497);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer55', '2024-03-15', 10);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer62', '2024-04-16', 23);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer69', '2024-05-17', 36);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer76', '2024-06-18', 49);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer83', '2024-07-19', 62);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer90', '2024-08-20', 75);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer00', '2024-09-21', 88);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer07', '2024-10-22', 101);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer14', '2024-11-23', 114);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer21', '2024-12-24', 127);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer28', '2024-01-25', 140);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer35', '2024-02-26', 153);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer42', '2024-03-27', 166);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer49', '2024-04-28', 179);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer56', '2024-05-01', 192);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer63', '2024-06-02', 205);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer70', '2024-07-03', 218);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer77', '2024-08-04', 231);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer84', '2024-09-05', 244);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer91', '2024-10-06', 257);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer01', '2024-11-07', 270);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer08', '2024-12-08', 283);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer15', '2024-01-09', 296);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer22', '2024-02-10', 309);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer29', '2024-03-11', 322);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer36', '2024-04-12', 335);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer43', '2024-05-13', 348);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer50', '2024-06-14', 361);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer57', '2024-07-15', 374);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer64', '2024-08-16', 387);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer71', '2024-09-17', 400);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer78', '2024-10-18', 413);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer85', '2024-11-19', 426);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer92', '2024-12-20', 439);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer02', '2024-01-21', 452);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer09', '2024-02-22', 465);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer16', '2024-03-23', 478);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer23', '2024-04-24', 491);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer30', '2024-05-25', 4);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer37', '2024-06-26', 17);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer44', '2024-07-27', 30);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer51', '2024-08-28', 43);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer58', '2024-09-01', 56);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer65', '2024-10-02', 69);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer72', '2024-11-03', 82);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer79', '2024-12-04', 95);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer86', '2024-01-05', 108);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer93', '2024-02-06', 121);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer03', '2024-03-07', 134);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer10', '2024-04-08', 147);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer17', '2024-05-09', 160);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer24', '2024-06-10', 173);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer31', '2024-07-11', 186);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer38', '2024-08-12', 199);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer45', '2024-09-13', 212);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer52', '2024-10-14', 225);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer59', '2024-11-15', 238);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer66', '2024-12-16', 251);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer73', '2024-01-17', 264);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer80', '2024-02-18', 277);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer87', '2024-03-19', 290);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer94', '2024-04-20', 303);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer04', '2024-05-21', 316);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer11', '2024-06-22', 329);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer18', '2024-07-23', 342);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer25', '2024-08-24', 355);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer32', '2024-09-25', 368);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer39', '2024-10-26', 381);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer46', '2024-11-27', 394);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer53', '2024-12-28', 407);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer60', '2024-01-01', 420);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer67', '2024-02-02', 433);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer74', '2024-03-03', 446);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer81', '2024-04-04', 459);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer88', '2024-05-05', 472);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer95', '2024-06-06', 485);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer05', '2024-07-07', 498);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer12', '2024-08-08', 11);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer19', '2024-09-09', 24);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer26', '2024-10-10', 37);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer33', '2024-11-11', 50);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer40', '2024-12-12', 63);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer47', '2024-01-13', 76);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer54', '2024-02-14', 89);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer61', '2024-03-15', 102);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer68', '2024-04-16', 115);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer75', '2024-05-17', 128);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer82', '2024-06-18', 141);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer89', '2024-07-19', 154);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer96', '2024-08-20', 167);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer06', '2024-09-21', 180);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer13', '2024-10-22', 193);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer20', '2024-11-23', 206);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer27', '2024-12-24', 219);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer34', '2024-01-25', 232);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer41', '2024-02-26', 245);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer48', '2024-03-27', 258);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer55', '2024-04-28', 271);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer62', '2024-05-01', 284);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer69', '2024-06-02', 297);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer76', '2024-07-03', 310);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer83', '2024-08-04', 323);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer90', '2024-09-05', 336);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer00', '2024-10-06', 349);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer07', '2024-11-07', 362);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer14', '2024-12-08', 375);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer21', '2024-01-09', 388);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer28', '2024-02-10', 401);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer35', '2024-03-11', 414);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer42', '2024-04-12', 427);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer49', '2024-05-13', 440);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer56', '2024-06-14', 453);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer63', '2024-07-15', 466);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer70', '2024-08-16', 479);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer77', '2024-09-17', 492);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer84', '2024-10-18', 5);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer91', '2024-11-19', 18);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer01', '2024-12-20', 31);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer08', '2024-01-21', 44);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer15', '2024-02-22', 57);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer22', '2024-03-23', 70);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer29', '2024-04-24', 83);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer36', '2024-05-25', 96);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer43', '2024-06-26', 109);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer50', '2024-07-27', 122);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer57', '2024-08-28', 135);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer64', '2024-09-01', 148);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer71', '2024-10-02', 161);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer78', '2024-11-03', 174);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer85', '2024-12-04', 187);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer92', '2024-01-05', 200);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer02', '2024-02-06', 213);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer09', '2024-03-07', 226);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer16', '2024-04-08', 239);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer23', '2024-05-09', 252);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer30', '2024-06-10', 265);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer37', '2024-07-11', 278);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer44', '2024-08-12', 291);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer51', '2024-09-13', 304);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer58', '2024-10-14', 317);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer65', '2024-11-15', 330);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer72', '2024-12-16', 343);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer79', '2024-01-17', 356);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer86', '2024-02-18', 369);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer93', '2024-03-19', 382);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer03', '2024-04-20', 395);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer10', '2024-05-21', 408);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer17', '2024-06-22', 421);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer24', '2024-07-23', 434);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer31', '2024-08-24', 447);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer38', '2024-09-25', 460);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer45', '2024-10-26', 473);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer52', '2024-11-27', 486);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer59', '2024-12-28', 499);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer66', '2024-01-01', 12);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer73', '2024-02-02', 25);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer80', '2024-03-03', 38);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer87', '2024-04-04', 51);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer94', '2024-05-05', 64);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer04', '2024-06-06', 77);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer11', '2024-07-07', 90);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer18', '2024-08-08', 103);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer25', '2024-09-09', 116);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer32', '2024-10-10', 129);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer39', '2024-11-11', 142);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer46', '2024-12-12', 155);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer53', '2024-01-13', 168);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer60', '2024-02-14', 181);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer67', '2024-03-15', 194);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer74', '2024-04-16', 207);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer81', '2024-05-17', 220);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer88', '2024-06-18', 233);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer95', '2024-07-19', 246);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer05', '2024-08-20', 259);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer12', '2024-09-21', 272);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer19', '2024-10-22', 285);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer26', '2024-11-23', 298);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer33', '2024-12-24', 311);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer40', '2024-01-25', 324);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer47', '2024-02-26', 337);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer54', '2024-03-27', 350);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer61', '2024-04-28', 363);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer68', '2024-05-01', 376);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer75', '2024-06-02', 389);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer82', '2024-07-03', 402);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer89', '2024-08-04', 415);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer96', '2024-09-05', 428);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer06', '2024-10-06', 441);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer13', '2024-11-07', 454);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer20', '2024-12-08', 467);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer27', '2024-01-09', 480);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer34', '2024-02-10', 493);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer41', '2024-03-11', 6);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer48', '2024-04-12', 19);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer55', '2024-05-13', 32);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer62', '2024-06-14', 45);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer69', '2024-07-15', 58);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer76', '2024-08-16', 71);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer83', '2024-09-17', 84);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer90', '2024-10-18', 97);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer00', '2024-11-19', 110);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer07', '2024-12-20', 123);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer14', '2024-01-21', 136);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer21', '2024-02-22', 149);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer28', '2024-03-23', 162);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer35', '2024-04-24', 175);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer42', '2024-05-25', 188);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer49', '2024-06-26', 201);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer56', '2024-07-27', 214);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer63', '2024-08-28', 227);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer70', '2024-09-01', 240);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer77', '2024-10-02', 253);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer84', '2024-11-03', 266);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer91', '2024-12-04', 279);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer01', '2024-01-05', 292);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer08', '2024-02-06', 305);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer15', '2024-03-07', 318);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer22', '2024-04-08', 331);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer29', '2024-05-09', 344);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer36', '2024-06-10', 357);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer43', '2024-07-11', 370);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer50', '2024-08-12', 383);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer57', '2024-09-13', 396);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer64', '2024-10-14', 409);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer71', '2024-11-15', 422);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer78', '2024-12-16', 435);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer85', '2024-01-17', 448);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer92', '2024-02-18', 461);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer02', '2024-03-19', 474);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer09', '2024-04-20', 487);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer16', '2024-05-21', 0);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer23', '2024-06-22', 13);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer30', '2024-07-23', 26);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer37', '2024-08-24', 39);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer44', '2024-09-25', 52);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer51', '2024-10-26', 65);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer58', '2024-11-27', 78);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer65', '2024-12-28', 91);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer72', '2024-01-01', 104);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer79', '2024-02-02', 117);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer86', '2024-03-03', 130);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer93', '2024-04-04', 143);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer03', '2024-05-05', 156);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer10', '2024-06-06', 169);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer17', '2024-07-07', 182);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer24', '2024-08-08', 195);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer31', '2024-09-09', 208);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer38', '2024-10-10', 221);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer45', '2024-11-11', 234);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer52', '2024-12-12', 247);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer59', '2024-01-13', 260);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer66', '2024-02-14', 273);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer73', '2024-03-15', 286);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer80', '2024-04-16', 299);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer87', '2024-05-17', 312);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer94', '2024-06-18', 325);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer04', '2024-07-19', 338);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer11', '2024-08-20', 351);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer18', '2024-09-21', 364);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer25', '2024-10-22', 377);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer32', '2024-11-23', 390);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer39', '2024-12-24', 403);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer46', '2024-01-25', 416);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer53', '2024-02-26', 429);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer60', '2024-03-27', 442);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer67', '2024-04-28', 455);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer74', '2024-05-01', 468);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer81', '2024-06-02', 481);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer88', '2024-07-03', 494);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer95', '2024-08-04', 7);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer05', '2024-09-05', 20);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer12', '2024-10-06', 33);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer19', '2024-11-07', 46);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer26', '2024-12-08', 59);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer33', '2024-01-09', 72);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer40', '2024-02-10', 85);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer47', '2024-03-11', 98);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer54', '2024-04-12', 111);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer61', '2024-05-13', 124);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer68', '2024-06-14', 137);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer75', '2024-07-15', 150);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer82', '2024-08-16', 163);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer89', '2024-09-17', 176);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer96', '2024-10-18', 189);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer06', '2024-11-19', 202);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer13', '2024-12-20', 215);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer20', '2024-01-21', 228);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer27', '2024-02-22', 241);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer34', '2024-03-23', 254);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer41', '2024-04-24', 267);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer48', '2024-05-25', 280);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer55', '2024-06-26', 293);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer62', '2024-07-27', 306);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer69', '2024-08-28', 319);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer76', '2024-09-01', 332);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer83', '2024-10-02', 345);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer90', '2024-11-03', 358);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer00', '2024-12-04', 371);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer07', '2024-01-05', 384);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer14', '2024-02-06', 397);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer21', '2024-03-07', 410);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer28', '2024-04-08', 423);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer35', '2024-05-09', 436);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer42', '2024-06-10', 449);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer49', '2024-07-11', 462);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer56', '2024-08-12', 475);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer63', '2024-09-13', 488);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer70', '2024-10-14', 1);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer77', '2024-11-15', 14);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer84', '2024-12-16', 27);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer91', '2024-01-17', 40);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer01', '2024-02-18', 53);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer08', '2024-03-19', 66);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer15', '2024-04-20', 79);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer22', '2024-05-21', 92);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer29', '2024-06-22', 105);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer36', '2024-07-23', 118);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer43', '2024-08-24', 131);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer50', '2024-09-25', 144);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer57', '2024-10-26', 157);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer64', '2024-11-27', 170);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer71', '2024-12-28', 183);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer78', '2024-01-01', 196);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer85', '2024-02-02', 209);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer92', '2024-03-03', 222);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer02', '2024-04-04', 235);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer09', '2024-05-05', 248);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer16', '2024-06-06', 261);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer23', '2024-07-07', 274);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer30', '2024-08-08', 287);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer37', '2024-09-09', 300);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer44', '2024-10-10', 313);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer51', '2024-11-11', 326);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer58', '2024-12-12', 339);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer65', '2024-01-13', 352);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer72', '2024-02-14', 365);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer79', '2024-03-15', 378);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer86', '2024-04-16', 391);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer93', '2024-05-17', 404);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer03', '2024-06-18', 417);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer10', '2024-07-19', 430);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer17', '2024-08-20', 443);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer24', '2024-09-21', 456);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer31', '2024-10-22', 469);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer38', '2024-11-23', 482);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer45', '2024-12-24', 495);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer52', '2024-01-25', 8);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer59', '2024-02-26', 21);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer66', '2024-03-27', 34);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer73', '2024-04-28', 47);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer80', '2024-05-01', 60);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer87', '2024-06-02', 73);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer94', '2024-07-03', 86);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer04', '2024-08-04', 99);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer11', '2024-09-05', 112);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer18', '2024-10-06', 125);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer25', '2024-11-07', 138);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer32', '2024-12-08', 151);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer39', '2024-01-09', 164);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer46', '2024-02-10', 177);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer53', '2024-03-11', 190);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer60', '2024-04-12', 203);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer67', '2024-05-13', 216);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer74', '2024-06-14', 229);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer81', '2024-07-15', 242);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer88', '2024-08-16', 255);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer95', '2024-09-17', 268);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer05', '2024-10-18', 281);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer12', '2024-11-19', 294);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer19', '2024-12-20', 307);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer26', '2024-01-21', 320);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer33', '2024-02-22', 333);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer40', '2024-03-23', 346);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer47', '2024-04-24', 359);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer54', '2024-05-25', 372);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer61', '2024-06-26', 385);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer68', '2024-07-27', 398);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer75', '2024-08-28', 411);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer82', '2024-09-01', 424);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer89', '2024-10-02', 437);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer96', '2024-11-03', 450);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer06', '2024-12-04', 463);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer13', '2024-01-05', 476);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer20', '2024-02-06', 489);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer27', '2024-03-07', 2);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer34', '2024-04-08', 15);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer41', '2024-05-09', 28);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer48', '2024-06-10', 41);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer55', '2024-07-11', 54);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer62', '2024-08-12', 67);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer69', '2024-09-13', 80);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer76', '2024-10-14', 93);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer83', '2024-11-15', 106);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer90', '2024-12-16', 119);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer00', '2024-01-17', 132);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer07', '2024-02-18', 145);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer14', '2024-03-19', 158);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer21', '2024-04-20', 171);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer28', '2024-05-21', 184);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer35', '2024-06-22', 197);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer42', '2024-07-23', 210);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer49', '2024-08-24', 223);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer56', '2024-09-25', 236);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer63', '2024-10-26', 249);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer70', '2024-11-27', 262);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer77', '2024-12-28', 275);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer84', '2024-01-01', 288);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer91', '2024-02-02', 301);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer01', '2024-03-03', 314);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer08', '2024-04-04', 327);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer15', '2024-05-05', 340);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer22', '2024-06-06', 353);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer29', '2024-07-07', 366);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer36', '2024-08-08', 379);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer43', '2024-09-09', 392);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer50', '2024-10-10', 405);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer57', '2024-11-11', 418);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer64', '2024-12-12', 431);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer71', '2024-01-13', 444);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer78', '2024-02-14', 457);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer85', '2024-03-15', 470);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer92', '2024-04-16', 483);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer02', '2024-05-17', 496);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer09', '2024-06-18', 9);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer16', '2024-07-19', 22);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer23', '2024-08-20', 35);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer30', '2024-09-21', 48);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer37', '2024-10-22', 61);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer44', '2024-11-23', 74);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer51', '2024-12-24', 87);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer58', '2024-01-25', 100);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer65', '2024-02-26', 113);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer72', '2024-03-27', 126);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer79', '2024-04-28', 139);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer86', '2024-05-01', 152);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer93', '2024-06-02', 165);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer03', '2024-07-03', 178);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer10', '2024-08-04', 191);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer17', '2024-09-05', 204);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer24', '2024-10-06', 217);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer31', '2024-11-07', 230);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer38', '2024-12-08', 243);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer45', '2024-01-09', 256);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer52', '2024-02-10', 269);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer59', '2024-03-11', 282);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer66', '2024-04-12', 295);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer73', '2024-05-13', 308);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer80', '2024-06-14', 321);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer87', '2024-07-15', 334);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer94', '2024-08-16', 347);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer04', '2024-09-17', 360);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer11', '2024-10-18', 373);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer18', '2024-11-19', 386);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer25', '2024-12-20', 399);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer32', '2024-01-21', 412);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer39', '2024-02-22', 425);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer46', '2024-03-23', 438);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer53', '2024-04-24', 451);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer60', '2024-05-25', 464);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer67', '2024-06-26', 477);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer74', '2024-07-27', 490);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer81', '2024-08-28', 3);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer88', '2024-09-01', 16);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer95', '2024-10-02', 29);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer05', '2024-11-03', 42);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer12', '2024-12-04', 55);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer19', '2024-01-05', 68);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer26', '2024-02-06', 81);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer33', '2024-03-07', 94);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer40', '2024-04-08', 107);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer47', '2024-05-09', 120);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer54', '2024-06-10', 133);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer61', '2024-07-11', 146);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer68', '2024-08-12', 159);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer75', '2024-09-13', 172);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer82', '2024-10-14', 185);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer89', '2024-11-15', 198);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer96', '2024-12-16', 211);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer06', '2024-01-17', 224);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer13', '2024-02-18', 237);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer20', '2024-03-19', 250);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer27', '2024-04-20', 263);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer34', '2024-05-21', 276);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer41', '2024-06-22', 289);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer48', '2024-07-23', 302);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer55', '2024-08-24', 315);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer62', '2024-09-25', 328);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer69', '2024-10-26', 341);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer76', '2024-11-27', 354);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer83', '2024-12-28', 367);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer90', '2024-01-01', 380);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer00', '2024-02-02', 393);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer07', '2024-03-03', 406);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer14', '2024-04-04', 419);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer21', '2024-05-05', 432);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer28', '2024-06-06', 445);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer35', '2024-07-07', 458);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer42', '2024-08-08', 471);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer49', '2024-09-09', 484);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer56', '2024-10-10', 497);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer63', '2024-11-11', 10);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer70', '2024-12-12', 23);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer77', '2024-01-13', 36);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer84', '2024-02-14', 49);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer91', '2024-03-15', 62);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer01', '2024-04-16', 75);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer08', '2024-05-17', 88);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer15', '2024-06-18', 101);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer22', '2024-07-19', 114);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer29', '2024-08-20', 127);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer36', '2024-09-21', 140);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer43', '2024-10-22', 153);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer50', '2024-11-23', 166);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer57', '2024-12-24', 179);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer64', '2024-01-25', 192);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer71', '2024-02-26', 205);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer78', '2024-03-27', 218);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer85', '2024-04-28', 231);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer92', '2024-05-01', 244);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer02', '2024-06-02', 257);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer09', '2024-07-03', 270);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer16', '2024-08-04', 283);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer23', '2024-09-05', 296);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer30', '2024-10-06', 309);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer37', '2024-11-07', 322);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer44', '2024-12-08', 335);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer51', '2024-01-09', 348);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer58', '2024-02-10', 361);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer65', '2024-03-11', 374);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer72', '2024-04-12', 387);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer79', '2024-05-13', 400);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer86', '2024-06-14', 413);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer93', '2024-07-15', 426);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer03', '2024-08-16', 439);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer10', '2024-09-17', 452);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer17', '2024-10-18', 465);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer24', '2024-11-19', 478);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer31', '2024-12-20', 491);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer38', '2024-01-21', 4);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer45', '2024-02-22', 17);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer52', '2024-03-23', 30);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer59', '2024-04-24', 43);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer66', '2024-05-25', 56);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer73', '2024-06-26', 69);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer80', '2024-07-27', 82);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer87', '2024-08-28', 95);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer94', '2024-09-01', 108);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer04', '2024-10-02', 121);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer11', '2024-11-03', 134);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer18', '2024-12-04', 147);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer25', '2024-01-05', 160);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer32', '2024-02-06', 173);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer39', '2024-03-07', 186);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer46', '2024-04-08', 199);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer53', '2024-05-09', 212);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer60', '2024-06-10', 225);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer67', '2024-07-11', 238);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer74', '2024-08-12', 251);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer81', '2024-09-13', 264);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer88', '2024-10-14', 277);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer95', '2024-11-15', 290);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer05', '2024-12-16', 303);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer12', '2024-01-17', 316);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer19', '2024-02-18', 329);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer26', '2024-03-19', 342);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer33', '2024-04-20', 355);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer40', '2024-05-21', 368);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer47', '2024-06-22', 381);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer54', '2024-07-23', 394);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer61', '2024-08-24', 407);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer68', '2024-09-25', 420);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer75', '2024-10-26', 433);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer82', '2024-11-27', 446);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer89', '2024-12-28', 459);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer96', '2024-01-01', 472);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer06', '2024-02-02', 485);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer13', '2024-03-03', 498);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer20', '2024-04-04', 11);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer27', '2024-05-05', 24);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer34', '2024-06-06', 37);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer41', '2024-07-07', 50);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer48', '2024-08-08', 63);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer55', '2024-09-09', 76);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer62', '2024-10-10', 89);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer69', '2024-11-11', 102);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer76', '2024-12-12', 115);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer83', '2024-01-13', 128);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer90', '2024-02-14', 141);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer00', '2024-03-15', 154);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer07', '2024-04-16', 167);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer14', '2024-05-17', 180);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer21', '2024-06-18', 193);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer28', '2024-07-19', 206);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer35', '2024-08-20', 219);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer42', '2024-09-21', 232);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer49', '2024-10-22', 245);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer56', '2024-11-23', 258);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer63', '2024-12-24', 271);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer70', '2024-01-25', 284);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer77', '2024-02-26', 297);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer84', '2024-03-27', 310);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer91', '2024-04-28', 323);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer01', '2024-05-01', 336);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer08', '2024-06-02', 349);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer15', '2024-07-03', 362);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer22', '2024-08-04', 375);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer29', '2024-09-05', 388);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer36', '2024-10-06', 401);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer43', '2024-11-07', 414);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer50', '2024-12-08', 427);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer57', '2024-01-09', 440);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer64', '2024-02-10', 453);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer71', '2024-03-11', 466);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer78', '2024-04-12', 479);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer85', '2024-05-13', 492);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer92', '2024-06-14', 5);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer02', '2024-07-15', 18);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer09', '2024-08-16', 31);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer16', '2024-09-17', 44);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer23', '2024-10-18', 57);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer30', '2024-11-19', 70);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer37', '2024-12-20', 83);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer44', '2024-01-21', 96);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer51', '2024-02-22', 109);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer58', '2024-03-23', 122);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer65', '2024-04-24', 135);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer72', '2024-05-25', 148);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer79', '2024-06-26', 161);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer86', '2024-07-27', 174);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer93', '2024-08-28', 187);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer03', '2024-09-01', 200);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer10', '2024-10-02', 213);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer17', '2024-11-03', 226);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer24', '2024-12-04', 239);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer31', '2024-01-05', 252);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer38', '2024-02-06', 265);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer45', '2024-03-07', 278);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer52', '2024-04-08', 291);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer59', '2024-05-09', 304);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer66', '2024-06-10', 317);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer73', '2024-07-11', 330);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer80', '2024-08-12', 343);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer87', '2024-09-13', 356);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer94', '2024-10-14', 369);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer04', '2024-11-15', 382);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer11', '2024-12-16', 395);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer18', '2024-01-17', 408);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer25', '2024-02-18', 421);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer32', '2024-03-19', 434);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer39', '2024-04-20', 447);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer46', '2024-05-21', 460);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer53', '2024-06-22', 473);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer60', '2024-07-23', 486);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer67', '2024-08-24', 499);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer74', '2024-09-25', 12);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer81', '2024-10-26', 25);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer88', '2024-11-27', 38);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer95', '2024-12-28', 51);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer05', '2024-01-01', 64);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer12', '2024-02-02', 77);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer19', '2024-03-03', 90);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer26', '2024-04-04', 103);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer33', '2024-05-05', 116);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer40', '2024-06-06', 129);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer47', '2024-07-07', 142);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer54', '2024-08-08', 155);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer61', '2024-09-09', 168);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer68', '2024-10-10', 181);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer75', '2024-11-11', 194);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer82', '2024-12-12', 207);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer89', '2024-01-13', 220);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer96', '2024-02-14', 233);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer06', '2024-03-15', 246);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer13', '2024-04-16', 259);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer20', '2024-05-17', 272);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer27', '2024-06-18', 285);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer34', '2024-07-19', 298);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer41', '2024-08-20', 311);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer48', '2024-09-21', 324);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer55', '2024-10-22', 337);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer62', '2024-11-23', 350);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer69', '2024-12-24', 363);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer76', '2024-01-25', 376);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer83', '2024-02-26', 389);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer90', '2024-03-27', 402);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer00', '2024-04-28', 415);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer07', '2024-05-01', 428);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer14', '2024-06-02', 441);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer21', '2024-07-03', 454);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer28', '2024-08-04', 467);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer35', '2024-09-05', 480);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer42', '2024-10-06', 493);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer49', '2024-11-07', 6);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer56', '2024-12-08', 19);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer63', '2024-01-09', 32);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer70', '2024-02-10', 45);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer77', '2024-03-11', 58);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer84', '2024-04-12', 71);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer91', '2024-05-13', 84);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer01', '2024-06-14', 97);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer08', '2024-07-15', 110);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer15', '2024-08-16', 123);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer22', '2024-09-17', 136);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer29', '2024-10-18', 149);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer36', '2024-11-19', 162);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer43', '2024-12-20', 175);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer50', '2024-01-21', 188);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer57', '2024-02-22', 201);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer64', '2024-03-23', 214);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer71', '2024-04-24', 227);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer78', '2024-05-25', 240);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer85', '2024-06-26', 253);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer92', '2024-07-27', 266);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer02', '2024-08-28', 279);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer09', '2024-09-01', 292);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer16', '2024-10-02', 305);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer23', '2024-11-03', 318);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer30', '2024-12-04', 331);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer37', '2024-01-05', 344);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer44', '2024-02-06', 357);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer51', '2024-03-07', 370);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer58', '2024-04-08', 383);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer65', '2024-05-09', 396);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer72', '2024-06-10', 409);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer79', '2024-07-11', 422);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer86', '2024-08-12', 435);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer93', '2024-09-13', 448);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer03', '2024-10-14', 461);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer10', '2024-11-15', 474);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer17', '2024-12-16', 487);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer24', '2024-01-17', 0);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer31', '2024-02-18', 13);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer38', '2024-03-19', 26);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer45', '2024-04-20', 39);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer52', '2024-05-21', 52);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer59', '2024-06-22', 65);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer66', '2024-07-23', 78);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer73', '2024-08-24', 91);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer80', '2024-09-25', 104);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer87', '2024-10-26', 117);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer94', '2024-11-27', 130);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer04', '2024-12-28', 143);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer11', '2024-01-01', 156);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer18', '2024-02-02', 169);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer25', '2024-03-03', 182);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer32', '2024-04-04', 195);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer39', '2024-05-05', 208);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer46', '2024-06-06', 221);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer53', '2024-07-07', 234);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer60', '2024-08-08', 247);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer67', '2024-09-09', 260);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer74', '2024-10-10', 273);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer81', '2024-11-11', 286);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer88', '2024-12-12', 299);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer95', '2024-01-13', 312);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer05', '2024-02-14', 325);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer12', '2024-03-15', 338);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer19', '2024-04-16', 351);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer26', '2024-05-17', 364);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer33', '2024-06-18', 377);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer40', '2024-07-19', 390);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer47', '2024-08-20', 403);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer54', '2024-09-21', 416);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer61', '2024-10-22', 429);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer68', '2024-11-23', 442);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer75', '2024-12-24', 455);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer82', '2024-01-25', 468);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer89', '2024-02-26', 481);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer96', '2024-03-27', 494);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer06', '2024-04-28', 7);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer13', '2024-05-01', 20);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer20', '2024-06-02', 33);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer27', '2024-07-03', 46);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer34', '2024-08-04', 59);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer41', '2024-09-05', 72);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer48', '2024-10-06', 85);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer55', '2024-11-07', 98);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer62', '2024-12-08', 111);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer69', '2024-01-09', 124);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer76', '2024-02-10', 137);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer83', '2024-03-11', 150);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer90', '2024-04-12', 163);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer00', '2024-05-13', 176);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer07', '2024-06-14', 189);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer14', '2024-07-15', 202);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer21', '2024-08-16', 215);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer28', '2024-09-17', 228);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer35', '2024-10-18', 241);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer42', '2024-11-19', 254);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer49', '2024-12-20', 267);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer56', '2024-01-21', 280);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer63', '2024-02-22', 293);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer70', '2024-03-23', 306);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer77', '2024-04-24', 319);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer84', '2024-05-25', 332);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer91', '2024-06-26', 345);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer01', '2024-07-27', 358);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer08', '2024-08-28', 371);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer15', '2024-09-01', 384);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer22', '2024-10-02', 397);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer29', '2024-11-03', 410);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer36', '2024-12-04', 423);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer43', '2024-01-05', 436);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer50', '2024-02-06', 449);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer57', '2024-03-07', 462);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer64', '2024-04-08', 475);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer71', '2024-05-09', 488);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer78', '2024-06-10', 1);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer85', '2024-07-11', 14);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer92', '2024-08-12', 27);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer02', '2024-09-13', 40);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer09', '2024-10-14', 53);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer16', '2024-11-15', 66);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer23', '2024-12-16', 79);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer30', '2024-01-17', 92);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer37', '2024-02-18', 105);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer44', '2024-03-19', 118);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer51', '2024-04-20', 131);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer58', '2024-05-21', 144);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer65', '2024-06-22', 157);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer72', '2024-07-23', 170);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer79', '2024-08-24', 183);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer86', '2024-09-25', 196);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer93', '2024-10-26', 209);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer03', '2024-11-27', 222);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer10', '2024-12-28', 235);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer17', '2024-01-01', 248);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer24', '2024-02-02', 261);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer31', '2024-03-03', 274);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer38', '2024-04-04', 287);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer45', '2024-05-05', 300);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer52', '2024-06-06', 313);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer59', '2024-07-07', 326);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer66', '2024-08-08', 339);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer73', '2024-09-09', 352);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer80', '2024-10-10', 365);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer87', '2024-11-11', 378);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer94', '2024-12-12', 391);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer04', '2024-01-13', 404);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer11', '2024-02-14', 417);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer18', '2024-03-15', 430);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer25', '2024-04-16', 443);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer32', '2024-05-17', 456);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer39', '2024-06-18', 469);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer46', '2024-07-19', 482);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer53', '2024-08-20', 495);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer60', '2024-09-21', 8);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer67', '2024-10-22', 21);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer74', '2024-11-23', 34);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer81', '2024-12-24', 47);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer88', '2024-01-25', 60);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer95', '2024-02-26', 73);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer05', '2024-03-27', 86);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer12', '2024-04-28', 99);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer19', '2024-05-01', 112);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer26', '2024-06-02', 125);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer33', '2024-07-03', 138);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer40', '2024-08-04', 151);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer47', '2024-09-05', 164);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer54', '2024-10-06', 177);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer61', '2024-11-07', 190);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer68', '2024-12-08', 203);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer75', '2024-01-09', 216);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer82', '2024-02-10', 229);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer89', '2024-03-11', 242);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer96', '2024-04-12', 255);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer06', '2024-05-13', 268);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer13', '2024-06-14', 281);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer20', '2024-07-15', 294);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer27', '2024-08-16', 307);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer34', '2024-09-17', 320);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer41', '2024-10-18', 333);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer48', '2024-11-19', 346);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer55', '2024-12-20', 359);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer62', '2024-01-21', 372);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer69', '2024-02-22', 385);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer76', '2024-03-23', 398);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer83', '2024-04-24', 411);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer90', '2024-05-25', 424);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer00', '2024-06-26', 437);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer07', '2024-07-27', 450);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer14', '2024-08-28', 463);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer21', '2024-09-01', 476);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer28', '2024-10-02', 489);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer35', '2024-11-03', 2);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer42', '2024-12-04', 15);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer49', '2024-01-05', 28);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer56', '2024-02-06', 41);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer63', '2024-03-07', 54);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer70', '2024-04-08', 67);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer77', '2024-05-09', 80);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer84', '2024-06-10', 93);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer91', '2024-07-11', 106);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer01', '2024-08-12', 119);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer08', '2024-09-13', 132);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer15', '2024-10-14', 145);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer22', '2024-11-15', 158);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer29', '2024-12-16', 171);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer36', '2024-01-17', 184);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer43', '2024-02-18', 197);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer50', '2024-03-19', 210);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer57', '2024-04-20', 223);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer64', '2024-05-21', 236);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer71', '2024-06-22', 249);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer78', '2024-07-23', 262);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer85', '2024-08-24', 275);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer92', '2024-09-25', 288);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer02', '2024-10-26', 301);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer09', '2024-11-27', 314);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer16', '2024-12-28', 327);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer23', '2024-01-01', 340);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer30', '2024-02-02', 353);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer37', '2024-03-03', 366);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer44', '2024-04-04', 379);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer51', '2024-05-05', 392);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer58', '2024-06-06', 405);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer65', '2024-07-07', 418);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer72', '2024-08-08', 431);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer79', '2024-09-09', 444);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer86', '2024-10-10', 457);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer93', '2024-11-11', 470);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer03', '2024-12-12', 483);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer10', '2024-01-13', 496);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer17', '2024-02-14', 9);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer24', '2024-03-15', 22);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer31', '2024-04-16', 35);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer38', '2024-05-17', 48);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer45', '2024-06-18', 61);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer52', '2024-07-19', 74);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer59', '2024-08-20', 87);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer66', '2024-09-21', 100);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer73', '2024-10-22', 113);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer80', '2024-11-23', 126);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer87', '2024-12-24', 139);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer94', '2024-01-25', 152);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer04', '2024-02-26', 165);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer11', '2024-03-27', 178);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer18', '2024-04-28', 191);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer25', '2024-05-01', 204);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer32', '2024-06-02', 217);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer39', '2024-07-03', 230);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer46', '2024-08-04', 243);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer53', '2024-09-05', 256);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer60', '2024-10-06', 269);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer67', '2024-11-07', 282);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer74', '2024-12-08', 295);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer81', '2024-01-09', 308);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer88', '2024-02-10', 321);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer95', '2024-03-11', 334);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer05', '2024-04-12', 347);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer12', '2024-05-13', 360);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer19', '2024-06-14', 373);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer26', '2024-07-15', 386);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer33', '2024-08-16', 399);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer40', '2024-09-17', 412);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer47', '2024-10-18', 425);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer54', '2024-11-19', 438);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer61', '2024-12-20', 451);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer68', '2024-01-21', 464);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer75', '2024-02-22', 477);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer82', '2024-03-23', 490);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer89', '2024-04-24', 3);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer96', '2024-05-25', 16);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer06', '2024-06-26', 29);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer13', '2024-07-27', 42);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer20', '2024-08-28', 55);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer27', '2024-09-01', 68);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer34', '2024-10-02', 81);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer41', '2024-11-03', 94);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer48', '2024-12-04', 107);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer55', '2024-01-05', 120);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer62', '2024-02-06', 133);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer69', '2024-03-07', 146);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer76', '2024-04-08', 159);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer83', '2024-05-09', 172);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer90', '2024-06-10', 185);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer00', '2024-07-11', 198);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer07', '2024-08-12', 211);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer14', '2024-09-13', 224);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer21', '2024-10-14', 237);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer28', '2024-11-15', 250);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer35', '2024-12-16', 263);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer42', '2024-01-17', 276);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer49', '2024-02-18', 289);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer56', '2024-03-19', 302);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer63', '2024-04-20', 315);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer70', '2024-05-21', 328);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer77', '2024-06-22', 341);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer84', '2024-07-23', 354);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer91', '2024-08-24', 367);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer01', '2024-09-25', 380);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer08', '2024-10-26', 393);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer15', '2024-11-27', 406);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer22', '2024-12-28', 419);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer29', '2024-01-01', 432);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer36', '2024-02-02', 445);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer43', '2024-03-03', 458);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer50', '2024-04-04', 471);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer57', '2024-05-05', 484);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer64', '2024-06-06', 497);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer71', '2024-07-07', 10);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer78', '2024-08-08', 23);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer85', '2024-09-09', 36);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer92', '2024-10-10', 49);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer02', '2024-11-11', 62);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer09', '2024-12-12', 75);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer16', '2024-01-13', 88);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer23', '2024-02-14', 101);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer30', '2024-03-15', 114);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer37', '2024-04-16', 127);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer44', '2024-05-17', 140);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer51', '2024-06-18', 153);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer58', '2024-07-19', 166);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer65', '2024-08-20', 179);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer72', '2024-09-21', 192);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer79', '2024-10-22', 205);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer86', '2024-11-23', 218);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer93', '2024-12-24', 231);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer03', '2024-01-25', 244);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer10', '2024-02-26', 257);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer17', '2024-03-27', 270);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer24', '2024-04-28', 283);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer31', '2024-05-01', 296);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer38', '2024-06-02', 309);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer45', '2024-07-03', 322);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer52', '2024-08-04', 335);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer59', '2024-09-05', 348);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer66', '2024-10-06', 361);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer73', '2024-11-07', 374);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer80', '2024-12-08', 387);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer87', '2024-01-09', 400);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer94', '2024-02-10', 413);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer04', '2024-03-11', 426);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer11', '2024-04-12', 439);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer18', '2024-05-13', 452);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer25', '2024-06-14', 465);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer32', '2024-07-15', 478);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer39', '2024-08-16', 491);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer46', '2024-09-17', 4);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer53', '2024-10-18', 17);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer60', '2024-11-19', 30);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer67', '2024-12-20', 43);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer74', '2024-01-21', 56);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer81', '2024-02-22', 69);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer88', '2024-03-23', 82);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer95', '2024-04-24', 95);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer05', '2024-05-25', 108);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer12', '2024-06-26', 121);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer19', '2024-07-27', 134);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer26', '2024-08-28', 147);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer33', '2024-09-01', 160);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer40', '2024-10-02', 173);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer47', '2024-11-03', 186);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer54', '2024-12-04', 199);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer61', '2024-01-05', 212);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer68', '2024-02-06', 225);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer75', '2024-03-07', 238);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer82', '2024-04-08', 251);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer89', '2024-05-09', 264);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer96', '2024-06-10', 277);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer06', '2024-07-11', 290);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer13', '2024-08-12', 303);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer20', '2024-09-13', 316);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer27', '2024-10-14', 329);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer34', '2024-11-15', 342);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer41', '2024-12-16', 355);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer48', '2024-01-17', 368);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer55', '2024-02-18', 381);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer62', '2024-03-19', 394);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer69', '2024-04-20', 407);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer76', '2024-05-21', 420);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer83', '2024-06-22', 433);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer90', '2024-07-23', 446);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer00', '2024-08-24', 459);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer07', '2024-09-25', 472);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer14', '2024-10-26', 485);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer21', '2024-11-27', 498);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer28', '2024-12-28', 11);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer35', '2024-01-01', 24);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer42', '2024-02-02', 37);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer49', '2024-03-03', 50);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer56', '2024-04-04', 63);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer63', '2024-05-05', 76);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer70', '2024-06-06', 89);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer77', '2024-07-07', 102);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer84', '2024-08-08', 115);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer91', '2024-09-09', 128);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer01', '2024-10-10', 141);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer08', '2024-11-11', 154);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer15', '2024-12-12', 167);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer22', '2024-01-13', 180);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer29', '2024-02-14', 193);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer36', '2024-03-15', 206);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer43', '2024-04-16', 219);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer50', '2024-05-17', 232);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer57', '2024-06-18', 245);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer64', '2024-07-19', 258);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer71', '2024-08-20', 271);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer78', '2024-09-21', 284);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer85', '2024-10-22', 297);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer92', '2024-11-23', 310);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer02', '2024-12-24', 323);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer09', '2024-01-25', 336);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer16', '2024-02-26', 349);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer23', '2024-03-27', 362);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer30', '2024-04-28', 375);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer37', '2024-05-01', 388);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer44', '2024-06-02', 401);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer51', '2024-07-03', 414);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer58', '2024-08-04', 427);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer65', '2024-09-05', 440);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer72', '2024-10-06', 453);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer79', '2024-11-07', 466);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer86', '2024-12-08', 479);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer93', '2024-01-09', 492);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer03', '2024-02-10', 5);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer10', '2024-03-11', 18);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer17', '2024-04-12', 31);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer24', '2024-05-13', 44);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer31', '2024-06-14', 57);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer38', '2024-07-15', 70);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer45', '2024-08-16', 83);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer52', '2024-09-17', 96);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer59', '2024-10-18', 109);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer66', '2024-11-19', 122);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer73', '2024-12-20', 135);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer80', '2024-01-21', 148);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer87', '2024-02-22', 161);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer94', '2024-03-23', 174);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer04', '2024-04-24', 187);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer11', '2024-05-25', 200);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer18', '2024-06-26', 213);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer25', '2024-07-27', 226);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer32', '2024-08-28', 239);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer39', '2024-09-01', 252);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer46', '2024-10-02', 265);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer53', '2024-11-03', 278);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer60', '2024-12-04', 291);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer67', '2024-01-05', 304);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer74', '2024-02-06', 317);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer81', '2024-03-07', 330);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer88', '2024-04-08', 343);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer95', '2024-05-09', 356);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer05', '2024-06-10', 369);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer12', '2024-07-11', 382);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer19', '2024-08-12', 395);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer26', '2024-09-13', 408);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer33', '2024-10-14', 421);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer40', '2024-11-15', 434);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer47', '2024-12-16', 447);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer54', '2024-01-17', 460);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer61', '2024-02-18', 473);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer68', '2024-03-19', 486);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer75', '2024-04-20', 499);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer82', '2024-05-21', 12);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer89', '2024-06-22', 25);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer96', '2024-07-23', 38);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer06', '2024-08-24', 51);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer13', '2024-09-25', 64);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer20', '2024-10-26', 77);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer27', '2024-11-27', 90);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer34', '2024-12-28', 103);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer41', '2024-01-01', 116);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer48', '2024-02-02', 129);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer55', '2024-03-03', 142);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer62', '2024-04-04', 155);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer69', '2024-05-05', 168);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer76', '2024-06-06', 181);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer83', '2024-07-07', 194);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer90', '2024-08-08', 207);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer00', '2024-09-09', 220);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer07', '2024-10-10', 233);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer14', '2024-11-11', 246);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer21', '2024-12-12', 259);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer28', '2024-01-13', 272);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer35', '2024-02-14', 285);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer42', '2024-03-15', 298);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer49', '2024-04-16', 311);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer56', '2024-05-17', 324);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer63', '2024-06-18', 337);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer70', '2024-07-19', 350);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer77', '2024-08-20', 363);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer84', '2024-09-21', 376);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer91', '2024-10-22', 389);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer01', '2024-11-23', 402);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer08', '2024-12-24', 415);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer15', '2024-01-25', 428);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer22', '2024-02-26', 441);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer29', '2024-03-27', 454);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer36', '2024-04-28', 467);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer43', '2024-05-01', 480);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer50', '2024-06-02', 493);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer57', '2024-07-03', 6);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer64', '2024-08-04', 19);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer71', '2024-09-05', 32);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer78', '2024-10-06', 45);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer85', '2024-11-07', 58);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer92', '2024-12-08', 71);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer02', '2024-01-09', 84);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer09', '2024-02-10', 97);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer16', '2024-03-11', 110);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer23', '2024-04-12', 123);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer30', '2024-05-13', 136);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer37', '2024-06-14', 149);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer44', '2024-07-15', 162);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer51', '2024-08-16', 175);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer58', '2024-09-17', 188);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer65', '2024-10-18', 201);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer72', '2024-11-19', 214);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer79', '2024-12-20', 227);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer86', '2024-01-21', 240);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer93', '2024-02-22', 253);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer03', '2024-03-23', 266);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer10', '2024-04-24', 279);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer17', '2024-05-25', 292);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer24', '2024-06-26', 305);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer31', '2024-07-27', 318);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer38', '2024-08-28', 331);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer45', '2024-09-01', 344);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer52', '2024-10-02', 357);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer59', '2024-11-03', 370);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer66', '2024-12-04', 383);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer73', '2024-01-05', 396);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer80', '2024-02-06', 409);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer87', '2024-03-07', 422);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer94', '2024-04-08', 435);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer04', '2024-05-09', 448);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer11', '2024-06-10', 461);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer18', '2024-07-11', 474);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer25', '2024-08-12', 487);
+INSERT INTO orders (customer, order_date, amount) VALUES ('customer32', '2024-09-13', 0);
+COMMIT;
@@ -0,0 +1,6 @@
+-- the bodies do not fit in a cell and spill to one or several overflow pages
+CREATE TABLE documents (id INTEGER PRIMARY KEY, title text, body text);
+INSERT INTO documents VALUES (1, 'short', 'fits in the cell');
+INSERT INTO documents VALUES (2, 'ten kb', printf('%.10240c', 'a'));
+INSERT INTO documents VALUES (3, 'just above the threshold', printf('%.4070c', 'b'));
+INSERT INTO documents VALUES (4, 'mixed', replace(printf('%.3000c', 'x'), 'x', 'abc'));
@@ -0,0 +1,5 @@
+-- the indexed keys spill to overflow pages, in the leaf and in the interior index pages
+CREATE TABLE notes (id INTEGER PRIMARY KEY, key text, label text);
+CREATE INDEX idx_notes_key ON notes (key);
+WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 300)
+INSERT INTO notes (key, label) SELECT printf('%.3000c', 'k') || i, 'note ' || i FROM n;
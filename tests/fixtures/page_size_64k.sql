@@ -0,0 +1,6 @@
+-- the page size is stored as 1 in the header
+PRAGMA page_size = 65536;
+CREATE TABLE items (id INTEGER PRIMARY KEY, name text, price integer);
+CREATE INDEX idx_items_name ON items (name);
+WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 5000)
+INSERT INTO items (name, price) SELECT 'item ' || i, i * 3 FROM n;
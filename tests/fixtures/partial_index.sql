@@ -0,0 +1,1004 @@
+CREATE TABLE members (id integer primary key, email text, active integer);
+CREATE INDEX idx_members_active_email ON members (email) WHERE active = 1;
+BEGIN;
+INSERT INTO members (email, active) VALUES ('user1@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user2@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user3@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user4@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user5@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user6@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user7@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user8@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user9@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user10@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user11@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user12@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user13@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user14@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user15@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user16@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user17@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user18@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user19@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user20@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user21@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user22@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user23@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user24@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user25@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user26@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user27@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user28@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user29@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user30@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user31@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user32@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user33@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user34@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user35@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user36@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user37@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user38@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user39@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user40@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user41@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user42@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user43@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user44@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user45@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user46@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user47@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user48@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user49@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user50@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user51@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user52@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user53@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user54@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user55@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user56@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user57@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user58@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user59@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user60@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user61@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user62@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user63@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user64@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user65@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user66@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user67@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user68@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user69@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user70@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user71@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user72@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user73@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user74@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user75@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user76@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user77@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user78@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user79@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user80@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user81@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user82@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user83@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user84@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user85@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user86@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user87@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user88@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user89@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user90@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user91@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user92@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user93@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user94@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user95@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user96@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user97@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user98@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user99@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user100@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user101@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user102@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user103@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user104@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user105@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user106@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user107@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user108@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user109@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user110@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user111@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user112@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user113@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user114@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user115@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user116@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user117@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user118@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user119@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user120@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user121@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user122@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user123@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user124@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user125@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user126@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user127@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user128@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user129@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user130@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user131@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user132@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user133@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user134@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user135@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user136@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user137@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user138@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user139@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user140@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user141@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user142@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user143@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user144@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user145@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user146@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user147@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user148@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user149@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user150@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user151@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user152@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user153@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user154@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user155@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user156@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user157@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user158@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user159@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user160@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user161@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user162@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user163@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user164@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user165@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user166@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user167@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user168@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user169@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user170@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user171@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user172@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user173@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user174@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user175@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user176@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user177@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user178@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user179@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user180@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user181@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user182@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user183@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user184@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user185@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user186@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user187@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user188@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user189@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user190@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user191@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user192@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user193@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user194@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user195@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user196@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user197@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user198@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user199@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user200@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user201@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user202@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user203@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user204@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user205@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user206@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user207@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user208@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user209@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user210@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user211@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user212@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user213@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user214@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user215@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user216@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user217@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user218@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user219@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user220@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user221@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user222@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user223@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user224@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user225@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user226@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user227@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user228@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user229@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user230@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user231@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user232@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user233@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user234@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user235@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user236@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user237@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user238@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user239@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user240@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user241@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user242@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user243@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user244@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user245@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user246@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user247@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user248@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user249@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user250@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user251@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user252@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user253@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user254@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user255@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user256@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user257@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user258@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user259@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user260@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user261@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user262@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user263@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user264@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user265@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user266@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user267@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user268@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user269@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user270@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user271@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user272@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user273@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user274@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user275@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user276@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user277@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user278@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user279@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user280@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user281@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user282@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user283@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user284@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user285@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user286@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user287@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user288@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user289@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user290@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user291@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user292@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user293@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user294@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user295@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user296@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user297@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user298@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user299@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user300@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user301@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user302@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user303@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user304@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user305@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user306@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user307@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user308@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user309@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user310@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user311@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user312@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user313@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user314@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user315@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user316@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user317@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user318@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user319@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user320@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user321@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user322@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user323@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user324@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user325@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user326@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user327@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user328@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user329@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user330@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user331@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user332@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user333@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user334@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user335@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user336@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user337@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user338@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user339@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user340@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user341@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user342@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user343@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user344@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user345@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user346@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user347@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user348@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user349@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user350@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user351@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user352@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user353@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user354@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user355@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user356@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user357@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user358@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user359@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user360@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user361@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user362@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user363@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user364@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user365@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user366@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user367@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user368@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user369@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user370@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user371@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user372@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user373@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user374@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user375@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user376@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user377@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user378@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user379@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user380@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user381@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user382@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user383@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user384@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user385@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user386@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user387@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user388@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user389@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user390@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user391@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user392@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user393@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user394@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user395@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user396@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user397@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user398@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user399@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user400@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user401@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user402@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user403@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user404@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user405@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user406@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user407@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user408@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user409@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user410@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user411@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user412@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user413@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user414@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user415@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user416@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user417@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user418@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user419@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user420@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user421@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user422@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user423@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user424@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user425@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user426@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user427@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user428@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user429@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user430@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user431@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user432@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user433@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user434@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user435@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user436@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user437@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user438@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user439@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user440@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user441@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user442@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user443@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user444@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user445@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user446@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user447@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user448@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user449@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user450@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user451@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user452@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user453@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user454@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user455@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user456@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user457@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user458@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user459@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user460@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user461@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user462@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user463@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user464@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user465@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user466@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user467@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user468@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user469@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user470@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user471@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user472@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user473@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user474@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user475@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user476@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user477@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user478@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user479@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user480@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user481@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user482@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user483@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user484@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user485@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user486@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user487@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user488@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user489@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user490@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user491@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user492@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user493@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user494@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user495@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user496@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user497@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user498@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user499@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user500@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user501@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user502@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user503@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user504@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user505@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user506@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user507@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user508@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user509@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user510@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user511@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user512@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user513@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user514@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user515@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user516@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user517@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user518@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user519@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user520@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user521@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user522@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user523@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user524@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user525@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user526@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user527@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user528@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user529@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user530@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user531@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user532@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user533@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user534@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user535@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user536@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user537@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user538@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user539@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user540@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user541@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user542@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user543@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user544@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user545@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user546@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user547@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user548@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user549@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user550@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user551@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user552@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user553@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user554@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user555@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user556@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user557@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user558@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user559@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user560@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user561@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user562@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user563@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user564@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user565@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user566@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user567@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user568@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user569@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user570@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user571@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user572@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user573@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user574@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user575@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user576@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user577@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user578@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user579@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user580@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user581@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user582@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user583@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user584@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user585@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user586@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user587@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user588@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user589@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user590@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user591@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user592@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user593@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user594@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user595@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user596@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user597@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user598@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user599@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user600@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user601@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user602@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user603@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user604@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user605@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user606@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user607@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user608@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user609@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user610@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user611@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user612@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user613@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user614@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user615@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user616@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user617@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user618@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user619@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user620@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user621@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user622@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user623@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user624@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user625@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user626@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user627@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user628@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user629@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user630@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user631@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user632@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user633@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user634@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user635@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user636@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user637@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user638@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user639@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user640@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user641@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user642@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user643@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user644@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user645@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user646@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user647@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user648@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user649@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user650@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user651@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user652@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user653@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user654@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user655@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user656@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user657@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user658@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user659@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user660@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user661@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user662@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user663@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user664@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user665@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user666@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user667@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user668@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user669@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user670@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user671@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user672@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user673@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user674@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user675@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user676@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user677@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user678@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user679@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user680@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user681@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user682@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user683@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user684@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user685@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user686@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user687@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user688@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user689@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user690@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user691@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user692@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user693@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user694@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user695@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user696@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user697@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user698@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user699@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user700@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user701@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user702@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user703@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user704@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user705@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user706@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user707@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user708@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user709@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user710@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user711@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user712@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user713@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user714@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user715@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user716@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user717@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user718@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user719@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user720@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user721@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user722@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user723@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user724@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user725@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user726@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user727@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user728@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user729@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user730@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user731@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user732@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user733@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user734@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user735@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user736@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user737@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user738@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user739@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user740@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user741@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user742@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user743@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user744@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user745@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user746@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user747@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user748@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user749@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user750@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user751@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user752@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user753@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user754@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user755@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user756@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user757@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user758@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user759@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user760@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user761@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user762@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user763@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user764@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user765@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user766@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user767@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user768@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user769@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user770@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user771@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user772@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user773@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user774@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user775@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user776@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user777@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user778@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user779@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user780@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user781@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user782@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user783@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user784@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user785@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user786@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user787@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user788@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user789@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user790@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user791@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user792@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user793@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user794@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user795@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user796@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user797@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user798@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user799@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user800@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user801@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user802@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user803@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user804@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user805@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user806@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user807@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user808@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user809@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user810@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user811@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user812@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user813@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user814@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user815@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user816@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user817@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user818@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user819@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user820@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user821@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user822@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user823@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user824@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user825@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user826@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user827@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user828@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user829@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user830@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user831@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user832@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user833@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user834@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user835@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user836@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user837@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user838@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user839@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user840@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user841@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user842@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user843@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user844@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user845@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user846@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user847@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user848@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user849@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user850@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user851@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user852@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user853@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user854@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user855@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user856@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user857@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user858@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user859@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user860@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user861@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user862@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user863@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user864@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user865@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user866@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user867@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user868@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user869@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user870@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user871@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user872@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user873@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user874@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user875@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user876@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user877@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user878@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user879@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user880@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user881@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user882@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user883@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user884@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user885@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user886@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user887@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user888@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user889@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user890@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user891@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user892@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user893@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user894@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user895@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user896@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user897@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user898@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user899@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user900@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user901@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user902@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user903@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user904@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user905@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user906@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user907@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user908@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user909@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user910@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user911@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user912@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user913@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user914@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user915@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user916@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user917@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user918@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user919@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user920@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user921@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user922@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user923@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user924@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user925@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user926@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user927@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user928@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user929@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user930@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user931@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user932@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user933@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user934@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user935@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user936@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user937@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user938@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user939@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user940@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user941@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user942@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user943@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user944@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user945@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user946@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user947@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user948@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user949@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user950@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user951@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user952@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user953@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user954@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user955@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user956@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user957@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user958@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user959@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user960@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user961@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user962@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user963@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user964@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user965@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user966@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user967@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user968@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user969@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user970@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user971@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user972@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user973@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user974@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user975@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user976@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user977@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user978@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user979@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user980@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user981@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user982@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user983@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user984@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user985@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user986@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user987@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user988@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user989@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user990@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user991@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user992@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user993@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user994@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user995@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user996@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user997@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user998@example.com', 0);
+INSERT INTO members (email, active) VALUES ('user999@example.com', 1);
+INSERT INTO members (email, active) VALUES ('user1000@example.com', 0);
+COMMIT;
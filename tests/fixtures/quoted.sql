@@ -0,0 +1,7 @@
+CREATE TABLE "products" ("size range" text, [order] integer, `group` text, "say ""hi""" text, `back``tick` integer);
+INSERT INTO products VALUES ('S-M', 2, 'shirts', 'hello', 10);
+INSERT INTO products VALUES ('L-XL', 1, 'coats', 'bye', 20);
+CREATE TABLE "order details" ("order id" integer, product text);
+INSERT INTO "order details" VALUES (1, 'tea'), (2, 'coffee');
+CREATE TABLE "group" (id integer primary key, [select] text);
+INSERT INTO "group" ([select]) VALUES ('all'), ('none');
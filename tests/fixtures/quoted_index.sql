@@ -0,0 +1,1005 @@
+CREATE TABLE "my table" (id integer primary key, "col" text, other text);
+CREATE INDEX idx_not_null ON "my table" (col) WHERE col IS NOT NULL;
+CREATE INDEX IF NOT EXISTS "idx name" ON "my table" ("col");
+BEGIN;
+INSERT INTO "my table" (col, other) VALUES ('value1', 'other 1');
+INSERT INTO "my table" (col, other) VALUES ('value2', 'other 2');
+INSERT INTO "my table" (col, other) VALUES ('value3', 'other 3');
+INSERT INTO "my table" (col, other) VALUES ('value4', 'other 4');
+INSERT INTO "my table" (col, other) VALUES ('value5', 'other 5');
+INSERT INTO "my table" (col, other) VALUES ('value6', 'other 6');
+INSERT INTO "my table" (col, other) VALUES ('value7', 'other 7');
+INSERT INTO "my table" (col, other) VALUES ('value8', 'other 8');
+INSERT INTO "my table" (col, other) VALUES ('value9', 'other 9');
+INSERT INTO "my table" (col, other) VALUES ('value10', 'other 10');
+INSERT INTO "my table" (col, other) VALUES ('value11', 'other 11');
+INSERT INTO "my table" (col, other) VALUES ('value12', 'other 12');
+INSERT INTO "my table" (col, other) VALUES ('value13', 'other 13');
+INSERT INTO "my table" (col, other) VALUES ('value14', 'other 14');
+INSERT INTO "my table" (col, other) VALUES ('value15', 'other 15');
+INSERT INTO "my table" (col, other) VALUES ('value16', 'other 16');
+INSERT INTO "my table" (col, other) VALUES ('value17', 'other 17');
+INSERT INTO "my table" (col, other) VALUES ('value18', 'other 18');
+INSERT INTO "my table" (col, other) VALUES ('value19', 'other 19');
+INSERT INTO "my table" (col, other) VALUES ('value20', 'other 20');
+INSERT INTO "my table" (col, other) VALUES ('value21', 'other 21');
+INSERT INTO "my table" (col, other) VALUES ('value22', 'other 22');
+INSERT INTO "my table" (col, other) VALUES ('value23', 'other 23');
+INSERT INTO "my table" (col, other) VALUES ('value24', 'other 24');
+INSERT INTO "my table" (col, other) VALUES ('value25', 'other 25');
+INSERT INTO "my table" (col, other) VALUES ('value26', 'other 26');
+INSERT INTO "my table" (col, other) VALUES ('value27', 'other 27');
+INSERT INTO "my table" (col, other) VALUES ('value28', 'other 28');
+INSERT INTO "my table" (col, other) VALUES ('value29', 'other 29');
+INSERT INTO "my table" (col, other) VALUES ('value30', 'other 30');
+INSERT INTO "my table" (col, other) VALUES ('value31', 'other 31');
+INSERT INTO "my table" (col, other) VALUES ('value32', 'other 32');
+INSERT INTO "my table" (col, other) VALUES ('value33', 'other 33');
+INSERT INTO "my table" (col, other) VALUES ('value34', 'other 34');
+INSERT INTO "my table" (col, other) VALUES ('value35', 'other 35');
+INSERT INTO "my table" (col, other) VALUES ('value36', 'other 36');
+INSERT INTO "my table" (col, other) VALUES ('value37', 'other 37');
+INSERT INTO "my table" (col, other) VALUES ('value38', 'other 38');
+INSERT INTO "my table" (col, other) VALUES ('value39', 'other 39');
+INSERT INTO "my table" (col, other) VALUES ('value40', 'other 40');
+INSERT INTO "my table" (col, other) VALUES ('value41', 'other 41');
+INSERT INTO "my table" (col, other) VALUES ('value42', 'other 42');
+INSERT INTO "my table" (col, other) VALUES ('value43', 'other 43');
+INSERT INTO "my table" (col, other) VALUES ('value44', 'other 44');
+INSERT INTO "my table" (col, other) VALUES ('value45', 'other 45');
+INSERT INTO "my table" (col, other) VALUES ('value46', 'other 46');
+INSERT INTO "my table" (col, other) VALUES ('value47', 'other 47');
+INSERT INTO "my table" (col, other) VALUES ('value48', 'other 48');
+INSERT INTO "my table" (col, other) VALUES ('value49', 'other 49');
+INSERT INTO "my table" (col, other) VALUES ('value50', 'other 50');
+INSERT INTO "my table" (col, other) VALUES ('value51', 'other 51');
+INSERT INTO "my table" (col, other) VALUES ('value52', 'other 52');
+INSERT INTO "my table" (col, other) VALUES ('value53', 'other 53');
+INSERT INTO "my table" (col, other) VALUES ('value54', 'other 54');
+INSERT INTO "my table" (col, other) VALUES ('value55', 'other 55');
+INSERT INTO "my table" (col, other) VALUES ('value56', 'other 56');
+INSERT INTO "my table" (col, other) VALUES ('value57', 'other 57');
+INSERT INTO "my table" (col, other) VALUES ('value58', 'other 58');
+INSERT INTO "my table" (col, other) VALUES ('value59', 'other 59');
+INSERT INTO "my table" (col, other) VALUES ('value60', 'other 60');
+INSERT INTO "my table" (col, other) VALUES ('value61', 'other 61');
+INSERT INTO "my table" (col, other) VALUES ('value62', 'other 62');
+INSERT INTO "my table" (col, other) VALUES ('value63', 'other 63');
+INSERT INTO "my table" (col, other) VALUES ('value64', 'other 64');
+INSERT INTO "my table" (col, other) VALUES ('value65', 'other 65');
+INSERT INTO "my table" (col, other) VALUES ('value66', 'other 66');
+INSERT INTO "my table" (col, other) VALUES ('value67', 'other 67');
+INSERT INTO "my table" (col, other) VALUES ('value68', 'other 68');
+INSERT INTO "my table" (col, other) VALUES ('value69', 'other 69');
+INSERT INTO "my table" (col, other) VALUES ('value70', 'other 70');
+INSERT INTO "my table" (col, other) VALUES ('value71', 'other 71');
+INSERT INTO "my table" (col, other) VALUES ('value72', 'other 72');
+INSERT INTO "my table" (col, other) VALUES ('value73', 'other 73');
+INSERT INTO "my table" (col, other) VALUES ('value74', 'other 74');
+INSERT INTO "my table" (col, other) VALUES ('value75', 'other 75');
+INSERT INTO "my table" (col, other) VALUES ('value76', 'other 76');
+INSERT INTO "my table" (col, other) VALUES ('value77', 'other 77');
+INSERT INTO "my table" (col, other) VALUES ('value78', 'other 78');
+INSERT INTO "my table" (col, other) VALUES ('value79', 'other 79');
+INSERT INTO "my table" (col, other) VALUES ('value80', 'other 80');
+INSERT INTO "my table" (col, other) VALUES ('value81', 'other 81');
+INSERT INTO "my table" (col, other) VALUES ('value82', 'other 82');
+INSERT INTO "my table" (col, other) VALUES ('value83', 'other 83');
+INSERT INTO "my table" (col, other) VALUES ('value84', 'other 84');
+INSERT INTO "my table" (col, other) VALUES ('value85', 'other 85');
+INSERT INTO "my table" (col, other) VALUES ('value86', 'other 86');
+INSERT INTO "my table" (col, other) VALUES ('value87', 'other 87');
+INSERT INTO "my table" (col, other) VALUES ('value88', 'other 88');
+INSERT INTO "my table" (col, other) VALUES ('value89', 'other 89');
+INSERT INTO "my table" (col, other) VALUES ('value90', 'other 90');
+INSERT INTO "my table" (col, other) VALUES ('value91', 'other 91');
+INSERT INTO "my table" (col, other) VALUES ('value92', 'other 92');
+INSERT INTO "my table" (col, other) VALUES ('value93', 'other 93');
+INSERT INTO "my table" (col, other) VALUES ('value94', 'other 94');
+INSERT INTO "my table" (col, other) VALUES ('value95', 'other 95');
+INSERT INTO "my table" (col, other) VALUES ('value96', 'other 96');
+INSERT INTO "my table" (col, other) VALUES ('value97', 'other 97');
+INSERT INTO "my table" (col, other) VALUES ('value98', 'other 98');
+INSERT INTO "my table" (col, other) VALUES ('value99', 'other 99');
+INSERT INTO "my table" (col, other) VALUES ('value100', 'other 100');
+INSERT INTO "my table" (col, other) VALUES ('value101', 'other 101');
+INSERT INTO "my table" (col, other) VALUES ('value102', 'other 102');
+INSERT INTO "my table" (col, other) VALUES ('value103', 'other 103');
+INSERT INTO "my table" (col, other) VALUES ('value104', 'other 104');
+INSERT INTO "my table" (col, other) VALUES ('value105', 'other 105');
+INSERT INTO "my table" (col, other) VALUES ('value106', 'other 106');
+INSERT INTO "my table" (col, other) VALUES ('value107', 'other 107');
+INSERT INTO "my table" (col, other) VALUES ('value108', 'other 108');
+INSERT INTO "my table" (col, other) VALUES ('value109', 'other 109');
+INSERT INTO "my table" (col, other) VALUES ('value110', 'other 110');
+INSERT INTO "my table" (col, other) VALUES ('value111', 'other 111');
+INSERT INTO "my table" (col, other) VALUES ('value112', 'other 112');
+INSERT INTO "my table" (col, other) VALUES ('value113', 'other 113');
+INSERT INTO "my table" (col, other) VALUES ('value114', 'other 114');
+INSERT INTO "my table" (col, other) VALUES ('value115', 'other 115');
+INSERT INTO "my table" (col, other) VALUES ('value116', 'other 116');
+INSERT INTO "my table" (col, other) VALUES ('value117', 'other 117');
+INSERT INTO "my table" (col, other) VALUES ('value118', 'other 118');
+INSERT INTO "my table" (col, other) VALUES ('value119', 'other 119');
+INSERT INTO "my table" (col, other) VALUES ('value120', 'other 120');
+INSERT INTO "my table" (col, other) VALUES ('value121', 'other 121');
+INSERT INTO "my table" (col, other) VALUES ('value122', 'other 122');
+INSERT INTO "my table" (col, other) VALUES ('value123', 'other 123');
+INSERT INTO "my table" (col, other) VALUES ('value124', 'other 124');
+INSERT INTO "my table" (col, other) VALUES ('value125', 'other 125');
+INSERT INTO "my table" (col, other) VALUES ('value126', 'other 126');
+INSERT INTO "my table" (col, other) VALUES ('value127', 'other 127');
+INSERT INTO "my table" (col, other) VALUES ('value128', 'other 128');
+INSERT INTO "my table" (col, other) VALUES ('value129', 'other 129');
+INSERT INTO "my table" (col, other) VALUES ('value130', 'other 130');
+INSERT INTO "my table" (col, other) VALUES ('value131', 'other 131');
+INSERT INTO "my table" (col, other) VALUES ('value132', 'other 132');
+INSERT INTO "my table" (col, other) VALUES ('value133', 'other 133');
+INSERT INTO "my table" (col, other) VALUES ('value134', 'other 134');
+INSERT INTO "my table" (col, other) VALUES ('value135', 'other 135');
+INSERT INTO "my table" (col, other) VALUES ('value136', 'other 136');
+INSERT INTO "my table" (col, other) VALUES ('value137', 'other 137');
+INSERT INTO "my table" (col, other) VALUES ('value138', 'other 138');
+INSERT INTO "my table" (col, other) VALUES ('value139', 'other 139');
+INSERT INTO "my table" (col, other) VALUES ('value140', 'other 140');
+INSERT INTO "my table" (col, other) VALUES ('value141', 'other 141');
+INSERT INTO "my table" (col, other) VALUES ('value142', 'other 142');
+INSERT INTO "my table" (col, other) VALUES ('value143', 'other 143');
+INSERT INTO "my table" (col, other) VALUES ('value144', 'other 144');
+INSERT INTO "my table" (col, other) VALUES ('value145', 'other 145');
+INSERT INTO "my table" (col, other) VALUES ('value146', 'other 146');
+INSERT INTO "my table" (col, other) VALUES ('value147', 'other 147');
+INSERT INTO "my table" (col, other) VALUES ('value148', 'other 148');
+INSERT INTO "my table" (col, other) VALUES ('value149', 'other 149');
+INSERT INTO "my table" (col, other) VALUES ('value150', 'other 150');
+INSERT INTO "my table" (col, other) VALUES ('value151', 'other 151');
+INSERT INTO "my table" (col, other) VALUES ('value152', 'other 152');
+INSERT INTO "my table" (col, other) VALUES ('value153', 'other 153');
+INSERT INTO "my table" (col, other) VALUES ('value154', 'other 154');
+INSERT INTO "my table" (col, other) VALUES ('value155', 'other 155');
+INSERT INTO "my table" (col, other) VALUES ('value156', 'other 156');
+INSERT INTO "my table" (col, other) VALUES ('value157', 'other 157');
+INSERT INTO "my table" (col, other) VALUES ('value158', 'other 158');
+INSERT INTO "my table" (col, other) VALUES ('value159', 'other 159');
+INSERT INTO "my table" (col, other) VALUES ('value160', 'other 160');
+INSERT INTO "my table" (col, other) VALUES ('value161', 'other 161');
+INSERT INTO "my table" (col, other) VALUES ('value162', 'other 162');
+INSERT INTO "my table" (col, other) VALUES ('value163', 'other 163');
+INSERT INTO "my table" (col, other) VALUES ('value164', 'other 164');
+INSERT INTO "my table" (col, other) VALUES ('value165', 'other 165');
+INSERT INTO "my table" (col, other) VALUES ('value166', 'other 166');
+INSERT INTO "my table" (col, other) VALUES ('value167', 'other 167');
+INSERT INTO "my table" (col, other) VALUES ('value168', 'other 168');
+INSERT INTO "my table" (col, other) VALUES ('value169', 'other 169');
+INSERT INTO "my table" (col, other) VALUES ('value170', 'other 170');
+INSERT INTO "my table" (col, other) VALUES ('value171', 'other 171');
+INSERT INTO "my table" (col, other) VALUES ('value172', 'other 172');
+INSERT INTO "my table" (col, other) VALUES ('value173', 'other 173');
+INSERT INTO "my table" (col, other) VALUES ('value174', 'other 174');
+INSERT INTO "my table" (col, other) VALUES ('value175', 'other 175');
+INSERT INTO "my table" (col, other) VALUES ('value176', 'other 176');
+INSERT INTO "my table" (col, other) VALUES ('value177', 'other 177');
+INSERT INTO "my table" (col, other) VALUES ('value178', 'other 178');
+INSERT INTO "my table" (col, other) VALUES ('value179', 'other 179');
+INSERT INTO "my table" (col, other) VALUES ('value180', 'other 180');
+INSERT INTO "my table" (col, other) VALUES ('value181', 'other 181');
+INSERT INTO "my table" (col, other) VALUES ('value182', 'other 182');
+INSERT INTO "my table" (col, other) VALUES ('value183', 'other 183');
+INSERT INTO "my table" (col, other) VALUES ('value184', 'other 184');
+INSERT INTO "my table" (col, other) VALUES ('value185', 'other 185');
+INSERT INTO "my table" (col, other) VALUES ('value186', 'other 186');
+INSERT INTO "my table" (col, other) VALUES ('value187', 'other 187');
+INSERT INTO "my table" (col, other) VALUES ('value188', 'other 188');
+INSERT INTO "my table" (col, other) VALUES ('value189', 'other 189');
+INSERT INTO "my table" (col, other) VALUES ('value190', 'other 190');
+INSERT INTO "my table" (col, other) VALUES ('value191', 'other 191');
+INSERT INTO "my table" (col, other) VALUES ('value192', 'other 192');
+INSERT INTO "my table" (col, other) VALUES ('value193', 'other 193');
+INSERT INTO "my table" (col, other) VALUES ('value194', 'other 194');
+INSERT INTO "my table" (col, other) VALUES ('value195', 'other 195');
+INSERT INTO "my table" (col, other) VALUES ('value196', 'other 196');
+INSERT INTO "my table" (col, other) VALUES ('value197', 'other 197');
+INSERT INTO "my table" (col, other) VALUES ('value198', 'other 198');
+INSERT INTO "my table" (col, other) VALUES ('value199', 'other 199');
+INSERT INTO "my table" (col, other) VALUES ('value200', 'other 200');
+INSERT INTO "my table" (col, other) VALUES ('value201', 'other 201');
+INSERT INTO "my table" (col, other) VALUES ('value202', 'other 202');
+INSERT INTO "my table" (col, other) VALUES ('value203', 'other 203');
+INSERT INTO "my table" (col, other) VALUES ('value204', 'other 204');
+INSERT INTO "my table" (col, other) VALUES ('value205', 'other 205');
+INSERT INTO "my table" (col, other) VALUES ('value206', 'other 206');
+INSERT INTO "my table" (col, other) VALUES ('value207', 'other 207');
+INSERT INTO "my table" (col, other) VALUES ('value208', 'other 208');
+INSERT INTO "my table" (col, other) VALUES ('value209', 'other 209');
+INSERT INTO "my table" (col, other) VALUES ('value210', 'other 210');
+INSERT INTO "my table" (col, other) VALUES ('value211', 'other 211');
+INSERT INTO "my table" (col, other) VALUES ('value212', 'other 212');
+INSERT INTO "my table" (col, other) VALUES ('value213', 'other 213');
+INSERT INTO "my table" (col, other) VALUES ('value214', 'other 214');
+INSERT INTO "my table" (col, other) VALUES ('value215', 'other 215');
+INSERT INTO "my table" (col, other) VALUES ('value216', 'other 216');
+INSERT INTO "my table" (col, other) VALUES ('value217', 'other 217');
+INSERT INTO "my table" (col, other) VALUES ('value218', 'other 218');
+INSERT INTO "my table" (col, other) VALUES ('value219', 'other 219');
+INSERT INTO "my table" (col, other) VALUES ('value220', 'other 220');
+INSERT INTO "my table" (col, other) VALUES ('value221', 'other 221');
+INSERT INTO "my table" (col, other) VALUES ('value222', 'other 222');
+INSERT INTO "my table" (col, other) VALUES ('value223', 'other 223');
+INSERT INTO "my table" (col, other) VALUES ('value224', 'other 224');
+INSERT INTO "my table" (col, other) VALUES ('value225', 'other 225');
+INSERT INTO "my table" (col, other) VALUES ('value226', 'other 226');
+INSERT INTO "my table" (col, other) VALUES ('value227', 'other 227');
+INSERT INTO "my table" (col, other) VALUES ('value228', 'other 228');
+INSERT INTO "my table" (col, other) VALUES ('value229', 'other 229');
+INSERT INTO "my table" (col, other) VALUES ('value230', 'other 230');
+INSERT INTO "my table" (col, other) VALUES ('value231', 'other 231');
+INSERT INTO "my table" (col, other) VALUES ('value232', 'other 232');
+INSERT INTO "my table" (col, other) VALUES ('value233', 'other 233');
+INSERT INTO "my table" (col, other) VALUES ('value234', 'other 234');
+INSERT INTO "my table" (col, other) VALUES ('value235', 'other 235');
+INSERT INTO "my table" (col, other) VALUES ('value236', 'other 236');
+INSERT INTO "my table" (col, other) VALUES ('value237', 'other 237');
+INSERT INTO "my table" (col, other) VALUES ('value238', 'other 238');
+INSERT INTO "my table" (col, other) VALUES ('value239', 'other 239');
+INSERT INTO "my table" (col, other) VALUES ('value240', 'other 240');
+INSERT INTO "my table" (col, other) VALUES ('value241', 'other 241');
+INSERT INTO "my table" (col, other) VALUES ('value242', 'other 242');
+INSERT INTO "my table" (col, other) VALUES ('value243', 'other 243');
+INSERT INTO "my table" (col, other) VALUES ('value244', 'other 244');
+INSERT INTO "my table" (col, other) VALUES ('value245', 'other 245');
+INSERT INTO "my table" (col, other) VALUES ('value246', 'other 246');
+INSERT INTO "my table" (col, other) VALUES ('value247', 'other 247');
+INSERT INTO "my table" (col, other) VALUES ('value248', 'other 248');
+INSERT INTO "my table" (col, other) VALUES ('value249', 'other 249');
+INSERT INTO "my table" (col, other) VALUES ('value250', 'other 250');
+INSERT INTO "my table" (col, other) VALUES ('value251', 'other 251');
+INSERT INTO "my table" (col, other) VALUES ('value252', 'other 252');
+INSERT INTO "my table" (col, other) VALUES ('value253', 'other 253');
+INSERT INTO "my table" (col, other) VALUES ('value254', 'other 254');
+INSERT INTO "my table" (col, other) VALUES ('value255', 'other 255');
+INSERT INTO "my table" (col, other) VALUES ('value256', 'other 256');
+INSERT INTO "my table" (col, other) VALUES ('value257', 'other 257');
+INSERT INTO "my table" (col, other) VALUES ('value258', 'other 258');
+INSERT INTO "my table" (col, other) VALUES ('value259', 'other 259');
+INSERT INTO "my table" (col, other) VALUES ('value260', 'other 260');
+INSERT INTO "my table" (col, other) VALUES ('value261', 'other 261');
+INSERT INTO "my table" (col, other) VALUES ('value262', 'other 262');
+INSERT INTO "my table" (col, other) VALUES ('value263', 'other 263');
+INSERT INTO "my table" (col, other) VALUES ('value264', 'other 264');
+INSERT INTO "my table" (col, other) VALUES ('value265', 'other 265');
+INSERT INTO "my table" (col, other) VALUES ('value266', 'other 266');
+INSERT INTO "my table" (col, other) VALUES ('value267', 'other 267');
+INSERT INTO "my table" (col, other) VALUES ('value268', 'other 268');
+INSERT INTO "my table" (col, other) VALUES ('value269', 'other 269');
+INSERT INTO "my table" (col, other) VALUES ('value270', 'other 270');
+INSERT INTO "my table" (col, other) VALUES ('value271', 'other 271');
+INSERT INTO "my table" (col, other) VALUES ('value272', 'other 272');
+INSERT INTO "my table" (col, other) VALUES ('value273', 'other 273');
+INSERT INTO "my table" (col, other) VALUES ('value274', 'other 274');
+INSERT INTO "my table" (col, other) VALUES ('value275', 'other 275');
+INSERT INTO "my table" (col, other) VALUES ('value276', 'other 276');
+INSERT INTO "my table" (col, other) VALUES ('value277', 'other 277');
+INSERT INTO "my table" (col, other) VALUES ('value278', 'other 278');
+INSERT INTO "my table" (col, other) VALUES ('value279', 'other 279');
+INSERT INTO "my table" (col, other) VALUES ('value280', 'other 280');
+INSERT INTO "my table" (col, other) VALUES ('value281', 'other 281');
+INSERT INTO "my table" (col, other) VALUES ('value282', 'other 282');
+INSERT INTO "my table" (col, other) VALUES ('value283', 'other 283');
+INSERT INTO "my table" (col, other) VALUES ('value284', 'other 284');
+INSERT INTO "my table" (col, other) VALUES ('value285', 'other 285');
+INSERT INTO "my table" (col, other) VALUES ('value286', 'other 286');
+INSERT INTO "my table" (col, other) VALUES ('value287', 'other 287');
+INSERT INTO "my table" (col, other) VALUES ('value288', 'other 288');
+INSERT INTO "my table" (col, other) VALUES ('value289', 'other 289');
+INSERT INTO "my table" (col, other) VALUES ('value290', 'other 290');
+INSERT INTO "my table" (col, other) VALUES ('value291', 'other 291');
+INSERT INTO "my table" (col, other) VALUES ('value292', 'other 292');
+INSERT INTO "my table" (col, other) VALUES ('value293', 'other 293');
+INSERT INTO "my table" (col, other) VALUES ('value294', 'other 294');
+INSERT INTO "my table" (col, other) VALUES ('value295', 'other 295');
+INSERT INTO "my table" (col, other) VALUES ('value296', 'other 296');
+INSERT INTO "my table" (col, other) VALUES ('value297', 'other 297');
+INSERT INTO "my table" (col, other) VALUES ('value298', 'other 298');
+INSERT INTO "my table" (col, other) VALUES ('value299', 'other 299');
+INSERT INTO "my table" (col, other) VALUES ('value0', 'other 300');
+INSERT INTO "my table" (col, other) VALUES ('value1', 'other 301');
+INSERT INTO "my table" (col, other) VALUES ('value2', 'other 302');
+INSERT INTO "my table" (col, other) VALUES ('value3', 'other 303');
+INSERT INTO "my table" (col, other) VALUES ('value4', 'other 304');
+INSERT INTO "my table" (col, other) VALUES ('value5', 'other 305');
+INSERT INTO "my table" (col, other) VALUES ('value6', 'other 306');
+INSERT INTO "my table" (col, other) VALUES ('value7', 'other 307');
+INSERT INTO "my table" (col, other) VALUES ('value8', 'other 308');
+INSERT INTO "my table" (col, other) VALUES ('value9', 'other 309');
+INSERT INTO "my table" (col, other) VALUES ('value10', 'other 310');
+INSERT INTO "my table" (col, other) VALUES ('value11', 'other 311');
+INSERT INTO "my table" (col, other) VALUES ('value12', 'other 312');
+INSERT INTO "my table" (col, other) VALUES ('value13', 'other 313');
+INSERT INTO "my table" (col, other) VALUES ('value14', 'other 314');
+INSERT INTO "my table" (col, other) VALUES ('value15', 'other 315');
+INSERT INTO "my table" (col, other) VALUES ('value16', 'other 316');
+INSERT INTO "my table" (col, other) VALUES ('value17', 'other 317');
+INSERT INTO "my table" (col, other) VALUES ('value18', 'other 318');
+INSERT INTO "my table" (col, other) VALUES ('value19', 'other 319');
+INSERT INTO "my table" (col, other) VALUES ('value20', 'other 320');
+INSERT INTO "my table" (col, other) VALUES ('value21', 'other 321');
+INSERT INTO "my table" (col, other) VALUES ('value22', 'other 322');
+INSERT INTO "my table" (col, other) VALUES ('value23', 'other 323');
+INSERT INTO "my table" (col, other) VALUES ('value24', 'other 324');
+INSERT INTO "my table" (col, other) VALUES ('value25', 'other 325');
+INSERT INTO "my table" (col, other) VALUES ('value26', 'other 326');
+INSERT INTO "my table" (col, other) VALUES ('value27', 'other 327');
+INSERT INTO "my table" (col, other) VALUES ('value28', 'other 328');
+INSERT INTO "my table" (col, other) VALUES ('value29', 'other 329');
+INSERT INTO "my table" (col, other) VALUES ('value30', 'other 330');
+INSERT INTO "my table" (col, other) VALUES ('value31', 'other 331');
+INSERT INTO "my table" (col, other) VALUES ('value32', 'other 332');
+INSERT INTO "my table" (col, other) VALUES ('value33', 'other 333');
+INSERT INTO "my table" (col, other) VALUES ('value34', 'other 334');
+INSERT INTO "my table" (col, other) VALUES ('value35', 'other 335');
+INSERT INTO "my table" (col, other) VALUES ('value36', 'other 336');
+INSERT INTO "my table" (col, other) VALUES ('value37', 'other 337');
+INSERT INTO "my table" (col, other) VALUES ('value38', 'other 338');
+INSERT INTO "my table" (col, other) VALUES ('value39', 'other 339');
+INSERT INTO "my table" (col, other) VALUES ('value40', 'other 340');
+INSERT INTO "my table" (col, other) VALUES ('value41', 'other 341');
+INSERT INTO "my table" (col, other) VALUES ('value42', 'other 342');
+INSERT INTO "my table" (col, other) VALUES ('value43', 'other 343');
+INSERT INTO "my table" (col, other) VALUES ('value44', 'other 344');
+INSERT INTO "my table" (col, other) VALUES ('value45', 'other 345');
+INSERT INTO "my table" (col, other) VALUES ('value46', 'other 346');
+INSERT INTO "my table" (col, other) VALUES ('value47', 'other 347');
+INSERT INTO "my table" (col, other) VALUES ('value48', 'other 348');
+INSERT INTO "my table" (col, other) VALUES ('value49', 'other 349');
+INSERT INTO "my table" (col, other) VALUES ('value50', 'other 350');
+INSERT INTO "my table" (col, other) VALUES ('value51', 'other 351');
+INSERT INTO "my table" (col, other) VALUES ('value52', 'other 352');
+INSERT INTO "my table" (col, other) VALUES ('value53', 'other 353');
+INSERT INTO "my table" (col, other) VALUES ('value54', 'other 354');
+INSERT INTO "my table" (col, other) VALUES ('value55', 'other 355');
+INSERT INTO "my table" (col, other) VALUES ('value56', 'other 356');
+INSERT INTO "my table" (col, other) VALUES ('value57', 'other 357');
+INSERT INTO "my table" (col, other) VALUES ('value58', 'other 358');
+INSERT INTO "my table" (col, other) VALUES ('value59', 'other 359');
+INSERT INTO "my table" (col, other) VALUES ('value60', 'other 360');
+INSERT INTO "my table" (col, other) VALUES ('value61', 'other 361');
+INSERT INTO "my table" (col, other) VALUES ('value62', 'other 362');
+INSERT INTO "my table" (col, other) VALUES ('value63', 'other 363');
+INSERT INTO "my table" (col, other) VALUES ('value64', 'other 364');
+INSERT INTO "my table" (col, other) VALUES ('value65', 'other 365');
+INSERT INTO "my table" (col, other) VALUES ('value66', 'other 366');
+INSERT INTO "my table" (col, other) VALUES ('value67', 'other 367');
+INSERT INTO "my table" (col, other) VALUES ('value68', 'other 368');
+INSERT INTO "my table" (col, other) VALUES ('value69', 'other 369');
+INSERT INTO "my table" (col, other) VALUES ('value70', 'other 370');
+INSERT INTO "my table" (col, other) VALUES ('value71', 'other 371');
+INSERT INTO "my table" (col, other) VALUES ('value72', 'other 372');
+INSERT INTO "my table" (col, other) VALUES ('value73', 'other 373');
+INSERT INTO "my table" (col, other) VALUES ('value74', 'other 374');
+INSERT INTO "my table" (col, other) VALUES ('value75', 'other 375');
+INSERT INTO "my table" (col, other) VALUES ('value76', 'other 376');
+INSERT INTO "my table" (col, other) VALUES ('value77', 'other 377');
+INSERT INTO "my table" (col, other) VALUES ('value78', 'other 378');
+INSERT INTO "my table" (col, other) VALUES ('value79', 'other 379');
+INSERT INTO "my table" (col, other) VALUES ('value80', 'other 380');
+INSERT INTO "my table" (col, other) VALUES ('value81', 'other 381');
+INSERT INTO "my table" (col, other) VALUES ('value82', 'other 382');
+INSERT INTO "my table" (col, other) VALUES ('value83', 'other 383');
+INSERT INTO "my table" (col, other) VALUES ('value84', 'other 384');
+INSERT INTO "my table" (col, other) VALUES ('value85', 'other 385');
+INSERT INTO "my table" (col, other) VALUES ('value86', 'other 386');
+INSERT INTO "my table" (col, other) VALUES ('value87', 'other 387');
+INSERT INTO "my table" (col, other) VALUES ('value88', 'other 388');
+INSERT INTO "my table" (col, other) VALUES ('value89', 'other 389');
+INSERT INTO "my table" (col, other) VALUES ('value90', 'other 390');
+INSERT INTO "my table" (col, other) VALUES ('value91', 'other 391');
+INSERT INTO "my table" (col, other) VALUES ('value92', 'other 392');
+INSERT INTO "my table" (col, other) VALUES ('value93', 'other 393');
+INSERT INTO "my table" (col, other) VALUES ('value94', 'other 394');
+INSERT INTO "my table" (col, other) VALUES ('value95', 'other 395');
+INSERT INTO "my table" (col, other) VALUES ('value96', 'other 396');
+INSERT INTO "my table" (col, other) VALUES ('value97', 'other 397');
+INSERT INTO "my table" (col, other) VALUES ('value98', 'other 398');
+INSERT INTO "my table" (col, other) VALUES ('value99', 'other 399');
+INSERT INTO "my table" (col, other) VALUES ('value100', 'other 400');
+INSERT INTO "my table" (col, other) VALUES ('value101', 'other 401');
+INSERT INTO "my table" (col, other) VALUES ('value102', 'other 402');
+INSERT INTO "my table" (col, other) VALUES ('value103', 'other 403');
+INSERT INTO "my table" (col, other) VALUES ('value104', 'other 404');
+INSERT INTO "my table" (col, other) VALUES ('value105', 'other 405');
+INSERT INTO "my table" (col, other) VALUES ('value106', 'other 406');
+INSERT INTO "my table" (col, other) VALUES ('value107', 'other 407');
+INSERT INTO "my table" (col, other) VALUES ('value108', 'other 408');
+INSERT INTO "my table" (col, other) VALUES ('value109', 'other 409');
+INSERT INTO "my table" (col, other) VALUES ('value110', 'other 410');
+INSERT INTO "my table" (col, other) VALUES ('value111', 'other 411');
+INSERT INTO "my table" (col, other) VALUES ('value112', 'other 412');
+INSERT INTO "my table" (col, other) VALUES ('value113', 'other 413');
+INSERT INTO "my table" (col, other) VALUES ('value114', 'other 414');
+INSERT INTO "my table" (col, other) VALUES ('value115', 'other 415');
+INSERT INTO "my table" (col, other) VALUES ('value116', 'other 416');
+INSERT INTO "my table" (col, other) VALUES ('value117', 'other 417');
+INSERT INTO "my table" (col, other) VALUES ('value118', 'other 418');
+INSERT INTO "my table" (col, other) VALUES ('value119', 'other 419');
+INSERT INTO "my table" (col, other) VALUES ('value120', 'other 420');
+INSERT INTO "my table" (col, other) VALUES ('value121', 'other 421');
+INSERT INTO "my table" (col, other) VALUES ('value122', 'other 422');
+INSERT INTO "my table" (col, other) VALUES ('value123', 'other 423');
+INSERT INTO "my table" (col, other) VALUES ('value124', 'other 424');
+INSERT INTO "my table" (col, other) VALUES ('value125', 'other 425');
+INSERT INTO "my table" (col, other) VALUES ('value126', 'other 426');
+INSERT INTO "my table" (col, other) VALUES ('value127', 'other 427');
+INSERT INTO "my table" (col, other) VALUES ('value128', 'other 428');
+INSERT INTO "my table" (col, other) VALUES ('value129', 'other 429');
+INSERT INTO "my table" (col, other) VALUES ('value130', 'other 430');
+INSERT INTO "my table" (col, other) VALUES ('value131', 'other 431');
+INSERT INTO "my table" (col, other) VALUES ('value132', 'other 432');
+INSERT INTO "my table" (col, other) VALUES ('value133', 'other 433');
+INSERT INTO "my table" (col, other) VALUES ('value134', 'other 434');
+INSERT INTO "my table" (col, other) VALUES ('value135', 'other 435');
+INSERT INTO "my table" (col, other) VALUES ('value136', 'other 436');
+INSERT INTO "my table" (col, other) VALUES ('value137', 'other 437');
+INSERT INTO "my table" (col, other) VALUES ('value138', 'other 438');
+INSERT INTO "my table" (col, other) VALUES ('value139', 'other 439');
+INSERT INTO "my table" (col, other) VALUES ('value140', 'other 440');
+INSERT INTO "my table" (col, other) VALUES ('value141', 'other 441');
+INSERT INTO "my table" (col, other) VALUES ('value142', 'other 442');
+INSERT INTO "my table" (col, other) VALUES ('value143', 'other 443');
+INSERT INTO "my table" (col, other) VALUES ('value144', 'other 444');
+INSERT INTO "my table" (col, other) VALUES ('value145', 'other 445');
+INSERT INTO "my table" (col, other) VALUES ('value146', 'other 446');
+INSERT INTO "my table" (col, other) VALUES ('value147', 'other 447');
+INSERT INTO "my table" (col, other) VALUES ('value148', 'other 448');
+INSERT INTO "my table" (col, other) VALUES ('value149', 'other 449');
+INSERT INTO "my table" (col, other) VALUES ('value150', 'other 450');
+INSERT INTO "my table" (col, other) VALUES ('value151', 'other 451');
+INSERT INTO "my table" (col, other) VALUES ('value152', 'other 452');
+INSERT INTO "my table" (col, other) VALUES ('value153', 'other 453');
+INSERT INTO "my table" (col, other) VALUES ('value154', 'other 454');
+INSERT INTO "my table" (col, other) VALUES ('value155', 'other 455');
+INSERT INTO "my table" (col, other) VALUES ('value156', 'other 456');
+INSERT INTO "my table" (col, other) VALUES ('value157', 'other 457');
+INSERT INTO "my table" (col, other) VALUES ('value158', 'other 458');
+INSERT INTO "my table" (col, other) VALUES ('value159', 'other 459');
+INSERT INTO "my table" (col, other) VALUES ('value160', 'other 460');
+INSERT INTO "my table" (col, other) VALUES ('value161', 'other 461');
+INSERT INTO "my table" (col, other) VALUES ('value162', 'other 462');
+INSERT INTO "my table" (col, other) VALUES ('value163', 'other 463');
+INSERT INTO "my table" (col, other) VALUES ('value164', 'other 464');
+INSERT INTO "my table" (col, other) VALUES ('value165', 'other 465');
+INSERT INTO "my table" (col, other) VALUES ('value166', 'other 466');
+INSERT INTO "my table" (col, other) VALUES ('value167', 'other 467');
+INSERT INTO "my table" (col, other) VALUES ('value168', 'other 468');
+INSERT INTO "my table" (col, other) VALUES ('value169', 'other 469');
+INSERT INTO "my table" (col, other) VALUES ('value170', 'other 470');
+INSERT INTO "my table" (col, other) VALUES ('value171', 'other 471');
+INSERT INTO "my table" (col, other) VALUES ('value172', 'other 472');
+INSERT INTO "my table" (col, other) VALUES ('value173', 'other 473');
+INSERT INTO "my table" (col, other) VALUES ('value174', 'other 474');
+INSERT INTO "my table" (col, other) VALUES ('value175', 'other 475');
+INSERT INTO "my table" (col, other) VALUES ('value176', 'other 476');
+INSERT INTO "my table" (col, other) VALUES ('value177', 'other 477');
+INSERT INTO "my table" (col, other) VALUES ('value178', 'other 478');
+INSERT INTO "my table" (col, other) VALUES ('value179', 'other 479');
+INSERT INTO "my table" (col, other) VALUES ('value180', 'other 480');
+INSERT INTO "my table" (col, other) VALUES ('value181', 'other 481');
+INSERT INTO "my table" (col, other) VALUES ('value182', 'other 482');
+INSERT INTO "my table" (col, other) VALUES ('value183', 'other 483');
+INSERT INTO "my table" (col, other) VALUES ('value184', 'other 484');
+INSERT INTO "my table" (col, other) VALUES ('value185', 'other 485');
+INSERT INTO "my table" (col, other) VALUES ('value186', 'other 486');
+INSERT INTO "my table" (col, other) VALUES ('value187', 'other 487');
+INSERT INTO "my table" (col, other) VALUES ('value188', 'other 488');
+INSERT INTO "my table" (col, other) VALUES ('value189', 'other 489');
+INSERT INTO "my table" (col, other) VALUES ('value190', 'other 490');
+INSERT INTO "my table" (col, other) VALUES ('value191', 'other 491');
+INSERT INTO "my table" (col, other) VALUES ('value192', 'other 492');
+INSERT INTO "my table" (col, other) VALUES ('value193', 'other 493');
+INSERT INTO "my table" (col, other) VALUES ('value194', 'other 494');
+INSERT INTO "my table" (col, other) VALUES ('value195', 'other 495');
+INSERT INTO "my table" (col, other) VALUES ('value196', 'other 496');
+INSERT INTO "my table" (col, other) VALUES ('value197', 'other 497');
+INSERT INTO "my table" (col, other) VALUES ('value198', 'other 498');
+INSERT INTO "my table" (col, other) VALUES ('value199', 'other 499');
+INSERT INTO "my table" (col, other) VALUES ('value200', 'other 500');
+INSERT INTO "my table" (col, other) VALUES ('value201', 'other 501');
+INSERT INTO "my table" (col, other) VALUES ('value202', 'other 502');
+INSERT INTO "my table" (col, other) VALUES ('value203', 'other 503');
+INSERT INTO "my table" (col, other) VALUES ('value204', 'other 504');
+INSERT INTO "my table" (col, other) VALUES ('value205', 'other 505');
+INSERT INTO "my table" (col, other) VALUES ('value206', 'other 506');
+INSERT INTO "my table" (col, other) VALUES ('value207', 'other 507');
+INSERT INTO "my table" (col, other) VALUES ('value208', 'other 508');
+INSERT INTO "my table" (col, other) VALUES ('value209', 'other 509');
+INSERT INTO "my table" (col, other) VALUES ('value210', 'other 510');
+INSERT INTO "my table" (col, other) VALUES ('value211', 'other 511');
+INSERT INTO "my table" (col, other) VALUES ('value212', 'other 512');
+INSERT INTO "my table" (col, other) VALUES ('value213', 'other 513');
+INSERT INTO "my table" (col, other) VALUES ('value214', 'other 514');
+INSERT INTO "my table" (col, other) VALUES ('value215', 'other 515');
+INSERT INTO "my table" (col, other) VALUES ('value216', 'other 516');
+INSERT INTO "my table" (col, other) VALUES ('value217', 'other 517');
+INSERT INTO "my table" (col, other) VALUES ('value218', 'other 518');
+INSERT INTO "my table" (col, other) VALUES ('value219', 'other 519');
+INSERT INTO "my table" (col, other) VALUES ('value220', 'other 520');
+INSERT INTO "my table" (col, other) VALUES ('value221', 'other 521');
+INSERT INTO "my table" (col, other) VALUES ('value222', 'other 522');
+INSERT INTO "my table" (col, other) VALUES ('value223', 'other 523');
+INSERT INTO "my table" (col, other) VALUES ('value224', 'other 524');
+INSERT INTO "my table" (col, other) VALUES ('value225', 'other 525');
+INSERT INTO "my table" (col, other) VALUES ('value226', 'other 526');
+INSERT INTO "my table" (col, other) VALUES ('value227', 'other 527');
+INSERT INTO "my table" (col, other) VALUES ('value228', 'other 528');
+INSERT INTO "my table" (col, other) VALUES ('value229', 'other 529');
+INSERT INTO "my table" (col, other) VALUES ('value230', 'other 530');
+INSERT INTO "my table" (col, other) VALUES ('value231', 'other 531');
+INSERT INTO "my table" (col, other) VALUES ('value232', 'other 532');
+INSERT INTO "my table" (col, other) VALUES ('value233', 'other 533');
+INSERT INTO "my table" (col, other) VALUES ('value234', 'other 534');
+INSERT INTO "my table" (col, other) VALUES ('value235', 'other 535');
+INSERT INTO "my table" (col, other) VALUES ('value236', 'other 536');
+INSERT INTO "my table" (col, other) VALUES ('value237', 'other 537');
+INSERT INTO "my table" (col, other) VALUES ('value238', 'other 538');
+INSERT INTO "my table" (col, other) VALUES ('value239', 'other 539');
+INSERT INTO "my table" (col, other) VALUES ('value240', 'other 540');
+INSERT INTO "my table" (col, other) VALUES ('value241', 'other 541');
+INSERT INTO "my table" (col, other) VALUES ('value242', 'other 542');
+INSERT INTO "my table" (col, other) VALUES ('value243', 'other 543');
+INSERT INTO "my table" (col, other) VALUES ('value244', 'other 544');
+INSERT INTO "my table" (col, other) VALUES ('value245', 'other 545');
+INSERT INTO "my table" (col, other) VALUES ('value246', 'other 546');
+INSERT INTO "my table" (col, other) VALUES ('value247', 'other 547');
+INSERT INTO "my table" (col, other) VALUES ('value248', 'other 548');
+INSERT INTO "my table" (col, other) VALUES ('value249', 'other 549');
+INSERT INTO "my table" (col, other) VALUES ('value250', 'other 550');
+INSERT INTO "my table" (col, other) VALUES ('value251', 'other 551');
+INSERT INTO "my table" (col, other) VALUES ('value252', 'other 552');
+INSERT INTO "my table" (col, other) VALUES ('value253', 'other 553');
+INSERT INTO "my table" (col, other) VALUES ('value254', 'other 554');
+INSERT INTO "my table" (col, other) VALUES ('value255', 'other 555');
+INSERT INTO "my table" (col, other) VALUES ('value256', 'other 556');
+INSERT INTO "my table" (col, other) VALUES ('value257', 'other 557');
+INSERT INTO "my table" (col, other) VALUES ('value258', 'other 558');
+INSERT INTO "my table" (col, other) VALUES ('value259', 'other 559');
+INSERT INTO "my table" (col, other) VALUES ('value260', 'other 560');
+INSERT INTO "my table" (col, other) VALUES ('value261', 'other 561');
+INSERT INTO "my table" (col, other) VALUES ('value262', 'other 562');
+INSERT INTO "my table" (col, other) VALUES ('value263', 'other 563');
+INSERT INTO "my table" (col, other) VALUES ('value264', 'other 564');
+INSERT INTO "my table" (col, other) VALUES ('value265', 'other 565');
+INSERT INTO "my table" (col, other) VALUES ('value266', 'other 566');
+INSERT INTO "my table" (col, other) VALUES ('value267', 'other 567');
+INSERT INTO "my table" (col, other) VALUES ('value268', 'other 568');
+INSERT INTO "my table" (col, other) VALUES ('value269', 'other 569');
+INSERT INTO "my table" (col, other) VALUES ('value270', 'other 570');
+INSERT INTO "my table" (col, other) VALUES ('value271', 'other 571');
+INSERT INTO "my table" (col, other) VALUES ('value272', 'other 572');
+INSERT INTO "my table" (col, other) VALUES ('value273', 'other 573');
+INSERT INTO "my table" (col, other) VALUES ('value274', 'other 574');
+INSERT INTO "my table" (col, other) VALUES ('value275', 'other 575');
+INSERT INTO "my table" (col, other) VALUES ('value276', 'other 576');
+INSERT INTO "my table" (col, other) VALUES ('value277', 'other 577');
+INSERT INTO "my table" (col, other) VALUES ('value278', 'other 578');
+INSERT INTO "my table" (col, other) VALUES ('value279', 'other 579');
+INSERT INTO "my table" (col, other) VALUES ('value280', 'other 580');
+INSERT INTO "my table" (col, other) VALUES ('value281', 'other 581');
+INSERT INTO "my table" (col, other) VALUES ('value282', 'other 582');
+INSERT INTO "my table" (col, other) VALUES ('value283', 'other 583');
+INSERT INTO "my table" (col, other) VALUES ('value284', 'other 584');
+INSERT INTO "my table" (col, other) VALUES ('value285', 'other 585');
+INSERT INTO "my table" (col, other) VALUES ('value286', 'other 586');
+INSERT INTO "my table" (col, other) VALUES ('value287', 'other 587');
+INSERT INTO "my table" (col, other) VALUES ('value288', 'other 588');
+INSERT INTO "my table" (col, other) VALUES ('value289', 'other 589');
+INSERT INTO "my table" (col, other) VALUES ('value290', 'other 590');
+INSERT INTO "my table" (col, other) VALUES ('value291', 'other 591');
+INSERT INTO "my table" (col, other) VALUES ('value292', 'other 592');
+INSERT INTO "my table" (col, other) VALUES ('value293', 'other 593');
+INSERT INTO "my table" (col, other) VALUES ('value294', 'other 594');
+INSERT INTO "my table" (col, other) VALUES ('value295', 'other 595');
+INSERT INTO "my table" (col, other) VALUES ('value296', 'other 596');
+INSERT INTO "my table" (col, other) VALUES ('value297', 'other 597');
+INSERT INTO "my table" (col, other) VALUES ('value298', 'other 598');
+INSERT INTO "my table" (col, other) VALUES ('value299', 'other 599');
+INSERT INTO "my table" (col, other) VALUES ('value0', 'other 600');
+INSERT INTO "my table" (col, other) VALUES ('value1', 'other 601');
+INSERT INTO "my table" (col, other) VALUES ('value2', 'other 602');
+INSERT INTO "my table" (col, other) VALUES ('value3', 'other 603');
+INSERT INTO "my table" (col, other) VALUES ('value4', 'other 604');
+INSERT INTO "my table" (col, other) VALUES ('value5', 'other 605');
+INSERT INTO "my table" (col, other) VALUES ('value6', 'other 606');
+INSERT INTO "my table" (col, other) VALUES ('value7', 'other 607');
+INSERT INTO "my table" (col, other) VALUES ('value8', 'other 608');
+INSERT INTO "my table" (col, other) VALUES ('value9', 'other 609');
+INSERT INTO "my table" (col, other) VALUES ('value10', 'other 610');
+INSERT INTO "my table" (col, other) VALUES ('value11', 'other 611');
+INSERT INTO "my table" (col, other) VALUES ('value12', 'other 612');
+INSERT INTO "my table" (col, other) VALUES ('value13', 'other 613');
+INSERT INTO "my table" (col, other) VALUES ('value14', 'other 614');
+INSERT INTO "my table" (col, other) VALUES ('value15', 'other 615');
+INSERT INTO "my table" (col, other) VALUES ('value16', 'other 616');
+INSERT INTO "my table" (col, other) VALUES ('value17', 'other 617');
+INSERT INTO "my table" (col, other) VALUES ('value18', 'other 618');
+INSERT INTO "my table" (col, other) VALUES ('value19', 'other 619');
+INSERT INTO "my table" (col, other) VALUES ('value20', 'other 620');
+INSERT INTO "my table" (col, other) VALUES ('value21', 'other 621');
+INSERT INTO "my table" (col, other) VALUES ('value22', 'other 622');
+INSERT INTO "my table" (col, other) VALUES ('value23', 'other 623');
+INSERT INTO "my table" (col, other) VALUES ('value24', 'other 624');
+INSERT INTO "my table" (col, other) VALUES ('value25', 'other 625');
+INSERT INTO "my table" (col, other) VALUES ('value26', 'other 626');
+INSERT INTO "my table" (col, other) VALUES ('value27', 'other 627');
+INSERT INTO "my table" (col, other) VALUES ('value28', 'other 628');
+INSERT INTO "my table" (col, other) VALUES ('value29', 'other 629');
+INSERT INTO "my table" (col, other) VALUES ('value30', 'other 630');
+INSERT INTO "my table" (col, other) VALUES ('value31', 'other 631');
+INSERT INTO "my table" (col, other) VALUES ('value32', 'other 632');
+INSERT INTO "my table" (col, other) VALUES ('value33', 'other 633');
+INSERT INTO "my table" (col, other) VALUES ('value34', 'other 634');
+INSERT INTO "my table" (col, other) VALUES ('value35', 'other 635');
+INSERT INTO "my table" (col, other) VALUES ('value36', 'other 636');
+INSERT INTO "my table" (col, other) VALUES ('value37', 'other 637');
+INSERT INTO "my table" (col, other) VALUES ('value38', 'other 638');
+INSERT INTO "my table" (col, other) VALUES ('value39', 'other 639');
+INSERT INTO "my table" (col, other) VALUES ('value40', 'other 640');
+INSERT INTO "my table" (col, other) VALUES ('value41', 'other 641');
+INSERT INTO "my table" (col, other) VALUES ('value42', 'other 642');
+INSERT INTO "my table" (col, other) VALUES ('value43', 'other 643');
+INSERT INTO "my table" (col, other) VALUES ('value44', 'other 644');
+INSERT INTO "my table" (col, other) VALUES ('value45', 'other 645');
+INSERT INTO "my table" (col, other) VALUES ('value46', 'other 646');
+INSERT INTO "my table" (col, other) VALUES ('value47', 'other 647');
+INSERT INTO "my table" (col, other) VALUES ('value48', 'other 648');
+INSERT INTO "my table" (col, other) VALUES ('value49', 'other 649');
+INSERT INTO "my table" (col, other) VALUES ('value50', 'other 650');
+INSERT INTO "my table" (col, other) VALUES ('value51', 'other 651');
+INSERT INTO "my table" (col, other) VALUES ('value52', 'other 652');
+INSERT INTO "my table" (col, other) VALUES ('value53', 'other 653');
+INSERT INTO "my table" (col, other) VALUES ('value54', 'other 654');
+INSERT INTO "my table" (col, other) VALUES ('value55', 'other 655');
+INSERT INTO "my table" (col, other) VALUES ('value56', 'other 656');
+INSERT INTO "my table" (col, other) VALUES ('value57', 'other 657');
+INSERT INTO "my table" (col, other) VALUES ('value58', 'other 658');
+INSERT INTO "my table" (col, other) VALUES ('value59', 'other 659');
+INSERT INTO "my table" (col, other) VALUES ('value60', 'other 660');
+INSERT INTO "my table" (col, other) VALUES ('value61', 'other 661');
+INSERT INTO "my table" (col, other) VALUES ('value62', 'other 662');
+INSERT INTO "my table" (col, other) VALUES ('value63', 'other 663');
+INSERT INTO "my table" (col, other) VALUES ('value64', 'other 664');
+INSERT INTO "my table" (col, other) VALUES ('value65', 'other 665');
+INSERT INTO "my table" (col, other) VALUES ('value66', 'other 666');
+INSERT INTO "my table" (col, other) VALUES ('value67', 'other 667');
+INSERT INTO "my table" (col, other) VALUES ('value68', 'other 668');
+INSERT INTO "my table" (col, other) VALUES ('value69', 'other 669');
+INSERT INTO "my table" (col, other) VALUES ('value70', 'other 670');
+INSERT INTO "my table" (col, other) VALUES ('value71', 'other 671');
+INSERT INTO "my table" (col, other) VALUES ('value72', 'other 672');
+INSERT INTO "my table" (col, other) VALUES ('value73', 'other 673');
+INSERT INTO "my table" (col, other) VALUES ('value74', 'other 674');
+INSERT INTO "my table" (col, other) VALUES ('value75', 'other 675');
+INSERT INTO "my table" (col, other) VALUES ('value76', 'other 676');
+INSERT INTO "my table" (col, other) VALUES ('value77', 'other 677');
+INSERT INTO "my table" (col, other) VALUES ('value78', 'other 678');
+INSERT INTO "my table" (col, other) VALUES ('value79', 'other 679');
+INSERT INTO "my table" (col, other) VALUES ('value80', 'other 680');
+INSERT INTO "my table" (col, other) VALUES ('value81', 'other 681');
+INSERT INTO "my table" (col, other) VALUES ('value82', 'other 682');
+INSERT INTO "my table" (col, other) VALUES ('value83', 'other 683');
+INSERT INTO "my table" (col, other) VALUES ('value84', 'other 684');
+INSERT INTO "my table" (col, other) VALUES ('value85', 'other 685');
+INSERT INTO "my table" (col, other) VALUES ('value86', 'other 686');
+INSERT INTO "my table" (col, other) VALUES ('value87', 'other 687');
+INSERT INTO "my table" (col, other) VALUES ('value88', 'other 688');
+INSERT INTO "my table" (col, other) VALUES ('value89', 'other 689');
+INSERT INTO "my table" (col, other) VALUES ('value90', 'other 690');
+INSERT INTO "my table" (col, other) VALUES ('value91', 'other 691');
+INSERT INTO "my table" (col, other) VALUES ('value92', 'other 692');
+INSERT INTO "my table" (col, other) VALUES ('value93', 'other 693');
+INSERT INTO "my table" (col, other) VALUES ('value94', 'other 694');
+INSERT INTO "my table" (col, other) VALUES ('value95', 'other 695');
+INSERT INTO "my table" (col, other) VALUES ('value96', 'other 696');
+INSERT INTO "my table" (col, other) VALUES ('value97', 'other 697');
+INSERT INTO "my table" (col, other) VALUES ('value98', 'other 698');
+INSERT INTO "my table" (col, other) VALUES ('value99', 'other 699');
+INSERT INTO "my table" (col, other) VALUES ('value100', 'other 700');
+INSERT INTO "my table" (col, other) VALUES ('value101', 'other 701');
+INSERT INTO "my table" (col, other) VALUES ('value102', 'other 702');
+INSERT INTO "my table" (col, other) VALUES ('value103', 'other 703');
+INSERT INTO "my table" (col, other) VALUES ('value104', 'other 704');
+INSERT INTO "my table" (col, other) VALUES ('value105', 'other 705');
+INSERT INTO "my table" (col, other) VALUES ('value106', 'other 706');
+INSERT INTO "my table" (col, other) VALUES ('value107', 'other 707');
+INSERT INTO "my table" (col, other) VALUES ('value108', 'other 708');
+INSERT INTO "my table" (col, other) VALUES ('value109', 'other 709');
+INSERT INTO "my table" (col, other) VALUES ('value110', 'other 710');
+INSERT INTO "my table" (col, other) VALUES ('value111', 'other 711');
+INSERT INTO "my table" (col, other) VALUES ('value112', 'other 712');
+INSERT INTO "my table" (col, other) VALUES ('value113', 'other 713');
+INSERT INTO "my table" (col, other) VALUES ('value114', 'other 714');
+INSERT INTO "my table" (col, other) VALUES ('value115', 'other 715');
+INSERT INTO "my table" (col, other) VALUES ('value116', 'other 716');
+INSERT INTO "my table" (col, other) VALUES ('value117', 'other 717');
+INSERT INTO "my table" (col, other) VALUES ('value118', 'other 718');
+INSERT INTO "my table" (col, other) VALUES ('value119', 'other 719');
+INSERT INTO "my table" (col, other) VALUES ('value120', 'other 720');
+INSERT INTO "my table" (col, other) VALUES ('value121', 'other 721');
+INSERT INTO "my table" (col, other) VALUES ('value122', 'other 722');
+INSERT INTO "my table" (col, other) VALUES ('value123', 'other 723');
+INSERT INTO "my table" (col, other) VALUES ('value124', 'other 724');
+INSERT INTO "my table" (col, other) VALUES ('value125', 'other 725');
+INSERT INTO "my table" (col, other) VALUES ('value126', 'other 726');
+INSERT INTO "my table" (col, other) VALUES ('value127', 'other 727');
+INSERT INTO "my table" (col, other) VALUES ('value128', 'other 728');
+INSERT INTO "my table" (col, other) VALUES ('value129', 'other 729');
+INSERT INTO "my table" (col, other) VALUES ('value130', 'other 730');
+INSERT INTO "my table" (col, other) VALUES ('value131', 'other 731');
+INSERT INTO "my table" (col, other) VALUES ('value132', 'other 732');
+INSERT INTO "my table" (col, other) VALUES ('value133', 'other 733');
+INSERT INTO "my table" (col, other) VALUES ('value134', 'other 734');
+INSERT INTO "my table" (col, other) VALUES ('value135', 'other 735');
+INSERT INTO "my table" (col, other) VALUES ('value136', 'other 736');
+INSERT INTO "my table" (col, other) VALUES ('value137', 'other 737');
+INSERT INTO "my table" (col, other) VALUES ('value138', 'other 738');
+INSERT INTO "my table" (col, other) VALUES ('value139', 'other 739');
+INSERT INTO "my table" (col, other) VALUES ('value140', 'other 740');
+INSERT INTO "my table" (col, other) VALUES ('value141', 'other 741');
+INSERT INTO "my table" (col, other) VALUES ('value142', 'other 742');
+INSERT INTO "my table" (col, other) VALUES ('value143', 'other 743');
+INSERT INTO "my table" (col, other) VALUES ('value144', 'other 744');
+INSERT INTO "my table" (col, other) VALUES ('value145', 'other 745');
+INSERT INTO "my table" (col, other) VALUES ('value146', 'other 746');
+INSERT INTO "my table" (col, other) VALUES ('value147', 'other 747');
+INSERT INTO "my table" (col, other) VALUES ('value148', 'other 748');
+INSERT INTO "my table" (col, other) VALUES ('value149', 'other 749');
+INSERT INTO "my table" (col, other) VALUES ('value150', 'other 750');
+INSERT INTO "my table" (col, other) VALUES ('value151', 'other 751');
+INSERT INTO "my table" (col, other) VALUES ('value152', 'other 752');
+INSERT INTO "my table" (col, other) VALUES ('value153', 'other 753');
+INSERT INTO "my table" (col, other) VALUES ('value154', 'other 754');
+INSERT INTO "my table" (col, other) VALUES ('value155', 'other 755');
+INSERT INTO "my table" (col, other) VALUES ('value156', 'other 756');
+INSERT INTO "my table" (col, other) VALUES ('value157', 'other 757');
+INSERT INTO "my table" (col, other) VALUES ('value158', 'other 758');
+INSERT INTO "my table" (col, other) VALUES ('value159', 'other 759');
+INSERT INTO "my table" (col, other) VALUES ('value160', 'other 760');
+INSERT INTO "my table" (col, other) VALUES ('value161', 'other 761');
+INSERT INTO "my table" (col, other) VALUES ('value162', 'other 762');
+INSERT INTO "my table" (col, other) VALUES ('value163', 'other 763');
+INSERT INTO "my table" (col, other) VALUES ('value164', 'other 764');
+INSERT INTO "my table" (col, other) VALUES ('value165', 'other 765');
+INSERT INTO "my table" (col, other) VALUES ('value166', 'other 766');
+INSERT INTO "my table" (col, other) VALUES ('value167', 'other 767');
+INSERT INTO "my table" (col, other) VALUES ('value168', 'other 768');
+INSERT INTO "my table" (col, other) VALUES ('value169', 'other 769');
+INSERT INTO "my table" (col, other) VALUES ('value170', 'other 770');
+INSERT INTO "my table" (col, other) VALUES ('value171', 'other 771');
+INSERT INTO "my table" (col, other) VALUES ('value172', 'other 772');
+INSERT INTO "my table" (col, other) VALUES ('value173', 'other 773');
+INSERT INTO "my table" (col, other) VALUES ('value174', 'other 774');
+INSERT INTO "my table" (col, other) VALUES ('value175', 'other 775');
+INSERT INTO "my table" (col, other) VALUES ('value176', 'other 776');
+INSERT INTO "my table" (col, other) VALUES ('value177', 'other 777');
+INSERT INTO "my table" (col, other) VALUES ('value178', 'other 778');
+INSERT INTO "my table" (col, other) VALUES ('value179', 'other 779');
+INSERT INTO "my table" (col, other) VALUES ('value180', 'other 780');
+INSERT INTO "my table" (col, other) VALUES ('value181', 'other 781');
+INSERT INTO "my table" (col, other) VALUES ('value182', 'other 782');
+INSERT INTO "my table" (col, other) VALUES ('value183', 'other 783');
+INSERT INTO "my table" (col, other) VALUES ('value184', 'other 784');
+INSERT INTO "my table" (col, other) VALUES ('value185', 'other 785');
+INSERT INTO "my table" (col, other) VALUES ('value186', 'other 786');
+INSERT INTO "my table" (col, other) VALUES ('value187', 'other 787');
+INSERT INTO "my table" (col, other) VALUES ('value188', 'other 788');
+INSERT INTO "my table" (col, other) VALUES ('value189', 'other 789');
+INSERT INTO "my table" (col, other) VALUES ('value190', 'other 790');
+INSERT INTO "my table" (col, other) VALUES ('value191', 'other 791');
+INSERT INTO "my table" (col, other) VALUES ('value192', 'other 792');
+INSERT INTO "my table" (col, other) VALUES ('value193', 'other 793');
+INSERT INTO "my table" (col, other) VALUES ('value194', 'other 794');
+INSERT INTO "my table" (col, other) VALUES ('value195', 'other 795');
+INSERT INTO "my table" (col, other) VALUES ('value196', 'other 796');
+INSERT INTO "my table" (col, other) VALUES ('value197', 'other 797');
+INSERT INTO "my table" (col, other) VALUES ('value198', 'other 798');
+INSERT INTO "my table" (col, other) VALUES ('value199', 'other 799');
+INSERT INTO "my table" (col, other) VALUES ('value200', 'other 800');
+INSERT INTO "my table" (col, other) VALUES ('value201', 'other 801');
+INSERT INTO "my table" (col, other) VALUES ('value202', 'other 802');
+INSERT INTO "my table" (col, other) VALUES ('value203', 'other 803');
+INSERT INTO "my table" (col, other) VALUES ('value204', 'other 804');
+INSERT INTO "my table" (col, other) VALUES ('value205', 'other 805');
+INSERT INTO "my table" (col, other) VALUES ('value206', 'other 806');
+INSERT INTO "my table" (col, other) VALUES ('value207', 'other 807');
+INSERT INTO "my table" (col, other) VALUES ('value208', 'other 808');
+INSERT INTO "my table" (col, other) VALUES ('value209', 'other 809');
+INSERT INTO "my table" (col, other) VALUES ('value210', 'other 810');
+INSERT INTO "my table" (col, other) VALUES ('value211', 'other 811');
+INSERT INTO "my table" (col, other) VALUES ('value212', 'other 812');
+INSERT INTO "my table" (col, other) VALUES ('value213', 'other 813');
+INSERT INTO "my table" (col, other) VALUES ('value214', 'other 814');
+INSERT INTO "my table" (col, other) VALUES ('value215', 'other 815');
+INSERT INTO "my table" (col, other) VALUES ('value216', 'other 816');
+INSERT INTO "my table" (col, other) VALUES ('value217', 'other 817');
+INSERT INTO "my table" (col, other) VALUES ('value218', 'other 818');
+INSERT INTO "my table" (col, other) VALUES ('value219', 'other 819');
+INSERT INTO "my table" (col, other) VALUES ('value220', 'other 820');
+INSERT INTO "my table" (col, other) VALUES ('value221', 'other 821');
+INSERT INTO "my table" (col, other) VALUES ('value222', 'other 822');
+INSERT INTO "my table" (col, other) VALUES ('value223', 'other 823');
+INSERT INTO "my table" (col, other) VALUES ('value224', 'other 824');
+INSERT INTO "my table" (col, other) VALUES ('value225', 'other 825');
+INSERT INTO "my table" (col, other) VALUES ('value226', 'other 826');
+INSERT INTO "my table" (col, other) VALUES ('value227', 'other 827');
+INSERT INTO "my table" (col, other) VALUES ('value228', 'other 828');
+INSERT INTO "my table" (col, other) VALUES ('value229', 'other 829');
+INSERT INTO "my table" (col, other) VALUES ('value230', 'other 830');
+INSERT INTO "my table" (col, other) VALUES ('value231', 'other 831');
+INSERT INTO "my table" (col, other) VALUES ('value232', 'other 832');
+INSERT INTO "my table" (col, other) VALUES ('value233', 'other 833');
+INSERT INTO "my table" (col, other) VALUES ('value234', 'other 834');
+INSERT INTO "my table" (col, other) VALUES ('value235', 'other 835');
+INSERT INTO "my table" (col, other) VALUES ('value236', 'other 836');
+INSERT INTO "my table" (col, other) VALUES ('value237', 'other 837');
+INSERT INTO "my table" (col, other) VALUES ('value238', 'other 838');
+INSERT INTO "my table" (col, other) VALUES ('value239', 'other 839');
+INSERT INTO "my table" (col, other) VALUES ('value240', 'other 840');
+INSERT INTO "my table" (col, other) VALUES ('value241', 'other 841');
+INSERT INTO "my table" (col, other) VALUES ('value242', 'other 842');
+INSERT INTO "my table" (col, other) VALUES ('value243', 'other 843');
+INSERT INTO "my table" (col, other) VALUES ('value244', 'other 844');
+INSERT INTO "my table" (col, other) VALUES ('value245', 'other 845');
+INSERT INTO "my table" (col, other) VALUES ('value246', 'other 846');
+INSERT INTO "my table" (col, other) VALUES ('value247', 'other 847');
+INSERT INTO "my table" (col, other) VALUES ('value248', 'other 848');
+INSERT INTO "my table" (col, other) VALUES ('value249', 'other 849');
+INSERT INTO "my table" (col, other) VALUES ('value250', 'other 850');
+INSERT INTO "my table" (col, other) VALUES ('value251', 'other 851');
+INSERT INTO "my table" (col, other) VALUES ('value252', 'other 852');
+INSERT INTO "my table" (col, other) VALUES ('value253', 'other 853');
+INSERT INTO "my table" (col, other) VALUES ('value254', 'other 854');
+INSERT INTO "my table" (col, other) VALUES ('value255', 'other 855');
+INSERT INTO "my table" (col, other) VALUES ('value256', 'other 856');
+INSERT INTO "my table" (col, other) VALUES ('value257', 'other 857');
+INSERT INTO "my table" (col, other) VALUES ('value258', 'other 858');
+INSERT INTO "my table" (col, other) VALUES ('value259', 'other 859');
+INSERT INTO "my table" (col, other) VALUES ('value260', 'other 860');
+INSERT INTO "my table" (col, other) VALUES ('value261', 'other 861');
+INSERT INTO "my table" (col, other) VALUES ('value262', 'other 862');
+INSERT INTO "my table" (col, other) VALUES ('value263', 'other 863');
+INSERT INTO "my table" (col, other) VALUES ('value264', 'other 864');
+INSERT INTO "my table" (col, other) VALUES ('value265', 'other 865');
+INSERT INTO "my table" (col, other) VALUES ('value266', 'other 866');
+INSERT INTO "my table" (col, other) VALUES ('value267', 'other 867');
+INSERT INTO "my table" (col, other) VALUES ('value268', 'other 868');
+INSERT INTO "my table" (col, other) VALUES ('value269', 'other 869');
+INSERT INTO "my table" (col, other) VALUES ('value270', 'other 870');
+INSERT INTO "my table" (col, other) VALUES ('value271', 'other 871');
+INSERT INTO "my table" (col, other) VALUES ('value272', 'other 872');
+INSERT INTO "my table" (col, other) VALUES ('value273', 'other 873');
+INSERT INTO "my table" (col, other) VALUES ('value274', 'other 874');
+INSERT INTO "my table" (col, other) VALUES ('value275', 'other 875');
+INSERT INTO "my table" (col, other) VALUES ('value276', 'other 876');
+INSERT INTO "my table" (col, other) VALUES ('value277', 'other 877');
+INSERT INTO "my table" (col, other) VALUES ('value278', 'other 878');
+INSERT INTO "my table" (col, other) VALUES ('value279', 'other 879');
+INSERT INTO "my table" (col, other) VALUES ('value280', 'other 880');
+INSERT INTO "my table" (col, other) VALUES ('value281', 'other 881');
+INSERT INTO "my table" (col, other) VALUES ('value282', 'other 882');
+INSERT INTO "my table" (col, other) VALUES ('value283', 'other 883');
+INSERT INTO "my table" (col, other) VALUES ('value284', 'other 884');
+INSERT INTO "my table" (col, other) VALUES ('value285', 'other 885');
+INSERT INTO "my table" (col, other) VALUES ('value286', 'other 886');
+INSERT INTO "my table" (col, other) VALUES ('value287', 'other 887');
+INSERT INTO "my table" (col, other) VALUES ('value288', 'other 888');
+INSERT INTO "my table" (col, other) VALUES ('value289', 'other 889');
+INSERT INTO "my table" (col, other) VALUES ('value290', 'other 890');
+INSERT INTO "my table" (col, other) VALUES ('value291', 'other 891');
+INSERT INTO "my table" (col, other) VALUES ('value292', 'other 892');
+INSERT INTO "my table" (col, other) VALUES ('value293', 'other 893');
+INSERT INTO "my table" (col, other) VALUES ('value294', 'other 894');
+INSERT INTO "my table" (col, other) VALUES ('value295', 'other 895');
+INSERT INTO "my table" (col, other) VALUES ('value296', 'other 896');
+INSERT INTO "my table" (col, other) VALUES ('value297', 'other 897');
+INSERT INTO "my table" (col, other) VALUES ('value298', 'other 898');
+INSERT INTO "my table" (col, other) VALUES ('value299', 'other 899');
+INSERT INTO "my table" (col, other) VALUES ('value0', 'other 900');
+INSERT INTO "my table" (col, other) VALUES ('value1', 'other 901');
+INSERT INTO "my table" (col, other) VALUES ('value2', 'other 902');
+INSERT INTO "my table" (col, other) VALUES ('value3', 'other 903');
+INSERT INTO "my table" (col, other) VALUES ('value4', 'other 904');
+INSERT INTO "my table" (col, other) VALUES ('value5', 'other 905');
+INSERT INTO "my table" (col, other) VALUES ('value6', 'other 906');
+INSERT INTO "my table" (col, other) VALUES ('value7', 'other 907');
+INSERT INTO "my table" (col, other) VALUES ('value8', 'other 908');
+INSERT INTO "my table" (col, other) VALUES ('value9', 'other 909');
+INSERT INTO "my table" (col, other) VALUES ('value10', 'other 910');
+INSERT INTO "my table" (col, other) VALUES ('value11', 'other 911');
+INSERT INTO "my table" (col, other) VALUES ('value12', 'other 912');
+INSERT INTO "my table" (col, other) VALUES ('value13', 'other 913');
+INSERT INTO "my table" (col, other) VALUES ('value14', 'other 914');
+INSERT INTO "my table" (col, other) VALUES ('value15', 'other 915');
+INSERT INTO "my table" (col, other) VALUES ('value16', 'other 916');
+INSERT INTO "my table" (col, other) VALUES ('value17', 'other 917');
+INSERT INTO "my table" (col, other) VALUES ('value18', 'other 918');
+INSERT INTO "my table" (col, other) VALUES ('value19', 'other 919');
+INSERT INTO "my table" (col, other) VALUES ('value20', 'other 920');
+INSERT INTO "my table" (col, other) VALUES ('value21', 'other 921');
+INSERT INTO "my table" (col, other) VALUES ('value22', 'other 922');
+INSERT INTO "my table" (col, other) VALUES ('value23', 'other 923');
+INSERT INTO "my table" (col, other) VALUES ('value24', 'other 924');
+INSERT INTO "my table" (col, other) VALUES ('value25', 'other 925');
+INSERT INTO "my table" (col, other) VALUES ('value26', 'other 926');
+INSERT INTO "my table" (col, other) VALUES ('value27', 'other 927');
+INSERT INTO "my table" (col, other) VALUES ('value28', 'other 928');
+INSERT INTO "my table" (col, other) VALUES ('value29', 'other 929');
+INSERT INTO "my table" (col, other) VALUES ('value30', 'other 930');
+INSERT INTO "my table" (col, other) VALUES ('value31', 'other 931');
+INSERT INTO "my table" (col, other) VALUES ('value32', 'other 932');
+INSERT INTO "my table" (col, other) VALUES ('value33', 'other 933');
+INSERT INTO "my table" (col, other) VALUES ('value34', 'other 934');
+INSERT INTO "my table" (col, other) VALUES ('value35', 'other 935');
+INSERT INTO "my table" (col, other) VALUES ('value36', 'other 936');
+INSERT INTO "my table" (col, other) VALUES ('value37', 'other 937');
+INSERT INTO "my table" (col, other) VALUES ('value38', 'other 938');
+INSERT INTO "my table" (col, other) VALUES ('value39', 'other 939');
+INSERT INTO "my table" (col, other) VALUES ('value40', 'other 940');
+INSERT INTO "my table" (col, other) VALUES ('value41', 'other 941');
+INSERT INTO "my table" (col, other) VALUES ('value42', 'other 942');
+INSERT INTO "my table" (col, other) VALUES ('value43', 'other 943');
+INSERT INTO "my table" (col, other) VALUES ('value44', 'other 944');
+INSERT INTO "my table" (col, other) VALUES ('value45', 'other 945');
+INSERT INTO "my table" (col, other) VALUES ('value46', 'other 946');
+INSERT INTO "my table" (col, other) VALUES ('value47', 'other 947');
+INSERT INTO "my table" (col, other) VALUES ('value48', 'other 948');
+INSERT INTO "my table" (col, other) VALUES ('value49', 'other 949');
+INSERT INTO "my table" (col, other) VALUES ('value50', 'other 950');
+INSERT INTO "my table" (col, other) VALUES ('value51', 'other 951');
+INSERT INTO "my table" (col, other) VALUES ('value52', 'other 952');
+INSERT INTO "my table" (col, other) VALUES ('value53', 'other 953');
+INSERT INTO "my table" (col, other) VALUES ('value54', 'other 954');
+INSERT INTO "my table" (col, other) VALUES ('value55', 'other 955');
+INSERT INTO "my table" (col, other) VALUES ('value56', 'other 956');
+INSERT INTO "my table" (col, other) VALUES ('value57', 'other 957');
+INSERT INTO "my table" (col, other) VALUES ('value58', 'other 958');
+INSERT INTO "my table" (col, other) VALUES ('value59', 'other 959');
+INSERT INTO "my table" (col, other) VALUES ('value60', 'other 960');
+INSERT INTO "my table" (col, other) VALUES ('value61', 'other 961');
+INSERT INTO "my table" (col, other) VALUES ('value62', 'other 962');
+INSERT INTO "my table" (col, other) VALUES ('value63', 'other 963');
+INSERT INTO "my table" (col, other) VALUES ('value64', 'other 964');
+INSERT INTO "my table" (col, other) VALUES ('value65', 'other 965');
+INSERT INTO "my table" (col, other) VALUES ('value66', 'other 966');
+INSERT INTO "my table" (col, other) VALUES ('value67', 'other 967');
+INSERT INTO "my table" (col, other) VALUES ('value68', 'other 968');
+INSERT INTO "my table" (col, other) VALUES ('value69', 'other 969');
+INSERT INTO "my table" (col, other) VALUES ('value70', 'other 970');
+INSERT INTO "my table" (col, other) VALUES ('value71', 'other 971');
+INSERT INTO "my table" (col, other) VALUES ('value72', 'other 972');
+INSERT INTO "my table" (col, other) VALUES ('value73', 'other 973');
+INSERT INTO "my table" (col, other) VALUES ('value74', 'other 974');
+INSERT INTO "my table" (col, other) VALUES ('value75', 'other 975');
+INSERT INTO "my table" (col, other) VALUES ('value76', 'other 976');
+INSERT INTO "my table" (col, other) VALUES ('value77', 'other 977');
+INSERT INTO "my table" (col, other) VALUES ('value78', 'other 978');
+INSERT INTO "my table" (col, other) VALUES ('value79', 'other 979');
+INSERT INTO "my table" (col, other) VALUES ('value80', 'other 980');
+INSERT INTO "my table" (col, other) VALUES ('value81', 'other 981');
+INSERT INTO "my table" (col, other) VALUES ('value82', 'other 982');
+INSERT INTO "my table" (col, other) VALUES ('value83', 'other 983');
+INSERT INTO "my table" (col, other) VALUES ('value84', 'other 984');
+INSERT INTO "my table" (col, other) VALUES ('value85', 'other 985');
+INSERT INTO "my table" (col, other) VALUES ('value86', 'other 986');
+INSERT INTO "my table" (col, other) VALUES ('value87', 'other 987');
+INSERT INTO "my table" (col, other) VALUES ('value88', 'other 988');
+INSERT INTO "my table" (col, other) VALUES ('value89', 'other 989');
+INSERT INTO "my table" (col, other) VALUES ('value90', 'other 990');
+INSERT INTO "my table" (col, other) VALUES ('value91', 'other 991');
+INSERT INTO "my table" (col, other) VALUES ('value92', 'other 992');
+INSERT INTO "my table" (col, other) VALUES ('value93', 'other 993');
+INSERT INTO "my table" (col, other) VALUES ('value94', 'other 994');
+INSERT INTO "my table" (col, other) VALUES ('value95', 'other 995');
+INSERT INTO "my table" (col, other) VALUES ('value96', 'other 996');
+INSERT INTO "my table" (col, other) VALUES ('value97', 'other 997');
+INSERT INTO "my table" (col, other) VALUES ('value98', 'other 998');
+INSERT INTO "my table" (col, other) VALUES ('value99', 'other 999');
+INSERT INTO "my table" (col, other) VALUES ('value100', 'other 1000');
+COMMIT;
@@ -0,0 +1,8 @@
+CREATE TABLE reals (id INTEGER PRIMARY KEY, label text, value real);
+INSERT INTO reals (label, value) VALUES
+  ('tenth', 0.1), ('tiny', 1e-10), ('huge', 1e20), ('negative zero', -0.0), ('three', 3.0),
+  ('1e15', 1e15), ('1e14', 1e14), ('18 digits', 123456789012345678.5),
+  ('sum', 0.1 + 0.2), ('max', 1.7976931348623157e308), ('denormal', 4.9e-324),
+  ('negative', -1.5), ('1e-4', 0.0001), ('1e-5', 0.00001), ('rounds up', 9.999999999999999e14),
+  ('third', 1.0 / 3), ('negative tiny', -2.5e-5);
+CREATE INDEX idx_reals_value ON reals (value);
@@ -0,0 +1,6 @@
+-- tests/fixtures/record_size_mismatch.db is this database with the serial type of 'alice'
+-- changed from 23 (text of 5 bytes) to 19 (text of 3 bytes): the columns of the record
+-- end 2 bytes before its payload
+CREATE TABLE people (id INTEGER PRIMARY KEY, name text, age integer);
+INSERT INTO people VALUES (1, 'alice', 30);
+INSERT INTO people VALUES (2, 'bob', 25);
@@ -0,0 +1,4 @@
+-- created with `.filectrl reserve_bytes 32` before the first table, each page ends with
+-- 32 unused bytes
+CREATE TABLE t(id INTEGER PRIMARY KEY, body text);
+INSERT INTO t VALUES (1, printf('%.5000c','z')), (2,'small');
@@ -0,0 +1,10 @@
+-- "id" is an ordinary column
+CREATE TABLE people (id text, name text);
+INSERT INTO people VALUES ('p-1', 'ann'), ('p-2', 'bob');
+-- only a column declared as INTEGER PRIMARY KEY is an alias of the rowid
+CREATE TABLE artists (ArtistId INTEGER, name text, PRIMARY KEY (ArtistId));
+INSERT INTO artists VALUES (10, 'AC/DC'), (20, 'Accept');
+CREATE TABLE codes (code INT PRIMARY KEY, label text);
+INSERT INTO codes VALUES (7, 'seven'), (3, 'three');
+CREATE TABLE reversed (key INTEGER PRIMARY KEY DESC, label text);
+INSERT INTO reversed VALUES (5, 'five');
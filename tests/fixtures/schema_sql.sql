@@ -0,0 +1,13 @@
+-- sqlite3 stores these statements without IF NOT EXISTS and without the schema name
+CREATE TABLE IF NOT EXISTS main.foo (a int, b text);
+CREATE INDEX IF NOT EXISTS main.foo_b on foo (b);
+CREATE UNIQUE INDEX  IF NOT EXISTS foo_a ON foo(a);
+CREATE TABLE "main".bar(x);
+CREATE TABLE legacy (k integer, v text);
+INSERT INTO foo VALUES (1, 'x'), (2, 'y'), (3, 'y');
+INSERT INTO bar VALUES (3);
+INSERT INTO legacy VALUES (1, 'one'), (2, 'two');
+-- statements written by other tools can keep them
+.dbconfig defensive off
+PRAGMA writable_schema = ON;
+UPDATE sqlite_master SET sql = 'CREATE TABLE IF NOT EXISTS legacy (k integer, v text)' WHERE name = 'legacy';
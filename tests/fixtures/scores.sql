@@ -0,0 +1,503 @@
+CREATE TABLE scores (id integer primary key, name text, score integer);
+BEGIN;
+INSERT INTO scores (name, score) VALUES ('player1', 4);
+INSERT INTO scores (name, score) VALUES ('player2', 8);
+INSERT INTO scores (name, score) VALUES ('player3', 1);
+INSERT INTO scores (name, score) VALUES ('player4', 5);
+INSERT INTO scores (name, score) VALUES ('player5', 9);
+INSERT INTO scores (name, score) VALUES ('player6', 2);
+INSERT INTO scores (name, score) VALUES ('player7', 6);
+INSERT INTO scores (name, score) VALUES ('player8', 10);
+INSERT INTO scores (name, score) VALUES ('player9', 3);
+INSERT INTO scores (name, score) VALUES ('player10', 7);
+INSERT INTO scores (name, score) VALUES ('player11', 0);
+INSERT INTO scores (name, score) VALUES ('player12', 4);
+INSERT INTO scores (name, score) VALUES ('player13', 8);
+INSERT INTO scores (name, score) VALUES ('player14', 1);
+INSERT INTO scores (name, score) VALUES ('player15', 5);
+INSERT INTO scores (name, score) VALUES ('player16', 9);
+INSERT INTO scores (name, score) VALUES ('player17', 2);
+INSERT INTO scores (name, score) VALUES ('player18', 6);
+INSERT INTO scores (name, score) VALUES ('player19', 10);
+INSERT INTO scores (name, score) VALUES ('player20', 3);
+INSERT INTO scores (name, score) VALUES ('player21', 7);
+INSERT INTO scores (name, score) VALUES ('player22', 0);
+INSERT INTO scores (name, score) VALUES ('player23', 4);
+INSERT INTO scores (name, score) VALUES ('player24', 8);
+INSERT INTO scores (name, score) VALUES ('player25', 1);
+INSERT INTO scores (name, score) VALUES ('player26', 5);
+INSERT INTO scores (name, score) VALUES ('player27', 9);
+INSERT INTO scores (name, score) VALUES ('player28', 2);
+INSERT INTO scores (name, score) VALUES ('player29', 6);
+INSERT INTO scores (name, score) VALUES ('player30', 10);
+INSERT INTO scores (name, score) VALUES ('player31', 3);
+INSERT INTO scores (name, score) VALUES ('player32', 7);
+INSERT INTO scores (name, score) VALUES ('player33', 0);
+INSERT INTO scores (name, score) VALUES ('player34', 4);
+INSERT INTO scores (name, score) VALUES ('player35', 8);
+INSERT INTO scores (name, score) VALUES ('player36', 1);
+INSERT INTO scores (name, score) VALUES ('player37', 5);
+INSERT INTO scores (name, score) VALUES ('player38', 9);
+INSERT INTO scores (name, score) VALUES ('player39', 2);
+INSERT INTO scores (name, score) VALUES ('player40', 6);
+INSERT INTO scores (name, score) VALUES ('player41', 10);
+INSERT INTO scores (name, score) VALUES ('player42', 3);
+INSERT INTO scores (name, score) VALUES ('player43', 7);
+INSERT INTO scores (name, score) VALUES ('player44', 0);
+INSERT INTO scores (name, score) VALUES ('player45', 4);
+INSERT INTO scores (name, score) VALUES ('player46', 8);
+INSERT INTO scores (name, score) VALUES ('player47', 1);
+INSERT INTO scores (name, score) VALUES ('player48', 5);
+INSERT INTO scores (name, score) VALUES ('player49', 9);
+INSERT INTO scores (name, score) VALUES ('player50', 2);
+INSERT INTO scores (name, score) VALUES ('player51', 6);
+INSERT INTO scores (name, score) VALUES ('player52', 10);
+INSERT INTO scores (name, score) VALUES ('player53', 3);
+INSERT INTO scores (name, score) VALUES ('player54', 7);
+INSERT INTO scores (name, score) VALUES ('player55', 0);
+INSERT INTO scores (name, score) VALUES ('player56', 4);
+INSERT INTO scores (name, score) VALUES ('player57', 8);
+INSERT INTO scores (name, score) VALUES ('player58', 1);
+INSERT INTO scores (name, score) VALUES ('player59', 5);
+INSERT INTO scores (name, score) VALUES ('player60', 9);
+INSERT INTO scores (name, score) VALUES ('player61', 2);
+INSERT INTO scores (name, score) VALUES ('player62', 6);
+INSERT INTO scores (name, score) VALUES ('player63', 10);
+INSERT INTO scores (name, score) VALUES ('player64', 3);
+INSERT INTO scores (name, score) VALUES ('player65', 7);
+INSERT INTO scores (name, score) VALUES ('player66', 0);
+INSERT INTO scores (name, score) VALUES ('player67', 4);
+INSERT INTO scores (name, score) VALUES ('player68', 8);
+INSERT INTO scores (name, score) VALUES ('player69', 1);
+INSERT INTO scores (name, score) VALUES ('player70', 5);
+INSERT INTO scores (name, score) VALUES ('player71', 9);
+INSERT INTO scores (name, score) VALUES ('player72', 2);
+INSERT INTO scores (name, score) VALUES ('player73', 6);
+INSERT INTO scores (name, score) VALUES ('player74', 10);
+INSERT INTO scores (name, score) VALUES ('player75', 3);
+INSERT INTO scores (name, score) VALUES ('player76', 7);
+INSERT INTO scores (name, score) VALUES ('player77', 0);
+INSERT INTO scores (name, score) VALUES ('player78', 4);
+INSERT INTO scores (name, score) VALUES ('player79', 8);
+INSERT INTO scores (name, score) VALUES ('player80', 1);
+INSERT INTO scores (name, score) VALUES ('player81', 5);
+INSERT INTO scores (name, score) VALUES ('player82', 9);
+INSERT INTO scores (name, score) VALUES ('player83', 2);
+INSERT INTO scores (name, score) VALUES ('player84', 6);
+INSERT INTO scores (name, score) VALUES ('player85', 10);
+INSERT INTO scores (name, score) VALUES ('player86', 3);
+INSERT INTO scores (name, score) VALUES ('player87', 7);
+INSERT INTO scores (name, score) VALUES ('player88', 0);
+INSERT INTO scores (name, score) VALUES ('player89', 4);
+INSERT INTO scores (name, score) VALUES ('player90', 8);
+INSERT INTO scores (name, score) VALUES ('player91', 1);
+INSERT INTO scores (name, score) VALUES ('player92', 5);
+INSERT INTO scores (name, score) VALUES ('player93', 9);
+INSERT INTO scores (name, score) VALUES ('player94', 2);
+INSERT INTO scores (name, score) VALUES ('player95', 6);
+INSERT INTO scores (name, score) VALUES ('player96', 10);
+INSERT INTO scores (name, score) VALUES ('player97', 3);
+INSERT INTO scores (name, score) VALUES ('player98', 7);
+INSERT INTO scores (name, score) VALUES ('player99', 0);
+INSERT INTO scores (name, score) VALUES ('player100', 4);
+INSERT INTO scores (name, score) VALUES ('player101', 8);
+INSERT INTO scores (name, score) VALUES ('player102', 1);
+INSERT INTO scores (name, score) VALUES ('player103', 5);
+INSERT INTO scores (name, score) VALUES ('player104', 9);
+INSERT INTO scores (name, score) VALUES ('player105', 2);
+INSERT INTO scores (name, score) VALUES ('player106', 6);
+INSERT INTO scores (name, score) VALUES ('player107', 10);
+INSERT INTO scores (name, score) VALUES ('player108', 3);
+INSERT INTO scores (name, score) VALUES ('player109', 7);
+INSERT INTO scores (name, score) VALUES ('player110', 0);
+INSERT INTO scores (name, score) VALUES ('player111', 4);
+INSERT INTO scores (name, score) VALUES ('player112', 8);
+INSERT INTO scores (name, score) VALUES ('player113', 1);
+INSERT INTO scores (name, score) VALUES ('player114', 5);
+INSERT INTO scores (name, score) VALUES ('player115', 9);
+INSERT INTO scores (name, score) VALUES ('player116', 2);
+INSERT INTO scores (name, score) VALUES ('player117', 6);
+INSERT INTO scores (name, score) VALUES ('player118', 10);
+INSERT INTO scores (name, score) VALUES ('player119', 3);
+INSERT INTO scores (name, score) VALUES ('player120', 7);
+INSERT INTO scores (name, score) VALUES ('player121', 0);
+INSERT INTO scores (name, score) VALUES ('player122', 4);
+INSERT INTO scores (name, score) VALUES ('player123', 8);
+INSERT INTO scores (name, score) VALUES ('player124', 1);
+INSERT INTO scores (name, score) VALUES ('player125', 5);
+INSERT INTO scores (name, score) VALUES ('player126', 9);
+INSERT INTO scores (name, score) VALUES ('player127', 2);
+INSERT INTO scores (name, score) VALUES ('player128', 6);
+INSERT INTO scores (name, score) VALUES ('player129', 10);
+INSERT INTO scores (name, score) VALUES ('player130', 3);
+INSERT INTO scores (name, score) VALUES ('player131', 7);
+INSERT INTO scores (name, score) VALUES ('player132', 0);
+INSERT INTO scores (name, score) VALUES ('player133', 4);
+INSERT INTO scores (name, score) VALUES ('player134', 8);
+INSERT INTO scores (name, score) VALUES ('player135', 1);
+INSERT INTO scores (name, score) VALUES ('player136', 5);
+INSERT INTO scores (name, score) VALUES ('player137', 9);
+INSERT INTO scores (name, score) VALUES ('player138', 2);
+INSERT INTO scores (name, score) VALUES ('player139', 6);
+INSERT INTO scores (name, score) VALUES ('player140', 10);
+INSERT INTO scores (name, score) VALUES ('player141', 3);
+INSERT INTO scores (name, score) VALUES ('player142', 7);
+INSERT INTO scores (name, score) VALUES ('player143', 0);
+INSERT INTO scores (name, score) VALUES ('player144', 4);
+INSERT INTO scores (name, score) VALUES ('player145', 8);
+INSERT INTO scores (name, score) VALUES ('player146', 1);
+INSERT INTO scores (name, score) VALUES ('player147', 5);
+INSERT INTO scores (name, score) VALUES ('player148', 9);
+INSERT INTO scores (name, score) VALUES ('player149', 2);
+INSERT INTO scores (name, score) VALUES ('player150', 6);
+INSERT INTO scores (name, score) VALUES ('player151', 10);
+INSERT INTO scores (name, score) VALUES ('player152', 3);
+INSERT INTO scores (name, score) VALUES ('player153', 7);
+INSERT INTO scores (name, score) VALUES ('player154', 0);
+INSERT INTO scores (name, score) VALUES ('player155', 4);
+INSERT INTO scores (name, score) VALUES ('player156', 8);
+INSERT INTO scores (name, score) VALUES ('player157', 1);
+INSERT INTO scores (name, score) VALUES ('player158', 5);
+INSERT INTO scores (name, score) VALUES ('player159', 9);
+INSERT INTO scores (name, score) VALUES ('player160', 2);
+INSERT INTO scores (name, score) VALUES ('player161', 6);
+INSERT INTO scores (name, score) VALUES ('player162', 10);
+INSERT INTO scores (name, score) VALUES ('player163', 3);
+INSERT INTO scores (name, score) VALUES ('player164', 7);
+INSERT INTO scores (name, score) VALUES ('player165', 0);
+INSERT INTO scores (name, score) VALUES ('player166', 4);
+INSERT INTO scores (name, score) VALUES ('player167', 8);
+INSERT INTO scores (name, score) VALUES ('player168', 1);
+INSERT INTO scores (name, score) VALUES ('player169', 5);
+INSERT INTO scores (name, score) VALUES ('player170', 9);
+INSERT INTO scores (name, score) VALUES ('player171', 2);
+INSERT INTO scores (name, score) VALUES ('player172', 6);
+INSERT INTO scores (name, score) VALUES ('player173', 10);
+INSERT INTO scores (name, score) VALUES ('player174', 3);
+INSERT INTO scores (name, score) VALUES ('player175', 7);
+INSERT INTO scores (name, score) VALUES ('player176', 0);
+INSERT INTO scores (name, score) VALUES ('player177', 4);
+INSERT INTO scores (name, score) VALUES ('player178', 8);
+INSERT INTO scores (name, score) VALUES ('player179', 1);
+INSERT INTO scores (name, score) VALUES ('player180', 5);
+INSERT INTO scores (name, score) VALUES ('player181', 9);
+INSERT INTO scores (name, score) VALUES ('player182', 2);
+INSERT INTO scores (name, score) VALUES ('player183', 6);
+INSERT INTO scores (name, score) VALUES ('player184', 10);
+INSERT INTO scores (name, score) VALUES ('player185', 3);
+INSERT INTO scores (name, score) VALUES ('player186', 7);
+INSERT INTO scores (name, score) VALUES ('player187', 0);
+INSERT INTO scores (name, score) VALUES ('player188', 4);
+INSERT INTO scores (name, score) VALUES ('player189', 8);
+INSERT INTO scores (name, score) VALUES ('player190', 1);
+INSERT INTO scores (name, score) VALUES ('player191', 5);
+INSERT INTO scores (name, score) VALUES ('player192', 9);
+INSERT INTO scores (name, score) VALUES ('player193', 2);
+INSERT INTO scores (name, score) VALUES ('player194', 6);
+INSERT INTO scores (name, score) VALUES ('player195', 10);
+INSERT INTO scores (name, score) VALUES ('player196', 3);
+INSERT INTO scores (name, score) VALUES ('player197', 7);
+INSERT INTO scores (name, score) VALUES ('player198', 0);
+INSERT INTO scores (name, score) VALUES ('player199', 4);
+INSERT INTO scores (name, score) VALUES ('player200', 8);
+INSERT INTO scores (name, score) VALUES ('player201', 1);
+INSERT INTO scores (name, score) VALUES ('player202', 5);
+INSERT INTO scores (name, score) VALUES ('player203', 9);
+INSERT INTO scores (name, score) VALUES ('player204', 2);
+INSERT INTO scores (name, score) VALUES ('player205', 6);
+INSERT INTO scores (name, score) VALUES ('player206', 10);
+INSERT INTO scores (name, score) VALUES ('player207', 3);
+INSERT INTO scores (name, score) VALUES ('player208', 7);
+INSERT INTO scores (name, score) VALUES ('player209', 0);
+INSERT INTO scores (name, score) VALUES ('player210', 4);
+INSERT INTO scores (name, score) VALUES ('player211', 8);
+INSERT INTO scores (name, score) VALUES ('player212', 1);
+INSERT INTO scores (name, score) VALUES ('player213', 5);
+INSERT INTO scores (name, score) VALUES ('player214', 9);
+INSERT INTO scores (name, score) VALUES ('player215', 2);
+INSERT INTO scores (name, score) VALUES ('player216', 6);
+INSERT INTO scores (name, score) VALUES ('player217', 10);
+INSERT INTO scores (name, score) VALUES ('player218', 3);
+INSERT INTO scores (name, score) VALUES ('player219', 7);
+INSERT INTO scores (name, score) VALUES ('player220', 0);
+INSERT INTO scores (name, score) VALUES ('player221', 4);
+INSERT INTO scores (name, score) VALUES ('player222', 8);
+INSERT INTO scores (name, score) VALUES ('player223', 1);
+INSERT INTO scores (name, score) VALUES ('player224', 5);
+INSERT INTO scores (name, score) VALUES ('player225', 9);
+INSERT INTO scores (name, score) VALUES ('player226', 2);
+INSERT INTO scores (name, score) VALUES ('player227', 6);
+INSERT INTO scores (name, score) VALUES ('player228', 10);
+INSERT INTO scores (name, score) VALUES ('player229', 3);
+INSERT INTO scores (name, score) VALUES ('player230', 7);
+INSERT INTO scores (name, score) VALUES ('player231', 0);
+INSERT INTO scores (name, score) VALUES ('player232', 4);
+INSERT INTO scores (name, score) VALUES ('player233', 8);
+INSERT INTO scores (name, score) VALUES ('player234', 1);
+INSERT INTO scores (name, score) VALUES ('player235', 5);
+INSERT INTO scores (name, score) VALUES ('player236', 9);
+INSERT INTO scores (name, score) VALUES ('player237', 2);
+INSERT INTO scores (name, score) VALUES ('player238', 6);
+INSERT INTO scores (name, score) VALUES ('player239', 10);
+INSERT INTO scores (name, score) VALUES ('player240', 3);
+INSERT INTO scores (name, score) VALUES ('player241', 7);
+INSERT INTO scores (name, score) VALUES ('player242', 0);
+INSERT INTO scores (name, score) VALUES ('player243', 4);
+INSERT INTO scores (name, score) VALUES ('player244', 8);
+INSERT INTO scores (name, score) VALUES ('player245', 1);
+INSERT INTO scores (name, score) VALUES ('player246', 5);
+INSERT INTO scores (name, score) VALUES ('player247', 9);
+INSERT INTO scores (name, score) VALUES ('player248', 2);
+INSERT INTO scores (name, score) VALUES ('player249', 6);
+INSERT INTO scores (name, score) VALUES ('player250', 10);
+INSERT INTO scores (name, score) VALUES ('player251', 3);
+INSERT INTO scores (name, score) VALUES ('player252', 7);
+INSERT INTO scores (name, score) VALUES ('player253', 0);
+INSERT INTO scores (name, score) VALUES ('player254', 4);
+INSERT INTO scores (name, score) VALUES ('player255', 8);
+INSERT INTO scores (name, score) VALUES ('player256', 1);
+INSERT INTO scores (name, score) VALUES ('player257', 5);
+INSERT INTO scores (name, score) VALUES ('player258', 9);
+INSERT INTO scores (name, score) VALUES ('player259', 2);
+INSERT INTO scores (name, score) VALUES ('player260', 6);
+INSERT INTO scores (name, score) VALUES ('player261', 10);
+INSERT INTO scores (name, score) VALUES ('player262', 3);
+INSERT INTO scores (name, score) VALUES ('player263', 7);
+INSERT INTO scores (name, score) VALUES ('player264', 0);
+INSERT INTO scores (name, score) VALUES ('player265', 4);
+INSERT INTO scores (name, score) VALUES ('player266', 8);
+INSERT INTO scores (name, score) VALUES ('player267', 1);
+INSERT INTO scores (name, score) VALUES ('player268', 5);
+INSERT INTO scores (name, score) VALUES ('player269', 9);
+INSERT INTO scores (name, score) VALUES ('player270', 2);
+INSERT INTO scores (name, score) VALUES ('player271', 6);
+INSERT INTO scores (name, score) VALUES ('player272', 10);
+INSERT INTO scores (name, score) VALUES ('player273', 3);
+INSERT INTO scores (name, score) VALUES ('player274', 7);
+INSERT INTO scores (name, score) VALUES ('player275', 0);
+INSERT INTO scores (name, score) VALUES ('player276', 4);
+INSERT INTO scores (name, score) VALUES ('player277', 8);
+INSERT INTO scores (name, score) VALUES ('player278', 1);
+INSERT INTO scores (name, score) VALUES ('player279', 5);
+INSERT INTO scores (name, score) VALUES ('player280', 9);
+INSERT INTO scores (name, score) VALUES ('player281', 2);
+INSERT INTO scores (name, score) VALUES ('player282', 6);
+INSERT INTO scores (name, score) VALUES ('player283', 10);
+INSERT INTO scores (name, score) VALUES ('player284', 3);
+INSERT INTO scores (name, score) VALUES ('player285', 7);
+INSERT INTO scores (name, score) VALUES ('player286', 0);
+INSERT INTO scores (name, score) VALUES ('player287', 4);
+INSERT INTO scores (name, score) VALUES ('player288', 8);
+INSERT INTO scores (name, score) VALUES ('player289', 1);
+INSERT INTO scores (name, score) VALUES ('player290', 5);
+INSERT INTO scores (name, score) VALUES ('player291', 9);
+INSERT INTO scores (name, score) VALUES ('player292', 2);
+INSERT INTO scores (name, score) VALUES ('player293', 6);
+INSERT INTO scores (name, score) VALUES ('player294', 10);
+INSERT INTO scores (name, score) VALUES ('player295', 3);
+INSERT INTO scores (name, score) VALUES ('player296', 7);
+INSERT INTO scores (name, score) VALUES ('player297', 0);
+INSERT INTO scores (name, score) VALUES ('player298', 4);
+INSERT INTO scores (name, score) VALUES ('player299', 8);
+INSERT INTO scores (name, score) VALUES ('player300', 1);
+INSERT INTO scores (name, score) VALUES ('player301', 5);
+INSERT INTO scores (name, score) VALUES ('player302', 9);
+INSERT INTO scores (name, score) VALUES ('player303', 2);
+INSERT INTO scores (name, score) VALUES ('player304', 6);
+INSERT INTO scores (name, score) VALUES ('player305', 10);
+INSERT INTO scores (name, score) VALUES ('player306', 3);
+INSERT INTO scores (name, score) VALUES ('player307', 7);
+INSERT INTO scores (name, score) VALUES ('player308', 0);
+INSERT INTO scores (name, score) VALUES ('player309', 4);
+INSERT INTO scores (name, score) VALUES ('player310', 8);
+INSERT INTO scores (name, score) VALUES ('player311', 1);
+INSERT INTO scores (name, score) VALUES ('player312', 5);
+INSERT INTO scores (name, score) VALUES ('player313', 9);
+INSERT INTO scores (name, score) VALUES ('player314', 2);
+INSERT INTO scores (name, score) VALUES ('player315', 6);
+INSERT INTO scores (name, score) VALUES ('player316', 10);
+INSERT INTO scores (name, score) VALUES ('player317', 3);
+INSERT INTO scores (name, score) VALUES ('player318', 7);
+INSERT INTO scores (name, score) VALUES ('player319', 0);
+INSERT INTO scores (name, score) VALUES ('player320', 4);
+INSERT INTO scores (name, score) VALUES ('player321', 8);
+INSERT INTO scores (name, score) VALUES ('player322', 1);
+INSERT INTO scores (name, score) VALUES ('player323', 5);
+INSERT INTO scores (name, score) VALUES ('player324', 9);
+INSERT INTO scores (name, score) VALUES ('player325', 2);
+INSERT INTO scores (name, score) VALUES ('player326', 6);
+INSERT INTO scores (name, score) VALUES ('player327', 10);
+INSERT INTO scores (name, score) VALUES ('player328', 3);
+INSERT INTO scores (name, score) VALUES ('player329', 7);
+INSERT INTO scores (name, score) VALUES ('player330', 0);
+INSERT INTO scores (name, score) VALUES ('player331', 4);
+INSERT INTO scores (name, score) VALUES ('player332', 8);
+INSERT INTO scores (name, score) VALUES ('player333', 1);
+INSERT INTO scores (name, score) VALUES ('player334', 5);
+INSERT INTO scores (name, score) VALUES ('player335', 9);
+INSERT INTO scores (name, score) VALUES ('player336', 2);
+INSERT INTO scores (name, score) VALUES ('player337', 6);
+INSERT INTO scores (name, score) VALUES ('player338', 10);
+INSERT INTO scores (name, score) VALUES ('player339', 3);
+INSERT INTO scores (name, score) VALUES ('player340', 7);
+INSERT INTO scores (name, score) VALUES ('player341', 0);
+INSERT INTO scores (name, score) VALUES ('player342', 4);
+INSERT INTO scores (name, score) VALUES ('player343', 8);
+INSERT INTO scores (name, score) VALUES ('player344', 1);
+INSERT INTO scores (name, score) VALUES ('player345', 5);
+INSERT INTO scores (name, score) VALUES ('player346', 9);
+INSERT INTO scores (name, score) VALUES ('player347', 2);
+INSERT INTO scores (name, score) VALUES ('player348', 6);
+INSERT INTO scores (name, score) VALUES ('player349', 10);
+INSERT INTO scores (name, score) VALUES ('player350', 3);
+INSERT INTO scores (name, score) VALUES ('player351', 7);
+INSERT INTO scores (name, score) VALUES ('player352', 0);
+INSERT INTO scores (name, score) VALUES ('player353', 4);
+INSERT INTO scores (name, score) VALUES ('player354', 8);
+INSERT INTO scores (name, score) VALUES ('player355', 1);
+INSERT INTO scores (name, score) VALUES ('player356', 5);
+INSERT INTO scores (name, score) VALUES ('player357', 9);
+INSERT INTO scores (name, score) VALUES ('player358', 2);
+INSERT INTO scores (name, score) VALUES ('player359', 6);
+INSERT INTO scores (name, score) VALUES ('player360', 10);
+INSERT INTO scores (name, score) VALUES ('player361', 3);
+INSERT INTO scores (name, score) VALUES ('player362', 7);
+INSERT INTO scores (name, score) VALUES ('player363', 0);
+INSERT INTO scores (name, score) VALUES ('player364', 4);
+INSERT INTO scores (name, score) VALUES ('player365', 8);
+INSERT INTO scores (name, score) VALUES ('player366', 1);
+INSERT INTO scores (name, score) VALUES ('player367', 5);
+INSERT INTO scores (name, score) VALUES ('player368', 9);
+INSERT INTO scores (name, score) VALUES ('player369', 2);
+INSERT INTO scores (name, score) VALUES ('player370', 6);
+INSERT INTO scores (name, score) VALUES ('player371', 10);
+INSERT INTO scores (name, score) VALUES ('player372', 3);
+INSERT INTO scores (name, score) VALUES ('player373', 7);
+INSERT INTO scores (name, score) VALUES ('player374', 0);
+INSERT INTO scores (name, score) VALUES ('player375', 4);
+INSERT INTO scores (name, score) VALUES ('player376', 8);
+INSERT INTO scores (name, score) VALUES ('player377', 1);
+INSERT INTO scores (name, score) VALUES ('player378', 5);
+INSERT INTO scores (name, score) VALUES ('player379', 9);
+INSERT INTO scores (name, score) VALUES ('player380', 2);
+INSERT INTO scores (name, score) VALUES ('player381', 6);
+INSERT INTO scores (name, score) VALUES ('player382', 10);
+INSERT INTO scores (name, score) VALUES ('player383', 3);
+INSERT INTO scores (name, score) VALUES ('player384', 7);
+INSERT INTO scores (name, score) VALUES ('player385', 0);
+INSERT INTO scores (name, score) VALUES ('player386', 4);
+INSERT INTO scores (name, score) VALUES ('player387', 8);
+INSERT INTO scores (name, score) VALUES ('player388', 1);
+INSERT INTO scores (name, score) VALUES ('player389', 5);
+INSERT INTO scores (name, score) VALUES ('player390', 9);
+INSERT INTO scores (name, score) VALUES ('player391', 2);
+INSERT INTO scores (name, score) VALUES ('player392', 6);
+INSERT INTO scores (name, score) VALUES ('player393', 10);
+INSERT INTO scores (name, score) VALUES ('player394', 3);
+INSERT INTO scores (name, score) VALUES ('player395', 7);
+INSERT INTO scores (name, score) VALUES ('player396', 0);
+INSERT INTO scores (name, score) VALUES ('player397', 4);
+INSERT INTO scores (name, score) VALUES ('player398', 8);
+INSERT INTO scores (name, score) VALUES ('player399', 1);
+INSERT INTO scores (name, score) VALUES ('player400', 5);
+INSERT INTO scores (name, score) VALUES ('player401', 9);
+INSERT INTO scores (name, score) VALUES ('player402', 2);
+INSERT INTO scores (name, score) VALUES ('player403', 6);
+INSERT INTO scores (name, score) VALUES ('player404', 10);
+INSERT INTO scores (name, score) VALUES ('player405', 3);
+INSERT INTO scores (name, score) VALUES ('player406', 7);
+INSERT INTO scores (name, score) VALUES ('player407', 0);
+INSERT INTO scores (name, score) VALUES ('player408', 4);
+INSERT INTO scores (name, score) VALUES ('player409', 8);
+INSERT INTO scores (name, score) VALUES ('player410', 1);
+INSERT INTO scores (name, score) VALUES ('player411', 5);
+INSERT INTO scores (name, score) VALUES ('player412', 9);
+INSERT INTO scores (name, score) VALUES ('player413', 2);
+INSERT INTO scores (name, score) VALUES ('player414', 6);
+INSERT INTO scores (name, score) VALUES ('player415', 10);
+INSERT INTO scores (name, score) VALUES ('player416', 3);
+INSERT INTO scores (name, score) VALUES ('player417', 7);
+INSERT INTO scores (name, score) VALUES ('player418', 0);
+INSERT INTO scores (name, score) VALUES ('player419', 4);
+INSERT INTO scores (name, score) VALUES ('player420', 8);
+INSERT INTO scores (name, score) VALUES ('player421', 1);
+INSERT INTO scores (name, score) VALUES ('player422', 5);
+INSERT INTO scores (name, score) VALUES ('player423', 9);
+INSERT INTO scores (name, score) VALUES ('player424', 2);
+INSERT INTO scores (name, score) VALUES ('player425', 6);
+INSERT INTO scores (name, score) VALUES ('player426', 10);
+INSERT INTO scores (name, score) VALUES ('player427', 3);
+INSERT INTO scores (name, score) VALUES ('player428', 7);
+INSERT INTO scores (name, score) VALUES ('player429', 0);
+INSERT INTO scores (name, score) VALUES ('player430', 4);
+INSERT INTO scores (name, score) VALUES ('player431', 8);
+INSERT INTO scores (name, score) VALUES ('player432', 1);
+INSERT INTO scores (name, score) VALUES ('player433', 5);
+INSERT INTO scores (name, score) VALUES ('player434', 9);
+INSERT INTO scores (name, score) VALUES ('player435', 2);
+INSERT INTO scores (name, score) VALUES ('player436', 6);
+INSERT INTO scores (name, score) VALUES ('player437', 10);
+INSERT INTO scores (name, score) VALUES ('player438', 3);
+INSERT INTO scores (name, score) VALUES ('player439', 7);
+INSERT INTO scores (name, score) VALUES ('player440', 0);
+INSERT INTO scores (name, score) VALUES ('player441', 4);
+INSERT INTO scores (name, score) VALUES ('player442', 8);
+INSERT INTO scores (name, score) VALUES ('player443', 1);
+INSERT INTO scores (name, score) VALUES ('player444', 5);
+INSERT INTO scores (name, score) VALUES ('player445', 9);
+INSERT INTO scores (name, score) VALUES ('player446', 2);
+INSERT INTO scores (name, score) VALUES ('player447', 6);
+INSERT INTO scores (name, score) VALUES ('player448', 10);
+INSERT INTO scores (name, score) VALUES ('player449', 3);
+INSERT INTO scores (name, score) VALUES ('player450', 7);
+INSERT INTO scores (name, score) VALUES ('player451', 0);
+INSERT INTO scores (name, score) VALUES ('player452', 4);
+INSERT INTO scores (name, score) VALUES ('player453', 8);
+INSERT INTO scores (name, score) VALUES ('player454', 1);
+INSERT INTO scores (name, score) VALUES ('player455', 5);
+INSERT INTO scores (name, score) VALUES ('player456', 9);
+INSERT INTO scores (name, score) VALUES ('player457', 2);
+INSERT INTO scores (name, score) VALUES ('player458', 6);
+INSERT INTO scores (name, score) VALUES ('player459', 10);
+INSERT INTO scores (name, score) VALUES ('player460', 3);
+INSERT INTO scores (name, score) VALUES ('player461', 7);
+INSERT INTO scores (name, score) VALUES ('player462', 0);
+INSERT INTO scores (name, score) VALUES ('player463', 4);
+INSERT INTO scores (name, score) VALUES ('player464', 8);
+INSERT INTO scores (name, score) VALUES ('player465', 1);
+INSERT INTO scores (name, score) VALUES ('player466', 5);
+INSERT INTO scores (name, score) VALUES ('player467', 9);
+INSERT INTO scores (name, score) VALUES ('player468', 2);
+INSERT INTO scores (name, score) VALUES ('player469', 6);
+INSERT INTO scores (name, score) VALUES ('player470', 10);
+INSERT INTO scores (name, score) VALUES ('player471', 3);
+INSERT INTO scores (name, score) VALUES ('player472', 7);
+INSERT INTO scores (name, score) VALUES ('player473', 0);
+INSERT INTO scores (name, score) VALUES ('player474', 4);
+INSERT INTO scores (name, score) VALUES ('player475', 8);
+INSERT INTO scores (name, score) VALUES ('player476', 1);
+INSERT INTO scores (name, score) VALUES ('player477', 5);
+INSERT INTO scores (name, score) VALUES ('player478', 9);
+INSERT INTO scores (name, score) VALUES ('player479', 2);
+INSERT INTO scores (name, score) VALUES ('player480', 6);
+INSERT INTO scores (name, score) VALUES ('player481', 10);
+INSERT INTO scores (name, score) VALUES ('player482', 3);
+INSERT INTO scores (name, score) VALUES ('player483', 7);
+INSERT INTO scores (name, score) VALUES ('player484', 0);
+INSERT INTO scores (name, score) VALUES ('player485', 4);
+INSERT INTO scores (name, score) VALUES ('player486', 8);
+INSERT INTO scores (name, score) VALUES ('player487', 1);
+INSERT INTO scores (name, score) VALUES ('player488', 5);
+INSERT INTO scores (name, score) VALUES ('player489', 9);
+INSERT INTO scores (name, score) VALUES ('player490', 2);
+INSERT INTO scores (name, score) VALUES ('player491', 6);
+INSERT INTO scores (name, score) VALUES ('player492', 10);
+INSERT INTO scores (name, score) VALUES ('player493', 3);
+INSERT INTO scores (name, score) VALUES ('player494', 7);
+INSERT INTO scores (name, score) VALUES ('player495', 0);
+INSERT INTO scores (name, score) VALUES ('player496', 4);
+INSERT INTO scores (name, score) VALUES ('player497', 8);
+INSERT INTO scores (name, score) VALUES ('player498', 1);
+INSERT INTO scores (name, score) VALUES ('player499', 5);
+INSERT INTO scores (name, score) VALUES ('player500', 9);
+COMMIT;
@@ -0,0 +1,5 @@
+-- written by sqlite 3.40.1 through the sqlite3 module of python, instead of the sqlite3
+-- shell, to have the header of another version:
+-- python3 -c "import sqlite3; sqlite3.connect('tests/fixtures/sqlite_3_40.db').executescript(open('tests/fixtures/sqlite_3_40.sql').read())"
+CREATE TABLE fruits (id INTEGER PRIMARY KEY, name text);
+INSERT INTO fruits (name) VALUES ('apple'), ('pear');
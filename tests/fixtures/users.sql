@@ -0,0 +1,3007 @@
+CREATE TABLE users (id integer primary key, email text, name text);
+CREATE UNIQUE INDEX idx_users_email ON users (email);
+CREATE TABLE guests (id integer primary key, email text, name text);
+CREATE INDEX idx_guests_email ON guests (email);
+BEGIN;
+INSERT INTO users (email, name) VALUES ('user1@example.com', 'user 1');
+INSERT INTO guests (email, name) VALUES ('user1@example.com', 'user 1');
+INSERT INTO users (email, name) VALUES ('user2@example.com', 'user 2');
+INSERT INTO guests (email, name) VALUES ('user2@example.com', 'user 2');
+INSERT INTO users (email, name) VALUES ('user3@example.com', 'user 3');
+INSERT INTO guests (email, name) VALUES ('user3@example.com', 'user 3');
+INSERT INTO users (email, name) VALUES ('user4@example.com', 'user 4');
+INSERT INTO guests (email, name) VALUES ('user4@example.com', 'user 4');
+INSERT INTO users (email, name) VALUES ('user5@example.com', 'user 5');
+INSERT INTO guests (email, name) VALUES ('user5@example.com', 'user 5');
+INSERT INTO users (email, name) VALUES ('user6@example.com', 'user 6');
+INSERT INTO guests (email, name) VALUES ('user6@example.com', 'user 6');
+INSERT INTO users (email, name) VALUES ('user7@example.com', 'user 7');
+INSERT INTO guests (email, name) VALUES ('user7@example.com', 'user 7');
+INSERT INTO users (email, name) VALUES ('user8@example.com', 'user 8');
+INSERT INTO guests (email, name) VALUES ('user8@example.com', 'user 8');
+INSERT INTO users (email, name) VALUES ('user9@example.com', 'user 9');
+INSERT INTO guests (email, name) VALUES ('user9@example.com', 'user 9');
+INSERT INTO users (email, name) VALUES ('user10@example.com', 'user 10');
+INSERT INTO guests (email, name) VALUES ('user10@example.com', 'user 10');
+INSERT INTO users (email, name) VALUES ('user11@example.com', 'user 11');
+INSERT INTO guests (email, name) VALUES ('user11@example.com', 'user 11');
+INSERT INTO users (email, name) VALUES ('user12@example.com', 'user 12');
+INSERT INTO guests (email, name) VALUES ('user12@example.com', 'user 12');
+INSERT INTO users (email, name) VALUES ('user13@example.com', 'user 13');
+INSERT INTO guests (email, name) VALUES ('user13@example.com', 'user 13');
+INSERT INTO users (email, name) VALUES ('user14@example.com', 'user 14');
+INSERT INTO guests (email, name) VALUES ('user14@example.com', 'user 14');
+INSERT INTO users (email, name) VALUES ('user15@example.com', 'user 15');
+INSERT INTO guests (email, name) VALUES ('user15@example.com', 'user 15');
+INSERT INTO users (email, name) VALUES ('user16@example.com', 'user 16');
+INSERT INTO guests (email, name) VALUES ('user16@example.com', 'user 16');
+INSERT INTO users (email, name) VALUES ('user17@example.com', 'user 17');
+INSERT INTO guests (email, name) VALUES ('user17@example.com', 'user 17');
+INSERT INTO users (email, name) VALUES ('user18@example.com', 'user 18');
+INSERT INTO guests (email, name) VALUES ('user18@example.com', 'user 18');
+INSERT INTO users (email, name) VALUES ('user19@example.com', 'user 19');
+INSERT INTO guests (email, name) VALUES ('user19@example.com', 'user 19');
+INSERT INTO users (email, name) VALUES ('user20@example.com', 'user 20');
+INSERT INTO guests (email, name) VALUES ('user20@example.com', 'user 20');
+INSERT INTO users (email, name) VALUES ('user21@example.com', 'user 21');
+INSERT INTO guests (email, name) VALUES ('user21@example.com', 'user 21');
+INSERT INTO users (email, name) VALUES ('user22@example.com', 'user 22');
+INSERT INTO guests (email, name) VALUES ('user22@example.com', 'user 22');
+INSERT INTO users (email, name) VALUES ('user23@example.com', 'user 23');
+INSERT INTO guests (email, name) VALUES ('user23@example.com', 'user 23');
+INSERT INTO users (email, name) VALUES ('user24@example.com', 'user 24');
+INSERT INTO guests (email, name) VALUES ('user24@example.com', 'user 24');
+INSERT INTO users (email, name) VALUES ('user25@example.com', 'user 25');
+INSERT INTO guests (email, name) VALUES ('user25@example.com', 'user 25');
+INSERT INTO users (email, name) VALUES ('user26@example.com', 'user 26');
+INSERT INTO guests (email, name) VALUES ('user26@example.com', 'user 26');
+INSERT INTO users (email, name) VALUES ('user27@example.com', 'user 27');
+INSERT INTO guests (email, name) VALUES ('user27@example.com', 'user 27');
+INSERT INTO users (email, name) VALUES ('user28@example.com', 'user 28');
+INSERT INTO guests (email, name) VALUES ('user28@example.com', 'user 28');
+INSERT INTO users (email, name) VALUES ('user29@example.com', 'user 29');
+INSERT INTO guests (email, name) VALUES ('user29@example.com', 'user 29');
+INSERT INTO users (email, name) VALUES ('user30@example.com', 'user 30');
+INSERT INTO guests (email, name) VALUES ('user30@example.com', 'user 30');
+INSERT INTO users (email, name) VALUES ('user31@example.com', 'user 31');
+INSERT INTO guests (email, name) VALUES ('user31@example.com', 'user 31');
+INSERT INTO users (email, name) VALUES ('user32@example.com', 'user 32');
+INSERT INTO guests (email, name) VALUES ('user32@example.com', 'user 32');
+INSERT INTO users (email, name) VALUES ('user33@example.com', 'user 33');
+INSERT INTO guests (email, name) VALUES ('user33@example.com', 'user 33');
+INSERT INTO users (email, name) VALUES ('user34@example.com', 'user 34');
+INSERT INTO guests (email, name) VALUES ('user34@example.com', 'user 34');
+INSERT INTO users (email, name) VALUES ('user35@example.com', 'user 35');
+INSERT INTO guests (email, name) VALUES ('user35@example.com', 'user 35');
+INSERT INTO users (email, name) VALUES ('user36@example.com', 'user 36');
+INSERT INTO guests (email, name) VALUES ('user36@example.com', 'user 36');
+INSERT INTO users (email, name) VALUES ('user37@example.com', 'user 37');
+INSERT INTO guests (email, name) VALUES ('user37@example.com', 'user 37');
+INSERT INTO users (email, name) VALUES ('user38@example.com', 'user 38');
+INSERT INTO guests (email, name) VALUES ('user38@example.com', 'user 38');
+INSERT INTO users (email, name) VALUES ('user39@example.com', 'user 39');
+INSERT INTO guests (email, name) VALUES ('user39@example.com', 'user 39');
+INSERT INTO users (email, name) VALUES ('user40@example.com', 'user 40');
+INSERT INTO guests (email, name) VALUES ('user40@example.com', 'user 40');
+INSERT INTO users (email, name) VALUES ('user41@example.com', 'user 41');
+INSERT INTO guests (email, name) VALUES ('user41@example.com', 'user 41');
+INSERT INTO users (email, name) VALUES ('user42@example.com', 'user 42');
+INSERT INTO guests (email, name) VALUES ('user42@example.com', 'user 42');
+INSERT INTO users (email, name) VALUES ('user43@example.com', 'user 43');
+INSERT INTO guests (email, name) VALUES ('user43@example.com', 'user 43');
+INSERT INTO users (email, name) VALUES ('user44@example.com', 'user 44');
+INSERT INTO guests (email, name) VALUES ('user44@example.com', 'user 44');
+INSERT INTO users (email, name) VALUES ('user45@example.com', 'user 45');
+INSERT INTO guests (email, name) VALUES ('user45@example.com', 'user 45');
+INSERT INTO users (email, name) VALUES ('user46@example.com', 'user 46');
+INSERT INTO guests (email, name) VALUES ('user46@example.com', 'user 46');
+INSERT INTO users (email, name) VALUES ('user47@example.com', 'user 47');
+INSERT INTO guests (email, name) VALUES ('user47@example.com', 'user 47');
+INSERT INTO users (email, name) VALUES ('user48@example.com', 'user 48');
+INSERT INTO guests (email, name) VALUES ('user48@example.com', 'user 48');
+INSERT INTO users (email, name) VALUES ('user49@example.com', 'user 49');
+INSERT INTO guests (email, name) VALUES ('user49@example.com', 'user 49');
+INSERT INTO users (email, name) VALUES ('user50@example.com', 'user 50');
+INSERT INTO guests (email, name) VALUES ('user50@example.com', 'user 50');
+INSERT INTO users (email, name) VALUES ('user51@example.com', 'user 51');
+INSERT INTO guests (email, name) VALUES ('user51@example.com', 'user 51');
+INSERT INTO users (email, name) VALUES ('user52@example.com', 'user 52');
+INSERT INTO guests (email, name) VALUES ('user52@example.com', 'user 52');
+INSERT INTO users (email, name) VALUES ('user53@example.com', 'user 53');
+INSERT INTO guests (email, name) VALUES ('user53@example.com', 'user 53');
+INSERT INTO users (email, name) VALUES ('user54@example.com', 'user 54');
+INSERT INTO guests (email, name) VALUES ('user54@example.com', 'user 54');
+INSERT INTO users (email, name) VALUES ('user55@example.com', 'user 55');
+INSERT INTO guests (email, name) VALUES ('user55@example.com', 'user 55');
+INSERT INTO users (email, name) VALUES ('user56@example.com', 'user 56');
+INSERT INTO guests (email, name) VALUES ('user56@example.com', 'user 56');
+INSERT INTO users (email, name) VALUES ('user57@example.com', 'user 57');
+INSERT INTO guests (email, name) VALUES ('user57@example.com', 'user 57');
+INSERT INTO users (email, name) VALUES ('user58@example.com', 'user 58');
+INSERT INTO guests (email, name) VALUES ('user58@example.com', 'user 58');
+INSERT INTO users (email, name) VALUES ('user59@example.com', 'user 59');
+INSERT INTO guests (email, name) VALUES ('user59@example.com', 'user 59');
+INSERT INTO users (email, name) VALUES ('user60@example.com', 'user 60');
+INSERT INTO guests (email, name) VALUES ('user60@example.com', 'user 60');
+INSERT INTO users (email, name) VALUES ('user61@example.com', 'user 61');
+INSERT INTO guests (email, name) VALUES ('user61@example.com', 'user 61');
+INSERT INTO users (email, name) VALUES ('user62@example.com', 'user 62');
+INSERT INTO guests (email, name) VALUES ('user62@example.com', 'user 62');
+INSERT INTO users (email, name) VALUES ('user63@example.com', 'user 63');
+INSERT INTO guests (email, name) VALUES ('user63@example.com', 'user 63');
+INSERT INTO users (email, name) VALUES ('user64@example.com', 'user 64');
+INSERT INTO guests (email, name) VALUES ('user64@example.com', 'user 64');
+INSERT INTO users (email, name) VALUES ('user65@example.com', 'user 65');
+INSERT INTO guests (email, name) VALUES ('user65@example.com', 'user 65');
+INSERT INTO users (email, name) VALUES ('user66@example.com', 'user 66');
+INSERT INTO guests (email, name) VALUES ('user66@example.com', 'user 66');
+INSERT INTO users (email, name) VALUES ('user67@example.com', 'user 67');
+INSERT INTO guests (email, name) VALUES ('user67@example.com', 'user 67');
+INSERT INTO users (email, name) VALUES ('user68@example.com', 'user 68');
+INSERT INTO guests (email, name) VALUES ('user68@example.com', 'user 68');
+INSERT INTO users (email, name) VALUES ('user69@example.com', 'user 69');
+INSERT INTO guests (email, name) VALUES ('user69@example.com', 'user 69');
+INSERT INTO users (email, name) VALUES ('user70@example.com', 'user 70');
+INSERT INTO guests (email, name) VALUES ('user70@example.com', 'user 70');
+INSERT INTO users (email, name) VALUES ('user71@example.com', 'user 71');
+INSERT INTO guests (email, name) VALUES ('user71@example.com', 'user 71');
+INSERT INTO users (email, name) VALUES ('user72@example.com', 'user 72');
+INSERT INTO guests (email, name) VALUES ('user72@example.com', 'user 72');
+INSERT INTO users (email, name) VALUES ('user73@example.com', 'user 73');
+INSERT INTO guests (email, name) VALUES ('user73@example.com', 'user 73');
+INSERT INTO users (email, name) VALUES ('user74@example.com', 'user 74');
+INSERT INTO guests (email, name) VALUES ('user74@example.com', 'user 74');
+INSERT INTO users (email, name) VALUES ('user75@example.com', 'user 75');
+INSERT INTO guests (email, name) VALUES ('user75@example.com', 'user 75');
+INSERT INTO users (email, name) VALUES ('user76@example.com', 'user 76');
+INSERT INTO guests (email, name) VALUES ('user76@example.com', 'user 76');
+INSERT INTO users (email, name) VALUES ('user77@example.com', 'user 77');
+INSERT INTO guests (email, name) VALUES ('user77@example.com', 'user 77');
+INSERT INTO users (email, name) VALUES ('user78@example.com', 'user 78');
+INSERT INTO guests (email, name) VALUES ('user78@example.com', 'user 78');
+INSERT INTO users (email, name) VALUES ('user79@example.com', 'user 79');
+INSERT INTO guests (email, name) VALUES ('user79@example.com', 'user 79');
+INSERT INTO users (email, name) VALUES ('user80@example.com', 'user 80');
+INSERT INTO guests (email, name) VALUES ('user80@example.com', 'user 80');
+INSERT INTO users (email, name) VALUES ('user81@example.com', 'user 81');
+INSERT INTO guests (email, name) VALUES ('user81@example.com', 'user 81');
+INSERT INTO users (email, name) VALUES ('user82@example.com', 'user 82');
+INSERT INTO guests (email, name) VALUES ('user82@example.com', 'user 82');
+INSERT INTO users (email, name) VALUES ('user83@example.com', 'user 83');
+INSERT INTO guests (email, name) VALUES ('user83@example.com', 'user 83');
+INSERT INTO users (email, name) VALUES ('user84@example.com', 'user 84');
+INSERT INTO guests (email, name) VALUES ('user84@example.com', 'user 84');
+INSERT INTO users (email, name) VALUES ('user85@example.com', 'user 85');
+INSERT INTO guests (email, name) VALUES ('user85@example.com', 'user 85');
+INSERT INTO users (email, name) VALUES ('user86@example.com', 'user 86');
+INSERT INTO guests (email, name) VALUES ('user86@example.com', 'user 86');
+INSERT INTO users (email, name) VALUES ('user87@example.com', 'user 87');
+INSERT INTO guests (email, name) VALUES ('user87@example.com', 'user 87');
+INSERT INTO users (email, name) VALUES ('user88@example.com', 'user 88');
+INSERT INTO guests (email, name) VALUES ('user88@example.com', 'user 88');
+INSERT INTO users (email, name) VALUES ('user89@example.com', 'user 89');
+INSERT INTO guests (email, name) VALUES ('user89@example.com', 'user 89');
+INSERT INTO users (email, name) VALUES ('user90@example.com', 'user 90');
+INSERT INTO guests (email, name) VALUES ('user90@example.com', 'user 90');
+INSERT INTO users (email, name) VALUES ('user91@example.com', 'user 91');
+INSERT INTO guests (email, name) VALUES ('user91@example.com', 'user 91');
+INSERT INTO users (email, name) VALUES ('user92@example.com', 'user 92');
+INSERT INTO guests (email, name) VALUES ('user92@example.com', 'user 92');
+INSERT INTO users (email, name) VALUES ('user93@example.com', 'user 93');
+INSERT INTO guests (email, name) VALUES ('user93@example.com', 'user 93');
+INSERT INTO users (email, name) VALUES ('user94@example.com', 'user 94');
+INSERT INTO guests (email, name) VALUES ('user94@example.com', 'user 94');
+INSERT INTO users (email, name) VALUES ('user95@example.com', 'user 95');
+INSERT INTO guests (email, name) VALUES ('user95@example.com', 'user 95');
+INSERT INTO users (email, name) VALUES ('user96@example.com', 'user 96');
+INSERT INTO guests (email, name) VALUES ('user96@example.com', 'user 96');
+INSERT INTO users (email, name) VALUES ('user97@example.com', 'user 97');
+INSERT INTO guests (email, name) VALUES ('user97@example.com', 'user 97');
+INSERT INTO users (email, name) VALUES ('user98@example.com', 'user 98');
+INSERT INTO guests (email, name) VALUES ('user98@example.com', 'user 98');
+INSERT INTO users (email, name) VALUES ('user99@example.com', 'user 99');
+INSERT INTO guests (email, name) VALUES ('user99@example.com', 'user 99');
+INSERT INTO users (email, name) VALUES ('user100@example.com', 'user 100');
+INSERT INTO guests (email, name) VALUES ('user100@example.com', 'user 100');
+INSERT INTO users (email, name) VALUES ('user101@example.com', 'user 101');
+INSERT INTO guests (email, name) VALUES ('user101@example.com', 'user 101');
+INSERT INTO users (email, name) VALUES ('user102@example.com', 'user 102');
+INSERT INTO guests (email, name) VALUES ('user102@example.com', 'user 102');
+INSERT INTO users (email, name) VALUES ('user103@example.com', 'user 103');
+INSERT INTO guests (email, name) VALUES ('user103@example.com', 'user 103');
+INSERT INTO users (email, name) VALUES ('user104@example.com', 'user 104');
+INSERT INTO guests (email, name) VALUES ('user104@example.com', 'user 104');
+INSERT INTO users (email, name) VALUES ('user105@example.com', 'user 105');
+INSERT INTO guests (email, name) VALUES ('user105@example.com', 'user 105');
+INSERT INTO users (email, name) VALUES ('user106@example.com', 'user 106');
+INSERT INTO guests (email, name) VALUES ('user106@example.com', 'user 106');
+INSERT INTO users (email, name) VALUES ('user107@example.com', 'user 107');
+INSERT INTO guests (email, name) VALUES ('user107@example.com', 'user 107');
+INSERT INTO users (email, name) VALUES ('user108@example.com', 'user 108');
+INSERT INTO guests (email, name) VALUES ('user108@example.com', 'user 108');
+INSERT INTO users (email, name) VALUES ('user109@example.com', 'user 109');
+INSERT INTO guests (email, name) VALUES ('user109@example.com', 'user 109');
+INSERT INTO users (email, name) VALUES ('user110@example.com', 'user 110');
+INSERT INTO guests (email, name) VALUES ('user110@example.com', 'user 110');
+INSERT INTO users (email, name) VALUES ('user111@example.com', 'user 111');
+INSERT INTO guests (email, name) VALUES ('user111@example.com', 'user 111');
+INSERT INTO users (email, name) VALUES ('user112@example.com', 'user 112');
+INSERT INTO guests (email, name) VALUES ('user112@example.com', 'user 112');
+INSERT INTO users (email, name) VALUES ('user113@example.com', 'user 113');
+INSERT INTO guests (email, name) VALUES ('user113@example.com', 'user 113');
+INSERT INTO users (email, name) VALUES ('user114@example.com', 'user 114');
+INSERT INTO guests (email, name) VALUES ('user114@example.com', 'user 114');
+INSERT INTO users (email, name) VALUES ('user115@example.com', 'user 115');
+INSERT INTO guests (email, name) VALUES ('user115@example.com', 'user 115');
+INSERT INTO users (email, name) VALUES ('user116@example.com', 'user 116');
+INSERT INTO guests (email, name) VALUES ('user116@example.com', 'user 116');
+INSERT INTO users (email, name) VALUES ('user117@example.com', 'user 117');
+INSERT INTO guests (email, name) VALUES ('user117@example.com', 'user 117');
+INSERT INTO users (email, name) VALUES ('user118@example.com', 'user 118');
+INSERT INTO guests (email, name) VALUES ('user118@example.com', 'user 118');
+INSERT INTO users (email, name) VALUES ('user119@example.com', 'user 119');
+INSERT INTO guests (email, name) VALUES ('user119@example.com', 'user 119');
+INSERT INTO users (email, name) VALUES ('user120@example.com', 'user 120');
+INSERT INTO guests (email, name) VALUES ('user120@example.com', 'user 120');
+INSERT INTO users (email, name) VALUES ('user121@example.com', 'user 121');
+INSERT INTO guests (email, name) VALUES ('user121@example.com', 'user 121');
+INSERT INTO users (email, name) VALUES ('user122@example.com', 'user 122');
+INSERT INTO guests (email, name) VALUES ('user122@example.com', 'user 122');
+INSERT INTO users (email, name) VALUES ('user123@example.com', 'user 123');
+INSERT INTO guests (email, name) VALUES ('user123@example.com', 'user 123');
+INSERT INTO users (email, name) VALUES ('user124@example.com', 'user 124');
+INSERT INTO guests (email, name) VALUES ('user124@example.com', 'user 124');
+INSERT INTO users (email, name) VALUES ('user125@example.com', 'user 125');
+INSERT INTO guests (email, name) VALUES ('user125@example.com', 'user 125');
+INSERT INTO users (email, name) VALUES ('user126@example.com', 'user 126');
+INSERT INTO guests (email, name) VALUES ('user126@example.com', 'user 126');
+INSERT INTO users (email, name) VALUES ('user127@example.com', 'user 127');
+INSERT INTO guests (email, name) VALUES ('user127@example.com', 'user 127');
+INSERT INTO users (email, name) VALUES ('user128@example.com', 'user 128');
+INSERT INTO guests (email, name) VALUES ('user128@example.com', 'user 128');
+INSERT INTO users (email, name) VALUES ('user129@example.com', 'user 129');
+INSERT INTO guests (email, name) VALUES ('user129@example.com', 'user 129');
+INSERT INTO users (email, name) VALUES ('user130@example.com', 'user 130');
+INSERT INTO guests (email, name) VALUES ('user130@example.com', 'user 130');
+INSERT INTO users (email, name) VALUES ('user131@example.com', 'user 131');
+INSERT INTO guests (email, name) VALUES ('user131@example.com', 'user 131');
+INSERT INTO users (email, name) VALUES ('user132@example.com', 'user 132');
+INSERT INTO guests (email, name) VALUES ('user132@example.com', 'user 132');
+INSERT INTO users (email, name) VALUES ('user133@example.com', 'user 133');
+INSERT INTO guests (email, name) VALUES ('user133@example.com', 'user 133');
+INSERT INTO users (email, name) VALUES ('user134@example.com', 'user 134');
+INSERT INTO guests (email, name) VALUES ('user134@example.com', 'user 134');
+INSERT INTO users (email, name) VALUES ('user135@example.com', 'user 135');
+INSERT INTO guests (email, name) VALUES ('user135@example.com', 'user 135');
+INSERT INTO users (email, name) VALUES ('user136@example.com', 'user 136');
+INSERT INTO guests (email, name) VALUES ('user136@example.com', 'user 136');
+INSERT INTO users (email, name) VALUES ('user137@example.com', 'user 137');
+INSERT INTO guests (email, name) VALUES ('user137@example.com', 'user 137');
+INSERT INTO users (email, name) VALUES ('user138@example.com', 'user 138');
+INSERT INTO guests (email, name) VALUES ('user138@example.com', 'user 138');
+INSERT INTO users (email, name) VALUES ('user139@example.com', 'user 139');
+INSERT INTO guests (email, name) VALUES ('user139@example.com', 'user 139');
+INSERT INTO users (email, name) VALUES ('user140@example.com', 'user 140');
+INSERT INTO guests (email, name) VALUES ('user140@example.com', 'user 140');
+INSERT INTO users (email, name) VALUES ('user141@example.com', 'user 141');
+INSERT INTO guests (email, name) VALUES ('user141@example.com', 'user 141');
+INSERT INTO users (email, name) VALUES ('user142@example.com', 'user 142');
+INSERT INTO guests (email, name) VALUES ('user142@example.com', 'user 142');
+INSERT INTO users (email, name) VALUES ('user143@example.com', 'user 143');
+INSERT INTO guests (email, name) VALUES ('user143@example.com', 'user 143');
+INSERT INTO users (email, name) VALUES ('user144@example.com', 'user 144');
+INSERT INTO guests (email, name) VALUES ('user144@example.com', 'user 144');
+INSERT INTO users (email, name) VALUES ('user145@example.com', 'user 145');
+INSERT INTO guests (email, name) VALUES ('user145@example.com', 'user 145');
+INSERT INTO users (email, name) VALUES ('user146@example.com', 'user 146');
+INSERT INTO guests (email, name) VALUES ('user146@example.com', 'user 146');
+INSERT INTO users (email, name) VALUES ('user147@example.com', 'user 147');
+INSERT INTO guests (email, name) VALUES ('user147@example.com', 'user 147');
+INSERT INTO users (email, name) VALUES ('user148@example.com', 'user 148');
+INSERT INTO guests (email, name) VALUES ('user148@example.com', 'user 148');
+INSERT INTO users (email, name) VALUES ('user149@example.com', 'user 149');
+INSERT INTO guests (email, name) VALUES ('user149@example.com', 'user 149');
+INSERT INTO users (email, name) VALUES ('user150@example.com', 'user 150');
+INSERT INTO guests (email, name) VALUES ('user150@example.com', 'user 150');
+INSERT INTO users (email, name) VALUES ('user151@example.com', 'user 151');
+INSERT INTO guests (email, name) VALUES ('user151@example.com', 'user 151');
+INSERT INTO users (email, name) VALUES ('user152@example.com', 'user 152');
+INSERT INTO guests (email, name) VALUES ('user152@example.com', 'user 152');
+INSERT INTO users (email, name) VALUES ('user153@example.com', 'user 153');
+INSERT INTO guests (email, name) VALUES ('user153@example.com', 'user 153');
+INSERT INTO users (email, name) VALUES ('user154@example.com', 'user 154');
+INSERT INTO guests (email, name) VALUES ('user154@example.com', 'user 154');
+INSERT INTO users (email, name) VALUES ('user155@example.com', 'user 155');
+INSERT INTO guests (email, name) VALUES ('user155@example.com', 'user 155');
+INSERT INTO users (email, name) VALUES ('user156@example.com', 'user 156');
+INSERT INTO guests (email, name) VALUES ('user156@example.com', 'user 156');
+INSERT INTO users (email, name) VALUES ('user157@example.com', 'user 157');
+INSERT INTO guests (email, name) VALUES ('user157@example.com', 'user 157');
+INSERT INTO users (email, name) VALUES ('user158@example.com', 'user 158');
+INSERT INTO guests (email, name) VALUES ('user158@example.com', 'user 158');
+INSERT INTO users (email, name) VALUES ('user159@example.com', 'user 159');
+INSERT INTO guests (email, name) VALUES ('user159@example.com', 'user 159');
+INSERT INTO users (email, name) VALUES ('user160@example.com', 'user 160');
+INSERT INTO guests (email, name) VALUES ('user160@example.com', 'user 160');
+INSERT INTO users (email, name) VALUES ('user161@example.com', 'user 161');
+INSERT INTO guests (email, name) VALUES ('user161@example.com', 'user 161');
+INSERT INTO users (email, name) VALUES ('user162@example.com', 'user 162');
+INSERT INTO guests (email, name) VALUES ('user162@example.com', 'user 162');
+INSERT INTO users (email, name) VALUES ('user163@example.com', 'user 163');
+INSERT INTO guests (email, name) VALUES ('user163@example.com', 'user 163');
+INSERT INTO users (email, name) VALUES ('user164@example.com', 'user 164');
+INSERT INTO guests (email, name) VALUES ('user164@example.com', 'user 164');
+INSERT INTO users (email, name) VALUES ('user165@example.com', 'user 165');
+INSERT INTO guests (email, name) VALUES ('user165@example.com', 'user 165');
+INSERT INTO users (email, name) VALUES ('user166@example.com', 'user 166');
+INSERT INTO guests (email, name) VALUES ('user166@example.com', 'user 166');
+INSERT INTO users (email, name) VALUES ('user167@example.com', 'user 167');
+INSERT INTO guests (email, name) VALUES ('user167@example.com', 'user 167');
+INSERT INTO users (email, name) VALUES ('user168@example.com', 'user 168');
+INSERT INTO guests (email, name) VALUES ('user168@example.com', 'user 168');
+INSERT INTO users (email, name) VALUES ('user169@example.com', 'user 169');
+INSERT INTO guests (email, name) VALUES ('user169@example.com', 'user 169');
+INSERT INTO users (email, name) VALUES ('user170@example.com', 'user 170');
+INSERT INTO guests (email, name) VALUES ('user170@example.com', 'user 170');
+INSERT INTO users (email, name) VALUES ('user171@example.com', 'user 171');
+INSERT INTO guests (email, name) VALUES ('user171@example.com', 'user 171');
+INSERT INTO users (email, name) VALUES ('user172@example.com', 'user 172');
+INSERT INTO guests (email, name) VALUES ('user172@example.com', 'user 172');
+INSERT INTO users (email, name) VALUES ('user173@example.com', 'user 173');
+INSERT INTO guests (email, name) VALUES ('user173@example.com', 'user 173');
+INSERT INTO users (email, name) VALUES ('user174@example.com', 'user 174');
+INSERT INTO guests (email, name) VALUES ('user174@example.com', 'user 174');
+INSERT INTO users (email, name) VALUES ('user175@example.com', 'user 175');
+INSERT INTO guests (email, name) VALUES ('user175@example.com', 'user 175');
+INSERT INTO users (email, name) VALUES ('user176@example.com', 'user 176');
+INSERT INTO guests (email, name) VALUES ('user176@example.com', 'user 176');
+INSERT INTO users (email, name) VALUES ('user177@example.com', 'user 177');
+INSERT INTO guests (email, name) VALUES ('user177@example.com', 'user 177');
+INSERT INTO users (email, name) VALUES ('user178@example.com', 'user 178');
+INSERT INTO guests (email, name) VALUES ('user178@example.com', 'user 178');
+INSERT INTO users (email, name) VALUES ('user179@example.com', 'user 179');
+INSERT INTO guests (email, name) VALUES ('user179@example.com', 'user 179');
+INSERT INTO users (email, name) VALUES ('user180@example.com', 'user 180');
+INSERT INTO guests (email, name) VALUES ('user180@example.com', 'user 180');
+INSERT INTO users (email, name) VALUES ('user181@example.com', 'user 181');
+INSERT INTO guests (email, name) VALUES ('user181@example.com', 'user 181');
+INSERT INTO users (email, name) VALUES ('user182@example.com', 'user 182');
+INSERT INTO guests (email, name) VALUES ('user182@example.com', 'user 182');
+INSERT INTO users (email, name) VALUES ('user183@example.com', 'user 183');
+INSERT INTO guests (email, name) VALUES ('user183@example.com', 'user 183');
+INSERT INTO users (email, name) VALUES ('user184@example.com', 'user 184');
+INSERT INTO guests (email, name) VALUES ('user184@example.com', 'user 184');
+INSERT INTO users (email, name) VALUES ('user185@example.com', 'user 185');
+INSERT INTO guests (email, name) VALUES ('user185@example.com', 'user 185');
+INSERT INTO users (email, name) VALUES ('user186@example.com', 'user 186');
+INSERT INTO guests (email, name) VALUES ('user186@example.com', 'user 186');
+INSERT INTO users (email, name) VALUES ('user187@example.com', 'user 187');
+INSERT INTO guests (email, name) VALUES ('user187@example.com', 'user 187');
+INSERT INTO users (email, name) VALUES ('user188@example.com', 'user 188');
+INSERT INTO guests (email, name) VALUES ('user188@example.com', 'user 188');
+INSERT INTO users (email, name) VALUES ('user189@example.com', 'user 189');
+INSERT INTO guests (email, name) VALUES ('user189@example.com', 'user 189');
+INSERT INTO users (email, name) VALUES ('user190@example.com', 'user 190');
+INSERT INTO guests (email, name) VALUES ('user190@example.com', 'user 190');
+INSERT INTO users (email, name) VALUES ('user191@example.com', 'user 191');
+INSERT INTO guests (email, name) VALUES ('user191@example.com', 'user 191');
+INSERT INTO users (email, name) VALUES ('user192@example.com', 'user 192');
+INSERT INTO guests (email, name) VALUES ('user192@example.com', 'user 192');
+INSERT INTO users (email, name) VALUES ('user193@example.com', 'user 193');
+INSERT INTO guests (email, name) VALUES ('user193@example.com', 'user 193');
+INSERT INTO users (email, name) VALUES ('user194@example.com', 'user 194');
+INSERT INTO guests (email, name) VALUES ('user194@example.com', 'user 194');
+INSERT INTO users (email, name) VALUES ('user195@example.com', 'user 195');
+INSERT INTO guests (email, name) VALUES ('user195@example.com', 'user 195');
+INSERT INTO users (email, name) VALUES ('user196@example.com', 'user 196');
+INSERT INTO guests (email, name) VALUES ('user196@example.com', 'user 196');
+INSERT INTO users (email, name) VALUES ('user197@example.com', 'user 197');
+INSERT INTO guests (email, name) VALUES ('user197@example.com', 'user 197');
+INSERT INTO users (email, name) VALUES ('user198@example.com', 'user 198');
+INSERT INTO guests (email, name) VALUES ('user198@example.com', 'user 198');
+INSERT INTO users (email, name) VALUES ('user199@example.com', 'user 199');
+INSERT INTO guests (email, name) VALUES ('user199@example.com', 'user 199');
+INSERT INTO users (email, name) VALUES ('user200@example.com', 'user 200');
+INSERT INTO guests (email, name) VALUES ('user200@example.com', 'user 200');
+INSERT INTO users (email, name) VALUES ('user201@example.com', 'user 201');
+INSERT INTO guests (email, name) VALUES ('user201@example.com', 'user 201');
+INSERT INTO users (email, name) VALUES ('user202@example.com', 'user 202');
+INSERT INTO guests (email, name) VALUES ('user202@example.com', 'user 202');
+INSERT INTO users (email, name) VALUES ('user203@example.com', 'user 203');
+INSERT INTO guests (email, name) VALUES ('user203@example.com', 'user 203');
+INSERT INTO users (email, name) VALUES ('user204@example.com', 'user 204');
+INSERT INTO guests (email, name) VALUES ('user204@example.com', 'user 204');
+INSERT INTO users (email, name) VALUES ('user205@example.com', 'user 205');
+INSERT INTO guests (email, name) VALUES ('user205@example.com', 'user 205');
+INSERT INTO users (email, name) VALUES ('user206@example.com', 'user 206');
+INSERT INTO guests (email, name) VALUES ('user206@example.com', 'user 206');
+INSERT INTO users (email, name) VALUES ('user207@example.com', 'user 207');
+INSERT INTO guests (email, name) VALUES ('user207@example.com', 'user 207');
+INSERT INTO users (email, name) VALUES ('user208@example.com', 'user 208');
+INSERT INTO guests (email, name) VALUES ('user208@example.com', 'user 208');
+INSERT INTO users (email, name) VALUES ('user209@example.com', 'user 209');
+INSERT INTO guests (email, name) VALUES ('user209@example.com', 'user 209');
+INSERT INTO users (email, name) VALUES ('user210@example.com', 'user 210');
+INSERT INTO guests (email, name) VALUES ('user210@example.com', 'user 210');
+INSERT INTO users (email, name) VALUES ('user211@example.com', 'user 211');
+INSERT INTO guests (email, name) VALUES ('user211@example.com', 'user 211');
+INSERT INTO users (email, name) VALUES ('user212@example.com', 'user 212');
+INSERT INTO guests (email, name) VALUES ('user212@example.com', 'user 212');
+INSERT INTO users (email, name) VALUES ('user213@example.com', 'user 213');
+INSERT INTO guests (email, name) VALUES ('user213@example.com', 'user 213');
+INSERT INTO users (email, name) VALUES ('user214@example.com', 'user 214');
+INSERT INTO guests (email, name) VALUES ('user214@example.com', 'user 214');
+INSERT INTO users (email, name) VALUES ('user215@example.com', 'user 215');
+INSERT INTO guests (email, name) VALUES ('user215@example.com', 'user 215');
+INSERT INTO users (email, name) VALUES ('user216@example.com', 'user 216');
+INSERT INTO guests (email, name) VALUES ('user216@example.com', 'user 216');
+INSERT INTO users (email, name) VALUES ('user217@example.com', 'user 217');
+INSERT INTO guests (email, name) VALUES ('user217@example.com', 'user 217');
+INSERT INTO users (email, name) VALUES ('user218@example.com', 'user 218');
+INSERT INTO guests (email, name) VALUES ('user218@example.com', 'user 218');
+INSERT INTO users (email, name) VALUES ('user219@example.com', 'user 219');
+INSERT INTO guests (email, name) VALUES ('user219@example.com', 'user 219');
+INSERT INTO users (email, name) VALUES ('user220@example.com', 'user 220');
+INSERT INTO guests (email, name) VALUES ('user220@example.com', 'user 220');
+INSERT INTO users (email, name) VALUES ('user221@example.com', 'user 221');
+INSERT INTO guests (email, name) VALUES ('user221@example.com', 'user 221');
+INSERT INTO users (email, name) VALUES ('user222@example.com', 'user 222');
+INSERT INTO guests (email, name) VALUES ('user222@example.com', 'user 222');
+INSERT INTO users (email, name) VALUES ('user223@example.com', 'user 223');
+INSERT INTO guests (email, name) VALUES ('user223@example.com', 'user 223');
+INSERT INTO users (email, name) VALUES ('user224@example.com', 'user 224');
+INSERT INTO guests (email, name) VALUES ('user224@example.com', 'user 224');
+INSERT INTO users (email, name) VALUES ('user225@example.com', 'user 225');
+INSERT INTO guests (email, name) VALUES ('user225@example.com', 'user 225');
+INSERT INTO users (email, name) VALUES ('user226@example.com', 'user 226');
+INSERT INTO guests (email, name) VALUES ('user226@example.com', 'user 226');
+INSERT INTO users (email, name) VALUES ('user227@example.com', 'user 227');
+INSERT INTO guests (email, name) VALUES ('user227@example.com', 'user 227');
+INSERT INTO users (email, name) VALUES ('user228@example.com', 'user 228');
+INSERT INTO guests (email, name) VALUES ('user228@example.com', 'user 228');
+INSERT INTO users (email, name) VALUES ('user229@example.com', 'user 229');
+INSERT INTO guests (email, name) VALUES ('user229@example.com', 'user 229');
+INSERT INTO users (email, name) VALUES ('user230@example.com', 'user 230');
+INSERT INTO guests (email, name) VALUES ('user230@example.com', 'user 230');
+INSERT INTO users (email, name) VALUES ('user231@example.com', 'user 231');
+INSERT INTO guests (email, name) VALUES ('user231@example.com', 'user 231');
+INSERT INTO users (email, name) VALUES ('user232@example.com', 'user 232');
+INSERT INTO guests (email, name) VALUES ('user232@example.com', 'user 232');
+INSERT INTO users (email, name) VALUES ('user233@example.com', 'user 233');
+INSERT INTO guests (email, name) VALUES ('user233@example.com', 'user 233');
+INSERT INTO users (email, name) VALUES ('user234@example.com', 'user 234');
+INSERT INTO guests (email, name) VALUES ('user234@example.com', 'user 234');
+INSERT INTO users (email, name) VALUES ('user235@example.com', 'user 235');
+INSERT INTO guests (email, name) VALUES ('user235@example.com', 'user 235');
+INSERT INTO users (email, name) VALUES ('user236@example.com', 'user 236');
+INSERT INTO guests (email, name) VALUES ('user236@example.com', 'user 236');
+INSERT INTO users (email, name) VALUES ('user237@example.com', 'user 237');
+INSERT INTO guests (email, name) VALUES ('user237@example.com', 'user 237');
+INSERT INTO users (email, name) VALUES ('user238@example.com', 'user 238');
+INSERT INTO guests (email, name) VALUES ('user238@example.com', 'user 238');
+INSERT INTO users (email, name) VALUES ('user239@example.com', 'user 239');
+INSERT INTO guests (email, name) VALUES ('user239@example.com', 'user 239');
+INSERT INTO users (email, name) VALUES ('user240@example.com', 'user 240');
+INSERT INTO guests (email, name) VALUES ('user240@example.com', 'user 240');
+INSERT INTO users (email, name) VALUES ('user241@example.com', 'user 241');
+INSERT INTO guests (email, name) VALUES ('user241@example.com', 'user 241');
+INSERT INTO users (email, name) VALUES ('user242@example.com', 'user 242');
+INSERT INTO guests (email, name) VALUES ('user242@example.com', 'user 242');
+INSERT INTO users (email, name) VALUES ('user243@example.com', 'user 243');
+INSERT INTO guests (email, name) VALUES ('user243@example.com', 'user 243');
+INSERT INTO users (email, name) VALUES ('user244@example.com', 'user 244');
+INSERT INTO guests (email, name) VALUES ('user244@example.com', 'user 244');
+INSERT INTO users (email, name) VALUES ('user245@example.com', 'user 245');
+INSERT INTO guests (email, name) VALUES ('user245@example.com', 'user 245');
+INSERT INTO users (email, name) VALUES ('user246@example.com', 'user 246');
+INSERT INTO guests (email, name) VALUES ('user246@example.com', 'user 246');
+INSERT INTO users (email, name) VALUES ('user247@example.com', 'user 247');
+INSERT INTO guests (email, name) VALUES ('user247@example.com', 'user 247');
+INSERT INTO users (email, name) VALUES ('user248@example.com', 'user 248');
+INSERT INTO guests (email, name) VALUES ('user248@example.com', 'user 248');
+INSERT INTO users (email, name) VALUES ('user249@example.com', 'user 249');
+INSERT INTO guests (email, name) VALUES ('user249@example.com', 'user 249');
+INSERT INTO users (email, name) VALUES ('user250@example.com', 'user 250');
+INSERT INTO guests (email, name) VALUES ('user250@example.com', 'user 250');
+INSERT INTO users (email, name) VALUES ('user251@example.com', 'user 251');
+INSERT INTO guests (email, name) VALUES ('user251@example.com', 'user 251');
+INSERT INTO users (email, name) VALUES ('user252@example.com', 'user 252');
+INSERT INTO guests (email, name) VALUES ('user252@example.com', 'user 252');
+INSERT INTO users (email, name) VALUES ('user253@example.com', 'user 253');
+INSERT INTO guests (email, name) VALUES ('user253@example.com', 'user 253');
+INSERT INTO users (email, name) VALUES ('user254@example.com', 'user 254');
+INSERT INTO guests (email, name) VALUES ('user254@example.com', 'user 254');
+INSERT INTO users (email, name) VALUES ('user255@example.com', 'user 255');
+INSERT INTO guests (email, name) VALUES ('user255@example.com', 'user 255');
+INSERT INTO users (email, name) VALUES ('user256@example.com', 'user 256');
+INSERT INTO guests (email, name) VALUES ('user256@example.com', 'user 256');
+INSERT INTO users (email, name) VALUES ('user257@example.com', 'user 257');
+INSERT INTO guests (email, name) VALUES ('user257@example.com', 'user 257');
+INSERT INTO users (email, name) VALUES ('user258@example.com', 'user 258');
+INSERT INTO guests (email, name) VALUES ('user258@example.com', 'user 258');
+INSERT INTO users (email, name) VALUES ('user259@example.com', 'user 259');
+INSERT INTO guests (email, name) VALUES ('user259@example.com', 'user 259');
+INSERT INTO users (email, name) VALUES ('user260@example.com', 'user 260');
+INSERT INTO guests (email, name) VALUES ('user260@example.com', 'user 260');
+INSERT INTO users (email, name) VALUES ('user261@example.com', 'user 261');
+INSERT INTO guests (email, name) VALUES ('user261@example.com', 'user 261');
+INSERT INTO users (email, name) VALUES ('user262@example.com', 'user 262');
+INSERT INTO guests (email, name) VALUES ('user262@example.com', 'user 262');
+INSERT INTO users (email, name) VALUES ('user263@example.com', 'user 263');
+INSERT INTO guests (email, name) VALUES ('user263@example.com', 'user 263');
+INSERT INTO users (email, name) VALUES ('user264@example.com', 'user 264');
+INSERT INTO guests (email, name) VALUES ('user264@example.com', 'user 264');
+INSERT INTO users (email, name) VALUES ('user265@example.com', 'user 265');
+INSERT INTO guests (email, name) VALUES ('user265@example.com', 'user 265');
+INSERT INTO users (email, name) VALUES ('user266@example.com', 'user 266');
+INSERT INTO guests (email, name) VALUES ('user266@example.com', 'user 266');
+INSERT INTO users (email, name) VALUES ('user267@example.com', 'user 267');
+INSERT INTO guests (email, name) VALUES ('user267@example.com', 'user 267');
+INSERT INTO users (email, name) VALUES ('user268@example.com', 'user 268');
+INSERT INTO guests (email, name) VALUES ('user268@example.com', 'user 268');
+INSERT INTO users (email, name) VALUES ('user269@example.com', 'user 269');
+INSERT INTO guests (email, name) VALUES ('user269@example.com', 'user 269');
+INSERT INTO users (email, name) VALUES ('user270@example.com', 'user 270');
+INSERT INTO guests (email, name) VALUES ('user270@example.com', 'user 270');
+INSERT INTO users (email, name) VALUES ('user271@example.com', 'user 271');
+INSERT INTO guests (email, name) VALUES ('user271@example.com', 'user 271');
+INSERT INTO users (email, name) VALUES ('user272@example.com', 'user 272');
+INSERT INTO guests (email, name) VALUES ('user272@example.com', 'user 272');
+INSERT INTO users (email, name) VALUES ('user273@example.com', 'user 273');
+INSERT INTO guests (email, name) VALUES ('user273@example.com', 'user 273');
+INSERT INTO users (email, name) VALUES ('user274@example.com', 'user 274');
+INSERT INTO guests (email, name) VALUES ('user274@example.com', 'user 274');
+INSERT INTO users (email, name) VALUES ('user275@example.com', 'user 275');
+INSERT INTO guests (email, name) VALUES ('user275@example.com', 'user 275');
+INSERT INTO users (email, name) VALUES ('user276@example.com', 'user 276');
+INSERT INTO guests (email, name) VALUES ('user276@example.com', 'user 276');
+INSERT INTO users (email, name) VALUES ('user277@example.com', 'user 277');
+INSERT INTO guests (email, name) VALUES ('user277@example.com', 'user 277');
+INSERT INTO users (email, name) VALUES ('user278@example.com', 'user 278');
+INSERT INTO guests (email, name) VALUES ('user278@example.com', 'user 278');
+INSERT INTO users (email, name) VALUES ('user279@example.com', 'user 279');
+INSERT INTO guests (email, name) VALUES ('user279@example.com', 'user 279');
+INSERT INTO users (email, name) VALUES ('user280@example.com', 'user 280');
+INSERT INTO guests (email, name) VALUES ('user280@example.com', 'user 280');
+INSERT INTO users (email, name) VALUES ('user281@example.com', 'user 281');
+INSERT INTO guests (email, name) VALUES ('user281@example.com', 'user 281');
+INSERT INTO users (email, name) VALUES ('user282@example.com', 'user 282');
+INSERT INTO guests (email, name) VALUES ('user282@example.com', 'user 282');
+INSERT INTO users (email, name) VALUES ('user283@example.com', 'user 283');
+INSERT INTO guests (email, name) VALUES ('user283@example.com', 'user 283');
+INSERT INTO users (email, name) VALUES ('user284@example.com', 'user 284');
+INSERT INTO guests (email, name) VALUES ('user284@example.com', 'user 284');
+INSERT INTO users (email, name) VALUES ('user285@example.com', 'user 285');
+INSERT INTO guests (email, name) VALUES ('user285@example.com', 'user 285');
+INSERT INTO users (email, name) VALUES ('user286@example.com', 'user 286');
+INSERT INTO guests (email, name) VALUES ('user286@example.com', 'user 286');
+INSERT INTO users (email, name) VALUES ('user287@example.com', 'user 287');
+INSERT INTO guests (email, name) VALUES ('user287@example.com', 'user 287');
+INSERT INTO users (email, name) VALUES ('user288@example.com', 'user 288');
+INSERT INTO guests (email, name) VALUES ('user288@example.com', 'user 288');
+INSERT INTO users (email, name) VALUES ('user289@example.com', 'user 289');
+INSERT INTO guests (email, name) VALUES ('user289@example.com', 'user 289');
+INSERT INTO users (email, name) VALUES ('user290@example.com', 'user 290');
+INSERT INTO guests (email, name) VALUES ('user290@example.com', 'user 290');
+INSERT INTO users (email, name) VALUES ('user291@example.com', 'user 291');
+INSERT INTO guests (email, name) VALUES ('user291@example.com', 'user 291');
+INSERT INTO users (email, name) VALUES ('user292@example.com', 'user 292');
+INSERT INTO guests (email, name) VALUES ('user292@example.com', 'user 292');
+INSERT INTO users (email, name) VALUES ('user293@example.com', 'user 293');
+INSERT INTO guests (email, name) VALUES ('user293@example.com', 'user 293');
+INSERT INTO users (email, name) VALUES ('user294@example.com', 'user 294');
+INSERT INTO guests (email, name) VALUES ('user294@example.com', 'user 294');
+INSERT INTO users (email, name) VALUES ('user295@example.com', 'user 295');
+INSERT INTO guests (email, name) VALUES ('user295@example.com', 'user 295');
+INSERT INTO users (email, name) VALUES ('user296@example.com', 'user 296');
+INSERT INTO guests (email, name) VALUES ('user296@example.com', 'user 296');
+INSERT INTO users (email, name) VALUES ('user297@example.com', 'user 297');
+INSERT INTO guests (email, name) VALUES ('user297@example.com', 'user 297');
+INSERT INTO users (email, name) VALUES ('user298@example.com', 'user 298');
+INSERT INTO guests (email, name) VALUES ('user298@example.com', 'user 298');
+INSERT INTO users (email, name) VALUES ('user299@example.com', 'user 299');
+INSERT INTO guests (email, name) VALUES ('user299@example.com', 'user 299');
+INSERT INTO users (email, name) VALUES ('user300@example.com', 'user 300');
+INSERT INTO guests (email, name) VALUES ('user300@example.com', 'user 300');
+INSERT INTO users (email, name) VALUES ('user301@example.com', 'user 301');
+INSERT INTO guests (email, name) VALUES ('user301@example.com', 'user 301');
+INSERT INTO users (email, name) VALUES ('user302@example.com', 'user 302');
+INSERT INTO guests (email, name) VALUES ('user302@example.com', 'user 302');
+INSERT INTO users (email, name) VALUES ('user303@example.com', 'user 303');
+INSERT INTO guests (email, name) VALUES ('user303@example.com', 'user 303');
+INSERT INTO users (email, name) VALUES ('user304@example.com', 'user 304');
+INSERT INTO guests (email, name) VALUES ('user304@example.com', 'user 304');
+INSERT INTO users (email, name) VALUES ('user305@example.com', 'user 305');
+INSERT INTO guests (email, name) VALUES ('user305@example.com', 'user 305');
+INSERT INTO users (email, name) VALUES ('user306@example.com', 'user 306');
+INSERT INTO guests (email, name) VALUES ('user306@example.com', 'user 306');
+INSERT INTO users (email, name) VALUES ('user307@example.com', 'user 307');
+INSERT INTO guests (email, name) VALUES ('user307@example.com', 'user 307');
+INSERT INTO users (email, name) VALUES ('user308@example.com', 'user 308');
+INSERT INTO guests (email, name) VALUES ('user308@example.com', 'user 308');
+INSERT INTO users (email, name) VALUES ('user309@example.com', 'user 309');
+INSERT INTO guests (email, name) VALUES ('user309@example.com', 'user 309');
+INSERT INTO users (email, name) VALUES ('user310@example.com', 'user 310');
+INSERT INTO guests (email, name) VALUES ('user310@example.com', 'user 310');
+INSERT INTO users (email, name) VALUES ('user311@example.com', 'user 311');
+INSERT INTO guests (email, name) VALUES ('user311@example.com', 'user 311');
+INSERT INTO users (email, name) VALUES ('user312@example.com', 'user 312');
+INSERT INTO guests (email, name) VALUES ('user312@example.com', 'user 312');
+INSERT INTO users (email, name) VALUES ('user313@example.com', 'user 313');
+INSERT INTO guests (email, name) VALUES ('user313@example.com', 'user 313');
+INSERT INTO users (email, name) VALUES ('user314@example.com', 'user 314');
+INSERT INTO guests (email, name) VALUES ('user314@example.com', 'user 314');
+INSERT INTO users (email, name) VALUES ('user315@example.com', 'user 315');
+INSERT INTO guests (email, name) VALUES ('user315@example.com', 'user 315');
+INSERT INTO users (email, name) VALUES ('user316@example.com', 'user 316');
+INSERT INTO guests (email, name) VALUES ('user316@example.com', 'user 316');
+INSERT INTO users (email, name) VALUES ('user317@example.com', 'user 317');
+INSERT INTO guests (email, name) VALUES ('user317@example.com', 'user 317');
+INSERT INTO users (email, name) VALUES ('user318@example.com', 'user 318');
+INSERT INTO guests (email, name) VALUES ('user318@example.com', 'user 318');
+INSERT INTO users (email, name) VALUES ('user319@example.com', 'user 319');
+INSERT INTO guests (email, name) VALUES ('user319@example.com', 'user 319');
+INSERT INTO users (email, name) VALUES ('user320@example.com', 'user 320');
+INSERT INTO guests (email, name) VALUES ('user320@example.com', 'user 320');
+INSERT INTO users (email, name) VALUES ('user321@example.com', 'user 321');
+INSERT INTO guests (email, name) VALUES ('user321@example.com', 'user 321');
+INSERT INTO users (email, name) VALUES ('user322@example.com', 'user 322');
+INSERT INTO guests (email, name) VALUES ('user322@example.com', 'user 322');
+INSERT INTO users (email, name) VALUES ('user323@example.com', 'user 323');
+INSERT INTO guests (email, name) VALUES ('user323@example.com', 'user 323');
+INSERT INTO users (email, name) VALUES ('user324@example.com', 'user 324');
+INSERT INTO guests (email, name) VALUES ('user324@example.com', 'user 324');
+INSERT INTO users (email, name) VALUES ('user325@example.com', 'user 325');
+INSERT INTO guests (email, name) VALUES ('user325@example.com', 'user 325');
+INSERT INTO users (email, name) VALUES ('user326@example.com', 'user 326');
+INSERT INTO guests (email, name) VALUES ('user326@example.com', 'user 326');
+INSERT INTO users (email, name) VALUES ('user327@example.com', 'user 327');
+INSERT INTO guests (email, name) VALUES ('user327@example.com', 'user 327');
+INSERT INTO users (email, name) VALUES ('user328@example.com', 'user 328');
+INSERT INTO guests (email, name) VALUES ('user328@example.com', 'user 328');
+INSERT INTO users (email, name) VALUES ('user329@example.com', 'user 329');
+INSERT INTO guests (email, name) VALUES ('user329@example.com', 'user 329');
+INSERT INTO users (email, name) VALUES ('user330@example.com', 'user 330');
+INSERT INTO guests (email, name) VALUES ('user330@example.com', 'user 330');
+INSERT INTO users (email, name) VALUES ('user331@example.com', 'user 331');
+INSERT INTO guests (email, name) VALUES ('user331@example.com', 'user 331');
+INSERT INTO users (email, name) VALUES ('user332@example.com', 'user 332');
+INSERT INTO guests (email, name) VALUES ('user332@example.com', 'user 332');
+INSERT INTO users (email, name) VALUES ('user333@example.com', 'user 333');
+INSERT INTO guests (email, name) VALUES ('user333@example.com', 'user 333');
+INSERT INTO users (email, name) VALUES ('user334@example.com', 'user 334');
+INSERT INTO guests (email, name) VALUES ('user334@example.com', 'user 334');
+INSERT INTO users (email, name) VALUES ('user335@example.com', 'user 335');
+INSERT INTO guests (email, name) VALUES ('user335@example.com', 'user 335');
+INSERT INTO users (email, name) VALUES ('user336@example.com', 'user 336');
+INSERT INTO guests (email, name) VALUES ('user336@example.com', 'user 336');
+INSERT INTO users (email, name) VALUES ('user337@example.com', 'user 337');
+INSERT INTO guests (email, name) VALUES ('user337@example.com', 'user 337');
+INSERT INTO users (email, name) VALUES ('user338@example.com', 'user 338');
+INSERT INTO guests (email, name) VALUES ('user338@example.com', 'user 338');
+INSERT INTO users (email, name) VALUES ('user339@example.com', 'user 339');
+INSERT INTO guests (email, name) VALUES ('user339@example.com', 'user 339');
+INSERT INTO users (email, name) VALUES ('user340@example.com', 'user 340');
+INSERT INTO guests (email, name) VALUES ('user340@example.com', 'user 340');
+INSERT INTO users (email, name) VALUES ('user341@example.com', 'user 341');
+INSERT INTO guests (email, name) VALUES ('user341@example.com', 'user 341');
+INSERT INTO users (email, name) VALUES ('user342@example.com', 'user 342');
+INSERT INTO guests (email, name) VALUES ('user342@example.com', 'user 342');
+INSERT INTO users (email, name) VALUES ('user343@example.com', 'user 343');
+INSERT INTO guests (email, name) VALUES ('user343@example.com', 'user 343');
+INSERT INTO users (email, name) VALUES ('user344@example.com', 'user 344');
+INSERT INTO guests (email, name) VALUES ('user344@example.com', 'user 344');
+INSERT INTO users (email, name) VALUES ('user345@example.com', 'user 345');
+INSERT INTO guests (email, name) VALUES ('user345@example.com', 'user 345');
+INSERT INTO users (email, name) VALUES ('user346@example.com', 'user 346');
+INSERT INTO guests (email, name) VALUES ('user346@example.com', 'user 346');
+INSERT INTO users (email, name) VALUES ('user347@example.com', 'user 347');
+INSERT INTO guests (email, name) VALUES ('user347@example.com', 'user 347');
+INSERT INTO users (email, name) VALUES ('user348@example.com', 'user 348');
+INSERT INTO guests (email, name) VALUES ('user348@example.com', 'user 348');
+INSERT INTO users (email, name) VALUES ('user349@example.com', 'user 349');
+INSERT INTO guests (email, name) VALUES ('user349@example.com', 'user 349');
+INSERT INTO users (email, name) VALUES ('user350@example.com', 'user 350');
+INSERT INTO guests (email, name) VALUES ('user350@example.com', 'user 350');
+INSERT INTO users (email, name) VALUES ('user351@example.com', 'user 351');
+INSERT INTO guests (email, name) VALUES ('user351@example.com', 'user 351');
+INSERT INTO users (email, name) VALUES ('user352@example.com', 'user 352');
+INSERT INTO guests (email, name) VALUES ('user352@example.com', 'user 352');
+INSERT INTO users (email, name) VALUES ('user353@example.com', 'user 353');
+INSERT INTO guests (email, name) VALUES ('user353@example.com', 'user 353');
+INSERT INTO users (email, name) VALUES ('user354@example.com', 'user 354');
+INSERT INTO guests (email, name) VALUES ('user354@example.com', 'user 354');
+INSERT INTO users (email, name) VALUES ('user355@example.com', 'user 355');
+INSERT INTO guests (email, name) VALUES ('user355@example.com', 'user 355');
+INSERT INTO users (email, name) VALUES ('user356@example.com', 'user 356');
+INSERT INTO guests (email, name) VALUES ('user356@example.com', 'user 356');
+INSERT INTO users (email, name) VALUES ('user357@example.com', 'user 357');
+INSERT INTO guests (email, name) VALUES ('user357@example.com', 'user 357');
+INSERT INTO users (email, name) VALUES ('user358@example.com', 'user 358');
+INSERT INTO guests (email, name) VALUES ('user358@example.com', 'user 358');
+INSERT INTO users (email, name) VALUES ('user359@example.com', 'user 359');
+INSERT INTO guests (email, name) VALUES ('user359@example.com', 'user 359');
+INSERT INTO users (email, name) VALUES ('user360@example.com', 'user 360');
+INSERT INTO guests (email, name) VALUES ('user360@example.com', 'user 360');
+INSERT INTO users (email, name) VALUES ('user361@example.com', 'user 361');
+INSERT INTO guests (email, name) VALUES ('user361@example.com', 'user 361');
+INSERT INTO users (email, name) VALUES ('user362@example.com', 'user 362');
+INSERT INTO guests (email, name) VALUES ('user362@example.com', 'user 362');
+INSERT INTO users (email, name) VALUES ('user363@example.com', 'user 363');
+INSERT INTO guests (email, name) VALUES ('user363@example.com', 'user 363');
+INSERT INTO users (email, name) VALUES ('user364@example.com', 'user 364');
+INSERT INTO guests (email, name) VALUES ('user364@example.com', 'user 364');
+INSERT INTO users (email, name) VALUES ('user365@example.com', 'user 365');
+INSERT INTO guests (email, name) VALUES ('user365@example.com', 'user 365');
+INSERT INTO users (email, name) VALUES ('user366@example.com', 'user 366');
+INSERT INTO guests (email, name) VALUES ('user366@example.com', 'user 366');
+INSERT INTO users (email, name) VALUES ('user367@example.com', 'user 367');
+INSERT INTO guests (email, name) VALUES ('user367@example.com', 'user 367');
+INSERT INTO users (email, name) VALUES ('user368@example.com', 'user 368');
+INSERT INTO guests (email, name) VALUES ('user368@example.com', 'user 368');
+INSERT INTO users (email, name) VALUES ('user369@example.com', 'user 369');
+INSERT INTO guests (email, name) VALUES ('user369@example.com', 'user 369');
+INSERT INTO users (email, name) VALUES ('user370@example.com', 'user 370');
+INSERT INTO guests (email, name) VALUES ('user370@example.com', 'user 370');
+INSERT INTO users (email, name) VALUES ('user371@example.com', 'user 371');
+INSERT INTO guests (email, name) VALUES ('user371@example.com', 'user 371');
+INSERT INTO users (email, name) VALUES ('user372@example.com', 'user 372');
+INSERT INTO guests (email, name) VALUES ('user372@example.com', 'user 372');
+INSERT INTO users (email, name) VALUES ('user373@example.com', 'user 373');
+INSERT INTO guests (email, name) VALUES ('user373@example.com', 'user 373');
+INSERT INTO users (email, name) VALUES ('user374@example.com', 'user 374');
+INSERT INTO guests (email, name) VALUES ('user374@example.com', 'user 374');
+INSERT INTO users (email, name) VALUES ('user375@example.com', 'user 375');
+INSERT INTO guests (email, name) VALUES ('user375@example.com', 'user 375');
+INSERT INTO users (email, name) VALUES ('user376@example.com', 'user 376');
+INSERT INTO guests (email, name) VALUES ('user376@example.com', 'user 376');
+INSERT INTO users (email, name) VALUES ('user377@example.com', 'user 377');
+INSERT INTO guests (email, name) VALUES ('user377@example.com', 'user 377');
+INSERT INTO users (email, name) VALUES ('user378@example.com', 'user 378');
+INSERT INTO guests (email, name) VALUES ('user378@example.com', 'user 378');
+INSERT INTO users (email, name) VALUES ('user379@example.com', 'user 379');
+INSERT INTO guests (email, name) VALUES ('user379@example.com', 'user 379');
+INSERT INTO users (email, name) VALUES ('user380@example.com', 'user 380');
+INSERT INTO guests (email, name) VALUES ('user380@example.com', 'user 380');
+INSERT INTO users (email, name) VALUES ('user381@example.com', 'user 381');
+INSERT INTO guests (email, name) VALUES ('user381@example.com', 'user 381');
+INSERT INTO users (email, name) VALUES ('user382@example.com', 'user 382');
+INSERT INTO guests (email, name) VALUES ('user382@example.com', 'user 382');
+INSERT INTO users (email, name) VALUES ('user383@example.com', 'user 383');
+INSERT INTO guests (email, name) VALUES ('user383@example.com', 'user 383');
+INSERT INTO users (email, name) VALUES ('user384@example.com', 'user 384');
+INSERT INTO guests (email, name) VALUES ('user384@example.com', 'user 384');
+INSERT INTO users (email, name) VALUES ('user385@example.com', 'user 385');
+INSERT INTO guests (email, name) VALUES ('user385@example.com', 'user 385');
+INSERT INTO users (email, name) VALUES ('user386@example.com', 'user 386');
+INSERT INTO guests (email, name) VALUES ('user386@example.com', 'user 386');
+INSERT INTO users (email, name) VALUES ('user387@example.com', 'user 387');
+INSERT INTO guests (email, name) VALUES ('user387@example.com', 'user 387');
+INSERT INTO users (email, name) VALUES ('user388@example.com', 'user 388');
+INSERT INTO guests (email, name) VALUES ('user388@example.com', 'user 388');
+INSERT INTO users (email, name) VALUES ('user389@example.com', 'user 389');
+INSERT INTO guests (email, name) VALUES ('user389@example.com', 'user 389');
+INSERT INTO users (email, name) VALUES ('user390@example.com', 'user 390');
+INSERT INTO guests (email, name) VALUES ('user390@example.com', 'user 390');
+INSERT INTO users (email, name) VALUES ('user391@example.com', 'user 391');
+INSERT INTO guests (email, name) VALUES ('user391@example.com', 'user 391');
+INSERT INTO users (email, name) VALUES ('user392@example.com', 'user 392');
+INSERT INTO guests (email, name) VALUES ('user392@example.com', 'user 392');
+INSERT INTO users (email, name) VALUES ('user393@example.com', 'user 393');
+INSERT INTO guests (email, name) VALUES ('user393@example.com', 'user 393');
+INSERT INTO users (email, name) VALUES ('user394@example.com', 'user 394');
+INSERT INTO guests (email, name) VALUES ('user394@example.com', 'user 394');
+INSERT INTO users (email, name) VALUES ('user395@example.com', 'user 395');
+INSERT INTO guests (email, name) VALUES ('user395@example.com', 'user 395');
+INSERT INTO users (email, name) VALUES ('user396@example.com', 'user 396');
+INSERT INTO guests (email, name) VALUES ('user396@example.com', 'user 396');
+INSERT INTO users (email, name) VALUES ('user397@example.com', 'user 397');
+INSERT INTO guests (email, name) VALUES ('user397@example.com', 'user 397');
+INSERT INTO users (email, name) VALUES ('user398@example.com', 'user 398');
+INSERT INTO guests (email, name) VALUES ('user398@example.com', 'user 398');
+INSERT INTO users (email, name) VALUES ('user399@example.com', 'user 399');
+INSERT INTO guests (email, name) VALUES ('user399@example.com', 'user 399');
+INSERT INTO users (email, name) VALUES ('user400@example.com', 'user 400');
+INSERT INTO guests (email, name) VALUES ('user400@example.com', 'user 400');
+INSERT INTO users (email, name) VALUES ('user401@example.com', 'user 401');
+INSERT INTO guests (email, name) VALUES ('user401@example.com', 'user 401');
+INSERT INTO users (email, name) VALUES ('user402@example.com', 'user 402');
+INSERT INTO guests (email, name) VALUES ('user402@example.com', 'user 402');
+INSERT INTO users (email, name) VALUES ('user403@example.com', 'user 403');
+INSERT INTO guests (email, name) VALUES ('user403@example.com', 'user 403');
+INSERT INTO users (email, name) VALUES ('user404@example.com', 'user 404');
+INSERT INTO guests (email, name) VALUES ('user404@example.com', 'user 404');
+INSERT INTO users (email, name) VALUES ('user405@example.com', 'user 405');
+INSERT INTO guests (email, name) VALUES ('user405@example.com', 'user 405');
+INSERT INTO users (email, name) VALUES ('user406@example.com', 'user 406');
+INSERT INTO guests (email, name) VALUES ('user406@example.com', 'user 406');
+INSERT INTO users (email, name) VALUES ('user407@example.com', 'user 407');
+INSERT INTO guests (email, name) VALUES ('user407@example.com', 'user 407');
+INSERT INTO users (email, name) VALUES ('user408@example.com', 'user 408');
+INSERT INTO guests (email, name) VALUES ('user408@example.com', 'user 408');
+INSERT INTO users (email, name) VALUES ('user409@example.com', 'user 409');
+INSERT INTO guests (email, name) VALUES ('user409@example.com', 'user 409');
+INSERT INTO users (email, name) VALUES ('user410@example.com', 'user 410');
+INSERT INTO guests (email, name) VALUES ('user410@example.com', 'user 410');
+INSERT INTO users (email, name) VALUES ('user411@example.com', 'user 411');
+INSERT INTO guests (email, name) VALUES ('user411@example.com', 'user 411');
+INSERT INTO users (email, name) VALUES ('user412@example.com', 'user 412');
+INSERT INTO guests (email, name) VALUES ('user412@example.com', 'user 412');
+INSERT INTO users (email, name) VALUES ('user413@example.com', 'user 413');
+INSERT INTO guests (email, name) VALUES ('user413@example.com', 'user 413');
+INSERT INTO users (email, name) VALUES ('user414@example.com', 'user 414');
+INSERT INTO guests (email, name) VALUES ('user414@example.com', 'user 414');
+INSERT INTO users (email, name) VALUES ('user415@example.com', 'user 415');
+INSERT INTO guests (email, name) VALUES ('user415@example.com', 'user 415');
+INSERT INTO users (email, name) VALUES ('user416@example.com', 'user 416');
+INSERT INTO guests (email, name) VALUES ('user416@example.com', 'user 416');
+INSERT INTO users (email, name) VALUES ('user417@example.com', 'user 417');
+INSERT INTO guests (email, name) VALUES ('user417@example.com', 'user 417');
+INSERT INTO users (email, name) VALUES ('user418@example.com', 'user 418');
+INSERT INTO guests (email, name) VALUES ('user418@example.com', 'user 418');
+INSERT INTO users (email, name) VALUES ('user419@example.com', 'user 419');
+INSERT INTO guests (email, name) VALUES ('user419@example.com', 'user 419');
+INSERT INTO users (email, name) VALUES ('user420@example.com', 'user 420');
+INSERT INTO guests (email, name) VALUES ('user420@example.com', 'user 420');
+INSERT INTO users (email, name) VALUES ('user421@example.com', 'user 421');
+INSERT INTO guests (email, name) VALUES ('user421@example.com', 'user 421');
+INSERT INTO users (email, name) VALUES ('user422@example.com', 'user 422');
+INSERT INTO guests (email, name) VALUES ('user422@example.com', 'user 422');
+INSERT INTO users (email, name) VALUES ('user423@example.com', 'user 423');
+INSERT INTO guests (email, name) VALUES ('user423@example.com', 'user 423');
+INSERT INTO users (email, name) VALUES ('user424@example.com', 'user 424');
+INSERT INTO guests (email, name) VALUES ('user424@example.com', 'user 424');
+INSERT INTO users (email, name) VALUES ('user425@example.com', 'user 425');
+INSERT INTO guests (email, name) VALUES ('user425@example.com', 'user 425');
+INSERT INTO users (email, name) VALUES ('user426@example.com', 'user 426');
+INSERT INTO guests (email, name) VALUES ('user426@example.com', 'user 426');
+INSERT INTO users (email, name) VALUES ('user427@example.com', 'user 427');
+INSERT INTO guests (email, name) VALUES ('user427@example.com', 'user 427');
+INSERT INTO users (email, name) VALUES ('user428@example.com', 'user 428');
+INSERT INTO guests (email, name) VALUES ('user428@example.com', 'user 428');
+INSERT INTO users (email, name) VALUES ('user429@example.com', 'user 429');
+INSERT INTO guests (email, name) VALUES ('user429@example.com', 'user 429');
+INSERT INTO users (email, name) VALUES ('user430@example.com', 'user 430');
+INSERT INTO guests (email, name) VALUES ('user430@example.com', 'user 430');
+INSERT INTO users (email, name) VALUES ('user431@example.com', 'user 431');
+INSERT INTO guests (email, name) VALUES ('user431@example.com', 'user 431');
+INSERT INTO users (email, name) VALUES ('user432@example.com', 'user 432');
+INSERT INTO guests (email, name) VALUES ('user432@example.com', 'user 432');
+INSERT INTO users (email, name) VALUES ('user433@example.com', 'user 433');
+INSERT INTO guests (email, name) VALUES ('user433@example.com', 'user 433');
+INSERT INTO users (email, name) VALUES ('user434@example.com', 'user 434');
+INSERT INTO guests (email, name) VALUES ('user434@example.com', 'user 434');
+INSERT INTO users (email, name) VALUES ('user435@example.com', 'user 435');
+INSERT INTO guests (email, name) VALUES ('user435@example.com', 'user 435');
+INSERT INTO users (email, name) VALUES ('user436@example.com', 'user 436');
+INSERT INTO guests (email, name) VALUES ('user436@example.com', 'user 436');
+INSERT INTO users (email, name) VALUES ('user437@example.com', 'user 437');
+INSERT INTO guests (email, name) VALUES ('user437@example.com', 'user 437');
+INSERT INTO users (email, name) VALUES ('user438@example.com', 'user 438');
+INSERT INTO guests (email, name) VALUES ('user438@example.com', 'user 438');
+INSERT INTO users (email, name) VALUES ('user439@example.com', 'user 439');
+INSERT INTO guests (email, name) VALUES ('user439@example.com', 'user 439');
+INSERT INTO users (email, name) VALUES ('user440@example.com', 'user 440');
+INSERT INTO guests (email, name) VALUES ('user440@example.com', 'user 440');
+INSERT INTO users (email, name) VALUES ('user441@example.com', 'user 441');
+INSERT INTO guests (email, name) VALUES ('user441@example.com', 'user 441');
+INSERT INTO users (email, name) VALUES ('user442@example.com', 'user 442');
+INSERT INTO guests (email, name) VALUES ('user442@example.com', 'user 442');
+INSERT INTO users (email, name) VALUES ('user443@example.com', 'user 443');
+INSERT INTO guests (email, name) VALUES ('user443@example.com', 'user 443');
+INSERT INTO users (email, name) VALUES ('user444@example.com', 'user 444');
+INSERT INTO guests (email, name) VALUES ('user444@example.com', 'user 444');
+INSERT INTO users (email, name) VALUES ('user445@example.com', 'user 445');
+INSERT INTO guests (email, name) VALUES ('user445@example.com', 'user 445');
+INSERT INTO users (email, name) VALUES ('user446@example.com', 'user 446');
+INSERT INTO guests (email, name) VALUES ('user446@example.com', 'user 446');
+INSERT INTO users (email, name) VALUES ('user447@example.com', 'user 447');
+INSERT INTO guests (email, name) VALUES ('user447@example.com', 'user 447');
+INSERT INTO users (email, name) VALUES ('user448@example.com', 'user 448');
+INSERT INTO guests (email, name) VALUES ('user448@example.com', 'user 448');
+INSERT INTO users (email, name) VALUES ('user449@example.com', 'user 449');
+INSERT INTO guests (email, name) VALUES ('user449@example.com', 'user 449');
+INSERT INTO users (email, name) VALUES ('user450@example.com', 'user 450');
+INSERT INTO guests (email, name) VALUES ('user450@example.com', 'user 450');
+INSERT INTO users (email, name) VALUES ('user451@example.com', 'user 451');
+INSERT INTO guests (email, name) VALUES ('user451@example.com', 'user 451');
+INSERT INTO users (email, name) VALUES ('user452@example.com', 'user 452');
+INSERT INTO guests (email, name) VALUES ('user452@example.com', 'user 452');
+INSERT INTO users (email, name) VALUES ('user453@example.com', 'user 453');
+INSERT INTO guests (email, name) VALUES ('user453@example.com', 'user 453');
+INSERT INTO users (email, name) VALUES ('user454@example.com', 'user 454');
+INSERT INTO guests (email, name) VALUES ('user454@example.com', 'user 454');
+INSERT INTO users (email, name) VALUES ('user455@example.com', 'user 455');
+INSERT INTO guests (email, name) VALUES ('user455@example.com', 'user 455');
+INSERT INTO users (email, name) VALUES ('user456@example.com', 'user 456');
+INSERT INTO guests (email, name) VALUES ('user456@example.com', 'user 456');
+INSERT INTO users (email, name) VALUES ('user457@example.com', 'user 457');
+INSERT INTO guests (email, name) VALUES ('user457@example.com', 'user 457');
+INSERT INTO users (email, name) VALUES ('user458@example.com', 'user 458');
+INSERT INTO guests (email, name) VALUES ('user458@example.com', 'user 458');
+INSERT INTO users (email, name) VALUES ('user459@example.com', 'user 459');
+INSERT INTO guests (email, name) VALUES ('user459@example.com', 'user 459');
+INSERT INTO users (email, name) VALUES ('user460@example.com', 'user 460');
+INSERT INTO guests (email, name) VALUES ('user460@example.com', 'user 460');
+INSERT INTO users (email, name) VALUES ('user461@example.com', 'user 461');
+INSERT INTO guests (email, name) VALUES ('user461@example.com', 'user 461');
+INSERT INTO users (email, name) VALUES ('user462@example.com', 'user 462');
+INSERT INTO guests (email, name) VALUES ('user462@example.com', 'user 462');
+INSERT INTO users (email, name) VALUES ('user463@example.com', 'user 463');
+INSERT INTO guests (email, name) VALUES ('user463@example.com', 'user 463');
+INSERT INTO users (email, name) VALUES ('user464@example.com', 'user 464');
+INSERT INTO guests (email, name) VALUES ('user464@example.com', 'user 464');
+INSERT INTO users (email, name) VALUES ('user465@example.com', 'user 465');
+INSERT INTO guests (email, name) VALUES ('user465@example.com', 'user 465');
+INSERT INTO users (email, name) VALUES ('user466@example.com', 'user 466');
+INSERT INTO guests (email, name) VALUES ('user466@example.com', 'user 466');
+INSERT INTO users (email, name) VALUES ('user467@example.com', 'user 467');
+INSERT INTO guests (email, name) VALUES ('user467@example.com', 'user 467');
+INSERT INTO users (email, name) VALUES ('user468@example.com', 'user 468');
+INSERT INTO guests (email, name) VALUES ('user468@example.com', 'user 468');
+INSERT INTO users (email, name) VALUES ('user469@example.com', 'user 469');
+INSERT INTO guests (email, name) VALUES ('user469@example.com', 'user 469');
+INSERT INTO users (email, name) VALUES ('user470@example.com', 'user 470');
+INSERT INTO guests (email, name) VALUES ('user470@example.com', 'user 470');
+INSERT INTO users (email, name) VALUES ('user471@example.com', 'user 471');
+INSERT INTO guests (email, name) VALUES ('user471@example.com', 'user 471');
+INSERT INTO users (email, name) VALUES ('user472@example.com', 'user 472');
+INSERT INTO guests (email, name) VALUES ('user472@example.com', 'user 472');
+INSERT INTO users (email, name) VALUES ('user473@example.com', 'user 473');
+INSERT INTO guests (email, name) VALUES ('user473@example.com', 'user 473');
+INSERT INTO users (email, name) VALUES ('user474@example.com', 'user 474');
+INSERT INTO guests (email, name) VALUES ('user474@example.com', 'user 474');
+INSERT INTO users (email, name) VALUES ('user475@example.com', 'user 475');
+INSERT INTO guests (email, name) VALUES ('user475@example.com', 'user 475');
+INSERT INTO users (email, name) VALUES ('user476@example.com', 'user 476');
+INSERT INTO guests (email, name) VALUES ('user476@example.com', 'user 476');
+INSERT INTO users (email, name) VALUES ('user477@example.com', 'user 477');
+INSERT INTO guests (email, name) VALUES ('user477@example.com', 'user 477');
+INSERT INTO users (email, name) VALUES ('user478@example.com', 'user 478');
+INSERT INTO guests (email, name) VALUES ('user478@example.com', 'user 478');
+INSERT INTO users (email, name) VALUES ('user479@example.com', 'user 479');
+INSERT INTO guests (email, name) VALUES ('user479@example.com', 'user 479');
+INSERT INTO users (email, name) VALUES ('user480@example.com', 'user 480');
+INSERT INTO guests (email, name) VALUES ('user480@example.com', 'user 480');
+INSERT INTO users (email, name) VALUES ('user481@example.com', 'user 481');
+INSERT INTO guests (email, name) VALUES ('user481@example.com', 'user 481');
+INSERT INTO users (email, name) VALUES ('user482@example.com', 'user 482');
+INSERT INTO guests (email, name) VALUES ('user482@example.com', 'user 482');
+INSERT INTO users (email, name) VALUES ('user483@example.com', 'user 483');
+INSERT INTO guests (email, name) VALUES ('user483@example.com', 'user 483');
+INSERT INTO users (email, name) VALUES ('user484@example.com', 'user 484');
+INSERT INTO guests (email, name) VALUES ('user484@example.com', 'user 484');
+INSERT INTO users (email, name) VALUES ('user485@example.com', 'user 485');
+INSERT INTO guests (email, name) VALUES ('user485@example.com', 'user 485');
+INSERT INTO users (email, name) VALUES ('user486@example.com', 'user 486');
+INSERT INTO guests (email, name) VALUES ('user486@example.com', 'user 486');
+INSERT INTO users (email, name) VALUES ('user487@example.com', 'user 487');
+INSERT INTO guests (email, name) VALUES ('user487@example.com', 'user 487');
+INSERT INTO users (email, name) VALUES ('user488@example.com', 'user 488');
+INSERT INTO guests (email, name) VALUES ('user488@example.com', 'user 488');
+INSERT INTO users (email, name) VALUES ('user489@example.com', 'user 489');
+INSERT INTO guests (email, name) VALUES ('user489@example.com', 'user 489');
+INSERT INTO users (email, name) VALUES ('user490@example.com', 'user 490');
+INSERT INTO guests (email, name) VALUES ('user490@example.com', 'user 490');
+INSERT INTO users (email, name) VALUES ('user491@example.com', 'user 491');
+INSERT INTO guests (email, name) VALUES ('user491@example.com', 'user 491');
+INSERT INTO users (email, name) VALUES ('user492@example.com', 'user 492');
+INSERT INTO guests (email, name) VALUES ('user492@example.com', 'user 492');
+INSERT INTO users (email, name) VALUES ('user493@example.com', 'user 493');
+INSERT INTO guests (email, name) VALUES ('user493@example.com', 'user 493');
+INSERT INTO users (email, name) VALUES ('user494@example.com', 'user 494');
+INSERT INTO guests (email, name) VALUES ('user494@example.com', 'user 494');
+INSERT INTO users (email, name) VALUES ('user495@example.com', 'user 495');
+INSERT INTO guests (email, name) VALUES ('user495@example.com', 'user 495');
+INSERT INTO users (email, name) VALUES ('user496@example.com', 'user 496');
+INSERT INTO guests (email, name) VALUES ('user496@example.com', 'user 496');
+INSERT INTO users (email, name) VALUES ('user497@example.com', 'user 497');
+INSERT INTO guests (email, name) VALUES ('user497@example.com', 'user 497');
+INSERT INTO users (email, name) VALUES ('user498@example.com', 'user 498');
+INSERT INTO guests (email, name) VALUES ('user498@example.com', 'user 498');
+INSERT INTO users (email, name) VALUES ('user499@example.com', 'user 499');
+INSERT INTO guests (email, name) VALUES ('user499@example.com', 'user 499');
+INSERT INTO users (email, name) VALUES ('user500@example.com', 'user 500');
+INSERT INTO guests (email, name) VALUES ('user500@example.com', 'user 500');
+INSERT INTO users (email, name) VALUES ('user501@example.com', 'user 501');
+INSERT INTO guests (email, name) VALUES ('user501@example.com', 'user 501');
+INSERT INTO users (email, name) VALUES ('user502@example.com', 'user 502');
+INSERT INTO guests (email, name) VALUES ('user502@example.com', 'user 502');
+INSERT INTO users (email, name) VALUES ('user503@example.com', 'user 503');
+INSERT INTO guests (email, name) VALUES ('user503@example.com', 'user 503');
+INSERT INTO users (email, name) VALUES ('user504@example.com', 'user 504');
+INSERT INTO guests (email, name) VALUES ('user504@example.com', 'user 504');
+INSERT INTO users (email, name) VALUES ('user505@example.com', 'user 505');
+INSERT INTO guests (email, name) VALUES ('user505@example.com', 'user 505');
+INSERT INTO users (email, name) VALUES ('user506@example.com', 'user 506');
+INSERT INTO guests (email, name) VALUES ('user506@example.com', 'user 506');
+INSERT INTO users (email, name) VALUES ('user507@example.com', 'user 507');
+INSERT INTO guests (email, name) VALUES ('user507@example.com', 'user 507');
+INSERT INTO users (email, name) VALUES ('user508@example.com', 'user 508');
+INSERT INTO guests (email, name) VALUES ('user508@example.com', 'user 508');
+INSERT INTO users (email, name) VALUES ('user509@example.com', 'user 509');
+INSERT INTO guests (email, name) VALUES ('user509@example.com', 'user 509');
+INSERT INTO users (email, name) VALUES ('user510@example.com', 'user 510');
+INSERT INTO guests (email, name) VALUES ('user510@example.com', 'user 510');
+INSERT INTO users (email, name) VALUES ('user511@example.com', 'user 511');
+INSERT INTO guests (email, name) VALUES ('user511@example.com', 'user 511');
+INSERT INTO users (email, name) VALUES ('user512@example.com', 'user 512');
+INSERT INTO guests (email, name) VALUES ('user512@example.com', 'user 512');
+INSERT INTO users (email, name) VALUES ('user513@example.com', 'user 513');
+INSERT INTO guests (email, name) VALUES ('user513@example.com', 'user 513');
+INSERT INTO users (email, name) VALUES ('user514@example.com', 'user 514');
+INSERT INTO guests (email, name) VALUES ('user514@example.com', 'user 514');
+INSERT INTO users (email, name) VALUES ('user515@example.com', 'user 515');
+INSERT INTO guests (email, name) VALUES ('user515@example.com', 'user 515');
+INSERT INTO users (email, name) VALUES ('user516@example.com', 'user 516');
+INSERT INTO guests (email, name) VALUES ('user516@example.com', 'user 516');
+INSERT INTO users (email, name) VALUES ('user517@example.com', 'user 517');
+INSERT INTO guests (email, name) VALUES ('user517@example.com', 'user 517');
+INSERT INTO users (email, name) VALUES ('user518@example.com', 'user 518');
+INSERT INTO guests (email, name) VALUES ('user518@example.com', 'user 518');
+INSERT INTO users (email, name) VALUES ('user519@example.com', 'user 519');
+INSERT INTO guests (email, name) VALUES ('user519@example.com', 'user 519');
+INSERT INTO users (email, name) VALUES ('user520@example.com', 'user 520');
+INSERT INTO guests (email, name) VALUES ('user520@example.com', 'user 520');
+INSERT INTO users (email, name) VALUES ('user521@example.com', 'user 521');
+INSERT INTO guests (email, name) VALUES ('user521@example.com', 'user 521');
+INSERT INTO users (email, name) VALUES ('user522@example.com', 'user 522');
+INSERT INTO guests (email, name) VALUES ('user522@example.com', 'user 522');
+INSERT INTO users (email, name) VALUES ('user523@example.com', 'user 523');
+INSERT INTO guests (email, name) VALUES ('user523@example.com', 'user 523');
+INSERT INTO users (email, name) VALUES ('user524@example.com', 'user 524');
+INSERT INTO guests (email, name) VALUES ('user524@example.com', 'user 524');
+INSERT INTO users (email, name) VALUES ('user525@example.com', 'user 525');
+INSERT INTO guests (email, name) VALUES ('user525@example.com', 'user 525');
+INSERT INTO users (email, name) VALUES ('user526@example.com', 'user 526');
+INSERT INTO guests (email, name) VALUES ('user526@example.com', 'user 526');
+INSERT INTO users (email, name) VALUES ('user527@example.com', 'user 527');
+INSERT INTO guests (email, name) VALUES ('user527@example.com', 'user 527');
+INSERT INTO users (email, name) VALUES ('user528@example.com', 'user 528');
+INSERT INTO guests (email, name) VALUES ('user528@example.com', 'user 528');
+INSERT INTO users (email, name) VALUES ('user529@example.com', 'user 529');
+INSERT INTO guests (email, name) VALUES ('user529@example.com', 'user 529');
+INSERT INTO users (email, name) VALUES ('user530@example.com', 'user 530');
+INSERT INTO guests (email, name) VALUES ('user530@example.com', 'user 530');
+INSERT INTO users (email, name) VALUES ('user531@example.com', 'user 531');
+INSERT INTO guests (email, name) VALUES ('user531@example.com', 'user 531');
+INSERT INTO users (email, name) VALUES ('user532@example.com', 'user 532');
+INSERT INTO guests (email, name) VALUES ('user532@example.com', 'user 532');
+INSERT INTO users (email, name) VALUES ('user533@example.com', 'user 533');
+INSERT INTO guests (email, name) VALUES ('user533@example.com', 'user 533');
+INSERT INTO users (email, name) VALUES ('user534@example.com', 'user 534');
+INSERT INTO guests (email, name) VALUES ('user534@example.com', 'user 534');
+INSERT INTO users (email, name) VALUES ('user535@example.com', 'user 535');
+INSERT INTO guests (email, name) VALUES ('user535@example.com', 'user 535');
+INSERT INTO users (email, name) VALUES ('user536@example.com', 'user 536');
+INSERT INTO guests (email, name) VALUES ('user536@example.com', 'user 536');
+INSERT INTO users (email, name) VALUES ('user537@example.com', 'user 537');
+INSERT INTO guests (email, name) VALUES ('user537@example.com', 'user 537');
+INSERT INTO users (email, name) VALUES ('user538@example.com', 'user 538');
+INSERT INTO guests (email, name) VALUES ('user538@example.com', 'user 538');
+INSERT INTO users (email, name) VALUES ('user539@example.com', 'user 539');
+INSERT INTO guests (email, name) VALUES ('user539@example.com', 'user 539');
+INSERT INTO users (email, name) VALUES ('user540@example.com', 'user 540');
+INSERT INTO guests (email, name) VALUES ('user540@example.com', 'user 540');
+INSERT INTO users (email, name) VALUES ('user541@example.com', 'user 541');
+INSERT INTO guests (email, name) VALUES ('user541@example.com', 'user 541');
+INSERT INTO users (email, name) VALUES ('user542@example.com', 'user 542');
+INSERT INTO guests (email, name) VALUES ('user542@example.com', 'user 542');
+INSERT INTO users (email, name) VALUES ('user543@example.com', 'user 543');
+INSERT INTO guests (email, name) VALUES ('user543@example.com', 'user 543');
+INSERT INTO users (email, name) VALUES ('user544@example.com', 'user 544');
+INSERT INTO guests (email, name) VALUES ('user544@example.com', 'user 544');
+INSERT INTO users (email, name) VALUES ('user545@example.com', 'user 545');
+INSERT INTO guests (email, name) VALUES ('user545@example.com', 'user 545');
+INSERT INTO users (email, name) VALUES ('user546@example.com', 'user 546');
+INSERT INTO guests (email, name) VALUES ('user546@example.com', 'user 546');
+INSERT INTO users (email, name) VALUES ('user547@example.com', 'user 547');
+INSERT INTO guests (email, name) VALUES ('user547@example.com', 'user 547');
+INSERT INTO users (email, name) VALUES ('user548@example.com', 'user 548');
+INSERT INTO guests (email, name) VALUES ('user548@example.com', 'user 548');
+INSERT INTO users (email, name) VALUES ('user549@example.com', 'user 549');
+INSERT INTO guests (email, name) VALUES ('user549@example.com', 'user 549');
+INSERT INTO users (email, name) VALUES ('user550@example.com', 'user 550');
+INSERT INTO guests (email, name) VALUES ('user550@example.com', 'user 550');
+INSERT INTO users (email, name) VALUES ('user551@example.com', 'user 551');
+INSERT INTO guests (email, name) VALUES ('user551@example.com', 'user 551');
+INSERT INTO users (email, name) VALUES ('user552@example.com', 'user 552');
+INSERT INTO guests (email, name) VALUES ('user552@example.com', 'user 552');
+INSERT INTO users (email, name) VALUES ('user553@example.com', 'user 553');
+INSERT INTO guests (email, name) VALUES ('user553@example.com', 'user 553');
+INSERT INTO users (email, name) VALUES ('user554@example.com', 'user 554');
+INSERT INTO guests (email, name) VALUES ('user554@example.com', 'user 554');
+INSERT INTO users (email, name) VALUES ('user555@example.com', 'user 555');
+INSERT INTO guests (email, name) VALUES ('user555@example.com', 'user 555');
+INSERT INTO users (email, name) VALUES ('user556@example.com', 'user 556');
+INSERT INTO guests (email, name) VALUES ('user556@example.com', 'user 556');
+INSERT INTO users (email, name) VALUES ('user557@example.com', 'user 557');
+INSERT INTO guests (email, name) VALUES ('user557@example.com', 'user 557');
+INSERT INTO users (email, name) VALUES ('user558@example.com', 'user 558');
+INSERT INTO guests (email, name) VALUES ('user558@example.com', 'user 558');
+INSERT INTO users (email, name) VALUES ('user559@example.com', 'user 559');
+INSERT INTO guests (email, name) VALUES ('user559@example.com', 'user 559');
+INSERT INTO users (email, name) VALUES ('user560@example.com', 'user 560');
+INSERT INTO guests (email, name) VALUES ('user560@example.com', 'user 560');
+INSERT INTO users (email, name) VALUES ('user561@example.com', 'user 561');
+INSERT INTO guests (email, name) VALUES ('user561@example.com', 'user 561');
+INSERT INTO users (email, name) VALUES ('user562@example.com', 'user 562');
+INSERT INTO guests (email, name) VALUES ('user562@example.com', 'user 562');
+INSERT INTO users (email, name) VALUES ('user563@example.com', 'user 563');
+INSERT INTO guests (email, name) VALUES ('user563@example.com', 'user 563');
+INSERT INTO users (email, name) VALUES ('user564@example.com', 'user 564');
+INSERT INTO guests (email, name) VALUES ('user564@example.com', 'user 564');
+INSERT INTO users (email, name) VALUES ('user565@example.com', 'user 565');
+INSERT INTO guests (email, name) VALUES ('user565@example.com', 'user 565');
+INSERT INTO users (email, name) VALUES ('user566@example.com', 'user 566');
+INSERT INTO guests (email, name) VALUES ('user566@example.com', 'user 566');
+INSERT INTO users (email, name) VALUES ('user567@example.com', 'user 567');
+INSERT INTO guests (email, name) VALUES ('user567@example.com', 'user 567');
+INSERT INTO users (email, name) VALUES ('user568@example.com', 'user 568');
+INSERT INTO guests (email, name) VALUES ('user568@example.com', 'user 568');
+INSERT INTO users (email, name) VALUES ('user569@example.com', 'user 569');
+INSERT INTO guests (email, name) VALUES ('user569@example.com', 'user 569');
+INSERT INTO users (email, name) VALUES ('user570@example.com', 'user 570');
+INSERT INTO guests (email, name) VALUES ('user570@example.com', 'user 570');
+INSERT INTO users (email, name) VALUES ('user571@example.com', 'user 571');
+INSERT INTO guests (email, name) VALUES ('user571@example.com', 'user 571');
+INSERT INTO users (email, name) VALUES ('user572@example.com', 'user 572');
+INSERT INTO guests (email, name) VALUES ('user572@example.com', 'user 572');
+INSERT INTO users (email, name) VALUES ('user573@example.com', 'user 573');
+INSERT INTO guests (email, name) VALUES ('user573@example.com', 'user 573');
+INSERT INTO users (email, name) VALUES ('user574@example.com', 'user 574');
+INSERT INTO guests (email, name) VALUES ('user574@example.com', 'user 574');
+INSERT INTO users (email, name) VALUES ('user575@example.com', 'user 575');
+INSERT INTO guests (email, name) VALUES ('user575@example.com', 'user 575');
+INSERT INTO users (email, name) VALUES ('user576@example.com', 'user 576');
+INSERT INTO guests (email, name) VALUES ('user576@example.com', 'user 576');
+INSERT INTO users (email, name) VALUES ('user577@example.com', 'user 577');
+INSERT INTO guests (email, name) VALUES ('user577@example.com', 'user 577');
+INSERT INTO users (email, name) VALUES ('user578@example.com', 'user 578');
+INSERT INTO guests (email, name) VALUES ('user578@example.com', 'user 578');
+INSERT INTO users (email, name) VALUES ('user579@example.com', 'user 579');
+INSERT INTO guests (email, name) VALUES ('user579@example.com', 'user 579');
+INSERT INTO users (email, name) VALUES ('user580@example.com', 'user 580');
+INSERT INTO guests (email, name) VALUES ('user580@example.com', 'user 580');
+INSERT INTO users (email, name) VALUES ('user581@example.com', 'user 581');
+INSERT INTO guests (email, name) VALUES ('user581@example.com', 'user 581');
+INSERT INTO users (email, name) VALUES ('user582@example.com', 'user 582');
+INSERT INTO guests (email, name) VALUES ('user582@example.com', 'user 582');
+INSERT INTO users (email, name) VALUES ('user583@example.com', 'user 583');
+INSERT INTO guests (email, name) VALUES ('user583@example.com', 'user 583');
+INSERT INTO users (email, name) VALUES ('user584@example.com', 'user 584');
+INSERT INTO guests (email, name) VALUES ('user584@example.com', 'user 584');
+INSERT INTO users (email, name) VALUES ('user585@example.com', 'user 585');
+INSERT INTO guests (email, name) VALUES ('user585@example.com', 'user 585');
+INSERT INTO users (email, name) VALUES ('user586@example.com', 'user 586');
+INSERT INTO guests (email, name) VALUES ('user586@example.com', 'user 586');
+INSERT INTO users (email, name) VALUES ('user587@example.com', 'user 587');
+INSERT INTO guests (email, name) VALUES ('user587@example.com', 'user 587');
+INSERT INTO users (email, name) VALUES ('user588@example.com', 'user 588');
+INSERT INTO guests (email, name) VALUES ('user588@example.com', 'user 588');
+INSERT INTO users (email, name) VALUES ('user589@example.com', 'user 589');
+INSERT INTO guests (email, name) VALUES ('user589@example.com', 'user 589');
+INSERT INTO users (email, name) VALUES ('user590@example.com', 'user 590');
+INSERT INTO guests (email, name) VALUES ('user590@example.com', 'user 590');
+INSERT INTO users (email, name) VALUES ('user591@example.com', 'user 591');
+INSERT INTO guests (email, name) VALUES ('user591@example.com', 'user 591');
+INSERT INTO users (email, name) VALUES ('user592@example.com', 'user 592');
+INSERT INTO guests (email, name) VALUES ('user592@example.com', 'user 592');
+INSERT INTO users (email, name) VALUES ('user593@example.com', 'user 593');
+INSERT INTO guests (email, name) VALUES ('user593@example.com', 'user 593');
+INSERT INTO users (email, name) VALUES ('user594@example.com', 'user 594');
+INSERT INTO guests (email, name) VALUES ('user594@example.com', 'user 594');
+INSERT INTO users (email, name) VALUES ('user595@example.com', 'user 595');
+INSERT INTO guests (email, name) VALUES ('user595@example.com', 'user 595');
+INSERT INTO users (email, name) VALUES ('user596@example.com', 'user 596');
+INSERT INTO guests (email, name) VALUES ('user596@example.com', 'user 596');
+INSERT INTO users (email, name) VALUES ('user597@example.com', 'user 597');
+INSERT INTO guests (email, name) VALUES ('user597@example.com', 'user 597');
+INSERT INTO users (email, name) VALUES ('user598@example.com', 'user 598');
+INSERT INTO guests (email, name) VALUES ('user598@example.com', 'user 598');
+INSERT INTO users (email, name) VALUES ('user599@example.com', 'user 599');
+INSERT INTO guests (email, name) VALUES ('user599@example.com', 'user 599');
+INSERT INTO users (email, name) VALUES ('user600@example.com', 'user 600');
+INSERT INTO guests (email, name) VALUES ('user600@example.com', 'user 600');
+INSERT INTO users (email, name) VALUES ('user601@example.com', 'user 601');
+INSERT INTO guests (email, name) VALUES ('user601@example.com', 'user 601');
+INSERT INTO users (email, name) VALUES ('user602@example.com', 'user 602');
+INSERT INTO guests (email, name) VALUES ('user602@example.com', 'user 602');
+INSERT INTO users (email, name) VALUES ('user603@example.com', 'user 603');
+INSERT INTO guests (email, name) VALUES ('user603@example.com', 'user 603');
+INSERT INTO users (email, name) VALUES ('user604@example.com', 'user 604');
+INSERT INTO guests (email, name) VALUES ('user604@example.com', 'user 604');
+INSERT INTO users (email, name) VALUES ('user605@example.com', 'user 605');
+INSERT INTO guests (email, name) VALUES ('user605@example.com', 'user 605');
+INSERT INTO users (email, name) VALUES ('user606@example.com', 'user 606');
+INSERT INTO guests (email, name) VALUES ('user606@example.com', 'user 606');
+INSERT INTO users (email, name) VALUES ('user607@example.com', 'user 607');
+INSERT INTO guests (email, name) VALUES ('user607@example.com', 'user 607');
+INSERT INTO users (email, name) VALUES ('user608@example.com', 'user 608');
+INSERT INTO guests (email, name) VALUES ('user608@example.com', 'user 608');
+INSERT INTO users (email, name) VALUES ('user609@example.com', 'user 609');
+INSERT INTO guests (email, name) VALUES ('user609@example.com', 'user 609');
+INSERT INTO users (email, name) VALUES ('user610@example.com', 'user 610');
+INSERT INTO guests (email, name) VALUES ('user610@example.com', 'user 610');
+INSERT INTO users (email, name) VALUES ('user611@example.com', 'user 611');
+INSERT INTO guests (email, name) VALUES ('user611@example.com', 'user 611');
+INSERT INTO users (email, name) VALUES ('user612@example.com', 'user 612');
+INSERT INTO guests (email, name) VALUES ('user612@example.com', 'user 612');
+INSERT INTO users (email, name) VALUES ('user613@example.com', 'user 613');
+INSERT INTO guests (email, name) VALUES ('user613@example.com', 'user 613');
+INSERT INTO users (email, name) VALUES ('user614@example.com', 'user 614');
+INSERT INTO guests (email, name) VALUES ('user614@example.com', 'user 614');
+INSERT INTO users (email, name) VALUES ('user615@example.com', 'user 615');
+INSERT INTO guests (email, name) VALUES ('user615@example.com', 'user 615');
+INSERT INTO users (email, name) VALUES ('user616@example.com', 'user 616');
+INSERT INTO guests (email, name) VALUES ('user616@example.com', 'user 616');
+INSERT INTO users (email, name) VALUES ('user617@example.com', 'user 617');
+INSERT INTO guests (email, name) VALUES ('user617@example.com', 'user 617');
+INSERT INTO users (email, name) VALUES ('user618@example.com', 'user 618');
+INSERT INTO guests (email, name) VALUES ('user618@example.com', 'user 618');
+INSERT INTO users (email, name) VALUES ('user619@example.com', 'user 619');
+INSERT INTO guests (email, name) VALUES ('user619@example.com', 'user 619');
+INSERT INTO users (email, name) VALUES ('user620@example.com', 'user 620');
+INSERT INTO guests (email, name) VALUES ('user620@example.com', 'user 620');
+INSERT INTO users (email, name) VALUES ('user621@example.com', 'user 621');
+INSERT INTO guests (email, name) VALUES ('user621@example.com', 'user 621');
+INSERT INTO users (email, name) VALUES ('user622@example.com', 'user 622');
+INSERT INTO guests (email, name) VALUES ('user622@example.com', 'user 622');
+INSERT INTO users (email, name) VALUES ('user623@example.com', 'user 623');
+INSERT INTO guests (email, name) VALUES ('user623@example.com', 'user 623');
+INSERT INTO users (email, name) VALUES ('user624@example.com', 'user 624');
+INSERT INTO guests (email, name) VALUES ('user624@example.com', 'user 624');
+INSERT INTO users (email, name) VALUES ('user625@example.com', 'user 625');
+INSERT INTO guests (email, name) VALUES ('user625@example.com', 'user 625');
+INSERT INTO users (email, name) VALUES ('user626@example.com', 'user 626');
+INSERT INTO guests (email, name) VALUES ('user626@example.com', 'user 626');
+INSERT INTO users (email, name) VALUES ('user627@example.com', 'user 627');
+INSERT INTO guests (email, name) VALUES ('user627@example.com', 'user 627');
+INSERT INTO users (email, name) VALUES ('user628@example.com', 'user 628');
+INSERT INTO guests (email, name) VALUES ('user628@example.com', 'user 628');
+INSERT INTO users (email, name) VALUES ('user629@example.com', 'user 629');
+INSERT INTO guests (email, name) VALUES ('user629@example.com', 'user 629');
+INSERT INTO users (email, name) VALUES ('user630@example.com', 'user 630');
+INSERT INTO guests (email, name) VALUES ('user630@example.com', 'user 630');
+INSERT INTO users (email, name) VALUES ('user631@example.com', 'user 631');
+INSERT INTO guests (email, name) VALUES ('user631@example.com', 'user 631');
+INSERT INTO users (email, name) VALUES ('user632@example.com', 'user 632');
+INSERT INTO guests (email, name) VALUES ('user632@example.com', 'user 632');
+INSERT INTO users (email, name) VALUES ('user633@example.com', 'user 633');
+INSERT INTO guests (email, name) VALUES ('user633@example.com', 'user 633');
+INSERT INTO users (email, name) VALUES ('user634@example.com', 'user 634');
+INSERT INTO guests (email, name) VALUES ('user634@example.com', 'user 634');
+INSERT INTO users (email, name) VALUES ('user635@example.com', 'user 635');
+INSERT INTO guests (email, name) VALUES ('user635@example.com', 'user 635');
+INSERT INTO users (email, name) VALUES ('user636@example.com', 'user 636');
+INSERT INTO guests (email, name) VALUES ('user636@example.com', 'user 636');
+INSERT INTO users (email, name) VALUES ('user637@example.com', 'user 637');
+INSERT INTO guests (email, name) VALUES ('user637@example.com', 'user 637');
+INSERT INTO users (email, name) VALUES ('user638@example.com', 'user 638');
+INSERT INTO guests (email, name) VALUES ('user638@example.com', 'user 638');
+INSERT INTO users (email, name) VALUES ('user639@example.com', 'user 639');
+INSERT INTO guests (email, name) VALUES ('user639@example.com', 'user 639');
+INSERT INTO users (email, name) VALUES ('user640@example.com', 'user 640');
+INSERT INTO guests (email, name) VALUES ('user640@example.com', 'user 640');
+INSERT INTO users (email, name) VALUES ('user641@example.com', 'user 641');
+INSERT INTO guests (email, name) VALUES ('user641@example.com', 'user 641');
+INSERT INTO users (email, name) VALUES ('user642@example.com', 'user 642');
+INSERT INTO guests (email, name) VALUES ('user642@example.com', 'user 642');
+INSERT INTO users (email, name) VALUES ('user643@example.com', 'user 643');
+INSERT INTO guests (email, name) VALUES ('user643@example.com', 'user 643');
+INSERT INTO users (email, name) VALUES ('user644@example.com', 'user 644');
+INSERT INTO guests (email, name) VALUES ('user644@example.com', 'user 644');
+INSERT INTO users (email, name) VALUES ('user645@example.com', 'user 645');
+INSERT INTO guests (email, name) VALUES ('user645@example.com', 'user 645');
+INSERT INTO users (email, name) VALUES ('user646@example.com', 'user 646');
+INSERT INTO guests (email, name) VALUES ('user646@example.com', 'user 646');
+INSERT INTO users (email, name) VALUES ('user647@example.com', 'user 647');
+INSERT INTO guests (email, name) VALUES ('user647@example.com', 'user 647');
+INSERT INTO users (email, name) VALUES ('user648@example.com', 'user 648');
+INSERT INTO guests (email, name) VALUES ('user648@example.com', 'user 648');
+INSERT INTO users (email, name) VALUES ('user649@example.com', 'user 649');
+INSERT INTO guests (email, name) VALUES ('user649@example.com', 'user 649');
+INSERT INTO users (email, name) VALUES ('user650@example.com', 'user 650');
+INSERT INTO guests (email, name) VALUES ('user650@example.com', 'user 650');
+INSERT INTO users (email, name) VALUES ('user651@example.com', 'user 651');
+INSERT INTO guests (email, name) VALUES ('user651@example.com', 'user 651');
+INSERT INTO users (email, name) VALUES ('user652@example.com', 'user 652');
+INSERT INTO guests (email, name) VALUES ('user652@example.com', 'user 652');
+INSERT INTO users (email, name) VALUES ('user653@example.com', 'user 653');
+INSERT INTO guests (email, name) VALUES ('user653@example.com', 'user 653');
+INSERT INTO users (email, name) VALUES ('user654@example.com', 'user 654');
+INSERT INTO guests (email, name) VALUES ('user654@example.com', 'user 654');
+INSERT INTO users (email, name) VALUES ('user655@example.com', 'user 655');
+INSERT INTO guests (email, name) VALUES ('user655@example.com', 'user 655');
+INSERT INTO users (email, name) VALUES ('user656@example.com', 'user 656');
+INSERT INTO guests (email, name) VALUES ('user656@example.com', 'user 656');
+INSERT INTO users (email, name) VALUES ('user657@example.com', 'user 657');
+INSERT INTO guests (email, name) VALUES ('user657@example.com', 'user 657');
+INSERT INTO users (email, name) VALUES ('user658@example.com', 'user 658');
+INSERT INTO guests (email, name) VALUES ('user658@example.com', 'user 658');
+INSERT INTO users (email, name) VALUES ('user659@example.com', 'user 659');
+INSERT INTO guests (email, name) VALUES ('user659@example.com', 'user 659');
+INSERT INTO users (email, name) VALUES ('user660@example.com', 'user 660');
+INSERT INTO guests (email, name) VALUES ('user660@example.com', 'user 660');
+INSERT INTO users (email, name) VALUES ('user661@example.com', 'user 661');
+INSERT INTO guests (email, name) VALUES ('user661@example.com', 'user 661');
+INSERT INTO users (email, name) VALUES ('user662@example.com', 'user 662');
+INSERT INTO guests (email, name) VALUES ('user662@example.com', 'user 662');
+INSERT INTO users (email, name) VALUES ('user663@example.com', 'user 663');
+INSERT INTO guests (email, name) VALUES ('user663@example.com', 'user 663');
+INSERT INTO users (email, name) VALUES ('user664@example.com', 'user 664');
+INSERT INTO guests (email, name) VALUES ('user664@example.com', 'user 664');
+INSERT INTO users (email, name) VALUES ('user665@example.com', 'user 665');
+INSERT INTO guests (email, name) VALUES ('user665@example.com', 'user 665');
+INSERT INTO users (email, name) VALUES ('user666@example.com', 'user 666');
+INSERT INTO guests (email, name) VALUES ('user666@example.com', 'user 666');
+INSERT INTO users (email, name) VALUES ('user667@example.com', 'user 667');
+INSERT INTO guests (email, name) VALUES ('user667@example.com', 'user 667');
+INSERT INTO users (email, name) VALUES ('user668@example.com', 'user 668');
+INSERT INTO guests (email, name) VALUES ('user668@example.com', 'user 668');
+INSERT INTO users (email, name) VALUES ('user669@example.com', 'user 669');
+INSERT INTO guests (email, name) VALUES ('user669@example.com', 'user 669');
+INSERT INTO users (email, name) VALUES ('user670@example.com', 'user 670');
+INSERT INTO guests (email, name) VALUES ('user670@example.com', 'user 670');
+INSERT INTO users (email, name) VALUES ('user671@example.com', 'user 671');
+INSERT INTO guests (email, name) VALUES ('user671@example.com', 'user 671');
+INSERT INTO users (email, name) VALUES ('user672@example.com', 'user 672');
+INSERT INTO guests (email, name) VALUES ('user672@example.com', 'user 672');
+INSERT INTO users (email, name) VALUES ('user673@example.com', 'user 673');
+INSERT INTO guests (email, name) VALUES ('user673@example.com', 'user 673');
+INSERT INTO users (email, name) VALUES ('user674@example.com', 'user 674');
+INSERT INTO guests (email, name) VALUES ('user674@example.com', 'user 674');
+INSERT INTO users (email, name) VALUES ('user675@example.com', 'user 675');
+INSERT INTO guests (email, name) VALUES ('user675@example.com', 'user 675');
+INSERT INTO users (email, name) VALUES ('user676@example.com', 'user 676');
+INSERT INTO guests (email, name) VALUES ('user676@example.com', 'user 676');
+INSERT INTO users (email, name) VALUES ('user677@example.com', 'user 677');
+INSERT INTO guests (email, name) VALUES ('user677@example.com', 'user 677');
+INSERT INTO users (email, name) VALUES ('user678@example.com', 'user 678');
+INSERT INTO guests (email, name) VALUES ('user678@example.com', 'user 678');
+INSERT INTO users (email, name) VALUES ('user679@example.com', 'user 679');
+INSERT INTO guests (email, name) VALUES ('user679@example.com', 'user 679');
+INSERT INTO users (email, name) VALUES ('user680@example.com', 'user 680');
+INSERT INTO guests (email, name) VALUES ('user680@example.com', 'user 680');
+INSERT INTO users (email, name) VALUES ('user681@example.com', 'user 681');
+INSERT INTO guests (email, name) VALUES ('user681@example.com', 'user 681');
+INSERT INTO users (email, name) VALUES ('user682@example.com', 'user 682');
+INSERT INTO guests (email, name) VALUES ('user682@example.com', 'user 682');
+INSERT INTO users (email, name) VALUES ('user683@example.com', 'user 683');
+INSERT INTO guests (email, name) VALUES ('user683@example.com', 'user 683');
+INSERT INTO users (email, name) VALUES ('user684@example.com', 'user 684');
+INSERT INTO guests (email, name) VALUES ('user684@example.com', 'user 684');
+INSERT INTO users (email, name) VALUES ('user685@example.com', 'user 685');
+INSERT INTO guests (email, name) VALUES ('user685@example.com', 'user 685');
+INSERT INTO users (email, name) VALUES ('user686@example.com', 'user 686');
+INSERT INTO guests (email, name) VALUES ('user686@example.com', 'user 686');
+INSERT INTO users (email, name) VALUES ('user687@example.com', 'user 687');
+INSERT INTO guests (email, name) VALUES ('user687@example.com', 'user 687');
+INSERT INTO users (email, name) VALUES ('user688@example.com', 'user 688');
+INSERT INTO guests (email, name) VALUES ('user688@example.com', 'user 688');
+INSERT INTO users (email, name) VALUES ('user689@example.com', 'user 689');
+INSERT INTO guests (email, name) VALUES ('user689@example.com', 'user 689');
+INSERT INTO users (email, name) VALUES ('user690@example.com', 'user 690');
+INSERT INTO guests (email, name) VALUES ('user690@example.com', 'user 690');
+INSERT INTO users (email, name) VALUES ('user691@example.com', 'user 691');
+INSERT INTO guests (email, name) VALUES ('user691@example.com', 'user 691');
+INSERT INTO users (email, name) VALUES ('user692@example.com', 'user 692');
+INSERT INTO guests (email, name) VALUES ('user692@example.com', 'user 692');
+INSERT INTO users (email, name) VALUES ('user693@example.com', 'user 693');
+INSERT INTO guests (email, name) VALUES ('user693@example.com', 'user 693');
+INSERT INTO users (email, name) VALUES ('user694@example.com', 'user 694');
+INSERT INTO guests (email, name) VALUES ('user694@example.com', 'user 694');
+INSERT INTO users (email, name) VALUES ('user695@example.com', 'user 695');
+INSERT INTO guests (email, name) VALUES ('user695@example.com', 'user 695');
+INSERT INTO users (email, name) VALUES ('user696@example.com', 'user 696');
+INSERT INTO guests (email, name) VALUES ('user696@example.com', 'user 696');
+INSERT INTO users (email, name) VALUES ('user697@example.com', 'user 697');
+INSERT INTO guests (email, name) VALUES ('user697@example.com', 'user 697');
+INSERT INTO users (email, name) VALUES ('user698@example.com', 'user 698');
+INSERT INTO guests (email, name) VALUES ('user698@example.com', 'user 698');
+INSERT INTO users (email, name) VALUES ('user699@example.com', 'user 699');
+INSERT INTO guests (email, name) VALUES ('user699@example.com', 'user 699');
+INSERT INTO users (email, name) VALUES ('user700@example.com', 'user 700');
+INSERT INTO guests (email, name) VALUES ('user700@example.com', 'user 700');
+INSERT INTO users (email, name) VALUES ('user701@example.com', 'user 701');
+INSERT INTO guests (email, name) VALUES ('user701@example.com', 'user 701');
+INSERT INTO users (email, name) VALUES ('user702@example.com', 'user 702');
+INSERT INTO guests (email, name) VALUES ('user702@example.com', 'user 702');
+INSERT INTO users (email, name) VALUES ('user703@example.com', 'user 703');
+INSERT INTO guests (email, name) VALUES ('user703@example.com', 'user 703');
+INSERT INTO users (email, name) VALUES ('user704@example.com', 'user 704');
+INSERT INTO guests (email, name) VALUES ('user704@example.com', 'user 704');
+INSERT INTO users (email, name) VALUES ('user705@example.com', 'user 705');
+INSERT INTO guests (email, name) VALUES ('user705@example.com', 'user 705');
+INSERT INTO users (email, name) VALUES ('user706@example.com', 'user 706');
+INSERT INTO guests (email, name) VALUES ('user706@example.com', 'user 706');
+INSERT INTO users (email, name) VALUES ('user707@example.com', 'user 707');
+INSERT INTO guests (email, name) VALUES ('user707@example.com', 'user 707');
+INSERT INTO users (email, name) VALUES ('user708@example.com', 'user 708');
+INSERT INTO guests (email, name) VALUES ('user708@example.com', 'user 708');
+INSERT INTO users (email, name) VALUES ('user709@example.com', 'user 709');
+INSERT INTO guests (email, name) VALUES ('user709@example.com', 'user 709');
+INSERT INTO users (email, name) VALUES ('user710@example.com', 'user 710');
+INSERT INTO guests (email, name) VALUES ('user710@example.com', 'user 710');
+INSERT INTO users (email, name) VALUES ('user711@example.com', 'user 711');
+INSERT INTO guests (email, name) VALUES ('user711@example.com', 'user 711');
+INSERT INTO users (email, name) VALUES ('user712@example.com', 'user 712');
+INSERT INTO guests (email, name) VALUES ('user712@example.com', 'user 712');
+INSERT INTO users (email, name) VALUES ('user713@example.com', 'user 713');
+INSERT INTO guests (email, name) VALUES ('user713@example.com', 'user 713');
+INSERT INTO users (email, name) VALUES ('user714@example.com', 'user 714');
+INSERT INTO guests (email, name) VALUES ('user714@example.com', 'user 714');
+INSERT INTO users (email, name) VALUES ('user715@example.com', 'user 715');
+INSERT INTO guests (email, name) VALUES ('user715@example.com', 'user 715');
+INSERT INTO users (email, name) VALUES ('user716@example.com', 'user 716');
+INSERT INTO guests (email, name) VALUES ('user716@example.com', 'user 716');
+INSERT INTO users (email, name) VALUES ('user717@example.com', 'user 717');
+INSERT INTO guests (email, name) VALUES ('user717@example.com', 'user 717');
+INSERT INTO users (email, name) VALUES ('user718@example.com', 'user 718');
+INSERT INTO guests (email, name) VALUES ('user718@example.com', 'user 718');
+INSERT INTO users (email, name) VALUES ('user719@example.com', 'user 719');
+INSERT INTO guests (email, name) VALUES ('user719@example.com', 'user 719');
+INSERT INTO users (email, name) VALUES ('user720@example.com', 'user 720');
+INSERT INTO guests (email, name) VALUES ('user720@example.com', 'user 720');
+INSERT INTO users (email, name) VALUES ('user721@example.com', 'user 721');
+INSERT INTO guests (email, name) VALUES ('user721@example.com', 'user 721');
+INSERT INTO users (email, name) VALUES ('user722@example.com', 'user 722');
+INSERT INTO guests (email, name) VALUES ('user722@example.com', 'user 722');
+INSERT INTO users (email, name) VALUES ('user723@example.com', 'user 723');
+INSERT INTO guests (email, name) VALUES ('user723@example.com', 'user 723');
+INSERT INTO users (email, name) VALUES ('user724@example.com', 'user 724');
+INSERT INTO guests (email, name) VALUES ('user724@example.com', 'user 724');
+INSERT INTO users (email, name) VALUES ('user725@example.com', 'user 725');
+INSERT INTO guests (email, name) VALUES ('user725@example.com', 'user 725');
+INSERT INTO users (email, name) VALUES ('user726@example.com', 'user 726');
+INSERT INTO guests (email, name) VALUES ('user726@example.com', 'user 726');
+INSERT INTO users (email, name) VALUES ('user727@example.com', 'user 727');
+INSERT INTO guests (email, name) VALUES ('user727@example.com', 'user 727');
+INSERT INTO users (email, name) VALUES ('user728@example.com', 'user 728');
+INSERT INTO guests (email, name) VALUES ('user728@example.com', 'user 728');
+INSERT INTO users (email, name) VALUES ('user729@example.com', 'user 729');
+INSERT INTO guests (email, name) VALUES ('user729@example.com', 'user 729');
+INSERT INTO users (email, name) VALUES ('user730@example.com', 'user 730');
+INSERT INTO guests (email, name) VALUES ('user730@example.com', 'user 730');
+INSERT INTO users (email, name) VALUES ('user731@example.com', 'user 731');
+INSERT INTO guests (email, name) VALUES ('user731@example.com', 'user 731');
+INSERT INTO users (email, name) VALUES ('user732@example.com', 'user 732');
+INSERT INTO guests (email, name) VALUES ('user732@example.com', 'user 732');
+INSERT INTO users (email, name) VALUES ('user733@example.com', 'user 733');
+INSERT INTO guests (email, name) VALUES ('user733@example.com', 'user 733');
+INSERT INTO users (email, name) VALUES ('user734@example.com', 'user 734');
+INSERT INTO guests (email, name) VALUES ('user734@example.com', 'user 734');
+INSERT INTO users (email, name) VALUES ('user735@example.com', 'user 735');
+INSERT INTO guests (email, name) VALUES ('user735@example.com', 'user 735');
+INSERT INTO users (email, name) VALUES ('user736@example.com', 'user 736');
+INSERT INTO guests (email, name) VALUES ('user736@example.com', 'user 736');
+INSERT INTO users (email, name) VALUES ('user737@example.com', 'user 737');
+INSERT INTO guests (email, name) VALUES ('user737@example.com', 'user 737');
+INSERT INTO users (email, name) VALUES ('user738@example.com', 'user 738');
+INSERT INTO guests (email, name) VALUES ('user738@example.com', 'user 738');
+INSERT INTO users (email, name) VALUES ('user739@example.com', 'user 739');
+INSERT INTO guests (email, name) VALUES ('user739@example.com', 'user 739');
+INSERT INTO users (email, name) VALUES ('user740@example.com', 'user 740');
+INSERT INTO guests (email, name) VALUES ('user740@example.com', 'user 740');
+INSERT INTO users (email, name) VALUES ('user741@example.com', 'user 741');
+INSERT INTO guests (email, name) VALUES ('user741@example.com', 'user 741');
+INSERT INTO users (email, name) VALUES ('user742@example.com', 'user 742');
+INSERT INTO guests (email, name) VALUES ('user742@example.com', 'user 742');
+INSERT INTO users (email, name) VALUES ('user743@example.com', 'user 743');
+INSERT INTO guests (email, name) VALUES ('user743@example.com', 'user 743');
+INSERT INTO users (email, name) VALUES ('user744@example.com', 'user 744');
+INSERT INTO guests (email, name) VALUES ('user744@example.com', 'user 744');
+INSERT INTO users (email, name) VALUES ('user745@example.com', 'user 745');
+INSERT INTO guests (email, name) VALUES ('user745@example.com', 'user 745');
+INSERT INTO users (email, name) VALUES ('user746@example.com', 'user 746');
+INSERT INTO guests (email, name) VALUES ('user746@example.com', 'user 746');
+INSERT INTO users (email, name) VALUES ('user747@example.com', 'user 747');
+INSERT INTO guests (email, name) VALUES ('user747@example.com', 'user 747');
+INSERT INTO users (email, name) VALUES ('user748@example.com', 'user 748');
+INSERT INTO guests (email, name) VALUES ('user748@example.com', 'user 748');
+INSERT INTO users (email, name) VALUES ('user749@example.com', 'user 749');
+INSERT INTO guests (email, name) VALUES ('user749@example.com', 'user 749');
+INSERT INTO users (email, name) VALUES ('user750@example.com', 'user 750');
+INSERT INTO guests (email, name) VALUES ('user750@example.com', 'user 750');
+INSERT INTO users (email, name) VALUES ('user751@example.com', 'user 751');
+INSERT INTO guests (email, name) VALUES ('user751@example.com', 'user 751');
+INSERT INTO users (email, name) VALUES ('user752@example.com', 'user 752');
+INSERT INTO guests (email, name) VALUES ('user752@example.com', 'user 752');
+INSERT INTO users (email, name) VALUES ('user753@example.com', 'user 753');
+INSERT INTO guests (email, name) VALUES ('user753@example.com', 'user 753');
+INSERT INTO users (email, name) VALUES ('user754@example.com', 'user 754');
+INSERT INTO guests (email, name) VALUES ('user754@example.com', 'user 754');
+INSERT INTO users (email, name) VALUES ('user755@example.com', 'user 755');
+INSERT INTO guests (email, name) VALUES ('user755@example.com', 'user 755');
+INSERT INTO users (email, name) VALUES ('user756@example.com', 'user 756');
+INSERT INTO guests (email, name) VALUES ('user756@example.com', 'user 756');
+INSERT INTO users (email, name) VALUES ('user757@example.com', 'user 757');
+INSERT INTO guests (email, name) VALUES ('user757@example.com', 'user 757');
+INSERT INTO users (email, name) VALUES ('user758@example.com', 'user 758');
+INSERT INTO guests (email, name) VALUES ('user758@example.com', 'user 758');
+INSERT INTO users (email, name) VALUES ('user759@example.com', 'user 759');
+INSERT INTO guests (email, name) VALUES ('user759@example.com', 'user 759');
+INSERT INTO users (email, name) VALUES ('user760@example.com', 'user 760');
+INSERT INTO guests (email, name) VALUES ('user760@example.com', 'user 760');
+INSERT INTO users (email, name) VALUES ('user761@example.com', 'user 761');
+INSERT INTO guests (email, name) VALUES ('user761@example.com', 'user 761');
+INSERT INTO users (email, name) VALUES ('user762@example.com', 'user 762');
+INSERT INTO guests (email, name) VALUES ('user762@example.com', 'user 762');
+INSERT INTO users (email, name) VALUES ('user763@example.com', 'user 763');
+INSERT INTO guests (email, name) VALUES ('user763@example.com', 'user 763');
+INSERT INTO users (email, name) VALUES ('user764@example.com', 'user 764');
+INSERT INTO guests (email, name) VALUES ('user764@example.com', 'user 764');
+INSERT INTO users (email, name) VALUES ('user765@example.com', 'user 765');
+INSERT INTO guests (email, name) VALUES ('user765@example.com', 'user 765');
+INSERT INTO users (email, name) VALUES ('user766@example.com', 'user 766');
+INSERT INTO guests (email, name) VALUES ('user766@example.com', 'user 766');
+INSERT INTO users (email, name) VALUES ('user767@example.com', 'user 767');
+INSERT INTO guests (email, name) VALUES ('user767@example.com', 'user 767');
+INSERT INTO users (email, name) VALUES ('user768@example.com', 'user 768');
+INSERT INTO guests (email, name) VALUES ('user768@example.com', 'user 768');
+INSERT INTO users (email, name) VALUES ('user769@example.com', 'user 769');
+INSERT INTO guests (email, name) VALUES ('user769@example.com', 'user 769');
+INSERT INTO users (email, name) VALUES ('user770@example.com', 'user 770');
+INSERT INTO guests (email, name) VALUES ('user770@example.com', 'user 770');
+INSERT INTO users (email, name) VALUES ('user771@example.com', 'user 771');
+INSERT INTO guests (email, name) VALUES ('user771@example.com', 'user 771');
+INSERT INTO users (email, name) VALUES ('user772@example.com', 'user 772');
+INSERT INTO guests (email, name) VALUES ('user772@example.com', 'user 772');
+INSERT INTO users (email, name) VALUES ('user773@example.com', 'user 773');
+INSERT INTO guests (email, name) VALUES ('user773@example.com', 'user 773');
+INSERT INTO users (email, name) VALUES ('user774@example.com', 'user 774');
+INSERT INTO guests (email, name) VALUES ('user774@example.com', 'user 774');
+INSERT INTO users (email, name) VALUES ('user775@example.com', 'user 775');
+INSERT INTO guests (email, name) VALUES ('user775@example.com', 'user 775');
+INSERT INTO users (email, name) VALUES ('user776@example.com', 'user 776');
+INSERT INTO guests (email, name) VALUES ('user776@example.com', 'user 776');
+INSERT INTO users (email, name) VALUES ('user777@example.com', 'user 777');
+INSERT INTO guests (email, name) VALUES ('user777@example.com', 'user 777');
+INSERT INTO users (email, name) VALUES ('user778@example.com', 'user 778');
+INSERT INTO guests (email, name) VALUES ('user778@example.com', 'user 778');
+INSERT INTO users (email, name) VALUES ('user779@example.com', 'user 779');
+INSERT INTO guests (email, name) VALUES ('user779@example.com', 'user 779');
+INSERT INTO users (email, name) VALUES ('user780@example.com', 'user 780');
+INSERT INTO guests (email, name) VALUES ('user780@example.com', 'user 780');
+INSERT INTO users (email, name) VALUES ('user781@example.com', 'user 781');
+INSERT INTO guests (email, name) VALUES ('user781@example.com', 'user 781');
+INSERT INTO users (email, name) VALUES ('user782@example.com', 'user 782');
+INSERT INTO guests (email, name) VALUES ('user782@example.com', 'user 782');
+INSERT INTO users (email, name) VALUES ('user783@example.com', 'user 783');
+INSERT INTO guests (email, name) VALUES ('user783@example.com', 'user 783');
+INSERT INTO users (email, name) VALUES ('user784@example.com', 'user 784');
+INSERT INTO guests (email, name) VALUES ('user784@example.com', 'user 784');
+INSERT INTO users (email, name) VALUES ('user785@example.com', 'user 785');
+INSERT INTO guests (email, name) VALUES ('user785@example.com', 'user 785');
+INSERT INTO users (email, name) VALUES ('user786@example.com', 'user 786');
+INSERT INTO guests (email, name) VALUES ('user786@example.com', 'user 786');
+INSERT INTO users (email, name) VALUES ('user787@example.com', 'user 787');
+INSERT INTO guests (email, name) VALUES ('user787@example.com', 'user 787');
+INSERT INTO users (email, name) VALUES ('user788@example.com', 'user 788');
+INSERT INTO guests (email, name) VALUES ('user788@example.com', 'user 788');
+INSERT INTO users (email, name) VALUES ('user789@example.com', 'user 789');
+INSERT INTO guests (email, name) VALUES ('user789@example.com', 'user 789');
+INSERT INTO users (email, name) VALUES ('user790@example.com', 'user 790');
+INSERT INTO guests (email, name) VALUES ('user790@example.com', 'user 790');
+INSERT INTO users (email, name) VALUES ('user791@example.com', 'user 791');
+INSERT INTO guests (email, name) VALUES ('user791@example.com', 'user 791');
+INSERT INTO users (email, name) VALUES ('user792@example.com', 'user 792');
+INSERT INTO guests (email, name) VALUES ('user792@example.com', 'user 792');
+INSERT INTO users (email, name) VALUES ('user793@example.com', 'user 793');
+INSERT INTO guests (email, name) VALUES ('user793@example.com', 'user 793');
+INSERT INTO users (email, name) VALUES ('user794@example.com', 'user 794');
+INSERT INTO guests (email, name) VALUES ('user794@example.com', 'user 794');
+INSERT INTO users (email, name) VALUES ('user795@example.com', 'user 795');
+INSERT INTO guests (email, name) VALUES ('user795@example.com', 'user 795');
+INSERT INTO users (email, name) VALUES ('user796@example.com', 'user 796');
+INSERT INTO guests (email, name) VALUES ('user796@example.com', 'user 796');
+INSERT INTO users (email, name) VALUES ('user797@example.com', 'user 797');
+INSERT INTO guests (email, name) VALUES ('user797@example.com', 'user 797');
+INSERT INTO users (email, name) VALUES ('user798@example.com', 'user 798');
+INSERT INTO guests (email, name) VALUES ('user798@example.com', 'user 798');
+INSERT INTO users (email, name) VALUES ('user799@example.com', 'user 799');
+INSERT INTO guests (email, name) VALUES ('user799@example.com', 'user 799');
+INSERT INTO users (email, name) VALUES ('user800@example.com', 'user 800');
+INSERT INTO guests (email, name) VALUES ('user800@example.com', 'user 800');
+INSERT INTO users (email, name) VALUES ('user801@example.com', 'user 801');
+INSERT INTO guests (email, name) VALUES ('user801@example.com', 'user 801');
+INSERT INTO users (email, name) VALUES ('user802@example.com', 'user 802');
+INSERT INTO guests (email, name) VALUES ('user802@example.com', 'user 802');
+INSERT INTO users (email, name) VALUES ('user803@example.com', 'user 803');
+INSERT INTO guests (email, name) VALUES ('user803@example.com', 'user 803');
+INSERT INTO users (email, name) VALUES ('user804@example.com', 'user 804');
+INSERT INTO guests (email, name) VALUES ('user804@example.com', 'user 804');
+INSERT INTO users (email, name) VALUES ('user805@example.com', 'user 805');
+INSERT INTO guests (email, name) VALUES ('user805@example.com', 'user 805');
+INSERT INTO users (email, name) VALUES ('user806@example.com', 'user 806');
+INSERT INTO guests (email, name) VALUES ('user806@example.com', 'user 806');
+INSERT INTO users (email, name) VALUES ('user807@example.com', 'user 807');
+INSERT INTO guests (email, name) VALUES ('user807@example.com', 'user 807');
+INSERT INTO users (email, name) VALUES ('user808@example.com', 'user 808');
+INSERT INTO guests (email, name) VALUES ('user808@example.com', 'user 808');
+INSERT INTO users (email, name) VALUES ('user809@example.com', 'user 809');
+INSERT INTO guests (email, name) VALUES ('user809@example.com', 'user 809');
+INSERT INTO users (email, name) VALUES ('user810@example.com', 'user 810');
+INSERT INTO guests (email, name) VALUES ('user810@example.com', 'user 810');
+INSERT INTO users (email, name) VALUES ('user811@example.com', 'user 811');
+INSERT INTO guests (email, name) VALUES ('user811@example.com', 'user 811');
+INSERT INTO users (email, name) VALUES ('user812@example.com', 'user 812');
+INSERT INTO guests (email, name) VALUES ('user812@example.com', 'user 812');
+INSERT INTO users (email, name) VALUES ('user813@example.com', 'user 813');
+INSERT INTO guests (email, name) VALUES ('user813@example.com', 'user 813');
+INSERT INTO users (email, name) VALUES ('user814@example.com', 'user 814');
+INSERT INTO guests (email, name) VALUES ('user814@example.com', 'user 814');
+INSERT INTO users (email, name) VALUES ('user815@example.com', 'user 815');
+INSERT INTO guests (email, name) VALUES ('user815@example.com', 'user 815');
+INSERT INTO users (email, name) VALUES ('user816@example.com', 'user 816');
+INSERT INTO guests (email, name) VALUES ('user816@example.com', 'user 816');
+INSERT INTO users (email, name) VALUES ('user817@example.com', 'user 817');
+INSERT INTO guests (email, name) VALUES ('user817@example.com', 'user 817');
+INSERT INTO users (email, name) VALUES ('user818@example.com', 'user 818');
+INSERT INTO guests (email, name) VALUES ('user818@example.com', 'user 818');
+INSERT INTO users (email, name) VALUES ('user819@example.com', 'user 819');
+INSERT INTO guests (email, name) VALUES ('user819@example.com', 'user 819');
+INSERT INTO users (email, name) VALUES ('user820@example.com', 'user 820');
+INSERT INTO guests (email, name) VALUES ('user820@example.com', 'user 820');
+INSERT INTO users (email, name) VALUES ('user821@example.com', 'user 821');
+INSERT INTO guests (email, name) VALUES ('user821@example.com', 'user 821');
+INSERT INTO users (email, name) VALUES ('user822@example.com', 'user 822');
+INSERT INTO guests (email, name) VALUES ('user822@example.com', 'user 822');
+INSERT INTO users (email, name) VALUES ('user823@example.com', 'user 823');
+INSERT INTO guests (email, name) VALUES ('user823@example.com', 'user 823');
+INSERT INTO users (email, name) VALUES ('user824@example.com', 'user 824');
+INSERT INTO guests (email, name) VALUES ('user824@example.com', 'user 824');
+INSERT INTO users (email, name) VALUES ('user825@example.com', 'user 825');
+INSERT INTO guests (email, name) VALUES ('user825@example.com', 'user 825');
+INSERT INTO users (email, name) VALUES ('user826@example.com', 'user 826');
+INSERT INTO guests (email, name) VALUES ('user826@example.com', 'user 826');
+INSERT INTO users (email, name) VALUES ('user827@example.com', 'user 827');
+INSERT INTO guests (email, name) VALUES ('user827@example.com', 'user 827');
+INSERT INTO users (email, name) VALUES ('user828@example.com', 'user 828');
+INSERT INTO guests (email, name) VALUES ('user828@example.com', 'user 828');
+INSERT INTO users (email, name) VALUES ('user829@example.com', 'user 829');
+INSERT INTO guests (email, name) VALUES ('user829@example.com', 'user 829');
+INSERT INTO users (email, name) VALUES ('user830@example.com', 'user 830');
+INSERT INTO guests (email, name) VALUES ('user830@example.com', 'user 830');
+INSERT INTO users (email, name) VALUES ('user831@example.com', 'user 831');
+INSERT INTO guests (email, name) VALUES ('user831@example.com', 'user 831');
+INSERT INTO users (email, name) VALUES ('user832@example.com', 'user 832');
+INSERT INTO guests (email, name) VALUES ('user832@example.com', 'user 832');
+INSERT INTO users (email, name) VALUES ('user833@example.com', 'user 833');
+INSERT INTO guests (email, name) VALUES ('user833@example.com', 'user 833');
+INSERT INTO users (email, name) VALUES ('user834@example.com', 'user 834');
+INSERT INTO guests (email, name) VALUES ('user834@example.com', 'user 834');
+INSERT INTO users (email, name) VALUES ('user835@example.com', 'user 835');
+INSERT INTO guests (email, name) VALUES ('user835@example.com', 'user 835');
+INSERT INTO users (email, name) VALUES ('user836@example.com', 'user 836');
+INSERT INTO guests (email, name) VALUES ('user836@example.com', 'user 836');
+INSERT INTO users (email, name) VALUES ('user837@example.com', 'user 837');
+INSERT INTO guests (email, name) VALUES ('user837@example.com', 'user 837');
+INSERT INTO users (email, name) VALUES ('user838@example.com', 'user 838');
+INSERT INTO guests (email, name) VALUES ('user838@example.com', 'user 838');
+INSERT INTO users (email, name) VALUES ('user839@example.com', 'user 839');
+INSERT INTO guests (email, name) VALUES ('user839@example.com', 'user 839');
+INSERT INTO users (email, name) VALUES ('user840@example.com', 'user 840');
+INSERT INTO guests (email, name) VALUES ('user840@example.com', 'user 840');
+INSERT INTO users (email, name) VALUES ('user841@example.com', 'user 841');
+INSERT INTO guests (email, name) VALUES ('user841@example.com', 'user 841');
+INSERT INTO users (email, name) VALUES ('user842@example.com', 'user 842');
+INSERT INTO guests (email, name) VALUES ('user842@example.com', 'user 842');
+INSERT INTO users (email, name) VALUES ('user843@example.com', 'user 843');
+INSERT INTO guests (email, name) VALUES ('user843@example.com', 'user 843');
+INSERT INTO users (email, name) VALUES ('user844@example.com', 'user 844');
+INSERT INTO guests (email, name) VALUES ('user844@example.com', 'user 844');
+INSERT INTO users (email, name) VALUES ('user845@example.com', 'user 845');
+INSERT INTO guests (email, name) VALUES ('user845@example.com', 'user 845');
+INSERT INTO users (email, name) VALUES ('user846@example.com', 'user 846');
+INSERT INTO guests (email, name) VALUES ('user846@example.com', 'user 846');
+INSERT INTO users (email, name) VALUES ('user847@example.com', 'user 847');
+INSERT INTO guests (email, name) VALUES ('user847@example.com', 'user 847');
+INSERT INTO users (email, name) VALUES ('user848@example.com', 'user 848');
+INSERT INTO guests (email, name) VALUES ('user848@example.com', 'user 848');
+INSERT INTO users (email, name) VALUES ('user849@example.com', 'user 849');
+INSERT INTO guests (email, name) VALUES ('user849@example.com', 'user 849');
+INSERT INTO users (email, name) VALUES ('user850@example.com', 'user 850');
+INSERT INTO guests (email, name) VALUES ('user850@example.com', 'user 850');
+INSERT INTO users (email, name) VALUES ('user851@example.com', 'user 851');
+INSERT INTO guests (email, name) VALUES ('user851@example.com', 'user 851');
+INSERT INTO users (email, name) VALUES ('user852@example.com', 'user 852');
+INSERT INTO guests (email, name) VALUES ('user852@example.com', 'user 852');
+INSERT INTO users (email, name) VALUES ('user853@example.com', 'user 853');
+INSERT INTO guests (email, name) VALUES ('user853@example.com', 'user 853');
+INSERT INTO users (email, name) VALUES ('user854@example.com', 'user 854');
+INSERT INTO guests (email, name) VALUES ('user854@example.com', 'user 854');
+INSERT INTO users (email, name) VALUES ('user855@example.com', 'user 855');
+INSERT INTO guests (email, name) VALUES ('user855@example.com', 'user 855');
+INSERT INTO users (email, name) VALUES ('user856@example.com', 'user 856');
+INSERT INTO guests (email, name) VALUES ('user856@example.com', 'user 856');
+INSERT INTO users (email, name) VALUES ('user857@example.com', 'user 857');
+INSERT INTO guests (email, name) VALUES ('user857@example.com', 'user 857');
+INSERT INTO users (email, name) VALUES ('user858@example.com', 'user 858');
+INSERT INTO guests (email, name) VALUES ('user858@example.com', 'user 858');
+INSERT INTO users (email, name) VALUES ('user859@example.com', 'user 859');
+INSERT INTO guests (email, name) VALUES ('user859@example.com', 'user 859');
+INSERT INTO users (email, name) VALUES ('user860@example.com', 'user 860');
+INSERT INTO guests (email, name) VALUES ('user860@example.com', 'user 860');
+INSERT INTO users (email, name) VALUES ('user861@example.com', 'user 861');
+INSERT INTO guests (email, name) VALUES ('user861@example.com', 'user 861');
+INSERT INTO users (email, name) VALUES ('user862@example.com', 'user 862');
+INSERT INTO guests (email, name) VALUES ('user862@example.com', 'user 862');
+INSERT INTO users (email, name) VALUES ('user863@example.com', 'user 863');
+INSERT INTO guests (email, name) VALUES ('user863@example.com', 'user 863');
+INSERT INTO users (email, name) VALUES ('user864@example.com', 'user 864');
+INSERT INTO guests (email, name) VALUES ('user864@example.com', 'user 864');
+INSERT INTO users (email, name) VALUES ('user865@example.com', 'user 865');
+INSERT INTO guests (email, name) VALUES ('user865@example.com', 'user 865');
+INSERT INTO users (email, name) VALUES ('user866@example.com', 'user 866');
+INSERT INTO guests (email, name) VALUES ('user866@example.com', 'user 866');
+INSERT INTO users (email, name) VALUES ('user867@example.com', 'user 867');
+INSERT INTO guests (email, name) VALUES ('user867@example.com', 'user 867');
+INSERT INTO users (email, name) VALUES ('user868@example.com', 'user 868');
+INSERT INTO guests (email, name) VALUES ('user868@example.com', 'user 868');
+INSERT INTO users (email, name) VALUES ('user869@example.com', 'user 869');
+INSERT INTO guests (email, name) VALUES ('user869@example.com', 'user 869');
+INSERT INTO users (email, name) VALUES ('user870@example.com', 'user 870');
+INSERT INTO guests (email, name) VALUES ('user870@example.com', 'user 870');
+INSERT INTO users (email, name) VALUES ('user871@example.com', 'user 871');
+INSERT INTO guests (email, name) VALUES ('user871@example.com', 'user 871');
+INSERT INTO users (email, name) VALUES ('user872@example.com', 'user 872');
+INSERT INTO guests (email, name) VALUES ('user872@example.com', 'user 872');
+INSERT INTO users (email, name) VALUES ('user873@example.com', 'user 873');
+INSERT INTO guests (email, name) VALUES ('user873@example.com', 'user 873');
+INSERT INTO users (email, name) VALUES ('user874@example.com', 'user 874');
+INSERT INTO guests (email, name) VALUES ('user874@example.com', 'user 874');
+INSERT INTO users (email, name) VALUES ('user875@example.com', 'user 875');
+INSERT INTO guests (email, name) VALUES ('user875@example.com', 'user 875');
+INSERT INTO users (email, name) VALUES ('user876@example.com', 'user 876');
+INSERT INTO guests (email, name) VALUES ('user876@example.com', 'user 876');
+INSERT INTO users (email, name) VALUES ('user877@example.com', 'user 877');
+INSERT INTO guests (email, name) VALUES ('user877@example.com', 'user 877');
+INSERT INTO users (email, name) VALUES ('user878@example.com', 'user 878');
+INSERT INTO guests (email, name) VALUES ('user878@example.com', 'user 878');
+INSERT INTO users (email, name) VALUES ('user879@example.com', 'user 879');
+INSERT INTO guests (email, name) VALUES ('user879@example.com', 'user 879');
+INSERT INTO users (email, name) VALUES ('user880@example.com', 'user 880');
+INSERT INTO guests (email, name) VALUES ('user880@example.com', 'user 880');
+INSERT INTO users (email, name) VALUES ('user881@example.com', 'user 881');
+INSERT INTO guests (email, name) VALUES ('user881@example.com', 'user 881');
+INSERT INTO users (email, name) VALUES ('user882@example.com', 'user 882');
+INSERT INTO guests (email, name) VALUES ('user882@example.com', 'user 882');
+INSERT INTO users (email, name) VALUES ('user883@example.com', 'user 883');
+INSERT INTO guests (email, name) VALUES ('user883@example.com', 'user 883');
+INSERT INTO users (email, name) VALUES ('user884@example.com', 'user 884');
+INSERT INTO guests (email, name) VALUES ('user884@example.com', 'user 884');
+INSERT INTO users (email, name) VALUES ('user885@example.com', 'user 885');
+INSERT INTO guests (email, name) VALUES ('user885@example.com', 'user 885');
+INSERT INTO users (email, name) VALUES ('user886@example.com', 'user 886');
+INSERT INTO guests (email, name) VALUES ('user886@example.com', 'user 886');
+INSERT INTO users (email, name) VALUES ('user887@example.com', 'user 887');
+INSERT INTO guests (email, name) VALUES ('user887@example.com', 'user 887');
+INSERT INTO users (email, name) VALUES ('user888@example.com', 'user 888');
+INSERT INTO guests (email, name) VALUES ('user888@example.com', 'user 888');
+INSERT INTO users (email, name) VALUES ('user889@example.com', 'user 889');
+INSERT INTO guests (email, name) VALUES ('user889@example.com', 'user 889');
+INSERT INTO users (email, name) VALUES ('user890@example.com', 'user 890');
+INSERT INTO guests (email, name) VALUES ('user890@example.com', 'user 890');
+INSERT INTO users (email, name) VALUES ('user891@example.com', 'user 891');
+INSERT INTO guests (email, name) VALUES ('user891@example.com', 'user 891');
+INSERT INTO users (email, name) VALUES ('user892@example.com', 'user 892');
+INSERT INTO guests (email, name) VALUES ('user892@example.com', 'user 892');
+INSERT INTO users (email, name) VALUES ('user893@example.com', 'user 893');
+INSERT INTO guests (email, name) VALUES ('user893@example.com', 'user 893');
+INSERT INTO users (email, name) VALUES ('user894@example.com', 'user 894');
+INSERT INTO guests (email, name) VALUES ('user894@example.com', 'user 894');
+INSERT INTO users (email, name) VALUES ('user895@example.com', 'user 895');
+INSERT INTO guests (email, name) VALUES ('user895@example.com', 'user 895');
+INSERT INTO users (email, name) VALUES ('user896@example.com', 'user 896');
+INSERT INTO guests (email, name) VALUES ('user896@example.com', 'user 896');
+INSERT INTO users (email, name) VALUES ('user897@example.com', 'user 897');
+INSERT INTO guests (email, name) VALUES ('user897@example.com', 'user 897');
+INSERT INTO users (email, name) VALUES ('user898@example.com', 'user 898');
+INSERT INTO guests (email, name) VALUES ('user898@example.com', 'user 898');
+INSERT INTO users (email, name) VALUES ('user899@example.com', 'user 899');
+INSERT INTO guests (email, name) VALUES ('user899@example.com', 'user 899');
+INSERT INTO users (email, name) VALUES ('user900@example.com', 'user 900');
+INSERT INTO guests (email, name) VALUES ('user900@example.com', 'user 900');
+INSERT INTO users (email, name) VALUES ('user901@example.com', 'user 901');
+INSERT INTO guests (email, name) VALUES ('user901@example.com', 'user 901');
+INSERT INTO users (email, name) VALUES ('user902@example.com', 'user 902');
+INSERT INTO guests (email, name) VALUES ('user902@example.com', 'user 902');
+INSERT INTO users (email, name) VALUES ('user903@example.com', 'user 903');
+INSERT INTO guests (email, name) VALUES ('user903@example.com', 'user 903');
+INSERT INTO users (email, name) VALUES ('user904@example.com', 'user 904');
+INSERT INTO guests (email, name) VALUES ('user904@example.com', 'user 904');
+INSERT INTO users (email, name) VALUES ('user905@example.com', 'user 905');
+INSERT INTO guests (email, name) VALUES ('user905@example.com', 'user 905');
+INSERT INTO users (email, name) VALUES ('user906@example.com', 'user 906');
+INSERT INTO guests (email, name) VALUES ('user906@example.com', 'user 906');
+INSERT INTO users (email, name) VALUES ('user907@example.com', 'user 907');
+INSERT INTO guests (email, name) VALUES ('user907@example.com', 'user 907');
+INSERT INTO users (email, name) VALUES ('user908@example.com', 'user 908');
+INSERT INTO guests (email, name) VALUES ('user908@example.com', 'user 908');
+INSERT INTO users (email, name) VALUES ('user909@example.com', 'user 909');
+INSERT INTO guests (email, name) VALUES ('user909@example.com', 'user 909');
+INSERT INTO users (email, name) VALUES ('user910@example.com', 'user 910');
+INSERT INTO guests (email, name) VALUES ('user910@example.com', 'user 910');
+INSERT INTO users (email, name) VALUES ('user911@example.com', 'user 911');
+INSERT INTO guests (email, name) VALUES ('user911@example.com', 'user 911');
+INSERT INTO users (email, name) VALUES ('user912@example.com', 'user 912');
+INSERT INTO guests (email, name) VALUES ('user912@example.com', 'user 912');
+INSERT INTO users (email, name) VALUES ('user913@example.com', 'user 913');
+INSERT INTO guests (email, name) VALUES ('user913@example.com', 'user 913');
+INSERT INTO users (email, name) VALUES ('user914@example.com', 'user 914');
+INSERT INTO guests (email, name) VALUES ('user914@example.com', 'user 914');
+INSERT INTO users (email, name) VALUES ('user915@example.com', 'user 915');
+INSERT INTO guests (email, name) VALUES ('user915@example.com', 'user 915');
+INSERT INTO users (email, name) VALUES ('user916@example.com', 'user 916');
+INSERT INTO guests (email, name) VALUES ('user916@example.com', 'user 916');
+INSERT INTO users (email, name) VALUES ('user917@example.com', 'user 917');
+INSERT INTO guests (email, name) VALUES ('user917@example.com', 'user 917');
+INSERT INTO users (email, name) VALUES ('user918@example.com', 'user 918');
+INSERT INTO guests (email, name) VALUES ('user918@example.com', 'user 918');
+INSERT INTO users (email, name) VALUES ('user919@example.com', 'user 919');
+INSERT INTO guests (email, name) VALUES ('user919@example.com', 'user 919');
+INSERT INTO users (email, name) VALUES ('user920@example.com', 'user 920');
+INSERT INTO guests (email, name) VALUES ('user920@example.com', 'user 920');
+INSERT INTO users (email, name) VALUES ('user921@example.com', 'user 921');
+INSERT INTO guests (email, name) VALUES ('user921@example.com', 'user 921');
+INSERT INTO users (email, name) VALUES ('user922@example.com', 'user 922');
+INSERT INTO guests (email, name) VALUES ('user922@example.com', 'user 922');
+INSERT INTO users (email, name) VALUES ('user923@example.com', 'user 923');
+INSERT INTO guests (email, name) VALUES ('user923@example.com', 'user 923');
+INSERT INTO users (email, name) VALUES ('user924@example.com', 'user 924');
+INSERT INTO guests (email, name) VALUES ('user924@example.com', 'user 924');
+INSERT INTO users (email, name) VALUES ('user925@example.com', 'user 925');
+INSERT INTO guests (email, name) VALUES ('user925@example.com', 'user 925');
+INSERT INTO users (email, name) VALUES ('user926@example.com', 'user 926');
+INSERT INTO guests (email, name) VALUES ('user926@example.com', 'user 926');
+INSERT INTO users (email, name) VALUES ('user927@example.com', 'user 927');
+INSERT INTO guests (email, name) VALUES ('user927@example.com', 'user 927');
+INSERT INTO users (email, name) VALUES ('user928@example.com', 'user 928');
+INSERT INTO guests (email, name) VALUES ('user928@example.com', 'user 928');
+INSERT INTO users (email, name) VALUES ('user929@example.com', 'user 929');
+INSERT INTO guests (email, name) VALUES ('user929@example.com', 'user 929');
+INSERT INTO users (email, name) VALUES ('user930@example.com', 'user 930');
+INSERT INTO guests (email, name) VALUES ('user930@example.com', 'user 930');
+INSERT INTO users (email, name) VALUES ('user931@example.com', 'user 931');
+INSERT INTO guests (email, name) VALUES ('user931@example.com', 'user 931');
+INSERT INTO users (email, name) VALUES ('user932@example.com', 'user 932');
+INSERT INTO guests (email, name) VALUES ('user932@example.com', 'user 932');
+INSERT INTO users (email, name) VALUES ('user933@example.com', 'user 933');
+INSERT INTO guests (email, name) VALUES ('user933@example.com', 'user 933');
+INSERT INTO users (email, name) VALUES ('user934@example.com', 'user 934');
+INSERT INTO guests (email, name) VALUES ('user934@example.com', 'user 934');
+INSERT INTO users (email, name) VALUES ('user935@example.com', 'user 935');
+INSERT INTO guests (email, name) VALUES ('user935@example.com', 'user 935');
+INSERT INTO users (email, name) VALUES ('user936@example.com', 'user 936');
+INSERT INTO guests (email, name) VALUES ('user936@example.com', 'user 936');
+INSERT INTO users (email, name) VALUES ('user937@example.com', 'user 937');
+INSERT INTO guests (email, name) VALUES ('user937@example.com', 'user 937');
+INSERT INTO users (email, name) VALUES ('user938@example.com', 'user 938');
+INSERT INTO guests (email, name) VALUES ('user938@example.com', 'user 938');
+INSERT INTO users (email, name) VALUES ('user939@example.com', 'user 939');
+INSERT INTO guests (email, name) VALUES ('user939@example.com', 'user 939');
+INSERT INTO users (email, name) VALUES ('user940@example.com', 'user 940');
+INSERT INTO guests (email, name) VALUES ('user940@example.com', 'user 940');
+INSERT INTO users (email, name) VALUES ('user941@example.com', 'user 941');
+INSERT INTO guests (email, name) VALUES ('user941@example.com', 'user 941');
+INSERT INTO users (email, name) VALUES ('user942@example.com', 'user 942');
+INSERT INTO guests (email, name) VALUES ('user942@example.com', 'user 942');
+INSERT INTO users (email, name) VALUES ('user943@example.com', 'user 943');
+INSERT INTO guests (email, name) VALUES ('user943@example.com', 'user 943');
+INSERT INTO users (email, name) VALUES ('user944@example.com', 'user 944');
+INSERT INTO guests (email, name) VALUES ('user944@example.com', 'user 944');
+INSERT INTO users (email, name) VALUES ('user945@example.com', 'user 945');
+INSERT INTO guests (email, name) VALUES ('user945@example.com', 'user 945');
+INSERT INTO users (email, name) VALUES ('user946@example.com', 'user 946');
+INSERT INTO guests (email, name) VALUES ('user946@example.com', 'user 946');
+INSERT INTO users (email, name) VALUES ('user947@example.com', 'user 947');
+INSERT INTO guests (email, name) VALUES ('user947@example.com', 'user 947');
+INSERT INTO users (email, name) VALUES ('user948@example.com', 'user 948');
+INSERT INTO guests (email, name) VALUES ('user948@example.com', 'user 948');
+INSERT INTO users (email, name) VALUES ('user949@example.com', 'user 949');
+INSERT INTO guests (email, name) VALUES ('user949@example.com', 'user 949');
+INSERT INTO users (email, name) VALUES ('user950@example.com', 'user 950');
+INSERT INTO guests (email, name) VALUES ('user950@example.com', 'user 950');
+INSERT INTO users (email, name) VALUES ('user951@example.com', 'user 951');
+INSERT INTO guests (email, name) VALUES ('user951@example.com', 'user 951');
+INSERT INTO users (email, name) VALUES ('user952@example.com', 'user 952');
+INSERT INTO guests (email, name) VALUES ('user952@example.com', 'user 952');
+INSERT INTO users (email, name) VALUES ('user953@example.com', 'user 953');
+INSERT INTO guests (email, name) VALUES ('user953@example.com', 'user 953');
+INSERT INTO users (email, name) VALUES ('user954@example.com', 'user 954');
+INSERT INTO guests (email, name) VALUES ('user954@example.com', 'user 954');
+INSERT INTO users (email, name) VALUES ('user955@example.com', 'user 955');
+INSERT INTO guests (email, name) VALUES ('user955@example.com', 'user 955');
+INSERT INTO users (email, name) VALUES ('user956@example.com', 'user 956');
+INSERT INTO guests (email, name) VALUES ('user956@example.com', 'user 956');
+INSERT INTO users (email, name) VALUES ('user957@example.com', 'user 957');
+INSERT INTO guests (email, name) VALUES ('user957@example.com', 'user 957');
+INSERT INTO users (email, name) VALUES ('user958@example.com', 'user 958');
+INSERT INTO guests (email, name) VALUES ('user958@example.com', 'user 958');
+INSERT INTO users (email, name) VALUES ('user959@example.com', 'user 959');
+INSERT INTO guests (email, name) VALUES ('user959@example.com', 'user 959');
+INSERT INTO users (email, name) VALUES ('user960@example.com', 'user 960');
+INSERT INTO guests (email, name) VALUES ('user960@example.com', 'user 960');
+INSERT INTO users (email, name) VALUES ('user961@example.com', 'user 961');
+INSERT INTO guests (email, name) VALUES ('user961@example.com', 'user 961');
+INSERT INTO users (email, name) VALUES ('user962@example.com', 'user 962');
+INSERT INTO guests (email, name) VALUES ('user962@example.com', 'user 962');
+INSERT INTO users (email, name) VALUES ('user963@example.com', 'user 963');
+INSERT INTO guests (email, name) VALUES ('user963@example.com', 'user 963');
+INSERT INTO users (email, name) VALUES ('user964@example.com', 'user 964');
+INSERT INTO guests (email, name) VALUES ('user964@example.com', 'user 964');
+INSERT INTO users (email, name) VALUES ('user965@example.com', 'user 965');
+INSERT INTO guests (email, name) VALUES ('user965@example.com', 'user 965');
+INSERT INTO users (email, name) VALUES ('user966@example.com', 'user 966');
+INSERT INTO guests (email, name) VALUES ('user966@example.com', 'user 966');
+INSERT INTO users (email, name) VALUES ('user967@example.com', 'user 967');
+INSERT INTO guests (email, name) VALUES ('user967@example.com', 'user 967');
+INSERT INTO users (email, name) VALUES ('user968@example.com', 'user 968');
+INSERT INTO guests (email, name) VALUES ('user968@example.com', 'user 968');
+INSERT INTO users (email, name) VALUES ('user969@example.com', 'user 969');
+INSERT INTO guests (email, name) VALUES ('user969@example.com', 'user 969');
+INSERT INTO users (email, name) VALUES ('user970@example.com', 'user 970');
+INSERT INTO guests (email, name) VALUES ('user970@example.com', 'user 970');
+INSERT INTO users (email, name) VALUES ('user971@example.com', 'user 971');
+INSERT INTO guests (email, name) VALUES ('user971@example.com', 'user 971');
+INSERT INTO users (email, name) VALUES ('user972@example.com', 'user 972');
+INSERT INTO guests (email, name) VALUES ('user972@example.com', 'user 972');
+INSERT INTO users (email, name) VALUES ('user973@example.com', 'user 973');
+INSERT INTO guests (email, name) VALUES ('user973@example.com', 'user 973');
+INSERT INTO users (email, name) VALUES ('user974@example.com', 'user 974');
+INSERT INTO guests (email, name) VALUES ('user974@example.com', 'user 974');
+INSERT INTO users (email, name) VALUES ('user975@example.com', 'user 975');
+INSERT INTO guests (email, name) VALUES ('user975@example.com', 'user 975');
+INSERT INTO users (email, name) VALUES ('user976@example.com', 'user 976');
+INSERT INTO guests (email, name) VALUES ('user976@example.com', 'user 976');
+INSERT INTO users (email, name) VALUES ('user977@example.com', 'user 977');
+INSERT INTO guests (email, name) VALUES ('user977@example.com', 'user 977');
+INSERT INTO users (email, name) VALUES ('user978@example.com', 'user 978');
+INSERT INTO guests (email, name) VALUES ('user978@example.com', 'user 978');
+INSERT INTO users (email, name) VALUES ('user979@example.com', 'user 979');
+INSERT INTO guests (email, name) VALUES ('user979@example.com', 'user 979');
+INSERT INTO users (email, name) VALUES ('user980@example.com', 'user 980');
+INSERT INTO guests (email, name) VALUES ('user980@example.com', 'user 980');
+INSERT INTO users (email, name) VALUES ('user981@example.com', 'user 981');
+INSERT INTO guests (email, name) VALUES ('user981@example.com', 'user 981');
+INSERT INTO users (email, name) VALUES ('user982@example.com', 'user 982');
+INSERT INTO guests (email, name) VALUES ('user982@example.com', 'user 982');
+INSERT INTO users (email, name) VALUES ('user983@example.com', 'user 983');
+INSERT INTO guests (email, name) VALUES ('user983@example.com', 'user 983');
+INSERT INTO users (email, name) VALUES ('user984@example.com', 'user 984');
+INSERT INTO guests (email, name) VALUES ('user984@example.com', 'user 984');
+INSERT INTO users (email, name) VALUES ('user985@example.com', 'user 985');
+INSERT INTO guests (email, name) VALUES ('user985@example.com', 'user 985');
+INSERT INTO users (email, name) VALUES ('user986@example.com', 'user 986');
+INSERT INTO guests (email, name) VALUES ('user986@example.com', 'user 986');
+INSERT INTO users (email, name) VALUES ('user987@example.com', 'user 987');
+INSERT INTO guests (email, name) VALUES ('user987@example.com', 'user 987');
+INSERT INTO users (email, name) VALUES ('user988@example.com', 'user 988');
+INSERT INTO guests (email, name) VALUES ('user988@example.com', 'user 988');
+INSERT INTO users (email, name) VALUES ('user989@example.com', 'user 989');
+INSERT INTO guests (email, name) VALUES ('user989@example.com', 'user 989');
+INSERT INTO users (email, name) VALUES ('user990@example.com', 'user 990');
+INSERT INTO guests (email, name) VALUES ('user990@example.com', 'user 990');
+INSERT INTO users (email, name) VALUES ('user991@example.com', 'user 991');
+INSERT INTO guests (email, name) VALUES ('user991@example.com', 'user 991');
+INSERT INTO users (email, name) VALUES ('user992@example.com', 'user 992');
+INSERT INTO guests (email, name) VALUES ('user992@example.com', 'user 992');
+INSERT INTO users (email, name) VALUES ('user993@example.com', 'user 993');
+INSERT INTO guests (email, name) VALUES ('user993@example.com', 'user 993');
+INSERT INTO users (email, name) VALUES ('user994@example.com', 'user 994');
+INSERT INTO guests (email, name) VALUES ('user994@example.com', 'user 994');
+INSERT INTO users (email, name) VALUES ('user995@example.com', 'user 995');
+INSERT INTO guests (email, name) VALUES ('user995@example.com', 'user 995');
+INSERT INTO users (email, name) VALUES ('user996@example.com', 'user 996');
+INSERT INTO guests (email, name) VALUES ('user996@example.com', 'user 996');
+INSERT INTO users (email, name) VALUES ('user997@example.com', 'user 997');
+INSERT INTO guests (email, name) VALUES ('user997@example.com', 'user 997');
+INSERT INTO users (email, name) VALUES ('user998@example.com', 'user 998');
+INSERT INTO guests (email, name) VALUES ('user998@example.com', 'user 998');
+INSERT INTO users (email, name) VALUES ('user999@example.com', 'user 999');
+INSERT INTO guests (email, name) VALUES ('user999@example.com', 'user 999');
+INSERT INTO users (email, name) VALUES ('user1000@example.com', 'user 1000');
+INSERT INTO guests (email, name) VALUES ('user1000@example.com', 'user 1000');
+INSERT INTO users (email, name) VALUES ('user1001@example.com', 'user 1001');
+INSERT INTO guests (email, name) VALUES ('user1001@example.com', 'user 1001');
+INSERT INTO users (email, name) VALUES ('user1002@example.com', 'user 1002');
+INSERT INTO guests (email, name) VALUES ('user1002@example.com', 'user 1002');
+INSERT INTO users (email, name) VALUES ('user1003@example.com', 'user 1003');
+INSERT INTO guests (email, name) VALUES ('user1003@example.com', 'user 1003');
+INSERT INTO users (email, name) VALUES ('user1004@example.com', 'user 1004');
+INSERT INTO guests (email, name) VALUES ('user1004@example.com', 'user 1004');
+INSERT INTO users (email, name) VALUES ('user1005@example.com', 'user 1005');
+INSERT INTO guests (email, name) VALUES ('user1005@example.com', 'user 1005');
+INSERT INTO users (email, name) VALUES ('user1006@example.com', 'user 1006');
+INSERT INTO guests (email, name) VALUES ('user1006@example.com', 'user 1006');
+INSERT INTO users (email, name) VALUES ('user1007@example.com', 'user 1007');
+INSERT INTO guests (email, name) VALUES ('user1007@example.com', 'user 1007');
+INSERT INTO users (email, name) VALUES ('user1008@example.com', 'user 1008');
+INSERT INTO guests (email, name) VALUES ('user1008@example.com', 'user 1008');
+INSERT INTO users (email, name) VALUES ('user1009@example.com', 'user 1009');
+INSERT INTO guests (email, name) VALUES ('user1009@example.com', 'user 1009');
+INSERT INTO users (email, name) VALUES ('user1010@example.com', 'user 1010');
+INSERT INTO guests (email, name) VALUES ('user1010@example.com', 'user 1010');
+INSERT INTO users (email, name) VALUES ('user1011@example.com', 'user 1011');
+INSERT INTO guests (email, name) VALUES ('user1011@example.com', 'user 1011');
+INSERT INTO users (email, name) VALUES ('user1012@example.com', 'user 1012');
+INSERT INTO guests (email, name) VALUES ('user1012@example.com', 'user 1012');
+INSERT INTO users (email, name) VALUES ('user1013@example.com', 'user 1013');
+INSERT INTO guests (email, name) VALUES ('user1013@example.com', 'user 1013');
+INSERT INTO users (email, name) VALUES ('user1014@example.com', 'user 1014');
+INSERT INTO guests (email, name) VALUES ('user1014@example.com', 'user 1014');
+INSERT INTO users (email, name) VALUES ('user1015@example.com', 'user 1015');
+INSERT INTO guests (email, name) VALUES ('user1015@example.com', 'user 1015');
+INSERT INTO users (email, name) VALUES ('user1016@example.com', 'user 1016');
+INSERT INTO guests (email, name) VALUES ('user1016@example.com', 'user 1016');
+INSERT INTO users (email, name) VALUES ('user1017@example.com', 'user 1017');
+INSERT INTO guests (email, name) VALUES ('user1017@example.com', 'user 1017');
+INSERT INTO users (email, name) VALUES ('user1018@example.com', 'user 1018');
+INSERT INTO guests (email, name) VALUES ('user1018@example.com', 'user 1018');
+INSERT INTO users (email, name) VALUES ('user1019@example.com', 'user 1019');
+INSERT INTO guests (email, name) VALUES ('user1019@example.com', 'user 1019');
+INSERT INTO users (email, name) VALUES ('user1020@example.com', 'user 1020');
+INSERT INTO guests (email, name) VALUES ('user1020@example.com', 'user 1020');
+INSERT INTO users (email, name) VALUES ('user1021@example.com', 'user 1021');
+INSERT INTO guests (email, name) VALUES ('user1021@example.com', 'user 1021');
+INSERT INTO users (email, name) VALUES ('user1022@example.com', 'user 1022');
+INSERT INTO guests (email, name) VALUES ('user1022@example.com', 'user 1022');
+INSERT INTO users (email, name) VALUES ('user1023@example.com', 'user 1023');
+INSERT INTO guests (email, name) VALUES ('user1023@example.com', 'user 1023');
+INSERT INTO users (email, name) VALUES ('user1024@example.com', 'user 1024');
+INSERT INTO guests (email, name) VALUES ('user1024@example.com', 'user 1024');
+INSERT INTO users (email, name) VALUES ('user1025@example.com', 'user 1025');
+INSERT INTO guests (email, name) VALUES ('user1025@example.com', 'user 1025');
+INSERT INTO users (email, name) VALUES ('user1026@example.com', 'user 1026');
+INSERT INTO guests (email, name) VALUES ('user1026@example.com', 'user 1026');
+INSERT INTO users (email, name) VALUES ('user1027@example.com', 'user 1027');
+INSERT INTO guests (email, name) VALUES ('user1027@example.com', 'user 1027');
+INSERT INTO users (email, name) VALUES ('user1028@example.com', 'user 1028');
+INSERT INTO guests (email, name) VALUES ('user1028@example.com', 'user 1028');
+INSERT INTO users (email, name) VALUES ('user1029@example.com', 'user 1029');
+INSERT INTO guests (email, name) VALUES ('user1029@example.com', 'user 1029');
+INSERT INTO users (email, name) VALUES ('user1030@example.com', 'user 1030');
+INSERT INTO guests (email, name) VALUES ('user1030@example.com', 'user 1030');
+INSERT INTO users (email, name) VALUES ('user1031@example.com', 'user 1031');
+INSERT INTO guests (email, name) VALUES ('user1031@example.com', 'user 1031');
+INSERT INTO users (email, name) VALUES ('user1032@example.com', 'user 1032');
+INSERT INTO guests (email, name) VALUES ('user1032@example.com', 'user 1032');
+INSERT INTO users (email, name) VALUES ('user1033@example.com', 'user 1033');
+INSERT INTO guests (email, name) VALUES ('user1033@example.com', 'user 1033');
+INSERT INTO users (email, name) VALUES ('user1034@example.com', 'user 1034');
+INSERT INTO guests (email, name) VALUES ('user1034@example.com', 'user 1034');
+INSERT INTO users (email, name) VALUES ('user1035@example.com', 'user 1035');
+INSERT INTO guests (email, name) VALUES ('user1035@example.com', 'user 1035');
+INSERT INTO users (email, name) VALUES ('user1036@example.com', 'user 1036');
+INSERT INTO guests (email, name) VALUES ('user1036@example.com', 'user 1036');
+INSERT INTO users (email, name) VALUES ('user1037@example.com', 'user 1037');
+INSERT INTO guests (email, name) VALUES ('user1037@example.com', 'user 1037');
+INSERT INTO users (email, name) VALUES ('user1038@example.com', 'user 1038');
+INSERT INTO guests (email, name) VALUES ('user1038@example.com', 'user 1038');
+INSERT INTO users (email, name) VALUES ('user1039@example.com', 'user 1039');
+INSERT INTO guests (email, name) VALUES ('user1039@example.com', 'user 1039');
+INSERT INTO users (email, name) VALUES ('user1040@example.com', 'user 1040');
+INSERT INTO guests (email, name) VALUES ('user1040@example.com', 'user 1040');
+INSERT INTO users (email, name) VALUES ('user1041@example.com', 'user 1041');
+INSERT INTO guests (email, name) VALUES ('user1041@example.com', 'user 1041');
+INSERT INTO users (email, name) VALUES ('user1042@example.com', 'user 1042');
+INSERT INTO guests (email, name) VALUES ('user1042@example.com', 'user 1042');
+INSERT INTO users (email, name) VALUES ('user1043@example.com', 'user 1043');
+INSERT INTO guests (email, name) VALUES ('user1043@example.com', 'user 1043');
+INSERT INTO users (email, name) VALUES ('user1044@example.com', 'user 1044');
+INSERT INTO guests (email, name) VALUES ('user1044@example.com', 'user 1044');
+INSERT INTO users (email, name) VALUES ('user1045@example.com', 'user 1045');
+INSERT INTO guests (email, name) VALUES ('user1045@example.com', 'user 1045');
+INSERT INTO users (email, name) VALUES ('user1046@example.com', 'user 1046');
+INSERT INTO guests (email, name) VALUES ('user1046@example.com', 'user 1046');
+INSERT INTO users (email, name) VALUES ('user1047@example.com', 'user 1047');
+INSERT INTO guests (email, name) VALUES ('user1047@example.com', 'user 1047');
+INSERT INTO users (email, name) VALUES ('user1048@example.com', 'user 1048');
+INSERT INTO guests (email, name) VALUES ('user1048@example.com', 'user 1048');
+INSERT INTO users (email, name) VALUES ('user1049@example.com', 'user 1049');
+INSERT INTO guests (email, name) VALUES ('user1049@example.com', 'user 1049');
+INSERT INTO users (email, name) VALUES ('user1050@example.com', 'user 1050');
+INSERT INTO guests (email, name) VALUES ('user1050@example.com', 'user 1050');
+INSERT INTO users (email, name) VALUES ('user1051@example.com', 'user 1051');
+INSERT INTO guests (email, name) VALUES ('user1051@example.com', 'user 1051');
+INSERT INTO users (email, name) VALUES ('user1052@example.com', 'user 1052');
+INSERT INTO guests (email, name) VALUES ('user1052@example.com', 'user 1052');
+INSERT INTO users (email, name) VALUES ('user1053@example.com', 'user 1053');
+INSERT INTO guests (email, name) VALUES ('user1053@example.com', 'user 1053');
+INSERT INTO users (email, name) VALUES ('user1054@example.com', 'user 1054');
+INSERT INTO guests (email, name) VALUES ('user1054@example.com', 'user 1054');
+INSERT INTO users (email, name) VALUES ('user1055@example.com', 'user 1055');
+INSERT INTO guests (email, name) VALUES ('user1055@example.com', 'user 1055');
+INSERT INTO users (email, name) VALUES ('user1056@example.com', 'user 1056');
+INSERT INTO guests (email, name) VALUES ('user1056@example.com', 'user 1056');
+INSERT INTO users (email, name) VALUES ('user1057@example.com', 'user 1057');
+INSERT INTO guests (email, name) VALUES ('user1057@example.com', 'user 1057');
+INSERT INTO users (email, name) VALUES ('user1058@example.com', 'user 1058');
+INSERT INTO guests (email, name) VALUES ('user1058@example.com', 'user 1058');
+INSERT INTO users (email, name) VALUES ('user1059@example.com', 'user 1059');
+INSERT INTO guests (email, name) VALUES ('user1059@example.com', 'user 1059');
+INSERT INTO users (email, name) VALUES ('user1060@example.com', 'user 1060');
+INSERT INTO guests (email, name) VALUES ('user1060@example.com', 'user 1060');
+INSERT INTO users (email, name) VALUES ('user1061@example.com', 'user 1061');
+INSERT INTO guests (email, name) VALUES ('user1061@example.com', 'user 1061');
+INSERT INTO users (email, name) VALUES ('user1062@example.com', 'user 1062');
+INSERT INTO guests (email, name) VALUES ('user1062@example.com', 'user 1062');
+INSERT INTO users (email, name) VALUES ('user1063@example.com', 'user 1063');
+INSERT INTO guests (email, name) VALUES ('user1063@example.com', 'user 1063');
+INSERT INTO users (email, name) VALUES ('user1064@example.com', 'user 1064');
+INSERT INTO guests (email, name) VALUES ('user1064@example.com', 'user 1064');
+INSERT INTO users (email, name) VALUES ('user1065@example.com', 'user 1065');
+INSERT INTO guests (email, name) VALUES ('user1065@example.com', 'user 1065');
+INSERT INTO users (email, name) VALUES ('user1066@example.com', 'user 1066');
+INSERT INTO guests (email, name) VALUES ('user1066@example.com', 'user 1066');
+INSERT INTO users (email, name) VALUES ('user1067@example.com', 'user 1067');
+INSERT INTO guests (email, name) VALUES ('user1067@example.com', 'user 1067');
+INSERT INTO users (email, name) VALUES ('user1068@example.com', 'user 1068');
+INSERT INTO guests (email, name) VALUES ('user1068@example.com', 'user 1068');
+INSERT INTO users (email, name) VALUES ('user1069@example.com', 'user 1069');
+INSERT INTO guests (email, name) VALUES ('user1069@example.com', 'user 1069');
+INSERT INTO users (email, name) VALUES ('user1070@example.com', 'user 1070');
+INSERT INTO guests (email, name) VALUES ('user1070@example.com', 'user 1070');
+INSERT INTO users (email, name) VALUES ('user1071@example.com', 'user 1071');
+INSERT INTO guests (email, name) VALUES ('user1071@example.com', 'user 1071');
+INSERT INTO users (email, name) VALUES ('user1072@example.com', 'user 1072');
+INSERT INTO guests (email, name) VALUES ('user1072@example.com', 'user 1072');
+INSERT INTO users (email, name) VALUES ('user1073@example.com', 'user 1073');
+INSERT INTO guests (email, name) VALUES ('user1073@example.com', 'user 1073');
+INSERT INTO users (email, name) VALUES ('user1074@example.com', 'user 1074');
+INSERT INTO guests (email, name) VALUES ('user1074@example.com', 'user 1074');
+INSERT INTO users (email, name) VALUES ('user1075@example.com', 'user 1075');
+INSERT INTO guests (email, name) VALUES ('user1075@example.com', 'user 1075');
+INSERT INTO users (email, name) VALUES ('user1076@example.com', 'user 1076');
+INSERT INTO guests (email, name) VALUES ('user1076@example.com', 'user 1076');
+INSERT INTO users (email, name) VALUES ('user1077@example.com', 'user 1077');
+INSERT INTO guests (email, name) VALUES ('user1077@example.com', 'user 1077');
+INSERT INTO users (email, name) VALUES ('user1078@example.com', 'user 1078');
+INSERT INTO guests (email, name) VALUES ('user1078@example.com', 'user 1078');
+INSERT INTO users (email, name) VALUES ('user1079@example.com', 'user 1079');
+INSERT INTO guests (email, name) VALUES ('user1079@example.com', 'user 1079');
+INSERT INTO users (email, name) VALUES ('user1080@example.com', 'user 1080');
+INSERT INTO guests (email, name) VALUES ('user1080@example.com', 'user 1080');
+INSERT INTO users (email, name) VALUES ('user1081@example.com', 'user 1081');
+INSERT INTO guests (email, name) VALUES ('user1081@example.com', 'user 1081');
+INSERT INTO users (email, name) VALUES ('user1082@example.com', 'user 1082');
+INSERT INTO guests (email, name) VALUES ('user1082@example.com', 'user 1082');
+INSERT INTO users (email, name) VALUES ('user1083@example.com', 'user 1083');
+INSERT INTO guests (email, name) VALUES ('user1083@example.com', 'user 1083');
+INSERT INTO users (email, name) VALUES ('user1084@example.com', 'user 1084');
+INSERT INTO guests (email, name) VALUES ('user1084@example.com', 'user 1084');
+INSERT INTO users (email, name) VALUES ('user1085@example.com', 'user 1085');
+INSERT INTO guests (email, name) VALUES ('user1085@example.com', 'user 1085');
+INSERT INTO users (email, name) VALUES ('user1086@example.com', 'user 1086');
+INSERT INTO guests (email, name) VALUES ('user1086@example.com', 'user 1086');
+INSERT INTO users (email, name) VALUES ('user1087@example.com', 'user 1087');
+INSERT INTO guests (email, name) VALUES ('user1087@example.com', 'user 1087');
+INSERT INTO users (email, name) VALUES ('user1088@example.com', 'user 1088');
+INSERT INTO guests (email, name) VALUES ('user1088@example.com', 'user 1088');
+INSERT INTO users (email, name) VALUES ('user1089@example.com', 'user 1089');
+INSERT INTO guests (email, name) VALUES ('user1089@example.com', 'user 1089');
+INSERT INTO users (email, name) VALUES ('user1090@example.com', 'user 1090');
+INSERT INTO guests (email, name) VALUES ('user1090@example.com', 'user 1090');
+INSERT INTO users (email, name) VALUES ('user1091@example.com', 'user 1091');
+INSERT INTO guests (email, name) VALUES ('user1091@example.com', 'user 1091');
+INSERT INTO users (email, name) VALUES ('user1092@example.com', 'user 1092');
+INSERT INTO guests (email, name) VALUES ('user1092@example.com', 'user 1092');
+INSERT INTO users (email, name) VALUES ('user1093@example.com', 'user 1093');
+INSERT INTO guests (email, name) VALUES ('user1093@example.com', 'user 1093');
+INSERT INTO users (email, name) VALUES ('user1094@example.com', 'user 1094');
+INSERT INTO guests (email, name) VALUES ('user1094@example.com', 'user 1094');
+INSERT INTO users (email, name) VALUES ('user1095@example.com', 'user 1095');
+INSERT INTO guests (email, name) VALUES ('user1095@example.com', 'user 1095');
+INSERT INTO users (email, name) VALUES ('user1096@example.com', 'user 1096');
+INSERT INTO guests (email, name) VALUES ('user1096@example.com', 'user 1096');
+INSERT INTO users (email, name) VALUES ('user1097@example.com', 'user 1097');
+INSERT INTO guests (email, name) VALUES ('user1097@example.com', 'user 1097');
+INSERT INTO users (email, name) VALUES ('user1098@example.com', 'user 1098');
+INSERT INTO guests (email, name) VALUES ('user1098@example.com', 'user 1098');
+INSERT INTO users (email, name) VALUES ('user1099@example.com', 'user 1099');
+INSERT INTO guests (email, name) VALUES ('user1099@example.com', 'user 1099');
+INSERT INTO users (email, name) VALUES ('user1100@example.com', 'user 1100');
+INSERT INTO guests (email, name) VALUES ('user1100@example.com', 'user 1100');
+INSERT INTO users (email, name) VALUES ('user1101@example.com', 'user 1101');
+INSERT INTO guests (email, name) VALUES ('user1101@example.com', 'user 1101');
+INSERT INTO users (email, name) VALUES ('user1102@example.com', 'user 1102');
+INSERT INTO guests (email, name) VALUES ('user1102@example.com', 'user 1102');
+INSERT INTO users (email, name) VALUES ('user1103@example.com', 'user 1103');
+INSERT INTO guests (email, name) VALUES ('user1103@example.com', 'user 1103');
+INSERT INTO users (email, name) VALUES ('user1104@example.com', 'user 1104');
+INSERT INTO guests (email, name) VALUES ('user1104@example.com', 'user 1104');
+INSERT INTO users (email, name) VALUES ('user1105@example.com', 'user 1105');
+INSERT INTO guests (email, name) VALUES ('user1105@example.com', 'user 1105');
+INSERT INTO users (email, name) VALUES ('user1106@example.com', 'user 1106');
+INSERT INTO guests (email, name) VALUES ('user1106@example.com', 'user 1106');
+INSERT INTO users (email, name) VALUES ('user1107@example.com', 'user 1107');
+INSERT INTO guests (email, name) VALUES ('user1107@example.com', 'user 1107');
+INSERT INTO users (email, name) VALUES ('user1108@example.com', 'user 1108');
+INSERT INTO guests (email, name) VALUES ('user1108@example.com', 'user 1108');
+INSERT INTO users (email, name) VALUES ('user1109@example.com', 'user 1109');
+INSERT INTO guests (email, name) VALUES ('user1109@example.com', 'user 1109');
+INSERT INTO users (email, name) VALUES ('user1110@example.com', 'user 1110');
+INSERT INTO guests (email, name) VALUES ('user1110@example.com', 'user 1110');
+INSERT INTO users (email, name) VALUES ('user1111@example.com', 'user 1111');
+INSERT INTO guests (email, name) VALUES ('user1111@example.com', 'user 1111');
+INSERT INTO users (email, name) VALUES ('user1112@example.com', 'user 1112');
+INSERT INTO guests (email, name) VALUES ('user1112@example.com', 'user 1112');
+INSERT INTO users (email, name) VALUES ('user1113@example.com', 'user 1113');
+INSERT INTO guests (email, name) VALUES ('user1113@example.com', 'user 1113');
+INSERT INTO users (email, name) VALUES ('user1114@example.com', 'user 1114');
+INSERT INTO guests (email, name) VALUES ('user1114@example.com', 'user 1114');
+INSERT INTO users (email, name) VALUES ('user1115@example.com', 'user 1115');
+INSERT INTO guests (email, name) VALUES ('user1115@example.com', 'user 1115');
+INSERT INTO users (email, name) VALUES ('user1116@example.com', 'user 1116');
+INSERT INTO guests (email, name) VALUES ('user1116@example.com', 'user 1116');
+INSERT INTO users (email, name) VALUES ('user1117@example.com', 'user 1117');
+INSERT INTO guests (email, name) VALUES ('user1117@example.com', 'user 1117');
+INSERT INTO users (email, name) VALUES ('user1118@example.com', 'user 1118');
+INSERT INTO guests (email, name) VALUES ('user1118@example.com', 'user 1118');
+INSERT INTO users (email, name) VALUES ('user1119@example.com', 'user 1119');
+INSERT INTO guests (email, name) VALUES ('user1119@example.com', 'user 1119');
+INSERT INTO users (email, name) VALUES ('user1120@example.com', 'user 1120');
+INSERT INTO guests (email, name) VALUES ('user1120@example.com', 'user 1120');
+INSERT INTO users (email, name) VALUES ('user1121@example.com', 'user 1121');
+INSERT INTO guests (email, name) VALUES ('user1121@example.com', 'user 1121');
+INSERT INTO users (email, name) VALUES ('user1122@example.com', 'user 1122');
+INSERT INTO guests (email, name) VALUES ('user1122@example.com', 'user 1122');
+INSERT INTO users (email, name) VALUES ('user1123@example.com', 'user 1123');
+INSERT INTO guests (email, name) VALUES ('user1123@example.com', 'user 1123');
+INSERT INTO users (email, name) VALUES ('user1124@example.com', 'user 1124');
+INSERT INTO guests (email, name) VALUES ('user1124@example.com', 'user 1124');
+INSERT INTO users (email, name) VALUES ('user1125@example.com', 'user 1125');
+INSERT INTO guests (email, name) VALUES ('user1125@example.com', 'user 1125');
+INSERT INTO users (email, name) VALUES ('user1126@example.com', 'user 1126');
+INSERT INTO guests (email, name) VALUES ('user1126@example.com', 'user 1126');
+INSERT INTO users (email, name) VALUES ('user1127@example.com', 'user 1127');
+INSERT INTO guests (email, name) VALUES ('user1127@example.com', 'user 1127');
+INSERT INTO users (email, name) VALUES ('user1128@example.com', 'user 1128');
+INSERT INTO guests (email, name) VALUES ('user1128@example.com', 'user 1128');
+INSERT INTO users (email, name) VALUES ('user1129@example.com', 'user 1129');
+INSERT INTO guests (email, name) VALUES ('user1129@example.com', 'user 1129');
+INSERT INTO users (email, name) VALUES ('user1130@example.com', 'user 1130');
+INSERT INTO guests (email, name) VALUES ('user1130@example.com', 'user 1130');
+INSERT INTO users (email, name) VALUES ('user1131@example.com', 'user 1131');
+INSERT INTO guests (email, name) VALUES ('user1131@example.com', 'user 1131');
+INSERT INTO users (email, name) VALUES ('user1132@example.com', 'user 1132');
+INSERT INTO guests (email, name) VALUES ('user1132@example.com', 'user 1132');
+INSERT INTO users (email, name) VALUES ('user1133@example.com', 'user 1133');
+INSERT INTO guests (email, name) VALUES ('user1133@example.com', 'user 1133');
+INSERT INTO users (email, name) VALUES ('user1134@example.com', 'user 1134');
+INSERT INTO guests (email, name) VALUES ('user1134@example.com', 'user 1134');
+INSERT INTO users (email, name) VALUES ('user1135@example.com', 'user 1135');
+INSERT INTO guests (email, name) VALUES ('user1135@example.com', 'user 1135');
+INSERT INTO users (email, name) VALUES ('user1136@example.com', 'user 1136');
+INSERT INTO guests (email, name) VALUES ('user1136@example.com', 'user 1136');
+INSERT INTO users (email, name) VALUES ('user1137@example.com', 'user 1137');
+INSERT INTO guests (email, name) VALUES ('user1137@example.com', 'user 1137');
+INSERT INTO users (email, name) VALUES ('user1138@example.com', 'user 1138');
+INSERT INTO guests (email, name) VALUES ('user1138@example.com', 'user 1138');
+INSERT INTO users (email, name) VALUES ('user1139@example.com', 'user 1139');
+INSERT INTO guests (email, name) VALUES ('user1139@example.com', 'user 1139');
+INSERT INTO users (email, name) VALUES ('user1140@example.com', 'user 1140');
+INSERT INTO guests (email, name) VALUES ('user1140@example.com', 'user 1140');
+INSERT INTO users (email, name) VALUES ('user1141@example.com', 'user 1141');
+INSERT INTO guests (email, name) VALUES ('user1141@example.com', 'user 1141');
+INSERT INTO users (email, name) VALUES ('user1142@example.com', 'user 1142');
+INSERT INTO guests (email, name) VALUES ('user1142@example.com', 'user 1142');
+INSERT INTO users (email, name) VALUES ('user1143@example.com', 'user 1143');
+INSERT INTO guests (email, name) VALUES ('user1143@example.com', 'user 1143');
+INSERT INTO users (email, name) VALUES ('user1144@example.com', 'user 1144');
+INSERT INTO guests (email, name) VALUES ('user1144@example.com', 'user 1144');
+INSERT INTO users (email, name) VALUES ('user1145@example.com', 'user 1145');
+INSERT INTO guests (email, name) VALUES ('user1145@example.com', 'user 1145');
+INSERT INTO users (email, name) VALUES ('user1146@example.com', 'user 1146');
+INSERT INTO guests (email, name) VALUES ('user1146@example.com', 'user 1146');
+INSERT INTO users (email, name) VALUES ('user1147@example.com', 'user 1147');
+INSERT INTO guests (email, name) VALUES ('user1147@example.com', 'user 1147');
+INSERT INTO users (email, name) VALUES ('user1148@example.com', 'user 1148');
+INSERT INTO guests (email, name) VALUES ('user1148@example.com', 'user 1148');
+INSERT INTO users (email, name) VALUES ('user1149@example.com', 'user 1149');
+INSERT INTO guests (email, name) VALUES ('user1149@example.com', 'user 1149');
+INSERT INTO users (email, name) VALUES ('user1150@example.com', 'user 1150');
+INSERT INTO guests (email, name) VALUES ('user1150@example.com', 'user 1150');
+INSERT INTO users (email, name) VALUES ('user1151@example.com', 'user 1151');
+INSERT INTO guests (email, name) VALUES ('user1151@example.com', 'user 1151');
+INSERT INTO users (email, name) VALUES ('user1152@example.com', 'user 1152');
+INSERT INTO guests (email, name) VALUES ('user1152@example.com', 'user 1152');
+INSERT INTO users (email, name) VALUES ('user1153@example.com', 'user 1153');
+INSERT INTO guests (email, name) VALUES ('user1153@example.com', 'user 1153');
+INSERT INTO users (email, name) VALUES ('user1154@example.com', 'user 1154');
+INSERT INTO guests (email, name) VALUES ('user1154@example.com', 'user 1154');
+INSERT INTO users (email, name) VALUES ('user1155@example.com', 'user 1155');
+INSERT INTO guests (email, name) VALUES ('user1155@example.com', 'user 1155');
+INSERT INTO users (email, name) VALUES ('user1156@example.com', 'user 1156');
+INSERT INTO guests (email, name) VALUES ('user1156@example.com', 'user 1156');
+INSERT INTO users (email, name) VALUES ('user1157@example.com', 'user 1157');
+INSERT INTO guests (email, name) VALUES ('user1157@example.com', 'user 1157');
+INSERT INTO users (email, name) VALUES ('user1158@example.com', 'user 1158');
+INSERT INTO guests (email, name) VALUES ('user1158@example.com', 'user 1158');
+INSERT INTO users (email, name) VALUES ('user1159@example.com', 'user 1159');
+INSERT INTO guests (email, name) VALUES ('user1159@example.com', 'user 1159');
+INSERT INTO users (email, name) VALUES ('user1160@example.com', 'user 1160');
+INSERT INTO guests (email, name) VALUES ('user1160@example.com', 'user 1160');
+INSERT INTO users (email, name) VALUES ('user1161@example.com', 'user 1161');
+INSERT INTO guests (email, name) VALUES ('user1161@example.com', 'user 1161');
+INSERT INTO users (email, name) VALUES ('user1162@example.com', 'user 1162');
+INSERT INTO guests (email, name) VALUES ('user1162@example.com', 'user 1162');
+INSERT INTO users (email, name) VALUES ('user1163@example.com', 'user 1163');
+INSERT INTO guests (email, name) VALUES ('user1163@example.com', 'user 1163');
+INSERT INTO users (email, name) VALUES ('user1164@example.com', 'user 1164');
+INSERT INTO guests (email, name) VALUES ('user1164@example.com', 'user 1164');
+INSERT INTO users (email, name) VALUES ('user1165@example.com', 'user 1165');
+INSERT INTO guests (email, name) VALUES ('user1165@example.com', 'user 1165');
+INSERT INTO users (email, name) VALUES ('user1166@example.com', 'user 1166');
+INSERT INTO guests (email, name) VALUES ('user1166@example.com', 'user 1166');
+INSERT INTO users (email, name) VALUES ('user1167@example.com', 'user 1167');
+INSERT INTO guests (email, name) VALUES ('user1167@example.com', 'user 1167');
+INSERT INTO users (email, name) VALUES ('user1168@example.com', 'user 1168');
+INSERT INTO guests (email, name) VALUES ('user1168@example.com', 'user 1168');
+INSERT INTO users (email, name) VALUES ('user1169@example.com', 'user 1169');
+INSERT INTO guests (email, name) VALUES ('user1169@example.com', 'user 1169');
+INSERT INTO users (email, name) VALUES ('user1170@example.com', 'user 1170');
+INSERT INTO guests (email, name) VALUES ('user1170@example.com', 'user 1170');
+INSERT INTO users (email, name) VALUES ('user1171@example.com', 'user 1171');
+INSERT INTO guests (email, name) VALUES ('user1171@example.com', 'user 1171');
+INSERT INTO users (email, name) VALUES ('user1172@example.com', 'user 1172');
+INSERT INTO guests (email, name) VALUES ('user1172@example.com', 'user 1172');
+INSERT INTO users (email, name) VALUES ('user1173@example.com', 'user 1173');
+INSERT INTO guests (email, name) VALUES ('user1173@example.com', 'user 1173');
+INSERT INTO users (email, name) VALUES ('user1174@example.com', 'user 1174');
+INSERT INTO guests (email, name) VALUES ('user1174@example.com', 'user 1174');
+INSERT INTO users (email, name) VALUES ('user1175@example.com', 'user 1175');
+INSERT INTO guests (email, name) VALUES ('user1175@example.com', 'user 1175');
+INSERT INTO users (email, name) VALUES ('user1176@example.com', 'user 1176');
+INSERT INTO guests (email, name) VALUES ('user1176@example.com', 'user 1176');
+INSERT INTO users (email, name) VALUES ('user1177@example.com', 'user 1177');
+INSERT INTO guests (email, name) VALUES ('user1177@example.com', 'user 1177');
+INSERT INTO users (email, name) VALUES ('user1178@example.com', 'user 1178');
+INSERT INTO guests (email, name) VALUES ('user1178@example.com', 'user 1178');
+INSERT INTO users (email, name) VALUES ('user1179@example.com', 'user 1179');
+INSERT INTO guests (email, name) VALUES ('user1179@example.com', 'user 1179');
+INSERT INTO users (email, name) VALUES ('user1180@example.com', 'user 1180');
+INSERT INTO guests (email, name) VALUES ('user1180@example.com', 'user 1180');
+INSERT INTO users (email, name) VALUES ('user1181@example.com', 'user 1181');
+INSERT INTO guests (email, name) VALUES ('user1181@example.com', 'user 1181');
+INSERT INTO users (email, name) VALUES ('user1182@example.com', 'user 1182');
+INSERT INTO guests (email, name) VALUES ('user1182@example.com', 'user 1182');
+INSERT INTO users (email, name) VALUES ('user1183@example.com', 'user 1183');
+INSERT INTO guests (email, name) VALUES ('user1183@example.com', 'user 1183');
+INSERT INTO users (email, name) VALUES ('user1184@example.com', 'user 1184');
+INSERT INTO guests (email, name) VALUES ('user1184@example.com', 'user 1184');
+INSERT INTO users (email, name) VALUES ('user1185@example.com', 'user 1185');
+INSERT INTO guests (email, name) VALUES ('user1185@example.com', 'user 1185');
+INSERT INTO users (email, name) VALUES ('user1186@example.com', 'user 1186');
+INSERT INTO guests (email, name) VALUES ('user1186@example.com', 'user 1186');
+INSERT INTO users (email, name) VALUES ('user1187@example.com', 'user 1187');
+INSERT INTO guests (email, name) VALUES ('user1187@example.com', 'user 1187');
+INSERT INTO users (email, name) VALUES ('user1188@example.com', 'user 1188');
+INSERT INTO guests (email, name) VALUES ('user1188@example.com', 'user 1188');
+INSERT INTO users (email, name) VALUES ('user1189@example.com', 'user 1189');
+INSERT INTO guests (email, name) VALUES ('user1189@example.com', 'user 1189');
+INSERT INTO users (email, name) VALUES ('user1190@example.com', 'user 1190');
+INSERT INTO guests (email, name) VALUES ('user1190@example.com', 'user 1190');
+INSERT INTO users (email, name) VALUES ('user1191@example.com', 'user 1191');
+INSERT INTO guests (email, name) VALUES ('user1191@example.com', 'user 1191');
+INSERT INTO users (email, name) VALUES ('user1192@example.com', 'user 1192');
+INSERT INTO guests (email, name) VALUES ('user1192@example.com', 'user 1192');
+INSERT INTO users (email, name) VALUES ('user1193@example.com', 'user 1193');
+INSERT INTO guests (email, name) VALUES ('user1193@example.com', 'user 1193');
+INSERT INTO users (email, name) VALUES ('user1194@example.com', 'user 1194');
+INSERT INTO guests (email, name) VALUES ('user1194@example.com', 'user 1194');
+INSERT INTO users (email, name) VALUES ('user1195@example.com', 'user 1195');
+INSERT INTO guests (email, name) VALUES ('user1195@example.com', 'user 1195');
+INSERT INTO users (email, name) VALUES ('user1196@example.com', 'user 1196');
+INSERT INTO guests (email, name) VALUES ('user1196@example.com', 'user 1196');
+INSERT INTO users (email, name) VALUES ('user1197@example.com', 'user 1197');
+INSERT INTO guests (email, name) VALUES ('user1197@example.com', 'user 1197');
+INSERT INTO users (email, name) VALUES ('user1198@example.com', 'user 1198');
+INSERT INTO guests (email, name) VALUES ('user1198@example.com', 'user 1198');
+INSERT INTO users (email, name) VALUES ('user1199@example.com', 'user 1199');
+INSERT INTO guests (email, name) VALUES ('user1199@example.com', 'user 1199');
+INSERT INTO users (email, name) VALUES ('user1200@example.com', 'user 1200');
+INSERT INTO guests (email, name) VALUES ('user1200@example.com', 'user 1200');
+INSERT INTO users (email, name) VALUES ('user1201@example.com', 'user 1201');
+INSERT INTO guests (email, name) VALUES ('user1201@example.com', 'user 1201');
+INSERT INTO users (email, name) VALUES ('user1202@example.com', 'user 1202');
+INSERT INTO guests (email, name) VALUES ('user1202@example.com', 'user 1202');
+INSERT INTO users (email, name) VALUES ('user1203@example.com', 'user 1203');
+INSERT INTO guests (email, name) VALUES ('user1203@example.com', 'user 1203');
+INSERT INTO users (email, name) VALUES ('user1204@example.com', 'user 1204');
+INSERT INTO guests (email, name) VALUES ('user1204@example.com', 'user 1204');
+INSERT INTO users (email, name) VALUES ('user1205@example.com', 'user 1205');
+INSERT INTO guests (email, name) VALUES ('user1205@example.com', 'user 1205');
+INSERT INTO users (email, name) VALUES ('user1206@example.com', 'user 1206');
+INSERT INTO guests (email, name) VALUES ('user1206@example.com', 'user 1206');
+INSERT INTO users (email, name) VALUES ('user1207@example.com', 'user 1207');
+INSERT INTO guests (email, name) VALUES ('user1207@example.com', 'user 1207');
+INSERT INTO users (email, name) VALUES ('user1208@example.com', 'user 1208');
+INSERT INTO guests (email, name) VALUES ('user1208@example.com', 'user 1208');
+INSERT INTO users (email, name) VALUES ('user1209@example.com', 'user 1209');
+INSERT INTO guests (email, name) VALUES ('user1209@example.com', 'user 1209');
+INSERT INTO users (email, name) VALUES ('user1210@example.com', 'user 1210');
+INSERT INTO guests (email, name) VALUES ('user1210@example.com', 'user 1210');
+INSERT INTO users (email, name) VALUES ('user1211@example.com', 'user 1211');
+INSERT INTO guests (email, name) VALUES ('user1211@example.com', 'user 1211');
+INSERT INTO users (email, name) VALUES ('user1212@example.com', 'user 1212');
+INSERT INTO guests (email, name) VALUES ('user1212@example.com', 'user 1212');
+INSERT INTO users (email, name) VALUES ('user1213@example.com', 'user 1213');
+INSERT INTO guests (email, name) VALUES ('user1213@example.com', 'user 1213');
+INSERT INTO users (email, name) VALUES ('user1214@example.com', 'user 1214');
+INSERT INTO guests (email, name) VALUES ('user1214@example.com', 'user 1214');
+INSERT INTO users (email, name) VALUES ('user1215@example.com', 'user 1215');
+INSERT INTO guests (email, name) VALUES ('user1215@example.com', 'user 1215');
+INSERT INTO users (email, name) VALUES ('user1216@example.com', 'user 1216');
+INSERT INTO guests (email, name) VALUES ('user1216@example.com', 'user 1216');
+INSERT INTO users (email, name) VALUES ('user1217@example.com', 'user 1217');
+INSERT INTO guests (email, name) VALUES ('user1217@example.com', 'user 1217');
+INSERT INTO users (email, name) VALUES ('user1218@example.com', 'user 1218');
+INSERT INTO guests (email, name) VALUES ('user1218@example.com', 'user 1218');
+INSERT INTO users (email, name) VALUES ('user1219@example.com', 'user 1219');
+INSERT INTO guests (email, name) VALUES ('user1219@example.com', 'user 1219');
+INSERT INTO users (email, name) VALUES ('user1220@example.com', 'user 1220');
+INSERT INTO guests (email, name) VALUES ('user1220@example.com', 'user 1220');
+INSERT INTO users (email, name) VALUES ('user1221@example.com', 'user 1221');
+INSERT INTO guests (email, name) VALUES ('user1221@example.com', 'user 1221');
+INSERT INTO users (email, name) VALUES ('user1222@example.com', 'user 1222');
+INSERT INTO guests (email, name) VALUES ('user1222@example.com', 'user 1222');
+INSERT INTO users (email, name) VALUES ('user1223@example.com', 'user 1223');
+INSERT INTO guests (email, name) VALUES ('user1223@example.com', 'user 1223');
+INSERT INTO users (email, name) VALUES ('user1224@example.com', 'user 1224');
+INSERT INTO guests (email, name) VALUES ('user1224@example.com', 'user 1224');
+INSERT INTO users (email, name) VALUES ('user1225@example.com', 'user 1225');
+INSERT INTO guests (email, name) VALUES ('user1225@example.com', 'user 1225');
+INSERT INTO users (email, name) VALUES ('user1226@example.com', 'user 1226');
+INSERT INTO guests (email, name) VALUES ('user1226@example.com', 'user 1226');
+INSERT INTO users (email, name) VALUES ('user1227@example.com', 'user 1227');
+INSERT INTO guests (email, name) VALUES ('user1227@example.com', 'user 1227');
+INSERT INTO users (email, name) VALUES ('user1228@example.com', 'user 1228');
+INSERT INTO guests (email, name) VALUES ('user1228@example.com', 'user 1228');
+INSERT INTO users (email, name) VALUES ('user1229@example.com', 'user 1229');
+INSERT INTO guests (email, name) VALUES ('user1229@example.com', 'user 1229');
+INSERT INTO users (email, name) VALUES ('user1230@example.com', 'user 1230');
+INSERT INTO guests (email, name) VALUES ('user1230@example.com', 'user 1230');
+INSERT INTO users (email, name) VALUES ('user1231@example.com', 'user 1231');
+INSERT INTO guests (email, name) VALUES ('user1231@example.com', 'user 1231');
+INSERT INTO users (email, name) VALUES ('user1232@example.com', 'user 1232');
+INSERT INTO guests (email, name) VALUES ('user1232@example.com', 'user 1232');
+INSERT INTO users (email, name) VALUES ('user1233@example.com', 'user 1233');
+INSERT INTO guests (email, name) VALUES ('user1233@example.com', 'user 1233');
+INSERT INTO users (email, name) VALUES ('user1234@example.com', 'user 1234');
+INSERT INTO guests (email, name) VALUES ('user1234@example.com', 'user 1234');
+INSERT INTO users (email, name) VALUES ('user1235@example.com', 'user 1235');
+INSERT INTO guests (email, name) VALUES ('user1235@example.com', 'user 1235');
+INSERT INTO users (email, name) VALUES ('user1236@example.com', 'user 1236');
+INSERT INTO guests (email, name) VALUES ('user1236@example.com', 'user 1236');
+INSERT INTO users (email, name) VALUES ('user1237@example.com', 'user 1237');
+INSERT INTO guests (email, name) VALUES ('user1237@example.com', 'user 1237');
+INSERT INTO users (email, name) VALUES ('user1238@example.com', 'user 1238');
+INSERT INTO guests (email, name) VALUES ('user1238@example.com', 'user 1238');
+INSERT INTO users (email, name) VALUES ('user1239@example.com', 'user 1239');
+INSERT INTO guests (email, name) VALUES ('user1239@example.com', 'user 1239');
+INSERT INTO users (email, name) VALUES ('user1240@example.com', 'user 1240');
+INSERT INTO guests (email, name) VALUES ('user1240@example.com', 'user 1240');
+INSERT INTO users (email, name) VALUES ('user1241@example.com', 'user 1241');
+INSERT INTO guests (email, name) VALUES ('user1241@example.com', 'user 1241');
+INSERT INTO users (email, name) VALUES ('user1242@example.com', 'user 1242');
+INSERT INTO guests (email, name) VALUES ('user1242@example.com', 'user 1242');
+INSERT INTO users (email, name) VALUES ('user1243@example.com', 'user 1243');
+INSERT INTO guests (email, name) VALUES ('user1243@example.com', 'user 1243');
+INSERT INTO users (email, name) VALUES ('user1244@example.com', 'user 1244');
+INSERT INTO guests (email, name) VALUES ('user1244@example.com', 'user 1244');
+INSERT INTO users (email, name) VALUES ('user1245@example.com', 'user 1245');
+INSERT INTO guests (email, name) VALUES ('user1245@example.com', 'user 1245');
+INSERT INTO users (email, name) VALUES ('user1246@example.com', 'user 1246');
+INSERT INTO guests (email, name) VALUES ('user1246@example.com', 'user 1246');
+INSERT INTO users (email, name) VALUES ('user1247@example.com', 'user 1247');
+INSERT INTO guests (email, name) VALUES ('user1247@example.com', 'user 1247');
+INSERT INTO users (email, name) VALUES ('user1248@example.com', 'user 1248');
+INSERT INTO guests (email, name) VALUES ('user1248@example.com', 'user 1248');
+INSERT INTO users (email, name) VALUES ('user1249@example.com', 'user 1249');
+INSERT INTO guests (email, name) VALUES ('user1249@example.com', 'user 1249');
+INSERT INTO users (email, name) VALUES ('user1250@example.com', 'user 1250');
+INSERT INTO guests (email, name) VALUES ('user1250@example.com', 'user 1250');
+INSERT INTO users (email, name) VALUES ('user1251@example.com', 'user 1251');
+INSERT INTO guests (email, name) VALUES ('user1251@example.com', 'user 1251');
+INSERT INTO users (email, name) VALUES ('user1252@example.com', 'user 1252');
+INSERT INTO guests (email, name) VALUES ('user1252@example.com', 'user 1252');
+INSERT INTO users (email, name) VALUES ('user1253@example.com', 'user 1253');
+INSERT INTO guests (email, name) VALUES ('user1253@example.com', 'user 1253');
+INSERT INTO users (email, name) VALUES ('user1254@example.com', 'user 1254');
+INSERT INTO guests (email, name) VALUES ('user1254@example.com', 'user 1254');
+INSERT INTO users (email, name) VALUES ('user1255@example.com', 'user 1255');
+INSERT INTO guests (email, name) VALUES ('user1255@example.com', 'user 1255');
+INSERT INTO users (email, name) VALUES ('user1256@example.com', 'user 1256');
+INSERT INTO guests (email, name) VALUES ('user1256@example.com', 'user 1256');
+INSERT INTO users (email, name) VALUES ('user1257@example.com', 'user 1257');
+INSERT INTO guests (email, name) VALUES ('user1257@example.com', 'user 1257');
+INSERT INTO users (email, name) VALUES ('user1258@example.com', 'user 1258');
+INSERT INTO guests (email, name) VALUES ('user1258@example.com', 'user 1258');
+INSERT INTO users (email, name) VALUES ('user1259@example.com', 'user 1259');
+INSERT INTO guests (email, name) VALUES ('user1259@example.com', 'user 1259');
+INSERT INTO users (email, name) VALUES ('user1260@example.com', 'user 1260');
+INSERT INTO guests (email, name) VALUES ('user1260@example.com', 'user 1260');
+INSERT INTO users (email, name) VALUES ('user1261@example.com', 'user 1261');
+INSERT INTO guests (email, name) VALUES ('user1261@example.com', 'user 1261');
+INSERT INTO users (email, name) VALUES ('user1262@example.com', 'user 1262');
+INSERT INTO guests (email, name) VALUES ('user1262@example.com', 'user 1262');
+INSERT INTO users (email, name) VALUES ('user1263@example.com', 'user 1263');
+INSERT INTO guests (email, name) VALUES ('user1263@example.com', 'user 1263');
+INSERT INTO users (email, name) VALUES ('user1264@example.com', 'user 1264');
+INSERT INTO guests (email, name) VALUES ('user1264@example.com', 'user 1264');
+INSERT INTO users (email, name) VALUES ('user1265@example.com', 'user 1265');
+INSERT INTO guests (email, name) VALUES ('user1265@example.com', 'user 1265');
+INSERT INTO users (email, name) VALUES ('user1266@example.com', 'user 1266');
+INSERT INTO guests (email, name) VALUES ('user1266@example.com', 'user 1266');
+INSERT INTO users (email, name) VALUES ('user1267@example.com', 'user 1267');
+INSERT INTO guests (email, name) VALUES ('user1267@example.com', 'user 1267');
+INSERT INTO users (email, name) VALUES ('user1268@example.com', 'user 1268');
+INSERT INTO guests (email, name) VALUES ('user1268@example.com', 'user 1268');
+INSERT INTO users (email, name) VALUES ('user1269@example.com', 'user 1269');
+INSERT INTO guests (email, name) VALUES ('user1269@example.com', 'user 1269');
+INSERT INTO users (email, name) VALUES ('user1270@example.com', 'user 1270');
+INSERT INTO guests (email, name) VALUES ('user1270@example.com', 'user 1270');
+INSERT INTO users (email, name) VALUES ('user1271@example.com', 'user 1271');
+INSERT INTO guests (email, name) VALUES ('user1271@example.com', 'user 1271');
+INSERT INTO users (email, name) VALUES ('user1272@example.com', 'user 1272');
+INSERT INTO guests (email, name) VALUES ('user1272@example.com', 'user 1272');
+INSERT INTO users (email, name) VALUES ('user1273@example.com', 'user 1273');
+INSERT INTO guests (email, name) VALUES ('user1273@example.com', 'user 1273');
+INSERT INTO users (email, name) VALUES ('user1274@example.com', 'user 1274');
+INSERT INTO guests (email, name) VALUES ('user1274@example.com', 'user 1274');
+INSERT INTO users (email, name) VALUES ('user1275@example.com', 'user 1275');
+INSERT INTO guests (email, name) VALUES ('user1275@example.com', 'user 1275');
+INSERT INTO users (email, name) VALUES ('user1276@example.com', 'user 1276');
+INSERT INTO guests (email, name) VALUES ('user1276@example.com', 'user 1276');
+INSERT INTO users (email, name) VALUES ('user1277@example.com', 'user 1277');
+INSERT INTO guests (email, name) VALUES ('user1277@example.com', 'user 1277');
+INSERT INTO users (email, name) VALUES ('user1278@example.com', 'user 1278');
+INSERT INTO guests (email, name) VALUES ('user1278@example.com', 'user 1278');
+INSERT INTO users (email, name) VALUES ('user1279@example.com', 'user 1279');
+INSERT INTO guests (email, name) VALUES ('user1279@example.com', 'user 1279');
+INSERT INTO users (email, name) VALUES ('user1280@example.com', 'user 1280');
+INSERT INTO guests (email, name) VALUES ('user1280@example.com', 'user 1280');
+INSERT INTO users (email, name) VALUES ('user1281@example.com', 'user 1281');
+INSERT INTO guests (email, name) VALUES ('user1281@example.com', 'user 1281');
+INSERT INTO users (email, name) VALUES ('user1282@example.com', 'user 1282');
+INSERT INTO guests (email, name) VALUES ('user1282@example.com', 'user 1282');
+INSERT INTO users (email, name) VALUES ('user1283@example.com', 'user 1283');
+INSERT INTO guests (email, name) VALUES ('user1283@example.com', 'user 1283');
+INSERT INTO users (email, name) VALUES ('user1284@example.com', 'user 1284');
+INSERT INTO guests (email, name) VALUES ('user1284@example.com', 'user 1284');
+INSERT INTO users (email, name) VALUES ('user1285@example.com', 'user 1285');
+INSERT INTO guests (email, name) VALUES ('user1285@example.com', 'user 1285');
+INSERT INTO users (email, name) VALUES ('user1286@example.com', 'user 1286');
+INSERT INTO guests (email, name) VALUES ('user1286@example.com', 'user 1286');
+INSERT INTO users (email, name) VALUES ('user1287@example.com', 'user 1287');
+INSERT INTO guests (email, name) VALUES ('user1287@example.com', 'user 1287');
+INSERT INTO users (email, name) VALUES ('user1288@example.com', 'user 1288');
+INSERT INTO guests (email, name) VALUES ('user1288@example.com', 'user 1288');
+INSERT INTO users (email, name) VALUES ('user1289@example.com', 'user 1289');
+INSERT INTO guests (email, name) VALUES ('user1289@example.com', 'user 1289');
+INSERT INTO users (email, name) VALUES ('user1290@example.com', 'user 1290');
+INSERT INTO guests (email, name) VALUES ('user1290@example.com', 'user 1290');
+INSERT INTO users (email, name) VALUES ('user1291@example.com', 'user 1291');
+INSERT INTO guests (email, name) VALUES ('user1291@example.com', 'user 1291');
+INSERT INTO users (email, name) VALUES ('user1292@example.com', 'user 1292');
+INSERT INTO guests (email, name) VALUES ('user1292@example.com', 'user 1292');
+INSERT INTO users (email, name) VALUES ('user1293@example.com', 'user 1293');
+INSERT INTO guests (email, name) VALUES ('user1293@example.com', 'user 1293');
+INSERT INTO users (email, name) VALUES ('user1294@example.com', 'user 1294');
+INSERT INTO guests (email, name) VALUES ('user1294@example.com', 'user 1294');
+INSERT INTO users (email, name) VALUES ('user1295@example.com', 'user 1295');
+INSERT INTO guests (email, name) VALUES ('user1295@example.com', 'user 1295');
+INSERT INTO users (email, name) VALUES ('user1296@example.com', 'user 1296');
+INSERT INTO guests (email, name) VALUES ('user1296@example.com', 'user 1296');
+INSERT INTO users (email, name) VALUES ('user1297@example.com', 'user 1297');
+INSERT INTO guests (email, name) VALUES ('user1297@example.com', 'user 1297');
+INSERT INTO users (email, name) VALUES ('user1298@example.com', 'user 1298');
+INSERT INTO guests (email, name) VALUES ('user1298@example.com', 'user 1298');
+INSERT INTO users (email, name) VALUES ('user1299@example.com', 'user 1299');
+INSERT INTO guests (email, name) VALUES ('user1299@example.com', 'user 1299');
+INSERT INTO users (email, name) VALUES ('user1300@example.com', 'user 1300');
+INSERT INTO guests (email, name) VALUES ('user1300@example.com', 'user 1300');
+INSERT INTO users (email, name) VALUES ('user1301@example.com', 'user 1301');
+INSERT INTO guests (email, name) VALUES ('user1301@example.com', 'user 1301');
+INSERT INTO users (email, name) VALUES ('user1302@example.com', 'user 1302');
+INSERT INTO guests (email, name) VALUES ('user1302@example.com', 'user 1302');
+INSERT INTO users (email, name) VALUES ('user1303@example.com', 'user 1303');
+INSERT INTO guests (email, name) VALUES ('user1303@example.com', 'user 1303');
+INSERT INTO users (email, name) VALUES ('user1304@example.com', 'user 1304');
+INSERT INTO guests (email, name) VALUES ('user1304@example.com', 'user 1304');
+INSERT INTO users (email, name) VALUES ('user1305@example.com', 'user 1305');
+INSERT INTO guests (email, name) VALUES ('user1305@example.com', 'user 1305');
+INSERT INTO users (email, name) VALUES ('user1306@example.com', 'user 1306');
+INSERT INTO guests (email, name) VALUES ('user1306@example.com', 'user 1306');
+INSERT INTO users (email, name) VALUES ('user1307@example.com', 'user 1307');
+INSERT INTO guests (email, name) VALUES ('user1307@example.com', 'user 1307');
+INSERT INTO users (email, name) VALUES ('user1308@example.com', 'user 1308');
+INSERT INTO guests (email, name) VALUES ('user1308@example.com', 'user 1308');
+INSERT INTO users (email, name) VALUES ('user1309@example.com', 'user 1309');
+INSERT INTO guests (email, name) VALUES ('user1309@example.com', 'user 1309');
+INSERT INTO users (email, name) VALUES ('user1310@example.com', 'user 1310');
+INSERT INTO guests (email, name) VALUES ('user1310@example.com', 'user 1310');
+INSERT INTO users (email, name) VALUES ('user1311@example.com', 'user 1311');
+INSERT INTO guests (email, name) VALUES ('user1311@example.com', 'user 1311');
+INSERT INTO users (email, name) VALUES ('user1312@example.com', 'user 1312');
+INSERT INTO guests (email, name) VALUES ('user1312@example.com', 'user 1312');
+INSERT INTO users (email, name) VALUES ('user1313@example.com', 'user 1313');
+INSERT INTO guests (email, name) VALUES ('user1313@example.com', 'user 1313');
+INSERT INTO users (email, name) VALUES ('user1314@example.com', 'user 1314');
+INSERT INTO guests (email, name) VALUES ('user1314@example.com', 'user 1314');
+INSERT INTO users (email, name) VALUES ('user1315@example.com', 'user 1315');
+INSERT INTO guests (email, name) VALUES ('user1315@example.com', 'user 1315');
+INSERT INTO users (email, name) VALUES ('user1316@example.com', 'user 1316');
+INSERT INTO guests (email, name) VALUES ('user1316@example.com', 'user 1316');
+INSERT INTO users (email, name) VALUES ('user1317@example.com', 'user 1317');
+INSERT INTO guests (email, name) VALUES ('user1317@example.com', 'user 1317');
+INSERT INTO users (email, name) VALUES ('user1318@example.com', 'user 1318');
+INSERT INTO guests (email, name) VALUES ('user1318@example.com', 'user 1318');
+INSERT INTO users (email, name) VALUES ('user1319@example.com', 'user 1319');
+INSERT INTO guests (email, name) VALUES ('user1319@example.com', 'user 1319');
+INSERT INTO users (email, name) VALUES ('user1320@example.com', 'user 1320');
+INSERT INTO guests (email, name) VALUES ('user1320@example.com', 'user 1320');
+INSERT INTO users (email, name) VALUES ('user1321@example.com', 'user 1321');
+INSERT INTO guests (email, name) VALUES ('user1321@example.com', 'user 1321');
+INSERT INTO users (email, name) VALUES ('user1322@example.com', 'user 1322');
+INSERT INTO guests (email, name) VALUES ('user1322@example.com', 'user 1322');
+INSERT INTO users (email, name) VALUES ('user1323@example.com', 'user 1323');
+INSERT INTO guests (email, name) VALUES ('user1323@example.com', 'user 1323');
+INSERT INTO users (email, name) VALUES ('user1324@example.com', 'user 1324');
+INSERT INTO guests (email, name) VALUES ('user1324@example.com', 'user 1324');
+INSERT INTO users (email, name) VALUES ('user1325@example.com', 'user 1325');
+INSERT INTO guests (email, name) VALUES ('user1325@example.com', 'user 1325');
+INSERT INTO users (email, name) VALUES ('user1326@example.com', 'user 1326');
+INSERT INTO guests (email, name) VALUES ('user1326@example.com', 'user 1326');
+INSERT INTO users (email, name) VALUES ('user1327@example.com', 'user 1327');
+INSERT INTO guests (email, name) VALUES ('user1327@example.com', 'user 1327');
+INSERT INTO users (email, name) VALUES ('user1328@example.com', 'user 1328');
+INSERT INTO guests (email, name) VALUES ('user1328@example.com', 'user 1328');
+INSERT INTO users (email, name) VALUES ('user1329@example.com', 'user 1329');
+INSERT INTO guests (email, name) VALUES ('user1329@example.com', 'user 1329');
+INSERT INTO users (email, name) VALUES ('user1330@example.com', 'user 1330');
+INSERT INTO guests (email, name) VALUES ('user1330@example.com', 'user 1330');
+INSERT INTO users (email, name) VALUES ('user1331@example.com', 'user 1331');
+INSERT INTO guests (email, name) VALUES ('user1331@example.com', 'user 1331');
+INSERT INTO users (email, name) VALUES ('user1332@example.com', 'user 1332');
+INSERT INTO guests (email, name) VALUES ('user1332@example.com', 'user 1332');
+INSERT INTO users (email, name) VALUES ('user1333@example.com', 'user 1333');
+INSERT INTO guests (email, name) VALUES ('user1333@example.com', 'user 1333');
+INSERT INTO users (email, name) VALUES ('user1334@example.com', 'user 1334');
+INSERT INTO guests (email, name) VALUES ('user1334@example.com', 'user 1334');
+INSERT INTO users (email, name) VALUES ('user1335@example.com', 'user 1335');
+INSERT INTO guests (email, name) VALUES ('user1335@example.com', 'user 1335');
+INSERT INTO users (email, name) VALUES ('user1336@example.com', 'user 1336');
+INSERT INTO guests (email, name) VALUES ('user1336@example.com', 'user 1336');
+INSERT INTO users (email, name) VALUES ('user1337@example.com', 'user 1337');
+INSERT INTO guests (email, name) VALUES ('user1337@example.com', 'user 1337');
+INSERT INTO users (email, name) VALUES ('user1338@example.com', 'user 1338');
+INSERT INTO guests (email, name) VALUES ('user1338@example.com', 'user 1338');
+INSERT INTO users (email, name) VALUES ('user1339@example.com', 'user 1339');
+INSERT INTO guests (email, name) VALUES ('user1339@example.com', 'user 1339');
+INSERT INTO users (email, name) VALUES ('user1340@example.com', 'user 1340');
+INSERT INTO guests (email, name) VALUES ('user1340@example.com', 'user 1340');
+INSERT INTO users (email, name) VALUES ('user1341@example.com', 'user 1341');
+INSERT INTO guests (email, name) VALUES ('user1341@example.com', 'user 1341');
+INSERT INTO users (email, name) VALUES ('user1342@example.com', 'user 1342');
+INSERT INTO guests (email, name) VALUES ('user1342@example.com', 'user 1342');
+INSERT INTO users (email, name) VALUES ('user1343@example.com', 'user 1343');
+INSERT INTO guests (email, name) VALUES ('user1343@example.com', 'user 1343');
+INSERT INTO users (email, name) VALUES ('user1344@example.com', 'user 1344');
+INSERT INTO guests (email, name) VALUES ('user1344@example.com', 'user 1344');
+INSERT INTO users (email, name) VALUES ('user1345@example.com', 'user 1345');
+INSERT INTO guests (email, name) VALUES ('user1345@example.com', 'user 1345');
+INSERT INTO users (email, name) VALUES ('user1346@example.com', 'user 1346');
+INSERT INTO guests (email, name) VALUES ('user1346@example.com', 'user 1346');
+INSERT INTO users (email, name) VALUES ('user1347@example.com', 'user 1347');
+INSERT INTO guests (email, name) VALUES ('user1347@example.com', 'user 1347');
+INSERT INTO users (email, name) VALUES ('user1348@example.com', 'user 1348');
+INSERT INTO guests (email, name) VALUES ('user1348@example.com', 'user 1348');
+INSERT INTO users (email, name) VALUES ('user1349@example.com', 'user 1349');
+INSERT INTO guests (email, name) VALUES ('user1349@example.com', 'user 1349');
+INSERT INTO users (email, name) VALUES ('user1350@example.com', 'user 1350');
+INSERT INTO guests (email, name) VALUES ('user1350@example.com', 'user 1350');
+INSERT INTO users (email, name) VALUES ('user1351@example.com', 'user 1351');
+INSERT INTO guests (email, name) VALUES ('user1351@example.com', 'user 1351');
+INSERT INTO users (email, name) VALUES ('user1352@example.com', 'user 1352');
+INSERT INTO guests (email, name) VALUES ('user1352@example.com', 'user 1352');
+INSERT INTO users (email, name) VALUES ('user1353@example.com', 'user 1353');
+INSERT INTO guests (email, name) VALUES ('user1353@example.com', 'user 1353');
+INSERT INTO users (email, name) VALUES ('user1354@example.com', 'user 1354');
+INSERT INTO guests (email, name) VALUES ('user1354@example.com', 'user 1354');
+INSERT INTO users (email, name) VALUES ('user1355@example.com', 'user 1355');
+INSERT INTO guests (email, name) VALUES ('user1355@example.com', 'user 1355');
+INSERT INTO users (email, name) VALUES ('user1356@example.com', 'user 1356');
+INSERT INTO guests (email, name) VALUES ('user1356@example.com', 'user 1356');
+INSERT INTO users (email, name) VALUES ('user1357@example.com', 'user 1357');
+INSERT INTO guests (email, name) VALUES ('user1357@example.com', 'user 1357');
+INSERT INTO users (email, name) VALUES ('user1358@example.com', 'user 1358');
+INSERT INTO guests (email, name) VALUES ('user1358@example.com', 'user 1358');
+INSERT INTO users (email, name) VALUES ('user1359@example.com', 'user 1359');
+INSERT INTO guests (email, name) VALUES ('user1359@example.com', 'user 1359');
+INSERT INTO users (email, name) VALUES ('user1360@example.com', 'user 1360');
+INSERT INTO guests (email, name) VALUES ('user1360@example.com', 'user 1360');
+INSERT INTO users (email, name) VALUES ('user1361@example.com', 'user 1361');
+INSERT INTO guests (email, name) VALUES ('user1361@example.com', 'user 1361');
+INSERT INTO users (email, name) VALUES ('user1362@example.com', 'user 1362');
+INSERT INTO guests (email, name) VALUES ('user1362@example.com', 'user 1362');
+INSERT INTO users (email, name) VALUES ('user1363@example.com', 'user 1363');
+INSERT INTO guests (email, name) VALUES ('user1363@example.com', 'user 1363');
+INSERT INTO users (email, name) VALUES ('user1364@example.com', 'user 1364');
+INSERT INTO guests (email, name) VALUES ('user1364@example.com', 'user 1364');
+INSERT INTO users (email, name) VALUES ('user1365@example.com', 'user 1365');
+INSERT INTO guests (email, name) VALUES ('user1365@example.com', 'user 1365');
+INSERT INTO users (email, name) VALUES ('user1366@example.com', 'user 1366');
+INSERT INTO guests (email, name) VALUES ('user1366@example.com', 'user 1366');
+INSERT INTO users (email, name) VALUES ('user1367@example.com', 'user 1367');
+INSERT INTO guests (email, name) VALUES ('user1367@example.com', 'user 1367');
+INSERT INTO users (email, name) VALUES ('user1368@example.com', 'user 1368');
+INSERT INTO guests (email, name) VALUES ('user1368@example.com', 'user 1368');
+INSERT INTO users (email, name) VALUES ('user1369@example.com', 'user 1369');
+INSERT INTO guests (email, name) VALUES ('user1369@example.com', 'user 1369');
+INSERT INTO users (email, name) VALUES ('user1370@example.com', 'user 1370');
+INSERT INTO guests (email, name) VALUES ('user1370@example.com', 'user 1370');
+INSERT INTO users (email, name) VALUES ('user1371@example.com', 'user 1371');
+INSERT INTO guests (email, name) VALUES ('user1371@example.com', 'user 1371');
+INSERT INTO users (email, name) VALUES ('user1372@example.com', 'user 1372');
+INSERT INTO guests (email, name) VALUES ('user1372@example.com', 'user 1372');
+INSERT INTO users (email, name) VALUES ('user1373@example.com', 'user 1373');
+INSERT INTO guests (email, name) VALUES ('user1373@example.com', 'user 1373');
+INSERT INTO users (email, name) VALUES ('user1374@example.com', 'user 1374');
+INSERT INTO guests (email, name) VALUES ('user1374@example.com', 'user 1374');
+INSERT INTO users (email, name) VALUES ('user1375@example.com', 'user 1375');
+INSERT INTO guests (email, name) VALUES ('user1375@example.com', 'user 1375');
+INSERT INTO users (email, name) VALUES ('user1376@example.com', 'user 1376');
+INSERT INTO guests (email, name) VALUES ('user1376@example.com', 'user 1376');
+INSERT INTO users (email, name) VALUES ('user1377@example.com', 'user 1377');
+INSERT INTO guests (email, name) VALUES ('user1377@example.com', 'user 1377');
+INSERT INTO users (email, name) VALUES ('user1378@example.com', 'user 1378');
+INSERT INTO guests (email, name) VALUES ('user1378@example.com', 'user 1378');
+INSERT INTO users (email, name) VALUES ('user1379@example.com', 'user 1379');
+INSERT INTO guests (email, name) VALUES ('user1379@example.com', 'user 1379');
+INSERT INTO users (email, name) VALUES ('user1380@example.com', 'user 1380');
+INSERT INTO guests (email, name) VALUES ('user1380@example.com', 'user 1380');
+INSERT INTO users (email, name) VALUES ('user1381@example.com', 'user 1381');
+INSERT INTO guests (email, name) VALUES ('user1381@example.com', 'user 1381');
+INSERT INTO users (email, name) VALUES ('user1382@example.com', 'user 1382');
+INSERT INTO guests (email, name) VALUES ('user1382@example.com', 'user 1382');
+INSERT INTO users (email, name) VALUES ('user1383@example.com', 'user 1383');
+INSERT INTO guests (email, name) VALUES ('user1383@example.com', 'user 1383');
+INSERT INTO users (email, name) VALUES ('user1384@example.com', 'user 1384');
+INSERT INTO guests (email, name) VALUES ('user1384@example.com', 'user 1384');
+INSERT INTO users (email, name) VALUES ('user1385@example.com', 'user 1385');
+INSERT INTO guests (email, name) VALUES ('user1385@example.com', 'user 1385');
+INSERT INTO users (email, name) VALUES ('user1386@example.com', 'user 1386');
+INSERT INTO guests (email, name) VALUES ('user1386@example.com', 'user 1386');
+INSERT INTO users (email, name) VALUES ('user1387@example.com', 'user 1387');
+INSERT INTO guests (email, name) VALUES ('user1387@example.com', 'user 1387');
+INSERT INTO users (email, name) VALUES ('user1388@example.com', 'user 1388');
+INSERT INTO guests (email, name) VALUES ('user1388@example.com', 'user 1388');
+INSERT INTO users (email, name) VALUES ('user1389@example.com', 'user 1389');
+INSERT INTO guests (email, name) VALUES ('user1389@example.com', 'user 1389');
+INSERT INTO users (email, name) VALUES ('user1390@example.com', 'user 1390');
+INSERT INTO guests (email, name) VALUES ('user1390@example.com', 'user 1390');
+INSERT INTO users (email, name) VALUES ('user1391@example.com', 'user 1391');
+INSERT INTO guests (email, name) VALUES ('user1391@example.com', 'user 1391');
+INSERT INTO users (email, name) VALUES ('user1392@example.com', 'user 1392');
+INSERT INTO guests (email, name) VALUES ('user1392@example.com', 'user 1392');
+INSERT INTO users (email, name) VALUES ('user1393@example.com', 'user 1393');
+INSERT INTO guests (email, name) VALUES ('user1393@example.com', 'user 1393');
+INSERT INTO users (email, name) VALUES ('user1394@example.com', 'user 1394');
+INSERT INTO guests (email, name) VALUES ('user1394@example.com', 'user 1394');
+INSERT INTO users (email, name) VALUES ('user1395@example.com', 'user 1395');
+INSERT INTO guests (email, name) VALUES ('user1395@example.com', 'user 1395');
+INSERT INTO users (email, name) VALUES ('user1396@example.com', 'user 1396');
+INSERT INTO guests (email, name) VALUES ('user1396@example.com', 'user 1396');
+INSERT INTO users (email, name) VALUES ('user1397@example.com', 'user 1397');
+INSERT INTO guests (email, name) VALUES ('user1397@example.com', 'user 1397');
+INSERT INTO users (email, name) VALUES ('user1398@example.com', 'user 1398');
+INSERT INTO guests (email, name) VALUES ('user1398@example.com', 'user 1398');
+INSERT INTO users (email, name) VALUES ('user1399@example.com', 'user 1399');
+INSERT INTO guests (email, name) VALUES ('user1399@example.com', 'user 1399');
+INSERT INTO users (email, name) VALUES ('user1400@example.com', 'user 1400');
+INSERT INTO guests (email, name) VALUES ('user1400@example.com', 'user 1400');
+INSERT INTO users (email, name) VALUES ('user1401@example.com', 'user 1401');
+INSERT INTO guests (email, name) VALUES ('user1401@example.com', 'user 1401');
+INSERT INTO users (email, name) VALUES ('user1402@example.com', 'user 1402');
+INSERT INTO guests (email, name) VALUES ('user1402@example.com', 'user 1402');
+INSERT INTO users (email, name) VALUES ('user1403@example.com', 'user 1403');
+INSERT INTO guests (email, name) VALUES ('user1403@example.com', 'user 1403');
+INSERT INTO users (email, name) VALUES ('user1404@example.com', 'user 1404');
+INSERT INTO guests (email, name) VALUES ('user1404@example.com', 'user 1404');
+INSERT INTO users (email, name) VALUES ('user1405@example.com', 'user 1405');
+INSERT INTO guests (email, name) VALUES ('user1405@example.com', 'user 1405');
+INSERT INTO users (email, name) VALUES ('user1406@example.com', 'user 1406');
+INSERT INTO guests (email, name) VALUES ('user1406@example.com', 'user 1406');
+INSERT INTO users (email, name) VALUES ('user1407@example.com', 'user 1407');
+INSERT INTO guests (email, name) VALUES ('user1407@example.com', 'user 1407');
+INSERT INTO users (email, name) VALUES ('user1408@example.com', 'user 1408');
+INSERT INTO guests (email, name) VALUES ('user1408@example.com', 'user 1408');
+INSERT INTO users (email, name) VALUES ('user1409@example.com', 'user 1409');
+INSERT INTO guests (email, name) VALUES ('user1409@example.com', 'user 1409');
+INSERT INTO users (email, name) VALUES ('user1410@example.com', 'user 1410');
+INSERT INTO guests (email, name) VALUES ('user1410@example.com', 'user 1410');
+INSERT INTO users (email, name) VALUES ('user1411@example.com', 'user 1411');
+INSERT INTO guests (email, name) VALUES ('user1411@example.com', 'user 1411');
+INSERT INTO users (email, name) VALUES ('user1412@example.com', 'user 1412');
+INSERT INTO guests (email, name) VALUES ('user1412@example.com', 'user 1412');
+INSERT INTO users (email, name) VALUES ('user1413@example.com', 'user 1413');
+INSERT INTO guests (email, name) VALUES ('user1413@example.com', 'user 1413');
+INSERT INTO users (email, name) VALUES ('user1414@example.com', 'user 1414');
+INSERT INTO guests (email, name) VALUES ('user1414@example.com', 'user 1414');
+INSERT INTO users (email, name) VALUES ('user1415@example.com', 'user 1415');
+INSERT INTO guests (email, name) VALUES ('user1415@example.com', 'user 1415');
+INSERT INTO users (email, name) VALUES ('user1416@example.com', 'user 1416');
+INSERT INTO guests (email, name) VALUES ('user1416@example.com', 'user 1416');
+INSERT INTO users (email, name) VALUES ('user1417@example.com', 'user 1417');
+INSERT INTO guests (email, name) VALUES ('user1417@example.com', 'user 1417');
+INSERT INTO users (email, name) VALUES ('user1418@example.com', 'user 1418');
+INSERT INTO guests (email, name) VALUES ('user1418@example.com', 'user 1418');
+INSERT INTO users (email, name) VALUES ('user1419@example.com', 'user 1419');
+INSERT INTO guests (email, name) VALUES ('user1419@example.com', 'user 1419');
+INSERT INTO users (email, name) VALUES ('user1420@example.com', 'user 1420');
+INSERT INTO guests (email, name) VALUES ('user1420@example.com', 'user 1420');
+INSERT INTO users (email, name) VALUES ('user1421@example.com', 'user 1421');
+INSERT INTO guests (email, name) VALUES ('user1421@example.com', 'user 1421');
+INSERT INTO users (email, name) VALUES ('user1422@example.com', 'user 1422');
+INSERT INTO guests (email, name) VALUES ('user1422@example.com', 'user 1422');
+INSERT INTO users (email, name) VALUES ('user1423@example.com', 'user 1423');
+INSERT INTO guests (email, name) VALUES ('user1423@example.com', 'user 1423');
+INSERT INTO users (email, name) VALUES ('user1424@example.com', 'user 1424');
+INSERT INTO guests (email, name) VALUES ('user1424@example.com', 'user 1424');
+INSERT INTO users (email, name) VALUES ('user1425@example.com', 'user 1425');
+INSERT INTO guests (email, name) VALUES ('user1425@example.com', 'user 1425');
+INSERT INTO users (email, name) VALUES ('user1426@example.com', 'user 1426');
+INSERT INTO guests (email, name) VALUES ('user1426@example.com', 'user 1426');
+INSERT INTO users (email, name) VALUES ('user1427@example.com', 'user 1427');
+INSERT INTO guests (email, name) VALUES ('user1427@example.com', 'user 1427');
+INSERT INTO users (email, name) VALUES ('user1428@example.com', 'user 1428');
+INSERT INTO guests (email, name) VALUES ('user1428@example.com', 'user 1428');
+INSERT INTO users (email, name) VALUES ('user1429@example.com', 'user 1429');
+INSERT INTO guests (email, name) VALUES ('user1429@example.com', 'user 1429');
+INSERT INTO users (email, name) VALUES ('user1430@example.com', 'user 1430');
+INSERT INTO guests (email, name) VALUES ('user1430@example.com', 'user 1430');
+INSERT INTO users (email, name) VALUES ('user1431@example.com', 'user 1431');
+INSERT INTO guests (email, name) VALUES ('user1431@example.com', 'user 1431');
+INSERT INTO users (email, name) VALUES ('user1432@example.com', 'user 1432');
+INSERT INTO guests (email, name) VALUES ('user1432@example.com', 'user 1432');
+INSERT INTO users (email, name) VALUES ('user1433@example.com', 'user 1433');
+INSERT INTO guests (email, name) VALUES ('user1433@example.com', 'user 1433');
+INSERT INTO users (email, name) VALUES ('user1434@example.com', 'user 1434');
+INSERT INTO guests (email, name) VALUES ('user1434@example.com', 'user 1434');
+INSERT INTO users (email, name) VALUES ('user1435@example.com', 'user 1435');
+INSERT INTO guests (email, name) VALUES ('user1435@example.com', 'user 1435');
+INSERT INTO users (email, name) VALUES ('user1436@example.com', 'user 1436');
+INSERT INTO guests (email, name) VALUES ('user1436@example.com', 'user 1436');
+INSERT INTO users (email, name) VALUES ('user1437@example.com', 'user 1437');
+INSERT INTO guests (email, name) VALUES ('user1437@example.com', 'user 1437');
+INSERT INTO users (email, name) VALUES ('user1438@example.com', 'user 1438');
+INSERT INTO guests (email, name) VALUES ('user1438@example.com', 'user 1438');
+INSERT INTO users (email, name) VALUES ('user1439@example.com', 'user 1439');
+INSERT INTO guests (email, name) VALUES ('user1439@example.com', 'user 1439');
+INSERT INTO users (email, name) VALUES ('user1440@example.com', 'user 1440');
+INSERT INTO guests (email, name) VALUES ('user1440@example.com', 'user 1440');
+INSERT INTO users (email, name) VALUES ('user1441@example.com', 'user 1441');
+INSERT INTO guests (email, name) VALUES ('user1441@example.com', 'user 1441');
+INSERT INTO users (email, name) VALUES ('user1442@example.com', 'user 1442');
+INSERT INTO guests (email, name) VALUES ('user1442@example.com', 'user 1442');
+INSERT INTO users (email, name) VALUES ('user1443@example.com', 'user 1443');
+INSERT INTO guests (email, name) VALUES ('user1443@example.com', 'user 1443');
+INSERT INTO users (email, name) VALUES ('user1444@example.com', 'user 1444');
+INSERT INTO guests (email, name) VALUES ('user1444@example.com', 'user 1444');
+INSERT INTO users (email, name) VALUES ('user1445@example.com', 'user 1445');
+INSERT INTO guests (email, name) VALUES ('user1445@example.com', 'user 1445');
+INSERT INTO users (email, name) VALUES ('user1446@example.com', 'user 1446');
+INSERT INTO guests (email, name) VALUES ('user1446@example.com', 'user 1446');
+INSERT INTO users (email, name) VALUES ('user1447@example.com', 'user 1447');
+INSERT INTO guests (email, name) VALUES ('user1447@example.com', 'user 1447');
+INSERT INTO users (email, name) VALUES ('user1448@example.com', 'user 1448');
+INSERT INTO guests (email, name) VALUES ('user1448@example.com', 'user 1448');
+INSERT INTO users (email, name) VALUES ('user1449@example.com', 'user 1449');
+INSERT INTO guests (email, name) VALUES ('user1449@example.com', 'user 1449');
+INSERT INTO users (email, name) VALUES ('user1450@example.com', 'user 1450');
+INSERT INTO guests (email, name) VALUES ('user1450@example.com', 'user 1450');
+INSERT INTO users (email, name) VALUES ('user1451@example.com', 'user 1451');
+INSERT INTO guests (email, name) VALUES ('user1451@example.com', 'user 1451');
+INSERT INTO users (email, name) VALUES ('user1452@example.com', 'user 1452');
+INSERT INTO guests (email, name) VALUES ('user1452@example.com', 'user 1452');
+INSERT INTO users (email, name) VALUES ('user1453@example.com', 'user 1453');
+INSERT INTO guests (email, name) VALUES ('user1453@example.com', 'user 1453');
+INSERT INTO users (email, name) VALUES ('user1454@example.com', 'user 1454');
+INSERT INTO guests (email, name) VALUES ('user1454@example.com', 'user 1454');
+INSERT INTO users (email, name) VALUES ('user1455@example.com', 'user 1455');
+INSERT INTO guests (email, name) VALUES ('user1455@example.com', 'user 1455');
+INSERT INTO users (email, name) VALUES ('user1456@example.com', 'user 1456');
+INSERT INTO guests (email, name) VALUES ('user1456@example.com', 'user 1456');
+INSERT INTO users (email, name) VALUES ('user1457@example.com', 'user 1457');
+INSERT INTO guests (email, name) VALUES ('user1457@example.com', 'user 1457');
+INSERT INTO users (email, name) VALUES ('user1458@example.com', 'user 1458');
+INSERT INTO guests (email, name) VALUES ('user1458@example.com', 'user 1458');
+INSERT INTO users (email, name) VALUES ('user1459@example.com', 'user 1459');
+INSERT INTO guests (email, name) VALUES ('user1459@example.com', 'user 1459');
+INSERT INTO users (email, name) VALUES ('user1460@example.com', 'user 1460');
+INSERT INTO guests (email, name) VALUES ('user1460@example.com', 'user 1460');
+INSERT INTO users (email, name) VALUES ('user1461@example.com', 'user 1461');
+INSERT INTO guests (email, name) VALUES ('user1461@example.com', 'user 1461');
+INSERT INTO users (email, name) VALUES ('user1462@example.com', 'user 1462');
+INSERT INTO guests (email, name) VALUES ('user1462@example.com', 'user 1462');
+INSERT INTO users (email, name) VALUES ('user1463@example.com', 'user 1463');
+INSERT INTO guests (email, name) VALUES ('user1463@example.com', 'user 1463');
+INSERT INTO users (email, name) VALUES ('user1464@example.com', 'user 1464');
+INSERT INTO guests (email, name) VALUES ('user1464@example.com', 'user 1464');
+INSERT INTO users (email, name) VALUES ('user1465@example.com', 'user 1465');
+INSERT INTO guests (email, name) VALUES ('user1465@example.com', 'user 1465');
+INSERT INTO users (email, name) VALUES ('user1466@example.com', 'user 1466');
+INSERT INTO guests (email, name) VALUES ('user1466@example.com', 'user 1466');
+INSERT INTO users (email, name) VALUES ('user1467@example.com', 'user 1467');
+INSERT INTO guests (email, name) VALUES ('user1467@example.com', 'user 1467');
+INSERT INTO users (email, name) VALUES ('user1468@example.com', 'user 1468');
+INSERT INTO guests (email, name) VALUES ('user1468@example.com', 'user 1468');
+INSERT INTO users (email, name) VALUES ('user1469@example.com', 'user 1469');
+INSERT INTO guests (email, name) VALUES ('user1469@example.com', 'user 1469');
+INSERT INTO users (email, name) VALUES ('user1470@example.com', 'user 1470');
+INSERT INTO guests (email, name) VALUES ('user1470@example.com', 'user 1470');
+INSERT INTO users (email, name) VALUES ('user1471@example.com', 'user 1471');
+INSERT INTO guests (email, name) VALUES ('user1471@example.com', 'user 1471');
+INSERT INTO users (email, name) VALUES ('user1472@example.com', 'user 1472');
+INSERT INTO guests (email, name) VALUES ('user1472@example.com', 'user 1472');
+INSERT INTO users (email, name) VALUES ('user1473@example.com', 'user 1473');
+INSERT INTO guests (email, name) VALUES ('user1473@example.com', 'user 1473');
+INSERT INTO users (email, name) VALUES ('user1474@example.com', 'user 1474');
+INSERT INTO guests (email, name) VALUES ('user1474@example.com', 'user 1474');
+INSERT INTO users (email, name) VALUES ('user1475@example.com', 'user 1475');
+INSERT INTO guests (email, name) VALUES ('user1475@example.com', 'user 1475');
+INSERT INTO users (email, name) VALUES ('user1476@example.com', 'user 1476');
+INSERT INTO guests (email, name) VALUES ('user1476@example.com', 'user 1476');
+INSERT INTO users (email, name) VALUES ('user1477@example.com', 'user 1477');
+INSERT INTO guests (email, name) VALUES ('user1477@example.com', 'user 1477');
+INSERT INTO users (email, name) VALUES ('user1478@example.com', 'user 1478');
+INSERT INTO guests (email, name) VALUES ('user1478@example.com', 'user 1478');
+INSERT INTO users (email, name) VALUES ('user1479@example.com', 'user 1479');
+INSERT INTO guests (email, name) VALUES ('user1479@example.com', 'user 1479');
+INSERT INTO users (email, name) VALUES ('user1480@example.com', 'user 1480');
+INSERT INTO guests (email, name) VALUES ('user1480@example.com', 'user 1480');
+INSERT INTO users (email, name) VALUES ('user1481@example.com', 'user 1481');
+INSERT INTO guests (email, name) VALUES ('user1481@example.com', 'user 1481');
+INSERT INTO users (email, name) VALUES ('user1482@example.com', 'user 1482');
+INSERT INTO guests (email, name) VALUES ('user1482@example.com', 'user 1482');
+INSERT INTO users (email, name) VALUES ('user1483@example.com', 'user 1483');
+INSERT INTO guests (email, name) VALUES ('user1483@example.com', 'user 1483');
+INSERT INTO users (email, name) VALUES ('user1484@example.com', 'user 1484');
+INSERT INTO guests (email, name) VALUES ('user1484@example.com', 'user 1484');
+INSERT INTO users (email, name) VALUES ('user1485@example.com', 'user 1485');
+INSERT INTO guests (email, name) VALUES ('user1485@example.com', 'user 1485');
+INSERT INTO users (email, name) VALUES ('user1486@example.com', 'user 1486');
+INSERT INTO guests (email, name) VALUES ('user1486@example.com', 'user 1486');
+INSERT INTO users (email, name) VALUES ('user1487@example.com', 'user 1487');
+INSERT INTO guests (email, name) VALUES ('user1487@example.com', 'user 1487');
+INSERT INTO users (email, name) VALUES ('user1488@example.com', 'user 1488');
+INSERT INTO guests (email, name) VALUES ('user1488@example.com', 'user 1488');
+INSERT INTO users (email, name) VALUES ('user1489@example.com', 'user 1489');
+INSERT INTO guests (email, name) VALUES ('user1489@example.com', 'user 1489');
+INSERT INTO users (email, name) VALUES ('user1490@example.com', 'user 1490');
+INSERT INTO guests (email, name) VALUES ('user1490@example.com', 'user 1490');
+INSERT INTO users (email, name) VALUES ('user1491@example.com', 'user 1491');
+INSERT INTO guests (email, name) VALUES ('user1491@example.com', 'user 1491');
+INSERT INTO users (email, name) VALUES ('user1492@example.com', 'user 1492');
+INSERT INTO guests (email, name) VALUES ('user1492@example.com', 'user 1492');
+INSERT INTO users (email, name) VALUES ('user1493@example.com', 'user 1493');
+INSERT INTO guests (email, name) VALUES ('user1493@example.com', 'user 1493');
+INSERT INTO users (email, name) VALUES ('user1494@example.com', 'user 1494');
+INSERT INTO guests (email, name) VALUES ('user1494@example.com', 'user 1494');
+INSERT INTO users (email, name) VALUES ('user1495@example.com', 'user 1495');
+INSERT INTO guests (email, name) VALUES ('user1495@example.com', 'user 1495');
+INSERT INTO users (email, name) VALUES ('user1496@example.com', 'user 1496');
+INSERT INTO guests (email, name) VALUES ('user1496@example.com', 'user 1496');
+INSERT INTO users (email, name) VALUES ('user1497@example.com', 'user 1497');
+INSERT INTO guests (email, name) VALUES ('user1497@example.com', 'user 1497');
+INSERT INTO users (email, name) VALUES ('user1498@example.com', 'user 1498');
+INSERT INTO guests (email, name) VALUES ('user1498@example.com', 'user 1498');
+INSERT INTO users (email, name) VALUES ('user1499@example.com', 'user 1499');
+INSERT INTO guests (email, name) VALUES ('user1499@example.com', 'user 1499');
+INSERT INTO users (email, name) VALUES ('user1500@example.com', 'user 1500');
+INSERT INTO guests (email, name) VALUES ('user1500@example.com', 'user 1500');
+INSERT INTO guests (email, name) VALUES ('user700@example.com', 'second user 700');
+COMMIT;
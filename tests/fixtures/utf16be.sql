@@ -0,0 +1,6 @@
+PRAGMA encoding = 'UTF-16be';
+CREATE TABLE cities (id INTEGER PRIMARY KEY, name text, country text);
+CREATE INDEX idx_cities_country ON cities (country);
+INSERT INTO cities (name, country) VALUES
+  ('Paris', 'France'), ('São Paulo', 'Brasil'), ('Zürich', 'Schweiz'),
+  ('東京', '日本'), ('Kraków', 'Polska'), ('Osaka', '日本'), ('𝕊𝕡𝕒𝕔𝕖 🚀', 'Moon');
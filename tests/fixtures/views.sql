@@ -0,0 +1,3 @@
+CREATE TABLE apples (id integer primary key, name text, color text);
+INSERT INTO apples (name, color) VALUES ('Fuji', 'Red'), ('Granny Smith', 'Green'), ('Gala', 'Red');
+CREATE VIEW red_apples AS SELECT name FROM apples WHERE color = 'Red';
@@ -0,0 +1,15 @@
+-- tests/fixtures/wal.db was written in WAL mode with automatic checkpoints disabled, and
+-- copied with its -wal file before the connection was closed: the main file only has
+-- the first transaction, which was checkpointed, and the log has the following ones.
+-- Opening it with the sqlite3 shell checkpoints the log and deletes it, use a copy.
+-- A last frame, valid but not committed, renames 'cherry' to 'cherrz' in page 2.
+PRAGMA journal_mode = WAL;
+PRAGMA wal_autocheckpoint = 0;
+CREATE TABLE fruits (id INTEGER PRIMARY KEY, name text);
+INSERT INTO fruits (name) VALUES ('apple'), ('pear');
+PRAGMA wal_checkpoint(TRUNCATE);
+INSERT INTO fruits (name) VALUES ('cherry');
+UPDATE fruits SET name = 'green apple' WHERE id = 1;
+CREATE TABLE vegetables (id INTEGER PRIMARY KEY, name text);
+INSERT INTO vegetables (name) VALUES ('leek');
+DELETE FROM fruits WHERE id = 2;
@@ -0,0 +1,7 @@
+-- Each row has a blob of 3000 bytes, which spills to the overflow pages, between two
+-- small columns: the columns around it can be read without reading the blob
+PRAGMA page_size = 1024;
+CREATE TABLE files (id INTEGER PRIMARY KEY, name TEXT, data BLOB, size INTEGER);
+WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 100)
+INSERT INTO files (name, data, size)
+SELECT printf('file%03d', i), zeroblob(3000), i * 10 FROM n;
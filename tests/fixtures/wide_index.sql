@@ -0,0 +1,7 @@
+-- The index fits in a single leaf page of 300 cells: 'word 001' to 'word 150', then
+-- 150 times 'zebra', from the middle of the page to its end
+PRAGMA page_size = 8192;
+CREATE TABLE words (id INTEGER PRIMARY KEY, word text);
+CREATE INDEX idx_words_word ON words (word);
+WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 300)
+INSERT INTO words SELECT i, CASE WHEN i > 150 THEN 'zebra' ELSE printf('word %03d', i) END FROM n;
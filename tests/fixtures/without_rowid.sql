@@ -0,0 +1,409 @@
+CREATE TABLE kv (k TEXT PRIMARY KEY, v TEXT) WITHOUT ROWID;
+CREATE TABLE grades (student text, course text, grade integer, PRIMARY KEY (course, student)) WITHOUT ROWID;
+BEGIN;
+INSERT INTO kv VALUES ('key001', 'value 1 x');
+INSERT INTO kv VALUES ('key002', 'value 2 xx');
+INSERT INTO kv VALUES ('key003', 'value 3 xxx');
+INSERT INTO kv VALUES ('key004', 'value 4 xxxx');
+INSERT INTO kv VALUES ('key005', 'value 5 xxxxx');
+INSERT INTO kv VALUES ('key006', 'value 6 xxxxxx');
+INSERT INTO kv VALUES ('key007', 'value 7 xxxxxxx');
+INSERT INTO kv VALUES ('key008', 'value 8 xxxxxxxx');
+INSERT INTO kv VALUES ('key009', 'value 9 xxxxxxxxx');
+INSERT INTO kv VALUES ('key010', 'value 10 xxxxxxxxxx');
+INSERT INTO kv VALUES ('key011', 'value 11 xxxxxxxxxxx');
+INSERT INTO kv VALUES ('key012', 'value 12 xxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key013', 'value 13 xxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key014', 'value 14 xxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key015', 'value 15 xxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key016', 'value 16 xxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key017', 'value 17 xxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key018', 'value 18 xxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key019', 'value 19 xxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key020', 'value 20 xxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key021', 'value 21 xxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key022', 'value 22 xxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key023', 'value 23 xxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key024', 'value 24 xxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key025', 'value 25 xxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key026', 'value 26 xxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key027', 'value 27 xxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key028', 'value 28 xxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key029', 'value 29 xxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key030', 'value 30 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key031', 'value 31 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key032', 'value 32 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key033', 'value 33 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key034', 'value 34 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key035', 'value 35 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key036', 'value 36 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key037', 'value 37 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key038', 'value 38 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key039', 'value 39 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key040', 'value 40 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key041', 'value 41 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key042', 'value 42 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key043', 'value 43 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key044', 'value 44 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key045', 'value 45 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key046', 'value 46 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key047', 'value 47 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key048', 'value 48 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key049', 'value 49 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key050', 'value 50 ');
+INSERT INTO kv VALUES ('key051', 'value 51 x');
+INSERT INTO kv VALUES ('key052', 'value 52 xx');
+INSERT INTO kv VALUES ('key053', 'value 53 xxx');
+INSERT INTO kv VALUES ('key054', 'value 54 xxxx');
+INSERT INTO kv VALUES ('key055', 'value 55 xxxxx');
+INSERT INTO kv VALUES ('key056', 'value 56 xxxxxx');
+INSERT INTO kv VALUES ('key057', 'value 57 xxxxxxx');
+INSERT INTO kv VALUES ('key058', 'value 58 xxxxxxxx');
+INSERT INTO kv VALUES ('key059', 'value 59 xxxxxxxxx');
+INSERT INTO kv VALUES ('key060', 'value 60 xxxxxxxxxx');
+INSERT INTO kv VALUES ('key061', 'value 61 xxxxxxxxxxx');
+INSERT INTO kv VALUES ('key062', 'value 62 xxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key063', 'value 63 xxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key064', 'value 64 xxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key065', 'value 65 xxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key066', 'value 66 xxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key067', 'value 67 xxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key068', 'value 68 xxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key069', 'value 69 xxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key070', 'value 70 xxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key071', 'value 71 xxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key072', 'value 72 xxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key073', 'value 73 xxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key074', 'value 74 xxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key075', 'value 75 xxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key076', 'value 76 xxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key077', 'value 77 xxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key078', 'value 78 xxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key079', 'value 79 xxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key080', 'value 80 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key081', 'value 81 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key082', 'value 82 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key083', 'value 83 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key084', 'value 84 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key085', 'value 85 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key086', 'value 86 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key087', 'value 87 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key088', 'value 88 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key089', 'value 89 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key090', 'value 90 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key091', 'value 91 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key092', 'value 92 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key093', 'value 93 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key094', 'value 94 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key095', 'value 95 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key096', 'value 96 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key097', 'value 97 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key098', 'value 98 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key099', 'value 99 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key100', 'value 100 ');
+INSERT INTO kv VALUES ('key101', 'value 101 x');
+INSERT INTO kv VALUES ('key102', 'value 102 xx');
+INSERT INTO kv VALUES ('key103', 'value 103 xxx');
+INSERT INTO kv VALUES ('key104', 'value 104 xxxx');
+INSERT INTO kv VALUES ('key105', 'value 105 xxxxx');
+INSERT INTO kv VALUES ('key106', 'value 106 xxxxxx');
+INSERT INTO kv VALUES ('key107', 'value 107 xxxxxxx');
+INSERT INTO kv VALUES ('key108', 'value 108 xxxxxxxx');
+INSERT INTO kv VALUES ('key109', 'value 109 xxxxxxxxx');
+INSERT INTO kv VALUES ('key110', 'value 110 xxxxxxxxxx');
+INSERT INTO kv VALUES ('key111', 'value 111 xxxxxxxxxxx');
+INSERT INTO kv VALUES ('key112', 'value 112 xxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key113', 'value 113 xxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key114', 'value 114 xxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key115', 'value 115 xxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key116', 'value 116 xxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key117', 'value 117 xxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key118', 'value 118 xxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key119', 'value 119 xxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key120', 'value 120 xxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key121', 'value 121 xxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key122', 'value 122 xxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key123', 'value 123 xxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key124', 'value 124 xxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key125', 'value 125 xxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key126', 'value 126 xxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key127', 'value 127 xxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key128', 'value 128 xxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key129', 'value 129 xxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key130', 'value 130 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key131', 'value 131 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key132', 'value 132 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key133', 'value 133 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key134', 'value 134 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key135', 'value 135 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key136', 'value 136 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key137', 'value 137 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key138', 'value 138 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key139', 'value 139 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key140', 'value 140 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key141', 'value 141 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key142', 'value 142 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key143', 'value 143 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key144', 'value 144 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key145', 'value 145 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key146', 'value 146 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key147', 'value 147 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key148', 'value 148 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key149', 'value 149 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key150', 'value 150 ');
+INSERT INTO kv VALUES ('key151', 'value 151 x');
+INSERT INTO kv VALUES ('key152', 'value 152 xx');
+INSERT INTO kv VALUES ('key153', 'value 153 xxx');
+INSERT INTO kv VALUES ('key154', 'value 154 xxxx');
+INSERT INTO kv VALUES ('key155', 'value 155 xxxxx');
+INSERT INTO kv VALUES ('key156', 'value 156 xxxxxx');
+INSERT INTO kv VALUES ('key157', 'value 157 xxxxxxx');
+INSERT INTO kv VALUES ('key158', 'value 158 xxxxxxxx');
+INSERT INTO kv VALUES ('key159', 'value 159 xxxxxxxxx');
+INSERT INTO kv VALUES ('key160', 'value 160 xxxxxxxxxx');
+INSERT INTO kv VALUES ('key161', 'value 161 xxxxxxxxxxx');
+INSERT INTO kv VALUES ('key162', 'value 162 xxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key163', 'value 163 xxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key164', 'value 164 xxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key165', 'value 165 xxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key166', 'value 166 xxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key167', 'value 167 xxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key168', 'value 168 xxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key169', 'value 169 xxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key170', 'value 170 xxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key171', 'value 171 xxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key172', 'value 172 xxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key173', 'value 173 xxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key174', 'value 174 xxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key175', 'value 175 xxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key176', 'value 176 xxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key177', 'value 177 xxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key178', 'value 178 xxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key179', 'value 179 xxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key180', 'value 180 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key181', 'value 181 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key182', 'value 182 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key183', 'value 183 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key184', 'value 184 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key185', 'value 185 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key186', 'value 186 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key187', 'value 187 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key188', 'value 188 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key189', 'value 189 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key190', 'value 190 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key191', 'value 191 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key192', 'value 192 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key193', 'value 193 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key194', 'value 194 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key195', 'value 195 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key196', 'value 196 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key197', 'value 197 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key198', 'value 198 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key199', 'value 199 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key200', 'value 200 ');
+INSERT INTO kv VALUES ('key201', 'value 201 x');
+INSERT INTO kv VALUES ('key202', 'value 202 xx');
+INSERT INTO kv VALUES ('key203', 'value 203 xxx');
+INSERT INTO kv VALUES ('key204', 'value 204 xxxx');
+INSERT INTO kv VALUES ('key205', 'value 205 xxxxx');
+INSERT INTO kv VALUES ('key206', 'value 206 xxxxxx');
+INSERT INTO kv VALUES ('key207', 'value 207 xxxxxxx');
+INSERT INTO kv VALUES ('key208', 'value 208 xxxxxxxx');
+INSERT INTO kv VALUES ('key209', 'value 209 xxxxxxxxx');
+INSERT INTO kv VALUES ('key210', 'value 210 xxxxxxxxxx');
+INSERT INTO kv VALUES ('key211', 'value 211 xxxxxxxxxxx');
+INSERT INTO kv VALUES ('key212', 'value 212 xxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key213', 'value 213 xxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key214', 'value 214 xxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key215', 'value 215 xxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key216', 'value 216 xxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key217', 'value 217 xxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key218', 'value 218 xxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key219', 'value 219 xxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key220', 'value 220 xxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key221', 'value 221 xxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key222', 'value 222 xxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key223', 'value 223 xxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key224', 'value 224 xxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key225', 'value 225 xxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key226', 'value 226 xxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key227', 'value 227 xxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key228', 'value 228 xxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key229', 'value 229 xxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key230', 'value 230 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key231', 'value 231 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key232', 'value 232 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key233', 'value 233 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key234', 'value 234 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key235', 'value 235 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key236', 'value 236 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key237', 'value 237 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key238', 'value 238 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key239', 'value 239 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key240', 'value 240 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key241', 'value 241 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key242', 'value 242 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key243', 'value 243 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key244', 'value 244 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key245', 'value 245 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key246', 'value 246 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key247', 'value 247 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key248', 'value 248 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key249', 'value 249 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key250', 'value 250 ');
+INSERT INTO kv VALUES ('key251', 'value 251 x');
+INSERT INTO kv VALUES ('key252', 'value 252 xx');
+INSERT INTO kv VALUES ('key253', 'value 253 xxx');
+INSERT INTO kv VALUES ('key254', 'value 254 xxxx');
+INSERT INTO kv VALUES ('key255', 'value 255 xxxxx');
+INSERT INTO kv VALUES ('key256', 'value 256 xxxxxx');
+INSERT INTO kv VALUES ('key257', 'value 257 xxxxxxx');
+INSERT INTO kv VALUES ('key258', 'value 258 xxxxxxxx');
+INSERT INTO kv VALUES ('key259', 'value 259 xxxxxxxxx');
+INSERT INTO kv VALUES ('key260', 'value 260 xxxxxxxxxx');
+INSERT INTO kv VALUES ('key261', 'value 261 xxxxxxxxxxx');
+INSERT INTO kv VALUES ('key262', 'value 262 xxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key263', 'value 263 xxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key264', 'value 264 xxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key265', 'value 265 xxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key266', 'value 266 xxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key267', 'value 267 xxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key268', 'value 268 xxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key269', 'value 269 xxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key270', 'value 270 xxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key271', 'value 271 xxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key272', 'value 272 xxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key273', 'value 273 xxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key274', 'value 274 xxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key275', 'value 275 xxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key276', 'value 276 xxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key277', 'value 277 xxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key278', 'value 278 xxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key279', 'value 279 xxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key280', 'value 280 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key281', 'value 281 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key282', 'value 282 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key283', 'value 283 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key284', 'value 284 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key285', 'value 285 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key286', 'value 286 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key287', 'value 287 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key288', 'value 288 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key289', 'value 289 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key290', 'value 290 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key291', 'value 291 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key292', 'value 292 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key293', 'value 293 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key294', 'value 294 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key295', 'value 295 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key296', 'value 296 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key297', 'value 297 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key298', 'value 298 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key299', 'value 299 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key300', 'value 300 ');
+INSERT INTO kv VALUES ('key301', 'value 301 x');
+INSERT INTO kv VALUES ('key302', 'value 302 xx');
+INSERT INTO kv VALUES ('key303', 'value 303 xxx');
+INSERT INTO kv VALUES ('key304', 'value 304 xxxx');
+INSERT INTO kv VALUES ('key305', 'value 305 xxxxx');
+INSERT INTO kv VALUES ('key306', 'value 306 xxxxxx');
+INSERT INTO kv VALUES ('key307', 'value 307 xxxxxxx');
+INSERT INTO kv VALUES ('key308', 'value 308 xxxxxxxx');
+INSERT INTO kv VALUES ('key309', 'value 309 xxxxxxxxx');
+INSERT INTO kv VALUES ('key310', 'value 310 xxxxxxxxxx');
+INSERT INTO kv VALUES ('key311', 'value 311 xxxxxxxxxxx');
+INSERT INTO kv VALUES ('key312', 'value 312 xxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key313', 'value 313 xxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key314', 'value 314 xxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key315', 'value 315 xxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key316', 'value 316 xxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key317', 'value 317 xxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key318', 'value 318 xxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key319', 'value 319 xxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key320', 'value 320 xxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key321', 'value 321 xxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key322', 'value 322 xxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key323', 'value 323 xxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key324', 'value 324 xxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key325', 'value 325 xxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key326', 'value 326 xxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key327', 'value 327 xxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key328', 'value 328 xxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key329', 'value 329 xxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key330', 'value 330 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key331', 'value 331 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key332', 'value 332 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key333', 'value 333 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key334', 'value 334 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key335', 'value 335 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key336', 'value 336 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key337', 'value 337 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key338', 'value 338 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key339', 'value 339 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key340', 'value 340 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key341', 'value 341 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key342', 'value 342 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key343', 'value 343 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key344', 'value 344 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key345', 'value 345 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key346', 'value 346 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key347', 'value 347 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key348', 'value 348 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key349', 'value 349 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key350', 'value 350 ');
+INSERT INTO kv VALUES ('key351', 'value 351 x');
+INSERT INTO kv VALUES ('key352', 'value 352 xx');
+INSERT INTO kv VALUES ('key353', 'value 353 xxx');
+INSERT INTO kv VALUES ('key354', 'value 354 xxxx');
+INSERT INTO kv VALUES ('key355', 'value 355 xxxxx');
+INSERT INTO kv VALUES ('key356', 'value 356 xxxxxx');
+INSERT INTO kv VALUES ('key357', 'value 357 xxxxxxx');
+INSERT INTO kv VALUES ('key358', 'value 358 xxxxxxxx');
+INSERT INTO kv VALUES ('key359', 'value 359 xxxxxxxxx');
+INSERT INTO kv VALUES ('key360', 'value 360 xxxxxxxxxx');
+INSERT INTO kv VALUES ('key361', 'value 361 xxxxxxxxxxx');
+INSERT INTO kv VALUES ('key362', 'value 362 xxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key363', 'value 363 xxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key364', 'value 364 xxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key365', 'value 365 xxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key366', 'value 366 xxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key367', 'value 367 xxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key368', 'value 368 xxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key369', 'value 369 xxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key370', 'value 370 xxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key371', 'value 371 xxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key372', 'value 372 xxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key373', 'value 373 xxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key374', 'value 374 xxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key375', 'value 375 xxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key376', 'value 376 xxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key377', 'value 377 xxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key378', 'value 378 xxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key379', 'value 379 xxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key380', 'value 380 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key381', 'value 381 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key382', 'value 382 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key383', 'value 383 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key384', 'value 384 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key385', 'value 385 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key386', 'value 386 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key387', 'value 387 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key388', 'value 388 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key389', 'value 389 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key390', 'value 390 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key391', 'value 391 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key392', 'value 392 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key393', 'value 393 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key394', 'value 394 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key395', 'value 395 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key396', 'value 396 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key397', 'value 397 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key398', 'value 398 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key399', 'value 399 xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx');
+INSERT INTO kv VALUES ('key400', 'value 400 ');
+INSERT INTO grades VALUES ('ann', 'math', 12);
+INSERT INTO grades VALUES ('bob', 'math', 15);
+INSERT INTO grades VALUES ('ann', 'art', 17);
+INSERT INTO grades VALUES ('cid', 'art', 9);
+INSERT INTO grades VALUES ('bob', 'bio', 11);
+COMMIT;
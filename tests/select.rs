@@ -0,0 +1,2159 @@
+use std::{
+    path::Path,
+    process::{Command, Output},
+};
+
+use common::{text, DatabaseBuilder, Table};
+use sqlite_starter_rust::page::ColumnContent::{Blob, Float, Int, Null};
+
+mod common;
+
+/// Runs the cli with the database read from the file, again with the database mapped in
+/// memory, and again with a copy of the file in memory: all the backends must give the
+/// same output. The copy is not compared for the databases with a -wal or a -journal
+/// file, which it does not read.
+fn cli<I: IntoIterator<Item = S> + Clone, S: AsRef<std::ffi::OsStr>>(args: I) -> Output {
+    let run_with = |backend_args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_sqlite-starter-rust"))
+            .args(backend_args)
+            .args(args.clone())
+            .output()
+            .expect("Could not run the cli")
+    };
+    let output = run_with(&[]);
+    let mmap_output = run_with(&["--mmap"]);
+    assert_eq!(output, mmap_output, "the mmap backend gives another output");
+
+    let has_side_files = args.clone().into_iter().any(|arg| {
+        let arg = arg.as_ref().to_string_lossy();
+        ["-wal", "-journal"]
+            .iter()
+            .any(|suffix| Path::new(&format!("{}{}", arg, suffix)).exists())
+    });
+    if !has_side_files {
+        let in_memory_output = run_with(&["--in-memory"]);
+        assert_eq!(
+            output, in_memory_output,
+            "the in-memory copy gives another output"
+        );
+    }
+    output
+}
+
+/// Runs the cli against a database and returns stdout
+fn run(db: &str, sql: &str) -> String {
+    let output = cli([db, sql]);
+    assert!(output.status.success(), "{:?}", output);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn count_without_where_clause() {
+    assert_eq!(run("sample.db", "SELECT count(*) FROM apples"), "4\n");
+}
+
+/// Runs the cli for a query that must fail and returns stderr
+fn run_error(db: &str, sql: &str) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_sqlite-starter-rust"))
+        .arg(db)
+        .arg(sql)
+        .output()
+        .expect("Could not run the cli");
+    assert!(!output.status.success(), "{:?}", output);
+    String::from_utf8(output.stderr).unwrap()
+}
+
+#[test]
+fn unknown_tables_are_errors() {
+    assert_eq!(
+        run_error("sample.db", "SELECT name FROM foo"),
+        "Parse error: no such table: foo\n"
+    );
+    // an index is in the schema but it is not a table
+    assert_eq!(
+        run_error(
+            "tests/fixtures/index_name.db",
+            "SELECT name FROM idx_fruits_name"
+        ),
+        "Parse error: no such table: idx_fruits_name\n"
+    );
+}
+
+#[test]
+fn schema_table_is_queried_like_a_table() {
+    // expected output of sqlite3
+    assert_eq!(
+        run(
+            "sample.db",
+            "SELECT name FROM sqlite_master WHERE type='table'"
+        ),
+        "apples\nsqlite_sequence\noranges\n"
+    );
+    assert_eq!(
+        run(
+            "sample.db",
+            "SELECT type, name, tbl_name FROM sqlite_schema"
+        ),
+        "table|apples|apples\ntable|sqlite_sequence|sqlite_sequence\ntable|oranges|oranges\n"
+    );
+    assert_eq!(
+        run("sample.db", "SELECT count(*) FROM sqlite_master"),
+        "3\n"
+    );
+    assert_eq!(
+        run(
+            "sample.db",
+            "SELECT rootpage FROM sqlite_master WHERE name = 'oranges'"
+        ),
+        "4\n"
+    );
+    let db = "tests/fixtures/index_name.db";
+    assert_eq!(
+        run(db, "SELECT type, name FROM sqlite_master"),
+        "table|fruits\nindex|idx_fruits_name\n"
+    );
+    assert_eq!(
+        run(db, "SELECT name FROM sqlite_schema WHERE type = 'table'"),
+        "fruits\n"
+    );
+    assert_eq!(
+        run(
+            db,
+            "SELECT count(*) FROM sqlite_schema WHERE type = 'index'"
+        ),
+        "1\n"
+    );
+}
+
+#[test]
+fn count_respects_where_clause() {
+    assert_eq!(
+        run(
+            "sample.db",
+            "SELECT count(*) FROM apples WHERE color = 'Yellow'"
+        ),
+        "1\n"
+    );
+    assert_eq!(
+        run(
+            "sample.db",
+            "SELECT count(*) FROM apples WHERE color = 'Purple'"
+        ),
+        "0\n"
+    );
+}
+
+/// Runs the cli and returns stderr, whether it succeeds or not
+fn run_stderr(db: &str, sql: &str) -> String {
+    let output = cli([db, sql]);
+    String::from_utf8(output.stderr).unwrap()
+}
+
+/// Output lines of a query, to compare with slices of another output
+fn run_lines(db: &str, sql: &str) -> Vec<String> {
+    run(db, sql).lines().map(|line| line.to_string()).collect()
+}
+
+/// Output of a query with the number of pages of the database it reads
+fn run_counting_pages(db: &str, sql: &str) -> (String, usize) {
+    let output = cli(["--count-pages", db, sql]);
+    assert!(output.status.success(), "{:?}", output);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let nb_pages = stderr.trim().strip_prefix("pages read: ").unwrap();
+    (
+        String::from_utf8(output.stdout).unwrap(),
+        nb_pages.parse().unwrap(),
+    )
+}
+
+/// Runs the cli with values bound to the placeholders of the query
+fn run_with_params(db: &str, sql: &str, params: &[&str]) -> Output {
+    let mut args = vec![db, sql];
+    for param in params {
+        args.extend(["--param", param]);
+    }
+    cli(args)
+}
+
+#[test]
+fn placeholders_are_bound_to_the_params() {
+    let db = "sample.db";
+    let stdout = |output: Output| {
+        assert!(output.status.success(), "{:?}", output);
+        String::from_utf8(output.stdout).unwrap()
+    };
+    let stderr = |output: Output| {
+        assert!(!output.status.success(), "{:?}", output);
+        String::from_utf8(output.stderr).unwrap()
+    };
+    assert_eq!(
+        stdout(run_with_params(
+            db,
+            "SELECT name FROM apples WHERE id = ?",
+            &["2"]
+        )),
+        "Fuji\n"
+    );
+    assert_eq!(
+        stdout(run_with_params(
+            db,
+            "SELECT name FROM apples WHERE id > ? AND color = :color",
+            &["1", "color=Red"]
+        )),
+        "Fuji\n"
+    );
+    assert_eq!(
+        stdout(run_with_params(
+            db,
+            "SELECT name FROM apples WHERE id = ?2 OR name = ?1",
+            &["Granny Smith", "4"]
+        )),
+        "Granny Smith\nGolden Delicious\n"
+    );
+    assert_eq!(
+        stderr(run_with_params(
+            db,
+            "SELECT name FROM apples WHERE id = ?",
+            &[]
+        )),
+        "Missing value for parameter ?1\n"
+    );
+    assert_eq!(
+        stderr(run_with_params(
+            db,
+            "SELECT name FROM apples WHERE color = :color",
+            &["2"]
+        )),
+        "Missing value for parameter :color\n"
+    );
+    assert_eq!(
+        stderr(run_with_params(
+            db,
+            "SELECT name FROM apples WHERE id = ?",
+            &["2", "3"]
+        )),
+        "Too many parameters: the query uses 1, got 2\n"
+    );
+
+    // an integer value is bound as an integer: the rowid is looked up
+    let db = "tests/fixtures/companies.db";
+    let sql = "SELECT name FROM companies WHERE id = ?";
+    assert_eq!(
+        stdout(run_with_params(
+            db,
+            &format!("EXPLAIN QUERY PLAN {}", sql),
+            &["1500"]
+        )),
+        "QUERY PLAN\n`--SEARCH companies USING INTEGER PRIMARY KEY (rowid=?)\n"
+    );
+    assert_eq!(
+        stdout(run_with_params(
+            db,
+            &format!("EXPLAIN QUERY PLAN {}", sql),
+            &["1500.5"]
+        )),
+        "QUERY PLAN\n`--SCAN companies\n"
+    );
+    let (_, nb_pages) = run_counting_pages(db, "SELECT name FROM companies WHERE id = 1500");
+    let output = cli(["--count-pages", db, sql, "--param", "1500"]);
+    assert_eq!(
+        String::from_utf8(output.stderr.clone()).unwrap(),
+        format!("pages read: {}\n", nb_pages)
+    );
+    assert_eq!(stdout(output), "company 1500\n");
+}
+
+#[test]
+fn columns_of_a_row_are_compared() {
+    let db = "tests/fixtures/scores.db";
+    // expected output of sqlite3: id is the rowid, it can be on either side
+    assert_eq!(
+        run(db, "SELECT id, score FROM scores WHERE id < score"),
+        "1|4\n2|8\n4|5\n5|9\n8|10\n"
+    );
+    assert_eq!(
+        run(db, "SELECT count(*) FROM scores WHERE score < id"),
+        "495\n"
+    );
+    assert_eq!(
+        run(db, "SELECT count(*) FROM scores WHERE id <= score"),
+        "5\n"
+    );
+    assert_eq!(
+        run(
+            db,
+            "SELECT count(*) FROM scores WHERE score >= id AND id > 3"
+        ),
+        "3\n"
+    );
+    assert_eq!(run(db, "SELECT id FROM scores WHERE id = score"), "");
+    assert_eq!(run(db, "SELECT id FROM scores WHERE score = id"), "");
+    assert_eq!(
+        run(db, "SELECT count(*) FROM scores WHERE id != score"),
+        "500\n"
+    );
+    assert_eq!(
+        run(db, "SELECT id FROM scores WHERE id = id AND id < 3"),
+        "1\n2\n"
+    );
+    // the texts are after the numbers
+    assert_eq!(
+        run(db, "SELECT count(*) FROM scores WHERE name > id"),
+        "500\n"
+    );
+}
+
+#[test]
+fn order_by_with_limit_matches_full_sort() {
+    let db = "tests/fixtures/scores.db";
+    // scores have many ties, which keep the table order in both cases
+    let sorted = run_lines(db, "SELECT id, score FROM scores ORDER BY score DESC");
+    assert_eq!(sorted.len(), 500);
+
+    for (limit, offset) in [(1, 0), (10, 0), (10, 45), (100, 420), (7, 499), (3, 600)] {
+        let top = run_lines(
+            db,
+            &format!(
+                "SELECT id, score FROM scores ORDER BY score DESC LIMIT {} OFFSET {}",
+                limit, offset
+            ),
+        );
+        let expected = sorted
+            .iter()
+            .skip(offset)
+            .take(limit)
+            .cloned()
+            .collect::<Vec<_>>();
+        assert_eq!(top, expected, "LIMIT {} OFFSET {}", limit, offset);
+    }
+}
+
+#[test]
+fn order_by_breaks_ties_with_next_term() {
+    assert_eq!(
+        run(
+            "tests/fixtures/scores.db",
+            "SELECT name FROM scores ORDER BY score, id DESC LIMIT 3"
+        ),
+        "player495\nplayer484\nplayer473\n"
+    );
+}
+
+#[test]
+fn limit_with_comma_takes_offset_first() {
+    assert_eq!(
+        run(
+            "sample.db",
+            "SELECT name FROM apples ORDER BY name LIMIT 1, 2"
+        ),
+        "Golden Delicious\nGranny Smith\n"
+    );
+}
+
+#[test]
+fn text_column_compares_numbers_as_text() {
+    let db = "tests/fixtures/affinity.db";
+    assert_eq!(run(db, "SELECT id FROM readings WHERE code = 1000"), "1\n");
+    // the leading zeros are kept in a text column
+    assert_eq!(run(db, "SELECT id FROM readings WHERE code = 7"), "3\n");
+    assert_eq!(run(db, "SELECT id FROM readings WHERE code = '007'"), "2\n");
+}
+
+#[test]
+fn numeric_column_compares_text_as_numbers() {
+    let db = "tests/fixtures/affinity.db";
+    assert_eq!(
+        run(db, "SELECT id FROM readings WHERE amount = '42'"),
+        "3\n"
+    );
+    assert_eq!(
+        run(db, "SELECT id FROM readings WHERE amount < '100'"),
+        "2\n3\n"
+    );
+    assert_eq!(run(db, "SELECT id FROM readings WHERE id = '2'"), "2\n");
+    // floats and integers with the same value are equal
+    assert_eq!(run(db, "SELECT id FROM readings WHERE ratio = 5"), "1\n3\n");
+}
+
+#[test]
+fn column_without_type_does_not_convert() {
+    let db = "tests/fixtures/affinity.db";
+    assert_eq!(run(db, "SELECT id FROM readings WHERE raw = 42"), "");
+    assert_eq!(run(db, "SELECT id FROM readings WHERE raw = '42'"), "3\n");
+}
+
+#[test]
+fn reads_tables_with_table_constraints() {
+    let db = "tests/fixtures/constraints.db";
+    assert_eq!(
+        run(db, "SELECT Title FROM Album WHERE ArtistId = 2"),
+        "Balls to the Wall\nRestless and Wild\n"
+    );
+    assert_eq!(
+        run(db, "SELECT BillingCity FROM Invoice WHERE Total = '3.96'"),
+        "Oslo\n"
+    );
+}
+
+#[test]
+fn column_constraints_do_not_split_columns() {
+    let db = "tests/fixtures/constraints.db";
+    assert_eq!(
+        run(db, "SELECT name, separators, artist_id, note FROM tags"),
+        "rock|a,b|1|loud\nx|c,d|2|\n"
+    );
+    assert_eq!(
+        run(db, "SELECT note FROM tags WHERE weight = '2.5'"),
+        "loud\n"
+    );
+}
+
+#[test]
+fn commas_in_type_arguments_do_not_split_columns() {
+    let db = "tests/fixtures/constraints.db";
+    // verbatim Chinook Invoice table, with NVARCHAR(40) and NUMERIC(10,2) columns
+    assert_eq!(
+        run(
+            db,
+            "SELECT BillingCountry, BillingPostalCode, Total FROM Invoice"
+        ),
+        "Germany|70174|1.98\nNorway|0171|3.96\n"
+    );
+    // commas in CHECK and DEFAULT expressions
+    assert_eq!(
+        run(db, "SELECT name, level, size FROM tags"),
+        "rock|1|3,4\nx|3|3,4\n"
+    );
+}
+
+#[test]
+fn table_constraints_are_not_columns() {
+    let db = "tests/fixtures/constraints.db";
+    assert_eq!(
+        run(db, "SELECT * FROM memberships WHERE band_id = 2"),
+        "1|2|guitar\n2|2|drums\n"
+    );
+}
+
+#[test]
+fn quoted_column_names() {
+    let db = "tests/fixtures/quoted.db";
+    assert_eq!(
+        run(db, "SELECT \"size range\" FROM products"),
+        "S-M\nL-XL\n"
+    );
+    assert_eq!(
+        run(
+            db,
+            "SELECT [order], `group` FROM products WHERE [order] = 1"
+        ),
+        "1|coats\n"
+    );
+    assert_eq!(
+        run(db, "SELECT \"say \"\"hi\"\"\", `back``tick` FROM products"),
+        "hello|10\nbye|20\n"
+    );
+}
+
+#[test]
+fn create_statements_with_optional_clauses() {
+    let db = "tests/fixtures/schema_sql.db";
+    // CREATE UNIQUE INDEX foo_a ON foo(a)
+    assert_eq!(run(db, "SELECT b FROM foo WHERE a = 2"), "y\n");
+    assert_eq!(run(db, "SELECT a FROM foo WHERE b = 'y'"), "2\n3\n");
+    // CREATE TABLE IF NOT EXISTS legacy (k integer, v text)
+    assert_eq!(run(db, "SELECT v FROM legacy WHERE k = 2"), "two\n");
+}
+
+#[test]
+fn without_rowid_tables() {
+    let db = "tests/fixtures/without_rowid.db";
+    // kv spans several pages of an index b tree
+    assert_eq!(run(db, "SELECT count(*) FROM kv"), "400\n");
+    assert_eq!(
+        run(db, "SELECT v FROM kv WHERE k = 'key250'"),
+        "value 250 \n"
+    );
+    assert_eq!(run_lines(db, "SELECT k FROM kv")[..2], ["key001", "key002"]);
+    // the primary key (course, student) is stored first
+    assert_eq!(
+        run(db, "SELECT student, grade FROM grades WHERE course = 'art'"),
+        "ann|17\ncid|9\n"
+    );
+}
+
+#[test]
+fn only_integer_primary_key_is_the_rowid() {
+    let db = "tests/fixtures/rowid_alias.db";
+    assert_eq!(run(db, "SELECT * FROM people"), "p-1|ann\np-2|bob\n");
+    assert_eq!(run(db, "SELECT * FROM artists"), "10|AC/DC\n20|Accept\n");
+    assert_eq!(
+        run(db, "SELECT name FROM artists WHERE ArtistId = 20"),
+        "Accept\n"
+    );
+    assert_eq!(run(db, "SELECT * FROM codes"), "7|seven\n3|three\n");
+    assert_eq!(run(db, "SELECT * FROM reversed"), "5|five\n");
+}
+
+#[test]
+fn autoincrement_and_sqlite_sequence() {
+    let db = "tests/fixtures/autoincrement.db";
+    assert_eq!(
+        run(db, "SELECT id, title FROM tasks"),
+        "1|write\n2|review\n"
+    );
+    // the deleted row is still recorded in sqlite_sequence
+    assert_eq!(
+        run(db, "SELECT name, seq FROM sqlite_sequence"),
+        "tasks|3\n"
+    );
+    assert!(!run(db, ".tables").contains("sqlite_sequence"));
+    // sqlite3 also counts sqlite_sequence in .dbinfo
+    assert!(run(db, ".dbinfo").contains("number of tables: 3\n"));
+}
+
+#[test]
+fn check_and_default_bodies_are_skipped() {
+    let db = "tests/fixtures/constraints.db";
+    assert_eq!(
+        run(db, "SELECT label, currency FROM prices"),
+        "tea|it's, (EUR)\ncoffee|it's, (EUR)\n"
+    );
+    assert_eq!(
+        run(db, "SELECT label FROM prices WHERE price = 3"),
+        "coffee\n"
+    );
+}
+
+#[test]
+fn comments_in_create_table() {
+    let db = "tests/fixtures/comments.db";
+    assert_eq!(
+        run(db, "SELECT * FROM notes"),
+        "1|first|3|ann\n2|second|4|bob\n"
+    );
+    assert_eq!(run(db, "SELECT author FROM notes WHERE id = 2"), "bob\n");
+}
+
+#[test]
+fn composite_index_on_leading_column() {
+    let db = "tests/fixtures/orders.db";
+    // uses idx_orders_customer_date (customer, order_date DESC)
+    let ids = run_lines(db, "SELECT id FROM orders WHERE customer = 'customer05'");
+    let expected = (0..20)
+        .map(|i| (70 + 97 * i).to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, expected);
+    // amount is only the second column of idx_orders_date_amount
+    assert_eq!(
+        run(db, "SELECT id FROM orders WHERE amount = 13"),
+        "1\n501\n1001\n1501\n"
+    );
+    assert_eq!(
+        run(
+            db,
+            "SELECT count(*) FROM orders WHERE order_date = '2024-03-03'"
+        ),
+        "24\n"
+    );
+    // a range on the leading column too, but the NOCASE index is not sorted like the
+    // comparison of order_date
+    assert_eq!(
+        run(
+            db,
+            "SELECT count(*) FROM orders WHERE customer >= 'customer95'"
+        ),
+        "40\n"
+    );
+    assert_eq!(
+        run(
+            db,
+            "SELECT count(*) FROM orders WHERE order_date > '2024-12-20'"
+        ),
+        "47\n"
+    );
+}
+
+#[test]
+fn unique_and_non_unique_index_lookups() {
+    let db = "tests/fixtures/users.db";
+    // CREATE UNIQUE INDEX idx_users_email ON users (email)
+    assert_eq!(
+        run(
+            db,
+            "SELECT id, name FROM users WHERE email = 'user700@example.com'"
+        ),
+        "700|user 700\n"
+    );
+    // CREATE INDEX idx_guests_email ON guests (email), with a duplicated email
+    assert_eq!(
+        run(
+            db,
+            "SELECT id, name FROM guests WHERE email = 'user700@example.com'"
+        ),
+        "700|user 700\n1501|second user 700\n"
+    );
+    for table in ["users", "guests"] {
+        assert_eq!(
+            run(
+                db,
+                &format!(
+                    "SELECT id FROM {} WHERE email = 'user1500@example.com'",
+                    table
+                )
+            ),
+            "1500\n"
+        );
+    }
+}
+
+#[test]
+fn quoted_index_and_unsupported_index_warning() {
+    let db = "tests/fixtures/quoted_index.db";
+    let sql = "SELECT id FROM \"my table\" WHERE col = 'value42'";
+    assert_eq!(run(db, sql), "42\n342\n642\n942\n");
+
+    // printed once when the database is opened, not each time a query is planned
+    let stderr = run_stderr(db, &format!("{} OR col = 'value43'", sql));
+    assert_eq!(
+        stderr
+            .matches("warning: index idx_not_null is not supported")
+            .count(),
+        1,
+        "{}",
+        stderr
+    );
+    // the warnings of an attached database start with its name
+    let output = cli([
+        "--attach",
+        "quoted=tests/fixtures/quoted_index.db",
+        "sample.db",
+        "SELECT count(*) FROM apples",
+    ]);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("warning: quoted: index idx_not_null is not supported"),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn partial_index_is_not_used_for_other_rows() {
+    let db = "tests/fixtures/partial_index.db";
+    // idx_members_active_email only contains the active members
+    assert_eq!(
+        run(
+            db,
+            "SELECT id, active FROM members WHERE email = 'user2@example.com'"
+        ),
+        "2|0\n"
+    );
+    assert_eq!(
+        run(
+            db,
+            "SELECT id, active FROM members WHERE email = 'user3@example.com'"
+        ),
+        "3|1\n"
+    );
+}
+
+#[test]
+fn partial_index_is_used_when_the_query_has_its_condition() {
+    let db = "tests/fixtures/partial_index.db";
+    let sql = "SELECT id FROM members WHERE email = 'user3@example.com' AND active = 1";
+    assert_eq!(run(db, sql), "3\n");
+    assert_eq!(
+        run(db, &format!("EXPLAIN QUERY PLAN {}", sql)),
+        "QUERY PLAN\n`--SEARCH members USING INDEX idx_members_active_email (email=?)\n"
+    );
+    // user2 is not active, it is not in the index
+    assert_eq!(
+        run(
+            db,
+            "SELECT id FROM members WHERE active = 1 AND email = 'user2@example.com'"
+        ),
+        ""
+    );
+}
+
+#[test]
+fn views_are_resolved_but_not_listed() {
+    let db = "tests/fixtures/views.db";
+    assert_eq!(run(db, "SELECT name FROM red_apples"), "Fuji\nGala\n");
+    assert_eq!(run(db, "SELECT count(*) FROM red_apples"), "2\n");
+    assert_eq!(run(db, ".tables"), "apples\n");
+    assert!(run(db, ".dbinfo").contains("number of tables: 1\n"));
+}
+
+#[test]
+fn virtual_tables_and_their_shadow_tables() {
+    let db = "tests/fixtures/fts5.db";
+    // like sqlite3, the virtual table and its shadow tables are counted
+    assert!(run(db, ".dbinfo").contains("number of tables: 7\n"));
+    assert_eq!(run(db, "SELECT body FROM notes WHERE id = 2"), "world\n");
+    assert_eq!(
+        run(db, "SELECT c0, c1 FROM docs_content"),
+        "first|hello world\nsecond|goodbye, world\n"
+    );
+    assert!(run_stderr(db, "SELECT * FROM docs").contains("virtual tables are not supported"));
+}
+
+#[test]
+fn virtual_generated_columns_are_not_in_the_record() {
+    let db = "tests/fixtures/constraints.db";
+    // label is VIRTUAL and rendered as NULL, total is STORED
+    assert_eq!(
+        run(db, "SELECT qty, label, name FROM line_items"),
+        "4||tea\n2||milk\n"
+    );
+    assert_eq!(
+        run(db, "SELECT name FROM line_items WHERE total = 2.5"),
+        "milk\n"
+    );
+}
+
+#[test]
+fn descending_and_expression_indexes_are_not_used() {
+    let db = "tests/fixtures/desc_index.db";
+    // idx_people_name_desc is sorted in descending order
+    assert_eq!(
+        run(db, "SELECT id FROM people WHERE name = 'name123'"),
+        "123\n523\n923\n1323\n"
+    );
+    // idx_people_email_lower indexes lower(email), not email
+    assert_eq!(
+        run(
+            db,
+            "SELECT id FROM people WHERE email = 'Person77@Example.com'"
+        ),
+        "77\n"
+    );
+    assert_eq!(
+        run_stderr(
+            db,
+            "SELECT id FROM people WHERE email = 'Person77@Example.com'"
+        ),
+        ""
+    );
+}
+
+#[test]
+fn quoted_table_names() {
+    let db = "tests/fixtures/quoted.db";
+    assert_eq!(
+        run(db, "SELECT * FROM \"order details\""),
+        "1|tea\n2|coffee\n"
+    );
+    assert_eq!(
+        run(
+            db,
+            "SELECT product FROM [Order Details] WHERE \"order id\" = 2"
+        ),
+        "coffee\n"
+    );
+    // a keyword as table name
+    assert_eq!(
+        run(db, "SELECT \"select\" FROM \"group\" WHERE id = 2"),
+        "none\n"
+    );
+    assert_eq!(run(db, "SELECT count(*) FROM `group`"), "2\n");
+}
+
+#[test]
+fn nine_byte_rowids() {
+    // these rowids are stored as 9 byte varints
+    let table = Table::new("t", "CREATE TABLE t (id INTEGER PRIMARY KEY, label text)")
+        .row(-1, vec![Null, text("minus one")])
+        .row(72057594037927936, vec![Null, text("two pow 56")])
+        .row(i64::MAX, vec![Null, text("max")]);
+    let built = DatabaseBuilder::new().table(table).write("big_rowids");
+    // the same table written by sqlite3, in case the builder encodes the varints wrong
+    // like the reader
+    for db in ["tests/fixtures/big_rowids.db", &built] {
+        assert_eq!(
+            run(db, "SELECT * FROM t"),
+            "-1|minus one\n72057594037927936|two pow 56\n9223372036854775807|max\n"
+        );
+        assert_eq!(
+            run(db, "SELECT label FROM t WHERE id = 9223372036854775807"),
+            "max\n"
+        );
+    }
+}
+
+#[test]
+fn negative_integers_are_sign_extended() {
+    // one value per integer width class, each with its negative counterpart
+    let values = [
+        ("int8", [-1, -128, 127]),
+        ("int16", [-129, -32768, 32767]),
+        ("int24", [-32769, -8388608, 8388607]),
+        ("int32", [-8388609, -2147483648, 2147483647]),
+        ("int48", [-2147483649, -140737488355328, 140737488355327]),
+        ("int64", [-140737488355329, i64::MIN, i64::MAX]),
+    ];
+    let mut ints = Table::new(
+        "ints",
+        "CREATE TABLE ints (id INTEGER PRIMARY KEY, width text, value integer)",
+    );
+    for (i, (width, value)) in values
+        .iter()
+        .flat_map(|(width, values)| values.iter().map(move |value| (width, value)))
+        .enumerate()
+    {
+        ints = ints.row(i as i64 + 1, vec![Null, text(width), Int(*value)]);
+    }
+    let mut negative_rowids = Table::new(
+        "negative_rowids",
+        "CREATE TABLE negative_rowids (id INTEGER PRIMARY KEY, label text)",
+    );
+    for (rowid, label) in [(-300, "a"), (-2, "b"), (0, "c"), (5, "d")] {
+        negative_rowids = negative_rowids.row(rowid, vec![Null, text(label)]);
+    }
+    let built = DatabaseBuilder::new()
+        .table(ints)
+        .table(negative_rowids)
+        .write("negative_ints");
+
+    // the same tables written by sqlite3
+    for db in ["tests/fixtures/negative_ints.db", &built] {
+        assert_eq!(
+            run(db, "SELECT width, value FROM ints WHERE value < 0"),
+            "int8|-1\nint8|-128\nint16|-129\nint16|-32768\nint24|-32769\nint24|-8388608\n\
+             int32|-8388609\nint32|-2147483648\nint48|-2147483649\nint48|-140737488355328\n\
+             int64|-140737488355329\nint64|-9223372036854775808\n"
+        );
+        assert_eq!(
+            run(db, "SELECT value FROM ints WHERE width = 'int48'"),
+            "-2147483649\n-140737488355328\n140737488355327\n"
+        );
+        assert_eq!(
+            run(db, "SELECT label FROM negative_rowids WHERE id < 0"),
+            "a\nb\n"
+        );
+        assert_eq!(
+            run(db, "SELECT label FROM negative_rowids WHERE id = -300"),
+            "a\n"
+        );
+        assert_eq!(
+            run(db, "SELECT label FROM negative_rowids WHERE id >= -2"),
+            "b\nc\nd\n"
+        );
+    }
+}
+
+#[test]
+fn record_with_every_serial_type() {
+    let values = vec![
+        Null,
+        Int(-5),
+        Int(1000),
+        Int(-100000),
+        Int(100000000),
+        Int(-10000000000),
+        Int(i64::MIN),
+        Float(-0.25),
+        Int(0),
+        Int(1),
+        Blob(vec![0xca, 0xfe]),
+        text("text"),
+        Blob(vec![]),
+        text(""),
+    ];
+    let table = Table::new(
+        "t",
+        "CREATE TABLE t (null_value, int8, int16, int24, int32, int48, int64, real, zero, one, \
+         blob, text, empty_blob, empty_text)",
+    )
+    .row(1, values);
+    let db = DatabaseBuilder::new().table(table).write("serial_types");
+    assert_eq!(
+        run(&db, "SELECT * FROM t"),
+        "|-5|1000|-100000|100000000|-10000000000|-9223372036854775808|-0.25|0|1|\
+         X'CAFE'|text|X''|\n"
+    );
+    assert_eq!(
+        run(
+            &db,
+            "SELECT count(*) FROM t WHERE zero = 0 AND one = 1 AND int8 < 0"
+        ),
+        "1\n"
+    );
+}
+
+#[test]
+fn empty_table() {
+    let db = DatabaseBuilder::new()
+        .table(Table::new(
+            "empty",
+            "CREATE TABLE empty (id INTEGER PRIMARY KEY, name text)",
+        ))
+        .write("empty_table");
+    assert_eq!(run(&db, "SELECT * FROM empty"), "");
+    assert_eq!(run(&db, "SELECT count(*) FROM empty"), "0\n");
+    assert_eq!(run(&db, "SELECT name FROM empty WHERE id = 1"), "");
+}
+
+#[test]
+fn table_on_exactly_two_leaf_pages() {
+    let mut table =
+        Table::new("t", "CREATE TABLE t (id INTEGER PRIMARY KEY, name text)").rows_per_leaf(5);
+    for rowid in 1..=10 {
+        table = table.row(rowid, vec![Null, text(&format!("row {}", rowid))]);
+    }
+    let db = DatabaseBuilder::new().table(table).write("two_leaves");
+
+    assert_eq!(run(&db, "SELECT count(*) FROM t"), "10\n");
+    // the last row of the first leaf, the first row of the second one
+    assert_eq!(run(&db, "SELECT name FROM t WHERE id = 5"), "row 5\n");
+    assert_eq!(run(&db, "SELECT name FROM t WHERE id = 6"), "row 6\n");
+    assert_eq!(
+        run(&db, "SELECT id FROM t WHERE id >= 4 AND id <= 7"),
+        "4\n5\n6\n7\n"
+    );
+    // the schema, the root and the two leaves
+    assert_eq!(run_counting_pages(&db, "SELECT * FROM t").1, 4);
+    assert_eq!(run_counting_pages(&db, "SELECT * FROM t WHERE id = 6").1, 3);
+}
+
+#[test]
+fn payloads_spilling_to_overflow_pages() {
+    let db = "tests/fixtures/overflow.db";
+    assert_eq!(
+        run(db, "SELECT body FROM documents WHERE id = 2"),
+        format!("{}\n", "a".repeat(10240))
+    );
+    assert_eq!(
+        run(db, "SELECT body FROM documents WHERE id = 3"),
+        format!("{}\n", "b".repeat(4070))
+    );
+    assert_eq!(
+        run(db, "SELECT id, body FROM documents WHERE title = 'mixed'"),
+        format!("4|{}\n", "abc".repeat(3000))
+    );
+    assert_eq!(
+        run(db, "SELECT title FROM documents"),
+        "short\nten kb\njust above the threshold\nmixed\n"
+    );
+}
+
+#[test]
+fn index_keys_spilling_to_overflow_pages() {
+    let db = "tests/fixtures/overflow_index.db";
+    let key = "k".repeat(3000);
+    assert_eq!(
+        run(
+            db,
+            &format!("SELECT label FROM notes WHERE key = '{}1'", key)
+        ),
+        "note 1\n"
+    );
+    assert_eq!(
+        run(
+            db,
+            &format!("SELECT id, label FROM notes WHERE key = '{}250'", key)
+        ),
+        "250|note 250\n"
+    );
+    assert_eq!(
+        run(
+            db,
+            &format!("SELECT label FROM notes WHERE key = '{}301'", key)
+        ),
+        ""
+    );
+}
+
+#[test]
+fn page_size_of_64_kib() {
+    let db = "tests/fixtures/page_size_64k.db";
+    assert!(run(db, ".dbinfo").contains("database page size: 65536\n"));
+    assert_eq!(run(db, "SELECT count(*) FROM items"), "5000\n");
+    assert_eq!(
+        run(db, "SELECT id, price FROM items WHERE name = 'item 4321'"),
+        "4321|12963\n"
+    );
+    assert_eq!(
+        run(db, "SELECT name FROM items WHERE id = 4999"),
+        "item 4999\n"
+    );
+}
+
+#[test]
+fn reserved_bytes_at_the_end_of_pages() {
+    let db = "tests/fixtures/reserved_bytes.db";
+    assert_eq!(
+        run(db, "SELECT body FROM t WHERE id = 1"),
+        format!("{}\n", "z".repeat(5000))
+    );
+    assert_eq!(run(db, "SELECT body FROM t WHERE id = 2"), "small\n");
+
+    // same file, with 64 reserved bytes in the header: the cells now overlap them
+    let corrupted = "tests/fixtures/reserved_bytes_corrupted.db";
+    assert!(run_stderr(corrupted, "SELECT id FROM t")
+        .contains("Corrupted cell: it extends into the reserved space at the end of the page"));
+}
+
+#[test]
+fn utf16_databases() {
+    for db in ["tests/fixtures/utf16le.db", "tests/fixtures/utf16be.db"] {
+        assert_eq!(
+            run(db, "SELECT name, country FROM cities"),
+            "Paris|France\nSão Paulo|Brasil\nZürich|Schweiz\n東京|日本\nKraków|Polska\n\
+             Osaka|日本\n𝕊𝕡𝕒𝕔𝕖 🚀|Moon\n"
+        );
+        assert_eq!(
+            run(db, "SELECT name FROM cities WHERE country = '日本'"),
+            "東京\nOsaka\n"
+        );
+        assert_eq!(
+            run(db, "SELECT id FROM cities WHERE name = 'Zürich'"),
+            "3\n"
+        );
+        assert_eq!(
+            run(db, "SELECT country FROM cities WHERE name = '𝕊𝕡𝕒𝕔𝕖 🚀'"),
+            "Moon\n"
+        );
+    }
+}
+
+#[test]
+fn freelist_pages_in_dbinfo() {
+    let dbinfo = run("tests/fixtures/freelist.db", ".dbinfo");
+    assert!(dbinfo.contains("number of freelist pages: 506\n"));
+    assert_eq!(
+        run("tests/fixtures/freelist.db", "SELECT count(*) FROM logs"),
+        "10\n"
+    );
+    assert!(run("sample.db", ".dbinfo").contains("number of freelist pages: 0\n"));
+}
+
+#[test]
+fn auto_vacuum_database() {
+    let db = "tests/fixtures/auto_vacuum.db";
+    // none of the free pages is a pointer map page
+    assert!(run(db, ".dbinfo").contains("number of freelist pages: 73\n"));
+    assert_eq!(run(db, "SELECT count(*) FROM events"), "2000\n");
+    assert_eq!(
+        run(db, "SELECT id FROM events WHERE name = 'event 1999'"),
+        "1999\n"
+    );
+    assert_eq!(
+        run(db, "SELECT name FROM events WHERE id = 1500"),
+        "event 1500\n"
+    );
+}
+
+#[test]
+fn blobs_are_shown_as_hex_literals() {
+    let db = "tests/fixtures/blobs.db";
+    assert_eq!(
+        run(db, "SELECT name, data FROM files"),
+        "logo.png|X'89504E470D0A1A0A'\nempty|X''\nhello.txt|X'68656C6C6F'\nmissing|\n\
+         text|not a blob\n"
+    );
+    assert_eq!(
+        run(
+            db,
+            "SELECT name FROM files WHERE data = X'89504e470d0a1a0a'"
+        ),
+        "logo.png\n"
+    );
+    assert_eq!(
+        run(db, "SELECT name FROM files WHERE data = x''"),
+        "empty\n"
+    );
+    // a blob is never equal to a text
+    assert_eq!(run(db, "SELECT name FROM files WHERE data = 'hello'"), "");
+    assert_eq!(
+        run(db, "SELECT id FROM files WHERE data = X'68656C6C6F'"),
+        "3\n"
+    );
+}
+
+#[test]
+fn reals_are_formatted_like_sqlite3() {
+    let db = "tests/fixtures/reals.db";
+    // expected output of sqlite3, 3.0 and -0.0 are stored as integers
+    assert_eq!(
+        run(db, "SELECT label, value FROM reals"),
+        "tenth|0.1\ntiny|1.0e-10\nhuge|1.0e+20\nnegative zero|0.0\nthree|3.0\n1e15|1.0e+15\n\
+         1e14|100000000000000.0\n18 digits|1.23456789012346e+17\nsum|0.3\n\
+         max|1.79769313486232e+308\ndenormal|4.94065645841247e-324\nnegative|-1.5\n\
+         1e-4|0.0001\n1e-5|1.0e-05\nrounds up|1.0e+15\nthird|0.333333333333333\n\
+         negative tiny|-2.5e-05\n"
+    );
+    assert_eq!(
+        run(db, "SELECT value * 2 FROM reals WHERE label = 'three'"),
+        "6.0\n"
+    );
+    assert_eq!(
+        run(db, "SELECT label FROM reals WHERE value = 1e20"),
+        "huge\n"
+    );
+}
+
+#[test]
+fn unary_minus_and_plus_on_columns() {
+    // expected output of sqlite3
+    assert_eq!(
+        run(
+            "sample.db",
+            "SELECT -id, - -id, +name, -id * 2, 3 - -id, -(id + 1) FROM apples WHERE -id < -2"
+        ),
+        "-3|3|Honeycrisp|-6|6|-4\n-4|4|Golden Delicious|-8|7|-5\n"
+    );
+}
+
+#[test]
+fn arithmetic_like_sqlite3() {
+    let db = "sample.db";
+    // expected output of sqlite3: the integer division truncates toward zero, and a
+    // division by zero is NULL
+    assert_eq!(
+        run(
+            db,
+            "SELECT 7 / 2, -7 / 2, 7 % 3, -7 % 3, 7.0 / 2, 1 / 0, 1 % 0, 1.5 / 0 \
+             FROM apples WHERE id = 2"
+        ),
+        "3|-3|1|-1|3.5|||\n"
+    );
+    assert_eq!(
+        run(
+            db,
+            "SELECT id / 0, id % 0, id + NULL, NULL * 2, id - NULL FROM apples WHERE id = 2"
+        ),
+        "||||\n"
+    );
+    assert_eq!(run(db, "SELECT name FROM apples WHERE id % 0 = 0"), "");
+    // the integers which overflow become reals
+    assert_eq!(
+        run(
+            db,
+            "SELECT 9223372036854775806 + 1, -9223372036854775807 - 1, 9223372036854775807 + 1, \
+             -9223372036854775807 - 10, 9223372036854775807 * 2, 4611686018427387904 * 2 \
+             FROM apples WHERE id = 2"
+        ),
+        "9223372036854775807|-9223372036854775808|9.22337203685478e+18|-9.22337203685478e+18|\
+         1.84467440737096e+19|9.22337203685478e+18\n"
+    );
+    assert_eq!(
+        run(
+            db,
+            "SELECT 2 + 3 * 4, (2 + 3) * 4, 10 - 4 - 3, 2 * 3 % 4, id + id * 10 \
+             FROM apples WHERE id = 2"
+        ),
+        "14|20|3|2|22\n"
+    );
+    assert_eq!(
+        run(db, "SELECT name FROM apples WHERE id * 2 + 1 = 5"),
+        "Fuji\n"
+    );
+}
+
+#[test]
+fn printf_formats_like_sqlite3() {
+    // expected output of sqlite3
+    assert_eq!(
+        run(
+            "sample.db",
+            "SELECT printf('%% %d', id), printf('[%5d|%-5d|%05d|%+d|%.3d]', id, id, -id, id, id), \
+             printf('[%8.3f|%-8.2f|%08.1f|%.0f|%.0f]', id / 3.0, 2.5, -1.25, 2.5, -0.5), \
+             printf('[%5s|%-6s|%.3s]', name, color, name), printf('%d %s %f|%x', 1), \
+             printf('%x', 255) FROM apples WHERE id = 2"
+        ),
+        "% 2|[    2|2    |-0002|+2|002]|[   0.667|2.50    |-00001.3|3|-1]|[ Fuji|Red   |Fuj]|\
+         1  0.000000|0|ff\n"
+    );
+}
+
+#[test]
+fn instr_replace_and_trim_like_sqlite3() {
+    let db = "sample.db";
+    // expected output of sqlite3: the positions start at 1, 0 when nothing matches, and
+    // the empty string is found at the start
+    assert_eq!(
+        run(
+            db,
+            "SELECT instr(name, 'i'), instr(name, 'z'), instr(name, ''), instr(color, 'Red') \
+             FROM apples"
+        ),
+        "10|0|1|0\n4|0|1|1\n8|0|1|7\n11|0|1|0\n"
+    );
+    assert_eq!(
+        run(db, "SELECT id FROM apples WHERE instr(color, 'Red') = 1"),
+        "2\n"
+    );
+    // an empty pattern is not replaced
+    assert_eq!(
+        run(
+            db,
+            "SELECT replace(name, '', 'x'), replace(name, 'e', ''), replace(color, 'Red', 'Blue') \
+             FROM apples WHERE id = 3"
+        ),
+        "Honeycrisp|Honycrisp|Blush Blue\n"
+    );
+    assert_eq!(
+        run(
+            db,
+            "SELECT trim('xxFujixy', 'xy'), ltrim('..a..', '.'), rtrim(name, 'ij'), \
+             ltrim(color, 'R'), trim('  a  ') FROM apples WHERE id = 2"
+        ),
+        "Fuji|a..|Fu|ed|a\n"
+    );
+    // a NULL argument gives NULL, which matches no comparison
+    assert_eq!(
+        run(
+            db,
+            "SELECT instr(NULL, 'a'), replace(name, NULL, 'x'), trim(name, NULL), ltrim(NULL), \
+             instr(name, 'F') FROM apples WHERE id = 2"
+        ),
+        "||||1\n"
+    );
+    assert_eq!(
+        run(db, "SELECT name FROM apples WHERE instr(name, NULL) = 0"),
+        ""
+    );
+}
+
+#[test]
+fn case_expressions_and_iif() {
+    let db = "sample.db";
+    // expected output of sqlite3
+    assert_eq!(
+        run(
+            db,
+            "SELECT name, CASE WHEN id < 2 THEN 'small' WHEN id < 4 THEN 'medium' ELSE 'large' END \
+             FROM apples"
+        ),
+        "Granny Smith|small\nFuji|medium\nHoneycrisp|medium\nGolden Delicious|large\n"
+    );
+    assert_eq!(
+        run(
+            db,
+            "SELECT CASE color WHEN 'Red' THEN 'red' WHEN 'Yellow' THEN 'yellow' ELSE 'other' END \
+             FROM apples"
+        ),
+        "other\nred\nother\nyellow\n"
+    );
+    // without ELSE, a CASE where no branch matches is NULL
+    assert_eq!(
+        run(db, "SELECT id, CASE WHEN id > 2 THEN 'big' END FROM apples"),
+        "1|\n2|\n3|big\n4|big\n"
+    );
+    assert_eq!(
+        run(
+            db,
+            "SELECT CASE id WHEN 1 THEN 'one' END FROM apples WHERE id < 3"
+        ),
+        "one\n\n"
+    );
+    assert_eq!(
+        run(
+            db,
+            "SELECT name FROM apples \
+             WHERE CASE WHEN color = 'Red' THEN 1 WHEN id = 4 THEN 1 ELSE 0 END = 1"
+        ),
+        "Fuji\nGolden Delicious\n"
+    );
+    assert_eq!(
+        run(
+            db,
+            "SELECT name FROM apples WHERE CASE color WHEN 'Red' THEN 1 END"
+        ),
+        "Fuji\n"
+    );
+
+    assert_eq!(
+        run(db, "SELECT iif(id > 2, 'big', 'small') FROM apples"),
+        "small\nsmall\nbig\nbig\n"
+    );
+    assert_eq!(
+        run_error(db, "SELECT iif(id) FROM apples"),
+        "Parse error: wrong number of arguments to function iif()\n"
+    );
+}
+
+#[test]
+fn sub_selects_in_the_where_clause() {
+    let db = "sample.db";
+    // expected output of sqlite3: the sub select depends on the row through apples.id
+    assert_eq!(
+        run(
+            db,
+            "SELECT name FROM apples \
+             WHERE EXISTS (SELECT 1 FROM oranges WHERE oranges.id = apples.id * 2)"
+        ),
+        "Granny Smith\nFuji\nHoneycrisp\n"
+    );
+    assert_eq!(
+        run(
+            db,
+            "SELECT name FROM apples WHERE id = (SELECT id FROM oranges WHERE name = 'Tangerine')"
+        ),
+        "Honeycrisp\n"
+    );
+    // a sub select without rows is NULL, which is neither equal nor different
+    assert_eq!(
+        run(
+            db,
+            "SELECT name FROM apples WHERE id = (SELECT id FROM oranges WHERE id = 99)"
+        ),
+        ""
+    );
+    assert_eq!(
+        run(
+            db,
+            "SELECT name FROM apples WHERE id <> (SELECT id FROM oranges WHERE id = 99)"
+        ),
+        ""
+    );
+    assert_eq!(
+        run_error(
+            db,
+            "SELECT name FROM apples WHERE id = (SELECT id, name FROM oranges)"
+        ),
+        "Parse error: sub-select returns 2 columns - expected 1\n"
+    );
+}
+
+#[test]
+fn groups_selected_from_a_sub_select() {
+    // expected output of sqlite3
+    let db = "tests/fixtures/companies.db";
+    assert_eq!(
+        run(
+            db,
+            "SELECT country, n FROM \
+             (SELECT country, count(*) AS n FROM companies GROUP BY country) WHERE n > 100"
+        ),
+        "france|582\nindia|584\nunited states|1746\n"
+    );
+    assert_eq!(
+        run(
+            db,
+            "SELECT country, n FROM \
+             (SELECT country, count(*) AS n FROM companies WHERE id <= 500 GROUP BY country) \
+             WHERE n > 2 AND n < 200"
+        ),
+        "albania|4\neritrea|5\nfrance|97\nindia|99\nzimbabwe|4\n"
+    );
+
+    // the rows whose column is NULL make one group, which comes first
+    let db = "tests/fixtures/chinook.db";
+    assert_eq!(
+        run(
+            db,
+            "SELECT Composer, count(Composer), count(*), sum(Milliseconds) FROM Track \
+             GROUP BY Composer"
+        ),
+        "|0|1|342562\nAngus Young, Malcolm Young, Brian Johnson|2|2|549381\n"
+    );
+    assert_eq!(
+        run(
+            db,
+            "SELECT Composer, n FROM \
+             (SELECT Composer, count(*) AS n FROM Track GROUP BY Composer) WHERE n < 2"
+        ),
+        "|1\n"
+    );
+}
+
+#[test]
+fn regexp_matches_the_texts() {
+    let db = "sample.db";
+    assert_eq!(
+        run(db, "SELECT name FROM apples WHERE name REGEXP '^G'"),
+        "Granny Smith\nGolden Delicious\n"
+    );
+    assert_eq!(
+        run(db, "SELECT name FROM apples WHERE color REGEXP 'Red$'"),
+        "Fuji\nHoneycrisp\n"
+    );
+    assert_eq!(
+        run(db, "SELECT name FROM apples WHERE name REGEXP 'smith'"),
+        ""
+    );
+    // only texts are matched: NULL and the numbers exclude the row
+    assert_eq!(run(db, "SELECT name FROM apples WHERE NULL REGEXP 'a'"), "");
+    assert_eq!(
+        run(db, "SELECT name FROM apples WHERE name REGEXP NULL"),
+        ""
+    );
+    assert_eq!(run(db, "SELECT name FROM apples WHERE id REGEXP '2'"), "");
+    assert!(
+        run_error(db, "SELECT name FROM apples WHERE name REGEXP '('")
+            .starts_with("Runtime error: Invalid regular expression (: regex parse error:")
+    );
+    // each row has its own pattern, more than the cache holds
+    assert_eq!(
+        run(
+            "tests/fixtures/companies.db",
+            "SELECT count(*) FROM companies WHERE name REGEXP name"
+        ),
+        "3000\n"
+    );
+}
+
+#[test]
+fn json_paths_and_values() {
+    let db = "sample.db";
+    // expected output of sqlite3
+    assert_eq!(
+        run(
+            db,
+            r#"SELECT json_extract('{"a":{"b":[10,{"c":"x"}]}}', '$.a.b'),
+               json_extract('{"a":{"b":[10,{"c":"x"}]}}', '$.a.b[1].c'),
+               json_extract('{"a":{"b":[10,{"c":"x"}]}}', '$.a.b[0]'),
+               json_extract('[1,2,3]', '$[2]'), json_extract('[1,2,3]', '$[5]')
+               FROM apples WHERE id = 2"#
+        ),
+        "[10,{\"c\":\"x\"}]|x|10|3|\n"
+    );
+    assert_eq!(
+        run(
+            db,
+            r#"SELECT json_extract('{"i":-3}', '$.i'), json_extract('{"r":1.5}', '$.r'),
+               json_extract('{"e":1e3}', '$.e'), json_extract('{"s":"t"}', '$.s'),
+               json_extract('{"t":true}', '$.t'), json_extract('{"n":null}', '$.n'),
+               json_extract('{"o":{"k": [1, "v"]}}', '$.o'), json_extract('{"s":"t"}', '$')
+               FROM apples WHERE id = 2"#
+        ),
+        "-3|1.5|1000.0|t|1||{\"k\":[1,\"v\"]}|{\"s\":\"t\"}\n"
+    );
+    assert_eq!(
+        run(
+            db,
+            r#"SELECT name FROM apples WHERE json_extract('{"x":2}', '$.x') = id"#
+        ),
+        "Fuji\n"
+    );
+    // unlike sqlite3, which fails on malformed JSON, invalid JSON gives NULL like a
+    // missing path
+    assert_eq!(
+        run(
+            db,
+            r#"SELECT json_extract('{"a":', '$.a'), json_valid('{"a":'), json_valid('[1, 2]'),
+               json_extract('{"a":1}', '$.b') FROM apples WHERE id = 2"#
+        ),
+        "|0|1|\n"
+    );
+}
+
+#[test]
+fn b_trees_with_several_interior_levels() {
+    let db = "tests/fixtures/multilevel.db";
+    let ids: Vec<String> = (1..=1000).map(|id| id.to_string()).collect();
+    assert_eq!(run_lines(db, "SELECT id FROM items"), ids);
+    assert_eq!(
+        run(db, "SELECT id, name FROM items WHERE id >= 998"),
+        "998|item 0293\n999|item 0862\n1000|item 0431\n"
+    );
+    assert_eq!(run(db, "SELECT id FROM items WHERE id < 4"), "1\n2\n3\n");
+    assert_eq!(
+        run(db, "SELECT id FROM items WHERE name = 'item 0777'"),
+        "34\n"
+    );
+    let tags: Vec<String> = (0..1000).map(|i| format!("tag {:04}", i)).collect();
+    assert_eq!(run_lines(db, "SELECT name FROM tags"), tags);
+}
+
+#[test]
+fn corrupted_child_pointers_are_errors() {
+    assert_eq!(
+        run("tests/fixtures/corrupted.db", "SELECT count(*) FROM items"),
+        "40\n"
+    );
+
+    let cycle = "tests/fixtures/corrupted_cycle.db";
+    for sql in [
+        "SELECT count(*) FROM items",
+        "SELECT id FROM items WHERE id = 40",
+    ] {
+        assert!(run_stderr(cycle, sql).contains(
+            "Corrupted database: the page 2 points to the page 2, already read in the b tree starting at the page 2"
+        ));
+    }
+
+    // the last key is in the right most child
+    let index_cycle = "tests/fixtures/corrupted_index_cycle.db";
+    assert!(
+        run_stderr(index_cycle, "SELECT id FROM items WHERE name = 'item 40'").contains(
+            "Corrupted database: the page 3 points to the page 3, already read in the b tree starting at the page 3"
+        )
+    );
+
+    let out_of_range = "tests/fixtures/corrupted_out_of_range.db";
+    assert!(run_stderr(out_of_range, "SELECT name FROM items").contains(
+        "Corrupted database: the page 2 points to the page 1000, but the database has 15 pages"
+    ));
+}
+
+#[test]
+fn truncated_databases_are_errors() {
+    // the first 5000 bytes of tests/fixtures/corrupted.db, which has 15 pages of 512 bytes
+    let truncated = "tests/fixtures/truncated.db";
+    for sql in [".tables", "SELECT count(*) FROM items"] {
+        assert!(run_stderr(truncated, sql)
+            .contains("database file is truncated: expected 7680 bytes, found 5000"));
+    }
+
+    // the header itself is cut
+    assert!(run_stderr("tests/fixtures/truncated_header.db", ".dbinfo")
+        .contains("database file is truncated: expected at least 100 bytes, found 60"));
+}
+
+#[test]
+fn records_must_fill_their_payload() {
+    let db = "tests/fixtures/record_size_mismatch.db";
+    assert!(run_stderr(db, "SELECT name FROM people").contains(
+        "Could not read the table people: Could not read the cell 0 of the page 2, with the rowid 1: Corrupted record: its header and columns take 8 bytes but the payload of the cell has 10 bytes"
+    ));
+
+    // the record is read with the wrong size of its name
+    let output = cli(["--lenient", db, "SELECT name FROM people"]);
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "ali\nbob\n");
+
+    assert_eq!(
+        run("tests/fixtures/record_size.db", "SELECT name FROM people"),
+        "alice\nbob\n"
+    );
+}
+
+#[test]
+fn page_count_of_stale_headers_comes_from_the_file() {
+    // like sqlite3, trusts the 3 pages announced by the header
+    assert!(run_stderr(
+        "tests/fixtures/small_page_count.db",
+        "SELECT count(*) FROM items"
+    )
+    .contains("Corrupted database: the page 2 points to the page 4, but the database has 3 pages"));
+    // unless the version-valid-for number shows it was not updated
+    assert_eq!(
+        run(
+            "tests/fixtures/stale_page_count.db",
+            "SELECT count(*) FROM items"
+        ),
+        "40\n"
+    );
+}
+
+#[test]
+fn reserved_header_bytes_are_a_warning() {
+    let db = "tests/fixtures/reserved_header_bytes.db";
+    assert_eq!(run(db, "SELECT count(*) FROM items"), "40\n");
+    assert!(run_stderr(db, "SELECT count(*) FROM items").contains(
+        "warning: the bytes 72 to 91 of the database header, reserved for expansion, are not zero"
+    ));
+}
+
+#[test]
+fn committed_pages_of_the_write_ahead_log() {
+    let db = "tests/fixtures/wal.db";
+    assert_eq!(run(db, "SELECT * FROM fruits"), "1|green apple\n3|cherry\n");
+    assert_eq!(run(db, "SELECT name FROM vegetables"), "leek\n");
+    assert_eq!(run(db, ".tables"), "fruits vegetables\n");
+    assert_eq!(
+        run(db, ".dbinfo"),
+        "database page size: 4096\nnumber of tables: 2\nnumber of freelist pages: 0\n"
+    );
+}
+
+#[test]
+fn hot_journals_are_rolled_back() {
+    // the main file has the 60 accounts of the interrupted transaction
+    let db = "tests/fixtures/hot_journal.db";
+    assert_eq!(
+        run(db, "SELECT count(*), sum(balance) FROM accounts"),
+        "20|2000\n"
+    );
+    assert_eq!(
+        run(db, "SELECT balance FROM accounts WHERE id = 3"),
+        "100\n"
+    );
+    assert_eq!(
+        run(db, "SELECT balance FROM accounts WHERE id = 17"),
+        "100\n"
+    );
+
+    let db = "tests/fixtures/corrupted_journal.db";
+    assert!(run_stderr(db, "SELECT count(*) FROM accounts").contains(
+        "the database has a hot journal tests/fixtures/corrupted_journal.db-journal which could not be read"
+    ));
+    let output = cli(["--force", db, "SELECT count(*), sum(balance) FROM accounts"]);
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "60|2000\n");
+}
+
+#[test]
+fn free_bytes_of_the_pages() {
+    // the free bytes are the unused bytes of the dbstat virtual table of sqlite3
+    assert_eq!(
+        run("tests/fixtures/freeblocks.db", ".stats"),
+        "sqlite_schema: 1 pages, 766 free bytes (74.8%): 766 unallocated, 0 in 0 freeblocks, 0 fragmented
+notes: 3 pages, 1521 free bytes (49.5%): 1334 unallocated, 179 in 10 freeblocks, 8 fragmented
+notes_body: 3 pages, 1525 free bytes (49.6%): 1281 unallocated, 231 in 7 freeblocks, 13 fragmented
+total: 7 pages, 3812 free bytes (53.2%): 3381 unallocated, 410 in 17 freeblocks, 21 fragmented
+"
+    );
+}
+
+#[test]
+fn blobs_are_streamed_to_a_file() {
+    let db = "tests/fixtures/attachments.db";
+    let output = std::env::temp_dir().join("codecrafters_sqlite_blob.txt");
+    let write_blob = |rowid: &str, column: &str| {
+        cli([
+            db,
+            ".blob",
+            "attachments",
+            rowid,
+            column,
+            output.to_str().unwrap(),
+        ])
+    };
+
+    // the blob spans about a hundred overflow pages
+    assert!(write_blob("1", "data").status.success());
+    let numbers = (0..20000).map(|i| format!("{:05}", i)).collect::<String>();
+    assert_eq!(std::fs::read(&output).unwrap(), numbers.as_bytes());
+
+    assert!(write_blob("2", "data").status.success());
+    assert_eq!(std::fs::read(&output).unwrap(), [0x00, 0xff, 0x10]);
+    assert!(write_blob("3", "data").status.success());
+    assert_eq!(std::fs::read(&output).unwrap(), Vec::<u8>::new());
+    assert!(write_blob("2", "name").status.success());
+    assert_eq!(std::fs::read(&output).unwrap(), b"small.bin");
+
+    let stderr = |output: Output| String::from_utf8(output.stderr).unwrap();
+    assert!(stderr(write_blob("4", "data")).contains("The column is not a blob or a text: Null"));
+    assert!(stderr(write_blob("5", "data")).contains("no such rowid: 5"));
+    assert!(stderr(write_blob("1", "size")).contains("no such column: size"));
+}
+
+#[test]
+fn invalid_text_is_replaced_rejected_or_read_as_a_blob() {
+    let db = "tests/fixtures/invalid_utf8.db";
+    let run_with =
+        |text_decoding: &str, sql: &str| cli(["--text-decoding", text_decoding, db, sql]);
+
+    assert_eq!(
+        run(db, "SELECT body FROM notes"),
+        "hello\nhi\u{FFFD}!\nbye\n"
+    );
+
+    let output = run_with("strict", "SELECT body FROM notes");
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr).unwrap().contains(
+        "Could not read the table notes: Could not read the cell 1 of the page 2, with the rowid 2: Invalid Utf8 text in the column 1 of the record"
+    ));
+    let output = run_with("strict", "SELECT body FROM notes WHERE id = 3");
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "bye\n");
+
+    let output = run_with("raw", "SELECT body FROM notes");
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "hello\nX'6869FF21'\nbye\n"
+    );
+}
+
+#[test]
+fn index_lookups_find_every_matching_record() {
+    let db = "tests/fixtures/companies.db";
+    let scan = run_lines(db, "SELECT id, country FROM companies");
+    let expected = |country: &str| {
+        scan.iter()
+            .filter(|line| line.ends_with(&format!("|{}", country)))
+            .map(|line| line.split('|').next().unwrap().to_string())
+            .collect::<Vec<_>>()
+    };
+
+    // 'albania' is the first key of the index and 'zimbabwe' the last one, 'united
+    // states' spans many leaf pages and cells of the interior pages
+    for country in ["albania", "eritrea", "france", "united states", "zimbabwe"] {
+        let found = run_lines(
+            db,
+            &format!("SELECT id FROM companies WHERE country = '{}'", country),
+        );
+        assert!(!found.is_empty());
+        assert_eq!(found, expected(country), "{}", country);
+    }
+    // before the first key and after the last one
+    assert_eq!(
+        run(db, "SELECT id FROM companies WHERE country = 'aaa'"),
+        ""
+    );
+    assert_eq!(
+        run(db, "SELECT id FROM companies WHERE country = 'zzz'"),
+        ""
+    );
+    assert_eq!(run(db, "SELECT id FROM companies WHERE country = 'g'"), "");
+}
+
+#[test]
+fn index_lookups_give_the_rows_of_a_scan() {
+    let db = "tests/fixtures/companies.db";
+    // the comparison with a function call can not use the index
+    assert_eq!(
+        run(
+            db,
+            "SELECT id, name FROM companies WHERE country = 'eritrea'"
+        ),
+        run(
+            db,
+            "SELECT id, name FROM companies WHERE country = trim(' eritrea ')"
+        )
+    );
+    assert_eq!(
+        run_lines(
+            db,
+            "SELECT id, name FROM companies WHERE country = 'eritrea'"
+        )[..3],
+        ["97|company 0097", "194|company 0194", "291|company 0291"]
+    );
+}
+
+#[test]
+fn index_range_scans_stop_at_their_bounds() {
+    let db = "tests/fixtures/companies.db";
+    // the comparisons with a function call can not use the index
+    assert_eq!(
+        run(
+            db,
+            "SELECT id, name FROM companies WHERE country >= 'a' AND country < 'b'"
+        ),
+        run(
+            db,
+            "SELECT id, name FROM companies WHERE trim(country) >= 'a' AND trim(country) < 'b'"
+        )
+    );
+    assert_eq!(
+        run(
+            db,
+            "SELECT count(*) FROM companies WHERE country >= 'eritrea' AND country < 'india'"
+        ),
+        "612\n"
+    );
+    assert_eq!(
+        run(
+            db,
+            "SELECT count(*) FROM companies WHERE country > 'eritrea' AND country <= 'india'"
+        ),
+        "1166\n"
+    );
+    assert_eq!(
+        run(
+            db,
+            "SELECT count(*) FROM companies WHERE 'united states' < country"
+        ),
+        "29\n"
+    );
+    assert_eq!(
+        run(
+            db,
+            "SELECT count(*) FROM companies WHERE country > 'zimbabwe'"
+        ),
+        "0\n"
+    );
+    // the other conditions still filter the rows of the range
+    assert_eq!(
+        run(
+            db,
+            "SELECT count(*) FROM companies WHERE country < 'f' AND country > 'b' AND id < 1000"
+        ),
+        "10\n"
+    );
+
+    let db = "tests/fixtures/wide_index.db";
+    assert_eq!(
+        run_lines(
+            db,
+            "SELECT id FROM words WHERE word > 'word 148' AND word < 'zebra'"
+        ),
+        ["149", "150"]
+    );
+}
+
+#[test]
+fn covering_indexes_do_not_read_the_table() {
+    // the root page of the table b tree is overwritten: only the queries answered from
+    // idx_companies_country (country) and the rowid can still be run
+    let mut bytes = std::fs::read("tests/fixtures/companies.db").unwrap();
+    bytes[512..1024].fill(0xff);
+    let path = std::env::temp_dir().join("codecrafters_sqlite_no_table.db");
+    std::fs::write(&path, bytes).unwrap();
+    let db = path.to_str().unwrap();
+
+    let query = "SELECT id, country FROM companies WHERE country = 'eritrea' ORDER BY id DESC";
+    assert_eq!(run(db, query), run("tests/fixtures/companies.db", query));
+    assert_eq!(
+        run_lines(
+            db,
+            "SELECT count(*), min(id) FROM companies WHERE country >= 'a' AND country < 'b'"
+        ),
+        run_lines(
+            "tests/fixtures/companies.db",
+            "SELECT count(*), min(id) FROM companies WHERE country >= 'a' AND country < 'b'"
+        )
+    );
+    assert!(!run_stderr(db, "SELECT name FROM companies WHERE country = 'eritrea'").is_empty());
+    assert!(!run_stderr(db, "SELECT * FROM companies WHERE country = 'eritrea'").is_empty());
+}
+
+#[test]
+fn index_lookups_in_a_wide_leaf_page() {
+    let db = "tests/fixtures/wide_index.db";
+    assert_eq!(
+        run(db, "SELECT id FROM words WHERE word = 'word 100'"),
+        "100\n"
+    );
+    assert_eq!(
+        run(db, "SELECT id FROM words WHERE word = 'word 001'"),
+        "1\n"
+    );
+    assert_eq!(run(db, "SELECT id FROM words WHERE word = 'word 000'"), "");
+    assert_eq!(run(db, "SELECT id FROM words WHERE word = 'word 1000'"), "");
+    assert_eq!(run(db, "SELECT id FROM words WHERE word = 'zzz'"), "");
+    // from the middle of the page to its end
+    let zebras = run_lines(db, "SELECT id FROM words WHERE word = 'zebra'");
+    assert_eq!(
+        zebras,
+        (151..=300).map(|id| id.to_string()).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn explain_query_plan_shows_how_the_rows_are_read() {
+    let db = "tests/fixtures/companies.db";
+    let explain = |sql: &str| run(db, &format!("EXPLAIN QUERY PLAN {}", sql));
+    // the same steps as sqlite3
+    assert_eq!(
+        explain("SELECT name FROM companies"),
+        "QUERY PLAN\n`--SCAN companies\n"
+    );
+    assert_eq!(
+        explain("SELECT name FROM companies WHERE id = 3"),
+        "QUERY PLAN\n`--SEARCH companies USING INTEGER PRIMARY KEY (rowid=?)\n"
+    );
+    assert_eq!(
+        explain("SELECT name FROM companies WHERE id > 3 AND id <= 9"),
+        "QUERY PLAN\n`--SEARCH companies USING INTEGER PRIMARY KEY (rowid>? AND rowid<?)\n"
+    );
+    assert_eq!(
+        explain("SELECT name FROM companies WHERE country = 'india' ORDER BY name"),
+        "QUERY PLAN\n\
+         |--SEARCH companies USING INDEX idx_companies_country (country=?)\n\
+         `--USE TEMP B-TREE FOR ORDER BY\n"
+    );
+    assert_eq!(
+        explain("SELECT name FROM companies WHERE country > 'a' AND country < 'b'"),
+        "QUERY PLAN\n`--SEARCH companies USING INDEX idx_companies_country (country>? AND country<?)\n"
+    );
+    assert_eq!(
+        explain("SELECT id FROM companies WHERE country < 'b'"),
+        "QUERY PLAN\n`--SEARCH companies USING COVERING INDEX idx_companies_country (country<?)\n"
+    );
+    // nothing is read
+    assert_eq!(
+        run_lines(db, "EXPLAIN QUERY PLAN SELECT * FROM companies").len(),
+        2
+    );
+}
+
+#[test]
+fn constraints_are_looked_up_through_their_autoindex() {
+    let db = "tests/fixtures/accounts.db";
+    assert_eq!(
+        run(
+            db,
+            "EXPLAIN QUERY PLAN SELECT age FROM accounts WHERE username = 'user0042'"
+        ),
+        "QUERY PLAN\n`--SEARCH accounts USING INDEX sqlite_autoindex_accounts_1 (username=?)\n"
+    );
+    assert_eq!(
+        run(
+            db,
+            "SELECT email, nickname, age FROM accounts WHERE username = 'user0042'"
+        ),
+        "user0518@example.com|Nick518|38\n"
+    );
+    assert_eq!(
+        run(
+            db,
+            "EXPLAIN QUERY PLAN SELECT age FROM accounts WHERE email = 'user0500@example.com'"
+        ),
+        "QUERY PLAN\n`--SEARCH accounts USING INDEX sqlite_autoindex_accounts_2 (email=?)\n"
+    );
+    assert_eq!(
+        run(
+            db,
+            "SELECT username FROM accounts WHERE email = 'user0500@example.com'"
+        ),
+        "user0500\n"
+    );
+    assert_eq!(
+        run_lines(
+            db,
+            "SELECT username FROM accounts WHERE username >= 'user0997' ORDER BY username"
+        ),
+        ["user0997", "user0998", "user0999"]
+    );
+    // the values of nickname are compared with their case, unlike in its index
+    assert_eq!(
+        run(
+            db,
+            "EXPLAIN QUERY PLAN SELECT age FROM accounts WHERE nickname = 'nick7'"
+        ),
+        "QUERY PLAN\n`--SCAN accounts\n"
+    );
+}
+
+#[test]
+fn limit_stops_reading_the_table() {
+    let db = "tests/fixtures/companies.db";
+    let pages_read = |sql: &str| run_counting_pages(db, sql).1;
+
+    // the table has almost 200 pages
+    assert!(pages_read("SELECT name FROM companies") > 150);
+    // the schema, then the first leaf page of the table through its interior pages
+    assert!(pages_read("SELECT name FROM companies LIMIT 5") < 8);
+    assert!(pages_read("SELECT name FROM companies WHERE id > 2000 LIMIT 2 OFFSET 3") < 8);
+    // all the rows are needed to sort them
+    assert!(pages_read("SELECT name FROM companies ORDER BY name LIMIT 2") > 150);
+    assert_eq!(
+        run(
+            db,
+            "SELECT name FROM companies WHERE country = 'india' LIMIT 2 OFFSET 1"
+        ),
+        "company 0010\ncompany 0015\n"
+    );
+}
+
+#[test]
+fn unused_columns_are_not_decoded() {
+    let db = "tests/fixtures/wide_blobs.db";
+    // the 100 leaf pages hold the start of the rows, the end of the blobs is in 200
+    // overflow pages. Besides the schema and the root page, 2 rows take 2 leaf pages
+    // and 4 overflow pages.
+    let (all, nb_pages_all) = run_counting_pages(db, "SELECT * FROM files WHERE id >= 99");
+    assert_eq!(all.lines().count(), 2);
+    assert_eq!(nb_pages_all, 8);
+    assert_eq!(run_counting_pages(db, "SELECT * FROM files").1, 302);
+
+    // the blob is before the last column: its overflow pages are passed over, but
+    // only their link to the next one is read
+    let (sizes, _) = run_counting_pages(db, "SELECT id, size FROM files WHERE id >= 99");
+    assert_eq!(sizes, "99|990\n100|1000\n");
+    let (names, nb_pages) = run_counting_pages(db, "SELECT name FROM files WHERE id >= 99");
+    assert_eq!(names, "file099\nfile100\n");
+    assert_eq!(nb_pages, 4);
+    assert_eq!(run_counting_pages(db, "SELECT name FROM files").1, 102);
+    assert_eq!(
+        run(db, "SELECT name FROM files WHERE size = 420"),
+        "file042\n"
+    );
+}
+
+#[test]
+fn rowid_lookups_read_one_path_of_the_b_tree() {
+    let db = "tests/fixtures/companies.db";
+    let (_, nb_pages_of_a_scan) = run_counting_pages(db, "SELECT count(*) FROM companies");
+    assert_eq!(nb_pages_of_a_scan, 193);
+    // expected output of sqlite3. The table has a root page, interior pages and leaf
+    // pages: a lookup reads the schema and one page of each level, even for a rowid
+    // which is not in the table.
+    for (id, row) in [
+        (1, "1|company 0001|france\n"),
+        (1500, "1500|company 1500|india\n"),
+        (3000, "3000|company 3000|india\n"),
+        (3001, ""),
+        (0, ""),
+        (-5, ""),
+    ] {
+        let sql = format!("SELECT id, name, country FROM companies WHERE id = {}", id);
+        assert_eq!(
+            run_counting_pages(db, &sql),
+            (row.to_string(), 4),
+            "id = {}",
+            id
+        );
+    }
+}
+
+#[test]
+fn rowid_searches_at_the_keys_of_the_interior_pages() {
+    let db = "tests/fixtures/multilevel.db";
+    // the keys of the root page are 166, 316, 441, 588, 737 and 869, the ones of its
+    // first child start with 4, 8 and 12. A key is the largest rowid of its left child.
+    for id in [4, 5, 166, 167, 869, 870, 1000] {
+        let (row, nb_pages) =
+            run_counting_pages(db, &format!("SELECT id FROM items WHERE id = {}", id));
+        assert_eq!(row, format!("{}\n", id));
+        // the schema, the root page, an interior page and a leaf page
+        assert_eq!(nb_pages, 4, "id = {}", id);
+    }
+    assert_eq!(run(db, "SELECT id FROM items WHERE id = 1001"), "");
+    assert_eq!(run(db, "SELECT id FROM items WHERE id = 0"), "");
+
+    assert_eq!(
+        run(db, "SELECT id FROM items WHERE id >= 166 AND id <= 167"),
+        "166\n167\n"
+    );
+    assert_eq!(
+        run(db, "SELECT id FROM items WHERE id > 165 AND id < 167"),
+        "166\n"
+    );
+    assert_eq!(
+        run(db, "SELECT id FROM items WHERE id > 166 AND id < 169"),
+        "167\n168\n"
+    );
+    assert_eq!(run(db, "SELECT id FROM items WHERE id > 1000"), "");
+    assert_eq!(
+        run(db, "SELECT id FROM items WHERE id <= 4"),
+        "1\n2\n3\n4\n"
+    );
+    let ids: Vec<String> = (869..=1000).map(|id| id.to_string()).collect();
+    assert_eq!(run_lines(db, "SELECT id FROM items WHERE id >= 869"), ids);
+    // a range within a leaf page only reads this page
+    assert_eq!(
+        run_counting_pages(db, "SELECT id FROM items WHERE id >= 5 AND id <= 8").1,
+        4
+    );
+}
+
+#[test]
+fn indexes_are_chosen_from_the_statistics_of_analyze() {
+    let db = "tests/fixtures/analyzed.db";
+    let explain = |db: &str, sql: &str| run(db, &format!("EXPLAIN QUERY PLAN {}", sql));
+
+    // without statistics, the first index of the schema would be chosen
+    let query = "SELECT amount FROM orders WHERE status = 'done' AND customer = 'c001'";
+    assert_eq!(
+        explain(db, query),
+        "QUERY PLAN\n`--SEARCH orders USING INDEX idx_orders_customer (customer=?)\n"
+    );
+    assert_eq!(run(db, query), "1\n401\n801\n");
+    // all the rows are in the same region: the index is only used when it covers the query
+    let query = "SELECT sum(amount) FROM orders WHERE region = 'eu'";
+    assert_eq!(explain(db, query), "QUERY PLAN\n`--SCAN orders\n");
+    assert_eq!(run(db, query), "500500\n");
+    assert_eq!(
+        explain(db, "SELECT count(*) FROM orders WHERE region = 'eu'"),
+        "QUERY PLAN\n`--SEARCH orders USING COVERING INDEX idx_orders_region (region=?)\n"
+    );
+    assert_eq!(
+        explain(db, "SELECT amount FROM orders WHERE status = 'pending'"),
+        "QUERY PLAN\n`--SEARCH orders USING INDEX idx_orders_status (status=?)\n"
+    );
+
+    // the statistics of idx_orders_customer can not be read: the other ones are not
+    // enough to compare the indexes
+    let mut bytes = std::fs::read(db).unwrap();
+    let stat = bytes.windows(6).position(|w| w == b"1000 3").unwrap();
+    bytes[stat..stat + 4].copy_from_slice(b"many");
+    let path = std::env::temp_dir().join("codecrafters_sqlite_malformed_stat1.db");
+    std::fs::write(&path, bytes).unwrap();
+    let malformed = path.to_str().unwrap();
+    assert_eq!(
+        explain(
+            malformed,
+            "SELECT amount FROM orders WHERE status = 'done' AND customer = 'c001'"
+        ),
+        "QUERY PLAN\n`--SEARCH orders USING INDEX idx_orders_status (status=?)\n"
+    );
+    assert_eq!(
+        run(
+            malformed,
+            "SELECT amount FROM orders WHERE customer = 'c001'"
+        ),
+        "1\n401\n801\n"
+    );
+}
+
+#[test]
+fn tables_can_be_scanned_by_several_threads() {
+    let db = "tests/fixtures/companies.db";
+    for sql in [
+        "SELECT id, name FROM companies WHERE name < 'd'",
+        "SELECT count(*) FROM companies",
+    ] {
+        let output = cli(["--threads", "3", db, sql]);
+        assert_eq!(String::from_utf8(output.stdout).unwrap(), run(db, sql));
+    }
+}
+
+#[test]
+fn large_integers_are_not_rounded_to_be_compared_with_reals() {
+    // 2^53 + 1 is not a f64, sqlite3 finds it greater than 2^53
+    let sql = "SELECT count(*) FROM apples WHERE 9007199254740993 > 9007199254740992.0";
+    assert_eq!(run("sample.db", sql), "4\n");
+    let sql = "SELECT count(*) FROM apples WHERE 9007199254740993 = 9007199254740992.0";
+    assert_eq!(run("sample.db", sql), "0\n");
+}
+
+#[test]
+fn reads_are_logged_on_stderr_with_verbose() {
+    let db = "tests/fixtures/companies.db";
+    let sql = "SELECT id FROM companies WHERE country = 'eritrea' LIMIT 2";
+    let output = cli(["--verbose", db, sql]);
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), run(db, sql));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    for event in [
+        "page read",
+        "index probe",
+        "descending into the child",
+        "row read",
+    ] {
+        assert!(
+            stderr.contains(event),
+            "{} is not logged: {}",
+            event,
+            stderr
+        );
+    }
+    // nothing is logged without --verbose
+    assert_eq!(run_stderr(db, sql), "");
+}
+
+#[test]
+fn tables_of_attached_databases() {
+    let attached = |args: &[&str]| {
+        let output = cli(["sample.db", "--attach", "other=tests/fixtures/users.db"]
+            .iter()
+            .chain(args));
+        (
+            String::from_utf8(output.stdout).unwrap(),
+            String::from_utf8(output.stderr).unwrap(),
+        )
+    };
+    assert_eq!(
+        attached(&[".tables"]).0,
+        "apples oranges other.users other.guests\n"
+    );
+    assert_eq!(
+        attached(&["SELECT name FROM other.users WHERE id = 2"]).0,
+        "user 2\n"
+    );
+    assert_eq!(attached(&["SELECT count(*) FROM main.apples"]).0, "4\n");
+    // a name which is not in the main database is found in the attached ones
+    assert_eq!(
+        attached(&["SELECT email FROM guests WHERE id = 1"]).0,
+        "user1@example.com\n"
+    );
+    // the indexes of the attached database are used
+    assert_eq!(
+        attached(&[
+            "EXPLAIN QUERY PLAN SELECT id FROM other.users WHERE email = 'user3@example.com'"
+        ])
+        .0,
+        "QUERY PLAN\n`--SEARCH users USING COVERING INDEX idx_users_email (email=?)\n"
+    );
+    assert_eq!(
+        attached(&["SELECT * FROM other.apples"]).1,
+        "Parse error: no such table: other.apples\n"
+    );
+    assert_eq!(
+        attached(&["SELECT * FROM nowhere.apples"]).1,
+        "Parse error: no such table: nowhere.apples\n"
+    );
+}